@@ -34,6 +34,12 @@ mod syn_parse {
     }
 }
 
+mod syn_parse_lazy {
+    pub fn bench(content: &str) -> Result<(), ()> {
+        syn::parse_file_lazy(content).map(drop).map_err(drop)
+    }
+}
+
 #[cfg(not(syn_only))]
 mod librustc_parse {
     extern crate rustc_data_structures;
@@ -143,6 +149,7 @@ fn main() {
         #[cfg(not(syn_only))]
         tokenstream_parse,
         syn_parse,
+        syn_parse_lazy,
         #[cfg(not(syn_only))]
         librustc_parse,
     ) {
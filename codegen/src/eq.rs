@@ -17,6 +17,13 @@ fn always_eq(field_type: &Type) -> bool {
     }
 }
 
+fn is_token_stream(field_type: &Type) -> bool {
+    match field_type {
+        Type::Ext(ty) => ty == "TokenStream",
+        _ => false,
+    }
+}
+
 fn expand_impl_body(defs: &Definitions, node: &Node) -> TokenStream {
     let type_name = &node.ident;
     let ident = Ident::new(type_name, Span::call_site());
@@ -90,6 +97,12 @@ fn expand_impl_body(defs: &Definitions, node: &Node) -> TokenStream {
                     Type::Ext(ty) if ty == "TokenStream" => {
                         quote!(TokenStreamHelper(&self.#ident) == TokenStreamHelper(&other.#ident))
                     }
+                    Type::Option(ty) if is_token_stream(ty) => {
+                        quote! {
+                            self.#ident.as_ref().map(TokenStreamHelper)
+                                == other.#ident.as_ref().map(TokenStreamHelper)
+                        }
+                    }
                     _ => quote!(self.#ident == other.#ident),
                 });
             }
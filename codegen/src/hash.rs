@@ -17,6 +17,13 @@ fn skip(field_type: &Type) -> bool {
     }
 }
 
+fn is_token_stream(field_type: &Type) -> bool {
+    match field_type {
+        Type::Ext(ty) => ty == "TokenStream",
+        _ => false,
+    }
+}
+
 fn expand_impl_body(defs: &Definitions, node: &Node) -> TokenStream {
     let type_name = &node.ident;
     let ident = Ident::new(type_name, Span::call_site());
@@ -96,10 +103,14 @@ fn expand_impl_body(defs: &Definitions, node: &Node) -> TokenStream {
                 }
                 let ident = Ident::new(f, Span::call_site());
                 let mut val = quote!(self.#ident);
-                if let Type::Ext(ty) = ty {
-                    if ty == "TokenStream" {
+                match ty {
+                    Type::Ext(ty) if ty == "TokenStream" => {
                         val = quote!(TokenStreamHelper(&#val));
                     }
+                    Type::Option(ty) if is_token_stream(ty) => {
+                        val = quote!(#val.as_ref().map(TokenStreamHelper));
+                    }
+                    _ => {}
                 }
                 Some(quote! {
                     #val.hash(state);
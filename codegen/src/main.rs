@@ -24,9 +24,13 @@ mod json;
 mod lookup;
 mod operand;
 mod parse;
+mod serde;
 mod snapshot;
+mod try_fold;
+mod try_visit;
 mod version;
 mod visit;
+mod visit_ancestors;
 mod visit_mut;
 
 fn main() -> anyhow::Result<()> {
@@ -38,8 +42,12 @@ fn main() -> anyhow::Result<()> {
     hash::generate(&defs)?;
     json::generate(&defs)?;
     fold::generate(&defs)?;
+    try_fold::generate(&defs)?;
     visit::generate(&defs)?;
     visit_mut::generate(&defs)?;
+    try_visit::generate(&defs)?;
+    visit_ancestors::generate(&defs)?;
+    serde::generate(&defs)?;
     snapshot::generate(&defs)?;
     Ok(())
 }
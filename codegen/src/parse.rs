@@ -602,12 +602,19 @@ fn do_load_file<P: AsRef<Path>>(
                 };
 
                 // Look up the submodule file, and recursively parse it.
-                // Only handles same-directory .rs file submodules for now.
-                let filename = match parsing::path_attr(&item.attrs)? {
-                    Some(filename) => filename.value(),
-                    None => format!("{}.rs", item.ident),
+                // Only handles same-directory .rs file and mod.rs submodules
+                // for now.
+                let path = match parsing::path_attr(&item.attrs)? {
+                    Some(filename) => parent.join(filename.value()),
+                    None => {
+                        let path = parent.join(format!("{}.rs", item.ident));
+                        if path.exists() {
+                            path
+                        } else {
+                            parent.join(item.ident.to_string()).join("mod.rs")
+                        }
+                    }
                 };
-                let path = parent.join(filename);
                 load_file(path, &features, lookup)?;
             }
             Item::Macro(item) => {
@@ -0,0 +1,533 @@
+use crate::{cfg, file, lookup};
+use anyhow::Result;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn_codegen::{Data, Definitions, Node, Type};
+
+const SERDE_SRC: &str = "../src/gen/serde.rs";
+
+fn skip(field_type: &Type) -> bool {
+    match field_type {
+        Type::Syn(node) => node == "Reserved",
+        Type::Ext(ty) => ty == "Span",
+        Type::Token(_) | Type::Group(_) => true,
+        Type::Box(inner) => skip(inner),
+        Type::Tuple(inner) => inner.iter().all(skip),
+        _ => false,
+    }
+}
+
+fn is_token(field_type: &Type) -> bool {
+    match field_type {
+        Type::Token(_) | Type::Group(_) => true,
+        _ => false,
+    }
+}
+
+// Types that implement Serialize and Deserialize themselves.
+fn is_plain(field_type: &Type) -> bool {
+    match field_type {
+        Type::Syn(_) | Type::Std(_) => true,
+        Type::Punctuated(punctuated) => is_plain(&punctuated.element),
+        Type::Option(inner) | Type::Box(inner) | Type::Vec(inner) => is_plain(inner),
+        Type::Ext(_) | Type::Token(_) | Type::Group(_) | Type::Tuple(_) => false,
+    }
+}
+
+// Missing fields of these types deserialize as empty.
+fn has_default(field_type: &Type) -> bool {
+    match field_type {
+        Type::Option(_) | Type::Vec(_) | Type::Punctuated(_) => true,
+        _ => false,
+    }
+}
+
+// Tokens are left out of tuples, and a tuple of one element is represented by
+// the element unless it is itself optional.
+fn unwrap_tuple(elements: &[Type]) -> Option<usize> {
+    let mut kept = elements
+        .iter()
+        .enumerate()
+        .filter(|(_i, element)| !is_token(element));
+    match (kept.next(), kept.next()) {
+        (Some((i, element)), None) => match element {
+            Type::Option(_) => None,
+            _ => Some(i),
+        },
+        _ => None,
+    }
+}
+
+// Converts a reference to a value of the given type into its serialized
+// representation.
+fn to_repr(field_type: &Type, val: TokenStream) -> TokenStream {
+    if is_plain(field_type) {
+        return val;
+    }
+    match field_type {
+        Type::Ext(_) => quote!(Ser(#val)),
+        Type::Option(inner) => match &**inner {
+            Type::Token(_) | Type::Group(_) => quote!(#val.is_some()),
+            Type::Ext(_) => quote!(#val.as_ref().map(Ser)),
+            Type::Tuple(elements) => {
+                let mut pats = Vec::new();
+                let mut reprs = Vec::new();
+                for (i, element) in elements.iter().enumerate() {
+                    if is_token(element) {
+                        pats.push(quote!(_));
+                        continue;
+                    }
+                    let var = format_ident!("v{}", i);
+                    reprs.push(to_repr(element, quote!(#var)));
+                    pats.push(quote!(#var));
+                }
+                let repr = match unwrap_tuple(elements) {
+                    Some(_) => quote!(#(#reprs)*),
+                    None => quote!((#(#reprs,)*)),
+                };
+                quote!(#val.as_ref().map(|(#(#pats),*)| #repr))
+            }
+            inner => {
+                let repr = to_repr(inner, quote!(v));
+                quote!(#val.as_ref().map(|v| #repr))
+            }
+        },
+        _ => unimplemented!("serialize {:?}", field_type),
+    }
+}
+
+// The type that is deserialized for a value of the given type, with
+// placeholders where the type is inferred.
+fn repr_type(field_type: &Type) -> TokenStream {
+    if is_plain(field_type) {
+        return quote!(_);
+    }
+    match field_type {
+        Type::Ext(_) => quote!(De<_>),
+        Type::Option(inner) => match &**inner {
+            Type::Token(_) | Type::Group(_) => quote!(bool),
+            Type::Tuple(elements) => {
+                let types = elements
+                    .iter()
+                    .filter(|element| !is_token(element))
+                    .map(repr_type);
+                match unwrap_tuple(elements) {
+                    Some(_) => quote!(Option<#(#types)*>),
+                    None => quote!(Option<(#(#types,)*)>),
+                }
+            }
+            inner => {
+                let ty = repr_type(inner);
+                quote!(Option<#ty>)
+            }
+        },
+        _ => unimplemented!("deserialize {:?}", field_type),
+    }
+}
+
+// Converts the deserialized representation of a value of the given type back
+// into the value.
+fn from_repr(field_type: &Type, val: TokenStream) -> TokenStream {
+    if is_plain(field_type) {
+        return val;
+    }
+    match field_type {
+        Type::Ext(_) => quote!(#val.0),
+        Type::Option(inner) => match &**inner {
+            Type::Token(_) | Type::Group(_) => quote!(present(#val)),
+            Type::Tuple(elements) => {
+                let mut vars = Vec::new();
+                let mut values = Vec::new();
+                for (i, element) in elements.iter().enumerate() {
+                    if is_token(element) {
+                        values.push(quote!(Default::default()));
+                        continue;
+                    }
+                    let var = format_ident!("v{}", i);
+                    values.push(from_repr(element, quote!(#var)));
+                    vars.push(var);
+                }
+                let pat = match unwrap_tuple(elements) {
+                    Some(_) => quote!(#(#vars)*),
+                    None => quote!((#(#vars,)*)),
+                };
+                quote!(#val.map(|#pat| (#(#values),*)))
+            }
+            inner => {
+                let value = from_repr(inner, quote!(v));
+                quote!(#val.map(|v| #value))
+            }
+        },
+        _ => unimplemented!("deserialize {:?}", field_type),
+    }
+}
+
+fn default_value(field_type: &Type) -> TokenStream {
+    match field_type {
+        Type::Ext(ty) if ty == "Span" => quote!(Span::call_site()),
+        _ => quote!(Default::default()),
+    }
+}
+
+fn full_only(defs: &Definitions, node: &Node, fields: &[Type]) -> Option<TokenStream> {
+    if node.ident == "Expr" {
+        if let Type::Syn(ty) = &fields[0] {
+            if !lookup::node(defs, ty).features.any.contains("derive") {
+                return Some(quote!(#[cfg(feature = "full")]));
+            }
+        }
+    }
+    None
+}
+
+fn expand_serialize(defs: &Definitions, node: &Node) -> TokenStream {
+    let type_name = &node.ident;
+    let ident = Ident::new(type_name, Span::call_site());
+
+    match &node.data {
+        Data::Enum(variants) => {
+            let arms = variants
+                .iter()
+                .enumerate()
+                .map(|(i, (variant_name, fields))| {
+                    let i = i as u32;
+                    let variant = Ident::new(variant_name, Span::call_site());
+                    let cfg = full_only(defs, node, fields);
+                    let mut pats = Vec::new();
+                    let mut reprs = Vec::new();
+                    for (i, field) in fields.iter().enumerate() {
+                        if skip(field) {
+                            pats.push(quote!(_));
+                            continue;
+                        }
+                        let var = format_ident!("v{}", i);
+                        reprs.push(match field {
+                            Type::Ext(_) => quote!(&Ser(#var)),
+                            _ => quote!(#var),
+                        });
+                        pats.push(quote!(#var));
+                    }
+                    let pat = if fields.is_empty() {
+                        quote!(#ident::#variant)
+                    } else {
+                        quote!(#ident::#variant(#(#pats),*))
+                    };
+                    if reprs.is_empty() {
+                        quote! {
+                            #cfg
+                            #pat => serializer.serialize_unit_variant(#type_name, #i, #variant_name),
+                        }
+                    } else {
+                        quote! {
+                            #cfg
+                            #pat => serializer.serialize_newtype_variant(#type_name, #i, #variant_name, #(#reprs)*),
+                        }
+                    }
+                });
+            let nonexhaustive = if node.exhaustive {
+                None
+            } else {
+                Some(quote!(_ => unreachable!()))
+            };
+            quote! {
+                match self {
+                    #(#arms)*
+                    #nonexhaustive
+                }
+            }
+        }
+        Data::Struct(fields) => {
+            let fields: Vec<_> = fields.iter().filter(|(_f, ty)| !skip(ty)).collect();
+            if fields.is_empty() {
+                return quote! {
+                    serializer.serialize_struct(#type_name, 0)?.end()
+                };
+            }
+            let len = fields.len();
+            let serialize_fields = fields.iter().map(|(f, ty)| {
+                let ident = Ident::new(f, Span::call_site());
+                let repr = match ty {
+                    Type::Ext(_) => quote!(&Ser(&self.#ident)),
+                    ty if is_plain(ty) => quote!(&self.#ident),
+                    ty => {
+                        let repr = to_repr(ty, quote!(self.#ident));
+                        quote!(&#repr)
+                    }
+                };
+                quote! {
+                    state.serialize_field(#f, #repr)?;
+                }
+            });
+            quote! {
+                let mut state = serializer.serialize_struct(#type_name, #len)?;
+                #(#serialize_fields)*
+                state.end()
+            }
+        }
+        Data::Private => unreachable!(),
+    }
+}
+
+fn expand_deserialize(defs: &Definitions, node: &Node) -> TokenStream {
+    let type_name = &node.ident;
+    let ident = Ident::new(type_name, Span::call_site());
+
+    match &node.data {
+        Data::Enum(variants) => {
+            let expecting = format!("enum {}", type_name);
+            let variant_names = variants.keys();
+            let arms = variants.iter().map(|(variant_name, fields)| {
+                let variant = Ident::new(variant_name, Span::call_site());
+                let cfg = full_only(defs, node, fields);
+                let kept = fields.iter().position(|field| !skip(field));
+                let body = match kept {
+                    None if fields.is_empty() => quote! {
+                        access.unit_variant()?;
+                        Ok(#ident::#variant)
+                    },
+                    None => {
+                        let values = fields.iter().map(default_value);
+                        quote! {
+                            access.unit_variant()?;
+                            Ok(#ident::#variant(#(#values),*))
+                        }
+                    }
+                    Some(kept) => {
+                        let ty = repr_type(&fields[kept]);
+                        let newtype = if ty.to_string() == "_" {
+                            quote!(access.newtype_variant()?)
+                        } else {
+                            quote!(access.newtype_variant::<#ty>()?)
+                        };
+                        let values = fields.iter().enumerate().map(|(i, field)| {
+                            if i == kept {
+                                from_repr(field, quote!(v))
+                            } else {
+                                default_value(field)
+                            }
+                        });
+                        quote! {
+                            let v = #newtype;
+                            Ok(#ident::#variant(#(#values),*))
+                        }
+                    }
+                };
+                quote! {
+                    #cfg
+                    #variant_name => {
+                        #body
+                    }
+                }
+            });
+            quote! {
+                const VARIANTS: &[&str] = &[#(#variant_names),*];
+
+                struct EnumVisitor;
+
+                impl<'de> Visitor<'de> for EnumVisitor {
+                    type Value = #ident;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                        formatter.write_str(#expecting)
+                    }
+
+                    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: EnumAccess<'de>,
+                    {
+                        let (name, access) = variant(data, VARIANTS)?;
+                        match name {
+                            #(#arms)*
+                            _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                        }
+                    }
+                }
+
+                deserializer.deserialize_enum(#type_name, VARIANTS, EnumVisitor)
+            }
+        }
+        Data::Struct(fields) => {
+            let expecting = format!("struct {}", type_name);
+            let kept: Vec<_> = fields.iter().filter(|(_f, ty)| !skip(ty)).collect();
+            let field_names = kept.iter().map(|(f, _ty)| f);
+            let vars: Vec<_> = kept
+                .iter()
+                .map(|(f, _ty)| Ident::new(f, Span::call_site()))
+                .collect();
+
+            let seq_elements = kept
+                .iter()
+                .zip(&vars)
+                .enumerate()
+                .map(|(i, ((_f, ty), var))| {
+                    let repr = repr_type(ty);
+                    let element = if repr.to_string() == "_" {
+                        quote!(element(&mut seq, #i, &self)?)
+                    } else {
+                        quote!(element::<#repr, _>(&mut seq, #i, &self)?)
+                    };
+                    let value = from_repr(ty, element);
+                    quote! {
+                        let #var = #value;
+                    }
+                });
+
+            let map_arms = kept.iter().zip(&vars).map(|((f, ty), var)| {
+                let repr = repr_type(ty);
+                let next = if repr.to_string() == "_" {
+                    quote!(map.next_value()?)
+                } else {
+                    quote!(map.next_value::<#repr>()?)
+                };
+                let value = from_repr(ty, next);
+                quote! {
+                    #f => #var = Some(#value),
+                }
+            });
+
+            let seq_values = fields.iter().map(|(f, ty)| {
+                let ident = Ident::new(f, Span::call_site());
+                if skip(ty) {
+                    let value = default_value(ty);
+                    quote!(#ident: #value)
+                } else {
+                    quote!(#ident)
+                }
+            });
+
+            let map_values = fields.iter().map(|(f, ty)| {
+                let ident = Ident::new(f, Span::call_site());
+                if skip(ty) {
+                    let value = default_value(ty);
+                    quote!(#ident: #value)
+                } else if has_default(ty) {
+                    quote!(#ident: #ident.unwrap_or_default())
+                } else {
+                    quote!(#ident: required(#ident, #f)?)
+                }
+            });
+
+            let (seq, read_map) = if kept.is_empty() {
+                let read_map = quote! {
+                    while field(&mut map, FIELDS)?.is_some() {
+                        map.next_value::<IgnoredAny>()?;
+                    }
+                };
+                (quote!(_seq), read_map)
+            } else {
+                let read_map = quote! {
+                    while let Some(key) = field(&mut map, FIELDS)? {
+                        match key {
+                            #(#map_arms)*
+                            _ => {
+                                map.next_value::<IgnoredAny>()?;
+                            }
+                        }
+                    }
+                };
+                (quote!(mut seq), read_map)
+            };
+
+            quote! {
+                const FIELDS: &[&str] = &[#(#field_names),*];
+
+                struct StructVisitor;
+
+                impl<'de> Visitor<'de> for StructVisitor {
+                    type Value = #ident;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                        formatter.write_str(#expecting)
+                    }
+
+                    fn visit_seq<A>(self, #seq: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: SeqAccess<'de>,
+                    {
+                        #(#seq_elements)*
+                        Ok(#ident {
+                            #(#seq_values,)*
+                        })
+                    }
+
+                    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: MapAccess<'de>,
+                    {
+                        #(let mut #vars = None;)*
+                        #read_map
+                        Ok(#ident {
+                            #(#map_values,)*
+                        })
+                    }
+                }
+
+                deserializer.deserialize_struct(#type_name, FIELDS, StructVisitor)
+            }
+        }
+        Data::Private => unreachable!(),
+    }
+}
+
+fn expand_impl(defs: &Definitions, node: &Node) -> TokenStream {
+    if node.data == Data::Private {
+        return TokenStream::new();
+    }
+
+    let ident = Ident::new(&node.ident, Span::call_site());
+    let cfg_features = cfg::features(&node.features);
+
+    let serialize = expand_serialize(defs, node);
+    let deserialize = expand_deserialize(defs, node);
+
+    quote! {
+        #cfg_features
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+        impl Serialize for #ident {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                #serialize
+            }
+        }
+
+        #cfg_features
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+        impl<'de> Deserialize<'de> for #ident {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                #deserialize
+            }
+        }
+    }
+}
+
+pub fn generate(defs: &Definitions) -> Result<()> {
+    let mut impls = TokenStream::new();
+    for node in &defs.types {
+        impls.extend(expand_impl(defs, node));
+    }
+
+    file::write(
+        SERDE_SRC,
+        quote! {
+            use crate::*;
+            use crate::gen::helper::serde::*;
+            use proc_macro2::Span;
+            use serde::de::{
+                self, Deserialize, Deserializer, EnumAccess, IgnoredAny, MapAccess, SeqAccess,
+                VariantAccess, Visitor,
+            };
+            use serde::ser::{Serialize, SerializeStruct, Serializer};
+            use std::fmt;
+            use std::result::Result;
+
+            #impls
+        },
+    )?;
+
+    Ok(())
+}
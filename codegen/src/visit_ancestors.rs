@@ -0,0 +1,435 @@
+use crate::operand::{Borrowed, Operand, Owned};
+use crate::{cfg, file, full, gen};
+use anyhow::Result;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::Index;
+use syn_codegen::{Data, Definitions, Features, Node, Type};
+
+const VISIT_ANCESTORS_SRC: &str = "../src/gen/visit_ancestors.rs";
+
+fn simple_visit(item: &str, name: &Operand) -> TokenStream {
+    let ident = gen::under_name(item);
+    let method = format_ident!("visit_{}", ident);
+    let name = name.ref_tokens();
+    quote! {
+        v.#method(#name, ancestors)
+    }
+}
+
+fn noop_visit(name: &Operand) -> TokenStream {
+    let name = name.tokens();
+    quote! {
+        skip!(#name)
+    }
+}
+
+fn visit(
+    ty: &Type,
+    features: &Features,
+    defs: &Definitions,
+    name: &Operand,
+) -> Option<TokenStream> {
+    match ty {
+        Type::Box(t) => {
+            let name = name.owned_tokens();
+            visit(t, features, defs, &Owned(quote!(*#name)))
+        }
+        Type::Vec(t) => {
+            let operand = Borrowed(quote!(it));
+            let val = visit(t, features, defs, &operand)?;
+            let name = name.tokens();
+            Some(quote! {
+                for (i, it) in #name.iter().enumerate() {
+                    ancestors.set_index(i);
+                    #val;
+                }
+            })
+        }
+        Type::Punctuated(p) => {
+            let operand = Borrowed(quote!(it));
+            let val = visit(&p.element, features, defs, &operand)?;
+            let name = name.ref_tokens();
+            Some(quote! {
+                for (i, el) in Punctuated::pairs(#name).enumerate() {
+                    let (it, p) = el.into_tuple();
+                    ancestors.set_index(i);
+                    #val;
+                    if let Some(p) = p {
+                        tokens_helper(v, &p.spans, ancestors);
+                    }
+                }
+            })
+        }
+        Type::Option(t) => {
+            let it = Borrowed(quote!(it));
+            let val = visit(t, features, defs, &it)?;
+            let name = name.owned_tokens();
+            Some(quote! {
+                if let Some(it) = &#name {
+                    #val;
+                }
+            })
+        }
+        Type::Tuple(t) => {
+            let mut code = TokenStream::new();
+            for (i, elem) in t.iter().enumerate() {
+                let name = name.tokens();
+                let i = Index::from(i);
+                let it = Owned(quote!((#name).#i));
+                let val = visit(elem, features, defs, &it).unwrap_or_else(|| noop_visit(&it));
+                code.extend(val);
+                code.extend(quote!(;));
+            }
+            Some(code)
+        }
+        Type::Token(t) => {
+            let name = name.tokens();
+            let repr = &defs.tokens[t];
+            let is_keyword = repr.chars().next().unwrap().is_alphabetic();
+            let spans = if is_keyword {
+                quote!(span)
+            } else {
+                quote!(spans)
+            };
+            Some(quote! {
+                tokens_helper(v, &#name.#spans, ancestors);
+            })
+        }
+        Type::Group(_) => {
+            let name = name.tokens();
+            Some(quote! {
+                tokens_helper(v, &#name.span, ancestors);
+            })
+        }
+        Type::Syn(t) => {
+            fn requires_full(features: &Features) -> bool {
+                features.any.contains("full") && features.any.len() == 1
+            }
+            let mut res = simple_visit(t, name);
+            let target = defs.types.iter().find(|ty| ty.ident == *t).unwrap();
+            if requires_full(&target.features) && !requires_full(features) {
+                res = quote!(full!(#res));
+            }
+            Some(res)
+        }
+        Type::Ext(t) if gen::TERMINAL_TYPES.contains(&&t[..]) => Some(simple_visit(t, name)),
+        Type::Ext(_) | Type::Std(_) => None,
+    }
+}
+
+fn node(traits: &mut TokenStream, impls: &mut TokenStream, s: &Node, defs: &Definitions) {
+    let under_name = gen::under_name(&s.ident);
+    let ty = Ident::new(&s.ident, Span::call_site());
+    let visit_fn = format_ident!("visit_{}", under_name);
+
+    let mut visit_impl = TokenStream::new();
+
+    match &s.data {
+        Data::Enum(variants) => {
+            let mut visit_variants = TokenStream::new();
+
+            for (variant, fields) in variants {
+                let variant_ident = Ident::new(variant, Span::call_site());
+
+                if fields.is_empty() {
+                    visit_variants.extend(quote! {
+                        #ty::#variant_ident => {}
+                    });
+                } else {
+                    let mut bind_visit_fields = TokenStream::new();
+                    let mut visit_fields = TokenStream::new();
+                    let mut reached = false;
+
+                    for (idx, ty) in fields.iter().enumerate() {
+                        let binding = format_ident!("_binding_{}", idx);
+
+                        bind_visit_fields.extend(quote! {
+                            #binding,
+                        });
+
+                        let borrowed_binding = Borrowed(quote!(#binding));
+
+                        let visit_field = visit(ty, &s.features, defs, &borrowed_binding);
+                        reached |= visit_field.is_some();
+                        visit_fields
+                            .extend(visit_field.unwrap_or_else(|| noop_visit(&borrowed_binding)));
+
+                        visit_fields.extend(quote!(;));
+                    }
+
+                    let set_field = if reached {
+                        Some(quote!(ancestors.set_field(#variant);))
+                    } else {
+                        None
+                    };
+
+                    visit_variants.extend(quote! {
+                        #ty::#variant_ident(#bind_visit_fields) => {
+                            #set_field
+                            #visit_fields
+                        }
+                    });
+                }
+            }
+
+            let nonexhaustive = if s.exhaustive {
+                None
+            } else {
+                Some(quote!(_ => unreachable!()))
+            };
+
+            visit_impl.extend(quote! {
+                match node {
+                    #visit_variants
+                    #nonexhaustive
+                }
+            });
+        }
+        Data::Struct(fields) => {
+            for (field, ty) in fields {
+                if let Type::Syn(ty) = ty {
+                    if ty == "Reserved" {
+                        continue;
+                    }
+                }
+
+                let id = Ident::new(&field, Span::call_site());
+                let ref_toks = Owned(quote!(node.#id));
+                match visit(&ty, &s.features, defs, &ref_toks) {
+                    Some(visit_field) => visit_impl.extend(quote! {
+                        ancestors.set_field(#field);
+                        #visit_field;
+                    }),
+                    None => {
+                        let noop = noop_visit(&ref_toks);
+                        visit_impl.extend(quote! {
+                            #noop;
+                        });
+                    }
+                }
+            }
+        }
+        Data::Private => {
+            if ty == "Ident" {
+                visit_impl.extend(quote! {
+                    v.visit_span(&node.span(), ancestors);
+                });
+            }
+        }
+    }
+
+    if let Data::Struct(_) | Data::Enum(_) = s.data {
+        visit_impl = quote! {
+            ancestors.push(Node::#ty(node));
+            #visit_impl
+            ancestors.pop();
+        };
+    }
+
+    let ast_lifetime = if s.ident == "Span" {
+        None
+    } else {
+        Some(quote!('ast))
+    };
+
+    traits.extend(quote! {
+        fn #visit_fn(&mut self, i: &#ast_lifetime #ty, ancestors: &mut Ancestors<'ast>) {
+            #visit_fn(self, i, ancestors);
+        }
+    });
+
+    impls.extend(quote! {
+        pub fn #visit_fn<'ast, V>(
+            v: &mut V,
+            node: &#ast_lifetime #ty,
+            ancestors: &mut Ancestors<'ast>,
+        )
+        where
+            V: VisitAncestors<'ast> + ?Sized,
+        {
+            #visit_impl
+        }
+    });
+}
+
+// The variants of the `Node` enum, one for each syntax tree type that has
+// fields or variants and so can be an ancestor of another node.
+fn node_variants(defs: &Definitions) -> TokenStream {
+    let mut types: Vec<&Node> = defs.types.iter().collect();
+    types.sort_by(|a, b| a.ident.cmp(&b.ident));
+
+    let mut variants = TokenStream::new();
+    for s in types {
+        if s.ident == "Reserved" || s.data == Data::Private {
+            continue;
+        }
+        let features = cfg::features(&s.features);
+        let ty = Ident::new(&s.ident, Span::call_site());
+        variants.extend(quote! {
+            #features
+            #ty(&'ast #ty),
+        });
+    }
+    variants
+}
+
+pub fn generate(defs: &Definitions) -> Result<()> {
+    let (traits, impls) = gen::traverse(defs, node);
+    let variants = node_variants(defs);
+    let full_macro = full::get_macro();
+    file::write(
+        VISIT_ANCESTORS_SRC,
+        quote! {
+            #![allow(unused_variables)]
+
+            use crate::*;
+            #[cfg(any(feature = "full", feature = "derive"))]
+            use crate::punctuated::Punctuated;
+            use proc_macro2::Span;
+            use std::slice;
+            #[cfg(any(feature = "full", feature = "derive"))]
+            use crate::gen::helper::visit_ancestors::*;
+
+            #full_macro
+
+            macro_rules! skip {
+                ($($tt:tt)*) => {};
+            }
+
+            /// A reference to a syntax tree node of any type that can have
+            /// other nodes inside of it.
+            ///
+            /// *This type is available only if Syn is built with the `"visit-ancestors"` feature.*
+            pub enum Node<'ast> {
+                #variants
+
+                #[doc(hidden)]
+                __TestExhaustive(&'ast crate::private),
+            }
+
+            impl<'ast> Copy for Node<'ast> {}
+
+            impl<'ast> Clone for Node<'ast> {
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+
+            /// A node between the root of the traversal and the node being
+            /// visited, along with the place in it where the traversal went
+            /// down to the next node.
+            ///
+            /// *This type is available only if Syn is built with the `"visit-ancestors"` feature.*
+            pub struct Ancestor<'ast> {
+                /// The ancestor node.
+                pub node: Node<'ast>,
+                /// The field of the ancestor that holds the next node down, or
+                /// the name of its variant if the ancestor is an enum.
+                pub field: &'static str,
+                /// The position of the next node down within the field, if the
+                /// field is a `Vec` or `Punctuated`.
+                pub index: Option<usize>,
+            }
+
+            impl<'ast> Copy for Ancestor<'ast> {}
+
+            impl<'ast> Clone for Ancestor<'ast> {
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+
+            /// The ancestors of the node being visited, from the root of the
+            /// traversal down to its parent.
+            ///
+            /// *This type is available only if Syn is built with the `"visit-ancestors"` feature.*
+            pub struct Ancestors<'ast> {
+                stack: Vec<Ancestor<'ast>>,
+            }
+
+            impl<'ast> Ancestors<'ast> {
+                /// Creates the empty list of ancestors with which to start a
+                /// traversal at its root.
+                pub fn new() -> Self {
+                    Ancestors { stack: Vec::new() }
+                }
+
+                /// The parent of the node being visited, or `None` if the node
+                /// is the root of the traversal.
+                pub fn parent(&self) -> Option<&Ancestor<'ast>> {
+                    self.stack.last()
+                }
+
+                /// Iterates over the ancestors from the root of the traversal
+                /// down to the parent of the node being visited.
+                pub fn iter(&self) -> slice::Iter<'_, Ancestor<'ast>> {
+                    self.stack.iter()
+                }
+
+                /// The number of ancestors, which is the depth of the node
+                /// being visited.
+                pub fn len(&self) -> usize {
+                    self.stack.len()
+                }
+
+                /// Whether the node being visited is the root of the traversal.
+                pub fn is_empty(&self) -> bool {
+                    self.stack.is_empty()
+                }
+
+                fn push(&mut self, node: Node<'ast>) {
+                    self.stack.push(Ancestor {
+                        node,
+                        field: "",
+                        index: None,
+                    });
+                }
+
+                fn pop(&mut self) {
+                    self.stack.pop();
+                }
+
+                fn set_field(&mut self, field: &'static str) {
+                    let last = self.stack.last_mut().unwrap();
+                    last.field = field;
+                    last.index = None;
+                }
+
+                #[cfg(any(feature = "full", feature = "derive"))]
+                fn set_index(&mut self, index: usize) {
+                    self.stack.last_mut().unwrap().index = Some(index);
+                }
+            }
+
+            impl<'ast> Default for Ancestors<'ast> {
+                fn default() -> Self {
+                    Ancestors::new()
+                }
+            }
+
+            impl<'ast> Clone for Ancestors<'ast> {
+                fn clone(&self) -> Self {
+                    Ancestors {
+                        stack: self.stack.clone(),
+                    }
+                }
+            }
+
+            /// Syntax tree traversal to walk a shared borrow of a syntax tree,
+            /// keeping track of the ancestors of each node.
+            ///
+            /// See the [module documentation] for details.
+            ///
+            /// [module documentation]: self
+            ///
+            /// *This trait is available only if Syn is built with the `"visit-ancestors"` feature.*
+            pub trait VisitAncestors<'ast> {
+                #traits
+            }
+
+            #impls
+        },
+    )?;
+    Ok(())
+}
@@ -163,36 +163,62 @@ impl Attribute {
     #[cfg(feature = "parsing")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
     pub fn parse_meta(&self) -> Result<Meta> {
-        fn clone_ident_segment(segment: &PathSegment) -> PathSegment {
-            PathSegment {
-                ident: segment.ident.clone(),
-                arguments: PathArguments::None,
-            }
-        }
-
-        let path = Path {
-            leading_colon: self
-                .path
-                .leading_colon
-                .as_ref()
-                .map(|colon| Token![::](colon.spans)),
-            segments: self
-                .path
-                .segments
-                .pairs()
-                .map(|pair| match pair {
-                    Pair::Punctuated(seg, punct) => {
-                        Pair::Punctuated(clone_ident_segment(seg), Token![::](punct.spans))
-                    }
-                    Pair::End(seg) => Pair::End(clone_ident_segment(seg)),
-                })
-                .collect(),
-        };
-
+        let path = clone_meta_path(&self.path);
         let parser = |input: ParseStream| parsing::parse_meta_after_path(path, input);
         parse::Parser::parse2(parser, self.tokens.clone())
     }
 
+    /// Parses the content of the attribute, consisting of the path and tokens,
+    /// as an [`AttrMeta`].
+    ///
+    /// Unlike [`parse_meta`][Attribute::parse_meta], this accepts every
+    /// attribute body that rustc accepts: a bare path like `#[test]`, a path
+    /// followed by arbitrary delimited tokens like `#[serde(default =
+    /// path::to::f)]`, or a path followed by `=` and an arbitrary expression
+    /// like `#[limit = 1 + 2]`.
+    ///
+    /// *This function is available only if Syn is built with the `"parsing"`
+    /// feature.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, AttrMeta, Attribute, Expr, Path, Token};
+    /// use syn::punctuated::Punctuated;
+    ///
+    /// let attr: Attribute = parse_quote! {
+    ///     #[builder(setter(into), default = Vec::new())]
+    /// };
+    ///
+    /// let list = match attr.parse_attr_meta()? {
+    ///     AttrMeta::List(list) => list,
+    ///     _ => unimplemented!(),
+    /// };
+    ///
+    /// let nested = list.parse_args_with(Punctuated::<AttrMeta, Token![,]>::parse_terminated)?;
+    /// for meta in nested {
+    ///     match meta {
+    ///         AttrMeta::NameValue(meta) if meta.path.is_ident("default") => {
+    ///             let default: Expr = meta.value;
+    ///             # let _ = default;
+    ///         }
+    ///         AttrMeta::List(meta) if meta.path.is_ident("setter") => {
+    ///             let options: Path = meta.parse_args()?;
+    ///             # let _ = options;
+    ///         }
+    ///         _ => unimplemented!(),
+    ///     }
+    /// }
+    /// # Ok::<(), syn::Error>(())
+    /// ```
+    #[cfg(feature = "parsing")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    pub fn parse_attr_meta(&self) -> Result<AttrMeta> {
+        let path = clone_meta_path(&self.path);
+        let parser = |input: ParseStream| parsing::parse_attr_meta_after_path(path, input);
+        parse::Parser::parse2(parser, self.tokens.clone())
+    }
+
     /// Parse the arguments to the attribute as a syntax tree.
     ///
     /// This is similar to `syn::parse2::<T>(attr.tokens)` except that:
@@ -228,6 +254,49 @@ impl Attribute {
         parser.parse2(self.tokens.clone())
     }
 
+    /// Parse the arguments to the attribute as a comma-separated list of
+    /// nested metas, calling `logic` once per entry.
+    ///
+    /// See the [`meta`] module for details and for a registry of expected
+    /// keys built on top of this.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, Attribute, LitStr};
+    ///
+    /// let attr: Attribute = parse_quote!(#[serde(rename = "id", skip)]);
+    ///
+    /// let mut rename: Option<LitStr> = None;
+    /// let mut skip = false;
+    /// attr.parse_nested_meta(|meta| {
+    ///     if meta.path.is_ident("rename") {
+    ///         rename = Some(meta.value()?.parse()?);
+    ///         Ok(())
+    ///     } else if meta.path.is_ident("skip") {
+    ///         skip = true;
+    ///         Ok(())
+    ///     } else {
+    ///         Err(meta.error("unsupported serde attribute"))
+    ///     }
+    /// })?;
+    ///
+    /// assert_eq!(rename.unwrap().value(), "id");
+    /// assert!(skip);
+    /// # Ok::<(), syn::Error>(())
+    /// ```
+    ///
+    /// *This function is available only if Syn is built with the `"parsing"`
+    /// feature.*
+    #[cfg(feature = "parsing")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    pub fn parse_nested_meta(
+        &self,
+        logic: impl FnMut(meta::ParseNestedMeta) -> Result<()>,
+    ) -> Result<()> {
+        self.parse_args_with(meta::parser(logic))
+    }
+
     /// Parses zero or more outer attributes from the stream.
     ///
     /// *This function is available only if Syn is built with the `"parsing"`
@@ -255,6 +324,33 @@ impl Attribute {
     }
 }
 
+#[cfg(feature = "parsing")]
+fn clone_meta_path(path: &Path) -> Path {
+    fn clone_ident_segment(segment: &PathSegment) -> PathSegment {
+        PathSegment {
+            ident: segment.ident.clone(),
+            arguments: PathArguments::None,
+        }
+    }
+
+    Path {
+        leading_colon: path
+            .leading_colon
+            .as_ref()
+            .map(|colon| Token![::](colon.spans)),
+        segments: path
+            .segments
+            .pairs()
+            .map(|pair| match pair {
+                Pair::Punctuated(seg, punct) => {
+                    Pair::Punctuated(clone_ident_segment(seg), Token![::](punct.spans))
+                }
+                Pair::End(seg) => Pair::End(clone_ident_segment(seg)),
+            })
+            .collect(),
+    }
+}
+
 #[cfg(feature = "parsing")]
 fn expected_parentheses(attr: &Attribute) -> String {
     let style = match attr.style {
@@ -424,6 +520,117 @@ ast_enum_of_structs! {
     }
 }
 
+ast_enum_of_structs! {
+    /// Content of an attribute in the most general form accepted by rustc.
+    ///
+    /// *This type is available only if Syn is built with the `"derive"` or `"full"`
+    /// feature.*
+    ///
+    /// This is the counterpart of [`Meta`] for attributes whose arguments are
+    /// not restricted to nested metas and literals. It is produced by
+    /// [`Attribute::parse_attr_meta`].
+    ///
+    /// ## Path
+    ///
+    /// A meta path is like the `test` in `#[test]`.
+    ///
+    /// ## List
+    ///
+    /// A meta list is like the `serde(default = path::to::f)` in
+    /// `#[serde(default = path::to::f)]`, with the tokens inside the
+    /// delimiters left unparsed.
+    ///
+    /// ## NameValue
+    ///
+    /// A name-value meta is like the `limit = 1 + 2` in `#[limit = 1 + 2]`.
+    ///
+    /// # Syntax tree enum
+    ///
+    /// This type is a [syntax tree enum].
+    ///
+    /// [syntax tree enum]: Expr#syntax-tree-enums
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    pub enum AttrMeta {
+        Path(Path),
+
+        /// A path followed by arbitrary tokens in delimiters, like
+        /// `builder(setter(into), default = Vec::new())`.
+        List(AttrMetaList),
+
+        /// A path followed by `=` and an expression, like `limit = 1 + 2`.
+        NameValue(AttrMetaNameValue),
+    }
+}
+
+ast_struct! {
+    /// A path followed by arbitrary tokens in delimiters, like
+    /// `builder(setter(into), default = Vec::new())`.
+    ///
+    /// *This type is available only if Syn is built with the `"derive"` or
+    /// `"full"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    pub struct AttrMetaList {
+        pub path: Path,
+        pub delimiter: MacroDelimiter,
+        pub tokens: TokenStream,
+    }
+}
+
+ast_struct! {
+    /// A path followed by `=` and an expression, like `limit = 1 + 2`.
+    ///
+    /// *This type is available only if Syn is built with the `"derive"` or
+    /// `"full"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+    pub struct AttrMetaNameValue {
+        pub path: Path,
+        pub eq_token: Token![=],
+        pub value: Expr,
+    }
+}
+
+impl AttrMeta {
+    /// Returns the path that begins this meta item.
+    ///
+    /// For example this would return the `test` in `#[test]`, the `serde` in
+    /// `#[serde(default)]`, and the `limit` in `#[limit = 1 + 2]`.
+    pub fn path(&self) -> &Path {
+        match self {
+            AttrMeta::Path(path) => path,
+            AttrMeta::List(meta) => &meta.path,
+            AttrMeta::NameValue(meta) => &meta.path,
+        }
+    }
+}
+
+impl AttrMetaList {
+    /// Parse the tokens within the delimiters of the meta list as a syntax
+    /// tree.
+    ///
+    /// This is equivalent to `syn::parse2::<T>(meta.tokens)` except that it
+    /// produces a more useful span when `tokens` is empty.
+    ///
+    /// *This function is available only if Syn is built with the `"parsing"`
+    /// feature.*
+    #[cfg(feature = "parsing")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    pub fn parse_args<T: Parse>(&self) -> Result<T> {
+        self.parse_args_with(T::parse)
+    }
+
+    /// Parse the tokens within the delimiters of the meta list using the
+    /// given parser.
+    ///
+    /// *This function is available only if Syn is built with the `"parsing"`
+    /// feature.*
+    #[cfg(feature = "parsing")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    pub fn parse_args_with<F: Parser>(&self, parser: F) -> Result<F::Output> {
+        let scope = mac::delimiter_span_close(&self.delimiter);
+        parse::parse_scoped(parser, scope, self.tokens.clone())
+    }
+}
+
 /// Conventional argument type associated with an invocation of an attribute
 /// macro.
 ///
@@ -532,7 +739,7 @@ pub mod parsing {
     }
 
     // Like Path::parse_mod_style but accepts keywords in the path.
-    fn parse_meta_path(input: ParseStream) -> Result<Path> {
+    pub fn parse_meta_path(input: ParseStream) -> Result<Path> {
         Ok(Path {
             leading_colon: input.parse()?,
             segments: {
@@ -595,6 +802,30 @@ pub mod parsing {
         }
     }
 
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    impl Parse for AttrMeta {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let path = input.call(parse_meta_path)?;
+            parse_attr_meta_after_path(path, input)
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    impl Parse for AttrMetaList {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let path = input.call(parse_meta_path)?;
+            parse_attr_meta_list_after_path(path, input)
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    impl Parse for AttrMetaNameValue {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let path = input.call(parse_meta_path)?;
+            parse_attr_meta_name_value_after_path(path, input)
+        }
+    }
+
     pub fn parse_meta_after_path(path: Path, input: ParseStream) -> Result<Meta> {
         if input.peek(token::Paren) {
             parse_meta_list_after_path(path, input).map(Meta::List)
@@ -621,6 +852,67 @@ pub mod parsing {
             lit: input.parse()?,
         })
     }
+
+    pub fn parse_attr_meta_after_path(path: Path, input: ParseStream) -> Result<AttrMeta> {
+        if input.peek(token::Paren) || input.peek(token::Bracket) || input.peek(token::Brace) {
+            parse_attr_meta_list_after_path(path, input).map(AttrMeta::List)
+        } else if input.peek(Token![=]) {
+            parse_attr_meta_name_value_after_path(path, input).map(AttrMeta::NameValue)
+        } else {
+            Ok(AttrMeta::Path(path))
+        }
+    }
+
+    fn parse_attr_meta_list_after_path(path: Path, input: ParseStream) -> Result<AttrMetaList> {
+        let (delimiter, tokens) = mac::parse_delimiter(input)?;
+        Ok(AttrMetaList {
+            path,
+            delimiter,
+            tokens,
+        })
+    }
+
+    fn parse_attr_meta_name_value_after_path(
+        path: Path,
+        input: ParseStream,
+    ) -> Result<AttrMetaNameValue> {
+        Ok(AttrMetaNameValue {
+            path,
+            eq_token: input.parse()?,
+            value: input.call(parse_attr_meta_value)?,
+        })
+    }
+
+    #[cfg(feature = "full")]
+    fn parse_attr_meta_value(input: ParseStream) -> Result<Expr> {
+        input.parse()
+    }
+
+    // Without the "full" feature only a subset of expressions can be parsed.
+    // Anything else up to the next comma is preserved as Expr::Verbatim so
+    // that every value accepted by rustc is still accepted here.
+    #[cfg(not(feature = "full"))]
+    fn parse_attr_meta_value(input: ParseStream) -> Result<Expr> {
+        use crate::parse::discouraged::Speculative;
+        use proc_macro2::TokenTree;
+
+        let ahead = input.fork();
+        if let Ok(expr) = ahead.parse::<Expr>() {
+            if ahead.is_empty() || ahead.peek(Token![,]) {
+                input.advance_to(&ahead);
+                return Ok(expr);
+            }
+        }
+
+        let begin = input.fork();
+        while !input.is_empty() && !input.peek(Token![,]) {
+            input.parse::<TokenTree>()?;
+        }
+        if begin.cursor() == input.cursor() {
+            return Err(input.error("expected expression"));
+        }
+        Ok(Expr::Verbatim(verbatim::between(begin, input)))
+    }
 }
 
 #[cfg(feature = "printing")]
@@ -661,4 +953,31 @@ mod printing {
             self.lit.to_tokens(tokens);
         }
     }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for AttrMetaList {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.path.to_tokens(tokens);
+            match &self.delimiter {
+                MacroDelimiter::Paren(paren) => {
+                    paren.surround(tokens, |tokens| self.tokens.to_tokens(tokens));
+                }
+                MacroDelimiter::Brace(brace) => {
+                    brace.surround(tokens, |tokens| self.tokens.to_tokens(tokens));
+                }
+                MacroDelimiter::Bracket(bracket) => {
+                    bracket.surround(tokens, |tokens| self.tokens.to_tokens(tokens));
+                }
+            }
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for AttrMetaNameValue {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.path.to_tokens(tokens);
+            self.eq_token.to_tokens(tokens);
+            self.value.to_tokens(tokens);
+        }
+    }
 }
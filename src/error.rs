@@ -1,14 +1,28 @@
 #[cfg(feature = "parsing")]
 use crate::buffer::Cursor;
+#[cfg(all(
+    any(feature = "full", feature = "derive"),
+    feature = "parsing",
+    feature = "span-locations"
+))]
+use crate::source_map::{Location, SourceMap};
 use crate::thread::ThreadBound;
+#[cfg(feature = "span-locations")]
+use proc_macro2::LineColumn;
 use proc_macro2::{
     Delimiter, Group, Ident, LexError, Literal, Punct, Spacing, Span, TokenStream, TokenTree,
 };
 #[cfg(feature = "printing")]
 use quote::ToTokens;
+use std::cell::{Cell, RefCell};
+use std::collections::hash_map::DefaultHasher;
+#[cfg(all(feature = "parsing", feature = "span-locations"))]
+use std::collections::VecDeque;
 use std::fmt::{self, Debug, Display};
+use std::hash::{Hash, Hasher};
 use std::iter::FromIterator;
 use std::slice;
+use std::thread;
 use std::vec;
 
 /// The result of a Syn parser.
@@ -83,15 +97,55 @@ pub struct Error {
     messages: Vec<ErrorMessage>,
 }
 
-struct ErrorMessage {
+pub(crate) struct ErrorMessage {
+    pub(crate) span: SpanRange,
+    pub(crate) message: String,
+    pub(crate) level: Level,
+    // Labels of secondary spans, and notes and help messages.
+    pub(crate) children: Vec<Child>,
+}
+
+pub(crate) enum Level {
+    Error,
+    Warning,
+}
+
+pub(crate) struct Child {
+    pub(crate) kind: ChildKind,
+    pub(crate) span: Option<SpanRange>,
+    pub(crate) message: String,
+}
+
+pub(crate) enum ChildKind {
+    Label,
+    Note,
+    Help,
+}
+
+pub(crate) struct SpanRange {
     // Span is implemented as an index into a thread-local interner to keep the
     // size small. It is not safe to access from a different thread. We want
     // errors to be Send and Sync to play nicely with the Failure crate, so pin
     // the span we're given to its original thread and assume it is
     // Span::call_site if accessed from any other thread.
-    start_span: ThreadBound<Span>,
-    end_span: ThreadBound<Span>,
-    message: String,
+    pub(crate) start: ThreadBound<Span>,
+    pub(crate) end: ThreadBound<Span>,
+    // The lines and columns of the spans, resolved up front so that they stay
+    // accurate on other threads. Only spans of code parsed from a string
+    // outside of a procedural macro have these.
+    #[cfg(feature = "span-locations")]
+    location: Option<(LineColumn, LineColumn)>,
+    // The string that the spans were lexed from, if it was lexed by Syn, so
+    // that the location is only shown against that same text.
+    #[cfg(all(feature = "parsing", feature = "span-locations"))]
+    source: Option<SourceId>,
+}
+
+// Identifies a string of code by its length and a hash of its text.
+#[cfg(all(feature = "parsing", feature = "span-locations"))]
+struct SourceId {
+    len: usize,
+    hash: u64,
 }
 
 #[cfg(test)]
@@ -131,13 +185,7 @@ impl Error {
     /// }
     /// ```
     pub fn new<T: Display>(span: Span, message: T) -> Self {
-        Error {
-            messages: vec![ErrorMessage {
-                start_span: ThreadBound::new(span),
-                end_span: ThreadBound::new(span),
-                message: message.to_string(),
-            }],
-        }
+        new2(span, span, message)
     }
 
     /// Creates an error with the specified message spanning the given syntax
@@ -158,13 +206,108 @@ impl Error {
         let mut iter = tokens.into_token_stream().into_iter();
         let start = iter.next().map_or_else(Span::call_site, |t| t.span());
         let end = iter.last().map_or(start, |t| t.span());
-        Error {
-            messages: vec![ErrorMessage {
-                start_span: ThreadBound::new(start),
-                end_span: ThreadBound::new(end),
-                message: message.to_string(),
-            }],
-        }
+        new2(start, end, message)
+    }
+
+    /// Creates a warning with the specified message, for reporting a problem
+    /// that does not prevent the macro from producing its output.
+    ///
+    /// Rust has no stable way for a procedural macro to emit a warning, so
+    /// [`to_compile_error`] renders a warning as the use of a deprecated
+    /// constant, whose deprecation note is the message. The result is a
+    /// function item with a unique name, which must be placed among the items
+    /// in the output of the macro:
+    ///
+    /// [`to_compile_error`]: Error::to_compile_error
+    ///
+    /// ```text
+    /// warning: use of deprecated constant `__syn_warning_9c6e2e0f1b3a4d57::warning`: `skip` has no effect here
+    ///  --> src/main.rs:3:3
+    ///   |
+    /// 3 | #[skip]
+    ///   |   ^^^^
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate proc_macro;
+    /// #
+    /// use proc_macro::TokenStream;
+    /// use quote::quote;
+    /// use syn::{parse_macro_input, DeriveInput, Error};
+    ///
+    /// # const _: &str = stringify! {
+    /// #[proc_macro_derive(MyTrait, attributes(skip))]
+    /// # };
+    /// pub fn derive_my_trait(input: TokenStream) -> TokenStream {
+    ///     let input = parse_macro_input!(input as DeriveInput);
+    ///     let mut expanded = quote! {
+    ///         /* ... */
+    ///     };
+    ///     for attr in &input.attrs {
+    ///         if attr.path.is_ident("skip") {
+    ///             let span = attr.path.segments[0].ident.span();
+    ///             let warning = Error::warning(span, "`skip` has no effect here")
+    ///                 .help("put `skip` on a field instead");
+    ///             expanded.extend(warning.to_compile_error());
+    ///         }
+    ///     }
+    ///     expanded.into()
+    /// }
+    /// ```
+    pub fn warning<T: Display>(span: Span, message: T) -> Self {
+        let mut warning = Error::new(span, message);
+        warning.messages[0].level = Level::Warning;
+        warning
+    }
+
+    /// Attaches a label to another span that is relevant to the last message
+    /// of the error, such as the location of a previous definition.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{Error, Ident};
+    ///
+    /// fn duplicate(first: &Ident, second: &Ident) -> Error {
+    ///     Error::new(second.span(), format!("duplicate field `{}`", second))
+    ///         .span_label(first.span(), "first defined here")
+    /// }
+    /// ```
+    pub fn span_label<T: Display>(self, span: Span, label: T) -> Self {
+        self.child(ChildKind::Label, Some(span), label)
+    }
+
+    /// Attaches a note to the last message of the error.
+    pub fn note<T: Display>(self, message: T) -> Self {
+        self.child(ChildKind::Note, None, message)
+    }
+
+    /// Attaches a note about another span to the last message of the error.
+    pub fn span_note<T: Display>(self, span: Span, message: T) -> Self {
+        self.child(ChildKind::Note, Some(span), message)
+    }
+
+    /// Attaches a help message to the last message of the error.
+    pub fn help<T: Display>(self, message: T) -> Self {
+        self.child(ChildKind::Help, None, message)
+    }
+
+    /// Attaches a help message about another span to the last message of the
+    /// error.
+    pub fn span_help<T: Display>(self, span: Span, message: T) -> Self {
+        self.child(ChildKind::Help, Some(span), message)
+    }
+
+    fn child<T: Display>(mut self, kind: ChildKind, span: Option<Span>, message: T) -> Self {
+        let child = Child {
+            kind,
+            span: span.map(|span| SpanRange::new(span, span)),
+            message: message.to_string(),
+        };
+        self.messages.last_mut().unwrap().children.push(child);
+        self
     }
 
     /// The source location of the error.
@@ -173,23 +316,83 @@ impl Error {
     /// if called from a different thread than the one on which the `Error` was
     /// originally created.
     pub fn span(&self) -> Span {
-        let start = match self.messages[0].start_span.get() {
+        let start = match self.messages[0].span.start.get() {
             Some(span) => *span,
             None => return Span::call_site(),
         };
-        let end = match self.messages[0].end_span.get() {
+        let end = match self.messages[0].span.end.get() {
             Some(span) => *span,
             None => return Span::call_site(),
         };
         start.join(end).unwrap_or(start)
     }
 
+    /// The line and column at which the error's span begins, as with
+    /// [`Span::start`] but from any thread.
+    ///
+    /// The location is resolved when the error is created, so unlike
+    /// [`Error::span`] it stays accurate when errors from parsing on several
+    /// threads are collected on another one. It is only known for spans of code
+    /// parsed from a string outside of a procedural macro. Otherwise the line
+    /// is 0.
+    ///
+    /// *This method is available only if Syn is built with the
+    /// `"span-locations"` feature.*
+    #[cfg(feature = "span-locations")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "span-locations")))]
+    pub fn start(&self) -> LineColumn {
+        match self.messages[0].span.location() {
+            Some((start, _end)) => start,
+            None => LineColumn { line: 0, column: 0 },
+        }
+    }
+
+    /// The line and column at which the error's span ends, as with
+    /// [`Span::end`] but from any thread.
+    ///
+    /// *This method is available only if Syn is built with the
+    /// `"span-locations"` feature.*
+    #[cfg(feature = "span-locations")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "span-locations")))]
+    pub fn end(&self) -> LineColumn {
+        match self.messages[0].span.location() {
+            Some((_start, end)) => end,
+            None => LineColumn { line: 0, column: 0 },
+        }
+    }
+
+    /// The location of the error within a file of a source map, from the
+    /// start of its first token to the end of its last.
+    ///
+    /// Returns `None` if the error's span is not from a file in the source
+    /// map, or if called from a different thread than the one on which the
+    /// `Error` was originally created.
+    ///
+    /// *This method is available only if Syn is built with the
+    /// `"span-locations"` feature.*
+    #[cfg(all(
+        any(feature = "full", feature = "derive"),
+        feature = "parsing",
+        feature = "span-locations"
+    ))]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "span-locations")))]
+    pub fn location<'a>(&self, source_map: &'a SourceMap) -> Option<Location<'a>> {
+        let start = *self.messages[0].span.start.get()?;
+        let end = *self.messages[0].span.end.get()?;
+        source_map.locate_between(start, end)
+    }
+
     /// Render the error as an invocation of [`compile_error!`].
     ///
     /// The [`parse_macro_input!`] macro provides a convenient way to invoke
     /// this method correctly in a procedural macro.
     ///
     /// [`compile_error!`]: std::compile_error!
+    ///
+    /// Notes and help messages without a span are appended to the message.
+    /// Labels, notes and help messages with a span of their own are rendered
+    /// as another `compile_error!` at that span. Warnings are rendered as
+    /// described in [`Error::warning`].
     pub fn to_compile_error(&self) -> TokenStream {
         self.messages
             .iter()
@@ -238,38 +441,335 @@ impl Error {
     pub fn combine(&mut self, another: Error) {
         self.messages.extend(another.messages);
     }
+
+    #[cfg(all(
+        any(feature = "full", feature = "derive"),
+        feature = "parsing",
+        feature = "span-locations"
+    ))]
+    pub(crate) fn messages(&self) -> &[ErrorMessage] {
+        &self.messages
+    }
 }
 
 impl ErrorMessage {
     fn to_compile_error(&self) -> TokenStream {
-        let start = self
-            .start_span
-            .get()
-            .cloned()
-            .unwrap_or_else(Span::call_site);
-        let end = self.end_span.get().cloned().unwrap_or_else(Span::call_site);
-
-        // compile_error!($message)
-        TokenStream::from_iter(vec![
-            TokenTree::Ident(Ident::new("compile_error", start)),
-            TokenTree::Punct({
-                let mut punct = Punct::new('!', Spacing::Alone);
-                punct.set_span(start);
-                punct
-            }),
-            TokenTree::Group({
-                let mut group = Group::new(Delimiter::Brace, {
-                    TokenStream::from_iter(vec![TokenTree::Literal({
-                        let mut string = Literal::string(&self.message);
-                        string.set_span(end);
-                        string
-                    })])
-                });
-                group.set_span(end);
-                group
-            }),
-        ])
+        let (start, end) = self.span.get();
+
+        let mut message = self.message.clone();
+        for child in &self.children {
+            if child.span.is_none() {
+                message.push_str("\n= ");
+                message.push_str(child.kind.prefix());
+                message.push_str(&child.message);
+            }
+        }
+
+        let mut tokens = self.level.to_tokens(start, end, &message);
+        for child in &self.children {
+            if let Some(span) = &child.span {
+                let (start, end) = span.get();
+                let message = format!("{}{}", child.kind.prefix(), child.message);
+                tokens.extend(self.level.to_tokens(start, end, &message));
+            }
+        }
+        tokens
+    }
+}
+
+impl Level {
+    fn to_tokens(&self, start: Span, end: Span, message: &str) -> TokenStream {
+        match self {
+            // compile_error!($message)
+            Level::Error => TokenStream::from_iter(vec![
+                TokenTree::Ident(Ident::new("compile_error", start)),
+                punct('!', start),
+                TokenTree::Group({
+                    let mut group = Group::new(Delimiter::Brace, {
+                        TokenStream::from_iter(vec![TokenTree::Literal({
+                            let mut string = Literal::string(message);
+                            string.set_span(end);
+                            string
+                        })])
+                    });
+                    group.set_span(end);
+                    group
+                }),
+            ]),
+            // #[allow(dead_code)]
+            // fn $unique() {
+            //     #[deprecated(note = $message)]
+            //     #[allow(non_upper_case_globals)]
+            //     const warning: () = ();
+            //     let _ = warning;
+            // }
+            Level::Warning => {
+                let ident = |name: &str| TokenTree::Ident(Ident::new(name, start));
+                let unit = || group(Delimiter::Parenthesis, Vec::new(), start);
+                let body = vec![
+                    punct('#', start),
+                    group(
+                        Delimiter::Bracket,
+                        vec![
+                            ident("deprecated"),
+                            group(
+                                Delimiter::Parenthesis,
+                                vec![
+                                    ident("note"),
+                                    punct('=', start),
+                                    TokenTree::Literal({
+                                        let mut string = Literal::string(message);
+                                        string.set_span(end);
+                                        string
+                                    }),
+                                ],
+                                start,
+                            ),
+                        ],
+                        start,
+                    ),
+                    punct('#', start),
+                    group(
+                        Delimiter::Bracket,
+                        vec![
+                            ident("allow"),
+                            group(
+                                Delimiter::Parenthesis,
+                                vec![ident("non_upper_case_globals")],
+                                start,
+                            ),
+                        ],
+                        start,
+                    ),
+                    ident("const"),
+                    ident("warning"),
+                    punct(':', start),
+                    unit(),
+                    punct('=', start),
+                    unit(),
+                    punct(';', start),
+                    ident("let"),
+                    ident("_"),
+                    punct('=', start),
+                    ident("warning"),
+                    punct(';', start),
+                ];
+                TokenStream::from_iter(vec![
+                    punct('#', start),
+                    group(
+                        Delimiter::Bracket,
+                        vec![
+                            ident("allow"),
+                            group(Delimiter::Parenthesis, vec![ident("dead_code")], start),
+                        ],
+                        start,
+                    ),
+                    ident("fn"),
+                    ident(&unique_name(message, start)),
+                    unit(),
+                    group(Delimiter::Brace, body, start),
+                ])
+            }
+        }
+    }
+}
+
+// A name for the function that holds a warning, which must not collide with
+// any other warning the same macro emits into the module. Names from other
+// macros are kept apart by hygiene. The `_` constant would serve the purpose
+// but needs Rust 1.37.
+fn unique_name(message: &str, span: Span) -> String {
+    thread_local! {
+        static COUNT: Cell<usize> = Cell::new(0);
+    }
+    let count = COUNT.with(|count| {
+        let next = count.get();
+        count.set(next + 1);
+        next
+    });
+    let mut hasher = DefaultHasher::new();
+    message.hash(&mut hasher);
+    count.hash(&mut hasher);
+    format!("{:?}", span).hash(&mut hasher);
+    format!("__syn_warning_{:016x}", hasher.finish())
+}
+
+impl ChildKind {
+    fn prefix(&self) -> &'static str {
+        match self {
+            ChildKind::Label => "",
+            ChildKind::Note => "note: ",
+            ChildKind::Help => "help: ",
+        }
+    }
+}
+
+impl SpanRange {
+    fn new(start: Span, end: Span) -> Self {
+        #[cfg(feature = "span-locations")]
+        let location = resolve(start, end);
+        // Spans without a location, such as the call site, are not from any
+        // lexed string, so there is no need to search for one.
+        #[cfg(all(feature = "parsing", feature = "span-locations"))]
+        let source = location.and_then(|_| lexed_from(start));
+        SpanRange {
+            start: ThreadBound::new(start),
+            end: ThreadBound::new(end),
+            #[cfg(feature = "span-locations")]
+            location,
+            #[cfg(all(feature = "parsing", feature = "span-locations"))]
+            source,
+        }
+    }
+
+    // The spans, or the call site if accessed from a different thread than
+    // the one on which they were created.
+    pub(crate) fn get(&self) -> (Span, Span) {
+        let start = self.start.get().cloned().unwrap_or_else(Span::call_site);
+        let end = self.end.get().cloned().unwrap_or_else(Span::call_site);
+        (start, end)
+    }
+
+    // The line and column of the start of the first span and of the end of the
+    // last, from any thread.
+    #[cfg(feature = "span-locations")]
+    pub(crate) fn location(&self) -> Option<(LineColumn, LineColumn)> {
+        self.location
+    }
+
+    // Whether the spans were lexed by Syn from the end of `text`, which may
+    // begin with a byte order mark or shebang that is not part of the tokens.
+    #[cfg(all(feature = "parsing", feature = "span-locations"))]
+    pub(crate) fn is_from(&self, text: &str) -> bool {
+        match self.source {
+            Some(source) => {
+                let start = match text.len().checked_sub(source.len) {
+                    Some(start) => start,
+                    None => return false,
+                };
+                match text.get(start..) {
+                    Some(lexed) => SourceId::new(lexed).hash == source.hash,
+                    None => false,
+                }
+            }
+            None => false,
+        }
+    }
+}
+
+impl Clone for SpanRange {
+    fn clone(&self) -> Self {
+        let (start, end) = self.get();
+        SpanRange {
+            start: ThreadBound::new(start),
+            end: ThreadBound::new(end),
+            #[cfg(feature = "span-locations")]
+            location: self.location,
+            #[cfg(all(feature = "parsing", feature = "span-locations"))]
+            source: self.source,
+        }
+    }
+}
+
+#[cfg(all(feature = "parsing", feature = "span-locations"))]
+impl Copy for SourceId {}
+
+#[cfg(all(feature = "parsing", feature = "span-locations"))]
+impl Clone for SourceId {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[cfg(all(feature = "parsing", feature = "span-locations"))]
+impl SourceId {
+    fn new(text: &str) -> Self {
+        let mut hasher = DefaultHasher::new();
+        text.hash(&mut hasher);
+        SourceId {
+            len: text.len(),
+            hash: hasher.finish(),
+        }
+    }
+}
+
+#[cfg(all(feature = "parsing", feature = "span-locations"))]
+thread_local! {
+    // One span from each of the strings most recently lexed on this thread,
+    // with the identity of that string. Spans of the same string can be joined
+    // with each other but not with spans of any other string.
+    static LEXED: RefCell<VecDeque<(Span, SourceId)>> = RefCell::new(VecDeque::new());
+}
+
+// How many lexed strings to remember. Errors are nearly always created soon
+// after the string they point into is parsed, and every error that has a
+// location searches this many strings in the worst case.
+#[cfg(all(feature = "parsing", feature = "span-locations"))]
+const LEXED_LIMIT: usize = 64;
+
+// Lexes a string of Rust code. With span locations, also remembers which
+// string the spans of the tokens belong to, so that errors at those spans
+// are only rendered against the same text.
+#[cfg(feature = "parsing")]
+pub(crate) fn lex(text: &str) -> std::result::Result<TokenStream, LexError> {
+    let result = text.parse::<TokenStream>();
+    #[cfg(all(feature = "parsing", feature = "span-locations"))]
+    {
+        let span = match &result {
+            Ok(tokens) => tokens.clone().into_iter().next().map(|token| token.span()),
+            Err(err) => Some(err.span()),
+        };
+        if let Some(span) = span {
+            LEXED.with(|lexed| {
+                let mut lexed = lexed.borrow_mut();
+                if lexed.len() == LEXED_LIMIT {
+                    lexed.pop_front();
+                }
+                lexed.push_back((span, SourceId::new(text)));
+            });
+        }
+    }
+    result
+}
+
+#[cfg(all(feature = "parsing", feature = "span-locations"))]
+fn lexed_from(span: Span) -> Option<SourceId> {
+    LEXED.with(|lexed| {
+        let lexed = lexed.borrow();
+        lexed
+            .iter()
+            .rev()
+            .find(|(other, _)| other.join(span).is_some())
+            .map(|(_, source)| *source)
+    })
+}
+
+#[cfg(feature = "span-locations")]
+fn resolve(start: Span, end: Span) -> Option<(LineColumn, LineColumn)> {
+    // Spans that are not from any parsed string belong to the same file as the
+    // call site, which has no source text. Spans of the compiler have line 0.
+    if start.join(Span::call_site()).is_some() {
+        return None;
     }
+    let start = start.start();
+    let end = end.end();
+    if start.line == 0 {
+        None
+    } else {
+        Some((start, end))
+    }
+}
+
+fn punct(ch: char, span: Span) -> TokenTree {
+    let mut punct = Punct::new(ch, Spacing::Alone);
+    punct.set_span(span);
+    TokenTree::Punct(punct)
+}
+
+fn group(delimiter: Delimiter, tokens: Vec<TokenTree>, span: Span) -> TokenTree {
+    let mut group = Group::new(delimiter, TokenStream::from_iter(tokens));
+    group.set_span(span);
+    TokenTree::Group(group)
 }
 
 #[cfg(feature = "parsing")]
@@ -282,13 +782,13 @@ pub fn new_at<T: Display>(scope: Span, cursor: Cursor, message: T) -> Error {
     }
 }
 
-#[cfg(all(feature = "parsing", any(feature = "full", feature = "derive")))]
 pub fn new2<T: Display>(start: Span, end: Span, message: T) -> Error {
     Error {
         messages: vec![ErrorMessage {
-            start_span: ThreadBound::new(start),
-            end_span: ThreadBound::new(end),
+            span: SpanRange::new(start, end),
             message: message.to_string(),
+            level: Level::Error,
+            children: Vec::new(),
         }],
     }
 }
@@ -331,20 +831,41 @@ impl Clone for Error {
 
 impl Clone for ErrorMessage {
     fn clone(&self) -> Self {
-        let start = self
-            .start_span
-            .get()
-            .cloned()
-            .unwrap_or_else(Span::call_site);
-        let end = self.end_span.get().cloned().unwrap_or_else(Span::call_site);
         ErrorMessage {
-            start_span: ThreadBound::new(start),
-            end_span: ThreadBound::new(end),
+            span: self.span.clone(),
+            message: self.message.clone(),
+            level: self.level,
+            children: self.children.clone(),
+        }
+    }
+}
+
+impl Copy for Level {}
+
+impl Clone for Level {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Clone for Child {
+    fn clone(&self) -> Self {
+        Child {
+            kind: self.kind,
+            span: self.span.clone(),
             message: self.message.clone(),
         }
     }
 }
 
+impl Copy for ChildKind {}
+
+impl Clone for ChildKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
 impl std::error::Error for Error {}
 
 impl From<LexError> for Error {
@@ -410,3 +931,145 @@ impl Extend<Error> for Error {
         }
     }
 }
+
+/// Collects errors from all over a procedural macro so that they can be
+/// reported together at the end.
+///
+/// Bailing out on the first error is often unhelpful in a derive macro, since
+/// the user then has to fix problems one recompile at a time. Instead every
+/// problem found (every bad field, every bad attribute) can be pushed into an
+/// `ErrorCollector` while expansion carries on, and the macro fails with all
+/// of them at once.
+///
+/// The collector must be consumed by either [`check`] or [`finish`] before it
+/// goes out of scope. Dropping it unchecked panics in debug builds, since the
+/// errors inside of it would otherwise be silently lost.
+///
+/// [`check`]: ErrorCollector::check
+/// [`finish`]: ErrorCollector::finish
+///
+/// # Example
+///
+/// ```
+/// # extern crate proc_macro;
+/// #
+/// use proc_macro::TokenStream;
+/// use quote::quote;
+/// use syn::{parse_macro_input, Data, DeriveInput, ErrorCollector, Fields};
+///
+/// # const IGNORE: &str = stringify! {
+/// #[proc_macro_derive(MyDerive)]
+/// # };
+/// pub fn my_derive(input: TokenStream) -> TokenStream {
+///     let input = parse_macro_input!(input as DeriveInput);
+///     let errors = ErrorCollector::new();
+///
+///     if let Data::Struct(data) = &input.data {
+///         if let Fields::Unnamed(fields) = &data.fields {
+///             for field in &fields.unnamed {
+///                 errors.push_spanned(field, "tuple struct fields are not supported");
+///             }
+///         }
+///     } else {
+///         errors.push_spanned(&input.ident, "only structs are supported");
+///     }
+///
+///     // Emitted even if errors were found, alongside the compile_error!
+///     // invocations, to avoid knock-on errors about the missing impl.
+///     let ident = &input.ident;
+///     let expanded = quote! {
+///         impl MyDerive for #ident {}
+///     };
+///
+///     errors.finish(expanded).into()
+/// }
+/// ```
+pub struct ErrorCollector {
+    messages: RefCell<Option<Vec<ErrorMessage>>>,
+}
+
+impl ErrorCollector {
+    /// Creates a collector that does not hold any errors yet.
+    pub fn new() -> Self {
+        ErrorCollector {
+            messages: RefCell::new(Some(Vec::new())),
+        }
+    }
+
+    /// Adds an error to the collection.
+    pub fn push(&self, error: Error) {
+        self.messages
+            .borrow_mut()
+            .as_mut()
+            .unwrap()
+            .extend(error.messages);
+    }
+
+    /// Adds an error with the specified message spanning the given syntax tree
+    /// node, as in [`Error::new_spanned`].
+    #[cfg(feature = "printing")]
+    pub fn push_spanned<T: ToTokens, U: Display>(&self, tokens: T, message: U) {
+        self.push(Error::new_spanned(tokens, message));
+    }
+
+    /// Unwraps a result, adding its error to the collection if it failed.
+    ///
+    /// This works with any Syn parse function, for example
+    /// `errors.handle(attr.parse_args::<LitStr>())`.
+    pub fn handle<T>(&self, result: Result<T>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.push(error);
+                None
+            }
+        }
+    }
+
+    /// Returns true if no errors have been collected so far.
+    pub fn is_empty(&self) -> bool {
+        self.messages.borrow().as_ref().unwrap().is_empty()
+    }
+
+    /// Consumes the collector, returning all of the collected errors combined
+    /// into a single `Error`, if any.
+    pub fn check(self) -> Result<()> {
+        let messages = self.messages.borrow_mut().take().unwrap();
+        if messages.is_empty() {
+            Ok(())
+        } else {
+            Err(Error { messages })
+        }
+    }
+
+    /// Consumes the collector, returning `output` followed by a
+    /// [`compile_error!`] invocation for each of the collected errors.
+    ///
+    /// This allows a macro to emit partial output alongside its errors.
+    ///
+    /// [`compile_error!`]: std::compile_error!
+    pub fn finish(self, output: TokenStream) -> TokenStream {
+        match self.check() {
+            Ok(()) => output,
+            Err(error) => {
+                let mut output = output;
+                output.extend(error.into_compile_error());
+                output
+            }
+        }
+    }
+}
+
+impl Default for ErrorCollector {
+    fn default() -> Self {
+        ErrorCollector::new()
+    }
+}
+
+impl Drop for ErrorCollector {
+    fn drop(&mut self) {
+        if cfg!(debug_assertions) && !thread::panicking() && self.messages.borrow().is_some() {
+            panic!("ErrorCollector dropped without being checked");
+        }
+    }
+}
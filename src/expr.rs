@@ -125,6 +125,9 @@ ast_enum_of_structs! {
         /// A closure expression: `|a, b| a + b`.
         Closure(ExprClosure),
 
+        /// A const block: `const { ... }`.
+        Const(ExprConst),
+
         /// A `continue`, with an optional label.
         Continue(ExprContinue),
 
@@ -182,6 +185,9 @@ ast_enum_of_structs! {
         /// A range expression: `1..2`, `1..`, `..2`, `1..=2`, `..=2`.
         Range(ExprRange),
 
+        /// A raw borrow expression: `&raw const place` or `&raw mut place`.
+        RawAddr(ExprRawAddr),
+
         /// A referencing operation: `&a` or `&mut a`.
         Reference(ExprReference),
 
@@ -414,6 +420,18 @@ ast_struct! {
     }
 }
 
+ast_struct! {
+    /// A const block: `const { ... }`.
+    ///
+    /// *This type is available only if Syn is built with the `"full"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    pub struct ExprConst #full {
+        pub attrs: Vec<Attribute>,
+        pub const_token: Token![const],
+        pub block: Block,
+    }
+}
+
 ast_struct! {
     /// A `continue`, with an optional label.
     ///
@@ -625,6 +643,20 @@ ast_struct! {
     }
 }
 
+ast_struct! {
+    /// A raw borrow expression: `&raw const place` or `&raw mut place`.
+    ///
+    /// *This type is available only if Syn is built with the `"full"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    pub struct ExprRawAddr #full {
+        pub attrs: Vec<Attribute>,
+        pub and_token: Token![&],
+        pub raw: Token![raw],
+        pub mutability: PointerMutability,
+        pub expr: Box<Expr>,
+    }
+}
+
 ast_struct! {
     /// A referencing operation: `&a` or `&mut a`.
     ///
@@ -805,12 +837,14 @@ impl Expr {
             | Expr::Match(ExprMatch { attrs, .. })
             | Expr::Closure(ExprClosure { attrs, .. })
             | Expr::Unsafe(ExprUnsafe { attrs, .. })
+            | Expr::Const(ExprConst { attrs, .. })
             | Expr::Block(ExprBlock { attrs, .. })
             | Expr::Assign(ExprAssign { attrs, .. })
             | Expr::AssignOp(ExprAssignOp { attrs, .. })
             | Expr::Field(ExprField { attrs, .. })
             | Expr::Index(ExprIndex { attrs, .. })
             | Expr::Range(ExprRange { attrs, .. })
+            | Expr::RawAddr(ExprRawAddr { attrs, .. })
             | Expr::Path(ExprPath { attrs, .. })
             | Expr::Reference(ExprReference { attrs, .. })
             | Expr::Break(ExprBreak { attrs, .. })
@@ -1066,12 +1100,26 @@ ast_enum! {
     }
 }
 
+#[cfg(feature = "full")]
+ast_enum! {
+    /// Mutability of a raw pointer (`*const T`, `*mut T`), in which non-mutable
+    /// isn't the implicit default.
+    ///
+    /// *This type is available only if Syn is built with the `"full"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    pub enum PointerMutability {
+        Const(Token![const]),
+        Mut(Token![mut]),
+    }
+}
+
 #[cfg(any(feature = "parsing", feature = "printing"))]
 #[cfg(feature = "full")]
 pub(crate) fn requires_terminator(expr: &Expr) -> bool {
     // see https://github.com/rust-lang/rust/blob/2679c38fc/src/librustc_ast/util/classify.rs#L7-L25
     match *expr {
         Expr::Unsafe(..)
+        | Expr::Const(..)
         | Expr::Block(..)
         | Expr::If(..)
         | Expr::Match(..)
@@ -1084,16 +1132,72 @@ pub(crate) fn requires_terminator(expr: &Expr) -> bool {
     }
 }
 
+/// Configurable parser for expressions, for use in macros whose input places
+/// an expression in a position where `Expr::parse` would consume too much.
+///
+/// *This type is available only if Syn is built with the `"parsing"` feature
+/// and either the `"derive"` or `"full"` feature.*
+///
+/// # Example
+///
+/// ```
+/// use syn::parse::{Parse, ParseStream};
+/// use syn::{braced, token, Arm, Expr, ExprParser, Result, Token};
+///
+/// // my_match!(x { Some(y) => y, None => 0 })
+/// struct MyMatch {
+///     scrutinee: Expr,
+///     brace_token: token::Brace,
+///     arms: Vec<Arm>,
+/// }
+///
+/// impl Parse for MyMatch {
+///     fn parse(input: ParseStream) -> Result<Self> {
+///         // Without this, `x { ... }` would be parsed as a struct literal.
+///         let scrutinee = ExprParser::new().without_eager_brace().parse_stream(input)?;
+///         let content;
+///         let brace_token = braced!(content in input);
+///         let mut arms = Vec::new();
+///         while !content.is_empty() {
+///             arms.push(content.parse()?);
+///         }
+///         Ok(MyMatch {
+///             scrutinee,
+///             brace_token,
+///             arms,
+///         })
+///     }
+/// }
+/// ```
+///
+/// `ExprParser` also implements [`Parser`], so it can be used directly on a
+/// token stream.
+///
+/// [`Parser`]: crate::parse::Parser
+///
+/// ```
+/// use syn::parse::Parser;
+/// use syn::{ExprParser, Precedence};
+///
+/// // Stops in front of the `==`, which binds more loosely than `+`.
+/// let parser = ExprParser::new().min_precedence(Precedence::Arithmetic);
+/// let err = parser.parse_str("a + b * c == d").unwrap_err();
+/// assert_eq!(err.to_string(), "unexpected token");
+/// ```
+#[cfg(feature = "parsing")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+pub struct ExprParser {
+    allow_struct: bool,
+    min_precedence: Precedence,
+}
+
 #[cfg(feature = "parsing")]
 pub(crate) mod parsing {
     use super::*;
-    use crate::parse::{Parse, ParseStream, Result};
+    use crate::parse::{Parse, ParseStream, Parser, Result};
     use crate::path;
     #[cfg(feature = "full")]
     use proc_macro2::TokenTree;
-    use std::cmp::Ordering;
-
-    crate::custom_keyword!(raw);
 
     // When we're parsing expressions which occur before blocks, like in an if
     // statement's condition, we cannot parse a struct literal.
@@ -1102,53 +1206,6 @@ pub(crate) mod parsing {
     // https://github.com/rust-lang/rfcs/pull/92
     pub struct AllowStruct(bool);
 
-    enum Precedence {
-        Any,
-        Assign,
-        Range,
-        Or,
-        And,
-        Compare,
-        BitOr,
-        BitXor,
-        BitAnd,
-        Shift,
-        Arithmetic,
-        Term,
-        Cast,
-    }
-
-    impl Precedence {
-        fn of(op: &BinOp) -> Self {
-            match *op {
-                BinOp::Add(_) | BinOp::Sub(_) => Precedence::Arithmetic,
-                BinOp::Mul(_) | BinOp::Div(_) | BinOp::Rem(_) => Precedence::Term,
-                BinOp::And(_) => Precedence::And,
-                BinOp::Or(_) => Precedence::Or,
-                BinOp::BitXor(_) => Precedence::BitXor,
-                BinOp::BitAnd(_) => Precedence::BitAnd,
-                BinOp::BitOr(_) => Precedence::BitOr,
-                BinOp::Shl(_) | BinOp::Shr(_) => Precedence::Shift,
-                BinOp::Eq(_)
-                | BinOp::Lt(_)
-                | BinOp::Le(_)
-                | BinOp::Ne(_)
-                | BinOp::Ge(_)
-                | BinOp::Gt(_) => Precedence::Compare,
-                BinOp::AddEq(_)
-                | BinOp::SubEq(_)
-                | BinOp::MulEq(_)
-                | BinOp::DivEq(_)
-                | BinOp::RemEq(_)
-                | BinOp::BitXorEq(_)
-                | BinOp::BitAndEq(_)
-                | BinOp::BitOrEq(_)
-                | BinOp::ShlEq(_)
-                | BinOp::ShrEq(_) => Precedence::Assign,
-            }
-        }
-    }
-
     #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
     impl Parse for Expr {
         fn parse(input: ParseStream) -> Result<Self> {
@@ -1244,33 +1301,76 @@ pub(crate) mod parsing {
         }
     }
 
-    impl Copy for AllowStruct {}
+    impl ExprParser {
+        /// Creates a parser that behaves like `Expr::parse`.
+        pub fn new() -> Self {
+            ExprParser {
+                allow_struct: true,
+                min_precedence: Precedence::Any,
+            }
+        }
 
-    impl Clone for AllowStruct {
-        fn clone(&self) -> Self {
-            *self
+        /// Do not take a trailing brace as the body of a struct literal, as in
+        /// the condition of an `if`, `while` or `match`. Refer to
+        /// [`Expr::parse_without_eager_brace`] for the details.
+        pub fn without_eager_brace(mut self) -> Self {
+            self.allow_struct = false;
+            self
+        }
+
+        /// Stop in front of the first binary operator, cast, range or
+        /// assignment that binds more loosely than `precedence`, leaving it
+        /// unparsed in the input.
+        pub fn min_precedence(mut self, precedence: Precedence) -> Self {
+            self.min_precedence = precedence;
+            self
+        }
+
+        /// Parses an expression from the front of `input`.
+        pub fn parse_stream(self, input: ParseStream) -> Result<Expr> {
+            let allow_struct = AllowStruct(self.allow_struct);
+            let lhs = unary_expr(input, allow_struct)?;
+            parse_expr(input, lhs, allow_struct, self.min_precedence)
+        }
+    }
+
+    impl Default for ExprParser {
+        fn default() -> Self {
+            ExprParser::new()
         }
     }
 
-    impl Copy for Precedence {}
+    impl Copy for ExprParser {}
 
-    impl Clone for Precedence {
+    impl Clone for ExprParser {
         fn clone(&self) -> Self {
             *self
         }
     }
 
-    impl PartialEq for Precedence {
-        fn eq(&self, other: &Self) -> bool {
-            *self as u8 == *other as u8
+    impl Parser for ExprParser {
+        type Output = Expr;
+
+        fn parse2(self, tokens: TokenStream) -> Result<Expr> {
+            (|input: ParseStream| self.parse_stream(input)).parse2(tokens)
+        }
+
+        #[doc(hidden)]
+        fn __parse_scoped(self, scope: Span, tokens: TokenStream) -> Result<Expr> {
+            (|input: ParseStream| self.parse_stream(input)).__parse_scoped(scope, tokens)
+        }
+
+        #[doc(hidden)]
+        fn __parse_stream(self, input: ParseStream) -> Result<Expr> {
+            self.parse_stream(input)
         }
     }
 
-    impl PartialOrd for Precedence {
-        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-            let this = *self as u8;
-            let other = *other as u8;
-            Some(this.cmp(&other))
+    impl Copy for AllowStruct {}
+
+    impl Clone for AllowStruct {
+        fn clone(&self) -> Self {
+            *self
         }
     }
 
@@ -1486,30 +1586,24 @@ pub(crate) mod parsing {
     // box <trailer>
     #[cfg(feature = "full")]
     fn unary_expr(input: ParseStream, allow_struct: AllowStruct) -> Result<Expr> {
-        let begin = input.fork();
         let attrs = input.call(expr_attrs)?;
         if input.peek(Token![&]) {
             let and_token: Token![&] = input.parse()?;
-            let raw: Option<raw> =
-                if input.peek(raw) && (input.peek2(Token![mut]) || input.peek2(Token![const])) {
-                    Some(input.parse()?)
-                } else {
-                    None
-                };
-            let mutability: Option<Token![mut]> = input.parse()?;
-            if raw.is_some() && mutability.is_none() {
-                input.parse::<Token![const]>()?;
-            }
-            let expr = Box::new(unary_expr(input, allow_struct)?);
-            if raw.is_some() {
-                Ok(Expr::Verbatim(verbatim::between(begin, input)))
+            if input.peek(Token![raw]) && (input.peek2(Token![mut]) || input.peek2(Token![const])) {
+                Ok(Expr::RawAddr(ExprRawAddr {
+                    attrs,
+                    and_token,
+                    raw: input.parse()?,
+                    mutability: input.parse()?,
+                    expr: Box::new(unary_expr(input, allow_struct)?),
+                }))
             } else {
                 Ok(Expr::Reference(ExprReference {
                     attrs,
                     and_token,
                     raw: Reserved::default(),
-                    mutability,
-                    expr,
+                    mutability: input.parse()?,
+                    expr: Box::new(unary_expr(input, allow_struct)?),
                 }))
             }
         } else if input.peek(Token![box]) {
@@ -1767,7 +1861,7 @@ pub(crate) mod parsing {
         } else if input.peek(Token![unsafe]) {
             input.parse().map(Expr::Unsafe)
         } else if input.peek(Token![const]) {
-            input.call(expr_const).map(Expr::Verbatim)
+            input.parse().map(Expr::Const)
         } else if input.peek(token::Brace) {
             input.parse().map(Expr::Block)
         } else if input.peek(Token![..]) {
@@ -2020,7 +2114,7 @@ pub(crate) mod parsing {
         } else if input.peek(Token![unsafe]) {
             Expr::Unsafe(input.parse()?)
         } else if input.peek(Token![const]) {
-            Expr::Verbatim(input.call(expr_const)?)
+            Expr::Const(input.parse()?)
         } else if input.peek(token::Brace) {
             Expr::Block(input.parse()?)
         } else {
@@ -2344,6 +2438,21 @@ pub(crate) mod parsing {
         }
     }
 
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    impl Parse for ExprRawAddr {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let allow_struct = AllowStruct(true);
+            Ok(ExprRawAddr {
+                attrs: Vec::new(),
+                and_token: input.parse()?,
+                raw: input.parse()?,
+                mutability: input.parse()?,
+                expr: Box::new(unary_expr(input, allow_struct)?),
+            })
+        }
+    }
+
     #[cfg(feature = "full")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
     impl Parse for ExprBreak {
@@ -2476,6 +2585,10 @@ pub(crate) mod parsing {
         } else {
             match &mut pat {
                 Pat::Box(pat) => pat.attrs = attrs,
+                Pat::Const(pat) => {
+                    let inner_attrs = mem::replace(&mut pat.attrs, attrs);
+                    pat.attrs.extend(inner_attrs);
+                }
                 Pat::Ident(pat) => pat.attrs = attrs,
                 Pat::Lit(pat) => pat.attrs = attrs,
                 Pat::Macro(pat) => pat.attrs = attrs,
@@ -2707,16 +2820,22 @@ pub(crate) mod parsing {
     }
 
     #[cfg(feature = "full")]
-    pub(crate) fn expr_const(input: ParseStream) -> Result<TokenStream> {
-        let begin = input.fork();
-        input.parse::<Token![const]>()?;
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    impl Parse for ExprConst {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let const_token: Token![const] = input.parse()?;
 
-        let content;
-        braced!(content in input);
-        content.call(Attribute::parse_inner)?;
-        content.call(Block::parse_within)?;
+            let content;
+            let brace_token = braced!(content in input);
+            let inner_attrs = content.call(Attribute::parse_inner)?;
+            let stmts = content.call(Block::parse_within)?;
 
-        Ok(verbatim::between(begin, input))
+            Ok(ExprConst {
+                attrs: inner_attrs,
+                const_token,
+                block: Block { brace_token, stmts },
+            })
+        }
     }
 
     #[cfg(feature = "full")]
@@ -2779,6 +2898,21 @@ pub(crate) mod parsing {
         }
     }
 
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    impl Parse for PointerMutability {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let lookahead = input.lookahead1();
+            if lookahead.peek(Token![const]) {
+                input.parse().map(PointerMutability::Const)
+            } else if lookahead.peek(Token![mut]) {
+                input.parse().map(PointerMutability::Mut)
+            } else {
+                Err(lookahead.error())
+            }
+        }
+    }
+
     #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
     impl Parse for ExprPath {
         fn parse(input: ParseStream) -> Result<Self> {
@@ -2894,14 +3028,14 @@ pub(crate) mod printing {
     use super::*;
     #[cfg(feature = "full")]
     use crate::attr::FilterAttrs;
+    use crate::precedence::{self, Operand};
     use proc_macro2::{Literal, TokenStream};
     use quote::{ToTokens, TokenStreamExt};
 
-    // If the given expression is a bare `ExprStruct`, wraps it in parenthesis
-    // before appending it to `TokenStream`.
-    #[cfg(feature = "full")]
-    fn wrap_bare_struct(tokens: &mut TokenStream, e: &Expr) {
-        if let Expr::Struct(_) = *e {
+    // Prints a subexpression, wrapped in parentheses if its precedence is too
+    // low for the position it appears in.
+    fn print_operand(tokens: &mut TokenStream, e: &Expr, operand: Operand) {
+        if precedence::needs_parens(e, operand) {
             token::Paren::default().surround(tokens, |tokens| {
                 e.to_tokens(tokens);
             });
@@ -2932,7 +3066,7 @@ pub(crate) mod printing {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
             self.box_token.to_tokens(tokens);
-            self.expr.to_tokens(tokens);
+            print_operand(tokens, &self.expr, Operand::Prefix);
         }
     }
 
@@ -2952,7 +3086,7 @@ pub(crate) mod printing {
     impl ToTokens for ExprCall {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
-            self.func.to_tokens(tokens);
+            print_operand(tokens, &self.func, Operand::Callee);
             self.paren_token.surround(tokens, |tokens| {
                 self.args.to_tokens(tokens);
             });
@@ -2964,7 +3098,7 @@ pub(crate) mod printing {
     impl ToTokens for ExprMethodCall {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
-            self.receiver.to_tokens(tokens);
+            print_operand(tokens, &self.receiver, Operand::Postfix);
             self.dot_token.to_tokens(tokens);
             self.method.to_tokens(tokens);
             self.turbofish.to_tokens(tokens);
@@ -3017,9 +3151,9 @@ pub(crate) mod printing {
     impl ToTokens for ExprBinary {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
-            self.left.to_tokens(tokens);
+            print_operand(tokens, &self.left, Operand::left_of(&self.op));
             self.op.to_tokens(tokens);
-            self.right.to_tokens(tokens);
+            print_operand(tokens, &self.right, Operand::right_of(&self.op));
         }
     }
 
@@ -3028,7 +3162,7 @@ pub(crate) mod printing {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
             self.op.to_tokens(tokens);
-            self.expr.to_tokens(tokens);
+            print_operand(tokens, &self.expr, Operand::Prefix);
         }
     }
 
@@ -3044,7 +3178,7 @@ pub(crate) mod printing {
     impl ToTokens for ExprCast {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
-            self.expr.to_tokens(tokens);
+            print_operand(tokens, &self.expr, Operand::Left(Precedence::Cast));
             self.as_token.to_tokens(tokens);
             self.ty.to_tokens(tokens);
         }
@@ -3055,7 +3189,7 @@ pub(crate) mod printing {
     impl ToTokens for ExprType {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
-            self.expr.to_tokens(tokens);
+            print_operand(tokens, &self.expr, Operand::Left(Precedence::Cast));
             self.colon_token.to_tokens(tokens);
             self.ty.to_tokens(tokens);
         }
@@ -3089,7 +3223,7 @@ pub(crate) mod printing {
             self.let_token.to_tokens(tokens);
             self.pat.to_tokens(tokens);
             self.eq_token.to_tokens(tokens);
-            wrap_bare_struct(tokens, &self.expr);
+            print_operand(tokens, &self.expr, Operand::Condition);
         }
     }
 
@@ -3099,7 +3233,7 @@ pub(crate) mod printing {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
             self.if_token.to_tokens(tokens);
-            wrap_bare_struct(tokens, &self.cond);
+            print_operand(tokens, &self.cond, Operand::Condition);
             self.then_branch.to_tokens(tokens);
             maybe_wrap_else(tokens, &self.else_branch);
         }
@@ -3112,7 +3246,7 @@ pub(crate) mod printing {
             outer_attrs_to_tokens(&self.attrs, tokens);
             self.label.to_tokens(tokens);
             self.while_token.to_tokens(tokens);
-            wrap_bare_struct(tokens, &self.cond);
+            print_operand(tokens, &self.cond, Operand::Condition);
             self.body.brace_token.surround(tokens, |tokens| {
                 inner_attrs_to_tokens(&self.attrs, tokens);
                 tokens.append_all(&self.body.stmts);
@@ -3129,7 +3263,7 @@ pub(crate) mod printing {
             self.for_token.to_tokens(tokens);
             self.pat.to_tokens(tokens);
             self.in_token.to_tokens(tokens);
-            wrap_bare_struct(tokens, &self.expr);
+            print_operand(tokens, &self.expr, Operand::Condition);
             self.body.brace_token.surround(tokens, |tokens| {
                 inner_attrs_to_tokens(&self.attrs, tokens);
                 tokens.append_all(&self.body.stmts);
@@ -3157,7 +3291,7 @@ pub(crate) mod printing {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
             self.match_token.to_tokens(tokens);
-            wrap_bare_struct(tokens, &self.expr);
+            print_operand(tokens, &self.expr, Operand::Condition);
             self.brace_token.surround(tokens, |tokens| {
                 inner_attrs_to_tokens(&self.attrs, tokens);
                 for (i, arm) in self.arms.iter().enumerate() {
@@ -3189,7 +3323,7 @@ pub(crate) mod printing {
     impl ToTokens for ExprAwait {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
-            self.base.to_tokens(tokens);
+            print_operand(tokens, &self.base, Operand::Postfix);
             self.dot_token.to_tokens(tokens);
             self.await_token.to_tokens(tokens);
         }
@@ -3244,6 +3378,19 @@ pub(crate) mod printing {
         }
     }
 
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for ExprConst {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            outer_attrs_to_tokens(&self.attrs, tokens);
+            self.const_token.to_tokens(tokens);
+            self.block.brace_token.surround(tokens, |tokens| {
+                inner_attrs_to_tokens(&self.attrs, tokens);
+                tokens.append_all(&self.block.stmts);
+            });
+        }
+    }
+
     #[cfg(feature = "full")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
     impl ToTokens for ExprBlock {
@@ -3262,9 +3409,9 @@ pub(crate) mod printing {
     impl ToTokens for ExprAssign {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
-            self.left.to_tokens(tokens);
+            print_operand(tokens, &self.left, Operand::Left(Precedence::Assign));
             self.eq_token.to_tokens(tokens);
-            self.right.to_tokens(tokens);
+            print_operand(tokens, &self.right, Operand::Right(Precedence::Assign));
         }
     }
 
@@ -3273,9 +3420,9 @@ pub(crate) mod printing {
     impl ToTokens for ExprAssignOp {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
-            self.left.to_tokens(tokens);
+            print_operand(tokens, &self.left, Operand::Left(Precedence::Assign));
             self.op.to_tokens(tokens);
-            self.right.to_tokens(tokens);
+            print_operand(tokens, &self.right, Operand::Right(Precedence::Assign));
         }
     }
 
@@ -3283,7 +3430,7 @@ pub(crate) mod printing {
     impl ToTokens for ExprField {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
-            self.base.to_tokens(tokens);
+            print_operand(tokens, &self.base, Operand::Postfix);
             self.dot_token.to_tokens(tokens);
             self.member.to_tokens(tokens);
         }
@@ -3312,7 +3459,7 @@ pub(crate) mod printing {
     impl ToTokens for ExprIndex {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
-            self.expr.to_tokens(tokens);
+            print_operand(tokens, &self.expr, Operand::Postfix);
             self.bracket_token.surround(tokens, |tokens| {
                 self.index.to_tokens(tokens);
             });
@@ -3324,12 +3471,39 @@ pub(crate) mod printing {
     impl ToTokens for ExprRange {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
-            self.from.to_tokens(tokens);
+            if let Some(from) = &self.from {
+                print_operand(tokens, from, Operand::Left(Precedence::Range));
+            }
             match &self.limits {
                 RangeLimits::HalfOpen(t) => t.to_tokens(tokens),
                 RangeLimits::Closed(t) => t.to_tokens(tokens),
             }
-            self.to.to_tokens(tokens);
+            if let Some(to) = &self.to {
+                print_operand(tokens, to, Operand::Right(Precedence::Range));
+            }
+        }
+    }
+
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for ExprRawAddr {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            outer_attrs_to_tokens(&self.attrs, tokens);
+            self.and_token.to_tokens(tokens);
+            self.raw.to_tokens(tokens);
+            self.mutability.to_tokens(tokens);
+            print_operand(tokens, &self.expr, Operand::Prefix);
+        }
+    }
+
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for PointerMutability {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            match self {
+                PointerMutability::Const(const_token) => const_token.to_tokens(tokens),
+                PointerMutability::Mut(mut_token) => mut_token.to_tokens(tokens),
+            }
         }
     }
 
@@ -3348,7 +3522,7 @@ pub(crate) mod printing {
             outer_attrs_to_tokens(&self.attrs, tokens);
             self.and_token.to_tokens(tokens);
             self.mutability.to_tokens(tokens);
-            self.expr.to_tokens(tokens);
+            print_operand(tokens, &self.expr, Operand::Prefix);
         }
     }
 
@@ -3452,7 +3626,7 @@ pub(crate) mod printing {
     impl ToTokens for ExprTry {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
-            self.expr.to_tokens(tokens);
+            print_operand(tokens, &self.expr, Operand::Postfix);
             self.question_token.to_tokens(tokens);
         }
     }
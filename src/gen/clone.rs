@@ -41,6 +41,39 @@ impl Clone for Arm {
 }
 #[cfg(any(feature = "derive", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for AttrMeta {
+    fn clone(&self) -> Self {
+        match self {
+            AttrMeta::Path(v0) => AttrMeta::Path(v0.clone()),
+            AttrMeta::List(v0) => AttrMeta::List(v0.clone()),
+            AttrMeta::NameValue(v0) => AttrMeta::NameValue(v0.clone()),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for AttrMetaList {
+    fn clone(&self) -> Self {
+        AttrMetaList {
+            path: self.path.clone(),
+            delimiter: self.delimiter.clone(),
+            tokens: self.tokens.clone(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for AttrMetaNameValue {
+    fn clone(&self) -> Self {
+        AttrMetaNameValue {
+            path: self.path.clone(),
+            eq_token: self.eq_token.clone(),
+            value: self.value.clone(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Copy for AttrStyle {}
 #[cfg(any(feature = "derive", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
@@ -225,6 +258,8 @@ impl Clone for Expr {
             #[cfg(feature = "full")]
             Expr::Closure(v0) => Expr::Closure(v0.clone()),
             #[cfg(feature = "full")]
+            Expr::Const(v0) => Expr::Const(v0.clone()),
+            #[cfg(feature = "full")]
             Expr::Continue(v0) => Expr::Continue(v0.clone()),
             Expr::Field(v0) => Expr::Field(v0.clone()),
             #[cfg(feature = "full")]
@@ -250,6 +285,8 @@ impl Clone for Expr {
             #[cfg(feature = "full")]
             Expr::Range(v0) => Expr::Range(v0.clone()),
             #[cfg(feature = "full")]
+            Expr::RawAddr(v0) => Expr::RawAddr(v0.clone()),
+            #[cfg(feature = "full")]
             Expr::Reference(v0) => Expr::Reference(v0.clone()),
             #[cfg(feature = "full")]
             Expr::Repeat(v0) => Expr::Repeat(v0.clone()),
@@ -425,6 +462,17 @@ impl Clone for ExprClosure {
 }
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for ExprConst {
+    fn clone(&self) -> Self {
+        ExprConst {
+            attrs: self.attrs.clone(),
+            const_token: self.const_token.clone(),
+            block: self.block.clone(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for ExprContinue {
     fn clone(&self) -> Self {
         ExprContinue {
@@ -606,6 +654,19 @@ impl Clone for ExprRange {
 }
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for ExprRawAddr {
+    fn clone(&self) -> Self {
+        ExprRawAddr {
+            attrs: self.attrs.clone(),
+            and_token: self.and_token.clone(),
+            raw: self.raw.clone(),
+            mutability: self.mutability.clone(),
+            expr: self.expr.clone(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for ExprReference {
     fn clone(&self) -> Self {
         ExprReference {
@@ -1039,6 +1100,7 @@ impl Clone for Item {
             Item::Enum(v0) => Item::Enum(v0.clone()),
             Item::ExternCrate(v0) => Item::ExternCrate(v0.clone()),
             Item::Fn(v0) => Item::Fn(v0.clone()),
+            Item::FnDecl(v0) => Item::FnDecl(v0.clone()),
             Item::ForeignMod(v0) => Item::ForeignMod(v0.clone()),
             Item::Impl(v0) => Item::Impl(v0.clone()),
             Item::Macro(v0) => Item::Macro(v0.clone()),
@@ -1067,7 +1129,6 @@ impl Clone for ItemConst {
             ident: self.ident.clone(),
             colon_token: self.colon_token.clone(),
             ty: self.ty.clone(),
-            eq_token: self.eq_token.clone(),
             expr: self.expr.clone(),
             semi_token: self.semi_token.clone(),
         }
@@ -1117,10 +1178,23 @@ impl Clone for ItemFn {
 }
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for ItemFnDecl {
+    fn clone(&self) -> Self {
+        ItemFnDecl {
+            attrs: self.attrs.clone(),
+            vis: self.vis.clone(),
+            sig: self.sig.clone(),
+            semi_token: self.semi_token.clone(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for ItemForeignMod {
     fn clone(&self) -> Self {
         ItemForeignMod {
             attrs: self.attrs.clone(),
+            unsafety: self.unsafety.clone(),
             abi: self.abi.clone(),
             brace_token: self.brace_token.clone(),
             items: self.items.clone(),
@@ -1176,6 +1250,7 @@ impl Clone for ItemMod {
         ItemMod {
             attrs: self.attrs.clone(),
             vis: self.vis.clone(),
+            unsafety: self.unsafety.clone(),
             mod_token: self.mod_token.clone(),
             ident: self.ident.clone(),
             content: self.content.clone(),
@@ -1193,9 +1268,7 @@ impl Clone for ItemStatic {
             static_token: self.static_token.clone(),
             mutability: self.mutability.clone(),
             ident: self.ident.clone(),
-            colon_token: self.colon_token.clone(),
             ty: self.ty.clone(),
-            eq_token: self.eq_token.clone(),
             expr: self.expr.clone(),
             semi_token: self.semi_token.clone(),
         }
@@ -1354,6 +1427,17 @@ impl Clone for Local {
         }
     }
 }
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for LocalInit {
+    fn clone(&self) -> Self {
+        LocalInit {
+            eq_token: self.eq_token.clone(),
+            expr: self.expr.clone(),
+            diverge: self.diverge.clone(),
+        }
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for Macro {
@@ -1377,6 +1461,83 @@ impl Clone for MacroDelimiter {
         }
     }
 }
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for MacroGroup {
+    fn clone(&self) -> Self {
+        MacroGroup {
+            delimiter: self.delimiter.clone(),
+            content: self.content.clone(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for MacroMetavar {
+    fn clone(&self) -> Self {
+        MacroMetavar {
+            dollar_token: self.dollar_token.clone(),
+            name: self.name.clone(),
+            specifier: self.specifier.clone(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for MacroRepOp {
+    fn clone(&self) -> Self {
+        match self {
+            MacroRepOp::ZeroOrMore(v0) => MacroRepOp::ZeroOrMore(v0.clone()),
+            MacroRepOp::OneOrMore(v0) => MacroRepOp::OneOrMore(v0.clone()),
+            MacroRepOp::ZeroOrOne(v0) => MacroRepOp::ZeroOrOne(v0.clone()),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for MacroRepetition {
+    fn clone(&self) -> Self {
+        MacroRepetition {
+            dollar_token: self.dollar_token.clone(),
+            paren_token: self.paren_token.clone(),
+            content: self.content.clone(),
+            separator: self.separator.clone(),
+            op: self.op.clone(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for MacroRule {
+    fn clone(&self) -> Self {
+        MacroRule {
+            matcher: self.matcher.clone(),
+            fat_arrow_token: self.fat_arrow_token.clone(),
+            transcriber: self.transcriber.clone(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for MacroRules {
+    fn clone(&self) -> Self {
+        MacroRules {
+            rules: self.rules.clone(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for MacroTree {
+    fn clone(&self) -> Self {
+        match self {
+            MacroTree::Verbatim(v0) => MacroTree::Verbatim(v0.clone()),
+            MacroTree::Group(v0) => MacroTree::Group(v0.clone()),
+            MacroTree::Metavar(v0) => MacroTree::Metavar(v0.clone()),
+            MacroTree::Repetition(v0) => MacroTree::Repetition(v0.clone()),
+        }
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for Member {
@@ -1459,6 +1620,7 @@ impl Clone for Pat {
     fn clone(&self) -> Self {
         match self {
             Pat::Box(v0) => Pat::Box(v0.clone()),
+            Pat::Const(v0) => Pat::Const(v0.clone()),
             Pat::Ident(v0) => Pat::Ident(v0.clone()),
             Pat::Lit(v0) => Pat::Lit(v0.clone()),
             Pat::Macro(v0) => Pat::Macro(v0.clone()),
@@ -1491,6 +1653,17 @@ impl Clone for PatBox {
 }
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for PatConst {
+    fn clone(&self) -> Self {
+        PatConst {
+            attrs: self.attrs.clone(),
+            const_token: self.const_token.clone(),
+            block: self.block.clone(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for PatIdent {
     fn clone(&self) -> Self {
         PatIdent {
@@ -1677,6 +1850,16 @@ impl Clone for PathSegment {
         }
     }
 }
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
+impl Clone for PointerMutability {
+    fn clone(&self) -> Self {
+        match self {
+            PointerMutability::Const(v0) => PointerMutability::Const(v0.clone()),
+            PointerMutability::Mut(v0) => PointerMutability::Mut(v0.clone()),
+        }
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "clone-impls")))]
 impl Clone for PredicateEq {
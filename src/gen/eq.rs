@@ -40,6 +40,43 @@ impl PartialEq for Arm {
 }
 #[cfg(any(feature = "derive", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for AttrMeta {}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for AttrMeta {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (AttrMeta::Path(self0), AttrMeta::Path(other0)) => self0 == other0,
+            (AttrMeta::List(self0), AttrMeta::List(other0)) => self0 == other0,
+            (AttrMeta::NameValue(self0), AttrMeta::NameValue(other0)) => self0 == other0,
+            _ => false,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for AttrMetaList {}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for AttrMetaList {
+    fn eq(&self, other: &Self) -> bool {
+        self.path == other.path
+            && self.delimiter == other.delimiter
+            && TokenStreamHelper(&self.tokens) == TokenStreamHelper(&other.tokens)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for AttrMetaNameValue {}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for AttrMetaNameValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.path == other.path && self.value == other.value
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for AttrStyle {}
 #[cfg(any(feature = "derive", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
@@ -257,6 +294,8 @@ impl PartialEq for Expr {
             #[cfg(feature = "full")]
             (Expr::Closure(self0), Expr::Closure(other0)) => self0 == other0,
             #[cfg(feature = "full")]
+            (Expr::Const(self0), Expr::Const(other0)) => self0 == other0,
+            #[cfg(feature = "full")]
             (Expr::Continue(self0), Expr::Continue(other0)) => self0 == other0,
             (Expr::Field(self0), Expr::Field(other0)) => self0 == other0,
             #[cfg(feature = "full")]
@@ -282,6 +321,8 @@ impl PartialEq for Expr {
             #[cfg(feature = "full")]
             (Expr::Range(self0), Expr::Range(other0)) => self0 == other0,
             #[cfg(feature = "full")]
+            (Expr::RawAddr(self0), Expr::RawAddr(other0)) => self0 == other0,
+            #[cfg(feature = "full")]
             (Expr::Reference(self0), Expr::Reference(other0)) => self0 == other0,
             #[cfg(feature = "full")]
             (Expr::Repeat(self0), Expr::Repeat(other0)) => self0 == other0,
@@ -445,6 +486,16 @@ impl PartialEq for ExprClosure {
 }
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for ExprConst {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for ExprConst {
+    fn eq(&self, other: &Self) -> bool {
+        self.attrs == other.attrs && self.block == other.block
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for ExprContinue {}
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
@@ -609,6 +660,16 @@ impl PartialEq for ExprRange {
 }
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for ExprRawAddr {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for ExprRawAddr {
+    fn eq(&self, other: &Self) -> bool {
+        self.attrs == other.attrs && self.mutability == other.mutability && self.expr == other.expr
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for ExprReference {}
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
@@ -1048,6 +1109,7 @@ impl PartialEq for Item {
             (Item::Enum(self0), Item::Enum(other0)) => self0 == other0,
             (Item::ExternCrate(self0), Item::ExternCrate(other0)) => self0 == other0,
             (Item::Fn(self0), Item::Fn(other0)) => self0 == other0,
+            (Item::FnDecl(self0), Item::FnDecl(other0)) => self0 == other0,
             (Item::ForeignMod(self0), Item::ForeignMod(other0)) => self0 == other0,
             (Item::Impl(self0), Item::Impl(other0)) => self0 == other0,
             (Item::Macro(self0), Item::Macro(other0)) => self0 == other0,
@@ -1123,12 +1185,25 @@ impl PartialEq for ItemFn {
 }
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for ItemFnDecl {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for ItemFnDecl {
+    fn eq(&self, other: &Self) -> bool {
+        self.attrs == other.attrs && self.vis == other.vis && self.sig == other.sig
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for ItemForeignMod {}
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl PartialEq for ItemForeignMod {
     fn eq(&self, other: &Self) -> bool {
-        self.attrs == other.attrs && self.abi == other.abi && self.items == other.items
+        self.attrs == other.attrs
+            && self.unsafety == other.unsafety
+            && self.abi == other.abi
+            && self.items == other.items
     }
 }
 #[cfg(feature = "full")]
@@ -1182,6 +1257,7 @@ impl PartialEq for ItemMod {
     fn eq(&self, other: &Self) -> bool {
         self.attrs == other.attrs
             && self.vis == other.vis
+            && self.unsafety == other.unsafety
             && self.ident == other.ident
             && self.content == other.content
             && self.semi == other.semi
@@ -1363,6 +1439,16 @@ impl PartialEq for Local {
         self.attrs == other.attrs && self.pat == other.pat && self.init == other.init
     }
 }
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for LocalInit {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for LocalInit {
+    fn eq(&self, other: &Self) -> bool {
+        self.expr == other.expr && self.diverge == other.diverge
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for Macro {}
@@ -1390,6 +1476,92 @@ impl PartialEq for MacroDelimiter {
         }
     }
 }
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for MacroGroup {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for MacroGroup {
+    fn eq(&self, other: &Self) -> bool {
+        self.delimiter == other.delimiter && self.content == other.content
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for MacroMetavar {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for MacroMetavar {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.specifier == other.specifier
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for MacroRepOp {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for MacroRepOp {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (MacroRepOp::ZeroOrMore(_), MacroRepOp::ZeroOrMore(_)) => true,
+            (MacroRepOp::OneOrMore(_), MacroRepOp::OneOrMore(_)) => true,
+            (MacroRepOp::ZeroOrOne(_), MacroRepOp::ZeroOrOne(_)) => true,
+            _ => false,
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for MacroRepetition {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for MacroRepetition {
+    fn eq(&self, other: &Self) -> bool {
+        self.content == other.content
+            && self.separator.as_ref().map(TokenStreamHelper)
+                == other.separator.as_ref().map(TokenStreamHelper)
+            && self.op == other.op
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for MacroRule {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for MacroRule {
+    fn eq(&self, other: &Self) -> bool {
+        self.matcher == other.matcher && self.transcriber == other.transcriber
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for MacroRules {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for MacroRules {
+    fn eq(&self, other: &Self) -> bool {
+        self.rules == other.rules
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for MacroTree {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for MacroTree {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (MacroTree::Verbatim(self0), MacroTree::Verbatim(other0)) => {
+                TokenStreamHelper(self0) == TokenStreamHelper(other0)
+            }
+            (MacroTree::Group(self0), MacroTree::Group(other0)) => self0 == other0,
+            (MacroTree::Metavar(self0), MacroTree::Metavar(other0)) => self0 == other0,
+            (MacroTree::Repetition(self0), MacroTree::Repetition(other0)) => self0 == other0,
+            _ => false,
+        }
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for Meta {}
@@ -1468,6 +1640,7 @@ impl PartialEq for Pat {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (Pat::Box(self0), Pat::Box(other0)) => self0 == other0,
+            (Pat::Const(self0), Pat::Const(other0)) => self0 == other0,
             (Pat::Ident(self0), Pat::Ident(other0)) => self0 == other0,
             (Pat::Lit(self0), Pat::Lit(other0)) => self0 == other0,
             (Pat::Macro(self0), Pat::Macro(other0)) => self0 == other0,
@@ -1501,6 +1674,16 @@ impl PartialEq for PatBox {
 }
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for PatConst {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for PatConst {
+    fn eq(&self, other: &Self) -> bool {
+        self.attrs == other.attrs && self.block == other.block
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for PatIdent {}
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
@@ -1690,6 +1873,20 @@ impl PartialEq for PathSegment {
         self.ident == other.ident && self.arguments == other.arguments
     }
 }
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Eq for PointerMutability {}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl PartialEq for PointerMutability {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (PointerMutability::Const(_), PointerMutability::Const(_)) => true,
+            (PointerMutability::Mut(_), PointerMutability::Mut(_)) => true,
+            _ => false,
+        }
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Eq for PredicateEq {}
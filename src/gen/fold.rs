@@ -45,6 +45,18 @@ pub trait Fold {
         fold_arm(self, i)
     }
     #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_attr_meta(&mut self, i: AttrMeta) -> AttrMeta {
+        fold_attr_meta(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_attr_meta_list(&mut self, i: AttrMetaList) -> AttrMetaList {
+        fold_attr_meta_list(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_attr_meta_name_value(&mut self, i: AttrMetaNameValue) -> AttrMetaNameValue {
+        fold_attr_meta_name_value(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_attr_style(&mut self, i: AttrStyle) -> AttrStyle {
         fold_attr_style(self, i)
     }
@@ -153,6 +165,10 @@ pub trait Fold {
         fold_expr_closure(self, i)
     }
     #[cfg(feature = "full")]
+    fn fold_expr_const(&mut self, i: ExprConst) -> ExprConst {
+        fold_expr_const(self, i)
+    }
+    #[cfg(feature = "full")]
     fn fold_expr_continue(&mut self, i: ExprContinue) -> ExprContinue {
         fold_expr_continue(self, i)
     }
@@ -213,6 +229,10 @@ pub trait Fold {
         fold_expr_range(self, i)
     }
     #[cfg(feature = "full")]
+    fn fold_expr_raw_addr(&mut self, i: ExprRawAddr) -> ExprRawAddr {
+        fold_expr_raw_addr(self, i)
+    }
+    #[cfg(feature = "full")]
     fn fold_expr_reference(&mut self, i: ExprReference) -> ExprReference {
         fold_expr_reference(self, i)
     }
@@ -376,6 +396,10 @@ pub trait Fold {
         fold_item_fn(self, i)
     }
     #[cfg(feature = "full")]
+    fn fold_item_fn_decl(&mut self, i: ItemFnDecl) -> ItemFnDecl {
+        fold_item_fn_decl(self, i)
+    }
+    #[cfg(feature = "full")]
     fn fold_item_foreign_mod(&mut self, i: ItemForeignMod) -> ItemForeignMod {
         fold_item_foreign_mod(self, i)
     }
@@ -462,6 +486,10 @@ pub trait Fold {
     fn fold_local(&mut self, i: Local) -> Local {
         fold_local(self, i)
     }
+    #[cfg(feature = "full")]
+    fn fold_local_init(&mut self, i: LocalInit) -> LocalInit {
+        fold_local_init(self, i)
+    }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_macro(&mut self, i: Macro) -> Macro {
         fold_macro(self, i)
@@ -470,6 +498,34 @@ pub trait Fold {
     fn fold_macro_delimiter(&mut self, i: MacroDelimiter) -> MacroDelimiter {
         fold_macro_delimiter(self, i)
     }
+    #[cfg(feature = "full")]
+    fn fold_macro_group(&mut self, i: MacroGroup) -> MacroGroup {
+        fold_macro_group(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_macro_metavar(&mut self, i: MacroMetavar) -> MacroMetavar {
+        fold_macro_metavar(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_macro_rep_op(&mut self, i: MacroRepOp) -> MacroRepOp {
+        fold_macro_rep_op(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_macro_repetition(&mut self, i: MacroRepetition) -> MacroRepetition {
+        fold_macro_repetition(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_macro_rule(&mut self, i: MacroRule) -> MacroRule {
+        fold_macro_rule(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_macro_rules(&mut self, i: MacroRules) -> MacroRules {
+        fold_macro_rules(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_macro_tree(&mut self, i: MacroTree) -> MacroTree {
+        fold_macro_tree(self, i)
+    }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_member(&mut self, i: Member) -> Member {
         fold_member(self, i)
@@ -510,6 +566,10 @@ pub trait Fold {
         fold_pat_box(self, i)
     }
     #[cfg(feature = "full")]
+    fn fold_pat_const(&mut self, i: PatConst) -> PatConst {
+        fold_pat_const(self, i)
+    }
+    #[cfg(feature = "full")]
     fn fold_pat_ident(&mut self, i: PatIdent) -> PatIdent {
         fold_pat_ident(self, i)
     }
@@ -577,6 +637,10 @@ pub trait Fold {
     fn fold_path_segment(&mut self, i: PathSegment) -> PathSegment {
         fold_path_segment(self, i)
     }
+    #[cfg(feature = "full")]
+    fn fold_pointer_mutability(&mut self, i: PointerMutability) -> PointerMutability {
+        fold_pointer_mutability(self, i)
+    }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn fold_predicate_eq(&mut self, i: PredicateEq) -> PredicateEq {
         fold_predicate_eq(self, i)
@@ -818,6 +882,41 @@ where
     }
 }
 #[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_attr_meta<F>(f: &mut F, node: AttrMeta) -> AttrMeta
+where
+    F: Fold + ?Sized,
+{
+    match node {
+        AttrMeta::Path(_binding_0) => AttrMeta::Path(f.fold_path(_binding_0)),
+        AttrMeta::List(_binding_0) => AttrMeta::List(f.fold_attr_meta_list(_binding_0)),
+        AttrMeta::NameValue(_binding_0) => {
+            AttrMeta::NameValue(f.fold_attr_meta_name_value(_binding_0))
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_attr_meta_list<F>(f: &mut F, node: AttrMetaList) -> AttrMetaList
+where
+    F: Fold + ?Sized,
+{
+    AttrMetaList {
+        path: f.fold_path(node.path),
+        delimiter: f.fold_macro_delimiter(node.delimiter),
+        tokens: node.tokens,
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_attr_meta_name_value<F>(f: &mut F, node: AttrMetaNameValue) -> AttrMetaNameValue
+where
+    F: Fold + ?Sized,
+{
+    AttrMetaNameValue {
+        path: f.fold_path(node.path),
+        eq_token: Token ! [=](tokens_helper(f, &node.eq_token.spans)),
+        value: f.fold_expr(node.value),
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
 pub fn fold_attr_style<F>(f: &mut F, node: AttrStyle) -> AttrStyle
 where
     F: Fold + ?Sized,
@@ -1041,6 +1140,7 @@ where
         Expr::Call(_binding_0) => Expr::Call(f.fold_expr_call(_binding_0)),
         Expr::Cast(_binding_0) => Expr::Cast(f.fold_expr_cast(_binding_0)),
         Expr::Closure(_binding_0) => Expr::Closure(full!(f.fold_expr_closure(_binding_0))),
+        Expr::Const(_binding_0) => Expr::Const(full!(f.fold_expr_const(_binding_0))),
         Expr::Continue(_binding_0) => Expr::Continue(full!(f.fold_expr_continue(_binding_0))),
         Expr::Field(_binding_0) => Expr::Field(f.fold_expr_field(_binding_0)),
         Expr::ForLoop(_binding_0) => Expr::ForLoop(full!(f.fold_expr_for_loop(_binding_0))),
@@ -1058,6 +1158,7 @@ where
         Expr::Paren(_binding_0) => Expr::Paren(f.fold_expr_paren(_binding_0)),
         Expr::Path(_binding_0) => Expr::Path(f.fold_expr_path(_binding_0)),
         Expr::Range(_binding_0) => Expr::Range(full!(f.fold_expr_range(_binding_0))),
+        Expr::RawAddr(_binding_0) => Expr::RawAddr(full!(f.fold_expr_raw_addr(_binding_0))),
         Expr::Reference(_binding_0) => Expr::Reference(full!(f.fold_expr_reference(_binding_0))),
         Expr::Repeat(_binding_0) => Expr::Repeat(full!(f.fold_expr_repeat(_binding_0))),
         Expr::Return(_binding_0) => Expr::Return(full!(f.fold_expr_return(_binding_0))),
@@ -1221,6 +1322,17 @@ where
     }
 }
 #[cfg(feature = "full")]
+pub fn fold_expr_const<F>(f: &mut F, node: ExprConst) -> ExprConst
+where
+    F: Fold + ?Sized,
+{
+    ExprConst {
+        attrs: FoldHelper::lift(node.attrs, |it| f.fold_attribute(it)),
+        const_token: Token![const](tokens_helper(f, &node.const_token.span)),
+        block: f.fold_block(node.block),
+    }
+}
+#[cfg(feature = "full")]
 pub fn fold_expr_continue<F>(f: &mut F, node: ExprContinue) -> ExprContinue
 where
     F: Fold + ?Sized,
@@ -1407,6 +1519,19 @@ where
     }
 }
 #[cfg(feature = "full")]
+pub fn fold_expr_raw_addr<F>(f: &mut F, node: ExprRawAddr) -> ExprRawAddr
+where
+    F: Fold + ?Sized,
+{
+    ExprRawAddr {
+        attrs: FoldHelper::lift(node.attrs, |it| f.fold_attribute(it)),
+        and_token: Token ! [&](tokens_helper(f, &node.and_token.spans)),
+        raw: Token![raw](tokens_helper(f, &node.raw.span)),
+        mutability: f.fold_pointer_mutability(node.mutability),
+        expr: Box::new(f.fold_expr(*node.expr)),
+    }
+}
+#[cfg(feature = "full")]
 pub fn fold_expr_reference<F>(f: &mut F, node: ExprReference) -> ExprReference
 where
     F: Fold + ?Sized,
@@ -1868,6 +1993,7 @@ where
         Item::Enum(_binding_0) => Item::Enum(f.fold_item_enum(_binding_0)),
         Item::ExternCrate(_binding_0) => Item::ExternCrate(f.fold_item_extern_crate(_binding_0)),
         Item::Fn(_binding_0) => Item::Fn(f.fold_item_fn(_binding_0)),
+        Item::FnDecl(_binding_0) => Item::FnDecl(f.fold_item_fn_decl(_binding_0)),
         Item::ForeignMod(_binding_0) => Item::ForeignMod(f.fold_item_foreign_mod(_binding_0)),
         Item::Impl(_binding_0) => Item::Impl(f.fold_item_impl(_binding_0)),
         Item::Macro(_binding_0) => Item::Macro(f.fold_item_macro(_binding_0)),
@@ -1896,8 +2022,12 @@ where
         ident: f.fold_ident(node.ident),
         colon_token: Token ! [:](tokens_helper(f, &node.colon_token.spans)),
         ty: Box::new(f.fold_type(*node.ty)),
-        eq_token: Token ! [=](tokens_helper(f, &node.eq_token.spans)),
-        expr: Box::new(f.fold_expr(*node.expr)),
+        expr: (node.expr).map(|it| {
+            (
+                Token ! [=](tokens_helper(f, &(it).0.spans)),
+                Box::new(f.fold_expr(*(it).1)),
+            )
+        }),
         semi_token: Token ! [;](tokens_helper(f, &node.semi_token.spans)),
     }
 }
@@ -1949,12 +2079,25 @@ where
     }
 }
 #[cfg(feature = "full")]
+pub fn fold_item_fn_decl<F>(f: &mut F, node: ItemFnDecl) -> ItemFnDecl
+where
+    F: Fold + ?Sized,
+{
+    ItemFnDecl {
+        attrs: FoldHelper::lift(node.attrs, |it| f.fold_attribute(it)),
+        vis: f.fold_visibility(node.vis),
+        sig: f.fold_signature(node.sig),
+        semi_token: Token ! [;](tokens_helper(f, &node.semi_token.spans)),
+    }
+}
+#[cfg(feature = "full")]
 pub fn fold_item_foreign_mod<F>(f: &mut F, node: ItemForeignMod) -> ItemForeignMod
 where
     F: Fold + ?Sized,
 {
     ItemForeignMod {
         attrs: FoldHelper::lift(node.attrs, |it| f.fold_attribute(it)),
+        unsafety: (node.unsafety).map(|it| Token![unsafe](tokens_helper(f, &it.span))),
         abi: f.fold_abi(node.abi),
         brace_token: Brace(tokens_helper(f, &node.brace_token.span)),
         items: FoldHelper::lift(node.items, |it| f.fold_foreign_item(it)),
@@ -2016,6 +2159,7 @@ where
     ItemMod {
         attrs: FoldHelper::lift(node.attrs, |it| f.fold_attribute(it)),
         vis: f.fold_visibility(node.vis),
+        unsafety: (node.unsafety).map(|it| Token![unsafe](tokens_helper(f, &it.span))),
         mod_token: Token![mod](tokens_helper(f, &node.mod_token.span)),
         ident: f.fold_ident(node.ident),
         content: (node.content).map(|it| {
@@ -2038,10 +2182,18 @@ where
         static_token: Token![static](tokens_helper(f, &node.static_token.span)),
         mutability: (node.mutability).map(|it| Token![mut](tokens_helper(f, &it.span))),
         ident: f.fold_ident(node.ident),
-        colon_token: Token ! [:](tokens_helper(f, &node.colon_token.spans)),
-        ty: Box::new(f.fold_type(*node.ty)),
-        eq_token: Token ! [=](tokens_helper(f, &node.eq_token.spans)),
-        expr: Box::new(f.fold_expr(*node.expr)),
+        ty: (node.ty).map(|it| {
+            (
+                Token ! [:](tokens_helper(f, &(it).0.spans)),
+                Box::new(f.fold_type(*(it).1)),
+            )
+        }),
+        expr: (node.expr).map(|it| {
+            (
+                Token ! [=](tokens_helper(f, &(it).0.spans)),
+                Box::new(f.fold_expr(*(it).1)),
+            )
+        }),
         semi_token: Token ! [;](tokens_helper(f, &node.semi_token.spans)),
     }
 }
@@ -2257,13 +2409,24 @@ where
         attrs: FoldHelper::lift(node.attrs, |it| f.fold_attribute(it)),
         let_token: Token![let](tokens_helper(f, &node.let_token.span)),
         pat: f.fold_pat(node.pat),
-        init: (node.init).map(|it| {
+        init: (node.init).map(|it| f.fold_local_init(it)),
+        semi_token: Token ! [;](tokens_helper(f, &node.semi_token.spans)),
+    }
+}
+#[cfg(feature = "full")]
+pub fn fold_local_init<F>(f: &mut F, node: LocalInit) -> LocalInit
+where
+    F: Fold + ?Sized,
+{
+    LocalInit {
+        eq_token: Token ! [=](tokens_helper(f, &node.eq_token.spans)),
+        expr: Box::new(f.fold_expr(*node.expr)),
+        diverge: (node.diverge).map(|it| {
             (
-                Token ! [=](tokens_helper(f, &(it).0.spans)),
-                Box::new(f.fold_expr(*(it).1)),
+                Token![else](tokens_helper(f, &(it).0.span)),
+                f.fold_block((it).1),
             )
         }),
-        semi_token: Token ! [;](tokens_helper(f, &node.semi_token.spans)),
     }
 }
 #[cfg(any(feature = "derive", feature = "full"))]
@@ -2295,6 +2458,96 @@ where
         }
     }
 }
+#[cfg(feature = "full")]
+pub fn fold_macro_group<F>(f: &mut F, node: MacroGroup) -> MacroGroup
+where
+    F: Fold + ?Sized,
+{
+    MacroGroup {
+        delimiter: f.fold_macro_delimiter(node.delimiter),
+        content: FoldHelper::lift(node.content, |it| f.fold_macro_tree(it)),
+    }
+}
+#[cfg(feature = "full")]
+pub fn fold_macro_metavar<F>(f: &mut F, node: MacroMetavar) -> MacroMetavar
+where
+    F: Fold + ?Sized,
+{
+    MacroMetavar {
+        dollar_token: Token ! [$](tokens_helper(f, &node.dollar_token.spans)),
+        name: f.fold_ident(node.name),
+        specifier: (node.specifier).map(|it| {
+            (
+                Token ! [:](tokens_helper(f, &(it).0.spans)),
+                f.fold_ident((it).1),
+            )
+        }),
+    }
+}
+#[cfg(feature = "full")]
+pub fn fold_macro_rep_op<F>(f: &mut F, node: MacroRepOp) -> MacroRepOp
+where
+    F: Fold + ?Sized,
+{
+    match node {
+        MacroRepOp::ZeroOrMore(_binding_0) => {
+            MacroRepOp::ZeroOrMore(Token ! [*](tokens_helper(f, &_binding_0.spans)))
+        }
+        MacroRepOp::OneOrMore(_binding_0) => {
+            MacroRepOp::OneOrMore(Token ! [+](tokens_helper(f, &_binding_0.spans)))
+        }
+        MacroRepOp::ZeroOrOne(_binding_0) => {
+            MacroRepOp::ZeroOrOne(Token ! [?](tokens_helper(f, &_binding_0.spans)))
+        }
+    }
+}
+#[cfg(feature = "full")]
+pub fn fold_macro_repetition<F>(f: &mut F, node: MacroRepetition) -> MacroRepetition
+where
+    F: Fold + ?Sized,
+{
+    MacroRepetition {
+        dollar_token: Token ! [$](tokens_helper(f, &node.dollar_token.spans)),
+        paren_token: Paren(tokens_helper(f, &node.paren_token.span)),
+        content: FoldHelper::lift(node.content, |it| f.fold_macro_tree(it)),
+        separator: node.separator,
+        op: f.fold_macro_rep_op(node.op),
+    }
+}
+#[cfg(feature = "full")]
+pub fn fold_macro_rule<F>(f: &mut F, node: MacroRule) -> MacroRule
+where
+    F: Fold + ?Sized,
+{
+    MacroRule {
+        matcher: f.fold_macro_group(node.matcher),
+        fat_arrow_token: Token ! [=>](tokens_helper(f, &node.fat_arrow_token.spans)),
+        transcriber: f.fold_macro_group(node.transcriber),
+    }
+}
+#[cfg(feature = "full")]
+pub fn fold_macro_rules<F>(f: &mut F, node: MacroRules) -> MacroRules
+where
+    F: Fold + ?Sized,
+{
+    MacroRules {
+        rules: FoldHelper::lift(node.rules, |it| f.fold_macro_rule(it)),
+    }
+}
+#[cfg(feature = "full")]
+pub fn fold_macro_tree<F>(f: &mut F, node: MacroTree) -> MacroTree
+where
+    F: Fold + ?Sized,
+{
+    match node {
+        MacroTree::Verbatim(_binding_0) => MacroTree::Verbatim(_binding_0),
+        MacroTree::Group(_binding_0) => MacroTree::Group(f.fold_macro_group(_binding_0)),
+        MacroTree::Metavar(_binding_0) => MacroTree::Metavar(f.fold_macro_metavar(_binding_0)),
+        MacroTree::Repetition(_binding_0) => {
+            MacroTree::Repetition(f.fold_macro_repetition(_binding_0))
+        }
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn fold_member<F>(f: &mut F, node: Member) -> Member
 where
@@ -2381,6 +2634,7 @@ where
 {
     match node {
         Pat::Box(_binding_0) => Pat::Box(f.fold_pat_box(_binding_0)),
+        Pat::Const(_binding_0) => Pat::Const(f.fold_pat_const(_binding_0)),
         Pat::Ident(_binding_0) => Pat::Ident(f.fold_pat_ident(_binding_0)),
         Pat::Lit(_binding_0) => Pat::Lit(f.fold_pat_lit(_binding_0)),
         Pat::Macro(_binding_0) => Pat::Macro(f.fold_pat_macro(_binding_0)),
@@ -2411,6 +2665,17 @@ where
     }
 }
 #[cfg(feature = "full")]
+pub fn fold_pat_const<F>(f: &mut F, node: PatConst) -> PatConst
+where
+    F: Fold + ?Sized,
+{
+    PatConst {
+        attrs: FoldHelper::lift(node.attrs, |it| f.fold_attribute(it)),
+        const_token: Token![const](tokens_helper(f, &node.const_token.span)),
+        block: f.fold_block(node.block),
+    }
+}
+#[cfg(feature = "full")]
 pub fn fold_pat_ident<F>(f: &mut F, node: PatIdent) -> PatIdent
 where
     F: Fold + ?Sized,
@@ -2607,6 +2872,20 @@ where
         arguments: f.fold_path_arguments(node.arguments),
     }
 }
+#[cfg(feature = "full")]
+pub fn fold_pointer_mutability<F>(f: &mut F, node: PointerMutability) -> PointerMutability
+where
+    F: Fold + ?Sized,
+{
+    match node {
+        PointerMutability::Const(_binding_0) => {
+            PointerMutability::Const(Token![const](tokens_helper(f, &_binding_0.span)))
+        }
+        PointerMutability::Mut(_binding_0) => {
+            PointerMutability::Mut(Token![mut](tokens_helper(f, &_binding_0.span)))
+        }
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn fold_predicate_eq<F>(f: &mut F, node: PredicateEq) -> PredicateEq
 where
@@ -42,6 +42,52 @@ impl Hash for Arm {
 }
 #[cfg(any(feature = "derive", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for AttrMeta {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        match self {
+            AttrMeta::Path(v0) => {
+                state.write_u8(0u8);
+                v0.hash(state);
+            }
+            AttrMeta::List(v0) => {
+                state.write_u8(1u8);
+                v0.hash(state);
+            }
+            AttrMeta::NameValue(v0) => {
+                state.write_u8(2u8);
+                v0.hash(state);
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for AttrMetaList {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.path.hash(state);
+        self.delimiter.hash(state);
+        TokenStreamHelper(&self.tokens).hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for AttrMetaNameValue {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.path.hash(state);
+        self.value.hash(state);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for AttrStyle {
     fn hash<H>(&self, state: &mut H)
     where
@@ -366,136 +412,146 @@ impl Hash for Expr {
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
-            Expr::Continue(v0) => {
+            Expr::Const(v0) => {
                 state.write_u8(12u8);
                 v0.hash(state);
             }
-            Expr::Field(v0) => {
+            #[cfg(feature = "full")]
+            Expr::Continue(v0) => {
                 state.write_u8(13u8);
                 v0.hash(state);
             }
+            Expr::Field(v0) => {
+                state.write_u8(14u8);
+                v0.hash(state);
+            }
             #[cfg(feature = "full")]
             Expr::ForLoop(v0) => {
-                state.write_u8(14u8);
+                state.write_u8(15u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Group(v0) => {
-                state.write_u8(15u8);
+                state.write_u8(16u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::If(v0) => {
-                state.write_u8(16u8);
+                state.write_u8(17u8);
                 v0.hash(state);
             }
             Expr::Index(v0) => {
-                state.write_u8(17u8);
+                state.write_u8(18u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Let(v0) => {
-                state.write_u8(18u8);
+                state.write_u8(19u8);
                 v0.hash(state);
             }
             Expr::Lit(v0) => {
-                state.write_u8(19u8);
+                state.write_u8(20u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Loop(v0) => {
-                state.write_u8(20u8);
+                state.write_u8(21u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Macro(v0) => {
-                state.write_u8(21u8);
+                state.write_u8(22u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Match(v0) => {
-                state.write_u8(22u8);
+                state.write_u8(23u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::MethodCall(v0) => {
-                state.write_u8(23u8);
+                state.write_u8(24u8);
                 v0.hash(state);
             }
             Expr::Paren(v0) => {
-                state.write_u8(24u8);
+                state.write_u8(25u8);
                 v0.hash(state);
             }
             Expr::Path(v0) => {
-                state.write_u8(25u8);
+                state.write_u8(26u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Range(v0) => {
-                state.write_u8(26u8);
+                state.write_u8(27u8);
+                v0.hash(state);
+            }
+            #[cfg(feature = "full")]
+            Expr::RawAddr(v0) => {
+                state.write_u8(28u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Reference(v0) => {
-                state.write_u8(27u8);
+                state.write_u8(29u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Repeat(v0) => {
-                state.write_u8(28u8);
+                state.write_u8(30u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Return(v0) => {
-                state.write_u8(29u8);
+                state.write_u8(31u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Struct(v0) => {
-                state.write_u8(30u8);
+                state.write_u8(32u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Try(v0) => {
-                state.write_u8(31u8);
+                state.write_u8(33u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::TryBlock(v0) => {
-                state.write_u8(32u8);
+                state.write_u8(34u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Tuple(v0) => {
-                state.write_u8(33u8);
+                state.write_u8(35u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Type(v0) => {
-                state.write_u8(34u8);
+                state.write_u8(36u8);
                 v0.hash(state);
             }
             Expr::Unary(v0) => {
-                state.write_u8(35u8);
+                state.write_u8(37u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Unsafe(v0) => {
-                state.write_u8(36u8);
+                state.write_u8(38u8);
                 v0.hash(state);
             }
             Expr::Verbatim(v0) => {
-                state.write_u8(37u8);
+                state.write_u8(39u8);
                 TokenStreamHelper(v0).hash(state);
             }
             #[cfg(feature = "full")]
             Expr::While(v0) => {
-                state.write_u8(38u8);
+                state.write_u8(40u8);
                 v0.hash(state);
             }
             #[cfg(feature = "full")]
             Expr::Yield(v0) => {
-                state.write_u8(39u8);
+                state.write_u8(41u8);
                 v0.hash(state);
             }
             _ => unreachable!(),
@@ -651,6 +707,17 @@ impl Hash for ExprClosure {
 }
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for ExprConst {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.attrs.hash(state);
+        self.block.hash(state);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for ExprContinue {
     fn hash<H>(&self, state: &mut H)
     where
@@ -832,6 +899,18 @@ impl Hash for ExprRange {
 }
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for ExprRawAddr {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.attrs.hash(state);
+        self.mutability.hash(state);
+        self.expr.hash(state);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for ExprReference {
     fn hash<H>(&self, state: &mut H)
     where
@@ -1364,56 +1443,60 @@ impl Hash for Item {
                 state.write_u8(3u8);
                 v0.hash(state);
             }
-            Item::ForeignMod(v0) => {
+            Item::FnDecl(v0) => {
                 state.write_u8(4u8);
                 v0.hash(state);
             }
-            Item::Impl(v0) => {
+            Item::ForeignMod(v0) => {
                 state.write_u8(5u8);
                 v0.hash(state);
             }
-            Item::Macro(v0) => {
+            Item::Impl(v0) => {
                 state.write_u8(6u8);
                 v0.hash(state);
             }
-            Item::Macro2(v0) => {
+            Item::Macro(v0) => {
                 state.write_u8(7u8);
                 v0.hash(state);
             }
-            Item::Mod(v0) => {
+            Item::Macro2(v0) => {
                 state.write_u8(8u8);
                 v0.hash(state);
             }
-            Item::Static(v0) => {
+            Item::Mod(v0) => {
                 state.write_u8(9u8);
                 v0.hash(state);
             }
-            Item::Struct(v0) => {
+            Item::Static(v0) => {
                 state.write_u8(10u8);
                 v0.hash(state);
             }
-            Item::Trait(v0) => {
+            Item::Struct(v0) => {
                 state.write_u8(11u8);
                 v0.hash(state);
             }
-            Item::TraitAlias(v0) => {
+            Item::Trait(v0) => {
                 state.write_u8(12u8);
                 v0.hash(state);
             }
-            Item::Type(v0) => {
+            Item::TraitAlias(v0) => {
                 state.write_u8(13u8);
                 v0.hash(state);
             }
-            Item::Union(v0) => {
+            Item::Type(v0) => {
                 state.write_u8(14u8);
                 v0.hash(state);
             }
-            Item::Use(v0) => {
+            Item::Union(v0) => {
                 state.write_u8(15u8);
                 v0.hash(state);
             }
-            Item::Verbatim(v0) => {
+            Item::Use(v0) => {
                 state.write_u8(16u8);
+                v0.hash(state);
+            }
+            Item::Verbatim(v0) => {
+                state.write_u8(17u8);
                 TokenStreamHelper(v0).hash(state);
             }
             _ => unreachable!(),
@@ -1476,12 +1559,25 @@ impl Hash for ItemFn {
 }
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for ItemFnDecl {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.attrs.hash(state);
+        self.vis.hash(state);
+        self.sig.hash(state);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for ItemForeignMod {
     fn hash<H>(&self, state: &mut H)
     where
         H: Hasher,
     {
         self.attrs.hash(state);
+        self.unsafety.hash(state);
         self.abi.hash(state);
         self.items.hash(state);
     }
@@ -1537,6 +1633,7 @@ impl Hash for ItemMod {
     {
         self.attrs.hash(state);
         self.vis.hash(state);
+        self.unsafety.hash(state);
         self.ident.hash(state);
         self.content.hash(state);
         self.semi.hash(state);
@@ -1731,6 +1828,17 @@ impl Hash for Local {
         self.init.hash(state);
     }
 }
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for LocalInit {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.expr.hash(state);
+        self.diverge.hash(state);
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for Macro {
@@ -1763,6 +1871,108 @@ impl Hash for MacroDelimiter {
         }
     }
 }
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for MacroGroup {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.delimiter.hash(state);
+        self.content.hash(state);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for MacroMetavar {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.name.hash(state);
+        self.specifier.hash(state);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for MacroRepOp {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        match self {
+            MacroRepOp::ZeroOrMore(_) => {
+                state.write_u8(0u8);
+            }
+            MacroRepOp::OneOrMore(_) => {
+                state.write_u8(1u8);
+            }
+            MacroRepOp::ZeroOrOne(_) => {
+                state.write_u8(2u8);
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for MacroRepetition {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.content.hash(state);
+        self.separator.as_ref().map(TokenStreamHelper).hash(state);
+        self.op.hash(state);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for MacroRule {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.matcher.hash(state);
+        self.transcriber.hash(state);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for MacroRules {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.rules.hash(state);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for MacroTree {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        match self {
+            MacroTree::Verbatim(v0) => {
+                state.write_u8(0u8);
+                TokenStreamHelper(v0).hash(state);
+            }
+            MacroTree::Group(v0) => {
+                state.write_u8(1u8);
+                v0.hash(state);
+            }
+            MacroTree::Metavar(v0) => {
+                state.write_u8(2u8);
+                v0.hash(state);
+            }
+            MacroTree::Repetition(v0) => {
+                state.write_u8(3u8);
+                v0.hash(state);
+            }
+        }
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for Meta {
@@ -1860,64 +2070,68 @@ impl Hash for Pat {
                 state.write_u8(0u8);
                 v0.hash(state);
             }
-            Pat::Ident(v0) => {
+            Pat::Const(v0) => {
                 state.write_u8(1u8);
                 v0.hash(state);
             }
-            Pat::Lit(v0) => {
+            Pat::Ident(v0) => {
                 state.write_u8(2u8);
                 v0.hash(state);
             }
-            Pat::Macro(v0) => {
+            Pat::Lit(v0) => {
                 state.write_u8(3u8);
                 v0.hash(state);
             }
-            Pat::Or(v0) => {
+            Pat::Macro(v0) => {
                 state.write_u8(4u8);
                 v0.hash(state);
             }
-            Pat::Path(v0) => {
+            Pat::Or(v0) => {
                 state.write_u8(5u8);
                 v0.hash(state);
             }
-            Pat::Range(v0) => {
+            Pat::Path(v0) => {
                 state.write_u8(6u8);
                 v0.hash(state);
             }
-            Pat::Reference(v0) => {
+            Pat::Range(v0) => {
                 state.write_u8(7u8);
                 v0.hash(state);
             }
-            Pat::Rest(v0) => {
+            Pat::Reference(v0) => {
                 state.write_u8(8u8);
                 v0.hash(state);
             }
-            Pat::Slice(v0) => {
+            Pat::Rest(v0) => {
                 state.write_u8(9u8);
                 v0.hash(state);
             }
-            Pat::Struct(v0) => {
+            Pat::Slice(v0) => {
                 state.write_u8(10u8);
                 v0.hash(state);
             }
-            Pat::Tuple(v0) => {
+            Pat::Struct(v0) => {
                 state.write_u8(11u8);
                 v0.hash(state);
             }
-            Pat::TupleStruct(v0) => {
+            Pat::Tuple(v0) => {
                 state.write_u8(12u8);
                 v0.hash(state);
             }
-            Pat::Type(v0) => {
+            Pat::TupleStruct(v0) => {
                 state.write_u8(13u8);
                 v0.hash(state);
             }
-            Pat::Verbatim(v0) => {
+            Pat::Type(v0) => {
                 state.write_u8(14u8);
+                v0.hash(state);
+            }
+            Pat::Verbatim(v0) => {
+                state.write_u8(15u8);
                 TokenStreamHelper(v0).hash(state);
             }
             Pat::Wild(v0) => {
-                state.write_u8(15u8);
+                state.write_u8(16u8);
                 v0.hash(state);
             }
             _ => unreachable!(),
@@ -1937,6 +2151,17 @@ impl Hash for PatBox {
 }
 #[cfg(feature = "full")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for PatConst {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.attrs.hash(state);
+        self.block.hash(state);
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for PatIdent {
     fn hash<H>(&self, state: &mut H)
     where
@@ -2143,6 +2368,23 @@ impl Hash for PathSegment {
         self.arguments.hash(state);
     }
 }
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
+impl Hash for PointerMutability {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        match self {
+            PointerMutability::Const(_) => {
+                state.write_u8(0u8);
+            }
+            PointerMutability::Mut(_) => {
+                state.write_u8(1u8);
+            }
+        }
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "extra-traits")))]
 impl Hash for PredicateEq {
@@ -0,0 +1,14003 @@
+// This file is @generated by syn-internal-codegen.
+// It is not intended for manual editing.
+
+use crate::gen::helper::serde::*;
+use crate::*;
+use proc_macro2::Span;
+use serde::de::{
+    self, Deserialize, Deserializer, EnumAccess, IgnoredAny, MapAccess, SeqAccess, VariantAccess,
+    Visitor,
+};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+use std::fmt;
+use std::result::Result;
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Abi {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Abi", 1usize)?;
+        state.serialize_field("name", &self.name)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Abi {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["name"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = Abi;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Abi")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let name = element(&mut seq, 0usize, &self)?;
+                Ok(Abi {
+                    extern_token: Default::default(),
+                    name,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut name = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "name" => name = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Abi {
+                    extern_token: Default::default(),
+                    name: name.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Abi", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for AngleBracketedGenericArguments {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("AngleBracketedGenericArguments", 2usize)?;
+        state.serialize_field("colon2_token", &self.colon2_token.is_some())?;
+        state.serialize_field("args", &self.args)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for AngleBracketedGenericArguments {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["colon2_token", "args"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = AngleBracketedGenericArguments;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct AngleBracketedGenericArguments")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let colon2_token = present(element::<bool, _>(&mut seq, 0usize, &self)?);
+                let args = element(&mut seq, 1usize, &self)?;
+                Ok(AngleBracketedGenericArguments {
+                    colon2_token,
+                    lt_token: Default::default(),
+                    args,
+                    gt_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut colon2_token = None;
+                let mut args = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "colon2_token" => colon2_token = Some(present(map.next_value::<bool>()?)),
+                        "args" => args = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(AngleBracketedGenericArguments {
+                    colon2_token: colon2_token.unwrap_or_default(),
+                    lt_token: Default::default(),
+                    args: args.unwrap_or_default(),
+                    gt_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("AngleBracketedGenericArguments", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Arm {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Arm", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("pat", &self.pat)?;
+        state.serialize_field("guard", &self.guard.as_ref().map(|(_, v1)| v1))?;
+        state.serialize_field("body", &self.body)?;
+        state.serialize_field("comma", &self.comma.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Arm {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "pat", "guard", "body", "comma"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = Arm;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Arm")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let pat = element(&mut seq, 1usize, &self)?;
+                let guard = element::<Option<_>, _>(&mut seq, 2usize, &self)?
+                    .map(|v1| (Default::default(), v1));
+                let body = element(&mut seq, 3usize, &self)?;
+                let comma = present(element::<bool, _>(&mut seq, 4usize, &self)?);
+                Ok(Arm {
+                    attrs,
+                    pat,
+                    guard,
+                    fat_arrow_token: Default::default(),
+                    body,
+                    comma,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut pat = None;
+                let mut guard = None;
+                let mut body = None;
+                let mut comma = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "pat" => pat = Some(map.next_value()?),
+                        "guard" => {
+                            guard = Some(
+                                map.next_value::<Option<_>>()?
+                                    .map(|v1| (Default::default(), v1)),
+                            )
+                        }
+                        "body" => body = Some(map.next_value()?),
+                        "comma" => comma = Some(present(map.next_value::<bool>()?)),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Arm {
+                    attrs: attrs.unwrap_or_default(),
+                    pat: required(pat, "pat")?,
+                    guard: guard.unwrap_or_default(),
+                    fat_arrow_token: Default::default(),
+                    body: required(body, "body")?,
+                    comma: comma.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Arm", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for AttrMeta {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            AttrMeta::Path(v0) => {
+                serializer.serialize_newtype_variant("AttrMeta", 0u32, "Path", v0)
+            }
+            AttrMeta::List(v0) => {
+                serializer.serialize_newtype_variant("AttrMeta", 1u32, "List", v0)
+            }
+            AttrMeta::NameValue(v0) => {
+                serializer.serialize_newtype_variant("AttrMeta", 2u32, "NameValue", v0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for AttrMeta {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Path", "List", "NameValue"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = AttrMeta;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum AttrMeta")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Path" => {
+                        let v = access.newtype_variant()?;
+                        Ok(AttrMeta::Path(v))
+                    }
+                    "List" => {
+                        let v = access.newtype_variant()?;
+                        Ok(AttrMeta::List(v))
+                    }
+                    "NameValue" => {
+                        let v = access.newtype_variant()?;
+                        Ok(AttrMeta::NameValue(v))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("AttrMeta", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for AttrMetaList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("AttrMetaList", 3usize)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("delimiter", &self.delimiter)?;
+        state.serialize_field("tokens", &Ser(&self.tokens))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for AttrMetaList {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["path", "delimiter", "tokens"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = AttrMetaList;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct AttrMetaList")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let path = element(&mut seq, 0usize, &self)?;
+                let delimiter = element(&mut seq, 1usize, &self)?;
+                let tokens = element::<De<_>, _>(&mut seq, 2usize, &self)?.0;
+                Ok(AttrMetaList {
+                    path,
+                    delimiter,
+                    tokens,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut path = None;
+                let mut delimiter = None;
+                let mut tokens = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "path" => path = Some(map.next_value()?),
+                        "delimiter" => delimiter = Some(map.next_value()?),
+                        "tokens" => tokens = Some(map.next_value::<De<_>>()?.0),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(AttrMetaList {
+                    path: required(path, "path")?,
+                    delimiter: required(delimiter, "delimiter")?,
+                    tokens: required(tokens, "tokens")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("AttrMetaList", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for AttrMetaNameValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("AttrMetaNameValue", 2usize)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("value", &self.value)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for AttrMetaNameValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["path", "value"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = AttrMetaNameValue;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct AttrMetaNameValue")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let path = element(&mut seq, 0usize, &self)?;
+                let value = element(&mut seq, 1usize, &self)?;
+                Ok(AttrMetaNameValue {
+                    path,
+                    eq_token: Default::default(),
+                    value,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut path = None;
+                let mut value = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "path" => path = Some(map.next_value()?),
+                        "value" => value = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(AttrMetaNameValue {
+                    path: required(path, "path")?,
+                    eq_token: Default::default(),
+                    value: required(value, "value")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("AttrMetaNameValue", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for AttrStyle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            AttrStyle::Outer => serializer.serialize_unit_variant("AttrStyle", 0u32, "Outer"),
+            AttrStyle::Inner(_) => serializer.serialize_unit_variant("AttrStyle", 1u32, "Inner"),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for AttrStyle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Outer", "Inner"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = AttrStyle;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum AttrStyle")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Outer" => {
+                        access.unit_variant()?;
+                        Ok(AttrStyle::Outer)
+                    }
+                    "Inner" => {
+                        access.unit_variant()?;
+                        Ok(AttrStyle::Inner(Default::default()))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("AttrStyle", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Attribute {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Attribute", 3usize)?;
+        state.serialize_field("style", &self.style)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("tokens", &Ser(&self.tokens))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Attribute {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["style", "path", "tokens"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = Attribute;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Attribute")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let style = element(&mut seq, 0usize, &self)?;
+                let path = element(&mut seq, 1usize, &self)?;
+                let tokens = element::<De<_>, _>(&mut seq, 2usize, &self)?.0;
+                Ok(Attribute {
+                    pound_token: Default::default(),
+                    style,
+                    bracket_token: Default::default(),
+                    path,
+                    tokens,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut style = None;
+                let mut path = None;
+                let mut tokens = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "style" => style = Some(map.next_value()?),
+                        "path" => path = Some(map.next_value()?),
+                        "tokens" => tokens = Some(map.next_value::<De<_>>()?.0),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Attribute {
+                    pound_token: Default::default(),
+                    style: required(style, "style")?,
+                    bracket_token: Default::default(),
+                    path: required(path, "path")?,
+                    tokens: required(tokens, "tokens")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Attribute", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for BareFnArg {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("BareFnArg", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("name", &self.name.as_ref().map(|(v0, _)| Ser(v0)))?;
+        state.serialize_field("ty", &self.ty)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for BareFnArg {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "name", "ty"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = BareFnArg;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct BareFnArg")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let name = element::<Option<De<_>>, _>(&mut seq, 1usize, &self)?
+                    .map(|v0| (v0.0, Default::default()));
+                let ty = element(&mut seq, 2usize, &self)?;
+                Ok(BareFnArg { attrs, name, ty })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut name = None;
+                let mut ty = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "name" => {
+                            name = Some(
+                                map.next_value::<Option<De<_>>>()?
+                                    .map(|v0| (v0.0, Default::default())),
+                            )
+                        }
+                        "ty" => ty = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(BareFnArg {
+                    attrs: attrs.unwrap_or_default(),
+                    name: name.unwrap_or_default(),
+                    ty: required(ty, "ty")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("BareFnArg", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for BinOp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            BinOp::Add(_) => serializer.serialize_unit_variant("BinOp", 0u32, "Add"),
+            BinOp::Sub(_) => serializer.serialize_unit_variant("BinOp", 1u32, "Sub"),
+            BinOp::Mul(_) => serializer.serialize_unit_variant("BinOp", 2u32, "Mul"),
+            BinOp::Div(_) => serializer.serialize_unit_variant("BinOp", 3u32, "Div"),
+            BinOp::Rem(_) => serializer.serialize_unit_variant("BinOp", 4u32, "Rem"),
+            BinOp::And(_) => serializer.serialize_unit_variant("BinOp", 5u32, "And"),
+            BinOp::Or(_) => serializer.serialize_unit_variant("BinOp", 6u32, "Or"),
+            BinOp::BitXor(_) => serializer.serialize_unit_variant("BinOp", 7u32, "BitXor"),
+            BinOp::BitAnd(_) => serializer.serialize_unit_variant("BinOp", 8u32, "BitAnd"),
+            BinOp::BitOr(_) => serializer.serialize_unit_variant("BinOp", 9u32, "BitOr"),
+            BinOp::Shl(_) => serializer.serialize_unit_variant("BinOp", 10u32, "Shl"),
+            BinOp::Shr(_) => serializer.serialize_unit_variant("BinOp", 11u32, "Shr"),
+            BinOp::Eq(_) => serializer.serialize_unit_variant("BinOp", 12u32, "Eq"),
+            BinOp::Lt(_) => serializer.serialize_unit_variant("BinOp", 13u32, "Lt"),
+            BinOp::Le(_) => serializer.serialize_unit_variant("BinOp", 14u32, "Le"),
+            BinOp::Ne(_) => serializer.serialize_unit_variant("BinOp", 15u32, "Ne"),
+            BinOp::Ge(_) => serializer.serialize_unit_variant("BinOp", 16u32, "Ge"),
+            BinOp::Gt(_) => serializer.serialize_unit_variant("BinOp", 17u32, "Gt"),
+            BinOp::AddEq(_) => serializer.serialize_unit_variant("BinOp", 18u32, "AddEq"),
+            BinOp::SubEq(_) => serializer.serialize_unit_variant("BinOp", 19u32, "SubEq"),
+            BinOp::MulEq(_) => serializer.serialize_unit_variant("BinOp", 20u32, "MulEq"),
+            BinOp::DivEq(_) => serializer.serialize_unit_variant("BinOp", 21u32, "DivEq"),
+            BinOp::RemEq(_) => serializer.serialize_unit_variant("BinOp", 22u32, "RemEq"),
+            BinOp::BitXorEq(_) => serializer.serialize_unit_variant("BinOp", 23u32, "BitXorEq"),
+            BinOp::BitAndEq(_) => serializer.serialize_unit_variant("BinOp", 24u32, "BitAndEq"),
+            BinOp::BitOrEq(_) => serializer.serialize_unit_variant("BinOp", 25u32, "BitOrEq"),
+            BinOp::ShlEq(_) => serializer.serialize_unit_variant("BinOp", 26u32, "ShlEq"),
+            BinOp::ShrEq(_) => serializer.serialize_unit_variant("BinOp", 27u32, "ShrEq"),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for BinOp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &[
+            "Add", "Sub", "Mul", "Div", "Rem", "And", "Or", "BitXor", "BitAnd", "BitOr", "Shl",
+            "Shr", "Eq", "Lt", "Le", "Ne", "Ge", "Gt", "AddEq", "SubEq", "MulEq", "DivEq", "RemEq",
+            "BitXorEq", "BitAndEq", "BitOrEq", "ShlEq", "ShrEq",
+        ];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = BinOp;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum BinOp")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Add" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::Add(Default::default()))
+                    }
+                    "Sub" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::Sub(Default::default()))
+                    }
+                    "Mul" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::Mul(Default::default()))
+                    }
+                    "Div" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::Div(Default::default()))
+                    }
+                    "Rem" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::Rem(Default::default()))
+                    }
+                    "And" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::And(Default::default()))
+                    }
+                    "Or" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::Or(Default::default()))
+                    }
+                    "BitXor" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::BitXor(Default::default()))
+                    }
+                    "BitAnd" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::BitAnd(Default::default()))
+                    }
+                    "BitOr" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::BitOr(Default::default()))
+                    }
+                    "Shl" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::Shl(Default::default()))
+                    }
+                    "Shr" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::Shr(Default::default()))
+                    }
+                    "Eq" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::Eq(Default::default()))
+                    }
+                    "Lt" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::Lt(Default::default()))
+                    }
+                    "Le" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::Le(Default::default()))
+                    }
+                    "Ne" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::Ne(Default::default()))
+                    }
+                    "Ge" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::Ge(Default::default()))
+                    }
+                    "Gt" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::Gt(Default::default()))
+                    }
+                    "AddEq" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::AddEq(Default::default()))
+                    }
+                    "SubEq" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::SubEq(Default::default()))
+                    }
+                    "MulEq" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::MulEq(Default::default()))
+                    }
+                    "DivEq" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::DivEq(Default::default()))
+                    }
+                    "RemEq" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::RemEq(Default::default()))
+                    }
+                    "BitXorEq" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::BitXorEq(Default::default()))
+                    }
+                    "BitAndEq" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::BitAndEq(Default::default()))
+                    }
+                    "BitOrEq" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::BitOrEq(Default::default()))
+                    }
+                    "ShlEq" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::ShlEq(Default::default()))
+                    }
+                    "ShrEq" => {
+                        access.unit_variant()?;
+                        Ok(BinOp::ShrEq(Default::default()))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("BinOp", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Binding {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Binding", 2usize)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("ty", &self.ty)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Binding {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident", "ty"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = Binding;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Binding")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let ident = element::<De<_>, _>(&mut seq, 0usize, &self)?.0;
+                let ty = element(&mut seq, 1usize, &self)?;
+                Ok(Binding {
+                    ident,
+                    eq_token: Default::default(),
+                    ty,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut ident = None;
+                let mut ty = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        "ty" => ty = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Binding {
+                    ident: required(ident, "ident")?,
+                    eq_token: Default::default(),
+                    ty: required(ty, "ty")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Binding", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Block {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Block", 1usize)?;
+        state.serialize_field("stmts", &self.stmts)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Block {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["stmts"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = Block;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Block")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let stmts = element(&mut seq, 0usize, &self)?;
+                Ok(Block {
+                    brace_token: Default::default(),
+                    stmts,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut stmts = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "stmts" => stmts = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Block {
+                    brace_token: Default::default(),
+                    stmts: stmts.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Block", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for BoundLifetimes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("BoundLifetimes", 1usize)?;
+        state.serialize_field("lifetimes", &self.lifetimes)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for BoundLifetimes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["lifetimes"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = BoundLifetimes;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct BoundLifetimes")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let lifetimes = element(&mut seq, 0usize, &self)?;
+                Ok(BoundLifetimes {
+                    for_token: Default::default(),
+                    lt_token: Default::default(),
+                    lifetimes,
+                    gt_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut lifetimes = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "lifetimes" => lifetimes = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(BoundLifetimes {
+                    for_token: Default::default(),
+                    lt_token: Default::default(),
+                    lifetimes: lifetimes.unwrap_or_default(),
+                    gt_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("BoundLifetimes", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ConstParam {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ConstParam", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("ty", &self.ty)?;
+        state.serialize_field("eq_token", &self.eq_token.is_some())?;
+        state.serialize_field("default", &self.default)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ConstParam {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "ident", "ty", "eq_token", "default"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ConstParam;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ConstParam")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let ident = element::<De<_>, _>(&mut seq, 1usize, &self)?.0;
+                let ty = element(&mut seq, 2usize, &self)?;
+                let eq_token = present(element::<bool, _>(&mut seq, 3usize, &self)?);
+                let default = element(&mut seq, 4usize, &self)?;
+                Ok(ConstParam {
+                    attrs,
+                    const_token: Default::default(),
+                    ident,
+                    colon_token: Default::default(),
+                    ty,
+                    eq_token,
+                    default,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut ident = None;
+                let mut ty = None;
+                let mut eq_token = None;
+                let mut default = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        "ty" => ty = Some(map.next_value()?),
+                        "eq_token" => eq_token = Some(present(map.next_value::<bool>()?)),
+                        "default" => default = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ConstParam {
+                    attrs: attrs.unwrap_or_default(),
+                    const_token: Default::default(),
+                    ident: required(ident, "ident")?,
+                    colon_token: Default::default(),
+                    ty: required(ty, "ty")?,
+                    eq_token: eq_token.unwrap_or_default(),
+                    default: default.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ConstParam", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Constraint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Constraint", 2usize)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("bounds", &self.bounds)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Constraint {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident", "bounds"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = Constraint;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Constraint")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let ident = element::<De<_>, _>(&mut seq, 0usize, &self)?.0;
+                let bounds = element(&mut seq, 1usize, &self)?;
+                Ok(Constraint {
+                    ident,
+                    colon_token: Default::default(),
+                    bounds,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut ident = None;
+                let mut bounds = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        "bounds" => bounds = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Constraint {
+                    ident: required(ident, "ident")?,
+                    colon_token: Default::default(),
+                    bounds: bounds.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Constraint", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Data {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Data::Struct(v0) => serializer.serialize_newtype_variant("Data", 0u32, "Struct", v0),
+            Data::Enum(v0) => serializer.serialize_newtype_variant("Data", 1u32, "Enum", v0),
+            Data::Union(v0) => serializer.serialize_newtype_variant("Data", 2u32, "Union", v0),
+        }
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Data {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Struct", "Enum", "Union"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = Data;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Data")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Struct" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Data::Struct(v))
+                    }
+                    "Enum" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Data::Enum(v))
+                    }
+                    "Union" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Data::Union(v))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Data", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for DataEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("DataEnum", 1usize)?;
+        state.serialize_field("variants", &self.variants)?;
+        state.end()
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for DataEnum {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["variants"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = DataEnum;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct DataEnum")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let variants = element(&mut seq, 0usize, &self)?;
+                Ok(DataEnum {
+                    enum_token: Default::default(),
+                    brace_token: Default::default(),
+                    variants,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut variants = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "variants" => variants = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DataEnum {
+                    enum_token: Default::default(),
+                    brace_token: Default::default(),
+                    variants: variants.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("DataEnum", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for DataStruct {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("DataStruct", 2usize)?;
+        state.serialize_field("fields", &self.fields)?;
+        state.serialize_field("semi_token", &self.semi_token.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for DataStruct {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["fields", "semi_token"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = DataStruct;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct DataStruct")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let fields = element(&mut seq, 0usize, &self)?;
+                let semi_token = present(element::<bool, _>(&mut seq, 1usize, &self)?);
+                Ok(DataStruct {
+                    struct_token: Default::default(),
+                    fields,
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut fields = None;
+                let mut semi_token = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "fields" => fields = Some(map.next_value()?),
+                        "semi_token" => semi_token = Some(present(map.next_value::<bool>()?)),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DataStruct {
+                    struct_token: Default::default(),
+                    fields: required(fields, "fields")?,
+                    semi_token: semi_token.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("DataStruct", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for DataUnion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("DataUnion", 1usize)?;
+        state.serialize_field("fields", &self.fields)?;
+        state.end()
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for DataUnion {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["fields"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = DataUnion;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct DataUnion")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let fields = element(&mut seq, 0usize, &self)?;
+                Ok(DataUnion {
+                    union_token: Default::default(),
+                    fields,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut fields = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "fields" => fields = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DataUnion {
+                    union_token: Default::default(),
+                    fields: required(fields, "fields")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("DataUnion", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for DeriveInput {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("DeriveInput", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("data", &self.data)?;
+        state.end()
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for DeriveInput {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "generics", "data"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = DeriveInput;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct DeriveInput")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let vis = element(&mut seq, 1usize, &self)?;
+                let ident = element::<De<_>, _>(&mut seq, 2usize, &self)?.0;
+                let generics = element(&mut seq, 3usize, &self)?;
+                let data = element(&mut seq, 4usize, &self)?;
+                Ok(DeriveInput {
+                    attrs,
+                    vis,
+                    ident,
+                    generics,
+                    data,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut ident = None;
+                let mut generics = None;
+                let mut data = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "vis" => vis = Some(map.next_value()?),
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        "generics" => generics = Some(map.next_value()?),
+                        "data" => data = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DeriveInput {
+                    attrs: attrs.unwrap_or_default(),
+                    vis: required(vis, "vis")?,
+                    ident: required(ident, "ident")?,
+                    generics: required(generics, "generics")?,
+                    data: required(data, "data")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("DeriveInput", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Expr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            #[cfg(feature = "full")]
+            Expr::Array(v0) => serializer.serialize_newtype_variant("Expr", 0u32, "Array", v0),
+            #[cfg(feature = "full")]
+            Expr::Assign(v0) => serializer.serialize_newtype_variant("Expr", 1u32, "Assign", v0),
+            #[cfg(feature = "full")]
+            Expr::AssignOp(v0) => {
+                serializer.serialize_newtype_variant("Expr", 2u32, "AssignOp", v0)
+            }
+            #[cfg(feature = "full")]
+            Expr::Async(v0) => serializer.serialize_newtype_variant("Expr", 3u32, "Async", v0),
+            #[cfg(feature = "full")]
+            Expr::Await(v0) => serializer.serialize_newtype_variant("Expr", 4u32, "Await", v0),
+            Expr::Binary(v0) => serializer.serialize_newtype_variant("Expr", 5u32, "Binary", v0),
+            #[cfg(feature = "full")]
+            Expr::Block(v0) => serializer.serialize_newtype_variant("Expr", 6u32, "Block", v0),
+            #[cfg(feature = "full")]
+            Expr::Box(v0) => serializer.serialize_newtype_variant("Expr", 7u32, "Box", v0),
+            #[cfg(feature = "full")]
+            Expr::Break(v0) => serializer.serialize_newtype_variant("Expr", 8u32, "Break", v0),
+            Expr::Call(v0) => serializer.serialize_newtype_variant("Expr", 9u32, "Call", v0),
+            Expr::Cast(v0) => serializer.serialize_newtype_variant("Expr", 10u32, "Cast", v0),
+            #[cfg(feature = "full")]
+            Expr::Closure(v0) => serializer.serialize_newtype_variant("Expr", 11u32, "Closure", v0),
+            #[cfg(feature = "full")]
+            Expr::Const(v0) => serializer.serialize_newtype_variant("Expr", 12u32, "Const", v0),
+            #[cfg(feature = "full")]
+            Expr::Continue(v0) => {
+                serializer.serialize_newtype_variant("Expr", 13u32, "Continue", v0)
+            }
+            Expr::Field(v0) => serializer.serialize_newtype_variant("Expr", 14u32, "Field", v0),
+            #[cfg(feature = "full")]
+            Expr::ForLoop(v0) => serializer.serialize_newtype_variant("Expr", 15u32, "ForLoop", v0),
+            #[cfg(feature = "full")]
+            Expr::Group(v0) => serializer.serialize_newtype_variant("Expr", 16u32, "Group", v0),
+            #[cfg(feature = "full")]
+            Expr::If(v0) => serializer.serialize_newtype_variant("Expr", 17u32, "If", v0),
+            Expr::Index(v0) => serializer.serialize_newtype_variant("Expr", 18u32, "Index", v0),
+            #[cfg(feature = "full")]
+            Expr::Let(v0) => serializer.serialize_newtype_variant("Expr", 19u32, "Let", v0),
+            Expr::Lit(v0) => serializer.serialize_newtype_variant("Expr", 20u32, "Lit", v0),
+            #[cfg(feature = "full")]
+            Expr::Loop(v0) => serializer.serialize_newtype_variant("Expr", 21u32, "Loop", v0),
+            #[cfg(feature = "full")]
+            Expr::Macro(v0) => serializer.serialize_newtype_variant("Expr", 22u32, "Macro", v0),
+            #[cfg(feature = "full")]
+            Expr::Match(v0) => serializer.serialize_newtype_variant("Expr", 23u32, "Match", v0),
+            #[cfg(feature = "full")]
+            Expr::MethodCall(v0) => {
+                serializer.serialize_newtype_variant("Expr", 24u32, "MethodCall", v0)
+            }
+            Expr::Paren(v0) => serializer.serialize_newtype_variant("Expr", 25u32, "Paren", v0),
+            Expr::Path(v0) => serializer.serialize_newtype_variant("Expr", 26u32, "Path", v0),
+            #[cfg(feature = "full")]
+            Expr::Range(v0) => serializer.serialize_newtype_variant("Expr", 27u32, "Range", v0),
+            #[cfg(feature = "full")]
+            Expr::RawAddr(v0) => serializer.serialize_newtype_variant("Expr", 28u32, "RawAddr", v0),
+            #[cfg(feature = "full")]
+            Expr::Reference(v0) => {
+                serializer.serialize_newtype_variant("Expr", 29u32, "Reference", v0)
+            }
+            #[cfg(feature = "full")]
+            Expr::Repeat(v0) => serializer.serialize_newtype_variant("Expr", 30u32, "Repeat", v0),
+            #[cfg(feature = "full")]
+            Expr::Return(v0) => serializer.serialize_newtype_variant("Expr", 31u32, "Return", v0),
+            #[cfg(feature = "full")]
+            Expr::Struct(v0) => serializer.serialize_newtype_variant("Expr", 32u32, "Struct", v0),
+            #[cfg(feature = "full")]
+            Expr::Try(v0) => serializer.serialize_newtype_variant("Expr", 33u32, "Try", v0),
+            #[cfg(feature = "full")]
+            Expr::TryBlock(v0) => {
+                serializer.serialize_newtype_variant("Expr", 34u32, "TryBlock", v0)
+            }
+            #[cfg(feature = "full")]
+            Expr::Tuple(v0) => serializer.serialize_newtype_variant("Expr", 35u32, "Tuple", v0),
+            #[cfg(feature = "full")]
+            Expr::Type(v0) => serializer.serialize_newtype_variant("Expr", 36u32, "Type", v0),
+            Expr::Unary(v0) => serializer.serialize_newtype_variant("Expr", 37u32, "Unary", v0),
+            #[cfg(feature = "full")]
+            Expr::Unsafe(v0) => serializer.serialize_newtype_variant("Expr", 38u32, "Unsafe", v0),
+            Expr::Verbatim(v0) => {
+                serializer.serialize_newtype_variant("Expr", 39u32, "Verbatim", &Ser(v0))
+            }
+            #[cfg(feature = "full")]
+            Expr::While(v0) => serializer.serialize_newtype_variant("Expr", 40u32, "While", v0),
+            #[cfg(feature = "full")]
+            Expr::Yield(v0) => serializer.serialize_newtype_variant("Expr", 41u32, "Yield", v0),
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Expr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &[
+            "Array",
+            "Assign",
+            "AssignOp",
+            "Async",
+            "Await",
+            "Binary",
+            "Block",
+            "Box",
+            "Break",
+            "Call",
+            "Cast",
+            "Closure",
+            "Const",
+            "Continue",
+            "Field",
+            "ForLoop",
+            "Group",
+            "If",
+            "Index",
+            "Let",
+            "Lit",
+            "Loop",
+            "Macro",
+            "Match",
+            "MethodCall",
+            "Paren",
+            "Path",
+            "Range",
+            "RawAddr",
+            "Reference",
+            "Repeat",
+            "Return",
+            "Struct",
+            "Try",
+            "TryBlock",
+            "Tuple",
+            "Type",
+            "Unary",
+            "Unsafe",
+            "Verbatim",
+            "While",
+            "Yield",
+        ];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = Expr;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Expr")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    #[cfg(feature = "full")]
+                    "Array" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Array(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "Assign" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Assign(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "AssignOp" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::AssignOp(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "Async" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Async(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "Await" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Await(v))
+                    }
+                    "Binary" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Binary(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "Block" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Block(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "Box" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Box(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "Break" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Break(v))
+                    }
+                    "Call" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Call(v))
+                    }
+                    "Cast" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Cast(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "Closure" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Closure(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "Const" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Const(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "Continue" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Continue(v))
+                    }
+                    "Field" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Field(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "ForLoop" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::ForLoop(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "Group" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Group(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "If" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::If(v))
+                    }
+                    "Index" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Index(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "Let" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Let(v))
+                    }
+                    "Lit" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Lit(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "Loop" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Loop(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "Macro" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Macro(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "Match" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Match(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "MethodCall" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::MethodCall(v))
+                    }
+                    "Paren" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Paren(v))
+                    }
+                    "Path" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Path(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "Range" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Range(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "RawAddr" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::RawAddr(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "Reference" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Reference(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "Repeat" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Repeat(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "Return" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Return(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "Struct" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Struct(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "Try" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Try(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "TryBlock" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::TryBlock(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "Tuple" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Tuple(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "Type" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Type(v))
+                    }
+                    "Unary" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Unary(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "Unsafe" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Unsafe(v))
+                    }
+                    "Verbatim" => {
+                        let v = access.newtype_variant::<De<_>>()?;
+                        Ok(Expr::Verbatim(v.0))
+                    }
+                    #[cfg(feature = "full")]
+                    "While" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::While(v))
+                    }
+                    #[cfg(feature = "full")]
+                    "Yield" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Expr::Yield(v))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Expr", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprArray {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprArray", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("elems", &self.elems)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprArray {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "elems"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprArray;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprArray")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let elems = element(&mut seq, 1usize, &self)?;
+                Ok(ExprArray {
+                    attrs,
+                    bracket_token: Default::default(),
+                    elems,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut elems = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "elems" => elems = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprArray {
+                    attrs: attrs.unwrap_or_default(),
+                    bracket_token: Default::default(),
+                    elems: elems.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprArray", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprAssign {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprAssign", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("left", &self.left)?;
+        state.serialize_field("right", &self.right)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprAssign {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "left", "right"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprAssign;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprAssign")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let left = element(&mut seq, 1usize, &self)?;
+                let right = element(&mut seq, 2usize, &self)?;
+                Ok(ExprAssign {
+                    attrs,
+                    left,
+                    eq_token: Default::default(),
+                    right,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut left = None;
+                let mut right = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "left" => left = Some(map.next_value()?),
+                        "right" => right = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprAssign {
+                    attrs: attrs.unwrap_or_default(),
+                    left: required(left, "left")?,
+                    eq_token: Default::default(),
+                    right: required(right, "right")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprAssign", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprAssignOp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprAssignOp", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("left", &self.left)?;
+        state.serialize_field("op", &self.op)?;
+        state.serialize_field("right", &self.right)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprAssignOp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "left", "op", "right"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprAssignOp;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprAssignOp")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let left = element(&mut seq, 1usize, &self)?;
+                let op = element(&mut seq, 2usize, &self)?;
+                let right = element(&mut seq, 3usize, &self)?;
+                Ok(ExprAssignOp {
+                    attrs,
+                    left,
+                    op,
+                    right,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut left = None;
+                let mut op = None;
+                let mut right = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "left" => left = Some(map.next_value()?),
+                        "op" => op = Some(map.next_value()?),
+                        "right" => right = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprAssignOp {
+                    attrs: attrs.unwrap_or_default(),
+                    left: required(left, "left")?,
+                    op: required(op, "op")?,
+                    right: required(right, "right")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprAssignOp", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprAsync {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprAsync", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("capture", &self.capture.is_some())?;
+        state.serialize_field("block", &self.block)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprAsync {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "capture", "block"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprAsync;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprAsync")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let capture = present(element::<bool, _>(&mut seq, 1usize, &self)?);
+                let block = element(&mut seq, 2usize, &self)?;
+                Ok(ExprAsync {
+                    attrs,
+                    async_token: Default::default(),
+                    capture,
+                    block,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut capture = None;
+                let mut block = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "capture" => capture = Some(present(map.next_value::<bool>()?)),
+                        "block" => block = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprAsync {
+                    attrs: attrs.unwrap_or_default(),
+                    async_token: Default::default(),
+                    capture: capture.unwrap_or_default(),
+                    block: required(block, "block")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprAsync", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprAwait {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprAwait", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("base", &self.base)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprAwait {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "base"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprAwait;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprAwait")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let base = element(&mut seq, 1usize, &self)?;
+                Ok(ExprAwait {
+                    attrs,
+                    base,
+                    dot_token: Default::default(),
+                    await_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut base = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "base" => base = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprAwait {
+                    attrs: attrs.unwrap_or_default(),
+                    base: required(base, "base")?,
+                    dot_token: Default::default(),
+                    await_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprAwait", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprBinary {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprBinary", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("left", &self.left)?;
+        state.serialize_field("op", &self.op)?;
+        state.serialize_field("right", &self.right)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprBinary {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "left", "op", "right"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprBinary;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprBinary")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let left = element(&mut seq, 1usize, &self)?;
+                let op = element(&mut seq, 2usize, &self)?;
+                let right = element(&mut seq, 3usize, &self)?;
+                Ok(ExprBinary {
+                    attrs,
+                    left,
+                    op,
+                    right,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut left = None;
+                let mut op = None;
+                let mut right = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "left" => left = Some(map.next_value()?),
+                        "op" => op = Some(map.next_value()?),
+                        "right" => right = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprBinary {
+                    attrs: attrs.unwrap_or_default(),
+                    left: required(left, "left")?,
+                    op: required(op, "op")?,
+                    right: required(right, "right")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprBinary", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprBlock {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprBlock", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("label", &self.label)?;
+        state.serialize_field("block", &self.block)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprBlock {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "label", "block"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprBlock;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprBlock")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let label = element(&mut seq, 1usize, &self)?;
+                let block = element(&mut seq, 2usize, &self)?;
+                Ok(ExprBlock {
+                    attrs,
+                    label,
+                    block,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut label = None;
+                let mut block = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "label" => label = Some(map.next_value()?),
+                        "block" => block = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprBlock {
+                    attrs: attrs.unwrap_or_default(),
+                    label: label.unwrap_or_default(),
+                    block: required(block, "block")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprBlock", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprBox {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprBox", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprBox {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprBox;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprBox")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let expr = element(&mut seq, 1usize, &self)?;
+                Ok(ExprBox {
+                    attrs,
+                    box_token: Default::default(),
+                    expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut expr = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "expr" => expr = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprBox {
+                    attrs: attrs.unwrap_or_default(),
+                    box_token: Default::default(),
+                    expr: required(expr, "expr")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprBox", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprBreak {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprBreak", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("label", &self.label)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprBreak {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "label", "expr"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprBreak;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprBreak")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let label = element(&mut seq, 1usize, &self)?;
+                let expr = element(&mut seq, 2usize, &self)?;
+                Ok(ExprBreak {
+                    attrs,
+                    break_token: Default::default(),
+                    label,
+                    expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut label = None;
+                let mut expr = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "label" => label = Some(map.next_value()?),
+                        "expr" => expr = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprBreak {
+                    attrs: attrs.unwrap_or_default(),
+                    break_token: Default::default(),
+                    label: label.unwrap_or_default(),
+                    expr: expr.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprBreak", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprCall {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprCall", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("func", &self.func)?;
+        state.serialize_field("args", &self.args)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprCall {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "func", "args"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprCall;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprCall")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let func = element(&mut seq, 1usize, &self)?;
+                let args = element(&mut seq, 2usize, &self)?;
+                Ok(ExprCall {
+                    attrs,
+                    func,
+                    paren_token: Default::default(),
+                    args,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut func = None;
+                let mut args = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "func" => func = Some(map.next_value()?),
+                        "args" => args = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprCall {
+                    attrs: attrs.unwrap_or_default(),
+                    func: required(func, "func")?,
+                    paren_token: Default::default(),
+                    args: args.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprCall", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprCast {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprCast", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.serialize_field("ty", &self.ty)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprCast {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr", "ty"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprCast;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprCast")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let expr = element(&mut seq, 1usize, &self)?;
+                let ty = element(&mut seq, 2usize, &self)?;
+                Ok(ExprCast {
+                    attrs,
+                    expr,
+                    as_token: Default::default(),
+                    ty,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut expr = None;
+                let mut ty = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "expr" => expr = Some(map.next_value()?),
+                        "ty" => ty = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprCast {
+                    attrs: attrs.unwrap_or_default(),
+                    expr: required(expr, "expr")?,
+                    as_token: Default::default(),
+                    ty: required(ty, "ty")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprCast", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprClosure {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprClosure", 7usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("asyncness", &self.asyncness.is_some())?;
+        state.serialize_field("movability", &self.movability.is_some())?;
+        state.serialize_field("capture", &self.capture.is_some())?;
+        state.serialize_field("inputs", &self.inputs)?;
+        state.serialize_field("output", &self.output)?;
+        state.serialize_field("body", &self.body)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprClosure {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "asyncness",
+            "movability",
+            "capture",
+            "inputs",
+            "output",
+            "body",
+        ];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprClosure;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprClosure")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let asyncness = present(element::<bool, _>(&mut seq, 1usize, &self)?);
+                let movability = present(element::<bool, _>(&mut seq, 2usize, &self)?);
+                let capture = present(element::<bool, _>(&mut seq, 3usize, &self)?);
+                let inputs = element(&mut seq, 4usize, &self)?;
+                let output = element(&mut seq, 5usize, &self)?;
+                let body = element(&mut seq, 6usize, &self)?;
+                Ok(ExprClosure {
+                    attrs,
+                    asyncness,
+                    movability,
+                    capture,
+                    or1_token: Default::default(),
+                    inputs,
+                    or2_token: Default::default(),
+                    output,
+                    body,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut asyncness = None;
+                let mut movability = None;
+                let mut capture = None;
+                let mut inputs = None;
+                let mut output = None;
+                let mut body = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "asyncness" => asyncness = Some(present(map.next_value::<bool>()?)),
+                        "movability" => movability = Some(present(map.next_value::<bool>()?)),
+                        "capture" => capture = Some(present(map.next_value::<bool>()?)),
+                        "inputs" => inputs = Some(map.next_value()?),
+                        "output" => output = Some(map.next_value()?),
+                        "body" => body = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprClosure {
+                    attrs: attrs.unwrap_or_default(),
+                    asyncness: asyncness.unwrap_or_default(),
+                    movability: movability.unwrap_or_default(),
+                    capture: capture.unwrap_or_default(),
+                    or1_token: Default::default(),
+                    inputs: inputs.unwrap_or_default(),
+                    or2_token: Default::default(),
+                    output: required(output, "output")?,
+                    body: required(body, "body")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprClosure", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprConst {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprConst", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("block", &self.block)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprConst {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "block"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprConst;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprConst")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let block = element(&mut seq, 1usize, &self)?;
+                Ok(ExprConst {
+                    attrs,
+                    const_token: Default::default(),
+                    block,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut block = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "block" => block = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprConst {
+                    attrs: attrs.unwrap_or_default(),
+                    const_token: Default::default(),
+                    block: required(block, "block")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprConst", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprContinue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprContinue", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("label", &self.label)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprContinue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "label"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprContinue;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprContinue")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let label = element(&mut seq, 1usize, &self)?;
+                Ok(ExprContinue {
+                    attrs,
+                    continue_token: Default::default(),
+                    label,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut label = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "label" => label = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprContinue {
+                    attrs: attrs.unwrap_or_default(),
+                    continue_token: Default::default(),
+                    label: label.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprContinue", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprField {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprField", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("base", &self.base)?;
+        state.serialize_field("member", &self.member)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprField {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "base", "member"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprField;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprField")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let base = element(&mut seq, 1usize, &self)?;
+                let member = element(&mut seq, 2usize, &self)?;
+                Ok(ExprField {
+                    attrs,
+                    base,
+                    dot_token: Default::default(),
+                    member,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut base = None;
+                let mut member = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "base" => base = Some(map.next_value()?),
+                        "member" => member = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprField {
+                    attrs: attrs.unwrap_or_default(),
+                    base: required(base, "base")?,
+                    dot_token: Default::default(),
+                    member: required(member, "member")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprField", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprForLoop {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprForLoop", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("label", &self.label)?;
+        state.serialize_field("pat", &self.pat)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.serialize_field("body", &self.body)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprForLoop {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "label", "pat", "expr", "body"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprForLoop;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprForLoop")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let label = element(&mut seq, 1usize, &self)?;
+                let pat = element(&mut seq, 2usize, &self)?;
+                let expr = element(&mut seq, 3usize, &self)?;
+                let body = element(&mut seq, 4usize, &self)?;
+                Ok(ExprForLoop {
+                    attrs,
+                    label,
+                    for_token: Default::default(),
+                    pat,
+                    in_token: Default::default(),
+                    expr,
+                    body,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut label = None;
+                let mut pat = None;
+                let mut expr = None;
+                let mut body = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "label" => label = Some(map.next_value()?),
+                        "pat" => pat = Some(map.next_value()?),
+                        "expr" => expr = Some(map.next_value()?),
+                        "body" => body = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprForLoop {
+                    attrs: attrs.unwrap_or_default(),
+                    label: label.unwrap_or_default(),
+                    for_token: Default::default(),
+                    pat: required(pat, "pat")?,
+                    in_token: Default::default(),
+                    expr: required(expr, "expr")?,
+                    body: required(body, "body")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprForLoop", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprGroup {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprGroup", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprGroup {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprGroup;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprGroup")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let expr = element(&mut seq, 1usize, &self)?;
+                Ok(ExprGroup {
+                    attrs,
+                    group_token: Default::default(),
+                    expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut expr = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "expr" => expr = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprGroup {
+                    attrs: attrs.unwrap_or_default(),
+                    group_token: Default::default(),
+                    expr: required(expr, "expr")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprGroup", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprIf {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprIf", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("cond", &self.cond)?;
+        state.serialize_field("then_branch", &self.then_branch)?;
+        state.serialize_field("else_branch", &self.else_branch.as_ref().map(|(_, v1)| v1))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprIf {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "cond", "then_branch", "else_branch"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprIf;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprIf")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let cond = element(&mut seq, 1usize, &self)?;
+                let then_branch = element(&mut seq, 2usize, &self)?;
+                let else_branch = element::<Option<_>, _>(&mut seq, 3usize, &self)?
+                    .map(|v1| (Default::default(), v1));
+                Ok(ExprIf {
+                    attrs,
+                    if_token: Default::default(),
+                    cond,
+                    then_branch,
+                    else_branch,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut cond = None;
+                let mut then_branch = None;
+                let mut else_branch = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "cond" => cond = Some(map.next_value()?),
+                        "then_branch" => then_branch = Some(map.next_value()?),
+                        "else_branch" => {
+                            else_branch = Some(
+                                map.next_value::<Option<_>>()?
+                                    .map(|v1| (Default::default(), v1)),
+                            )
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprIf {
+                    attrs: attrs.unwrap_or_default(),
+                    if_token: Default::default(),
+                    cond: required(cond, "cond")?,
+                    then_branch: required(then_branch, "then_branch")?,
+                    else_branch: else_branch.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprIf", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprIndex {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprIndex", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.serialize_field("index", &self.index)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprIndex {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr", "index"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprIndex;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprIndex")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let expr = element(&mut seq, 1usize, &self)?;
+                let index = element(&mut seq, 2usize, &self)?;
+                Ok(ExprIndex {
+                    attrs,
+                    expr,
+                    bracket_token: Default::default(),
+                    index,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut expr = None;
+                let mut index = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "expr" => expr = Some(map.next_value()?),
+                        "index" => index = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprIndex {
+                    attrs: attrs.unwrap_or_default(),
+                    expr: required(expr, "expr")?,
+                    bracket_token: Default::default(),
+                    index: required(index, "index")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprIndex", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprLet {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprLet", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("pat", &self.pat)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprLet {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "pat", "expr"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprLet;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprLet")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let pat = element(&mut seq, 1usize, &self)?;
+                let expr = element(&mut seq, 2usize, &self)?;
+                Ok(ExprLet {
+                    attrs,
+                    let_token: Default::default(),
+                    pat,
+                    eq_token: Default::default(),
+                    expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut pat = None;
+                let mut expr = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "pat" => pat = Some(map.next_value()?),
+                        "expr" => expr = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprLet {
+                    attrs: attrs.unwrap_or_default(),
+                    let_token: Default::default(),
+                    pat: required(pat, "pat")?,
+                    eq_token: Default::default(),
+                    expr: required(expr, "expr")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprLet", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprLit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprLit", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("lit", &self.lit)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprLit {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "lit"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprLit;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprLit")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let lit = element(&mut seq, 1usize, &self)?;
+                Ok(ExprLit { attrs, lit })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut lit = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "lit" => lit = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprLit {
+                    attrs: attrs.unwrap_or_default(),
+                    lit: required(lit, "lit")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprLit", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprLoop {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprLoop", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("label", &self.label)?;
+        state.serialize_field("body", &self.body)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprLoop {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "label", "body"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprLoop;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprLoop")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let label = element(&mut seq, 1usize, &self)?;
+                let body = element(&mut seq, 2usize, &self)?;
+                Ok(ExprLoop {
+                    attrs,
+                    label,
+                    loop_token: Default::default(),
+                    body,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut label = None;
+                let mut body = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "label" => label = Some(map.next_value()?),
+                        "body" => body = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprLoop {
+                    attrs: attrs.unwrap_or_default(),
+                    label: label.unwrap_or_default(),
+                    loop_token: Default::default(),
+                    body: required(body, "body")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprLoop", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprMacro", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("mac", &self.mac)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "mac"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let mac = element(&mut seq, 1usize, &self)?;
+                Ok(ExprMacro { attrs, mac })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut mac = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "mac" => mac = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprMacro {
+                    attrs: attrs.unwrap_or_default(),
+                    mac: required(mac, "mac")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprMacro", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprMatch {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprMatch", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.serialize_field("arms", &self.arms)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprMatch {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr", "arms"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprMatch;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprMatch")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let expr = element(&mut seq, 1usize, &self)?;
+                let arms = element(&mut seq, 2usize, &self)?;
+                Ok(ExprMatch {
+                    attrs,
+                    match_token: Default::default(),
+                    expr,
+                    brace_token: Default::default(),
+                    arms,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut expr = None;
+                let mut arms = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "expr" => expr = Some(map.next_value()?),
+                        "arms" => arms = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprMatch {
+                    attrs: attrs.unwrap_or_default(),
+                    match_token: Default::default(),
+                    expr: required(expr, "expr")?,
+                    brace_token: Default::default(),
+                    arms: arms.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprMatch", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprMethodCall {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprMethodCall", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("receiver", &self.receiver)?;
+        state.serialize_field("method", &Ser(&self.method))?;
+        state.serialize_field("turbofish", &self.turbofish)?;
+        state.serialize_field("args", &self.args)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprMethodCall {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "receiver", "method", "turbofish", "args"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprMethodCall;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprMethodCall")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let receiver = element(&mut seq, 1usize, &self)?;
+                let method = element::<De<_>, _>(&mut seq, 2usize, &self)?.0;
+                let turbofish = element(&mut seq, 3usize, &self)?;
+                let args = element(&mut seq, 4usize, &self)?;
+                Ok(ExprMethodCall {
+                    attrs,
+                    receiver,
+                    dot_token: Default::default(),
+                    method,
+                    turbofish,
+                    paren_token: Default::default(),
+                    args,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut receiver = None;
+                let mut method = None;
+                let mut turbofish = None;
+                let mut args = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "receiver" => receiver = Some(map.next_value()?),
+                        "method" => method = Some(map.next_value::<De<_>>()?.0),
+                        "turbofish" => turbofish = Some(map.next_value()?),
+                        "args" => args = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprMethodCall {
+                    attrs: attrs.unwrap_or_default(),
+                    receiver: required(receiver, "receiver")?,
+                    dot_token: Default::default(),
+                    method: required(method, "method")?,
+                    turbofish: turbofish.unwrap_or_default(),
+                    paren_token: Default::default(),
+                    args: args.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprMethodCall", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprParen {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprParen", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprParen {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprParen;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprParen")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let expr = element(&mut seq, 1usize, &self)?;
+                Ok(ExprParen {
+                    attrs,
+                    paren_token: Default::default(),
+                    expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut expr = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "expr" => expr = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprParen {
+                    attrs: attrs.unwrap_or_default(),
+                    paren_token: Default::default(),
+                    expr: required(expr, "expr")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprParen", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprPath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprPath", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("qself", &self.qself)?;
+        state.serialize_field("path", &self.path)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprPath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "qself", "path"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprPath;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprPath")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let qself = element(&mut seq, 1usize, &self)?;
+                let path = element(&mut seq, 2usize, &self)?;
+                Ok(ExprPath { attrs, qself, path })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut qself = None;
+                let mut path = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "qself" => qself = Some(map.next_value()?),
+                        "path" => path = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprPath {
+                    attrs: attrs.unwrap_or_default(),
+                    qself: qself.unwrap_or_default(),
+                    path: required(path, "path")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprPath", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprRange {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprRange", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("from", &self.from)?;
+        state.serialize_field("limits", &self.limits)?;
+        state.serialize_field("to", &self.to)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprRange {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "from", "limits", "to"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprRange;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprRange")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let from = element(&mut seq, 1usize, &self)?;
+                let limits = element(&mut seq, 2usize, &self)?;
+                let to = element(&mut seq, 3usize, &self)?;
+                Ok(ExprRange {
+                    attrs,
+                    from,
+                    limits,
+                    to,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut from = None;
+                let mut limits = None;
+                let mut to = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "from" => from = Some(map.next_value()?),
+                        "limits" => limits = Some(map.next_value()?),
+                        "to" => to = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprRange {
+                    attrs: attrs.unwrap_or_default(),
+                    from: from.unwrap_or_default(),
+                    limits: required(limits, "limits")?,
+                    to: to.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprRange", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprRawAddr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprRawAddr", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("mutability", &self.mutability)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprRawAddr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "mutability", "expr"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprRawAddr;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprRawAddr")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let mutability = element(&mut seq, 1usize, &self)?;
+                let expr = element(&mut seq, 2usize, &self)?;
+                Ok(ExprRawAddr {
+                    attrs,
+                    and_token: Default::default(),
+                    raw: Default::default(),
+                    mutability,
+                    expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut mutability = None;
+                let mut expr = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "mutability" => mutability = Some(map.next_value()?),
+                        "expr" => expr = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprRawAddr {
+                    attrs: attrs.unwrap_or_default(),
+                    and_token: Default::default(),
+                    raw: Default::default(),
+                    mutability: required(mutability, "mutability")?,
+                    expr: required(expr, "expr")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprRawAddr", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprReference {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprReference", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("mutability", &self.mutability.is_some())?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprReference {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "mutability", "expr"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprReference;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprReference")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let mutability = present(element::<bool, _>(&mut seq, 1usize, &self)?);
+                let expr = element(&mut seq, 2usize, &self)?;
+                Ok(ExprReference {
+                    attrs,
+                    and_token: Default::default(),
+                    raw: Default::default(),
+                    mutability,
+                    expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut mutability = None;
+                let mut expr = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "mutability" => mutability = Some(present(map.next_value::<bool>()?)),
+                        "expr" => expr = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprReference {
+                    attrs: attrs.unwrap_or_default(),
+                    and_token: Default::default(),
+                    raw: Default::default(),
+                    mutability: mutability.unwrap_or_default(),
+                    expr: required(expr, "expr")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprReference", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprRepeat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprRepeat", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.serialize_field("len", &self.len)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprRepeat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr", "len"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprRepeat;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprRepeat")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let expr = element(&mut seq, 1usize, &self)?;
+                let len = element(&mut seq, 2usize, &self)?;
+                Ok(ExprRepeat {
+                    attrs,
+                    bracket_token: Default::default(),
+                    expr,
+                    semi_token: Default::default(),
+                    len,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut expr = None;
+                let mut len = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "expr" => expr = Some(map.next_value()?),
+                        "len" => len = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprRepeat {
+                    attrs: attrs.unwrap_or_default(),
+                    bracket_token: Default::default(),
+                    expr: required(expr, "expr")?,
+                    semi_token: Default::default(),
+                    len: required(len, "len")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprRepeat", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprReturn {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprReturn", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprReturn {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprReturn;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprReturn")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let expr = element(&mut seq, 1usize, &self)?;
+                Ok(ExprReturn {
+                    attrs,
+                    return_token: Default::default(),
+                    expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut expr = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "expr" => expr = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprReturn {
+                    attrs: attrs.unwrap_or_default(),
+                    return_token: Default::default(),
+                    expr: expr.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprReturn", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprStruct {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprStruct", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("fields", &self.fields)?;
+        state.serialize_field("dot2_token", &self.dot2_token.is_some())?;
+        state.serialize_field("rest", &self.rest)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprStruct {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "path", "fields", "dot2_token", "rest"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprStruct;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprStruct")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let path = element(&mut seq, 1usize, &self)?;
+                let fields = element(&mut seq, 2usize, &self)?;
+                let dot2_token = present(element::<bool, _>(&mut seq, 3usize, &self)?);
+                let rest = element(&mut seq, 4usize, &self)?;
+                Ok(ExprStruct {
+                    attrs,
+                    path,
+                    brace_token: Default::default(),
+                    fields,
+                    dot2_token,
+                    rest,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut path = None;
+                let mut fields = None;
+                let mut dot2_token = None;
+                let mut rest = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "path" => path = Some(map.next_value()?),
+                        "fields" => fields = Some(map.next_value()?),
+                        "dot2_token" => dot2_token = Some(present(map.next_value::<bool>()?)),
+                        "rest" => rest = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprStruct {
+                    attrs: attrs.unwrap_or_default(),
+                    path: required(path, "path")?,
+                    brace_token: Default::default(),
+                    fields: fields.unwrap_or_default(),
+                    dot2_token: dot2_token.unwrap_or_default(),
+                    rest: rest.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprStruct", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprTry {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprTry", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprTry {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprTry;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprTry")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let expr = element(&mut seq, 1usize, &self)?;
+                Ok(ExprTry {
+                    attrs,
+                    expr,
+                    question_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut expr = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "expr" => expr = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprTry {
+                    attrs: attrs.unwrap_or_default(),
+                    expr: required(expr, "expr")?,
+                    question_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprTry", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprTryBlock {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprTryBlock", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("block", &self.block)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprTryBlock {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "block"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprTryBlock;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprTryBlock")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let block = element(&mut seq, 1usize, &self)?;
+                Ok(ExprTryBlock {
+                    attrs,
+                    try_token: Default::default(),
+                    block,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut block = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "block" => block = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprTryBlock {
+                    attrs: attrs.unwrap_or_default(),
+                    try_token: Default::default(),
+                    block: required(block, "block")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprTryBlock", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprTuple {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprTuple", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("elems", &self.elems)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprTuple {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "elems"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprTuple;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprTuple")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let elems = element(&mut seq, 1usize, &self)?;
+                Ok(ExprTuple {
+                    attrs,
+                    paren_token: Default::default(),
+                    elems,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut elems = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "elems" => elems = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprTuple {
+                    attrs: attrs.unwrap_or_default(),
+                    paren_token: Default::default(),
+                    elems: elems.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprTuple", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprType", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.serialize_field("ty", &self.ty)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr", "ty"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let expr = element(&mut seq, 1usize, &self)?;
+                let ty = element(&mut seq, 2usize, &self)?;
+                Ok(ExprType {
+                    attrs,
+                    expr,
+                    colon_token: Default::default(),
+                    ty,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut expr = None;
+                let mut ty = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "expr" => expr = Some(map.next_value()?),
+                        "ty" => ty = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprType {
+                    attrs: attrs.unwrap_or_default(),
+                    expr: required(expr, "expr")?,
+                    colon_token: Default::default(),
+                    ty: required(ty, "ty")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprType", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprUnary {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprUnary", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("op", &self.op)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprUnary {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "op", "expr"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprUnary;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprUnary")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let op = element(&mut seq, 1usize, &self)?;
+                let expr = element(&mut seq, 2usize, &self)?;
+                Ok(ExprUnary { attrs, op, expr })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut op = None;
+                let mut expr = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "op" => op = Some(map.next_value()?),
+                        "expr" => expr = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprUnary {
+                    attrs: attrs.unwrap_or_default(),
+                    op: required(op, "op")?,
+                    expr: required(expr, "expr")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprUnary", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprUnsafe {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprUnsafe", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("block", &self.block)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprUnsafe {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "block"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprUnsafe;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprUnsafe")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let block = element(&mut seq, 1usize, &self)?;
+                Ok(ExprUnsafe {
+                    attrs,
+                    unsafe_token: Default::default(),
+                    block,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut block = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "block" => block = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprUnsafe {
+                    attrs: attrs.unwrap_or_default(),
+                    unsafe_token: Default::default(),
+                    block: required(block, "block")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprUnsafe", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprWhile {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprWhile", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("label", &self.label)?;
+        state.serialize_field("cond", &self.cond)?;
+        state.serialize_field("body", &self.body)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprWhile {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "label", "cond", "body"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprWhile;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprWhile")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let label = element(&mut seq, 1usize, &self)?;
+                let cond = element(&mut seq, 2usize, &self)?;
+                let body = element(&mut seq, 3usize, &self)?;
+                Ok(ExprWhile {
+                    attrs,
+                    label,
+                    while_token: Default::default(),
+                    cond,
+                    body,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut label = None;
+                let mut cond = None;
+                let mut body = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "label" => label = Some(map.next_value()?),
+                        "cond" => cond = Some(map.next_value()?),
+                        "body" => body = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprWhile {
+                    attrs: attrs.unwrap_or_default(),
+                    label: label.unwrap_or_default(),
+                    while_token: Default::default(),
+                    cond: required(cond, "cond")?,
+                    body: required(body, "body")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprWhile", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ExprYield {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprYield", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ExprYield {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ExprYield;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprYield")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let expr = element(&mut seq, 1usize, &self)?;
+                Ok(ExprYield {
+                    attrs,
+                    yield_token: Default::default(),
+                    expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut expr = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "expr" => expr = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExprYield {
+                    attrs: attrs.unwrap_or_default(),
+                    yield_token: Default::default(),
+                    expr: expr.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprYield", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Field {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Field", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("ident", &self.ident.as_ref().map(Ser))?;
+        state.serialize_field("colon_token", &self.colon_token.is_some())?;
+        state.serialize_field("ty", &self.ty)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "colon_token", "ty"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = Field;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Field")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let vis = element(&mut seq, 1usize, &self)?;
+                let ident = element::<Option<De<_>>, _>(&mut seq, 2usize, &self)?.map(|v| v.0);
+                let colon_token = present(element::<bool, _>(&mut seq, 3usize, &self)?);
+                let ty = element(&mut seq, 4usize, &self)?;
+                Ok(Field {
+                    attrs,
+                    vis,
+                    ident,
+                    colon_token,
+                    ty,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut ident = None;
+                let mut colon_token = None;
+                let mut ty = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "vis" => vis = Some(map.next_value()?),
+                        "ident" => ident = Some(map.next_value::<Option<De<_>>>()?.map(|v| v.0)),
+                        "colon_token" => colon_token = Some(present(map.next_value::<bool>()?)),
+                        "ty" => ty = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Field {
+                    attrs: attrs.unwrap_or_default(),
+                    vis: required(vis, "vis")?,
+                    ident: ident.unwrap_or_default(),
+                    colon_token: colon_token.unwrap_or_default(),
+                    ty: required(ty, "ty")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Field", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for FieldPat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("FieldPat", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("member", &self.member)?;
+        state.serialize_field("colon_token", &self.colon_token.is_some())?;
+        state.serialize_field("pat", &self.pat)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for FieldPat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "member", "colon_token", "pat"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = FieldPat;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct FieldPat")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let member = element(&mut seq, 1usize, &self)?;
+                let colon_token = present(element::<bool, _>(&mut seq, 2usize, &self)?);
+                let pat = element(&mut seq, 3usize, &self)?;
+                Ok(FieldPat {
+                    attrs,
+                    member,
+                    colon_token,
+                    pat,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut member = None;
+                let mut colon_token = None;
+                let mut pat = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "member" => member = Some(map.next_value()?),
+                        "colon_token" => colon_token = Some(present(map.next_value::<bool>()?)),
+                        "pat" => pat = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(FieldPat {
+                    attrs: attrs.unwrap_or_default(),
+                    member: required(member, "member")?,
+                    colon_token: colon_token.unwrap_or_default(),
+                    pat: required(pat, "pat")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("FieldPat", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for FieldValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("FieldValue", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("member", &self.member)?;
+        state.serialize_field("colon_token", &self.colon_token.is_some())?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for FieldValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "member", "colon_token", "expr"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = FieldValue;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct FieldValue")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let member = element(&mut seq, 1usize, &self)?;
+                let colon_token = present(element::<bool, _>(&mut seq, 2usize, &self)?);
+                let expr = element(&mut seq, 3usize, &self)?;
+                Ok(FieldValue {
+                    attrs,
+                    member,
+                    colon_token,
+                    expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut member = None;
+                let mut colon_token = None;
+                let mut expr = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "member" => member = Some(map.next_value()?),
+                        "colon_token" => colon_token = Some(present(map.next_value::<bool>()?)),
+                        "expr" => expr = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(FieldValue {
+                    attrs: attrs.unwrap_or_default(),
+                    member: required(member, "member")?,
+                    colon_token: colon_token.unwrap_or_default(),
+                    expr: required(expr, "expr")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("FieldValue", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Fields {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Fields::Named(v0) => serializer.serialize_newtype_variant("Fields", 0u32, "Named", v0),
+            Fields::Unnamed(v0) => {
+                serializer.serialize_newtype_variant("Fields", 1u32, "Unnamed", v0)
+            }
+            Fields::Unit => serializer.serialize_unit_variant("Fields", 2u32, "Unit"),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Fields {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Named", "Unnamed", "Unit"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = Fields;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Fields")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Named" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Fields::Named(v))
+                    }
+                    "Unnamed" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Fields::Unnamed(v))
+                    }
+                    "Unit" => {
+                        access.unit_variant()?;
+                        Ok(Fields::Unit)
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Fields", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for FieldsNamed {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("FieldsNamed", 1usize)?;
+        state.serialize_field("named", &self.named)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for FieldsNamed {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["named"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = FieldsNamed;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct FieldsNamed")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let named = element(&mut seq, 0usize, &self)?;
+                Ok(FieldsNamed {
+                    brace_token: Default::default(),
+                    named,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut named = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "named" => named = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(FieldsNamed {
+                    brace_token: Default::default(),
+                    named: named.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("FieldsNamed", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for FieldsUnnamed {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("FieldsUnnamed", 1usize)?;
+        state.serialize_field("unnamed", &self.unnamed)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for FieldsUnnamed {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["unnamed"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = FieldsUnnamed;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct FieldsUnnamed")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let unnamed = element(&mut seq, 0usize, &self)?;
+                Ok(FieldsUnnamed {
+                    paren_token: Default::default(),
+                    unnamed,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut unnamed = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "unnamed" => unnamed = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(FieldsUnnamed {
+                    paren_token: Default::default(),
+                    unnamed: unnamed.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("FieldsUnnamed", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for File {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("File", 3usize)?;
+        state.serialize_field("shebang", &self.shebang)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("items", &self.items)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for File {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["shebang", "attrs", "items"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = File;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct File")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let shebang = element(&mut seq, 0usize, &self)?;
+                let attrs = element(&mut seq, 1usize, &self)?;
+                let items = element(&mut seq, 2usize, &self)?;
+                Ok(File {
+                    shebang,
+                    attrs,
+                    items,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut shebang = None;
+                let mut attrs = None;
+                let mut items = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "shebang" => shebang = Some(map.next_value()?),
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "items" => items = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(File {
+                    shebang: shebang.unwrap_or_default(),
+                    attrs: attrs.unwrap_or_default(),
+                    items: items.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("File", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for FnArg {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            FnArg::Receiver(v0) => {
+                serializer.serialize_newtype_variant("FnArg", 0u32, "Receiver", v0)
+            }
+            FnArg::Typed(v0) => serializer.serialize_newtype_variant("FnArg", 1u32, "Typed", v0),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for FnArg {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Receiver", "Typed"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = FnArg;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum FnArg")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Receiver" => {
+                        let v = access.newtype_variant()?;
+                        Ok(FnArg::Receiver(v))
+                    }
+                    "Typed" => {
+                        let v = access.newtype_variant()?;
+                        Ok(FnArg::Typed(v))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("FnArg", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ForeignItem {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            ForeignItem::Fn(v0) => {
+                serializer.serialize_newtype_variant("ForeignItem", 0u32, "Fn", v0)
+            }
+            ForeignItem::Static(v0) => {
+                serializer.serialize_newtype_variant("ForeignItem", 1u32, "Static", v0)
+            }
+            ForeignItem::Type(v0) => {
+                serializer.serialize_newtype_variant("ForeignItem", 2u32, "Type", v0)
+            }
+            ForeignItem::Macro(v0) => {
+                serializer.serialize_newtype_variant("ForeignItem", 3u32, "Macro", v0)
+            }
+            ForeignItem::Verbatim(v0) => {
+                serializer.serialize_newtype_variant("ForeignItem", 4u32, "Verbatim", &Ser(v0))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ForeignItem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Fn", "Static", "Type", "Macro", "Verbatim"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = ForeignItem;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum ForeignItem")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Fn" => {
+                        let v = access.newtype_variant()?;
+                        Ok(ForeignItem::Fn(v))
+                    }
+                    "Static" => {
+                        let v = access.newtype_variant()?;
+                        Ok(ForeignItem::Static(v))
+                    }
+                    "Type" => {
+                        let v = access.newtype_variant()?;
+                        Ok(ForeignItem::Type(v))
+                    }
+                    "Macro" => {
+                        let v = access.newtype_variant()?;
+                        Ok(ForeignItem::Macro(v))
+                    }
+                    "Verbatim" => {
+                        let v = access.newtype_variant::<De<_>>()?;
+                        Ok(ForeignItem::Verbatim(v.0))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("ForeignItem", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ForeignItemFn {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ForeignItemFn", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("sig", &self.sig)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ForeignItemFn {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "sig"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ForeignItemFn;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ForeignItemFn")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let vis = element(&mut seq, 1usize, &self)?;
+                let sig = element(&mut seq, 2usize, &self)?;
+                Ok(ForeignItemFn {
+                    attrs,
+                    vis,
+                    sig,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut sig = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "vis" => vis = Some(map.next_value()?),
+                        "sig" => sig = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ForeignItemFn {
+                    attrs: attrs.unwrap_or_default(),
+                    vis: required(vis, "vis")?,
+                    sig: required(sig, "sig")?,
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ForeignItemFn", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ForeignItemMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ForeignItemMacro", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("mac", &self.mac)?;
+        state.serialize_field("semi_token", &self.semi_token.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ForeignItemMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "mac", "semi_token"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ForeignItemMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ForeignItemMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let mac = element(&mut seq, 1usize, &self)?;
+                let semi_token = present(element::<bool, _>(&mut seq, 2usize, &self)?);
+                Ok(ForeignItemMacro {
+                    attrs,
+                    mac,
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut mac = None;
+                let mut semi_token = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "mac" => mac = Some(map.next_value()?),
+                        "semi_token" => semi_token = Some(present(map.next_value::<bool>()?)),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ForeignItemMacro {
+                    attrs: attrs.unwrap_or_default(),
+                    mac: required(mac, "mac")?,
+                    semi_token: semi_token.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ForeignItemMacro", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ForeignItemStatic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ForeignItemStatic", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("mutability", &self.mutability.is_some())?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("ty", &self.ty)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ForeignItemStatic {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "mutability", "ident", "ty"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ForeignItemStatic;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ForeignItemStatic")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let vis = element(&mut seq, 1usize, &self)?;
+                let mutability = present(element::<bool, _>(&mut seq, 2usize, &self)?);
+                let ident = element::<De<_>, _>(&mut seq, 3usize, &self)?.0;
+                let ty = element(&mut seq, 4usize, &self)?;
+                Ok(ForeignItemStatic {
+                    attrs,
+                    vis,
+                    static_token: Default::default(),
+                    mutability,
+                    ident,
+                    colon_token: Default::default(),
+                    ty,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut mutability = None;
+                let mut ident = None;
+                let mut ty = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "vis" => vis = Some(map.next_value()?),
+                        "mutability" => mutability = Some(present(map.next_value::<bool>()?)),
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        "ty" => ty = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ForeignItemStatic {
+                    attrs: attrs.unwrap_or_default(),
+                    vis: required(vis, "vis")?,
+                    static_token: Default::default(),
+                    mutability: mutability.unwrap_or_default(),
+                    ident: required(ident, "ident")?,
+                    colon_token: Default::default(),
+                    ty: required(ty, "ty")?,
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ForeignItemStatic", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ForeignItemType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ForeignItemType", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ForeignItemType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ForeignItemType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ForeignItemType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let vis = element(&mut seq, 1usize, &self)?;
+                let ident = element::<De<_>, _>(&mut seq, 2usize, &self)?.0;
+                Ok(ForeignItemType {
+                    attrs,
+                    vis,
+                    type_token: Default::default(),
+                    ident,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut ident = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "vis" => vis = Some(map.next_value()?),
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ForeignItemType {
+                    attrs: attrs.unwrap_or_default(),
+                    vis: required(vis, "vis")?,
+                    type_token: Default::default(),
+                    ident: required(ident, "ident")?,
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ForeignItemType", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for GenericArgument {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            GenericArgument::Lifetime(v0) => {
+                serializer.serialize_newtype_variant("GenericArgument", 0u32, "Lifetime", v0)
+            }
+            GenericArgument::Type(v0) => {
+                serializer.serialize_newtype_variant("GenericArgument", 1u32, "Type", v0)
+            }
+            GenericArgument::Binding(v0) => {
+                serializer.serialize_newtype_variant("GenericArgument", 2u32, "Binding", v0)
+            }
+            GenericArgument::Constraint(v0) => {
+                serializer.serialize_newtype_variant("GenericArgument", 3u32, "Constraint", v0)
+            }
+            GenericArgument::Const(v0) => {
+                serializer.serialize_newtype_variant("GenericArgument", 4u32, "Const", v0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for GenericArgument {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Lifetime", "Type", "Binding", "Constraint", "Const"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = GenericArgument;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum GenericArgument")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Lifetime" => {
+                        let v = access.newtype_variant()?;
+                        Ok(GenericArgument::Lifetime(v))
+                    }
+                    "Type" => {
+                        let v = access.newtype_variant()?;
+                        Ok(GenericArgument::Type(v))
+                    }
+                    "Binding" => {
+                        let v = access.newtype_variant()?;
+                        Ok(GenericArgument::Binding(v))
+                    }
+                    "Constraint" => {
+                        let v = access.newtype_variant()?;
+                        Ok(GenericArgument::Constraint(v))
+                    }
+                    "Const" => {
+                        let v = access.newtype_variant()?;
+                        Ok(GenericArgument::Const(v))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("GenericArgument", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for GenericMethodArgument {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            GenericMethodArgument::Type(v0) => {
+                serializer.serialize_newtype_variant("GenericMethodArgument", 0u32, "Type", v0)
+            }
+            GenericMethodArgument::Const(v0) => {
+                serializer.serialize_newtype_variant("GenericMethodArgument", 1u32, "Const", v0)
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for GenericMethodArgument {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Type", "Const"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = GenericMethodArgument;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum GenericMethodArgument")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Type" => {
+                        let v = access.newtype_variant()?;
+                        Ok(GenericMethodArgument::Type(v))
+                    }
+                    "Const" => {
+                        let v = access.newtype_variant()?;
+                        Ok(GenericMethodArgument::Const(v))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("GenericMethodArgument", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for GenericParam {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            GenericParam::Type(v0) => {
+                serializer.serialize_newtype_variant("GenericParam", 0u32, "Type", v0)
+            }
+            GenericParam::Lifetime(v0) => {
+                serializer.serialize_newtype_variant("GenericParam", 1u32, "Lifetime", v0)
+            }
+            GenericParam::Const(v0) => {
+                serializer.serialize_newtype_variant("GenericParam", 2u32, "Const", v0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for GenericParam {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Type", "Lifetime", "Const"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = GenericParam;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum GenericParam")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Type" => {
+                        let v = access.newtype_variant()?;
+                        Ok(GenericParam::Type(v))
+                    }
+                    "Lifetime" => {
+                        let v = access.newtype_variant()?;
+                        Ok(GenericParam::Lifetime(v))
+                    }
+                    "Const" => {
+                        let v = access.newtype_variant()?;
+                        Ok(GenericParam::Const(v))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("GenericParam", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Generics {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Generics", 4usize)?;
+        state.serialize_field("lt_token", &self.lt_token.is_some())?;
+        state.serialize_field("params", &self.params)?;
+        state.serialize_field("gt_token", &self.gt_token.is_some())?;
+        state.serialize_field("where_clause", &self.where_clause)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Generics {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["lt_token", "params", "gt_token", "where_clause"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = Generics;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Generics")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let lt_token = present(element::<bool, _>(&mut seq, 0usize, &self)?);
+                let params = element(&mut seq, 1usize, &self)?;
+                let gt_token = present(element::<bool, _>(&mut seq, 2usize, &self)?);
+                let where_clause = element(&mut seq, 3usize, &self)?;
+                Ok(Generics {
+                    lt_token,
+                    params,
+                    gt_token,
+                    where_clause,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut lt_token = None;
+                let mut params = None;
+                let mut gt_token = None;
+                let mut where_clause = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "lt_token" => lt_token = Some(present(map.next_value::<bool>()?)),
+                        "params" => params = Some(map.next_value()?),
+                        "gt_token" => gt_token = Some(present(map.next_value::<bool>()?)),
+                        "where_clause" => where_clause = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Generics {
+                    lt_token: lt_token.unwrap_or_default(),
+                    params: params.unwrap_or_default(),
+                    gt_token: gt_token.unwrap_or_default(),
+                    where_clause: where_clause.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Generics", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ImplItem {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            ImplItem::Const(v0) => {
+                serializer.serialize_newtype_variant("ImplItem", 0u32, "Const", v0)
+            }
+            ImplItem::Method(v0) => {
+                serializer.serialize_newtype_variant("ImplItem", 1u32, "Method", v0)
+            }
+            ImplItem::Type(v0) => {
+                serializer.serialize_newtype_variant("ImplItem", 2u32, "Type", v0)
+            }
+            ImplItem::Macro(v0) => {
+                serializer.serialize_newtype_variant("ImplItem", 3u32, "Macro", v0)
+            }
+            ImplItem::Verbatim(v0) => {
+                serializer.serialize_newtype_variant("ImplItem", 4u32, "Verbatim", &Ser(v0))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ImplItem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Const", "Method", "Type", "Macro", "Verbatim"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = ImplItem;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum ImplItem")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Const" => {
+                        let v = access.newtype_variant()?;
+                        Ok(ImplItem::Const(v))
+                    }
+                    "Method" => {
+                        let v = access.newtype_variant()?;
+                        Ok(ImplItem::Method(v))
+                    }
+                    "Type" => {
+                        let v = access.newtype_variant()?;
+                        Ok(ImplItem::Type(v))
+                    }
+                    "Macro" => {
+                        let v = access.newtype_variant()?;
+                        Ok(ImplItem::Macro(v))
+                    }
+                    "Verbatim" => {
+                        let v = access.newtype_variant::<De<_>>()?;
+                        Ok(ImplItem::Verbatim(v.0))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("ImplItem", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ImplItemConst {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ImplItemConst", 6usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("defaultness", &self.defaultness.is_some())?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("ty", &self.ty)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ImplItemConst {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "defaultness", "ident", "ty", "expr"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ImplItemConst;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ImplItemConst")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let vis = element(&mut seq, 1usize, &self)?;
+                let defaultness = present(element::<bool, _>(&mut seq, 2usize, &self)?);
+                let ident = element::<De<_>, _>(&mut seq, 3usize, &self)?.0;
+                let ty = element(&mut seq, 4usize, &self)?;
+                let expr = element(&mut seq, 5usize, &self)?;
+                Ok(ImplItemConst {
+                    attrs,
+                    vis,
+                    defaultness,
+                    const_token: Default::default(),
+                    ident,
+                    colon_token: Default::default(),
+                    ty,
+                    eq_token: Default::default(),
+                    expr,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut defaultness = None;
+                let mut ident = None;
+                let mut ty = None;
+                let mut expr = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "vis" => vis = Some(map.next_value()?),
+                        "defaultness" => defaultness = Some(present(map.next_value::<bool>()?)),
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        "ty" => ty = Some(map.next_value()?),
+                        "expr" => expr = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ImplItemConst {
+                    attrs: attrs.unwrap_or_default(),
+                    vis: required(vis, "vis")?,
+                    defaultness: defaultness.unwrap_or_default(),
+                    const_token: Default::default(),
+                    ident: required(ident, "ident")?,
+                    colon_token: Default::default(),
+                    ty: required(ty, "ty")?,
+                    eq_token: Default::default(),
+                    expr: required(expr, "expr")?,
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ImplItemConst", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ImplItemMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ImplItemMacro", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("mac", &self.mac)?;
+        state.serialize_field("semi_token", &self.semi_token.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ImplItemMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "mac", "semi_token"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ImplItemMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ImplItemMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let mac = element(&mut seq, 1usize, &self)?;
+                let semi_token = present(element::<bool, _>(&mut seq, 2usize, &self)?);
+                Ok(ImplItemMacro {
+                    attrs,
+                    mac,
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut mac = None;
+                let mut semi_token = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "mac" => mac = Some(map.next_value()?),
+                        "semi_token" => semi_token = Some(present(map.next_value::<bool>()?)),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ImplItemMacro {
+                    attrs: attrs.unwrap_or_default(),
+                    mac: required(mac, "mac")?,
+                    semi_token: semi_token.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ImplItemMacro", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ImplItemMethod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ImplItemMethod", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("defaultness", &self.defaultness.is_some())?;
+        state.serialize_field("sig", &self.sig)?;
+        state.serialize_field("block", &self.block)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ImplItemMethod {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "defaultness", "sig", "block"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ImplItemMethod;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ImplItemMethod")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let vis = element(&mut seq, 1usize, &self)?;
+                let defaultness = present(element::<bool, _>(&mut seq, 2usize, &self)?);
+                let sig = element(&mut seq, 3usize, &self)?;
+                let block = element(&mut seq, 4usize, &self)?;
+                Ok(ImplItemMethod {
+                    attrs,
+                    vis,
+                    defaultness,
+                    sig,
+                    block,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut defaultness = None;
+                let mut sig = None;
+                let mut block = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "vis" => vis = Some(map.next_value()?),
+                        "defaultness" => defaultness = Some(present(map.next_value::<bool>()?)),
+                        "sig" => sig = Some(map.next_value()?),
+                        "block" => block = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ImplItemMethod {
+                    attrs: attrs.unwrap_or_default(),
+                    vis: required(vis, "vis")?,
+                    defaultness: defaultness.unwrap_or_default(),
+                    sig: required(sig, "sig")?,
+                    block: required(block, "block")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ImplItemMethod", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ImplItemType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ImplItemType", 6usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("defaultness", &self.defaultness.is_some())?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("ty", &self.ty)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ImplItemType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "defaultness", "ident", "generics", "ty"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ImplItemType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ImplItemType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let vis = element(&mut seq, 1usize, &self)?;
+                let defaultness = present(element::<bool, _>(&mut seq, 2usize, &self)?);
+                let ident = element::<De<_>, _>(&mut seq, 3usize, &self)?.0;
+                let generics = element(&mut seq, 4usize, &self)?;
+                let ty = element(&mut seq, 5usize, &self)?;
+                Ok(ImplItemType {
+                    attrs,
+                    vis,
+                    defaultness,
+                    type_token: Default::default(),
+                    ident,
+                    generics,
+                    eq_token: Default::default(),
+                    ty,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut defaultness = None;
+                let mut ident = None;
+                let mut generics = None;
+                let mut ty = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "vis" => vis = Some(map.next_value()?),
+                        "defaultness" => defaultness = Some(present(map.next_value::<bool>()?)),
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        "generics" => generics = Some(map.next_value()?),
+                        "ty" => ty = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ImplItemType {
+                    attrs: attrs.unwrap_or_default(),
+                    vis: required(vis, "vis")?,
+                    defaultness: defaultness.unwrap_or_default(),
+                    type_token: Default::default(),
+                    ident: required(ident, "ident")?,
+                    generics: required(generics, "generics")?,
+                    eq_token: Default::default(),
+                    ty: required(ty, "ty")?,
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ImplItemType", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Index {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Index", 1usize)?;
+        state.serialize_field("index", &self.index)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Index {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["index"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = Index;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Index")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let index = element(&mut seq, 0usize, &self)?;
+                Ok(Index {
+                    index,
+                    span: Span::call_site(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut index = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "index" => index = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Index {
+                    index: required(index, "index")?,
+                    span: Span::call_site(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Index", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Item {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Item::Const(v0) => serializer.serialize_newtype_variant("Item", 0u32, "Const", v0),
+            Item::Enum(v0) => serializer.serialize_newtype_variant("Item", 1u32, "Enum", v0),
+            Item::ExternCrate(v0) => {
+                serializer.serialize_newtype_variant("Item", 2u32, "ExternCrate", v0)
+            }
+            Item::Fn(v0) => serializer.serialize_newtype_variant("Item", 3u32, "Fn", v0),
+            Item::FnDecl(v0) => serializer.serialize_newtype_variant("Item", 4u32, "FnDecl", v0),
+            Item::ForeignMod(v0) => {
+                serializer.serialize_newtype_variant("Item", 5u32, "ForeignMod", v0)
+            }
+            Item::Impl(v0) => serializer.serialize_newtype_variant("Item", 6u32, "Impl", v0),
+            Item::Macro(v0) => serializer.serialize_newtype_variant("Item", 7u32, "Macro", v0),
+            Item::Macro2(v0) => serializer.serialize_newtype_variant("Item", 8u32, "Macro2", v0),
+            Item::Mod(v0) => serializer.serialize_newtype_variant("Item", 9u32, "Mod", v0),
+            Item::Static(v0) => serializer.serialize_newtype_variant("Item", 10u32, "Static", v0),
+            Item::Struct(v0) => serializer.serialize_newtype_variant("Item", 11u32, "Struct", v0),
+            Item::Trait(v0) => serializer.serialize_newtype_variant("Item", 12u32, "Trait", v0),
+            Item::TraitAlias(v0) => {
+                serializer.serialize_newtype_variant("Item", 13u32, "TraitAlias", v0)
+            }
+            Item::Type(v0) => serializer.serialize_newtype_variant("Item", 14u32, "Type", v0),
+            Item::Union(v0) => serializer.serialize_newtype_variant("Item", 15u32, "Union", v0),
+            Item::Use(v0) => serializer.serialize_newtype_variant("Item", 16u32, "Use", v0),
+            Item::Verbatim(v0) => {
+                serializer.serialize_newtype_variant("Item", 17u32, "Verbatim", &Ser(v0))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Item {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &[
+            "Const",
+            "Enum",
+            "ExternCrate",
+            "Fn",
+            "FnDecl",
+            "ForeignMod",
+            "Impl",
+            "Macro",
+            "Macro2",
+            "Mod",
+            "Static",
+            "Struct",
+            "Trait",
+            "TraitAlias",
+            "Type",
+            "Union",
+            "Use",
+            "Verbatim",
+        ];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = Item;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Item")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Const" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Item::Const(v))
+                    }
+                    "Enum" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Item::Enum(v))
+                    }
+                    "ExternCrate" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Item::ExternCrate(v))
+                    }
+                    "Fn" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Item::Fn(v))
+                    }
+                    "FnDecl" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Item::FnDecl(v))
+                    }
+                    "ForeignMod" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Item::ForeignMod(v))
+                    }
+                    "Impl" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Item::Impl(v))
+                    }
+                    "Macro" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Item::Macro(v))
+                    }
+                    "Macro2" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Item::Macro2(v))
+                    }
+                    "Mod" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Item::Mod(v))
+                    }
+                    "Static" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Item::Static(v))
+                    }
+                    "Struct" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Item::Struct(v))
+                    }
+                    "Trait" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Item::Trait(v))
+                    }
+                    "TraitAlias" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Item::TraitAlias(v))
+                    }
+                    "Type" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Item::Type(v))
+                    }
+                    "Union" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Item::Union(v))
+                    }
+                    "Use" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Item::Use(v))
+                    }
+                    "Verbatim" => {
+                        let v = access.newtype_variant::<De<_>>()?;
+                        Ok(Item::Verbatim(v.0))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Item", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemConst {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemConst", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("ty", &self.ty)?;
+        state.serialize_field("expr", &self.expr.as_ref().map(|(_, v1)| v1))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemConst {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "ty", "expr"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ItemConst;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemConst")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let vis = element(&mut seq, 1usize, &self)?;
+                let ident = element::<De<_>, _>(&mut seq, 2usize, &self)?.0;
+                let ty = element(&mut seq, 3usize, &self)?;
+                let expr = element::<Option<_>, _>(&mut seq, 4usize, &self)?
+                    .map(|v1| (Default::default(), v1));
+                Ok(ItemConst {
+                    attrs,
+                    vis,
+                    const_token: Default::default(),
+                    ident,
+                    colon_token: Default::default(),
+                    ty,
+                    expr,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut ident = None;
+                let mut ty = None;
+                let mut expr = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "vis" => vis = Some(map.next_value()?),
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        "ty" => ty = Some(map.next_value()?),
+                        "expr" => {
+                            expr = Some(
+                                map.next_value::<Option<_>>()?
+                                    .map(|v1| (Default::default(), v1)),
+                            )
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemConst {
+                    attrs: attrs.unwrap_or_default(),
+                    vis: required(vis, "vis")?,
+                    const_token: Default::default(),
+                    ident: required(ident, "ident")?,
+                    colon_token: Default::default(),
+                    ty: required(ty, "ty")?,
+                    expr: expr.unwrap_or_default(),
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemConst", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemEnum", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("variants", &self.variants)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemEnum {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "generics", "variants"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ItemEnum;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemEnum")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let vis = element(&mut seq, 1usize, &self)?;
+                let ident = element::<De<_>, _>(&mut seq, 2usize, &self)?.0;
+                let generics = element(&mut seq, 3usize, &self)?;
+                let variants = element(&mut seq, 4usize, &self)?;
+                Ok(ItemEnum {
+                    attrs,
+                    vis,
+                    enum_token: Default::default(),
+                    ident,
+                    generics,
+                    brace_token: Default::default(),
+                    variants,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut ident = None;
+                let mut generics = None;
+                let mut variants = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "vis" => vis = Some(map.next_value()?),
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        "generics" => generics = Some(map.next_value()?),
+                        "variants" => variants = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemEnum {
+                    attrs: attrs.unwrap_or_default(),
+                    vis: required(vis, "vis")?,
+                    enum_token: Default::default(),
+                    ident: required(ident, "ident")?,
+                    generics: required(generics, "generics")?,
+                    brace_token: Default::default(),
+                    variants: variants.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemEnum", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemExternCrate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemExternCrate", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("rename", &self.rename.as_ref().map(|(_, v1)| Ser(v1)))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemExternCrate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "rename"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ItemExternCrate;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemExternCrate")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let vis = element(&mut seq, 1usize, &self)?;
+                let ident = element::<De<_>, _>(&mut seq, 2usize, &self)?.0;
+                let rename = element::<Option<De<_>>, _>(&mut seq, 3usize, &self)?
+                    .map(|v1| (Default::default(), v1.0));
+                Ok(ItemExternCrate {
+                    attrs,
+                    vis,
+                    extern_token: Default::default(),
+                    crate_token: Default::default(),
+                    ident,
+                    rename,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut ident = None;
+                let mut rename = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "vis" => vis = Some(map.next_value()?),
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        "rename" => {
+                            rename = Some(
+                                map.next_value::<Option<De<_>>>()?
+                                    .map(|v1| (Default::default(), v1.0)),
+                            )
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemExternCrate {
+                    attrs: attrs.unwrap_or_default(),
+                    vis: required(vis, "vis")?,
+                    extern_token: Default::default(),
+                    crate_token: Default::default(),
+                    ident: required(ident, "ident")?,
+                    rename: rename.unwrap_or_default(),
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemExternCrate", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemFn {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemFn", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("sig", &self.sig)?;
+        state.serialize_field("block", &self.block)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemFn {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "sig", "block"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ItemFn;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemFn")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let vis = element(&mut seq, 1usize, &self)?;
+                let sig = element(&mut seq, 2usize, &self)?;
+                let block = element(&mut seq, 3usize, &self)?;
+                Ok(ItemFn {
+                    attrs,
+                    vis,
+                    sig,
+                    block,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut sig = None;
+                let mut block = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "vis" => vis = Some(map.next_value()?),
+                        "sig" => sig = Some(map.next_value()?),
+                        "block" => block = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemFn {
+                    attrs: attrs.unwrap_or_default(),
+                    vis: required(vis, "vis")?,
+                    sig: required(sig, "sig")?,
+                    block: required(block, "block")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemFn", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemFnDecl {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemFnDecl", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("sig", &self.sig)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemFnDecl {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "sig"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ItemFnDecl;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemFnDecl")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let vis = element(&mut seq, 1usize, &self)?;
+                let sig = element(&mut seq, 2usize, &self)?;
+                Ok(ItemFnDecl {
+                    attrs,
+                    vis,
+                    sig,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut sig = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "vis" => vis = Some(map.next_value()?),
+                        "sig" => sig = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemFnDecl {
+                    attrs: attrs.unwrap_or_default(),
+                    vis: required(vis, "vis")?,
+                    sig: required(sig, "sig")?,
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemFnDecl", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemForeignMod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemForeignMod", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("unsafety", &self.unsafety.is_some())?;
+        state.serialize_field("abi", &self.abi)?;
+        state.serialize_field("items", &self.items)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemForeignMod {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "unsafety", "abi", "items"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ItemForeignMod;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemForeignMod")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let unsafety = present(element::<bool, _>(&mut seq, 1usize, &self)?);
+                let abi = element(&mut seq, 2usize, &self)?;
+                let items = element(&mut seq, 3usize, &self)?;
+                Ok(ItemForeignMod {
+                    attrs,
+                    unsafety,
+                    abi,
+                    brace_token: Default::default(),
+                    items,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut unsafety = None;
+                let mut abi = None;
+                let mut items = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "unsafety" => unsafety = Some(present(map.next_value::<bool>()?)),
+                        "abi" => abi = Some(map.next_value()?),
+                        "items" => items = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemForeignMod {
+                    attrs: attrs.unwrap_or_default(),
+                    unsafety: unsafety.unwrap_or_default(),
+                    abi: required(abi, "abi")?,
+                    brace_token: Default::default(),
+                    items: items.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemForeignMod", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemImpl {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemImpl", 7usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("defaultness", &self.defaultness.is_some())?;
+        state.serialize_field("unsafety", &self.unsafety.is_some())?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field(
+            "trait_",
+            &self.trait_.as_ref().map(|(v0, v1, _)| (v0.is_some(), v1)),
+        )?;
+        state.serialize_field("self_ty", &self.self_ty)?;
+        state.serialize_field("items", &self.items)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemImpl {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "defaultness",
+            "unsafety",
+            "generics",
+            "trait_",
+            "self_ty",
+            "items",
+        ];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ItemImpl;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemImpl")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let defaultness = present(element::<bool, _>(&mut seq, 1usize, &self)?);
+                let unsafety = present(element::<bool, _>(&mut seq, 2usize, &self)?);
+                let generics = element(&mut seq, 3usize, &self)?;
+                let trait_ = element::<Option<(bool, _)>, _>(&mut seq, 4usize, &self)?
+                    .map(|(v0, v1)| (present(v0), v1, Default::default()));
+                let self_ty = element(&mut seq, 5usize, &self)?;
+                let items = element(&mut seq, 6usize, &self)?;
+                Ok(ItemImpl {
+                    attrs,
+                    defaultness,
+                    unsafety,
+                    impl_token: Default::default(),
+                    generics,
+                    trait_,
+                    self_ty,
+                    brace_token: Default::default(),
+                    items,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut defaultness = None;
+                let mut unsafety = None;
+                let mut generics = None;
+                let mut trait_ = None;
+                let mut self_ty = None;
+                let mut items = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "defaultness" => defaultness = Some(present(map.next_value::<bool>()?)),
+                        "unsafety" => unsafety = Some(present(map.next_value::<bool>()?)),
+                        "generics" => generics = Some(map.next_value()?),
+                        "trait_" => {
+                            trait_ = Some(
+                                map.next_value::<Option<(bool, _)>>()?
+                                    .map(|(v0, v1)| (present(v0), v1, Default::default())),
+                            )
+                        }
+                        "self_ty" => self_ty = Some(map.next_value()?),
+                        "items" => items = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemImpl {
+                    attrs: attrs.unwrap_or_default(),
+                    defaultness: defaultness.unwrap_or_default(),
+                    unsafety: unsafety.unwrap_or_default(),
+                    impl_token: Default::default(),
+                    generics: required(generics, "generics")?,
+                    trait_: trait_.unwrap_or_default(),
+                    self_ty: required(self_ty, "self_ty")?,
+                    brace_token: Default::default(),
+                    items: items.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemImpl", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemMacro", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("ident", &self.ident.as_ref().map(Ser))?;
+        state.serialize_field("mac", &self.mac)?;
+        state.serialize_field("semi_token", &self.semi_token.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "ident", "mac", "semi_token"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ItemMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let ident = element::<Option<De<_>>, _>(&mut seq, 1usize, &self)?.map(|v| v.0);
+                let mac = element(&mut seq, 2usize, &self)?;
+                let semi_token = present(element::<bool, _>(&mut seq, 3usize, &self)?);
+                Ok(ItemMacro {
+                    attrs,
+                    ident,
+                    mac,
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut ident = None;
+                let mut mac = None;
+                let mut semi_token = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "ident" => ident = Some(map.next_value::<Option<De<_>>>()?.map(|v| v.0)),
+                        "mac" => mac = Some(map.next_value()?),
+                        "semi_token" => semi_token = Some(present(map.next_value::<bool>()?)),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemMacro {
+                    attrs: attrs.unwrap_or_default(),
+                    ident: ident.unwrap_or_default(),
+                    mac: required(mac, "mac")?,
+                    semi_token: semi_token.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemMacro", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemMacro2 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemMacro2", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("rules", &Ser(&self.rules))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemMacro2 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "rules"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ItemMacro2;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemMacro2")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let vis = element(&mut seq, 1usize, &self)?;
+                let ident = element::<De<_>, _>(&mut seq, 2usize, &self)?.0;
+                let rules = element::<De<_>, _>(&mut seq, 3usize, &self)?.0;
+                Ok(ItemMacro2 {
+                    attrs,
+                    vis,
+                    macro_token: Default::default(),
+                    ident,
+                    rules,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut ident = None;
+                let mut rules = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "vis" => vis = Some(map.next_value()?),
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        "rules" => rules = Some(map.next_value::<De<_>>()?.0),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemMacro2 {
+                    attrs: attrs.unwrap_or_default(),
+                    vis: required(vis, "vis")?,
+                    macro_token: Default::default(),
+                    ident: required(ident, "ident")?,
+                    rules: required(rules, "rules")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemMacro2", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemMod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemMod", 6usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("unsafety", &self.unsafety.is_some())?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("content", &self.content.as_ref().map(|(_, v1)| v1))?;
+        state.serialize_field("semi", &self.semi.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemMod {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "unsafety", "ident", "content", "semi"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ItemMod;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemMod")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let vis = element(&mut seq, 1usize, &self)?;
+                let unsafety = present(element::<bool, _>(&mut seq, 2usize, &self)?);
+                let ident = element::<De<_>, _>(&mut seq, 3usize, &self)?.0;
+                let content = element::<Option<_>, _>(&mut seq, 4usize, &self)?
+                    .map(|v1| (Default::default(), v1));
+                let semi = present(element::<bool, _>(&mut seq, 5usize, &self)?);
+                Ok(ItemMod {
+                    attrs,
+                    vis,
+                    unsafety,
+                    mod_token: Default::default(),
+                    ident,
+                    content,
+                    semi,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut unsafety = None;
+                let mut ident = None;
+                let mut content = None;
+                let mut semi = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "vis" => vis = Some(map.next_value()?),
+                        "unsafety" => unsafety = Some(present(map.next_value::<bool>()?)),
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        "content" => {
+                            content = Some(
+                                map.next_value::<Option<_>>()?
+                                    .map(|v1| (Default::default(), v1)),
+                            )
+                        }
+                        "semi" => semi = Some(present(map.next_value::<bool>()?)),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemMod {
+                    attrs: attrs.unwrap_or_default(),
+                    vis: required(vis, "vis")?,
+                    unsafety: unsafety.unwrap_or_default(),
+                    mod_token: Default::default(),
+                    ident: required(ident, "ident")?,
+                    content: content.unwrap_or_default(),
+                    semi: semi.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemMod", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemStatic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemStatic", 6usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("mutability", &self.mutability.is_some())?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("ty", &self.ty.as_ref().map(|(_, v1)| v1))?;
+        state.serialize_field("expr", &self.expr.as_ref().map(|(_, v1)| v1))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemStatic {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "mutability", "ident", "ty", "expr"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ItemStatic;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemStatic")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let vis = element(&mut seq, 1usize, &self)?;
+                let mutability = present(element::<bool, _>(&mut seq, 2usize, &self)?);
+                let ident = element::<De<_>, _>(&mut seq, 3usize, &self)?.0;
+                let ty = element::<Option<_>, _>(&mut seq, 4usize, &self)?
+                    .map(|v1| (Default::default(), v1));
+                let expr = element::<Option<_>, _>(&mut seq, 5usize, &self)?
+                    .map(|v1| (Default::default(), v1));
+                Ok(ItemStatic {
+                    attrs,
+                    vis,
+                    static_token: Default::default(),
+                    mutability,
+                    ident,
+                    ty,
+                    expr,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut mutability = None;
+                let mut ident = None;
+                let mut ty = None;
+                let mut expr = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "vis" => vis = Some(map.next_value()?),
+                        "mutability" => mutability = Some(present(map.next_value::<bool>()?)),
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        "ty" => {
+                            ty = Some(
+                                map.next_value::<Option<_>>()?
+                                    .map(|v1| (Default::default(), v1)),
+                            )
+                        }
+                        "expr" => {
+                            expr = Some(
+                                map.next_value::<Option<_>>()?
+                                    .map(|v1| (Default::default(), v1)),
+                            )
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemStatic {
+                    attrs: attrs.unwrap_or_default(),
+                    vis: required(vis, "vis")?,
+                    static_token: Default::default(),
+                    mutability: mutability.unwrap_or_default(),
+                    ident: required(ident, "ident")?,
+                    ty: ty.unwrap_or_default(),
+                    expr: expr.unwrap_or_default(),
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemStatic", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemStruct {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemStruct", 6usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("fields", &self.fields)?;
+        state.serialize_field("semi_token", &self.semi_token.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemStruct {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "generics", "fields", "semi_token"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ItemStruct;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemStruct")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let vis = element(&mut seq, 1usize, &self)?;
+                let ident = element::<De<_>, _>(&mut seq, 2usize, &self)?.0;
+                let generics = element(&mut seq, 3usize, &self)?;
+                let fields = element(&mut seq, 4usize, &self)?;
+                let semi_token = present(element::<bool, _>(&mut seq, 5usize, &self)?);
+                Ok(ItemStruct {
+                    attrs,
+                    vis,
+                    struct_token: Default::default(),
+                    ident,
+                    generics,
+                    fields,
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut ident = None;
+                let mut generics = None;
+                let mut fields = None;
+                let mut semi_token = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "vis" => vis = Some(map.next_value()?),
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        "generics" => generics = Some(map.next_value()?),
+                        "fields" => fields = Some(map.next_value()?),
+                        "semi_token" => semi_token = Some(present(map.next_value::<bool>()?)),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemStruct {
+                    attrs: attrs.unwrap_or_default(),
+                    vis: required(vis, "vis")?,
+                    struct_token: Default::default(),
+                    ident: required(ident, "ident")?,
+                    generics: required(generics, "generics")?,
+                    fields: required(fields, "fields")?,
+                    semi_token: semi_token.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemStruct", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemTrait {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemTrait", 9usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("unsafety", &self.unsafety.is_some())?;
+        state.serialize_field("auto_token", &self.auto_token.is_some())?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("colon_token", &self.colon_token.is_some())?;
+        state.serialize_field("supertraits", &self.supertraits)?;
+        state.serialize_field("items", &self.items)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemTrait {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "vis",
+            "unsafety",
+            "auto_token",
+            "ident",
+            "generics",
+            "colon_token",
+            "supertraits",
+            "items",
+        ];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ItemTrait;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemTrait")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let vis = element(&mut seq, 1usize, &self)?;
+                let unsafety = present(element::<bool, _>(&mut seq, 2usize, &self)?);
+                let auto_token = present(element::<bool, _>(&mut seq, 3usize, &self)?);
+                let ident = element::<De<_>, _>(&mut seq, 4usize, &self)?.0;
+                let generics = element(&mut seq, 5usize, &self)?;
+                let colon_token = present(element::<bool, _>(&mut seq, 6usize, &self)?);
+                let supertraits = element(&mut seq, 7usize, &self)?;
+                let items = element(&mut seq, 8usize, &self)?;
+                Ok(ItemTrait {
+                    attrs,
+                    vis,
+                    unsafety,
+                    auto_token,
+                    trait_token: Default::default(),
+                    ident,
+                    generics,
+                    colon_token,
+                    supertraits,
+                    brace_token: Default::default(),
+                    items,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut unsafety = None;
+                let mut auto_token = None;
+                let mut ident = None;
+                let mut generics = None;
+                let mut colon_token = None;
+                let mut supertraits = None;
+                let mut items = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "vis" => vis = Some(map.next_value()?),
+                        "unsafety" => unsafety = Some(present(map.next_value::<bool>()?)),
+                        "auto_token" => auto_token = Some(present(map.next_value::<bool>()?)),
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        "generics" => generics = Some(map.next_value()?),
+                        "colon_token" => colon_token = Some(present(map.next_value::<bool>()?)),
+                        "supertraits" => supertraits = Some(map.next_value()?),
+                        "items" => items = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemTrait {
+                    attrs: attrs.unwrap_or_default(),
+                    vis: required(vis, "vis")?,
+                    unsafety: unsafety.unwrap_or_default(),
+                    auto_token: auto_token.unwrap_or_default(),
+                    trait_token: Default::default(),
+                    ident: required(ident, "ident")?,
+                    generics: required(generics, "generics")?,
+                    colon_token: colon_token.unwrap_or_default(),
+                    supertraits: supertraits.unwrap_or_default(),
+                    brace_token: Default::default(),
+                    items: items.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemTrait", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemTraitAlias {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemTraitAlias", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("bounds", &self.bounds)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemTraitAlias {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "generics", "bounds"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ItemTraitAlias;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemTraitAlias")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let vis = element(&mut seq, 1usize, &self)?;
+                let ident = element::<De<_>, _>(&mut seq, 2usize, &self)?.0;
+                let generics = element(&mut seq, 3usize, &self)?;
+                let bounds = element(&mut seq, 4usize, &self)?;
+                Ok(ItemTraitAlias {
+                    attrs,
+                    vis,
+                    trait_token: Default::default(),
+                    ident,
+                    generics,
+                    eq_token: Default::default(),
+                    bounds,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut ident = None;
+                let mut generics = None;
+                let mut bounds = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "vis" => vis = Some(map.next_value()?),
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        "generics" => generics = Some(map.next_value()?),
+                        "bounds" => bounds = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemTraitAlias {
+                    attrs: attrs.unwrap_or_default(),
+                    vis: required(vis, "vis")?,
+                    trait_token: Default::default(),
+                    ident: required(ident, "ident")?,
+                    generics: required(generics, "generics")?,
+                    eq_token: Default::default(),
+                    bounds: bounds.unwrap_or_default(),
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemTraitAlias", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemType", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("ty", &self.ty)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "generics", "ty"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ItemType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let vis = element(&mut seq, 1usize, &self)?;
+                let ident = element::<De<_>, _>(&mut seq, 2usize, &self)?.0;
+                let generics = element(&mut seq, 3usize, &self)?;
+                let ty = element(&mut seq, 4usize, &self)?;
+                Ok(ItemType {
+                    attrs,
+                    vis,
+                    type_token: Default::default(),
+                    ident,
+                    generics,
+                    eq_token: Default::default(),
+                    ty,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut ident = None;
+                let mut generics = None;
+                let mut ty = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "vis" => vis = Some(map.next_value()?),
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        "generics" => generics = Some(map.next_value()?),
+                        "ty" => ty = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemType {
+                    attrs: attrs.unwrap_or_default(),
+                    vis: required(vis, "vis")?,
+                    type_token: Default::default(),
+                    ident: required(ident, "ident")?,
+                    generics: required(generics, "generics")?,
+                    eq_token: Default::default(),
+                    ty: required(ty, "ty")?,
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemType", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemUnion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemUnion", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("fields", &self.fields)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemUnion {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "generics", "fields"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ItemUnion;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemUnion")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let vis = element(&mut seq, 1usize, &self)?;
+                let ident = element::<De<_>, _>(&mut seq, 2usize, &self)?.0;
+                let generics = element(&mut seq, 3usize, &self)?;
+                let fields = element(&mut seq, 4usize, &self)?;
+                Ok(ItemUnion {
+                    attrs,
+                    vis,
+                    union_token: Default::default(),
+                    ident,
+                    generics,
+                    fields,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut ident = None;
+                let mut generics = None;
+                let mut fields = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "vis" => vis = Some(map.next_value()?),
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        "generics" => generics = Some(map.next_value()?),
+                        "fields" => fields = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemUnion {
+                    attrs: attrs.unwrap_or_default(),
+                    vis: required(vis, "vis")?,
+                    union_token: Default::default(),
+                    ident: required(ident, "ident")?,
+                    generics: required(generics, "generics")?,
+                    fields: required(fields, "fields")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemUnion", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ItemUse {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemUse", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("leading_colon", &self.leading_colon.is_some())?;
+        state.serialize_field("tree", &self.tree)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ItemUse {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "leading_colon", "tree"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ItemUse;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemUse")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let vis = element(&mut seq, 1usize, &self)?;
+                let leading_colon = present(element::<bool, _>(&mut seq, 2usize, &self)?);
+                let tree = element(&mut seq, 3usize, &self)?;
+                Ok(ItemUse {
+                    attrs,
+                    vis,
+                    use_token: Default::default(),
+                    leading_colon,
+                    tree,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut vis = None;
+                let mut leading_colon = None;
+                let mut tree = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "vis" => vis = Some(map.next_value()?),
+                        "leading_colon" => leading_colon = Some(present(map.next_value::<bool>()?)),
+                        "tree" => tree = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ItemUse {
+                    attrs: attrs.unwrap_or_default(),
+                    vis: required(vis, "vis")?,
+                    use_token: Default::default(),
+                    leading_colon: leading_colon.unwrap_or_default(),
+                    tree: required(tree, "tree")?,
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemUse", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Label {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Label", 1usize)?;
+        state.serialize_field("name", &self.name)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Label {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["name"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = Label;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Label")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let name = element(&mut seq, 0usize, &self)?;
+                Ok(Label {
+                    name,
+                    colon_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut name = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "name" => name = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Label {
+                    name: required(name, "name")?,
+                    colon_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Label", FIELDS, StructVisitor)
+    }
+}
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Lifetime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Lifetime", 1usize)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.end()
+    }
+}
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Lifetime {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = Lifetime;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Lifetime")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let ident = element::<De<_>, _>(&mut seq, 0usize, &self)?.0;
+                Ok(Lifetime {
+                    apostrophe: Span::call_site(),
+                    ident,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut ident = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Lifetime {
+                    apostrophe: Span::call_site(),
+                    ident: required(ident, "ident")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Lifetime", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for LifetimeDef {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("LifetimeDef", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("lifetime", &self.lifetime)?;
+        state.serialize_field("colon_token", &self.colon_token.is_some())?;
+        state.serialize_field("bounds", &self.bounds)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for LifetimeDef {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "lifetime", "colon_token", "bounds"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = LifetimeDef;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct LifetimeDef")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let lifetime = element(&mut seq, 1usize, &self)?;
+                let colon_token = present(element::<bool, _>(&mut seq, 2usize, &self)?);
+                let bounds = element(&mut seq, 3usize, &self)?;
+                Ok(LifetimeDef {
+                    attrs,
+                    lifetime,
+                    colon_token,
+                    bounds,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut lifetime = None;
+                let mut colon_token = None;
+                let mut bounds = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "lifetime" => lifetime = Some(map.next_value()?),
+                        "colon_token" => colon_token = Some(present(map.next_value::<bool>()?)),
+                        "bounds" => bounds = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(LifetimeDef {
+                    attrs: attrs.unwrap_or_default(),
+                    lifetime: required(lifetime, "lifetime")?,
+                    colon_token: colon_token.unwrap_or_default(),
+                    bounds: bounds.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("LifetimeDef", FIELDS, StructVisitor)
+    }
+}
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Lit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Lit::Str(v0) => serializer.serialize_newtype_variant("Lit", 0u32, "Str", v0),
+            Lit::ByteStr(v0) => serializer.serialize_newtype_variant("Lit", 1u32, "ByteStr", v0),
+            Lit::Byte(v0) => serializer.serialize_newtype_variant("Lit", 2u32, "Byte", v0),
+            Lit::Char(v0) => serializer.serialize_newtype_variant("Lit", 3u32, "Char", v0),
+            Lit::Int(v0) => serializer.serialize_newtype_variant("Lit", 4u32, "Int", v0),
+            Lit::Float(v0) => serializer.serialize_newtype_variant("Lit", 5u32, "Float", v0),
+            Lit::Bool(v0) => serializer.serialize_newtype_variant("Lit", 6u32, "Bool", v0),
+            Lit::Verbatim(v0) => {
+                serializer.serialize_newtype_variant("Lit", 7u32, "Verbatim", &Ser(v0))
+            }
+        }
+    }
+}
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Lit {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &[
+            "Str", "ByteStr", "Byte", "Char", "Int", "Float", "Bool", "Verbatim",
+        ];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = Lit;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Lit")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Str" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Lit::Str(v))
+                    }
+                    "ByteStr" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Lit::ByteStr(v))
+                    }
+                    "Byte" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Lit::Byte(v))
+                    }
+                    "Char" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Lit::Char(v))
+                    }
+                    "Int" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Lit::Int(v))
+                    }
+                    "Float" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Lit::Float(v))
+                    }
+                    "Bool" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Lit::Bool(v))
+                    }
+                    "Verbatim" => {
+                        let v = access.newtype_variant::<De<_>>()?;
+                        Ok(Lit::Verbatim(v.0))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Lit", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for LitBool {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("LitBool", 1usize)?;
+        state.serialize_field("value", &self.value)?;
+        state.end()
+    }
+}
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for LitBool {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["value"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = LitBool;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct LitBool")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let value = element(&mut seq, 0usize, &self)?;
+                Ok(LitBool {
+                    value,
+                    span: Span::call_site(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut value = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "value" => value = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(LitBool {
+                    value: required(value, "value")?,
+                    span: Span::call_site(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("LitBool", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Local {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Local", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("pat", &self.pat)?;
+        state.serialize_field("init", &self.init)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Local {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "pat", "init"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = Local;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Local")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let pat = element(&mut seq, 1usize, &self)?;
+                let init = element(&mut seq, 2usize, &self)?;
+                Ok(Local {
+                    attrs,
+                    let_token: Default::default(),
+                    pat,
+                    init,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut pat = None;
+                let mut init = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "pat" => pat = Some(map.next_value()?),
+                        "init" => init = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Local {
+                    attrs: attrs.unwrap_or_default(),
+                    let_token: Default::default(),
+                    pat: required(pat, "pat")?,
+                    init: init.unwrap_or_default(),
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Local", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for LocalInit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("LocalInit", 2usize)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.serialize_field("diverge", &self.diverge.as_ref().map(|(_, v1)| v1))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for LocalInit {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["expr", "diverge"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = LocalInit;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct LocalInit")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let expr = element(&mut seq, 0usize, &self)?;
+                let diverge = element::<Option<_>, _>(&mut seq, 1usize, &self)?
+                    .map(|v1| (Default::default(), v1));
+                Ok(LocalInit {
+                    eq_token: Default::default(),
+                    expr,
+                    diverge,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut expr = None;
+                let mut diverge = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "expr" => expr = Some(map.next_value()?),
+                        "diverge" => {
+                            diverge = Some(
+                                map.next_value::<Option<_>>()?
+                                    .map(|v1| (Default::default(), v1)),
+                            )
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(LocalInit {
+                    eq_token: Default::default(),
+                    expr: required(expr, "expr")?,
+                    diverge: diverge.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("LocalInit", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Macro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Macro", 3usize)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("delimiter", &self.delimiter)?;
+        state.serialize_field("tokens", &Ser(&self.tokens))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Macro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["path", "delimiter", "tokens"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = Macro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Macro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let path = element(&mut seq, 0usize, &self)?;
+                let delimiter = element(&mut seq, 1usize, &self)?;
+                let tokens = element::<De<_>, _>(&mut seq, 2usize, &self)?.0;
+                Ok(Macro {
+                    path,
+                    bang_token: Default::default(),
+                    delimiter,
+                    tokens,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut path = None;
+                let mut delimiter = None;
+                let mut tokens = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "path" => path = Some(map.next_value()?),
+                        "delimiter" => delimiter = Some(map.next_value()?),
+                        "tokens" => tokens = Some(map.next_value::<De<_>>()?.0),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Macro {
+                    path: required(path, "path")?,
+                    bang_token: Default::default(),
+                    delimiter: required(delimiter, "delimiter")?,
+                    tokens: required(tokens, "tokens")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Macro", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for MacroDelimiter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            MacroDelimiter::Paren(_) => {
+                serializer.serialize_unit_variant("MacroDelimiter", 0u32, "Paren")
+            }
+            MacroDelimiter::Brace(_) => {
+                serializer.serialize_unit_variant("MacroDelimiter", 1u32, "Brace")
+            }
+            MacroDelimiter::Bracket(_) => {
+                serializer.serialize_unit_variant("MacroDelimiter", 2u32, "Bracket")
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for MacroDelimiter {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Paren", "Brace", "Bracket"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = MacroDelimiter;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum MacroDelimiter")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Paren" => {
+                        access.unit_variant()?;
+                        Ok(MacroDelimiter::Paren(Default::default()))
+                    }
+                    "Brace" => {
+                        access.unit_variant()?;
+                        Ok(MacroDelimiter::Brace(Default::default()))
+                    }
+                    "Bracket" => {
+                        access.unit_variant()?;
+                        Ok(MacroDelimiter::Bracket(Default::default()))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("MacroDelimiter", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for MacroGroup {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("MacroGroup", 2usize)?;
+        state.serialize_field("delimiter", &self.delimiter)?;
+        state.serialize_field("content", &self.content)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for MacroGroup {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["delimiter", "content"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = MacroGroup;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct MacroGroup")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let delimiter = element(&mut seq, 0usize, &self)?;
+                let content = element(&mut seq, 1usize, &self)?;
+                Ok(MacroGroup { delimiter, content })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut delimiter = None;
+                let mut content = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "delimiter" => delimiter = Some(map.next_value()?),
+                        "content" => content = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(MacroGroup {
+                    delimiter: required(delimiter, "delimiter")?,
+                    content: content.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("MacroGroup", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for MacroMetavar {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("MacroMetavar", 2usize)?;
+        state.serialize_field("name", &Ser(&self.name))?;
+        state.serialize_field("specifier", &self.specifier.as_ref().map(|(_, v1)| Ser(v1)))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for MacroMetavar {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["name", "specifier"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = MacroMetavar;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct MacroMetavar")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let name = element::<De<_>, _>(&mut seq, 0usize, &self)?.0;
+                let specifier = element::<Option<De<_>>, _>(&mut seq, 1usize, &self)?
+                    .map(|v1| (Default::default(), v1.0));
+                Ok(MacroMetavar {
+                    dollar_token: Default::default(),
+                    name,
+                    specifier,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut name = None;
+                let mut specifier = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "name" => name = Some(map.next_value::<De<_>>()?.0),
+                        "specifier" => {
+                            specifier = Some(
+                                map.next_value::<Option<De<_>>>()?
+                                    .map(|v1| (Default::default(), v1.0)),
+                            )
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(MacroMetavar {
+                    dollar_token: Default::default(),
+                    name: required(name, "name")?,
+                    specifier: specifier.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("MacroMetavar", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for MacroRepOp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            MacroRepOp::ZeroOrMore(_) => {
+                serializer.serialize_unit_variant("MacroRepOp", 0u32, "ZeroOrMore")
+            }
+            MacroRepOp::OneOrMore(_) => {
+                serializer.serialize_unit_variant("MacroRepOp", 1u32, "OneOrMore")
+            }
+            MacroRepOp::ZeroOrOne(_) => {
+                serializer.serialize_unit_variant("MacroRepOp", 2u32, "ZeroOrOne")
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for MacroRepOp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["ZeroOrMore", "OneOrMore", "ZeroOrOne"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = MacroRepOp;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum MacroRepOp")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "ZeroOrMore" => {
+                        access.unit_variant()?;
+                        Ok(MacroRepOp::ZeroOrMore(Default::default()))
+                    }
+                    "OneOrMore" => {
+                        access.unit_variant()?;
+                        Ok(MacroRepOp::OneOrMore(Default::default()))
+                    }
+                    "ZeroOrOne" => {
+                        access.unit_variant()?;
+                        Ok(MacroRepOp::ZeroOrOne(Default::default()))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("MacroRepOp", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for MacroRepetition {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("MacroRepetition", 3usize)?;
+        state.serialize_field("content", &self.content)?;
+        state.serialize_field("separator", &self.separator.as_ref().map(Ser))?;
+        state.serialize_field("op", &self.op)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for MacroRepetition {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["content", "separator", "op"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = MacroRepetition;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct MacroRepetition")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let content = element(&mut seq, 0usize, &self)?;
+                let separator = element::<Option<De<_>>, _>(&mut seq, 1usize, &self)?.map(|v| v.0);
+                let op = element(&mut seq, 2usize, &self)?;
+                Ok(MacroRepetition {
+                    dollar_token: Default::default(),
+                    paren_token: Default::default(),
+                    content,
+                    separator,
+                    op,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut content = None;
+                let mut separator = None;
+                let mut op = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "content" => content = Some(map.next_value()?),
+                        "separator" => {
+                            separator = Some(map.next_value::<Option<De<_>>>()?.map(|v| v.0))
+                        }
+                        "op" => op = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(MacroRepetition {
+                    dollar_token: Default::default(),
+                    paren_token: Default::default(),
+                    content: content.unwrap_or_default(),
+                    separator: separator.unwrap_or_default(),
+                    op: required(op, "op")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("MacroRepetition", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for MacroRule {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("MacroRule", 2usize)?;
+        state.serialize_field("matcher", &self.matcher)?;
+        state.serialize_field("transcriber", &self.transcriber)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for MacroRule {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["matcher", "transcriber"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = MacroRule;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct MacroRule")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let matcher = element(&mut seq, 0usize, &self)?;
+                let transcriber = element(&mut seq, 1usize, &self)?;
+                Ok(MacroRule {
+                    matcher,
+                    fat_arrow_token: Default::default(),
+                    transcriber,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut matcher = None;
+                let mut transcriber = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "matcher" => matcher = Some(map.next_value()?),
+                        "transcriber" => transcriber = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(MacroRule {
+                    matcher: required(matcher, "matcher")?,
+                    fat_arrow_token: Default::default(),
+                    transcriber: required(transcriber, "transcriber")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("MacroRule", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for MacroRules {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("MacroRules", 1usize)?;
+        state.serialize_field("rules", &self.rules)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for MacroRules {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["rules"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = MacroRules;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct MacroRules")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let rules = element(&mut seq, 0usize, &self)?;
+                Ok(MacroRules { rules })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut rules = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "rules" => rules = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(MacroRules {
+                    rules: rules.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("MacroRules", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for MacroTree {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            MacroTree::Verbatim(v0) => {
+                serializer.serialize_newtype_variant("MacroTree", 0u32, "Verbatim", &Ser(v0))
+            }
+            MacroTree::Group(v0) => {
+                serializer.serialize_newtype_variant("MacroTree", 1u32, "Group", v0)
+            }
+            MacroTree::Metavar(v0) => {
+                serializer.serialize_newtype_variant("MacroTree", 2u32, "Metavar", v0)
+            }
+            MacroTree::Repetition(v0) => {
+                serializer.serialize_newtype_variant("MacroTree", 3u32, "Repetition", v0)
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for MacroTree {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Verbatim", "Group", "Metavar", "Repetition"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = MacroTree;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum MacroTree")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Verbatim" => {
+                        let v = access.newtype_variant::<De<_>>()?;
+                        Ok(MacroTree::Verbatim(v.0))
+                    }
+                    "Group" => {
+                        let v = access.newtype_variant()?;
+                        Ok(MacroTree::Group(v))
+                    }
+                    "Metavar" => {
+                        let v = access.newtype_variant()?;
+                        Ok(MacroTree::Metavar(v))
+                    }
+                    "Repetition" => {
+                        let v = access.newtype_variant()?;
+                        Ok(MacroTree::Repetition(v))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("MacroTree", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Member {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Member::Named(v0) => {
+                serializer.serialize_newtype_variant("Member", 0u32, "Named", &Ser(v0))
+            }
+            Member::Unnamed(v0) => {
+                serializer.serialize_newtype_variant("Member", 1u32, "Unnamed", v0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Member {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Named", "Unnamed"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = Member;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Member")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Named" => {
+                        let v = access.newtype_variant::<De<_>>()?;
+                        Ok(Member::Named(v.0))
+                    }
+                    "Unnamed" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Member::Unnamed(v))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Member", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Meta {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Meta::Path(v0) => serializer.serialize_newtype_variant("Meta", 0u32, "Path", v0),
+            Meta::List(v0) => serializer.serialize_newtype_variant("Meta", 1u32, "List", v0),
+            Meta::NameValue(v0) => {
+                serializer.serialize_newtype_variant("Meta", 2u32, "NameValue", v0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Meta {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Path", "List", "NameValue"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = Meta;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Meta")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Path" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Meta::Path(v))
+                    }
+                    "List" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Meta::List(v))
+                    }
+                    "NameValue" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Meta::NameValue(v))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Meta", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for MetaList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("MetaList", 2usize)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("nested", &self.nested)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for MetaList {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["path", "nested"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = MetaList;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct MetaList")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let path = element(&mut seq, 0usize, &self)?;
+                let nested = element(&mut seq, 1usize, &self)?;
+                Ok(MetaList {
+                    path,
+                    paren_token: Default::default(),
+                    nested,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut path = None;
+                let mut nested = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "path" => path = Some(map.next_value()?),
+                        "nested" => nested = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(MetaList {
+                    path: required(path, "path")?,
+                    paren_token: Default::default(),
+                    nested: nested.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("MetaList", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for MetaNameValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("MetaNameValue", 2usize)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("lit", &self.lit)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for MetaNameValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["path", "lit"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = MetaNameValue;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct MetaNameValue")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let path = element(&mut seq, 0usize, &self)?;
+                let lit = element(&mut seq, 1usize, &self)?;
+                Ok(MetaNameValue {
+                    path,
+                    eq_token: Default::default(),
+                    lit,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut path = None;
+                let mut lit = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "path" => path = Some(map.next_value()?),
+                        "lit" => lit = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(MetaNameValue {
+                    path: required(path, "path")?,
+                    eq_token: Default::default(),
+                    lit: required(lit, "lit")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("MetaNameValue", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for MethodTurbofish {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("MethodTurbofish", 1usize)?;
+        state.serialize_field("args", &self.args)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for MethodTurbofish {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["args"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = MethodTurbofish;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct MethodTurbofish")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let args = element(&mut seq, 0usize, &self)?;
+                Ok(MethodTurbofish {
+                    colon2_token: Default::default(),
+                    lt_token: Default::default(),
+                    args,
+                    gt_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut args = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "args" => args = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(MethodTurbofish {
+                    colon2_token: Default::default(),
+                    lt_token: Default::default(),
+                    args: args.unwrap_or_default(),
+                    gt_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("MethodTurbofish", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for NestedMeta {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            NestedMeta::Meta(v0) => {
+                serializer.serialize_newtype_variant("NestedMeta", 0u32, "Meta", v0)
+            }
+            NestedMeta::Lit(v0) => {
+                serializer.serialize_newtype_variant("NestedMeta", 1u32, "Lit", v0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for NestedMeta {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Meta", "Lit"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = NestedMeta;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum NestedMeta")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Meta" => {
+                        let v = access.newtype_variant()?;
+                        Ok(NestedMeta::Meta(v))
+                    }
+                    "Lit" => {
+                        let v = access.newtype_variant()?;
+                        Ok(NestedMeta::Lit(v))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("NestedMeta", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ParenthesizedGenericArguments {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ParenthesizedGenericArguments", 2usize)?;
+        state.serialize_field("inputs", &self.inputs)?;
+        state.serialize_field("output", &self.output)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ParenthesizedGenericArguments {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["inputs", "output"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = ParenthesizedGenericArguments;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ParenthesizedGenericArguments")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let inputs = element(&mut seq, 0usize, &self)?;
+                let output = element(&mut seq, 1usize, &self)?;
+                Ok(ParenthesizedGenericArguments {
+                    paren_token: Default::default(),
+                    inputs,
+                    output,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut inputs = None;
+                let mut output = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "inputs" => inputs = Some(map.next_value()?),
+                        "output" => output = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ParenthesizedGenericArguments {
+                    paren_token: Default::default(),
+                    inputs: inputs.unwrap_or_default(),
+                    output: required(output, "output")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ParenthesizedGenericArguments", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Pat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Pat::Box(v0) => serializer.serialize_newtype_variant("Pat", 0u32, "Box", v0),
+            Pat::Const(v0) => serializer.serialize_newtype_variant("Pat", 1u32, "Const", v0),
+            Pat::Ident(v0) => serializer.serialize_newtype_variant("Pat", 2u32, "Ident", v0),
+            Pat::Lit(v0) => serializer.serialize_newtype_variant("Pat", 3u32, "Lit", v0),
+            Pat::Macro(v0) => serializer.serialize_newtype_variant("Pat", 4u32, "Macro", v0),
+            Pat::Or(v0) => serializer.serialize_newtype_variant("Pat", 5u32, "Or", v0),
+            Pat::Path(v0) => serializer.serialize_newtype_variant("Pat", 6u32, "Path", v0),
+            Pat::Range(v0) => serializer.serialize_newtype_variant("Pat", 7u32, "Range", v0),
+            Pat::Reference(v0) => {
+                serializer.serialize_newtype_variant("Pat", 8u32, "Reference", v0)
+            }
+            Pat::Rest(v0) => serializer.serialize_newtype_variant("Pat", 9u32, "Rest", v0),
+            Pat::Slice(v0) => serializer.serialize_newtype_variant("Pat", 10u32, "Slice", v0),
+            Pat::Struct(v0) => serializer.serialize_newtype_variant("Pat", 11u32, "Struct", v0),
+            Pat::Tuple(v0) => serializer.serialize_newtype_variant("Pat", 12u32, "Tuple", v0),
+            Pat::TupleStruct(v0) => {
+                serializer.serialize_newtype_variant("Pat", 13u32, "TupleStruct", v0)
+            }
+            Pat::Type(v0) => serializer.serialize_newtype_variant("Pat", 14u32, "Type", v0),
+            Pat::Verbatim(v0) => {
+                serializer.serialize_newtype_variant("Pat", 15u32, "Verbatim", &Ser(v0))
+            }
+            Pat::Wild(v0) => serializer.serialize_newtype_variant("Pat", 16u32, "Wild", v0),
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Pat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &[
+            "Box",
+            "Const",
+            "Ident",
+            "Lit",
+            "Macro",
+            "Or",
+            "Path",
+            "Range",
+            "Reference",
+            "Rest",
+            "Slice",
+            "Struct",
+            "Tuple",
+            "TupleStruct",
+            "Type",
+            "Verbatim",
+            "Wild",
+        ];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = Pat;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Pat")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Box" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Pat::Box(v))
+                    }
+                    "Const" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Pat::Const(v))
+                    }
+                    "Ident" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Pat::Ident(v))
+                    }
+                    "Lit" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Pat::Lit(v))
+                    }
+                    "Macro" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Pat::Macro(v))
+                    }
+                    "Or" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Pat::Or(v))
+                    }
+                    "Path" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Pat::Path(v))
+                    }
+                    "Range" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Pat::Range(v))
+                    }
+                    "Reference" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Pat::Reference(v))
+                    }
+                    "Rest" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Pat::Rest(v))
+                    }
+                    "Slice" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Pat::Slice(v))
+                    }
+                    "Struct" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Pat::Struct(v))
+                    }
+                    "Tuple" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Pat::Tuple(v))
+                    }
+                    "TupleStruct" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Pat::TupleStruct(v))
+                    }
+                    "Type" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Pat::Type(v))
+                    }
+                    "Verbatim" => {
+                        let v = access.newtype_variant::<De<_>>()?;
+                        Ok(Pat::Verbatim(v.0))
+                    }
+                    "Wild" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Pat::Wild(v))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Pat", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatBox {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatBox", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("pat", &self.pat)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatBox {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "pat"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = PatBox;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatBox")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let pat = element(&mut seq, 1usize, &self)?;
+                Ok(PatBox {
+                    attrs,
+                    box_token: Default::default(),
+                    pat,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut pat = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "pat" => pat = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatBox {
+                    attrs: attrs.unwrap_or_default(),
+                    box_token: Default::default(),
+                    pat: required(pat, "pat")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatBox", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatConst {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatConst", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("block", &self.block)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatConst {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "block"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = PatConst;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatConst")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let block = element(&mut seq, 1usize, &self)?;
+                Ok(PatConst {
+                    attrs,
+                    const_token: Default::default(),
+                    block,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut block = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "block" => block = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatConst {
+                    attrs: attrs.unwrap_or_default(),
+                    const_token: Default::default(),
+                    block: required(block, "block")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatConst", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatIdent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatIdent", 5usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("by_ref", &self.by_ref.is_some())?;
+        state.serialize_field("mutability", &self.mutability.is_some())?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("subpat", &self.subpat.as_ref().map(|(_, v1)| v1))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatIdent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "by_ref", "mutability", "ident", "subpat"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = PatIdent;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatIdent")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let by_ref = present(element::<bool, _>(&mut seq, 1usize, &self)?);
+                let mutability = present(element::<bool, _>(&mut seq, 2usize, &self)?);
+                let ident = element::<De<_>, _>(&mut seq, 3usize, &self)?.0;
+                let subpat = element::<Option<_>, _>(&mut seq, 4usize, &self)?
+                    .map(|v1| (Default::default(), v1));
+                Ok(PatIdent {
+                    attrs,
+                    by_ref,
+                    mutability,
+                    ident,
+                    subpat,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut by_ref = None;
+                let mut mutability = None;
+                let mut ident = None;
+                let mut subpat = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "by_ref" => by_ref = Some(present(map.next_value::<bool>()?)),
+                        "mutability" => mutability = Some(present(map.next_value::<bool>()?)),
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        "subpat" => {
+                            subpat = Some(
+                                map.next_value::<Option<_>>()?
+                                    .map(|v1| (Default::default(), v1)),
+                            )
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatIdent {
+                    attrs: attrs.unwrap_or_default(),
+                    by_ref: by_ref.unwrap_or_default(),
+                    mutability: mutability.unwrap_or_default(),
+                    ident: required(ident, "ident")?,
+                    subpat: subpat.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatIdent", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatLit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatLit", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatLit {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = PatLit;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatLit")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let expr = element(&mut seq, 1usize, &self)?;
+                Ok(PatLit { attrs, expr })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut expr = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "expr" => expr = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatLit {
+                    attrs: attrs.unwrap_or_default(),
+                    expr: required(expr, "expr")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatLit", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatMacro", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("mac", &self.mac)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "mac"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = PatMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let mac = element(&mut seq, 1usize, &self)?;
+                Ok(PatMacro { attrs, mac })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut mac = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "mac" => mac = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatMacro {
+                    attrs: attrs.unwrap_or_default(),
+                    mac: required(mac, "mac")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatMacro", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatOr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatOr", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("leading_vert", &self.leading_vert.is_some())?;
+        state.serialize_field("cases", &self.cases)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatOr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "leading_vert", "cases"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = PatOr;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatOr")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let leading_vert = present(element::<bool, _>(&mut seq, 1usize, &self)?);
+                let cases = element(&mut seq, 2usize, &self)?;
+                Ok(PatOr {
+                    attrs,
+                    leading_vert,
+                    cases,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut leading_vert = None;
+                let mut cases = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "leading_vert" => leading_vert = Some(present(map.next_value::<bool>()?)),
+                        "cases" => cases = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatOr {
+                    attrs: attrs.unwrap_or_default(),
+                    leading_vert: leading_vert.unwrap_or_default(),
+                    cases: cases.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatOr", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatPath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatPath", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("qself", &self.qself)?;
+        state.serialize_field("path", &self.path)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatPath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "qself", "path"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = PatPath;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatPath")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let qself = element(&mut seq, 1usize, &self)?;
+                let path = element(&mut seq, 2usize, &self)?;
+                Ok(PatPath { attrs, qself, path })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut qself = None;
+                let mut path = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "qself" => qself = Some(map.next_value()?),
+                        "path" => path = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatPath {
+                    attrs: attrs.unwrap_or_default(),
+                    qself: qself.unwrap_or_default(),
+                    path: required(path, "path")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatPath", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatRange {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatRange", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("lo", &self.lo)?;
+        state.serialize_field("limits", &self.limits)?;
+        state.serialize_field("hi", &self.hi)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatRange {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "lo", "limits", "hi"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = PatRange;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatRange")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let lo = element(&mut seq, 1usize, &self)?;
+                let limits = element(&mut seq, 2usize, &self)?;
+                let hi = element(&mut seq, 3usize, &self)?;
+                Ok(PatRange {
+                    attrs,
+                    lo,
+                    limits,
+                    hi,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut lo = None;
+                let mut limits = None;
+                let mut hi = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "lo" => lo = Some(map.next_value()?),
+                        "limits" => limits = Some(map.next_value()?),
+                        "hi" => hi = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatRange {
+                    attrs: attrs.unwrap_or_default(),
+                    lo: required(lo, "lo")?,
+                    limits: required(limits, "limits")?,
+                    hi: required(hi, "hi")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatRange", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatReference {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatReference", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("mutability", &self.mutability.is_some())?;
+        state.serialize_field("pat", &self.pat)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatReference {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "mutability", "pat"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = PatReference;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatReference")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let mutability = present(element::<bool, _>(&mut seq, 1usize, &self)?);
+                let pat = element(&mut seq, 2usize, &self)?;
+                Ok(PatReference {
+                    attrs,
+                    and_token: Default::default(),
+                    mutability,
+                    pat,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut mutability = None;
+                let mut pat = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "mutability" => mutability = Some(present(map.next_value::<bool>()?)),
+                        "pat" => pat = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatReference {
+                    attrs: attrs.unwrap_or_default(),
+                    and_token: Default::default(),
+                    mutability: mutability.unwrap_or_default(),
+                    pat: required(pat, "pat")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatReference", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatRest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatRest", 1usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatRest {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = PatRest;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatRest")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                Ok(PatRest {
+                    attrs,
+                    dot2_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatRest {
+                    attrs: attrs.unwrap_or_default(),
+                    dot2_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatRest", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatSlice {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatSlice", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("elems", &self.elems)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatSlice {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "elems"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = PatSlice;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatSlice")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let elems = element(&mut seq, 1usize, &self)?;
+                Ok(PatSlice {
+                    attrs,
+                    bracket_token: Default::default(),
+                    elems,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut elems = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "elems" => elems = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatSlice {
+                    attrs: attrs.unwrap_or_default(),
+                    bracket_token: Default::default(),
+                    elems: elems.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatSlice", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatStruct {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatStruct", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("fields", &self.fields)?;
+        state.serialize_field("dot2_token", &self.dot2_token.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatStruct {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "path", "fields", "dot2_token"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = PatStruct;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatStruct")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let path = element(&mut seq, 1usize, &self)?;
+                let fields = element(&mut seq, 2usize, &self)?;
+                let dot2_token = present(element::<bool, _>(&mut seq, 3usize, &self)?);
+                Ok(PatStruct {
+                    attrs,
+                    path,
+                    brace_token: Default::default(),
+                    fields,
+                    dot2_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut path = None;
+                let mut fields = None;
+                let mut dot2_token = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "path" => path = Some(map.next_value()?),
+                        "fields" => fields = Some(map.next_value()?),
+                        "dot2_token" => dot2_token = Some(present(map.next_value::<bool>()?)),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatStruct {
+                    attrs: attrs.unwrap_or_default(),
+                    path: required(path, "path")?,
+                    brace_token: Default::default(),
+                    fields: fields.unwrap_or_default(),
+                    dot2_token: dot2_token.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatStruct", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatTuple {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatTuple", 2usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("elems", &self.elems)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatTuple {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "elems"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = PatTuple;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatTuple")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let elems = element(&mut seq, 1usize, &self)?;
+                Ok(PatTuple {
+                    attrs,
+                    paren_token: Default::default(),
+                    elems,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut elems = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "elems" => elems = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatTuple {
+                    attrs: attrs.unwrap_or_default(),
+                    paren_token: Default::default(),
+                    elems: elems.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatTuple", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatTupleStruct {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatTupleStruct", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("pat", &self.pat)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatTupleStruct {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "path", "pat"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = PatTupleStruct;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatTupleStruct")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let path = element(&mut seq, 1usize, &self)?;
+                let pat = element(&mut seq, 2usize, &self)?;
+                Ok(PatTupleStruct { attrs, path, pat })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut path = None;
+                let mut pat = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "path" => path = Some(map.next_value()?),
+                        "pat" => pat = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatTupleStruct {
+                    attrs: attrs.unwrap_or_default(),
+                    path: required(path, "path")?,
+                    pat: required(pat, "pat")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatTupleStruct", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatType", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("pat", &self.pat)?;
+        state.serialize_field("ty", &self.ty)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "pat", "ty"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = PatType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let pat = element(&mut seq, 1usize, &self)?;
+                let ty = element(&mut seq, 2usize, &self)?;
+                Ok(PatType {
+                    attrs,
+                    pat,
+                    colon_token: Default::default(),
+                    ty,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut pat = None;
+                let mut ty = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "pat" => pat = Some(map.next_value()?),
+                        "ty" => ty = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatType {
+                    attrs: attrs.unwrap_or_default(),
+                    pat: required(pat, "pat")?,
+                    colon_token: Default::default(),
+                    ty: required(ty, "ty")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatType", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PatWild {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatWild", 1usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PatWild {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = PatWild;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatWild")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                Ok(PatWild {
+                    attrs,
+                    underscore_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PatWild {
+                    attrs: attrs.unwrap_or_default(),
+                    underscore_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatWild", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Path {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Path", 2usize)?;
+        state.serialize_field("leading_colon", &self.leading_colon.is_some())?;
+        state.serialize_field("segments", &self.segments)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Path {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["leading_colon", "segments"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = Path;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Path")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let leading_colon = present(element::<bool, _>(&mut seq, 0usize, &self)?);
+                let segments = element(&mut seq, 1usize, &self)?;
+                Ok(Path {
+                    leading_colon,
+                    segments,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut leading_colon = None;
+                let mut segments = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "leading_colon" => leading_colon = Some(present(map.next_value::<bool>()?)),
+                        "segments" => segments = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Path {
+                    leading_colon: leading_colon.unwrap_or_default(),
+                    segments: segments.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Path", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PathArguments {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            PathArguments::None => serializer.serialize_unit_variant("PathArguments", 0u32, "None"),
+            PathArguments::AngleBracketed(v0) => {
+                serializer.serialize_newtype_variant("PathArguments", 1u32, "AngleBracketed", v0)
+            }
+            PathArguments::Parenthesized(v0) => {
+                serializer.serialize_newtype_variant("PathArguments", 2u32, "Parenthesized", v0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PathArguments {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["None", "AngleBracketed", "Parenthesized"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = PathArguments;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum PathArguments")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "None" => {
+                        access.unit_variant()?;
+                        Ok(PathArguments::None)
+                    }
+                    "AngleBracketed" => {
+                        let v = access.newtype_variant()?;
+                        Ok(PathArguments::AngleBracketed(v))
+                    }
+                    "Parenthesized" => {
+                        let v = access.newtype_variant()?;
+                        Ok(PathArguments::Parenthesized(v))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("PathArguments", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PathSegment {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PathSegment", 2usize)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("arguments", &self.arguments)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PathSegment {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident", "arguments"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = PathSegment;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PathSegment")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let ident = element::<De<_>, _>(&mut seq, 0usize, &self)?.0;
+                let arguments = element(&mut seq, 1usize, &self)?;
+                Ok(PathSegment { ident, arguments })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut ident = None;
+                let mut arguments = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        "arguments" => arguments = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PathSegment {
+                    ident: required(ident, "ident")?,
+                    arguments: required(arguments, "arguments")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PathSegment", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PointerMutability {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            PointerMutability::Const(_) => {
+                serializer.serialize_unit_variant("PointerMutability", 0u32, "Const")
+            }
+            PointerMutability::Mut(_) => {
+                serializer.serialize_unit_variant("PointerMutability", 1u32, "Mut")
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PointerMutability {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Const", "Mut"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = PointerMutability;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum PointerMutability")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Const" => {
+                        access.unit_variant()?;
+                        Ok(PointerMutability::Const(Default::default()))
+                    }
+                    "Mut" => {
+                        access.unit_variant()?;
+                        Ok(PointerMutability::Mut(Default::default()))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("PointerMutability", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PredicateEq {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PredicateEq", 2usize)?;
+        state.serialize_field("lhs_ty", &self.lhs_ty)?;
+        state.serialize_field("rhs_ty", &self.rhs_ty)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PredicateEq {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["lhs_ty", "rhs_ty"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = PredicateEq;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PredicateEq")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let lhs_ty = element(&mut seq, 0usize, &self)?;
+                let rhs_ty = element(&mut seq, 1usize, &self)?;
+                Ok(PredicateEq {
+                    lhs_ty,
+                    eq_token: Default::default(),
+                    rhs_ty,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut lhs_ty = None;
+                let mut rhs_ty = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "lhs_ty" => lhs_ty = Some(map.next_value()?),
+                        "rhs_ty" => rhs_ty = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PredicateEq {
+                    lhs_ty: required(lhs_ty, "lhs_ty")?,
+                    eq_token: Default::default(),
+                    rhs_ty: required(rhs_ty, "rhs_ty")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PredicateEq", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PredicateLifetime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PredicateLifetime", 2usize)?;
+        state.serialize_field("lifetime", &self.lifetime)?;
+        state.serialize_field("bounds", &self.bounds)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PredicateLifetime {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["lifetime", "bounds"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = PredicateLifetime;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PredicateLifetime")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let lifetime = element(&mut seq, 0usize, &self)?;
+                let bounds = element(&mut seq, 1usize, &self)?;
+                Ok(PredicateLifetime {
+                    lifetime,
+                    colon_token: Default::default(),
+                    bounds,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut lifetime = None;
+                let mut bounds = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "lifetime" => lifetime = Some(map.next_value()?),
+                        "bounds" => bounds = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PredicateLifetime {
+                    lifetime: required(lifetime, "lifetime")?,
+                    colon_token: Default::default(),
+                    bounds: bounds.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("PredicateLifetime", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for PredicateType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PredicateType", 3usize)?;
+        state.serialize_field("lifetimes", &self.lifetimes)?;
+        state.serialize_field("bounded_ty", &self.bounded_ty)?;
+        state.serialize_field("bounds", &self.bounds)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PredicateType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["lifetimes", "bounded_ty", "bounds"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = PredicateType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PredicateType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let lifetimes = element(&mut seq, 0usize, &self)?;
+                let bounded_ty = element(&mut seq, 1usize, &self)?;
+                let bounds = element(&mut seq, 2usize, &self)?;
+                Ok(PredicateType {
+                    lifetimes,
+                    bounded_ty,
+                    colon_token: Default::default(),
+                    bounds,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut lifetimes = None;
+                let mut bounded_ty = None;
+                let mut bounds = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "lifetimes" => lifetimes = Some(map.next_value()?),
+                        "bounded_ty" => bounded_ty = Some(map.next_value()?),
+                        "bounds" => bounds = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PredicateType {
+                    lifetimes: lifetimes.unwrap_or_default(),
+                    bounded_ty: required(bounded_ty, "bounded_ty")?,
+                    colon_token: Default::default(),
+                    bounds: bounds.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("PredicateType", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for QSelf {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("QSelf", 3usize)?;
+        state.serialize_field("ty", &self.ty)?;
+        state.serialize_field("position", &self.position)?;
+        state.serialize_field("as_token", &self.as_token.is_some())?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for QSelf {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ty", "position", "as_token"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = QSelf;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct QSelf")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let ty = element(&mut seq, 0usize, &self)?;
+                let position = element(&mut seq, 1usize, &self)?;
+                let as_token = present(element::<bool, _>(&mut seq, 2usize, &self)?);
+                Ok(QSelf {
+                    lt_token: Default::default(),
+                    ty,
+                    position,
+                    as_token,
+                    gt_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut ty = None;
+                let mut position = None;
+                let mut as_token = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "ty" => ty = Some(map.next_value()?),
+                        "position" => position = Some(map.next_value()?),
+                        "as_token" => as_token = Some(present(map.next_value::<bool>()?)),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(QSelf {
+                    lt_token: Default::default(),
+                    ty: required(ty, "ty")?,
+                    position: required(position, "position")?,
+                    as_token: as_token.unwrap_or_default(),
+                    gt_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("QSelf", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for RangeLimits {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            RangeLimits::HalfOpen(_) => {
+                serializer.serialize_unit_variant("RangeLimits", 0u32, "HalfOpen")
+            }
+            RangeLimits::Closed(_) => {
+                serializer.serialize_unit_variant("RangeLimits", 1u32, "Closed")
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for RangeLimits {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["HalfOpen", "Closed"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = RangeLimits;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum RangeLimits")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "HalfOpen" => {
+                        access.unit_variant()?;
+                        Ok(RangeLimits::HalfOpen(Default::default()))
+                    }
+                    "Closed" => {
+                        access.unit_variant()?;
+                        Ok(RangeLimits::Closed(Default::default()))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("RangeLimits", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Receiver {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Receiver", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("reference", &self.reference.as_ref().map(|(_, v1)| (v1,)))?;
+        state.serialize_field("mutability", &self.mutability.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Receiver {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "reference", "mutability"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = Receiver;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Receiver")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let reference = element::<Option<(_,)>, _>(&mut seq, 1usize, &self)?
+                    .map(|(v1,)| (Default::default(), v1));
+                let mutability = present(element::<bool, _>(&mut seq, 2usize, &self)?);
+                Ok(Receiver {
+                    attrs,
+                    reference,
+                    mutability,
+                    self_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut reference = None;
+                let mut mutability = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "reference" => {
+                            reference = Some(
+                                map.next_value::<Option<(_,)>>()?
+                                    .map(|(v1,)| (Default::default(), v1)),
+                            )
+                        }
+                        "mutability" => mutability = Some(present(map.next_value::<bool>()?)),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Receiver {
+                    attrs: attrs.unwrap_or_default(),
+                    reference: reference.unwrap_or_default(),
+                    mutability: mutability.unwrap_or_default(),
+                    self_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Receiver", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for ReturnType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            ReturnType::Default => serializer.serialize_unit_variant("ReturnType", 0u32, "Default"),
+            ReturnType::Type(_, v1) => {
+                serializer.serialize_newtype_variant("ReturnType", 1u32, "Type", v1)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for ReturnType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Default", "Type"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = ReturnType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum ReturnType")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Default" => {
+                        access.unit_variant()?;
+                        Ok(ReturnType::Default)
+                    }
+                    "Type" => {
+                        let v = access.newtype_variant()?;
+                        Ok(ReturnType::Type(Default::default(), v))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("ReturnType", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Signature {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Signature", 9usize)?;
+        state.serialize_field("constness", &self.constness.is_some())?;
+        state.serialize_field("asyncness", &self.asyncness.is_some())?;
+        state.serialize_field("unsafety", &self.unsafety.is_some())?;
+        state.serialize_field("abi", &self.abi)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("inputs", &self.inputs)?;
+        state.serialize_field("variadic", &self.variadic)?;
+        state.serialize_field("output", &self.output)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Signature {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "constness",
+            "asyncness",
+            "unsafety",
+            "abi",
+            "ident",
+            "generics",
+            "inputs",
+            "variadic",
+            "output",
+        ];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = Signature;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Signature")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let constness = present(element::<bool, _>(&mut seq, 0usize, &self)?);
+                let asyncness = present(element::<bool, _>(&mut seq, 1usize, &self)?);
+                let unsafety = present(element::<bool, _>(&mut seq, 2usize, &self)?);
+                let abi = element(&mut seq, 3usize, &self)?;
+                let ident = element::<De<_>, _>(&mut seq, 4usize, &self)?.0;
+                let generics = element(&mut seq, 5usize, &self)?;
+                let inputs = element(&mut seq, 6usize, &self)?;
+                let variadic = element(&mut seq, 7usize, &self)?;
+                let output = element(&mut seq, 8usize, &self)?;
+                Ok(Signature {
+                    constness,
+                    asyncness,
+                    unsafety,
+                    abi,
+                    fn_token: Default::default(),
+                    ident,
+                    generics,
+                    paren_token: Default::default(),
+                    inputs,
+                    variadic,
+                    output,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut constness = None;
+                let mut asyncness = None;
+                let mut unsafety = None;
+                let mut abi = None;
+                let mut ident = None;
+                let mut generics = None;
+                let mut inputs = None;
+                let mut variadic = None;
+                let mut output = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "constness" => constness = Some(present(map.next_value::<bool>()?)),
+                        "asyncness" => asyncness = Some(present(map.next_value::<bool>()?)),
+                        "unsafety" => unsafety = Some(present(map.next_value::<bool>()?)),
+                        "abi" => abi = Some(map.next_value()?),
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        "generics" => generics = Some(map.next_value()?),
+                        "inputs" => inputs = Some(map.next_value()?),
+                        "variadic" => variadic = Some(map.next_value()?),
+                        "output" => output = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Signature {
+                    constness: constness.unwrap_or_default(),
+                    asyncness: asyncness.unwrap_or_default(),
+                    unsafety: unsafety.unwrap_or_default(),
+                    abi: abi.unwrap_or_default(),
+                    fn_token: Default::default(),
+                    ident: required(ident, "ident")?,
+                    generics: required(generics, "generics")?,
+                    paren_token: Default::default(),
+                    inputs: inputs.unwrap_or_default(),
+                    variadic: variadic.unwrap_or_default(),
+                    output: required(output, "output")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Signature", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Stmt {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Stmt::Local(v0) => serializer.serialize_newtype_variant("Stmt", 0u32, "Local", v0),
+            Stmt::Item(v0) => serializer.serialize_newtype_variant("Stmt", 1u32, "Item", v0),
+            Stmt::Expr(v0) => serializer.serialize_newtype_variant("Stmt", 2u32, "Expr", v0),
+            Stmt::Semi(v0, _) => serializer.serialize_newtype_variant("Stmt", 3u32, "Semi", v0),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Stmt {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Local", "Item", "Expr", "Semi"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = Stmt;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Stmt")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Local" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Stmt::Local(v))
+                    }
+                    "Item" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Stmt::Item(v))
+                    }
+                    "Expr" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Stmt::Expr(v))
+                    }
+                    "Semi" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Stmt::Semi(v, Default::default()))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Stmt", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TraitBound {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TraitBound", 4usize)?;
+        state.serialize_field("paren_token", &self.paren_token.is_some())?;
+        state.serialize_field("modifier", &self.modifier)?;
+        state.serialize_field("lifetimes", &self.lifetimes)?;
+        state.serialize_field("path", &self.path)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TraitBound {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["paren_token", "modifier", "lifetimes", "path"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = TraitBound;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TraitBound")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let paren_token = present(element::<bool, _>(&mut seq, 0usize, &self)?);
+                let modifier = element(&mut seq, 1usize, &self)?;
+                let lifetimes = element(&mut seq, 2usize, &self)?;
+                let path = element(&mut seq, 3usize, &self)?;
+                Ok(TraitBound {
+                    paren_token,
+                    modifier,
+                    lifetimes,
+                    path,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut paren_token = None;
+                let mut modifier = None;
+                let mut lifetimes = None;
+                let mut path = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "paren_token" => paren_token = Some(present(map.next_value::<bool>()?)),
+                        "modifier" => modifier = Some(map.next_value()?),
+                        "lifetimes" => lifetimes = Some(map.next_value()?),
+                        "path" => path = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TraitBound {
+                    paren_token: paren_token.unwrap_or_default(),
+                    modifier: required(modifier, "modifier")?,
+                    lifetimes: lifetimes.unwrap_or_default(),
+                    path: required(path, "path")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TraitBound", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TraitBoundModifier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            TraitBoundModifier::None => {
+                serializer.serialize_unit_variant("TraitBoundModifier", 0u32, "None")
+            }
+            TraitBoundModifier::Maybe(_) => {
+                serializer.serialize_unit_variant("TraitBoundModifier", 1u32, "Maybe")
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TraitBoundModifier {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["None", "Maybe"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = TraitBoundModifier;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum TraitBoundModifier")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "None" => {
+                        access.unit_variant()?;
+                        Ok(TraitBoundModifier::None)
+                    }
+                    "Maybe" => {
+                        access.unit_variant()?;
+                        Ok(TraitBoundModifier::Maybe(Default::default()))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("TraitBoundModifier", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TraitItem {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            TraitItem::Const(v0) => {
+                serializer.serialize_newtype_variant("TraitItem", 0u32, "Const", v0)
+            }
+            TraitItem::Method(v0) => {
+                serializer.serialize_newtype_variant("TraitItem", 1u32, "Method", v0)
+            }
+            TraitItem::Type(v0) => {
+                serializer.serialize_newtype_variant("TraitItem", 2u32, "Type", v0)
+            }
+            TraitItem::Macro(v0) => {
+                serializer.serialize_newtype_variant("TraitItem", 3u32, "Macro", v0)
+            }
+            TraitItem::Verbatim(v0) => {
+                serializer.serialize_newtype_variant("TraitItem", 4u32, "Verbatim", &Ser(v0))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TraitItem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Const", "Method", "Type", "Macro", "Verbatim"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = TraitItem;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum TraitItem")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Const" => {
+                        let v = access.newtype_variant()?;
+                        Ok(TraitItem::Const(v))
+                    }
+                    "Method" => {
+                        let v = access.newtype_variant()?;
+                        Ok(TraitItem::Method(v))
+                    }
+                    "Type" => {
+                        let v = access.newtype_variant()?;
+                        Ok(TraitItem::Type(v))
+                    }
+                    "Macro" => {
+                        let v = access.newtype_variant()?;
+                        Ok(TraitItem::Macro(v))
+                    }
+                    "Verbatim" => {
+                        let v = access.newtype_variant::<De<_>>()?;
+                        Ok(TraitItem::Verbatim(v.0))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("TraitItem", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TraitItemConst {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TraitItemConst", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("ty", &self.ty)?;
+        state.serialize_field("default", &self.default.as_ref().map(|(_, v1)| v1))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TraitItemConst {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "ident", "ty", "default"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = TraitItemConst;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TraitItemConst")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let ident = element::<De<_>, _>(&mut seq, 1usize, &self)?.0;
+                let ty = element(&mut seq, 2usize, &self)?;
+                let default = element::<Option<_>, _>(&mut seq, 3usize, &self)?
+                    .map(|v1| (Default::default(), v1));
+                Ok(TraitItemConst {
+                    attrs,
+                    const_token: Default::default(),
+                    ident,
+                    colon_token: Default::default(),
+                    ty,
+                    default,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut ident = None;
+                let mut ty = None;
+                let mut default = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        "ty" => ty = Some(map.next_value()?),
+                        "default" => {
+                            default = Some(
+                                map.next_value::<Option<_>>()?
+                                    .map(|v1| (Default::default(), v1)),
+                            )
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TraitItemConst {
+                    attrs: attrs.unwrap_or_default(),
+                    const_token: Default::default(),
+                    ident: required(ident, "ident")?,
+                    colon_token: Default::default(),
+                    ty: required(ty, "ty")?,
+                    default: default.unwrap_or_default(),
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("TraitItemConst", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TraitItemMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TraitItemMacro", 3usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("mac", &self.mac)?;
+        state.serialize_field("semi_token", &self.semi_token.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TraitItemMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "mac", "semi_token"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = TraitItemMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TraitItemMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let mac = element(&mut seq, 1usize, &self)?;
+                let semi_token = present(element::<bool, _>(&mut seq, 2usize, &self)?);
+                Ok(TraitItemMacro {
+                    attrs,
+                    mac,
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut mac = None;
+                let mut semi_token = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "mac" => mac = Some(map.next_value()?),
+                        "semi_token" => semi_token = Some(present(map.next_value::<bool>()?)),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TraitItemMacro {
+                    attrs: attrs.unwrap_or_default(),
+                    mac: required(mac, "mac")?,
+                    semi_token: semi_token.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("TraitItemMacro", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TraitItemMethod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TraitItemMethod", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("sig", &self.sig)?;
+        state.serialize_field("default", &self.default)?;
+        state.serialize_field("semi_token", &self.semi_token.is_some())?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TraitItemMethod {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "sig", "default", "semi_token"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = TraitItemMethod;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TraitItemMethod")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let sig = element(&mut seq, 1usize, &self)?;
+                let default = element(&mut seq, 2usize, &self)?;
+                let semi_token = present(element::<bool, _>(&mut seq, 3usize, &self)?);
+                Ok(TraitItemMethod {
+                    attrs,
+                    sig,
+                    default,
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut sig = None;
+                let mut default = None;
+                let mut semi_token = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "sig" => sig = Some(map.next_value()?),
+                        "default" => default = Some(map.next_value()?),
+                        "semi_token" => semi_token = Some(present(map.next_value::<bool>()?)),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TraitItemMethod {
+                    attrs: attrs.unwrap_or_default(),
+                    sig: required(sig, "sig")?,
+                    default: default.unwrap_or_default(),
+                    semi_token: semi_token.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("TraitItemMethod", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TraitItemType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TraitItemType", 6usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("colon_token", &self.colon_token.is_some())?;
+        state.serialize_field("bounds", &self.bounds)?;
+        state.serialize_field("default", &self.default.as_ref().map(|(_, v1)| v1))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TraitItemType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "ident",
+            "generics",
+            "colon_token",
+            "bounds",
+            "default",
+        ];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = TraitItemType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TraitItemType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let ident = element::<De<_>, _>(&mut seq, 1usize, &self)?.0;
+                let generics = element(&mut seq, 2usize, &self)?;
+                let colon_token = present(element::<bool, _>(&mut seq, 3usize, &self)?);
+                let bounds = element(&mut seq, 4usize, &self)?;
+                let default = element::<Option<_>, _>(&mut seq, 5usize, &self)?
+                    .map(|v1| (Default::default(), v1));
+                Ok(TraitItemType {
+                    attrs,
+                    type_token: Default::default(),
+                    ident,
+                    generics,
+                    colon_token,
+                    bounds,
+                    default,
+                    semi_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut ident = None;
+                let mut generics = None;
+                let mut colon_token = None;
+                let mut bounds = None;
+                let mut default = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        "generics" => generics = Some(map.next_value()?),
+                        "colon_token" => colon_token = Some(present(map.next_value::<bool>()?)),
+                        "bounds" => bounds = Some(map.next_value()?),
+                        "default" => {
+                            default = Some(
+                                map.next_value::<Option<_>>()?
+                                    .map(|v1| (Default::default(), v1)),
+                            )
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TraitItemType {
+                    attrs: attrs.unwrap_or_default(),
+                    type_token: Default::default(),
+                    ident: required(ident, "ident")?,
+                    generics: required(generics, "generics")?,
+                    colon_token: colon_token.unwrap_or_default(),
+                    bounds: bounds.unwrap_or_default(),
+                    default: default.unwrap_or_default(),
+                    semi_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("TraitItemType", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Type::Array(v0) => serializer.serialize_newtype_variant("Type", 0u32, "Array", v0),
+            Type::BareFn(v0) => serializer.serialize_newtype_variant("Type", 1u32, "BareFn", v0),
+            Type::Group(v0) => serializer.serialize_newtype_variant("Type", 2u32, "Group", v0),
+            Type::ImplTrait(v0) => {
+                serializer.serialize_newtype_variant("Type", 3u32, "ImplTrait", v0)
+            }
+            Type::Infer(v0) => serializer.serialize_newtype_variant("Type", 4u32, "Infer", v0),
+            Type::Macro(v0) => serializer.serialize_newtype_variant("Type", 5u32, "Macro", v0),
+            Type::Never(v0) => serializer.serialize_newtype_variant("Type", 6u32, "Never", v0),
+            Type::Paren(v0) => serializer.serialize_newtype_variant("Type", 7u32, "Paren", v0),
+            Type::Path(v0) => serializer.serialize_newtype_variant("Type", 8u32, "Path", v0),
+            Type::Ptr(v0) => serializer.serialize_newtype_variant("Type", 9u32, "Ptr", v0),
+            Type::Reference(v0) => {
+                serializer.serialize_newtype_variant("Type", 10u32, "Reference", v0)
+            }
+            Type::Slice(v0) => serializer.serialize_newtype_variant("Type", 11u32, "Slice", v0),
+            Type::TraitObject(v0) => {
+                serializer.serialize_newtype_variant("Type", 12u32, "TraitObject", v0)
+            }
+            Type::Tuple(v0) => serializer.serialize_newtype_variant("Type", 13u32, "Tuple", v0),
+            Type::Verbatim(v0) => {
+                serializer.serialize_newtype_variant("Type", 14u32, "Verbatim", &Ser(v0))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &[
+            "Array",
+            "BareFn",
+            "Group",
+            "ImplTrait",
+            "Infer",
+            "Macro",
+            "Never",
+            "Paren",
+            "Path",
+            "Ptr",
+            "Reference",
+            "Slice",
+            "TraitObject",
+            "Tuple",
+            "Verbatim",
+        ];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = Type;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Type")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Array" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Type::Array(v))
+                    }
+                    "BareFn" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Type::BareFn(v))
+                    }
+                    "Group" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Type::Group(v))
+                    }
+                    "ImplTrait" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Type::ImplTrait(v))
+                    }
+                    "Infer" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Type::Infer(v))
+                    }
+                    "Macro" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Type::Macro(v))
+                    }
+                    "Never" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Type::Never(v))
+                    }
+                    "Paren" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Type::Paren(v))
+                    }
+                    "Path" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Type::Path(v))
+                    }
+                    "Ptr" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Type::Ptr(v))
+                    }
+                    "Reference" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Type::Reference(v))
+                    }
+                    "Slice" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Type::Slice(v))
+                    }
+                    "TraitObject" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Type::TraitObject(v))
+                    }
+                    "Tuple" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Type::Tuple(v))
+                    }
+                    "Verbatim" => {
+                        let v = access.newtype_variant::<De<_>>()?;
+                        Ok(Type::Verbatim(v.0))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Type", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeArray {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeArray", 2usize)?;
+        state.serialize_field("elem", &self.elem)?;
+        state.serialize_field("len", &self.len)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeArray {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["elem", "len"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = TypeArray;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeArray")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let elem = element(&mut seq, 0usize, &self)?;
+                let len = element(&mut seq, 1usize, &self)?;
+                Ok(TypeArray {
+                    bracket_token: Default::default(),
+                    elem,
+                    semi_token: Default::default(),
+                    len,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut elem = None;
+                let mut len = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "elem" => elem = Some(map.next_value()?),
+                        "len" => len = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypeArray {
+                    bracket_token: Default::default(),
+                    elem: required(elem, "elem")?,
+                    semi_token: Default::default(),
+                    len: required(len, "len")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeArray", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeBareFn {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeBareFn", 6usize)?;
+        state.serialize_field("lifetimes", &self.lifetimes)?;
+        state.serialize_field("unsafety", &self.unsafety.is_some())?;
+        state.serialize_field("abi", &self.abi)?;
+        state.serialize_field("inputs", &self.inputs)?;
+        state.serialize_field("variadic", &self.variadic)?;
+        state.serialize_field("output", &self.output)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeBareFn {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "lifetimes",
+            "unsafety",
+            "abi",
+            "inputs",
+            "variadic",
+            "output",
+        ];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = TypeBareFn;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeBareFn")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let lifetimes = element(&mut seq, 0usize, &self)?;
+                let unsafety = present(element::<bool, _>(&mut seq, 1usize, &self)?);
+                let abi = element(&mut seq, 2usize, &self)?;
+                let inputs = element(&mut seq, 3usize, &self)?;
+                let variadic = element(&mut seq, 4usize, &self)?;
+                let output = element(&mut seq, 5usize, &self)?;
+                Ok(TypeBareFn {
+                    lifetimes,
+                    unsafety,
+                    abi,
+                    fn_token: Default::default(),
+                    paren_token: Default::default(),
+                    inputs,
+                    variadic,
+                    output,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut lifetimes = None;
+                let mut unsafety = None;
+                let mut abi = None;
+                let mut inputs = None;
+                let mut variadic = None;
+                let mut output = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "lifetimes" => lifetimes = Some(map.next_value()?),
+                        "unsafety" => unsafety = Some(present(map.next_value::<bool>()?)),
+                        "abi" => abi = Some(map.next_value()?),
+                        "inputs" => inputs = Some(map.next_value()?),
+                        "variadic" => variadic = Some(map.next_value()?),
+                        "output" => output = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypeBareFn {
+                    lifetimes: lifetimes.unwrap_or_default(),
+                    unsafety: unsafety.unwrap_or_default(),
+                    abi: abi.unwrap_or_default(),
+                    fn_token: Default::default(),
+                    paren_token: Default::default(),
+                    inputs: inputs.unwrap_or_default(),
+                    variadic: variadic.unwrap_or_default(),
+                    output: required(output, "output")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeBareFn", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeGroup {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeGroup", 1usize)?;
+        state.serialize_field("elem", &self.elem)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeGroup {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["elem"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = TypeGroup;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeGroup")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let elem = element(&mut seq, 0usize, &self)?;
+                Ok(TypeGroup {
+                    group_token: Default::default(),
+                    elem,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut elem = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "elem" => elem = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypeGroup {
+                    group_token: Default::default(),
+                    elem: required(elem, "elem")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeGroup", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeImplTrait {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeImplTrait", 1usize)?;
+        state.serialize_field("bounds", &self.bounds)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeImplTrait {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["bounds"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = TypeImplTrait;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeImplTrait")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let bounds = element(&mut seq, 0usize, &self)?;
+                Ok(TypeImplTrait {
+                    impl_token: Default::default(),
+                    bounds,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut bounds = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "bounds" => bounds = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypeImplTrait {
+                    impl_token: Default::default(),
+                    bounds: bounds.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeImplTrait", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeInfer {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_struct("TypeInfer", 0)?.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeInfer {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = TypeInfer;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeInfer")
+            }
+            fn visit_seq<A>(self, _seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(TypeInfer {
+                    underscore_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                while field(&mut map, FIELDS)?.is_some() {
+                    map.next_value::<IgnoredAny>()?;
+                }
+                Ok(TypeInfer {
+                    underscore_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeInfer", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeMacro", 1usize)?;
+        state.serialize_field("mac", &self.mac)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["mac"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = TypeMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mac = element(&mut seq, 0usize, &self)?;
+                Ok(TypeMacro { mac })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut mac = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "mac" => mac = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypeMacro {
+                    mac: required(mac, "mac")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeMacro", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeNever {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_struct("TypeNever", 0)?.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeNever {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = TypeNever;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeNever")
+            }
+            fn visit_seq<A>(self, _seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(TypeNever {
+                    bang_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                while field(&mut map, FIELDS)?.is_some() {
+                    map.next_value::<IgnoredAny>()?;
+                }
+                Ok(TypeNever {
+                    bang_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeNever", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeParam {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeParam", 6usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("colon_token", &self.colon_token.is_some())?;
+        state.serialize_field("bounds", &self.bounds)?;
+        state.serialize_field("eq_token", &self.eq_token.is_some())?;
+        state.serialize_field("default", &self.default)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeParam {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "ident",
+            "colon_token",
+            "bounds",
+            "eq_token",
+            "default",
+        ];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = TypeParam;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeParam")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let ident = element::<De<_>, _>(&mut seq, 1usize, &self)?.0;
+                let colon_token = present(element::<bool, _>(&mut seq, 2usize, &self)?);
+                let bounds = element(&mut seq, 3usize, &self)?;
+                let eq_token = present(element::<bool, _>(&mut seq, 4usize, &self)?);
+                let default = element(&mut seq, 5usize, &self)?;
+                Ok(TypeParam {
+                    attrs,
+                    ident,
+                    colon_token,
+                    bounds,
+                    eq_token,
+                    default,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut ident = None;
+                let mut colon_token = None;
+                let mut bounds = None;
+                let mut eq_token = None;
+                let mut default = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        "colon_token" => colon_token = Some(present(map.next_value::<bool>()?)),
+                        "bounds" => bounds = Some(map.next_value()?),
+                        "eq_token" => eq_token = Some(present(map.next_value::<bool>()?)),
+                        "default" => default = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypeParam {
+                    attrs: attrs.unwrap_or_default(),
+                    ident: required(ident, "ident")?,
+                    colon_token: colon_token.unwrap_or_default(),
+                    bounds: bounds.unwrap_or_default(),
+                    eq_token: eq_token.unwrap_or_default(),
+                    default: default.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeParam", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeParamBound {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            TypeParamBound::Trait(v0) => {
+                serializer.serialize_newtype_variant("TypeParamBound", 0u32, "Trait", v0)
+            }
+            TypeParamBound::Lifetime(v0) => {
+                serializer.serialize_newtype_variant("TypeParamBound", 1u32, "Lifetime", v0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeParamBound {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Trait", "Lifetime"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = TypeParamBound;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum TypeParamBound")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Trait" => {
+                        let v = access.newtype_variant()?;
+                        Ok(TypeParamBound::Trait(v))
+                    }
+                    "Lifetime" => {
+                        let v = access.newtype_variant()?;
+                        Ok(TypeParamBound::Lifetime(v))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("TypeParamBound", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeParen {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeParen", 1usize)?;
+        state.serialize_field("elem", &self.elem)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeParen {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["elem"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = TypeParen;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeParen")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let elem = element(&mut seq, 0usize, &self)?;
+                Ok(TypeParen {
+                    paren_token: Default::default(),
+                    elem,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut elem = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "elem" => elem = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypeParen {
+                    paren_token: Default::default(),
+                    elem: required(elem, "elem")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeParen", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypePath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypePath", 2usize)?;
+        state.serialize_field("qself", &self.qself)?;
+        state.serialize_field("path", &self.path)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypePath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["qself", "path"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = TypePath;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypePath")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let qself = element(&mut seq, 0usize, &self)?;
+                let path = element(&mut seq, 1usize, &self)?;
+                Ok(TypePath { qself, path })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut qself = None;
+                let mut path = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "qself" => qself = Some(map.next_value()?),
+                        "path" => path = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypePath {
+                    qself: qself.unwrap_or_default(),
+                    path: required(path, "path")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypePath", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypePtr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypePtr", 3usize)?;
+        state.serialize_field("const_token", &self.const_token.is_some())?;
+        state.serialize_field("mutability", &self.mutability.is_some())?;
+        state.serialize_field("elem", &self.elem)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypePtr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["const_token", "mutability", "elem"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = TypePtr;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypePtr")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let const_token = present(element::<bool, _>(&mut seq, 0usize, &self)?);
+                let mutability = present(element::<bool, _>(&mut seq, 1usize, &self)?);
+                let elem = element(&mut seq, 2usize, &self)?;
+                Ok(TypePtr {
+                    star_token: Default::default(),
+                    const_token,
+                    mutability,
+                    elem,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut const_token = None;
+                let mut mutability = None;
+                let mut elem = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "const_token" => const_token = Some(present(map.next_value::<bool>()?)),
+                        "mutability" => mutability = Some(present(map.next_value::<bool>()?)),
+                        "elem" => elem = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypePtr {
+                    star_token: Default::default(),
+                    const_token: const_token.unwrap_or_default(),
+                    mutability: mutability.unwrap_or_default(),
+                    elem: required(elem, "elem")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypePtr", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeReference {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeReference", 3usize)?;
+        state.serialize_field("lifetime", &self.lifetime)?;
+        state.serialize_field("mutability", &self.mutability.is_some())?;
+        state.serialize_field("elem", &self.elem)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeReference {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["lifetime", "mutability", "elem"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = TypeReference;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeReference")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let lifetime = element(&mut seq, 0usize, &self)?;
+                let mutability = present(element::<bool, _>(&mut seq, 1usize, &self)?);
+                let elem = element(&mut seq, 2usize, &self)?;
+                Ok(TypeReference {
+                    and_token: Default::default(),
+                    lifetime,
+                    mutability,
+                    elem,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut lifetime = None;
+                let mut mutability = None;
+                let mut elem = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "lifetime" => lifetime = Some(map.next_value()?),
+                        "mutability" => mutability = Some(present(map.next_value::<bool>()?)),
+                        "elem" => elem = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypeReference {
+                    and_token: Default::default(),
+                    lifetime: lifetime.unwrap_or_default(),
+                    mutability: mutability.unwrap_or_default(),
+                    elem: required(elem, "elem")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeReference", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeSlice {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeSlice", 1usize)?;
+        state.serialize_field("elem", &self.elem)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeSlice {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["elem"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = TypeSlice;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeSlice")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let elem = element(&mut seq, 0usize, &self)?;
+                Ok(TypeSlice {
+                    bracket_token: Default::default(),
+                    elem,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut elem = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "elem" => elem = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypeSlice {
+                    bracket_token: Default::default(),
+                    elem: required(elem, "elem")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeSlice", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeTraitObject {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeTraitObject", 2usize)?;
+        state.serialize_field("dyn_token", &self.dyn_token.is_some())?;
+        state.serialize_field("bounds", &self.bounds)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeTraitObject {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["dyn_token", "bounds"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = TypeTraitObject;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeTraitObject")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let dyn_token = present(element::<bool, _>(&mut seq, 0usize, &self)?);
+                let bounds = element(&mut seq, 1usize, &self)?;
+                Ok(TypeTraitObject { dyn_token, bounds })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut dyn_token = None;
+                let mut bounds = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "dyn_token" => dyn_token = Some(present(map.next_value::<bool>()?)),
+                        "bounds" => bounds = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypeTraitObject {
+                    dyn_token: dyn_token.unwrap_or_default(),
+                    bounds: bounds.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeTraitObject", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for TypeTuple {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeTuple", 1usize)?;
+        state.serialize_field("elems", &self.elems)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for TypeTuple {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["elems"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = TypeTuple;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeTuple")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let elems = element(&mut seq, 0usize, &self)?;
+                Ok(TypeTuple {
+                    paren_token: Default::default(),
+                    elems,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut elems = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "elems" => elems = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TypeTuple {
+                    paren_token: Default::default(),
+                    elems: elems.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeTuple", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for UnOp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            UnOp::Deref(_) => serializer.serialize_unit_variant("UnOp", 0u32, "Deref"),
+            UnOp::Not(_) => serializer.serialize_unit_variant("UnOp", 1u32, "Not"),
+            UnOp::Neg(_) => serializer.serialize_unit_variant("UnOp", 2u32, "Neg"),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for UnOp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Deref", "Not", "Neg"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = UnOp;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum UnOp")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Deref" => {
+                        access.unit_variant()?;
+                        Ok(UnOp::Deref(Default::default()))
+                    }
+                    "Not" => {
+                        access.unit_variant()?;
+                        Ok(UnOp::Not(Default::default()))
+                    }
+                    "Neg" => {
+                        access.unit_variant()?;
+                        Ok(UnOp::Neg(Default::default()))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("UnOp", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for UseGlob {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_struct("UseGlob", 0)?.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for UseGlob {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = UseGlob;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct UseGlob")
+            }
+            fn visit_seq<A>(self, _seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(UseGlob {
+                    star_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                while field(&mut map, FIELDS)?.is_some() {
+                    map.next_value::<IgnoredAny>()?;
+                }
+                Ok(UseGlob {
+                    star_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("UseGlob", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for UseGroup {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("UseGroup", 1usize)?;
+        state.serialize_field("items", &self.items)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for UseGroup {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["items"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = UseGroup;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct UseGroup")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let items = element(&mut seq, 0usize, &self)?;
+                Ok(UseGroup {
+                    brace_token: Default::default(),
+                    items,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut items = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "items" => items = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(UseGroup {
+                    brace_token: Default::default(),
+                    items: items.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("UseGroup", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for UseName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("UseName", 1usize)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for UseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = UseName;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct UseName")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let ident = element::<De<_>, _>(&mut seq, 0usize, &self)?.0;
+                Ok(UseName { ident })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut ident = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(UseName {
+                    ident: required(ident, "ident")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("UseName", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for UsePath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("UsePath", 2usize)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("tree", &self.tree)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for UsePath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident", "tree"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = UsePath;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct UsePath")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let ident = element::<De<_>, _>(&mut seq, 0usize, &self)?.0;
+                let tree = element(&mut seq, 1usize, &self)?;
+                Ok(UsePath {
+                    ident,
+                    colon2_token: Default::default(),
+                    tree,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut ident = None;
+                let mut tree = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        "tree" => tree = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(UsePath {
+                    ident: required(ident, "ident")?,
+                    colon2_token: Default::default(),
+                    tree: required(tree, "tree")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("UsePath", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for UseRename {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("UseRename", 2usize)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("rename", &Ser(&self.rename))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for UseRename {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident", "rename"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = UseRename;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct UseRename")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let ident = element::<De<_>, _>(&mut seq, 0usize, &self)?.0;
+                let rename = element::<De<_>, _>(&mut seq, 1usize, &self)?.0;
+                Ok(UseRename {
+                    ident,
+                    as_token: Default::default(),
+                    rename,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut ident = None;
+                let mut rename = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        "rename" => rename = Some(map.next_value::<De<_>>()?.0),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(UseRename {
+                    ident: required(ident, "ident")?,
+                    as_token: Default::default(),
+                    rename: required(rename, "rename")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("UseRename", FIELDS, StructVisitor)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for UseTree {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            UseTree::Path(v0) => serializer.serialize_newtype_variant("UseTree", 0u32, "Path", v0),
+            UseTree::Name(v0) => serializer.serialize_newtype_variant("UseTree", 1u32, "Name", v0),
+            UseTree::Rename(v0) => {
+                serializer.serialize_newtype_variant("UseTree", 2u32, "Rename", v0)
+            }
+            UseTree::Glob(v0) => serializer.serialize_newtype_variant("UseTree", 3u32, "Glob", v0),
+            UseTree::Group(v0) => {
+                serializer.serialize_newtype_variant("UseTree", 4u32, "Group", v0)
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for UseTree {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Path", "Name", "Rename", "Glob", "Group"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = UseTree;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum UseTree")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Path" => {
+                        let v = access.newtype_variant()?;
+                        Ok(UseTree::Path(v))
+                    }
+                    "Name" => {
+                        let v = access.newtype_variant()?;
+                        Ok(UseTree::Name(v))
+                    }
+                    "Rename" => {
+                        let v = access.newtype_variant()?;
+                        Ok(UseTree::Rename(v))
+                    }
+                    "Glob" => {
+                        let v = access.newtype_variant()?;
+                        Ok(UseTree::Glob(v))
+                    }
+                    "Group" => {
+                        let v = access.newtype_variant()?;
+                        Ok(UseTree::Group(v))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("UseTree", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Variadic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Variadic", 1usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Variadic {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = Variadic;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Variadic")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                Ok(Variadic {
+                    attrs,
+                    dots: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Variadic {
+                    attrs: attrs.unwrap_or_default(),
+                    dots: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Variadic", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Variant {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Variant", 4usize)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("fields", &self.fields)?;
+        state.serialize_field(
+            "discriminant",
+            &self.discriminant.as_ref().map(|(_, v1)| v1),
+        )?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Variant {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "ident", "fields", "discriminant"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = Variant;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Variant")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs = element(&mut seq, 0usize, &self)?;
+                let ident = element::<De<_>, _>(&mut seq, 1usize, &self)?.0;
+                let fields = element(&mut seq, 2usize, &self)?;
+                let discriminant = element::<Option<_>, _>(&mut seq, 3usize, &self)?
+                    .map(|v1| (Default::default(), v1));
+                Ok(Variant {
+                    attrs,
+                    ident,
+                    fields,
+                    discriminant,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs = None;
+                let mut ident = None;
+                let mut fields = None;
+                let mut discriminant = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "attrs" => attrs = Some(map.next_value()?),
+                        "ident" => ident = Some(map.next_value::<De<_>>()?.0),
+                        "fields" => fields = Some(map.next_value()?),
+                        "discriminant" => {
+                            discriminant = Some(
+                                map.next_value::<Option<_>>()?
+                                    .map(|v1| (Default::default(), v1)),
+                            )
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Variant {
+                    attrs: attrs.unwrap_or_default(),
+                    ident: required(ident, "ident")?,
+                    fields: required(fields, "fields")?,
+                    discriminant: discriminant.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Variant", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for VisCrate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_struct("VisCrate", 0)?.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for VisCrate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = VisCrate;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct VisCrate")
+            }
+            fn visit_seq<A>(self, _seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(VisCrate {
+                    crate_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                while field(&mut map, FIELDS)?.is_some() {
+                    map.next_value::<IgnoredAny>()?;
+                }
+                Ok(VisCrate {
+                    crate_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("VisCrate", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for VisPublic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_struct("VisPublic", 0)?.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for VisPublic {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = VisPublic;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct VisPublic")
+            }
+            fn visit_seq<A>(self, _seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                Ok(VisPublic {
+                    pub_token: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                while field(&mut map, FIELDS)?.is_some() {
+                    map.next_value::<IgnoredAny>()?;
+                }
+                Ok(VisPublic {
+                    pub_token: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("VisPublic", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for VisRestricted {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("VisRestricted", 2usize)?;
+        state.serialize_field("in_token", &self.in_token.is_some())?;
+        state.serialize_field("path", &self.path)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for VisRestricted {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["in_token", "path"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = VisRestricted;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct VisRestricted")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let in_token = present(element::<bool, _>(&mut seq, 0usize, &self)?);
+                let path = element(&mut seq, 1usize, &self)?;
+                Ok(VisRestricted {
+                    pub_token: Default::default(),
+                    paren_token: Default::default(),
+                    in_token,
+                    path,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut in_token = None;
+                let mut path = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "in_token" => in_token = Some(present(map.next_value::<bool>()?)),
+                        "path" => path = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(VisRestricted {
+                    pub_token: Default::default(),
+                    paren_token: Default::default(),
+                    in_token: in_token.unwrap_or_default(),
+                    path: required(path, "path")?,
+                })
+            }
+        }
+        deserializer.deserialize_struct("VisRestricted", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for Visibility {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Visibility::Public(v0) => {
+                serializer.serialize_newtype_variant("Visibility", 0u32, "Public", v0)
+            }
+            Visibility::Crate(v0) => {
+                serializer.serialize_newtype_variant("Visibility", 1u32, "Crate", v0)
+            }
+            Visibility::Restricted(v0) => {
+                serializer.serialize_newtype_variant("Visibility", 2u32, "Restricted", v0)
+            }
+            Visibility::Inherited => {
+                serializer.serialize_unit_variant("Visibility", 3u32, "Inherited")
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Visibility {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Public", "Crate", "Restricted", "Inherited"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = Visibility;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Visibility")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Public" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Visibility::Public(v))
+                    }
+                    "Crate" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Visibility::Crate(v))
+                    }
+                    "Restricted" => {
+                        let v = access.newtype_variant()?;
+                        Ok(Visibility::Restricted(v))
+                    }
+                    "Inherited" => {
+                        access.unit_variant()?;
+                        Ok(Visibility::Inherited)
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Visibility", VARIANTS, EnumVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for WhereClause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("WhereClause", 1usize)?;
+        state.serialize_field("predicates", &self.predicates)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for WhereClause {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["predicates"];
+        struct StructVisitor;
+        impl<'de> Visitor<'de> for StructVisitor {
+            type Value = WhereClause;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct WhereClause")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let predicates = element(&mut seq, 0usize, &self)?;
+                Ok(WhereClause {
+                    where_token: Default::default(),
+                    predicates,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut predicates = None;
+                while let Some(key) = field(&mut map, FIELDS)? {
+                    match key {
+                        "predicates" => predicates = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(WhereClause {
+                    where_token: Default::default(),
+                    predicates: predicates.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("WhereClause", FIELDS, StructVisitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl Serialize for WherePredicate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            WherePredicate::Type(v0) => {
+                serializer.serialize_newtype_variant("WherePredicate", 0u32, "Type", v0)
+            }
+            WherePredicate::Lifetime(v0) => {
+                serializer.serialize_newtype_variant("WherePredicate", 1u32, "Lifetime", v0)
+            }
+            WherePredicate::Eq(v0) => {
+                serializer.serialize_newtype_variant("WherePredicate", 2u32, "Eq", v0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for WherePredicate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Type", "Lifetime", "Eq"];
+        struct EnumVisitor;
+        impl<'de> Visitor<'de> for EnumVisitor {
+            type Value = WherePredicate;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum WherePredicate")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (name, access) = variant(data, VARIANTS)?;
+                match name {
+                    "Type" => {
+                        let v = access.newtype_variant()?;
+                        Ok(WherePredicate::Type(v))
+                    }
+                    "Lifetime" => {
+                        let v = access.newtype_variant()?;
+                        Ok(WherePredicate::Lifetime(v))
+                    }
+                    "Eq" => {
+                        let v = access.newtype_variant()?;
+                        Ok(WherePredicate::Eq(v))
+                    }
+                    _ => Err(de::Error::unknown_variant(name, VARIANTS)),
+                }
+            }
+        }
+        deserializer.deserialize_enum("WherePredicate", VARIANTS, EnumVisitor)
+    }
+}
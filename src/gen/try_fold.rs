@@ -0,0 +1,3885 @@
+// This file is @generated by syn-internal-codegen.
+// It is not intended for manual editing.
+
+#![allow(unreachable_code, unused_variables)]
+#![allow(
+    clippy::match_wildcard_for_single_variants,
+    clippy::needless_question_mark
+)]
+#[cfg(any(feature = "full", feature = "derive"))]
+use crate::gen::helper::try_fold::*;
+#[cfg(any(feature = "full", feature = "derive"))]
+use crate::token::{Brace, Bracket, Group, Paren};
+use crate::*;
+use proc_macro2::Span;
+use std::result::Result;
+#[cfg(feature = "full")]
+macro_rules! full {
+    ($e:expr) => {
+        $e
+    };
+}
+#[cfg(all(feature = "derive", not(feature = "full")))]
+macro_rules! full {
+    ($e:expr) => {
+        unreachable!()
+    };
+}
+/// Syntax tree traversal to transform the nodes of an owned syntax tree,
+/// stopping at the first error.
+///
+/// See the [module documentation] for details.
+///
+/// [module documentation]: self
+///
+/// *This trait is available only if Syn is built with the `"try-fold"` feature.*
+pub trait TryFold {
+    /// The error that a method returns to abandon the transformation.
+    type Error;
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_abi(&mut self, i: Abi) -> Result<Abi, Self::Error> {
+        fold_abi(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_angle_bracketed_generic_arguments(
+        &mut self,
+        i: AngleBracketedGenericArguments,
+    ) -> Result<AngleBracketedGenericArguments, Self::Error> {
+        fold_angle_bracketed_generic_arguments(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_arm(&mut self, i: Arm) -> Result<Arm, Self::Error> {
+        fold_arm(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_attr_meta(&mut self, i: AttrMeta) -> Result<AttrMeta, Self::Error> {
+        fold_attr_meta(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_attr_meta_list(&mut self, i: AttrMetaList) -> Result<AttrMetaList, Self::Error> {
+        fold_attr_meta_list(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_attr_meta_name_value(
+        &mut self,
+        i: AttrMetaNameValue,
+    ) -> Result<AttrMetaNameValue, Self::Error> {
+        fold_attr_meta_name_value(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_attr_style(&mut self, i: AttrStyle) -> Result<AttrStyle, Self::Error> {
+        fold_attr_style(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_attribute(&mut self, i: Attribute) -> Result<Attribute, Self::Error> {
+        fold_attribute(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_bare_fn_arg(&mut self, i: BareFnArg) -> Result<BareFnArg, Self::Error> {
+        fold_bare_fn_arg(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_bin_op(&mut self, i: BinOp) -> Result<BinOp, Self::Error> {
+        fold_bin_op(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_binding(&mut self, i: Binding) -> Result<Binding, Self::Error> {
+        fold_binding(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_block(&mut self, i: Block) -> Result<Block, Self::Error> {
+        fold_block(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_bound_lifetimes(&mut self, i: BoundLifetimes) -> Result<BoundLifetimes, Self::Error> {
+        fold_bound_lifetimes(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_const_param(&mut self, i: ConstParam) -> Result<ConstParam, Self::Error> {
+        fold_const_param(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_constraint(&mut self, i: Constraint) -> Result<Constraint, Self::Error> {
+        fold_constraint(self, i)
+    }
+    #[cfg(feature = "derive")]
+    fn fold_data(&mut self, i: Data) -> Result<Data, Self::Error> {
+        fold_data(self, i)
+    }
+    #[cfg(feature = "derive")]
+    fn fold_data_enum(&mut self, i: DataEnum) -> Result<DataEnum, Self::Error> {
+        fold_data_enum(self, i)
+    }
+    #[cfg(feature = "derive")]
+    fn fold_data_struct(&mut self, i: DataStruct) -> Result<DataStruct, Self::Error> {
+        fold_data_struct(self, i)
+    }
+    #[cfg(feature = "derive")]
+    fn fold_data_union(&mut self, i: DataUnion) -> Result<DataUnion, Self::Error> {
+        fold_data_union(self, i)
+    }
+    #[cfg(feature = "derive")]
+    fn fold_derive_input(&mut self, i: DeriveInput) -> Result<DeriveInput, Self::Error> {
+        fold_derive_input(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_expr(&mut self, i: Expr) -> Result<Expr, Self::Error> {
+        fold_expr(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_array(&mut self, i: ExprArray) -> Result<ExprArray, Self::Error> {
+        fold_expr_array(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_assign(&mut self, i: ExprAssign) -> Result<ExprAssign, Self::Error> {
+        fold_expr_assign(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_assign_op(&mut self, i: ExprAssignOp) -> Result<ExprAssignOp, Self::Error> {
+        fold_expr_assign_op(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_async(&mut self, i: ExprAsync) -> Result<ExprAsync, Self::Error> {
+        fold_expr_async(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_await(&mut self, i: ExprAwait) -> Result<ExprAwait, Self::Error> {
+        fold_expr_await(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_expr_binary(&mut self, i: ExprBinary) -> Result<ExprBinary, Self::Error> {
+        fold_expr_binary(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_block(&mut self, i: ExprBlock) -> Result<ExprBlock, Self::Error> {
+        fold_expr_block(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_box(&mut self, i: ExprBox) -> Result<ExprBox, Self::Error> {
+        fold_expr_box(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_break(&mut self, i: ExprBreak) -> Result<ExprBreak, Self::Error> {
+        fold_expr_break(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_expr_call(&mut self, i: ExprCall) -> Result<ExprCall, Self::Error> {
+        fold_expr_call(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_expr_cast(&mut self, i: ExprCast) -> Result<ExprCast, Self::Error> {
+        fold_expr_cast(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_closure(&mut self, i: ExprClosure) -> Result<ExprClosure, Self::Error> {
+        fold_expr_closure(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_const(&mut self, i: ExprConst) -> Result<ExprConst, Self::Error> {
+        fold_expr_const(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_continue(&mut self, i: ExprContinue) -> Result<ExprContinue, Self::Error> {
+        fold_expr_continue(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_expr_field(&mut self, i: ExprField) -> Result<ExprField, Self::Error> {
+        fold_expr_field(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_for_loop(&mut self, i: ExprForLoop) -> Result<ExprForLoop, Self::Error> {
+        fold_expr_for_loop(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_group(&mut self, i: ExprGroup) -> Result<ExprGroup, Self::Error> {
+        fold_expr_group(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_if(&mut self, i: ExprIf) -> Result<ExprIf, Self::Error> {
+        fold_expr_if(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_expr_index(&mut self, i: ExprIndex) -> Result<ExprIndex, Self::Error> {
+        fold_expr_index(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_let(&mut self, i: ExprLet) -> Result<ExprLet, Self::Error> {
+        fold_expr_let(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_expr_lit(&mut self, i: ExprLit) -> Result<ExprLit, Self::Error> {
+        fold_expr_lit(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_loop(&mut self, i: ExprLoop) -> Result<ExprLoop, Self::Error> {
+        fold_expr_loop(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_macro(&mut self, i: ExprMacro) -> Result<ExprMacro, Self::Error> {
+        fold_expr_macro(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_match(&mut self, i: ExprMatch) -> Result<ExprMatch, Self::Error> {
+        fold_expr_match(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_method_call(&mut self, i: ExprMethodCall) -> Result<ExprMethodCall, Self::Error> {
+        fold_expr_method_call(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_expr_paren(&mut self, i: ExprParen) -> Result<ExprParen, Self::Error> {
+        fold_expr_paren(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_expr_path(&mut self, i: ExprPath) -> Result<ExprPath, Self::Error> {
+        fold_expr_path(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_range(&mut self, i: ExprRange) -> Result<ExprRange, Self::Error> {
+        fold_expr_range(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_raw_addr(&mut self, i: ExprRawAddr) -> Result<ExprRawAddr, Self::Error> {
+        fold_expr_raw_addr(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_reference(&mut self, i: ExprReference) -> Result<ExprReference, Self::Error> {
+        fold_expr_reference(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_repeat(&mut self, i: ExprRepeat) -> Result<ExprRepeat, Self::Error> {
+        fold_expr_repeat(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_return(&mut self, i: ExprReturn) -> Result<ExprReturn, Self::Error> {
+        fold_expr_return(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_struct(&mut self, i: ExprStruct) -> Result<ExprStruct, Self::Error> {
+        fold_expr_struct(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_try(&mut self, i: ExprTry) -> Result<ExprTry, Self::Error> {
+        fold_expr_try(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_try_block(&mut self, i: ExprTryBlock) -> Result<ExprTryBlock, Self::Error> {
+        fold_expr_try_block(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_tuple(&mut self, i: ExprTuple) -> Result<ExprTuple, Self::Error> {
+        fold_expr_tuple(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_type(&mut self, i: ExprType) -> Result<ExprType, Self::Error> {
+        fold_expr_type(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_expr_unary(&mut self, i: ExprUnary) -> Result<ExprUnary, Self::Error> {
+        fold_expr_unary(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_unsafe(&mut self, i: ExprUnsafe) -> Result<ExprUnsafe, Self::Error> {
+        fold_expr_unsafe(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_while(&mut self, i: ExprWhile) -> Result<ExprWhile, Self::Error> {
+        fold_expr_while(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_expr_yield(&mut self, i: ExprYield) -> Result<ExprYield, Self::Error> {
+        fold_expr_yield(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_field(&mut self, i: Field) -> Result<Field, Self::Error> {
+        fold_field(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_field_pat(&mut self, i: FieldPat) -> Result<FieldPat, Self::Error> {
+        fold_field_pat(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_field_value(&mut self, i: FieldValue) -> Result<FieldValue, Self::Error> {
+        fold_field_value(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_fields(&mut self, i: Fields) -> Result<Fields, Self::Error> {
+        fold_fields(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_fields_named(&mut self, i: FieldsNamed) -> Result<FieldsNamed, Self::Error> {
+        fold_fields_named(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_fields_unnamed(&mut self, i: FieldsUnnamed) -> Result<FieldsUnnamed, Self::Error> {
+        fold_fields_unnamed(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_file(&mut self, i: File) -> Result<File, Self::Error> {
+        fold_file(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_fn_arg(&mut self, i: FnArg) -> Result<FnArg, Self::Error> {
+        fold_fn_arg(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_foreign_item(&mut self, i: ForeignItem) -> Result<ForeignItem, Self::Error> {
+        fold_foreign_item(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_foreign_item_fn(&mut self, i: ForeignItemFn) -> Result<ForeignItemFn, Self::Error> {
+        fold_foreign_item_fn(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_foreign_item_macro(
+        &mut self,
+        i: ForeignItemMacro,
+    ) -> Result<ForeignItemMacro, Self::Error> {
+        fold_foreign_item_macro(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_foreign_item_static(
+        &mut self,
+        i: ForeignItemStatic,
+    ) -> Result<ForeignItemStatic, Self::Error> {
+        fold_foreign_item_static(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_foreign_item_type(
+        &mut self,
+        i: ForeignItemType,
+    ) -> Result<ForeignItemType, Self::Error> {
+        fold_foreign_item_type(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_generic_argument(
+        &mut self,
+        i: GenericArgument,
+    ) -> Result<GenericArgument, Self::Error> {
+        fold_generic_argument(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_generic_method_argument(
+        &mut self,
+        i: GenericMethodArgument,
+    ) -> Result<GenericMethodArgument, Self::Error> {
+        fold_generic_method_argument(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_generic_param(&mut self, i: GenericParam) -> Result<GenericParam, Self::Error> {
+        fold_generic_param(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_generics(&mut self, i: Generics) -> Result<Generics, Self::Error> {
+        fold_generics(self, i)
+    }
+    fn fold_ident(&mut self, i: Ident) -> Result<Ident, Self::Error> {
+        fold_ident(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_impl_item(&mut self, i: ImplItem) -> Result<ImplItem, Self::Error> {
+        fold_impl_item(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_impl_item_const(&mut self, i: ImplItemConst) -> Result<ImplItemConst, Self::Error> {
+        fold_impl_item_const(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_impl_item_macro(&mut self, i: ImplItemMacro) -> Result<ImplItemMacro, Self::Error> {
+        fold_impl_item_macro(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_impl_item_method(&mut self, i: ImplItemMethod) -> Result<ImplItemMethod, Self::Error> {
+        fold_impl_item_method(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_impl_item_type(&mut self, i: ImplItemType) -> Result<ImplItemType, Self::Error> {
+        fold_impl_item_type(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_index(&mut self, i: Index) -> Result<Index, Self::Error> {
+        fold_index(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item(&mut self, i: Item) -> Result<Item, Self::Error> {
+        fold_item(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_const(&mut self, i: ItemConst) -> Result<ItemConst, Self::Error> {
+        fold_item_const(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_enum(&mut self, i: ItemEnum) -> Result<ItemEnum, Self::Error> {
+        fold_item_enum(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_extern_crate(
+        &mut self,
+        i: ItemExternCrate,
+    ) -> Result<ItemExternCrate, Self::Error> {
+        fold_item_extern_crate(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_fn(&mut self, i: ItemFn) -> Result<ItemFn, Self::Error> {
+        fold_item_fn(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_fn_decl(&mut self, i: ItemFnDecl) -> Result<ItemFnDecl, Self::Error> {
+        fold_item_fn_decl(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_foreign_mod(&mut self, i: ItemForeignMod) -> Result<ItemForeignMod, Self::Error> {
+        fold_item_foreign_mod(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_impl(&mut self, i: ItemImpl) -> Result<ItemImpl, Self::Error> {
+        fold_item_impl(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_macro(&mut self, i: ItemMacro) -> Result<ItemMacro, Self::Error> {
+        fold_item_macro(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_macro2(&mut self, i: ItemMacro2) -> Result<ItemMacro2, Self::Error> {
+        fold_item_macro2(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_mod(&mut self, i: ItemMod) -> Result<ItemMod, Self::Error> {
+        fold_item_mod(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_static(&mut self, i: ItemStatic) -> Result<ItemStatic, Self::Error> {
+        fold_item_static(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_struct(&mut self, i: ItemStruct) -> Result<ItemStruct, Self::Error> {
+        fold_item_struct(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_trait(&mut self, i: ItemTrait) -> Result<ItemTrait, Self::Error> {
+        fold_item_trait(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_trait_alias(&mut self, i: ItemTraitAlias) -> Result<ItemTraitAlias, Self::Error> {
+        fold_item_trait_alias(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_type(&mut self, i: ItemType) -> Result<ItemType, Self::Error> {
+        fold_item_type(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_union(&mut self, i: ItemUnion) -> Result<ItemUnion, Self::Error> {
+        fold_item_union(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_item_use(&mut self, i: ItemUse) -> Result<ItemUse, Self::Error> {
+        fold_item_use(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_label(&mut self, i: Label) -> Result<Label, Self::Error> {
+        fold_label(self, i)
+    }
+    fn fold_lifetime(&mut self, i: Lifetime) -> Result<Lifetime, Self::Error> {
+        fold_lifetime(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_lifetime_def(&mut self, i: LifetimeDef) -> Result<LifetimeDef, Self::Error> {
+        fold_lifetime_def(self, i)
+    }
+    fn fold_lit(&mut self, i: Lit) -> Result<Lit, Self::Error> {
+        fold_lit(self, i)
+    }
+    fn fold_lit_bool(&mut self, i: LitBool) -> Result<LitBool, Self::Error> {
+        fold_lit_bool(self, i)
+    }
+    fn fold_lit_byte(&mut self, i: LitByte) -> Result<LitByte, Self::Error> {
+        fold_lit_byte(self, i)
+    }
+    fn fold_lit_byte_str(&mut self, i: LitByteStr) -> Result<LitByteStr, Self::Error> {
+        fold_lit_byte_str(self, i)
+    }
+    fn fold_lit_char(&mut self, i: LitChar) -> Result<LitChar, Self::Error> {
+        fold_lit_char(self, i)
+    }
+    fn fold_lit_float(&mut self, i: LitFloat) -> Result<LitFloat, Self::Error> {
+        fold_lit_float(self, i)
+    }
+    fn fold_lit_int(&mut self, i: LitInt) -> Result<LitInt, Self::Error> {
+        fold_lit_int(self, i)
+    }
+    fn fold_lit_str(&mut self, i: LitStr) -> Result<LitStr, Self::Error> {
+        fold_lit_str(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_local(&mut self, i: Local) -> Result<Local, Self::Error> {
+        fold_local(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_local_init(&mut self, i: LocalInit) -> Result<LocalInit, Self::Error> {
+        fold_local_init(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_macro(&mut self, i: Macro) -> Result<Macro, Self::Error> {
+        fold_macro(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_macro_delimiter(&mut self, i: MacroDelimiter) -> Result<MacroDelimiter, Self::Error> {
+        fold_macro_delimiter(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_macro_group(&mut self, i: MacroGroup) -> Result<MacroGroup, Self::Error> {
+        fold_macro_group(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_macro_metavar(&mut self, i: MacroMetavar) -> Result<MacroMetavar, Self::Error> {
+        fold_macro_metavar(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_macro_rep_op(&mut self, i: MacroRepOp) -> Result<MacroRepOp, Self::Error> {
+        fold_macro_rep_op(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_macro_repetition(
+        &mut self,
+        i: MacroRepetition,
+    ) -> Result<MacroRepetition, Self::Error> {
+        fold_macro_repetition(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_macro_rule(&mut self, i: MacroRule) -> Result<MacroRule, Self::Error> {
+        fold_macro_rule(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_macro_rules(&mut self, i: MacroRules) -> Result<MacroRules, Self::Error> {
+        fold_macro_rules(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_macro_tree(&mut self, i: MacroTree) -> Result<MacroTree, Self::Error> {
+        fold_macro_tree(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_member(&mut self, i: Member) -> Result<Member, Self::Error> {
+        fold_member(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_meta(&mut self, i: Meta) -> Result<Meta, Self::Error> {
+        fold_meta(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_meta_list(&mut self, i: MetaList) -> Result<MetaList, Self::Error> {
+        fold_meta_list(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_meta_name_value(&mut self, i: MetaNameValue) -> Result<MetaNameValue, Self::Error> {
+        fold_meta_name_value(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_method_turbofish(
+        &mut self,
+        i: MethodTurbofish,
+    ) -> Result<MethodTurbofish, Self::Error> {
+        fold_method_turbofish(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_nested_meta(&mut self, i: NestedMeta) -> Result<NestedMeta, Self::Error> {
+        fold_nested_meta(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_parenthesized_generic_arguments(
+        &mut self,
+        i: ParenthesizedGenericArguments,
+    ) -> Result<ParenthesizedGenericArguments, Self::Error> {
+        fold_parenthesized_generic_arguments(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat(&mut self, i: Pat) -> Result<Pat, Self::Error> {
+        fold_pat(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat_box(&mut self, i: PatBox) -> Result<PatBox, Self::Error> {
+        fold_pat_box(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat_const(&mut self, i: PatConst) -> Result<PatConst, Self::Error> {
+        fold_pat_const(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat_ident(&mut self, i: PatIdent) -> Result<PatIdent, Self::Error> {
+        fold_pat_ident(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat_lit(&mut self, i: PatLit) -> Result<PatLit, Self::Error> {
+        fold_pat_lit(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat_macro(&mut self, i: PatMacro) -> Result<PatMacro, Self::Error> {
+        fold_pat_macro(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat_or(&mut self, i: PatOr) -> Result<PatOr, Self::Error> {
+        fold_pat_or(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat_path(&mut self, i: PatPath) -> Result<PatPath, Self::Error> {
+        fold_pat_path(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat_range(&mut self, i: PatRange) -> Result<PatRange, Self::Error> {
+        fold_pat_range(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat_reference(&mut self, i: PatReference) -> Result<PatReference, Self::Error> {
+        fold_pat_reference(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat_rest(&mut self, i: PatRest) -> Result<PatRest, Self::Error> {
+        fold_pat_rest(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat_slice(&mut self, i: PatSlice) -> Result<PatSlice, Self::Error> {
+        fold_pat_slice(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat_struct(&mut self, i: PatStruct) -> Result<PatStruct, Self::Error> {
+        fold_pat_struct(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat_tuple(&mut self, i: PatTuple) -> Result<PatTuple, Self::Error> {
+        fold_pat_tuple(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat_tuple_struct(&mut self, i: PatTupleStruct) -> Result<PatTupleStruct, Self::Error> {
+        fold_pat_tuple_struct(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat_type(&mut self, i: PatType) -> Result<PatType, Self::Error> {
+        fold_pat_type(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pat_wild(&mut self, i: PatWild) -> Result<PatWild, Self::Error> {
+        fold_pat_wild(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_path(&mut self, i: Path) -> Result<Path, Self::Error> {
+        fold_path(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_path_arguments(&mut self, i: PathArguments) -> Result<PathArguments, Self::Error> {
+        fold_path_arguments(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_path_segment(&mut self, i: PathSegment) -> Result<PathSegment, Self::Error> {
+        fold_path_segment(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_pointer_mutability(
+        &mut self,
+        i: PointerMutability,
+    ) -> Result<PointerMutability, Self::Error> {
+        fold_pointer_mutability(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_predicate_eq(&mut self, i: PredicateEq) -> Result<PredicateEq, Self::Error> {
+        fold_predicate_eq(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_predicate_lifetime(
+        &mut self,
+        i: PredicateLifetime,
+    ) -> Result<PredicateLifetime, Self::Error> {
+        fold_predicate_lifetime(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_predicate_type(&mut self, i: PredicateType) -> Result<PredicateType, Self::Error> {
+        fold_predicate_type(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_qself(&mut self, i: QSelf) -> Result<QSelf, Self::Error> {
+        fold_qself(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_range_limits(&mut self, i: RangeLimits) -> Result<RangeLimits, Self::Error> {
+        fold_range_limits(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_receiver(&mut self, i: Receiver) -> Result<Receiver, Self::Error> {
+        fold_receiver(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_return_type(&mut self, i: ReturnType) -> Result<ReturnType, Self::Error> {
+        fold_return_type(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_signature(&mut self, i: Signature) -> Result<Signature, Self::Error> {
+        fold_signature(self, i)
+    }
+    fn fold_span(&mut self, i: Span) -> Result<Span, Self::Error> {
+        fold_span(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_stmt(&mut self, i: Stmt) -> Result<Stmt, Self::Error> {
+        fold_stmt(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_trait_bound(&mut self, i: TraitBound) -> Result<TraitBound, Self::Error> {
+        fold_trait_bound(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_trait_bound_modifier(
+        &mut self,
+        i: TraitBoundModifier,
+    ) -> Result<TraitBoundModifier, Self::Error> {
+        fold_trait_bound_modifier(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_trait_item(&mut self, i: TraitItem) -> Result<TraitItem, Self::Error> {
+        fold_trait_item(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_trait_item_const(&mut self, i: TraitItemConst) -> Result<TraitItemConst, Self::Error> {
+        fold_trait_item_const(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_trait_item_macro(&mut self, i: TraitItemMacro) -> Result<TraitItemMacro, Self::Error> {
+        fold_trait_item_macro(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_trait_item_method(
+        &mut self,
+        i: TraitItemMethod,
+    ) -> Result<TraitItemMethod, Self::Error> {
+        fold_trait_item_method(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_trait_item_type(&mut self, i: TraitItemType) -> Result<TraitItemType, Self::Error> {
+        fold_trait_item_type(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type(&mut self, i: Type) -> Result<Type, Self::Error> {
+        fold_type(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_array(&mut self, i: TypeArray) -> Result<TypeArray, Self::Error> {
+        fold_type_array(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_bare_fn(&mut self, i: TypeBareFn) -> Result<TypeBareFn, Self::Error> {
+        fold_type_bare_fn(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_group(&mut self, i: TypeGroup) -> Result<TypeGroup, Self::Error> {
+        fold_type_group(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_impl_trait(&mut self, i: TypeImplTrait) -> Result<TypeImplTrait, Self::Error> {
+        fold_type_impl_trait(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_infer(&mut self, i: TypeInfer) -> Result<TypeInfer, Self::Error> {
+        fold_type_infer(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_macro(&mut self, i: TypeMacro) -> Result<TypeMacro, Self::Error> {
+        fold_type_macro(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_never(&mut self, i: TypeNever) -> Result<TypeNever, Self::Error> {
+        fold_type_never(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_param(&mut self, i: TypeParam) -> Result<TypeParam, Self::Error> {
+        fold_type_param(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_param_bound(&mut self, i: TypeParamBound) -> Result<TypeParamBound, Self::Error> {
+        fold_type_param_bound(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_paren(&mut self, i: TypeParen) -> Result<TypeParen, Self::Error> {
+        fold_type_paren(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_path(&mut self, i: TypePath) -> Result<TypePath, Self::Error> {
+        fold_type_path(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_ptr(&mut self, i: TypePtr) -> Result<TypePtr, Self::Error> {
+        fold_type_ptr(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_reference(&mut self, i: TypeReference) -> Result<TypeReference, Self::Error> {
+        fold_type_reference(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_slice(&mut self, i: TypeSlice) -> Result<TypeSlice, Self::Error> {
+        fold_type_slice(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_trait_object(
+        &mut self,
+        i: TypeTraitObject,
+    ) -> Result<TypeTraitObject, Self::Error> {
+        fold_type_trait_object(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_type_tuple(&mut self, i: TypeTuple) -> Result<TypeTuple, Self::Error> {
+        fold_type_tuple(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_un_op(&mut self, i: UnOp) -> Result<UnOp, Self::Error> {
+        fold_un_op(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_use_glob(&mut self, i: UseGlob) -> Result<UseGlob, Self::Error> {
+        fold_use_glob(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_use_group(&mut self, i: UseGroup) -> Result<UseGroup, Self::Error> {
+        fold_use_group(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_use_name(&mut self, i: UseName) -> Result<UseName, Self::Error> {
+        fold_use_name(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_use_path(&mut self, i: UsePath) -> Result<UsePath, Self::Error> {
+        fold_use_path(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_use_rename(&mut self, i: UseRename) -> Result<UseRename, Self::Error> {
+        fold_use_rename(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn fold_use_tree(&mut self, i: UseTree) -> Result<UseTree, Self::Error> {
+        fold_use_tree(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_variadic(&mut self, i: Variadic) -> Result<Variadic, Self::Error> {
+        fold_variadic(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_variant(&mut self, i: Variant) -> Result<Variant, Self::Error> {
+        fold_variant(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_vis_crate(&mut self, i: VisCrate) -> Result<VisCrate, Self::Error> {
+        fold_vis_crate(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_vis_public(&mut self, i: VisPublic) -> Result<VisPublic, Self::Error> {
+        fold_vis_public(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_vis_restricted(&mut self, i: VisRestricted) -> Result<VisRestricted, Self::Error> {
+        fold_vis_restricted(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_visibility(&mut self, i: Visibility) -> Result<Visibility, Self::Error> {
+        fold_visibility(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_where_clause(&mut self, i: WhereClause) -> Result<WhereClause, Self::Error> {
+        fold_where_clause(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn fold_where_predicate(&mut self, i: WherePredicate) -> Result<WherePredicate, Self::Error> {
+        fold_where_predicate(self, i)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_abi<F>(f: &mut F, node: Abi) -> Result<Abi, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(Abi {
+        extern_token: Token![extern](tokens_helper(f, &node.extern_token.span)?),
+        name: match node.name {
+            Some(it) => Some(f.fold_lit_str(it)?),
+            None => None,
+        },
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_angle_bracketed_generic_arguments<F>(
+    f: &mut F,
+    node: AngleBracketedGenericArguments,
+) -> Result<AngleBracketedGenericArguments, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(AngleBracketedGenericArguments {
+        colon2_token: match node.colon2_token {
+            Some(it) => Some(Token ! [::](tokens_helper(f, &it.spans)?)),
+            None => None,
+        },
+        lt_token: Token ! [<](tokens_helper(f, &node.lt_token.spans)?),
+        args: FoldHelper::lift(node.args, f, |f, it| Ok(f.fold_generic_argument(it)?))?,
+        gt_token: Token ! [>](tokens_helper(f, &node.gt_token.spans)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_arm<F>(f: &mut F, node: Arm) -> Result<Arm, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(Arm {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        pat: f.fold_pat(node.pat)?,
+        guard: match node.guard {
+            Some(it) => Some((
+                Token![if](tokens_helper(f, &(it).0.span)?),
+                Box::new(f.fold_expr(*(it).1)?),
+            )),
+            None => None,
+        },
+        fat_arrow_token: Token ! [=>](tokens_helper(f, &node.fat_arrow_token.spans)?),
+        body: Box::new(f.fold_expr(*node.body)?),
+        comma: match node.comma {
+            Some(it) => Some(Token ! [,](tokens_helper(f, &it.spans)?)),
+            None => None,
+        },
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_attr_meta<F>(f: &mut F, node: AttrMeta) -> Result<AttrMeta, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        AttrMeta::Path(_binding_0) => AttrMeta::Path(f.fold_path(_binding_0)?),
+        AttrMeta::List(_binding_0) => AttrMeta::List(f.fold_attr_meta_list(_binding_0)?),
+        AttrMeta::NameValue(_binding_0) => {
+            AttrMeta::NameValue(f.fold_attr_meta_name_value(_binding_0)?)
+        }
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_attr_meta_list<F>(f: &mut F, node: AttrMetaList) -> Result<AttrMetaList, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(AttrMetaList {
+        path: f.fold_path(node.path)?,
+        delimiter: f.fold_macro_delimiter(node.delimiter)?,
+        tokens: node.tokens,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_attr_meta_name_value<F>(
+    f: &mut F,
+    node: AttrMetaNameValue,
+) -> Result<AttrMetaNameValue, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(AttrMetaNameValue {
+        path: f.fold_path(node.path)?,
+        eq_token: Token ! [=](tokens_helper(f, &node.eq_token.spans)?),
+        value: f.fold_expr(node.value)?,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_attr_style<F>(f: &mut F, node: AttrStyle) -> Result<AttrStyle, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        AttrStyle::Outer => AttrStyle::Outer,
+        AttrStyle::Inner(_binding_0) => {
+            AttrStyle::Inner(Token![!](tokens_helper(f, &_binding_0.spans)?))
+        }
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_attribute<F>(f: &mut F, node: Attribute) -> Result<Attribute, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(Attribute {
+        pound_token: Token ! [#](tokens_helper(f, &node.pound_token.spans)?),
+        style: f.fold_attr_style(node.style)?,
+        bracket_token: Bracket(tokens_helper(f, &node.bracket_token.span)?),
+        path: f.fold_path(node.path)?,
+        tokens: node.tokens,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_bare_fn_arg<F>(f: &mut F, node: BareFnArg) -> Result<BareFnArg, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(BareFnArg {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        name: match node.name {
+            Some(it) => Some((
+                f.fold_ident((it).0)?,
+                Token ! [:](tokens_helper(f, &(it).1.spans)?),
+            )),
+            None => None,
+        },
+        ty: f.fold_type(node.ty)?,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_bin_op<F>(f: &mut F, node: BinOp) -> Result<BinOp, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        BinOp::Add(_binding_0) => BinOp::Add(Token ! [+](tokens_helper(f, &_binding_0.spans)?)),
+        BinOp::Sub(_binding_0) => BinOp::Sub(Token ! [-](tokens_helper(f, &_binding_0.spans)?)),
+        BinOp::Mul(_binding_0) => BinOp::Mul(Token ! [*](tokens_helper(f, &_binding_0.spans)?)),
+        BinOp::Div(_binding_0) => BinOp::Div(Token ! [/](tokens_helper(f, &_binding_0.spans)?)),
+        BinOp::Rem(_binding_0) => BinOp::Rem(Token ! [%](tokens_helper(f, &_binding_0.spans)?)),
+        BinOp::And(_binding_0) => BinOp::And(Token ! [&&](tokens_helper(f, &_binding_0.spans)?)),
+        BinOp::Or(_binding_0) => BinOp::Or(Token ! [||](tokens_helper(f, &_binding_0.spans)?)),
+        BinOp::BitXor(_binding_0) => {
+            BinOp::BitXor(Token ! [^](tokens_helper(f, &_binding_0.spans)?))
+        }
+        BinOp::BitAnd(_binding_0) => {
+            BinOp::BitAnd(Token ! [&](tokens_helper(f, &_binding_0.spans)?))
+        }
+        BinOp::BitOr(_binding_0) => BinOp::BitOr(Token ! [|](tokens_helper(f, &_binding_0.spans)?)),
+        BinOp::Shl(_binding_0) => BinOp::Shl(Token ! [<<](tokens_helper(f, &_binding_0.spans)?)),
+        BinOp::Shr(_binding_0) => BinOp::Shr(Token ! [>>](tokens_helper(f, &_binding_0.spans)?)),
+        BinOp::Eq(_binding_0) => BinOp::Eq(Token ! [==](tokens_helper(f, &_binding_0.spans)?)),
+        BinOp::Lt(_binding_0) => BinOp::Lt(Token ! [<](tokens_helper(f, &_binding_0.spans)?)),
+        BinOp::Le(_binding_0) => BinOp::Le(Token ! [<=](tokens_helper(f, &_binding_0.spans)?)),
+        BinOp::Ne(_binding_0) => BinOp::Ne(Token ! [!=](tokens_helper(f, &_binding_0.spans)?)),
+        BinOp::Ge(_binding_0) => BinOp::Ge(Token ! [>=](tokens_helper(f, &_binding_0.spans)?)),
+        BinOp::Gt(_binding_0) => BinOp::Gt(Token ! [>](tokens_helper(f, &_binding_0.spans)?)),
+        BinOp::AddEq(_binding_0) => {
+            BinOp::AddEq(Token ! [+=](tokens_helper(f, &_binding_0.spans)?))
+        }
+        BinOp::SubEq(_binding_0) => {
+            BinOp::SubEq(Token ! [-=](tokens_helper(f, &_binding_0.spans)?))
+        }
+        BinOp::MulEq(_binding_0) => {
+            BinOp::MulEq(Token ! [*=](tokens_helper(f, &_binding_0.spans)?))
+        }
+        BinOp::DivEq(_binding_0) => {
+            BinOp::DivEq(Token ! [/=](tokens_helper(f, &_binding_0.spans)?))
+        }
+        BinOp::RemEq(_binding_0) => {
+            BinOp::RemEq(Token ! [%=](tokens_helper(f, &_binding_0.spans)?))
+        }
+        BinOp::BitXorEq(_binding_0) => {
+            BinOp::BitXorEq(Token ! [^=](tokens_helper(f, &_binding_0.spans)?))
+        }
+        BinOp::BitAndEq(_binding_0) => {
+            BinOp::BitAndEq(Token ! [&=](tokens_helper(f, &_binding_0.spans)?))
+        }
+        BinOp::BitOrEq(_binding_0) => {
+            BinOp::BitOrEq(Token ! [|=](tokens_helper(f, &_binding_0.spans)?))
+        }
+        BinOp::ShlEq(_binding_0) => {
+            BinOp::ShlEq(Token ! [<<=](tokens_helper(f, &_binding_0.spans)?))
+        }
+        BinOp::ShrEq(_binding_0) => {
+            BinOp::ShrEq(Token ! [>>=](tokens_helper(f, &_binding_0.spans)?))
+        }
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_binding<F>(f: &mut F, node: Binding) -> Result<Binding, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(Binding {
+        ident: f.fold_ident(node.ident)?,
+        eq_token: Token ! [=](tokens_helper(f, &node.eq_token.spans)?),
+        ty: f.fold_type(node.ty)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_block<F>(f: &mut F, node: Block) -> Result<Block, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(Block {
+        brace_token: Brace(tokens_helper(f, &node.brace_token.span)?),
+        stmts: FoldHelper::lift(node.stmts, f, |f, it| Ok(f.fold_stmt(it)?))?,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_bound_lifetimes<F>(f: &mut F, node: BoundLifetimes) -> Result<BoundLifetimes, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(BoundLifetimes {
+        for_token: Token![for](tokens_helper(f, &node.for_token.span)?),
+        lt_token: Token ! [<](tokens_helper(f, &node.lt_token.spans)?),
+        lifetimes: FoldHelper::lift(node.lifetimes, f, |f, it| Ok(f.fold_lifetime_def(it)?))?,
+        gt_token: Token ! [>](tokens_helper(f, &node.gt_token.spans)?),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_const_param<F>(f: &mut F, node: ConstParam) -> Result<ConstParam, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ConstParam {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        const_token: Token![const](tokens_helper(f, &node.const_token.span)?),
+        ident: f.fold_ident(node.ident)?,
+        colon_token: Token ! [:](tokens_helper(f, &node.colon_token.spans)?),
+        ty: f.fold_type(node.ty)?,
+        eq_token: match node.eq_token {
+            Some(it) => Some(Token ! [=](tokens_helper(f, &it.spans)?)),
+            None => None,
+        },
+        default: match node.default {
+            Some(it) => Some(f.fold_expr(it)?),
+            None => None,
+        },
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_constraint<F>(f: &mut F, node: Constraint) -> Result<Constraint, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(Constraint {
+        ident: f.fold_ident(node.ident)?,
+        colon_token: Token ! [:](tokens_helper(f, &node.colon_token.spans)?),
+        bounds: FoldHelper::lift(node.bounds, f, |f, it| Ok(f.fold_type_param_bound(it)?))?,
+    })
+}
+#[cfg(feature = "derive")]
+pub fn fold_data<F>(f: &mut F, node: Data) -> Result<Data, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        Data::Struct(_binding_0) => Data::Struct(f.fold_data_struct(_binding_0)?),
+        Data::Enum(_binding_0) => Data::Enum(f.fold_data_enum(_binding_0)?),
+        Data::Union(_binding_0) => Data::Union(f.fold_data_union(_binding_0)?),
+    })
+}
+#[cfg(feature = "derive")]
+pub fn fold_data_enum<F>(f: &mut F, node: DataEnum) -> Result<DataEnum, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(DataEnum {
+        enum_token: Token![enum](tokens_helper(f, &node.enum_token.span)?),
+        brace_token: Brace(tokens_helper(f, &node.brace_token.span)?),
+        variants: FoldHelper::lift(node.variants, f, |f, it| Ok(f.fold_variant(it)?))?,
+    })
+}
+#[cfg(feature = "derive")]
+pub fn fold_data_struct<F>(f: &mut F, node: DataStruct) -> Result<DataStruct, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(DataStruct {
+        struct_token: Token![struct](tokens_helper(f, &node.struct_token.span)?),
+        fields: f.fold_fields(node.fields)?,
+        semi_token: match node.semi_token {
+            Some(it) => Some(Token ! [;](tokens_helper(f, &it.spans)?)),
+            None => None,
+        },
+    })
+}
+#[cfg(feature = "derive")]
+pub fn fold_data_union<F>(f: &mut F, node: DataUnion) -> Result<DataUnion, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(DataUnion {
+        union_token: Token![union](tokens_helper(f, &node.union_token.span)?),
+        fields: f.fold_fields_named(node.fields)?,
+    })
+}
+#[cfg(feature = "derive")]
+pub fn fold_derive_input<F>(f: &mut F, node: DeriveInput) -> Result<DeriveInput, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(DeriveInput {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        vis: f.fold_visibility(node.vis)?,
+        ident: f.fold_ident(node.ident)?,
+        generics: f.fold_generics(node.generics)?,
+        data: f.fold_data(node.data)?,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_expr<F>(f: &mut F, node: Expr) -> Result<Expr, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        Expr::Array(_binding_0) => Expr::Array(full!(f.fold_expr_array(_binding_0)?)),
+        Expr::Assign(_binding_0) => Expr::Assign(full!(f.fold_expr_assign(_binding_0)?)),
+        Expr::AssignOp(_binding_0) => Expr::AssignOp(full!(f.fold_expr_assign_op(_binding_0)?)),
+        Expr::Async(_binding_0) => Expr::Async(full!(f.fold_expr_async(_binding_0)?)),
+        Expr::Await(_binding_0) => Expr::Await(full!(f.fold_expr_await(_binding_0)?)),
+        Expr::Binary(_binding_0) => Expr::Binary(f.fold_expr_binary(_binding_0)?),
+        Expr::Block(_binding_0) => Expr::Block(full!(f.fold_expr_block(_binding_0)?)),
+        Expr::Box(_binding_0) => Expr::Box(full!(f.fold_expr_box(_binding_0)?)),
+        Expr::Break(_binding_0) => Expr::Break(full!(f.fold_expr_break(_binding_0)?)),
+        Expr::Call(_binding_0) => Expr::Call(f.fold_expr_call(_binding_0)?),
+        Expr::Cast(_binding_0) => Expr::Cast(f.fold_expr_cast(_binding_0)?),
+        Expr::Closure(_binding_0) => Expr::Closure(full!(f.fold_expr_closure(_binding_0)?)),
+        Expr::Const(_binding_0) => Expr::Const(full!(f.fold_expr_const(_binding_0)?)),
+        Expr::Continue(_binding_0) => Expr::Continue(full!(f.fold_expr_continue(_binding_0)?)),
+        Expr::Field(_binding_0) => Expr::Field(f.fold_expr_field(_binding_0)?),
+        Expr::ForLoop(_binding_0) => Expr::ForLoop(full!(f.fold_expr_for_loop(_binding_0)?)),
+        Expr::Group(_binding_0) => Expr::Group(full!(f.fold_expr_group(_binding_0)?)),
+        Expr::If(_binding_0) => Expr::If(full!(f.fold_expr_if(_binding_0)?)),
+        Expr::Index(_binding_0) => Expr::Index(f.fold_expr_index(_binding_0)?),
+        Expr::Let(_binding_0) => Expr::Let(full!(f.fold_expr_let(_binding_0)?)),
+        Expr::Lit(_binding_0) => Expr::Lit(f.fold_expr_lit(_binding_0)?),
+        Expr::Loop(_binding_0) => Expr::Loop(full!(f.fold_expr_loop(_binding_0)?)),
+        Expr::Macro(_binding_0) => Expr::Macro(full!(f.fold_expr_macro(_binding_0)?)),
+        Expr::Match(_binding_0) => Expr::Match(full!(f.fold_expr_match(_binding_0)?)),
+        Expr::MethodCall(_binding_0) => {
+            Expr::MethodCall(full!(f.fold_expr_method_call(_binding_0)?))
+        }
+        Expr::Paren(_binding_0) => Expr::Paren(f.fold_expr_paren(_binding_0)?),
+        Expr::Path(_binding_0) => Expr::Path(f.fold_expr_path(_binding_0)?),
+        Expr::Range(_binding_0) => Expr::Range(full!(f.fold_expr_range(_binding_0)?)),
+        Expr::RawAddr(_binding_0) => Expr::RawAddr(full!(f.fold_expr_raw_addr(_binding_0)?)),
+        Expr::Reference(_binding_0) => Expr::Reference(full!(f.fold_expr_reference(_binding_0)?)),
+        Expr::Repeat(_binding_0) => Expr::Repeat(full!(f.fold_expr_repeat(_binding_0)?)),
+        Expr::Return(_binding_0) => Expr::Return(full!(f.fold_expr_return(_binding_0)?)),
+        Expr::Struct(_binding_0) => Expr::Struct(full!(f.fold_expr_struct(_binding_0)?)),
+        Expr::Try(_binding_0) => Expr::Try(full!(f.fold_expr_try(_binding_0)?)),
+        Expr::TryBlock(_binding_0) => Expr::TryBlock(full!(f.fold_expr_try_block(_binding_0)?)),
+        Expr::Tuple(_binding_0) => Expr::Tuple(full!(f.fold_expr_tuple(_binding_0)?)),
+        Expr::Type(_binding_0) => Expr::Type(full!(f.fold_expr_type(_binding_0)?)),
+        Expr::Unary(_binding_0) => Expr::Unary(f.fold_expr_unary(_binding_0)?),
+        Expr::Unsafe(_binding_0) => Expr::Unsafe(full!(f.fold_expr_unsafe(_binding_0)?)),
+        Expr::Verbatim(_binding_0) => Expr::Verbatim(_binding_0),
+        Expr::While(_binding_0) => Expr::While(full!(f.fold_expr_while(_binding_0)?)),
+        Expr::Yield(_binding_0) => Expr::Yield(full!(f.fold_expr_yield(_binding_0)?)),
+        _ => unreachable!(),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_array<F>(f: &mut F, node: ExprArray) -> Result<ExprArray, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprArray {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        bracket_token: Bracket(tokens_helper(f, &node.bracket_token.span)?),
+        elems: FoldHelper::lift(node.elems, f, |f, it| Ok(f.fold_expr(it)?))?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_assign<F>(f: &mut F, node: ExprAssign) -> Result<ExprAssign, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprAssign {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        left: Box::new(f.fold_expr(*node.left)?),
+        eq_token: Token ! [=](tokens_helper(f, &node.eq_token.spans)?),
+        right: Box::new(f.fold_expr(*node.right)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_assign_op<F>(f: &mut F, node: ExprAssignOp) -> Result<ExprAssignOp, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprAssignOp {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        left: Box::new(f.fold_expr(*node.left)?),
+        op: f.fold_bin_op(node.op)?,
+        right: Box::new(f.fold_expr(*node.right)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_async<F>(f: &mut F, node: ExprAsync) -> Result<ExprAsync, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprAsync {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        async_token: Token![async](tokens_helper(f, &node.async_token.span)?),
+        capture: match node.capture {
+            Some(it) => Some(Token![move](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        block: f.fold_block(node.block)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_await<F>(f: &mut F, node: ExprAwait) -> Result<ExprAwait, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprAwait {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        base: Box::new(f.fold_expr(*node.base)?),
+        dot_token: Token ! [.](tokens_helper(f, &node.dot_token.spans)?),
+        await_token: crate::token::Await(tokens_helper(f, &node.await_token.span)?),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_expr_binary<F>(f: &mut F, node: ExprBinary) -> Result<ExprBinary, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprBinary {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        left: Box::new(f.fold_expr(*node.left)?),
+        op: f.fold_bin_op(node.op)?,
+        right: Box::new(f.fold_expr(*node.right)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_block<F>(f: &mut F, node: ExprBlock) -> Result<ExprBlock, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprBlock {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        label: match node.label {
+            Some(it) => Some(f.fold_label(it)?),
+            None => None,
+        },
+        block: f.fold_block(node.block)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_box<F>(f: &mut F, node: ExprBox) -> Result<ExprBox, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprBox {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        box_token: Token![box](tokens_helper(f, &node.box_token.span)?),
+        expr: Box::new(f.fold_expr(*node.expr)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_break<F>(f: &mut F, node: ExprBreak) -> Result<ExprBreak, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprBreak {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        break_token: Token![break](tokens_helper(f, &node.break_token.span)?),
+        label: match node.label {
+            Some(it) => Some(f.fold_lifetime(it)?),
+            None => None,
+        },
+        expr: match node.expr {
+            Some(it) => Some(Box::new(f.fold_expr(*it)?)),
+            None => None,
+        },
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_expr_call<F>(f: &mut F, node: ExprCall) -> Result<ExprCall, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprCall {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        func: Box::new(f.fold_expr(*node.func)?),
+        paren_token: Paren(tokens_helper(f, &node.paren_token.span)?),
+        args: FoldHelper::lift(node.args, f, |f, it| Ok(f.fold_expr(it)?))?,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_expr_cast<F>(f: &mut F, node: ExprCast) -> Result<ExprCast, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprCast {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        expr: Box::new(f.fold_expr(*node.expr)?),
+        as_token: Token![as](tokens_helper(f, &node.as_token.span)?),
+        ty: Box::new(f.fold_type(*node.ty)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_closure<F>(f: &mut F, node: ExprClosure) -> Result<ExprClosure, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprClosure {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        asyncness: match node.asyncness {
+            Some(it) => Some(Token![async](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        movability: match node.movability {
+            Some(it) => Some(Token![static](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        capture: match node.capture {
+            Some(it) => Some(Token![move](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        or1_token: Token ! [|](tokens_helper(f, &node.or1_token.spans)?),
+        inputs: FoldHelper::lift(node.inputs, f, |f, it| Ok(f.fold_pat(it)?))?,
+        or2_token: Token ! [|](tokens_helper(f, &node.or2_token.spans)?),
+        output: f.fold_return_type(node.output)?,
+        body: Box::new(f.fold_expr(*node.body)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_const<F>(f: &mut F, node: ExprConst) -> Result<ExprConst, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprConst {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        const_token: Token![const](tokens_helper(f, &node.const_token.span)?),
+        block: f.fold_block(node.block)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_continue<F>(f: &mut F, node: ExprContinue) -> Result<ExprContinue, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprContinue {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        continue_token: Token![continue](tokens_helper(f, &node.continue_token.span)?),
+        label: match node.label {
+            Some(it) => Some(f.fold_lifetime(it)?),
+            None => None,
+        },
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_expr_field<F>(f: &mut F, node: ExprField) -> Result<ExprField, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprField {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        base: Box::new(f.fold_expr(*node.base)?),
+        dot_token: Token ! [.](tokens_helper(f, &node.dot_token.spans)?),
+        member: f.fold_member(node.member)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_for_loop<F>(f: &mut F, node: ExprForLoop) -> Result<ExprForLoop, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprForLoop {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        label: match node.label {
+            Some(it) => Some(f.fold_label(it)?),
+            None => None,
+        },
+        for_token: Token![for](tokens_helper(f, &node.for_token.span)?),
+        pat: f.fold_pat(node.pat)?,
+        in_token: Token![in](tokens_helper(f, &node.in_token.span)?),
+        expr: Box::new(f.fold_expr(*node.expr)?),
+        body: f.fold_block(node.body)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_group<F>(f: &mut F, node: ExprGroup) -> Result<ExprGroup, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprGroup {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        group_token: Group(tokens_helper(f, &node.group_token.span)?),
+        expr: Box::new(f.fold_expr(*node.expr)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_if<F>(f: &mut F, node: ExprIf) -> Result<ExprIf, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprIf {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        if_token: Token![if](tokens_helper(f, &node.if_token.span)?),
+        cond: Box::new(f.fold_expr(*node.cond)?),
+        then_branch: f.fold_block(node.then_branch)?,
+        else_branch: match node.else_branch {
+            Some(it) => Some((
+                Token![else](tokens_helper(f, &(it).0.span)?),
+                Box::new(f.fold_expr(*(it).1)?),
+            )),
+            None => None,
+        },
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_expr_index<F>(f: &mut F, node: ExprIndex) -> Result<ExprIndex, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprIndex {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        expr: Box::new(f.fold_expr(*node.expr)?),
+        bracket_token: Bracket(tokens_helper(f, &node.bracket_token.span)?),
+        index: Box::new(f.fold_expr(*node.index)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_let<F>(f: &mut F, node: ExprLet) -> Result<ExprLet, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprLet {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        let_token: Token![let](tokens_helper(f, &node.let_token.span)?),
+        pat: f.fold_pat(node.pat)?,
+        eq_token: Token ! [=](tokens_helper(f, &node.eq_token.spans)?),
+        expr: Box::new(f.fold_expr(*node.expr)?),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_expr_lit<F>(f: &mut F, node: ExprLit) -> Result<ExprLit, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprLit {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        lit: f.fold_lit(node.lit)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_loop<F>(f: &mut F, node: ExprLoop) -> Result<ExprLoop, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprLoop {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        label: match node.label {
+            Some(it) => Some(f.fold_label(it)?),
+            None => None,
+        },
+        loop_token: Token![loop](tokens_helper(f, &node.loop_token.span)?),
+        body: f.fold_block(node.body)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_macro<F>(f: &mut F, node: ExprMacro) -> Result<ExprMacro, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprMacro {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        mac: f.fold_macro(node.mac)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_match<F>(f: &mut F, node: ExprMatch) -> Result<ExprMatch, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprMatch {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        match_token: Token![match](tokens_helper(f, &node.match_token.span)?),
+        expr: Box::new(f.fold_expr(*node.expr)?),
+        brace_token: Brace(tokens_helper(f, &node.brace_token.span)?),
+        arms: FoldHelper::lift(node.arms, f, |f, it| Ok(f.fold_arm(it)?))?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_method_call<F>(f: &mut F, node: ExprMethodCall) -> Result<ExprMethodCall, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprMethodCall {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        receiver: Box::new(f.fold_expr(*node.receiver)?),
+        dot_token: Token ! [.](tokens_helper(f, &node.dot_token.spans)?),
+        method: f.fold_ident(node.method)?,
+        turbofish: match node.turbofish {
+            Some(it) => Some(f.fold_method_turbofish(it)?),
+            None => None,
+        },
+        paren_token: Paren(tokens_helper(f, &node.paren_token.span)?),
+        args: FoldHelper::lift(node.args, f, |f, it| Ok(f.fold_expr(it)?))?,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_expr_paren<F>(f: &mut F, node: ExprParen) -> Result<ExprParen, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprParen {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        paren_token: Paren(tokens_helper(f, &node.paren_token.span)?),
+        expr: Box::new(f.fold_expr(*node.expr)?),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_expr_path<F>(f: &mut F, node: ExprPath) -> Result<ExprPath, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprPath {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        qself: match node.qself {
+            Some(it) => Some(f.fold_qself(it)?),
+            None => None,
+        },
+        path: f.fold_path(node.path)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_range<F>(f: &mut F, node: ExprRange) -> Result<ExprRange, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprRange {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        from: match node.from {
+            Some(it) => Some(Box::new(f.fold_expr(*it)?)),
+            None => None,
+        },
+        limits: f.fold_range_limits(node.limits)?,
+        to: match node.to {
+            Some(it) => Some(Box::new(f.fold_expr(*it)?)),
+            None => None,
+        },
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_raw_addr<F>(f: &mut F, node: ExprRawAddr) -> Result<ExprRawAddr, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprRawAddr {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        and_token: Token ! [&](tokens_helper(f, &node.and_token.spans)?),
+        raw: Token![raw](tokens_helper(f, &node.raw.span)?),
+        mutability: f.fold_pointer_mutability(node.mutability)?,
+        expr: Box::new(f.fold_expr(*node.expr)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_reference<F>(f: &mut F, node: ExprReference) -> Result<ExprReference, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprReference {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        and_token: Token ! [&](tokens_helper(f, &node.and_token.spans)?),
+        raw: node.raw,
+        mutability: match node.mutability {
+            Some(it) => Some(Token![mut](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        expr: Box::new(f.fold_expr(*node.expr)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_repeat<F>(f: &mut F, node: ExprRepeat) -> Result<ExprRepeat, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprRepeat {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        bracket_token: Bracket(tokens_helper(f, &node.bracket_token.span)?),
+        expr: Box::new(f.fold_expr(*node.expr)?),
+        semi_token: Token ! [;](tokens_helper(f, &node.semi_token.spans)?),
+        len: Box::new(f.fold_expr(*node.len)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_return<F>(f: &mut F, node: ExprReturn) -> Result<ExprReturn, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprReturn {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        return_token: Token![return](tokens_helper(f, &node.return_token.span)?),
+        expr: match node.expr {
+            Some(it) => Some(Box::new(f.fold_expr(*it)?)),
+            None => None,
+        },
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_struct<F>(f: &mut F, node: ExprStruct) -> Result<ExprStruct, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprStruct {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        path: f.fold_path(node.path)?,
+        brace_token: Brace(tokens_helper(f, &node.brace_token.span)?),
+        fields: FoldHelper::lift(node.fields, f, |f, it| Ok(f.fold_field_value(it)?))?,
+        dot2_token: match node.dot2_token {
+            Some(it) => Some(Token![..](tokens_helper(f, &it.spans)?)),
+            None => None,
+        },
+        rest: match node.rest {
+            Some(it) => Some(Box::new(f.fold_expr(*it)?)),
+            None => None,
+        },
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_try<F>(f: &mut F, node: ExprTry) -> Result<ExprTry, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprTry {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        expr: Box::new(f.fold_expr(*node.expr)?),
+        question_token: Token ! [?](tokens_helper(f, &node.question_token.spans)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_try_block<F>(f: &mut F, node: ExprTryBlock) -> Result<ExprTryBlock, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprTryBlock {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        try_token: Token![try](tokens_helper(f, &node.try_token.span)?),
+        block: f.fold_block(node.block)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_tuple<F>(f: &mut F, node: ExprTuple) -> Result<ExprTuple, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprTuple {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        paren_token: Paren(tokens_helper(f, &node.paren_token.span)?),
+        elems: FoldHelper::lift(node.elems, f, |f, it| Ok(f.fold_expr(it)?))?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_type<F>(f: &mut F, node: ExprType) -> Result<ExprType, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprType {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        expr: Box::new(f.fold_expr(*node.expr)?),
+        colon_token: Token ! [:](tokens_helper(f, &node.colon_token.spans)?),
+        ty: Box::new(f.fold_type(*node.ty)?),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_expr_unary<F>(f: &mut F, node: ExprUnary) -> Result<ExprUnary, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprUnary {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        op: f.fold_un_op(node.op)?,
+        expr: Box::new(f.fold_expr(*node.expr)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_unsafe<F>(f: &mut F, node: ExprUnsafe) -> Result<ExprUnsafe, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprUnsafe {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        unsafe_token: Token![unsafe](tokens_helper(f, &node.unsafe_token.span)?),
+        block: f.fold_block(node.block)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_while<F>(f: &mut F, node: ExprWhile) -> Result<ExprWhile, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprWhile {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        label: match node.label {
+            Some(it) => Some(f.fold_label(it)?),
+            None => None,
+        },
+        while_token: Token![while](tokens_helper(f, &node.while_token.span)?),
+        cond: Box::new(f.fold_expr(*node.cond)?),
+        body: f.fold_block(node.body)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_expr_yield<F>(f: &mut F, node: ExprYield) -> Result<ExprYield, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ExprYield {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        yield_token: Token![yield](tokens_helper(f, &node.yield_token.span)?),
+        expr: match node.expr {
+            Some(it) => Some(Box::new(f.fold_expr(*it)?)),
+            None => None,
+        },
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_field<F>(f: &mut F, node: Field) -> Result<Field, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(Field {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        vis: f.fold_visibility(node.vis)?,
+        ident: match node.ident {
+            Some(it) => Some(f.fold_ident(it)?),
+            None => None,
+        },
+        colon_token: match node.colon_token {
+            Some(it) => Some(Token ! [:](tokens_helper(f, &it.spans)?)),
+            None => None,
+        },
+        ty: f.fold_type(node.ty)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_field_pat<F>(f: &mut F, node: FieldPat) -> Result<FieldPat, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(FieldPat {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        member: f.fold_member(node.member)?,
+        colon_token: match node.colon_token {
+            Some(it) => Some(Token ! [:](tokens_helper(f, &it.spans)?)),
+            None => None,
+        },
+        pat: Box::new(f.fold_pat(*node.pat)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_field_value<F>(f: &mut F, node: FieldValue) -> Result<FieldValue, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(FieldValue {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        member: f.fold_member(node.member)?,
+        colon_token: match node.colon_token {
+            Some(it) => Some(Token ! [:](tokens_helper(f, &it.spans)?)),
+            None => None,
+        },
+        expr: f.fold_expr(node.expr)?,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_fields<F>(f: &mut F, node: Fields) -> Result<Fields, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        Fields::Named(_binding_0) => Fields::Named(f.fold_fields_named(_binding_0)?),
+        Fields::Unnamed(_binding_0) => Fields::Unnamed(f.fold_fields_unnamed(_binding_0)?),
+        Fields::Unit => Fields::Unit,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_fields_named<F>(f: &mut F, node: FieldsNamed) -> Result<FieldsNamed, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(FieldsNamed {
+        brace_token: Brace(tokens_helper(f, &node.brace_token.span)?),
+        named: FoldHelper::lift(node.named, f, |f, it| Ok(f.fold_field(it)?))?,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_fields_unnamed<F>(f: &mut F, node: FieldsUnnamed) -> Result<FieldsUnnamed, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(FieldsUnnamed {
+        paren_token: Paren(tokens_helper(f, &node.paren_token.span)?),
+        unnamed: FoldHelper::lift(node.unnamed, f, |f, it| Ok(f.fold_field(it)?))?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_file<F>(f: &mut F, node: File) -> Result<File, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(File {
+        shebang: node.shebang,
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        items: FoldHelper::lift(node.items, f, |f, it| Ok(f.fold_item(it)?))?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_fn_arg<F>(f: &mut F, node: FnArg) -> Result<FnArg, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        FnArg::Receiver(_binding_0) => FnArg::Receiver(f.fold_receiver(_binding_0)?),
+        FnArg::Typed(_binding_0) => FnArg::Typed(f.fold_pat_type(_binding_0)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_foreign_item<F>(f: &mut F, node: ForeignItem) -> Result<ForeignItem, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        ForeignItem::Fn(_binding_0) => ForeignItem::Fn(f.fold_foreign_item_fn(_binding_0)?),
+        ForeignItem::Static(_binding_0) => {
+            ForeignItem::Static(f.fold_foreign_item_static(_binding_0)?)
+        }
+        ForeignItem::Type(_binding_0) => ForeignItem::Type(f.fold_foreign_item_type(_binding_0)?),
+        ForeignItem::Macro(_binding_0) => {
+            ForeignItem::Macro(f.fold_foreign_item_macro(_binding_0)?)
+        }
+        ForeignItem::Verbatim(_binding_0) => ForeignItem::Verbatim(_binding_0),
+        _ => unreachable!(),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_foreign_item_fn<F>(f: &mut F, node: ForeignItemFn) -> Result<ForeignItemFn, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ForeignItemFn {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        vis: f.fold_visibility(node.vis)?,
+        sig: f.fold_signature(node.sig)?,
+        semi_token: Token ! [;](tokens_helper(f, &node.semi_token.spans)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_foreign_item_macro<F>(
+    f: &mut F,
+    node: ForeignItemMacro,
+) -> Result<ForeignItemMacro, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ForeignItemMacro {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        mac: f.fold_macro(node.mac)?,
+        semi_token: match node.semi_token {
+            Some(it) => Some(Token ! [;](tokens_helper(f, &it.spans)?)),
+            None => None,
+        },
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_foreign_item_static<F>(
+    f: &mut F,
+    node: ForeignItemStatic,
+) -> Result<ForeignItemStatic, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ForeignItemStatic {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        vis: f.fold_visibility(node.vis)?,
+        static_token: Token![static](tokens_helper(f, &node.static_token.span)?),
+        mutability: match node.mutability {
+            Some(it) => Some(Token![mut](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        ident: f.fold_ident(node.ident)?,
+        colon_token: Token ! [:](tokens_helper(f, &node.colon_token.spans)?),
+        ty: Box::new(f.fold_type(*node.ty)?),
+        semi_token: Token ! [;](tokens_helper(f, &node.semi_token.spans)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_foreign_item_type<F>(
+    f: &mut F,
+    node: ForeignItemType,
+) -> Result<ForeignItemType, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ForeignItemType {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        vis: f.fold_visibility(node.vis)?,
+        type_token: Token![type](tokens_helper(f, &node.type_token.span)?),
+        ident: f.fold_ident(node.ident)?,
+        semi_token: Token ! [;](tokens_helper(f, &node.semi_token.spans)?),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_generic_argument<F>(
+    f: &mut F,
+    node: GenericArgument,
+) -> Result<GenericArgument, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        GenericArgument::Lifetime(_binding_0) => {
+            GenericArgument::Lifetime(f.fold_lifetime(_binding_0)?)
+        }
+        GenericArgument::Type(_binding_0) => GenericArgument::Type(f.fold_type(_binding_0)?),
+        GenericArgument::Binding(_binding_0) => {
+            GenericArgument::Binding(f.fold_binding(_binding_0)?)
+        }
+        GenericArgument::Constraint(_binding_0) => {
+            GenericArgument::Constraint(f.fold_constraint(_binding_0)?)
+        }
+        GenericArgument::Const(_binding_0) => GenericArgument::Const(f.fold_expr(_binding_0)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_generic_method_argument<F>(
+    f: &mut F,
+    node: GenericMethodArgument,
+) -> Result<GenericMethodArgument, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        GenericMethodArgument::Type(_binding_0) => {
+            GenericMethodArgument::Type(f.fold_type(_binding_0)?)
+        }
+        GenericMethodArgument::Const(_binding_0) => {
+            GenericMethodArgument::Const(f.fold_expr(_binding_0)?)
+        }
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_generic_param<F>(f: &mut F, node: GenericParam) -> Result<GenericParam, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        GenericParam::Type(_binding_0) => GenericParam::Type(f.fold_type_param(_binding_0)?),
+        GenericParam::Lifetime(_binding_0) => {
+            GenericParam::Lifetime(f.fold_lifetime_def(_binding_0)?)
+        }
+        GenericParam::Const(_binding_0) => GenericParam::Const(f.fold_const_param(_binding_0)?),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_generics<F>(f: &mut F, node: Generics) -> Result<Generics, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(Generics {
+        lt_token: match node.lt_token {
+            Some(it) => Some(Token ! [<](tokens_helper(f, &it.spans)?)),
+            None => None,
+        },
+        params: FoldHelper::lift(node.params, f, |f, it| Ok(f.fold_generic_param(it)?))?,
+        gt_token: match node.gt_token {
+            Some(it) => Some(Token ! [>](tokens_helper(f, &it.spans)?)),
+            None => None,
+        },
+        where_clause: match node.where_clause {
+            Some(it) => Some(f.fold_where_clause(it)?),
+            None => None,
+        },
+    })
+}
+pub fn fold_ident<F>(f: &mut F, node: Ident) -> Result<Ident, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    let mut node = node;
+    let span = f.fold_span(node.span())?;
+    node.set_span(span);
+    Ok(node)
+}
+#[cfg(feature = "full")]
+pub fn fold_impl_item<F>(f: &mut F, node: ImplItem) -> Result<ImplItem, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        ImplItem::Const(_binding_0) => ImplItem::Const(f.fold_impl_item_const(_binding_0)?),
+        ImplItem::Method(_binding_0) => ImplItem::Method(f.fold_impl_item_method(_binding_0)?),
+        ImplItem::Type(_binding_0) => ImplItem::Type(f.fold_impl_item_type(_binding_0)?),
+        ImplItem::Macro(_binding_0) => ImplItem::Macro(f.fold_impl_item_macro(_binding_0)?),
+        ImplItem::Verbatim(_binding_0) => ImplItem::Verbatim(_binding_0),
+        _ => unreachable!(),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_impl_item_const<F>(f: &mut F, node: ImplItemConst) -> Result<ImplItemConst, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ImplItemConst {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        vis: f.fold_visibility(node.vis)?,
+        defaultness: match node.defaultness {
+            Some(it) => Some(Token![default](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        const_token: Token![const](tokens_helper(f, &node.const_token.span)?),
+        ident: f.fold_ident(node.ident)?,
+        colon_token: Token ! [:](tokens_helper(f, &node.colon_token.spans)?),
+        ty: f.fold_type(node.ty)?,
+        eq_token: Token ! [=](tokens_helper(f, &node.eq_token.spans)?),
+        expr: f.fold_expr(node.expr)?,
+        semi_token: Token ! [;](tokens_helper(f, &node.semi_token.spans)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_impl_item_macro<F>(f: &mut F, node: ImplItemMacro) -> Result<ImplItemMacro, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ImplItemMacro {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        mac: f.fold_macro(node.mac)?,
+        semi_token: match node.semi_token {
+            Some(it) => Some(Token ! [;](tokens_helper(f, &it.spans)?)),
+            None => None,
+        },
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_impl_item_method<F>(f: &mut F, node: ImplItemMethod) -> Result<ImplItemMethod, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ImplItemMethod {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        vis: f.fold_visibility(node.vis)?,
+        defaultness: match node.defaultness {
+            Some(it) => Some(Token![default](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        sig: f.fold_signature(node.sig)?,
+        block: f.fold_block(node.block)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_impl_item_type<F>(f: &mut F, node: ImplItemType) -> Result<ImplItemType, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ImplItemType {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        vis: f.fold_visibility(node.vis)?,
+        defaultness: match node.defaultness {
+            Some(it) => Some(Token![default](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        type_token: Token![type](tokens_helper(f, &node.type_token.span)?),
+        ident: f.fold_ident(node.ident)?,
+        generics: f.fold_generics(node.generics)?,
+        eq_token: Token ! [=](tokens_helper(f, &node.eq_token.spans)?),
+        ty: f.fold_type(node.ty)?,
+        semi_token: Token ! [;](tokens_helper(f, &node.semi_token.spans)?),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_index<F>(f: &mut F, node: Index) -> Result<Index, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(Index {
+        index: node.index,
+        span: f.fold_span(node.span)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_item<F>(f: &mut F, node: Item) -> Result<Item, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        Item::Const(_binding_0) => Item::Const(f.fold_item_const(_binding_0)?),
+        Item::Enum(_binding_0) => Item::Enum(f.fold_item_enum(_binding_0)?),
+        Item::ExternCrate(_binding_0) => Item::ExternCrate(f.fold_item_extern_crate(_binding_0)?),
+        Item::Fn(_binding_0) => Item::Fn(f.fold_item_fn(_binding_0)?),
+        Item::FnDecl(_binding_0) => Item::FnDecl(f.fold_item_fn_decl(_binding_0)?),
+        Item::ForeignMod(_binding_0) => Item::ForeignMod(f.fold_item_foreign_mod(_binding_0)?),
+        Item::Impl(_binding_0) => Item::Impl(f.fold_item_impl(_binding_0)?),
+        Item::Macro(_binding_0) => Item::Macro(f.fold_item_macro(_binding_0)?),
+        Item::Macro2(_binding_0) => Item::Macro2(f.fold_item_macro2(_binding_0)?),
+        Item::Mod(_binding_0) => Item::Mod(f.fold_item_mod(_binding_0)?),
+        Item::Static(_binding_0) => Item::Static(f.fold_item_static(_binding_0)?),
+        Item::Struct(_binding_0) => Item::Struct(f.fold_item_struct(_binding_0)?),
+        Item::Trait(_binding_0) => Item::Trait(f.fold_item_trait(_binding_0)?),
+        Item::TraitAlias(_binding_0) => Item::TraitAlias(f.fold_item_trait_alias(_binding_0)?),
+        Item::Type(_binding_0) => Item::Type(f.fold_item_type(_binding_0)?),
+        Item::Union(_binding_0) => Item::Union(f.fold_item_union(_binding_0)?),
+        Item::Use(_binding_0) => Item::Use(f.fold_item_use(_binding_0)?),
+        Item::Verbatim(_binding_0) => Item::Verbatim(_binding_0),
+        _ => unreachable!(),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_item_const<F>(f: &mut F, node: ItemConst) -> Result<ItemConst, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ItemConst {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        vis: f.fold_visibility(node.vis)?,
+        const_token: Token![const](tokens_helper(f, &node.const_token.span)?),
+        ident: f.fold_ident(node.ident)?,
+        colon_token: Token ! [:](tokens_helper(f, &node.colon_token.spans)?),
+        ty: Box::new(f.fold_type(*node.ty)?),
+        expr: match node.expr {
+            Some(it) => Some((
+                Token ! [=](tokens_helper(f, &(it).0.spans)?),
+                Box::new(f.fold_expr(*(it).1)?),
+            )),
+            None => None,
+        },
+        semi_token: Token ! [;](tokens_helper(f, &node.semi_token.spans)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_item_enum<F>(f: &mut F, node: ItemEnum) -> Result<ItemEnum, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ItemEnum {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        vis: f.fold_visibility(node.vis)?,
+        enum_token: Token![enum](tokens_helper(f, &node.enum_token.span)?),
+        ident: f.fold_ident(node.ident)?,
+        generics: f.fold_generics(node.generics)?,
+        brace_token: Brace(tokens_helper(f, &node.brace_token.span)?),
+        variants: FoldHelper::lift(node.variants, f, |f, it| Ok(f.fold_variant(it)?))?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_item_extern_crate<F>(
+    f: &mut F,
+    node: ItemExternCrate,
+) -> Result<ItemExternCrate, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ItemExternCrate {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        vis: f.fold_visibility(node.vis)?,
+        extern_token: Token![extern](tokens_helper(f, &node.extern_token.span)?),
+        crate_token: Token![crate](tokens_helper(f, &node.crate_token.span)?),
+        ident: f.fold_ident(node.ident)?,
+        rename: match node.rename {
+            Some(it) => Some((
+                Token![as](tokens_helper(f, &(it).0.span)?),
+                f.fold_ident((it).1)?,
+            )),
+            None => None,
+        },
+        semi_token: Token ! [;](tokens_helper(f, &node.semi_token.spans)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_item_fn<F>(f: &mut F, node: ItemFn) -> Result<ItemFn, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ItemFn {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        vis: f.fold_visibility(node.vis)?,
+        sig: f.fold_signature(node.sig)?,
+        block: Box::new(f.fold_block(*node.block)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_item_fn_decl<F>(f: &mut F, node: ItemFnDecl) -> Result<ItemFnDecl, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ItemFnDecl {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        vis: f.fold_visibility(node.vis)?,
+        sig: f.fold_signature(node.sig)?,
+        semi_token: Token ! [;](tokens_helper(f, &node.semi_token.spans)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_item_foreign_mod<F>(f: &mut F, node: ItemForeignMod) -> Result<ItemForeignMod, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ItemForeignMod {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        unsafety: match node.unsafety {
+            Some(it) => Some(Token![unsafe](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        abi: f.fold_abi(node.abi)?,
+        brace_token: Brace(tokens_helper(f, &node.brace_token.span)?),
+        items: FoldHelper::lift(node.items, f, |f, it| Ok(f.fold_foreign_item(it)?))?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_item_impl<F>(f: &mut F, node: ItemImpl) -> Result<ItemImpl, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ItemImpl {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        defaultness: match node.defaultness {
+            Some(it) => Some(Token![default](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        unsafety: match node.unsafety {
+            Some(it) => Some(Token![unsafe](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        impl_token: Token![impl](tokens_helper(f, &node.impl_token.span)?),
+        generics: f.fold_generics(node.generics)?,
+        trait_: match node.trait_ {
+            Some(it) => Some((
+                match (it).0 {
+                    Some(it) => Some(Token![!](tokens_helper(f, &it.spans)?)),
+                    None => None,
+                },
+                f.fold_path((it).1)?,
+                Token![for](tokens_helper(f, &(it).2.span)?),
+            )),
+            None => None,
+        },
+        self_ty: Box::new(f.fold_type(*node.self_ty)?),
+        brace_token: Brace(tokens_helper(f, &node.brace_token.span)?),
+        items: FoldHelper::lift(node.items, f, |f, it| Ok(f.fold_impl_item(it)?))?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_item_macro<F>(f: &mut F, node: ItemMacro) -> Result<ItemMacro, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ItemMacro {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        ident: match node.ident {
+            Some(it) => Some(f.fold_ident(it)?),
+            None => None,
+        },
+        mac: f.fold_macro(node.mac)?,
+        semi_token: match node.semi_token {
+            Some(it) => Some(Token ! [;](tokens_helper(f, &it.spans)?)),
+            None => None,
+        },
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_item_macro2<F>(f: &mut F, node: ItemMacro2) -> Result<ItemMacro2, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ItemMacro2 {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        vis: f.fold_visibility(node.vis)?,
+        macro_token: Token![macro](tokens_helper(f, &node.macro_token.span)?),
+        ident: f.fold_ident(node.ident)?,
+        rules: node.rules,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_item_mod<F>(f: &mut F, node: ItemMod) -> Result<ItemMod, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ItemMod {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        vis: f.fold_visibility(node.vis)?,
+        unsafety: match node.unsafety {
+            Some(it) => Some(Token![unsafe](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        mod_token: Token![mod](tokens_helper(f, &node.mod_token.span)?),
+        ident: f.fold_ident(node.ident)?,
+        content: match node.content {
+            Some(it) => Some((
+                Brace(tokens_helper(f, &(it).0.span)?),
+                FoldHelper::lift((it).1, f, |f, it| Ok(f.fold_item(it)?))?,
+            )),
+            None => None,
+        },
+        semi: match node.semi {
+            Some(it) => Some(Token ! [;](tokens_helper(f, &it.spans)?)),
+            None => None,
+        },
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_item_static<F>(f: &mut F, node: ItemStatic) -> Result<ItemStatic, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ItemStatic {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        vis: f.fold_visibility(node.vis)?,
+        static_token: Token![static](tokens_helper(f, &node.static_token.span)?),
+        mutability: match node.mutability {
+            Some(it) => Some(Token![mut](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        ident: f.fold_ident(node.ident)?,
+        ty: match node.ty {
+            Some(it) => Some((
+                Token ! [:](tokens_helper(f, &(it).0.spans)?),
+                Box::new(f.fold_type(*(it).1)?),
+            )),
+            None => None,
+        },
+        expr: match node.expr {
+            Some(it) => Some((
+                Token ! [=](tokens_helper(f, &(it).0.spans)?),
+                Box::new(f.fold_expr(*(it).1)?),
+            )),
+            None => None,
+        },
+        semi_token: Token ! [;](tokens_helper(f, &node.semi_token.spans)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_item_struct<F>(f: &mut F, node: ItemStruct) -> Result<ItemStruct, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ItemStruct {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        vis: f.fold_visibility(node.vis)?,
+        struct_token: Token![struct](tokens_helper(f, &node.struct_token.span)?),
+        ident: f.fold_ident(node.ident)?,
+        generics: f.fold_generics(node.generics)?,
+        fields: f.fold_fields(node.fields)?,
+        semi_token: match node.semi_token {
+            Some(it) => Some(Token ! [;](tokens_helper(f, &it.spans)?)),
+            None => None,
+        },
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_item_trait<F>(f: &mut F, node: ItemTrait) -> Result<ItemTrait, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ItemTrait {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        vis: f.fold_visibility(node.vis)?,
+        unsafety: match node.unsafety {
+            Some(it) => Some(Token![unsafe](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        auto_token: match node.auto_token {
+            Some(it) => Some(Token![auto](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        trait_token: Token![trait](tokens_helper(f, &node.trait_token.span)?),
+        ident: f.fold_ident(node.ident)?,
+        generics: f.fold_generics(node.generics)?,
+        colon_token: match node.colon_token {
+            Some(it) => Some(Token ! [:](tokens_helper(f, &it.spans)?)),
+            None => None,
+        },
+        supertraits: FoldHelper::lift(node.supertraits, f, |f, it| {
+            Ok(f.fold_type_param_bound(it)?)
+        })?,
+        brace_token: Brace(tokens_helper(f, &node.brace_token.span)?),
+        items: FoldHelper::lift(node.items, f, |f, it| Ok(f.fold_trait_item(it)?))?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_item_trait_alias<F>(f: &mut F, node: ItemTraitAlias) -> Result<ItemTraitAlias, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ItemTraitAlias {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        vis: f.fold_visibility(node.vis)?,
+        trait_token: Token![trait](tokens_helper(f, &node.trait_token.span)?),
+        ident: f.fold_ident(node.ident)?,
+        generics: f.fold_generics(node.generics)?,
+        eq_token: Token ! [=](tokens_helper(f, &node.eq_token.spans)?),
+        bounds: FoldHelper::lift(node.bounds, f, |f, it| Ok(f.fold_type_param_bound(it)?))?,
+        semi_token: Token ! [;](tokens_helper(f, &node.semi_token.spans)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_item_type<F>(f: &mut F, node: ItemType) -> Result<ItemType, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ItemType {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        vis: f.fold_visibility(node.vis)?,
+        type_token: Token![type](tokens_helper(f, &node.type_token.span)?),
+        ident: f.fold_ident(node.ident)?,
+        generics: f.fold_generics(node.generics)?,
+        eq_token: Token ! [=](tokens_helper(f, &node.eq_token.spans)?),
+        ty: Box::new(f.fold_type(*node.ty)?),
+        semi_token: Token ! [;](tokens_helper(f, &node.semi_token.spans)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_item_union<F>(f: &mut F, node: ItemUnion) -> Result<ItemUnion, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ItemUnion {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        vis: f.fold_visibility(node.vis)?,
+        union_token: Token![union](tokens_helper(f, &node.union_token.span)?),
+        ident: f.fold_ident(node.ident)?,
+        generics: f.fold_generics(node.generics)?,
+        fields: f.fold_fields_named(node.fields)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_item_use<F>(f: &mut F, node: ItemUse) -> Result<ItemUse, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ItemUse {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        vis: f.fold_visibility(node.vis)?,
+        use_token: Token![use](tokens_helper(f, &node.use_token.span)?),
+        leading_colon: match node.leading_colon {
+            Some(it) => Some(Token ! [::](tokens_helper(f, &it.spans)?)),
+            None => None,
+        },
+        tree: f.fold_use_tree(node.tree)?,
+        semi_token: Token ! [;](tokens_helper(f, &node.semi_token.spans)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_label<F>(f: &mut F, node: Label) -> Result<Label, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(Label {
+        name: f.fold_lifetime(node.name)?,
+        colon_token: Token ! [:](tokens_helper(f, &node.colon_token.spans)?),
+    })
+}
+pub fn fold_lifetime<F>(f: &mut F, node: Lifetime) -> Result<Lifetime, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(Lifetime {
+        apostrophe: f.fold_span(node.apostrophe)?,
+        ident: f.fold_ident(node.ident)?,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_lifetime_def<F>(f: &mut F, node: LifetimeDef) -> Result<LifetimeDef, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(LifetimeDef {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        lifetime: f.fold_lifetime(node.lifetime)?,
+        colon_token: match node.colon_token {
+            Some(it) => Some(Token ! [:](tokens_helper(f, &it.spans)?)),
+            None => None,
+        },
+        bounds: FoldHelper::lift(node.bounds, f, |f, it| Ok(f.fold_lifetime(it)?))?,
+    })
+}
+pub fn fold_lit<F>(f: &mut F, node: Lit) -> Result<Lit, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        Lit::Str(_binding_0) => Lit::Str(f.fold_lit_str(_binding_0)?),
+        Lit::ByteStr(_binding_0) => Lit::ByteStr(f.fold_lit_byte_str(_binding_0)?),
+        Lit::Byte(_binding_0) => Lit::Byte(f.fold_lit_byte(_binding_0)?),
+        Lit::Char(_binding_0) => Lit::Char(f.fold_lit_char(_binding_0)?),
+        Lit::Int(_binding_0) => Lit::Int(f.fold_lit_int(_binding_0)?),
+        Lit::Float(_binding_0) => Lit::Float(f.fold_lit_float(_binding_0)?),
+        Lit::Bool(_binding_0) => Lit::Bool(f.fold_lit_bool(_binding_0)?),
+        Lit::Verbatim(_binding_0) => Lit::Verbatim(_binding_0),
+    })
+}
+pub fn fold_lit_bool<F>(f: &mut F, node: LitBool) -> Result<LitBool, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(LitBool {
+        value: node.value,
+        span: f.fold_span(node.span)?,
+    })
+}
+pub fn fold_lit_byte<F>(f: &mut F, node: LitByte) -> Result<LitByte, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    let span = f.fold_span(node.span())?;
+    let mut node = node;
+    node.set_span(span);
+    Ok(node)
+}
+pub fn fold_lit_byte_str<F>(f: &mut F, node: LitByteStr) -> Result<LitByteStr, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    let span = f.fold_span(node.span())?;
+    let mut node = node;
+    node.set_span(span);
+    Ok(node)
+}
+pub fn fold_lit_char<F>(f: &mut F, node: LitChar) -> Result<LitChar, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    let span = f.fold_span(node.span())?;
+    let mut node = node;
+    node.set_span(span);
+    Ok(node)
+}
+pub fn fold_lit_float<F>(f: &mut F, node: LitFloat) -> Result<LitFloat, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    let span = f.fold_span(node.span())?;
+    let mut node = node;
+    node.set_span(span);
+    Ok(node)
+}
+pub fn fold_lit_int<F>(f: &mut F, node: LitInt) -> Result<LitInt, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    let span = f.fold_span(node.span())?;
+    let mut node = node;
+    node.set_span(span);
+    Ok(node)
+}
+pub fn fold_lit_str<F>(f: &mut F, node: LitStr) -> Result<LitStr, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    let span = f.fold_span(node.span())?;
+    let mut node = node;
+    node.set_span(span);
+    Ok(node)
+}
+#[cfg(feature = "full")]
+pub fn fold_local<F>(f: &mut F, node: Local) -> Result<Local, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(Local {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        let_token: Token![let](tokens_helper(f, &node.let_token.span)?),
+        pat: f.fold_pat(node.pat)?,
+        init: match node.init {
+            Some(it) => Some(f.fold_local_init(it)?),
+            None => None,
+        },
+        semi_token: Token ! [;](tokens_helper(f, &node.semi_token.spans)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_local_init<F>(f: &mut F, node: LocalInit) -> Result<LocalInit, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(LocalInit {
+        eq_token: Token ! [=](tokens_helper(f, &node.eq_token.spans)?),
+        expr: Box::new(f.fold_expr(*node.expr)?),
+        diverge: match node.diverge {
+            Some(it) => Some((
+                Token![else](tokens_helper(f, &(it).0.span)?),
+                f.fold_block((it).1)?,
+            )),
+            None => None,
+        },
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_macro<F>(f: &mut F, node: Macro) -> Result<Macro, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(Macro {
+        path: f.fold_path(node.path)?,
+        bang_token: Token![!](tokens_helper(f, &node.bang_token.spans)?),
+        delimiter: f.fold_macro_delimiter(node.delimiter)?,
+        tokens: node.tokens,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_macro_delimiter<F>(f: &mut F, node: MacroDelimiter) -> Result<MacroDelimiter, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        MacroDelimiter::Paren(_binding_0) => {
+            MacroDelimiter::Paren(Paren(tokens_helper(f, &_binding_0.span)?))
+        }
+        MacroDelimiter::Brace(_binding_0) => {
+            MacroDelimiter::Brace(Brace(tokens_helper(f, &_binding_0.span)?))
+        }
+        MacroDelimiter::Bracket(_binding_0) => {
+            MacroDelimiter::Bracket(Bracket(tokens_helper(f, &_binding_0.span)?))
+        }
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_macro_group<F>(f: &mut F, node: MacroGroup) -> Result<MacroGroup, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(MacroGroup {
+        delimiter: f.fold_macro_delimiter(node.delimiter)?,
+        content: FoldHelper::lift(node.content, f, |f, it| Ok(f.fold_macro_tree(it)?))?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_macro_metavar<F>(f: &mut F, node: MacroMetavar) -> Result<MacroMetavar, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(MacroMetavar {
+        dollar_token: Token ! [$](tokens_helper(f, &node.dollar_token.spans)?),
+        name: f.fold_ident(node.name)?,
+        specifier: match node.specifier {
+            Some(it) => Some((
+                Token ! [:](tokens_helper(f, &(it).0.spans)?),
+                f.fold_ident((it).1)?,
+            )),
+            None => None,
+        },
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_macro_rep_op<F>(f: &mut F, node: MacroRepOp) -> Result<MacroRepOp, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        MacroRepOp::ZeroOrMore(_binding_0) => {
+            MacroRepOp::ZeroOrMore(Token ! [*](tokens_helper(f, &_binding_0.spans)?))
+        }
+        MacroRepOp::OneOrMore(_binding_0) => {
+            MacroRepOp::OneOrMore(Token ! [+](tokens_helper(f, &_binding_0.spans)?))
+        }
+        MacroRepOp::ZeroOrOne(_binding_0) => {
+            MacroRepOp::ZeroOrOne(Token ! [?](tokens_helper(f, &_binding_0.spans)?))
+        }
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_macro_repetition<F>(
+    f: &mut F,
+    node: MacroRepetition,
+) -> Result<MacroRepetition, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(MacroRepetition {
+        dollar_token: Token ! [$](tokens_helper(f, &node.dollar_token.spans)?),
+        paren_token: Paren(tokens_helper(f, &node.paren_token.span)?),
+        content: FoldHelper::lift(node.content, f, |f, it| Ok(f.fold_macro_tree(it)?))?,
+        separator: node.separator,
+        op: f.fold_macro_rep_op(node.op)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_macro_rule<F>(f: &mut F, node: MacroRule) -> Result<MacroRule, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(MacroRule {
+        matcher: f.fold_macro_group(node.matcher)?,
+        fat_arrow_token: Token ! [=>](tokens_helper(f, &node.fat_arrow_token.spans)?),
+        transcriber: f.fold_macro_group(node.transcriber)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_macro_rules<F>(f: &mut F, node: MacroRules) -> Result<MacroRules, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(MacroRules {
+        rules: FoldHelper::lift(node.rules, f, |f, it| Ok(f.fold_macro_rule(it)?))?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_macro_tree<F>(f: &mut F, node: MacroTree) -> Result<MacroTree, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        MacroTree::Verbatim(_binding_0) => MacroTree::Verbatim(_binding_0),
+        MacroTree::Group(_binding_0) => MacroTree::Group(f.fold_macro_group(_binding_0)?),
+        MacroTree::Metavar(_binding_0) => MacroTree::Metavar(f.fold_macro_metavar(_binding_0)?),
+        MacroTree::Repetition(_binding_0) => {
+            MacroTree::Repetition(f.fold_macro_repetition(_binding_0)?)
+        }
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_member<F>(f: &mut F, node: Member) -> Result<Member, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        Member::Named(_binding_0) => Member::Named(f.fold_ident(_binding_0)?),
+        Member::Unnamed(_binding_0) => Member::Unnamed(f.fold_index(_binding_0)?),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_meta<F>(f: &mut F, node: Meta) -> Result<Meta, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        Meta::Path(_binding_0) => Meta::Path(f.fold_path(_binding_0)?),
+        Meta::List(_binding_0) => Meta::List(f.fold_meta_list(_binding_0)?),
+        Meta::NameValue(_binding_0) => Meta::NameValue(f.fold_meta_name_value(_binding_0)?),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_meta_list<F>(f: &mut F, node: MetaList) -> Result<MetaList, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(MetaList {
+        path: f.fold_path(node.path)?,
+        paren_token: Paren(tokens_helper(f, &node.paren_token.span)?),
+        nested: FoldHelper::lift(node.nested, f, |f, it| Ok(f.fold_nested_meta(it)?))?,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_meta_name_value<F>(f: &mut F, node: MetaNameValue) -> Result<MetaNameValue, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(MetaNameValue {
+        path: f.fold_path(node.path)?,
+        eq_token: Token ! [=](tokens_helper(f, &node.eq_token.spans)?),
+        lit: f.fold_lit(node.lit)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_method_turbofish<F>(
+    f: &mut F,
+    node: MethodTurbofish,
+) -> Result<MethodTurbofish, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(MethodTurbofish {
+        colon2_token: Token ! [::](tokens_helper(f, &node.colon2_token.spans)?),
+        lt_token: Token ! [<](tokens_helper(f, &node.lt_token.spans)?),
+        args: FoldHelper::lift(
+            node.args,
+            f,
+            |f, it| Ok(f.fold_generic_method_argument(it)?),
+        )?,
+        gt_token: Token ! [>](tokens_helper(f, &node.gt_token.spans)?),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_nested_meta<F>(f: &mut F, node: NestedMeta) -> Result<NestedMeta, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        NestedMeta::Meta(_binding_0) => NestedMeta::Meta(f.fold_meta(_binding_0)?),
+        NestedMeta::Lit(_binding_0) => NestedMeta::Lit(f.fold_lit(_binding_0)?),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_parenthesized_generic_arguments<F>(
+    f: &mut F,
+    node: ParenthesizedGenericArguments,
+) -> Result<ParenthesizedGenericArguments, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(ParenthesizedGenericArguments {
+        paren_token: Paren(tokens_helper(f, &node.paren_token.span)?),
+        inputs: FoldHelper::lift(node.inputs, f, |f, it| Ok(f.fold_type(it)?))?,
+        output: f.fold_return_type(node.output)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_pat<F>(f: &mut F, node: Pat) -> Result<Pat, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        Pat::Box(_binding_0) => Pat::Box(f.fold_pat_box(_binding_0)?),
+        Pat::Const(_binding_0) => Pat::Const(f.fold_pat_const(_binding_0)?),
+        Pat::Ident(_binding_0) => Pat::Ident(f.fold_pat_ident(_binding_0)?),
+        Pat::Lit(_binding_0) => Pat::Lit(f.fold_pat_lit(_binding_0)?),
+        Pat::Macro(_binding_0) => Pat::Macro(f.fold_pat_macro(_binding_0)?),
+        Pat::Or(_binding_0) => Pat::Or(f.fold_pat_or(_binding_0)?),
+        Pat::Path(_binding_0) => Pat::Path(f.fold_pat_path(_binding_0)?),
+        Pat::Range(_binding_0) => Pat::Range(f.fold_pat_range(_binding_0)?),
+        Pat::Reference(_binding_0) => Pat::Reference(f.fold_pat_reference(_binding_0)?),
+        Pat::Rest(_binding_0) => Pat::Rest(f.fold_pat_rest(_binding_0)?),
+        Pat::Slice(_binding_0) => Pat::Slice(f.fold_pat_slice(_binding_0)?),
+        Pat::Struct(_binding_0) => Pat::Struct(f.fold_pat_struct(_binding_0)?),
+        Pat::Tuple(_binding_0) => Pat::Tuple(f.fold_pat_tuple(_binding_0)?),
+        Pat::TupleStruct(_binding_0) => Pat::TupleStruct(f.fold_pat_tuple_struct(_binding_0)?),
+        Pat::Type(_binding_0) => Pat::Type(f.fold_pat_type(_binding_0)?),
+        Pat::Verbatim(_binding_0) => Pat::Verbatim(_binding_0),
+        Pat::Wild(_binding_0) => Pat::Wild(f.fold_pat_wild(_binding_0)?),
+        _ => unreachable!(),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_pat_box<F>(f: &mut F, node: PatBox) -> Result<PatBox, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(PatBox {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        box_token: Token![box](tokens_helper(f, &node.box_token.span)?),
+        pat: Box::new(f.fold_pat(*node.pat)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_pat_const<F>(f: &mut F, node: PatConst) -> Result<PatConst, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(PatConst {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        const_token: Token![const](tokens_helper(f, &node.const_token.span)?),
+        block: f.fold_block(node.block)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_pat_ident<F>(f: &mut F, node: PatIdent) -> Result<PatIdent, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(PatIdent {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        by_ref: match node.by_ref {
+            Some(it) => Some(Token![ref](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        mutability: match node.mutability {
+            Some(it) => Some(Token![mut](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        ident: f.fold_ident(node.ident)?,
+        subpat: match node.subpat {
+            Some(it) => Some((
+                Token ! [@](tokens_helper(f, &(it).0.spans)?),
+                Box::new(f.fold_pat(*(it).1)?),
+            )),
+            None => None,
+        },
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_pat_lit<F>(f: &mut F, node: PatLit) -> Result<PatLit, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(PatLit {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        expr: Box::new(f.fold_expr(*node.expr)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_pat_macro<F>(f: &mut F, node: PatMacro) -> Result<PatMacro, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(PatMacro {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        mac: f.fold_macro(node.mac)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_pat_or<F>(f: &mut F, node: PatOr) -> Result<PatOr, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(PatOr {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        leading_vert: match node.leading_vert {
+            Some(it) => Some(Token ! [|](tokens_helper(f, &it.spans)?)),
+            None => None,
+        },
+        cases: FoldHelper::lift(node.cases, f, |f, it| Ok(f.fold_pat(it)?))?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_pat_path<F>(f: &mut F, node: PatPath) -> Result<PatPath, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(PatPath {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        qself: match node.qself {
+            Some(it) => Some(f.fold_qself(it)?),
+            None => None,
+        },
+        path: f.fold_path(node.path)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_pat_range<F>(f: &mut F, node: PatRange) -> Result<PatRange, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(PatRange {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        lo: Box::new(f.fold_expr(*node.lo)?),
+        limits: f.fold_range_limits(node.limits)?,
+        hi: Box::new(f.fold_expr(*node.hi)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_pat_reference<F>(f: &mut F, node: PatReference) -> Result<PatReference, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(PatReference {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        and_token: Token ! [&](tokens_helper(f, &node.and_token.spans)?),
+        mutability: match node.mutability {
+            Some(it) => Some(Token![mut](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        pat: Box::new(f.fold_pat(*node.pat)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_pat_rest<F>(f: &mut F, node: PatRest) -> Result<PatRest, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(PatRest {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        dot2_token: Token![..](tokens_helper(f, &node.dot2_token.spans)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_pat_slice<F>(f: &mut F, node: PatSlice) -> Result<PatSlice, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(PatSlice {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        bracket_token: Bracket(tokens_helper(f, &node.bracket_token.span)?),
+        elems: FoldHelper::lift(node.elems, f, |f, it| Ok(f.fold_pat(it)?))?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_pat_struct<F>(f: &mut F, node: PatStruct) -> Result<PatStruct, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(PatStruct {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        path: f.fold_path(node.path)?,
+        brace_token: Brace(tokens_helper(f, &node.brace_token.span)?),
+        fields: FoldHelper::lift(node.fields, f, |f, it| Ok(f.fold_field_pat(it)?))?,
+        dot2_token: match node.dot2_token {
+            Some(it) => Some(Token![..](tokens_helper(f, &it.spans)?)),
+            None => None,
+        },
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_pat_tuple<F>(f: &mut F, node: PatTuple) -> Result<PatTuple, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(PatTuple {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        paren_token: Paren(tokens_helper(f, &node.paren_token.span)?),
+        elems: FoldHelper::lift(node.elems, f, |f, it| Ok(f.fold_pat(it)?))?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_pat_tuple_struct<F>(f: &mut F, node: PatTupleStruct) -> Result<PatTupleStruct, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(PatTupleStruct {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        path: f.fold_path(node.path)?,
+        pat: f.fold_pat_tuple(node.pat)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_pat_type<F>(f: &mut F, node: PatType) -> Result<PatType, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(PatType {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        pat: Box::new(f.fold_pat(*node.pat)?),
+        colon_token: Token ! [:](tokens_helper(f, &node.colon_token.spans)?),
+        ty: Box::new(f.fold_type(*node.ty)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_pat_wild<F>(f: &mut F, node: PatWild) -> Result<PatWild, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(PatWild {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        underscore_token: Token![_](tokens_helper(f, &node.underscore_token.spans)?),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_path<F>(f: &mut F, node: Path) -> Result<Path, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(Path {
+        leading_colon: match node.leading_colon {
+            Some(it) => Some(Token ! [::](tokens_helper(f, &it.spans)?)),
+            None => None,
+        },
+        segments: FoldHelper::lift(node.segments, f, |f, it| Ok(f.fold_path_segment(it)?))?,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_path_arguments<F>(f: &mut F, node: PathArguments) -> Result<PathArguments, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        PathArguments::None => PathArguments::None,
+        PathArguments::AngleBracketed(_binding_0) => {
+            PathArguments::AngleBracketed(f.fold_angle_bracketed_generic_arguments(_binding_0)?)
+        }
+        PathArguments::Parenthesized(_binding_0) => {
+            PathArguments::Parenthesized(f.fold_parenthesized_generic_arguments(_binding_0)?)
+        }
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_path_segment<F>(f: &mut F, node: PathSegment) -> Result<PathSegment, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(PathSegment {
+        ident: f.fold_ident(node.ident)?,
+        arguments: f.fold_path_arguments(node.arguments)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_pointer_mutability<F>(
+    f: &mut F,
+    node: PointerMutability,
+) -> Result<PointerMutability, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        PointerMutability::Const(_binding_0) => {
+            PointerMutability::Const(Token![const](tokens_helper(f, &_binding_0.span)?))
+        }
+        PointerMutability::Mut(_binding_0) => {
+            PointerMutability::Mut(Token![mut](tokens_helper(f, &_binding_0.span)?))
+        }
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_predicate_eq<F>(f: &mut F, node: PredicateEq) -> Result<PredicateEq, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(PredicateEq {
+        lhs_ty: f.fold_type(node.lhs_ty)?,
+        eq_token: Token ! [=](tokens_helper(f, &node.eq_token.spans)?),
+        rhs_ty: f.fold_type(node.rhs_ty)?,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_predicate_lifetime<F>(
+    f: &mut F,
+    node: PredicateLifetime,
+) -> Result<PredicateLifetime, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(PredicateLifetime {
+        lifetime: f.fold_lifetime(node.lifetime)?,
+        colon_token: Token ! [:](tokens_helper(f, &node.colon_token.spans)?),
+        bounds: FoldHelper::lift(node.bounds, f, |f, it| Ok(f.fold_lifetime(it)?))?,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_predicate_type<F>(f: &mut F, node: PredicateType) -> Result<PredicateType, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(PredicateType {
+        lifetimes: match node.lifetimes {
+            Some(it) => Some(f.fold_bound_lifetimes(it)?),
+            None => None,
+        },
+        bounded_ty: f.fold_type(node.bounded_ty)?,
+        colon_token: Token ! [:](tokens_helper(f, &node.colon_token.spans)?),
+        bounds: FoldHelper::lift(node.bounds, f, |f, it| Ok(f.fold_type_param_bound(it)?))?,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_qself<F>(f: &mut F, node: QSelf) -> Result<QSelf, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(QSelf {
+        lt_token: Token ! [<](tokens_helper(f, &node.lt_token.spans)?),
+        ty: Box::new(f.fold_type(*node.ty)?),
+        position: node.position,
+        as_token: match node.as_token {
+            Some(it) => Some(Token![as](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        gt_token: Token ! [>](tokens_helper(f, &node.gt_token.spans)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_range_limits<F>(f: &mut F, node: RangeLimits) -> Result<RangeLimits, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        RangeLimits::HalfOpen(_binding_0) => {
+            RangeLimits::HalfOpen(Token![..](tokens_helper(f, &_binding_0.spans)?))
+        }
+        RangeLimits::Closed(_binding_0) => {
+            RangeLimits::Closed(Token ! [..=](tokens_helper(f, &_binding_0.spans)?))
+        }
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_receiver<F>(f: &mut F, node: Receiver) -> Result<Receiver, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(Receiver {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        reference: match node.reference {
+            Some(it) => Some((
+                Token ! [&](tokens_helper(f, &(it).0.spans)?),
+                match (it).1 {
+                    Some(it) => Some(f.fold_lifetime(it)?),
+                    None => None,
+                },
+            )),
+            None => None,
+        },
+        mutability: match node.mutability {
+            Some(it) => Some(Token![mut](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        self_token: Token![self](tokens_helper(f, &node.self_token.span)?),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_return_type<F>(f: &mut F, node: ReturnType) -> Result<ReturnType, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        ReturnType::Default => ReturnType::Default,
+        ReturnType::Type(_binding_0, _binding_1) => ReturnType::Type(
+            Token ! [->](tokens_helper(f, &_binding_0.spans)?),
+            Box::new(f.fold_type(*_binding_1)?),
+        ),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_signature<F>(f: &mut F, node: Signature) -> Result<Signature, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(Signature {
+        constness: match node.constness {
+            Some(it) => Some(Token![const](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        asyncness: match node.asyncness {
+            Some(it) => Some(Token![async](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        unsafety: match node.unsafety {
+            Some(it) => Some(Token![unsafe](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        abi: match node.abi {
+            Some(it) => Some(f.fold_abi(it)?),
+            None => None,
+        },
+        fn_token: Token![fn](tokens_helper(f, &node.fn_token.span)?),
+        ident: f.fold_ident(node.ident)?,
+        generics: f.fold_generics(node.generics)?,
+        paren_token: Paren(tokens_helper(f, &node.paren_token.span)?),
+        inputs: FoldHelper::lift(node.inputs, f, |f, it| Ok(f.fold_fn_arg(it)?))?,
+        variadic: match node.variadic {
+            Some(it) => Some(f.fold_variadic(it)?),
+            None => None,
+        },
+        output: f.fold_return_type(node.output)?,
+    })
+}
+pub fn fold_span<F>(f: &mut F, node: Span) -> Result<Span, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(node)
+}
+#[cfg(feature = "full")]
+pub fn fold_stmt<F>(f: &mut F, node: Stmt) -> Result<Stmt, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        Stmt::Local(_binding_0) => Stmt::Local(f.fold_local(_binding_0)?),
+        Stmt::Item(_binding_0) => Stmt::Item(f.fold_item(_binding_0)?),
+        Stmt::Expr(_binding_0) => Stmt::Expr(f.fold_expr(_binding_0)?),
+        Stmt::Semi(_binding_0, _binding_1) => Stmt::Semi(
+            f.fold_expr(_binding_0)?,
+            Token ! [;](tokens_helper(f, &_binding_1.spans)?),
+        ),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_trait_bound<F>(f: &mut F, node: TraitBound) -> Result<TraitBound, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(TraitBound {
+        paren_token: match node.paren_token {
+            Some(it) => Some(Paren(tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        modifier: f.fold_trait_bound_modifier(node.modifier)?,
+        lifetimes: match node.lifetimes {
+            Some(it) => Some(f.fold_bound_lifetimes(it)?),
+            None => None,
+        },
+        path: f.fold_path(node.path)?,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_trait_bound_modifier<F>(
+    f: &mut F,
+    node: TraitBoundModifier,
+) -> Result<TraitBoundModifier, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        TraitBoundModifier::None => TraitBoundModifier::None,
+        TraitBoundModifier::Maybe(_binding_0) => {
+            TraitBoundModifier::Maybe(Token ! [?](tokens_helper(f, &_binding_0.spans)?))
+        }
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_trait_item<F>(f: &mut F, node: TraitItem) -> Result<TraitItem, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        TraitItem::Const(_binding_0) => TraitItem::Const(f.fold_trait_item_const(_binding_0)?),
+        TraitItem::Method(_binding_0) => TraitItem::Method(f.fold_trait_item_method(_binding_0)?),
+        TraitItem::Type(_binding_0) => TraitItem::Type(f.fold_trait_item_type(_binding_0)?),
+        TraitItem::Macro(_binding_0) => TraitItem::Macro(f.fold_trait_item_macro(_binding_0)?),
+        TraitItem::Verbatim(_binding_0) => TraitItem::Verbatim(_binding_0),
+        _ => unreachable!(),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_trait_item_const<F>(f: &mut F, node: TraitItemConst) -> Result<TraitItemConst, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(TraitItemConst {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        const_token: Token![const](tokens_helper(f, &node.const_token.span)?),
+        ident: f.fold_ident(node.ident)?,
+        colon_token: Token ! [:](tokens_helper(f, &node.colon_token.spans)?),
+        ty: f.fold_type(node.ty)?,
+        default: match node.default {
+            Some(it) => Some((
+                Token ! [=](tokens_helper(f, &(it).0.spans)?),
+                f.fold_expr((it).1)?,
+            )),
+            None => None,
+        },
+        semi_token: Token ! [;](tokens_helper(f, &node.semi_token.spans)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_trait_item_macro<F>(f: &mut F, node: TraitItemMacro) -> Result<TraitItemMacro, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(TraitItemMacro {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        mac: f.fold_macro(node.mac)?,
+        semi_token: match node.semi_token {
+            Some(it) => Some(Token ! [;](tokens_helper(f, &it.spans)?)),
+            None => None,
+        },
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_trait_item_method<F>(
+    f: &mut F,
+    node: TraitItemMethod,
+) -> Result<TraitItemMethod, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(TraitItemMethod {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        sig: f.fold_signature(node.sig)?,
+        default: match node.default {
+            Some(it) => Some(f.fold_block(it)?),
+            None => None,
+        },
+        semi_token: match node.semi_token {
+            Some(it) => Some(Token ! [;](tokens_helper(f, &it.spans)?)),
+            None => None,
+        },
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_trait_item_type<F>(f: &mut F, node: TraitItemType) -> Result<TraitItemType, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(TraitItemType {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        type_token: Token![type](tokens_helper(f, &node.type_token.span)?),
+        ident: f.fold_ident(node.ident)?,
+        generics: f.fold_generics(node.generics)?,
+        colon_token: match node.colon_token {
+            Some(it) => Some(Token ! [:](tokens_helper(f, &it.spans)?)),
+            None => None,
+        },
+        bounds: FoldHelper::lift(node.bounds, f, |f, it| Ok(f.fold_type_param_bound(it)?))?,
+        default: match node.default {
+            Some(it) => Some((
+                Token ! [=](tokens_helper(f, &(it).0.spans)?),
+                f.fold_type((it).1)?,
+            )),
+            None => None,
+        },
+        semi_token: Token ! [;](tokens_helper(f, &node.semi_token.spans)?),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_type<F>(f: &mut F, node: Type) -> Result<Type, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        Type::Array(_binding_0) => Type::Array(f.fold_type_array(_binding_0)?),
+        Type::BareFn(_binding_0) => Type::BareFn(f.fold_type_bare_fn(_binding_0)?),
+        Type::Group(_binding_0) => Type::Group(f.fold_type_group(_binding_0)?),
+        Type::ImplTrait(_binding_0) => Type::ImplTrait(f.fold_type_impl_trait(_binding_0)?),
+        Type::Infer(_binding_0) => Type::Infer(f.fold_type_infer(_binding_0)?),
+        Type::Macro(_binding_0) => Type::Macro(f.fold_type_macro(_binding_0)?),
+        Type::Never(_binding_0) => Type::Never(f.fold_type_never(_binding_0)?),
+        Type::Paren(_binding_0) => Type::Paren(f.fold_type_paren(_binding_0)?),
+        Type::Path(_binding_0) => Type::Path(f.fold_type_path(_binding_0)?),
+        Type::Ptr(_binding_0) => Type::Ptr(f.fold_type_ptr(_binding_0)?),
+        Type::Reference(_binding_0) => Type::Reference(f.fold_type_reference(_binding_0)?),
+        Type::Slice(_binding_0) => Type::Slice(f.fold_type_slice(_binding_0)?),
+        Type::TraitObject(_binding_0) => Type::TraitObject(f.fold_type_trait_object(_binding_0)?),
+        Type::Tuple(_binding_0) => Type::Tuple(f.fold_type_tuple(_binding_0)?),
+        Type::Verbatim(_binding_0) => Type::Verbatim(_binding_0),
+        _ => unreachable!(),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_type_array<F>(f: &mut F, node: TypeArray) -> Result<TypeArray, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(TypeArray {
+        bracket_token: Bracket(tokens_helper(f, &node.bracket_token.span)?),
+        elem: Box::new(f.fold_type(*node.elem)?),
+        semi_token: Token ! [;](tokens_helper(f, &node.semi_token.spans)?),
+        len: f.fold_expr(node.len)?,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_type_bare_fn<F>(f: &mut F, node: TypeBareFn) -> Result<TypeBareFn, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(TypeBareFn {
+        lifetimes: match node.lifetimes {
+            Some(it) => Some(f.fold_bound_lifetimes(it)?),
+            None => None,
+        },
+        unsafety: match node.unsafety {
+            Some(it) => Some(Token![unsafe](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        abi: match node.abi {
+            Some(it) => Some(f.fold_abi(it)?),
+            None => None,
+        },
+        fn_token: Token![fn](tokens_helper(f, &node.fn_token.span)?),
+        paren_token: Paren(tokens_helper(f, &node.paren_token.span)?),
+        inputs: FoldHelper::lift(node.inputs, f, |f, it| Ok(f.fold_bare_fn_arg(it)?))?,
+        variadic: match node.variadic {
+            Some(it) => Some(f.fold_variadic(it)?),
+            None => None,
+        },
+        output: f.fold_return_type(node.output)?,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_type_group<F>(f: &mut F, node: TypeGroup) -> Result<TypeGroup, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(TypeGroup {
+        group_token: Group(tokens_helper(f, &node.group_token.span)?),
+        elem: Box::new(f.fold_type(*node.elem)?),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_type_impl_trait<F>(f: &mut F, node: TypeImplTrait) -> Result<TypeImplTrait, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(TypeImplTrait {
+        impl_token: Token![impl](tokens_helper(f, &node.impl_token.span)?),
+        bounds: FoldHelper::lift(node.bounds, f, |f, it| Ok(f.fold_type_param_bound(it)?))?,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_type_infer<F>(f: &mut F, node: TypeInfer) -> Result<TypeInfer, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(TypeInfer {
+        underscore_token: Token![_](tokens_helper(f, &node.underscore_token.spans)?),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_type_macro<F>(f: &mut F, node: TypeMacro) -> Result<TypeMacro, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(TypeMacro {
+        mac: f.fold_macro(node.mac)?,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_type_never<F>(f: &mut F, node: TypeNever) -> Result<TypeNever, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(TypeNever {
+        bang_token: Token![!](tokens_helper(f, &node.bang_token.spans)?),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_type_param<F>(f: &mut F, node: TypeParam) -> Result<TypeParam, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(TypeParam {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        ident: f.fold_ident(node.ident)?,
+        colon_token: match node.colon_token {
+            Some(it) => Some(Token ! [:](tokens_helper(f, &it.spans)?)),
+            None => None,
+        },
+        bounds: FoldHelper::lift(node.bounds, f, |f, it| Ok(f.fold_type_param_bound(it)?))?,
+        eq_token: match node.eq_token {
+            Some(it) => Some(Token ! [=](tokens_helper(f, &it.spans)?)),
+            None => None,
+        },
+        default: match node.default {
+            Some(it) => Some(f.fold_type(it)?),
+            None => None,
+        },
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_type_param_bound<F>(f: &mut F, node: TypeParamBound) -> Result<TypeParamBound, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        TypeParamBound::Trait(_binding_0) => TypeParamBound::Trait(f.fold_trait_bound(_binding_0)?),
+        TypeParamBound::Lifetime(_binding_0) => {
+            TypeParamBound::Lifetime(f.fold_lifetime(_binding_0)?)
+        }
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_type_paren<F>(f: &mut F, node: TypeParen) -> Result<TypeParen, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(TypeParen {
+        paren_token: Paren(tokens_helper(f, &node.paren_token.span)?),
+        elem: Box::new(f.fold_type(*node.elem)?),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_type_path<F>(f: &mut F, node: TypePath) -> Result<TypePath, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(TypePath {
+        qself: match node.qself {
+            Some(it) => Some(f.fold_qself(it)?),
+            None => None,
+        },
+        path: f.fold_path(node.path)?,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_type_ptr<F>(f: &mut F, node: TypePtr) -> Result<TypePtr, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(TypePtr {
+        star_token: Token ! [*](tokens_helper(f, &node.star_token.spans)?),
+        const_token: match node.const_token {
+            Some(it) => Some(Token![const](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        mutability: match node.mutability {
+            Some(it) => Some(Token![mut](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        elem: Box::new(f.fold_type(*node.elem)?),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_type_reference<F>(f: &mut F, node: TypeReference) -> Result<TypeReference, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(TypeReference {
+        and_token: Token ! [&](tokens_helper(f, &node.and_token.spans)?),
+        lifetime: match node.lifetime {
+            Some(it) => Some(f.fold_lifetime(it)?),
+            None => None,
+        },
+        mutability: match node.mutability {
+            Some(it) => Some(Token![mut](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        elem: Box::new(f.fold_type(*node.elem)?),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_type_slice<F>(f: &mut F, node: TypeSlice) -> Result<TypeSlice, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(TypeSlice {
+        bracket_token: Bracket(tokens_helper(f, &node.bracket_token.span)?),
+        elem: Box::new(f.fold_type(*node.elem)?),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_type_trait_object<F>(
+    f: &mut F,
+    node: TypeTraitObject,
+) -> Result<TypeTraitObject, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(TypeTraitObject {
+        dyn_token: match node.dyn_token {
+            Some(it) => Some(Token![dyn](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        bounds: FoldHelper::lift(node.bounds, f, |f, it| Ok(f.fold_type_param_bound(it)?))?,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_type_tuple<F>(f: &mut F, node: TypeTuple) -> Result<TypeTuple, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(TypeTuple {
+        paren_token: Paren(tokens_helper(f, &node.paren_token.span)?),
+        elems: FoldHelper::lift(node.elems, f, |f, it| Ok(f.fold_type(it)?))?,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_un_op<F>(f: &mut F, node: UnOp) -> Result<UnOp, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        UnOp::Deref(_binding_0) => UnOp::Deref(Token ! [*](tokens_helper(f, &_binding_0.spans)?)),
+        UnOp::Not(_binding_0) => UnOp::Not(Token![!](tokens_helper(f, &_binding_0.spans)?)),
+        UnOp::Neg(_binding_0) => UnOp::Neg(Token ! [-](tokens_helper(f, &_binding_0.spans)?)),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_use_glob<F>(f: &mut F, node: UseGlob) -> Result<UseGlob, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(UseGlob {
+        star_token: Token ! [*](tokens_helper(f, &node.star_token.spans)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_use_group<F>(f: &mut F, node: UseGroup) -> Result<UseGroup, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(UseGroup {
+        brace_token: Brace(tokens_helper(f, &node.brace_token.span)?),
+        items: FoldHelper::lift(node.items, f, |f, it| Ok(f.fold_use_tree(it)?))?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_use_name<F>(f: &mut F, node: UseName) -> Result<UseName, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(UseName {
+        ident: f.fold_ident(node.ident)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_use_path<F>(f: &mut F, node: UsePath) -> Result<UsePath, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(UsePath {
+        ident: f.fold_ident(node.ident)?,
+        colon2_token: Token ! [::](tokens_helper(f, &node.colon2_token.spans)?),
+        tree: Box::new(f.fold_use_tree(*node.tree)?),
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_use_rename<F>(f: &mut F, node: UseRename) -> Result<UseRename, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(UseRename {
+        ident: f.fold_ident(node.ident)?,
+        as_token: Token![as](tokens_helper(f, &node.as_token.span)?),
+        rename: f.fold_ident(node.rename)?,
+    })
+}
+#[cfg(feature = "full")]
+pub fn fold_use_tree<F>(f: &mut F, node: UseTree) -> Result<UseTree, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        UseTree::Path(_binding_0) => UseTree::Path(f.fold_use_path(_binding_0)?),
+        UseTree::Name(_binding_0) => UseTree::Name(f.fold_use_name(_binding_0)?),
+        UseTree::Rename(_binding_0) => UseTree::Rename(f.fold_use_rename(_binding_0)?),
+        UseTree::Glob(_binding_0) => UseTree::Glob(f.fold_use_glob(_binding_0)?),
+        UseTree::Group(_binding_0) => UseTree::Group(f.fold_use_group(_binding_0)?),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_variadic<F>(f: &mut F, node: Variadic) -> Result<Variadic, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(Variadic {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        dots: Token ! [...](tokens_helper(f, &node.dots.spans)?),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_variant<F>(f: &mut F, node: Variant) -> Result<Variant, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(Variant {
+        attrs: FoldHelper::lift(node.attrs, f, |f, it| Ok(f.fold_attribute(it)?))?,
+        ident: f.fold_ident(node.ident)?,
+        fields: f.fold_fields(node.fields)?,
+        discriminant: match node.discriminant {
+            Some(it) => Some((
+                Token ! [=](tokens_helper(f, &(it).0.spans)?),
+                f.fold_expr((it).1)?,
+            )),
+            None => None,
+        },
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_vis_crate<F>(f: &mut F, node: VisCrate) -> Result<VisCrate, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(VisCrate {
+        crate_token: Token![crate](tokens_helper(f, &node.crate_token.span)?),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_vis_public<F>(f: &mut F, node: VisPublic) -> Result<VisPublic, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(VisPublic {
+        pub_token: Token![pub](tokens_helper(f, &node.pub_token.span)?),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_vis_restricted<F>(f: &mut F, node: VisRestricted) -> Result<VisRestricted, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(VisRestricted {
+        pub_token: Token![pub](tokens_helper(f, &node.pub_token.span)?),
+        paren_token: Paren(tokens_helper(f, &node.paren_token.span)?),
+        in_token: match node.in_token {
+            Some(it) => Some(Token![in](tokens_helper(f, &it.span)?)),
+            None => None,
+        },
+        path: Box::new(f.fold_path(*node.path)?),
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_visibility<F>(f: &mut F, node: Visibility) -> Result<Visibility, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        Visibility::Public(_binding_0) => Visibility::Public(f.fold_vis_public(_binding_0)?),
+        Visibility::Crate(_binding_0) => Visibility::Crate(f.fold_vis_crate(_binding_0)?),
+        Visibility::Restricted(_binding_0) => {
+            Visibility::Restricted(f.fold_vis_restricted(_binding_0)?)
+        }
+        Visibility::Inherited => Visibility::Inherited,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_where_clause<F>(f: &mut F, node: WhereClause) -> Result<WhereClause, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(WhereClause {
+        where_token: Token![where](tokens_helper(f, &node.where_token.span)?),
+        predicates: FoldHelper::lift(node.predicates, f, |f, it| Ok(f.fold_where_predicate(it)?))?,
+    })
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn fold_where_predicate<F>(f: &mut F, node: WherePredicate) -> Result<WherePredicate, F::Error>
+where
+    F: TryFold + ?Sized,
+{
+    Ok(match node {
+        WherePredicate::Type(_binding_0) => {
+            WherePredicate::Type(f.fold_predicate_type(_binding_0)?)
+        }
+        WherePredicate::Lifetime(_binding_0) => {
+            WherePredicate::Lifetime(f.fold_predicate_lifetime(_binding_0)?)
+        }
+        WherePredicate::Eq(_binding_0) => WherePredicate::Eq(f.fold_predicate_eq(_binding_0)?),
+    })
+}
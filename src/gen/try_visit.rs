@@ -0,0 +1,4355 @@
+// This file is @generated by syn-internal-codegen.
+// It is not intended for manual editing.
+
+#![allow(unused_variables)]
+#[cfg(any(feature = "full", feature = "derive"))]
+use crate::gen::helper::try_visit::*;
+#[cfg(any(feature = "full", feature = "derive"))]
+use crate::punctuated::Punctuated;
+use crate::*;
+use proc_macro2::Span;
+use std::result::Result;
+#[cfg(feature = "full")]
+macro_rules! full {
+    ($e:expr) => {
+        $e
+    };
+}
+#[cfg(all(feature = "derive", not(feature = "full")))]
+macro_rules! full {
+    ($e:expr) => {
+        unreachable!()
+    };
+}
+macro_rules! skip {
+    ($($tt:tt)*) => {};
+}
+/// Syntax tree traversal to walk a shared borrow of a syntax tree,
+/// stopping as soon as any method returns `Err`.
+///
+/// See the [module documentation] for details.
+///
+/// [module documentation]: self
+///
+/// *This trait is available only if Syn is built with the `"try-visit"` feature.*
+pub trait TryVisit<'ast> {
+    /// The value that a method returns to stop the traversal, for
+    /// example the node being searched for or an error.
+    type Break;
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_abi(&mut self, i: &'ast Abi) -> Result<(), Self::Break> {
+        visit_abi(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_angle_bracketed_generic_arguments(
+        &mut self,
+        i: &'ast AngleBracketedGenericArguments,
+    ) -> Result<(), Self::Break> {
+        visit_angle_bracketed_generic_arguments(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_arm(&mut self, i: &'ast Arm) -> Result<(), Self::Break> {
+        visit_arm(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_attr_meta(&mut self, i: &'ast AttrMeta) -> Result<(), Self::Break> {
+        visit_attr_meta(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_attr_meta_list(&mut self, i: &'ast AttrMetaList) -> Result<(), Self::Break> {
+        visit_attr_meta_list(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_attr_meta_name_value(
+        &mut self,
+        i: &'ast AttrMetaNameValue,
+    ) -> Result<(), Self::Break> {
+        visit_attr_meta_name_value(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_attr_style(&mut self, i: &'ast AttrStyle) -> Result<(), Self::Break> {
+        visit_attr_style(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_attribute(&mut self, i: &'ast Attribute) -> Result<(), Self::Break> {
+        visit_attribute(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_bare_fn_arg(&mut self, i: &'ast BareFnArg) -> Result<(), Self::Break> {
+        visit_bare_fn_arg(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_bin_op(&mut self, i: &'ast BinOp) -> Result<(), Self::Break> {
+        visit_bin_op(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_binding(&mut self, i: &'ast Binding) -> Result<(), Self::Break> {
+        visit_binding(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_block(&mut self, i: &'ast Block) -> Result<(), Self::Break> {
+        visit_block(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_bound_lifetimes(&mut self, i: &'ast BoundLifetimes) -> Result<(), Self::Break> {
+        visit_bound_lifetimes(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_const_param(&mut self, i: &'ast ConstParam) -> Result<(), Self::Break> {
+        visit_const_param(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_constraint(&mut self, i: &'ast Constraint) -> Result<(), Self::Break> {
+        visit_constraint(self, i)
+    }
+    #[cfg(feature = "derive")]
+    fn visit_data(&mut self, i: &'ast Data) -> Result<(), Self::Break> {
+        visit_data(self, i)
+    }
+    #[cfg(feature = "derive")]
+    fn visit_data_enum(&mut self, i: &'ast DataEnum) -> Result<(), Self::Break> {
+        visit_data_enum(self, i)
+    }
+    #[cfg(feature = "derive")]
+    fn visit_data_struct(&mut self, i: &'ast DataStruct) -> Result<(), Self::Break> {
+        visit_data_struct(self, i)
+    }
+    #[cfg(feature = "derive")]
+    fn visit_data_union(&mut self, i: &'ast DataUnion) -> Result<(), Self::Break> {
+        visit_data_union(self, i)
+    }
+    #[cfg(feature = "derive")]
+    fn visit_derive_input(&mut self, i: &'ast DeriveInput) -> Result<(), Self::Break> {
+        visit_derive_input(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr(&mut self, i: &'ast Expr) -> Result<(), Self::Break> {
+        visit_expr(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_array(&mut self, i: &'ast ExprArray) -> Result<(), Self::Break> {
+        visit_expr_array(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_assign(&mut self, i: &'ast ExprAssign) -> Result<(), Self::Break> {
+        visit_expr_assign(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_assign_op(&mut self, i: &'ast ExprAssignOp) -> Result<(), Self::Break> {
+        visit_expr_assign_op(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_async(&mut self, i: &'ast ExprAsync) -> Result<(), Self::Break> {
+        visit_expr_async(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_await(&mut self, i: &'ast ExprAwait) -> Result<(), Self::Break> {
+        visit_expr_await(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_binary(&mut self, i: &'ast ExprBinary) -> Result<(), Self::Break> {
+        visit_expr_binary(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_block(&mut self, i: &'ast ExprBlock) -> Result<(), Self::Break> {
+        visit_expr_block(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_box(&mut self, i: &'ast ExprBox) -> Result<(), Self::Break> {
+        visit_expr_box(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_break(&mut self, i: &'ast ExprBreak) -> Result<(), Self::Break> {
+        visit_expr_break(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_call(&mut self, i: &'ast ExprCall) -> Result<(), Self::Break> {
+        visit_expr_call(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_cast(&mut self, i: &'ast ExprCast) -> Result<(), Self::Break> {
+        visit_expr_cast(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_closure(&mut self, i: &'ast ExprClosure) -> Result<(), Self::Break> {
+        visit_expr_closure(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_const(&mut self, i: &'ast ExprConst) -> Result<(), Self::Break> {
+        visit_expr_const(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_continue(&mut self, i: &'ast ExprContinue) -> Result<(), Self::Break> {
+        visit_expr_continue(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_field(&mut self, i: &'ast ExprField) -> Result<(), Self::Break> {
+        visit_expr_field(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_for_loop(&mut self, i: &'ast ExprForLoop) -> Result<(), Self::Break> {
+        visit_expr_for_loop(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_group(&mut self, i: &'ast ExprGroup) -> Result<(), Self::Break> {
+        visit_expr_group(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_if(&mut self, i: &'ast ExprIf) -> Result<(), Self::Break> {
+        visit_expr_if(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_index(&mut self, i: &'ast ExprIndex) -> Result<(), Self::Break> {
+        visit_expr_index(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_let(&mut self, i: &'ast ExprLet) -> Result<(), Self::Break> {
+        visit_expr_let(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_lit(&mut self, i: &'ast ExprLit) -> Result<(), Self::Break> {
+        visit_expr_lit(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_loop(&mut self, i: &'ast ExprLoop) -> Result<(), Self::Break> {
+        visit_expr_loop(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_macro(&mut self, i: &'ast ExprMacro) -> Result<(), Self::Break> {
+        visit_expr_macro(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_match(&mut self, i: &'ast ExprMatch) -> Result<(), Self::Break> {
+        visit_expr_match(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_method_call(&mut self, i: &'ast ExprMethodCall) -> Result<(), Self::Break> {
+        visit_expr_method_call(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_paren(&mut self, i: &'ast ExprParen) -> Result<(), Self::Break> {
+        visit_expr_paren(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_path(&mut self, i: &'ast ExprPath) -> Result<(), Self::Break> {
+        visit_expr_path(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_range(&mut self, i: &'ast ExprRange) -> Result<(), Self::Break> {
+        visit_expr_range(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_raw_addr(&mut self, i: &'ast ExprRawAddr) -> Result<(), Self::Break> {
+        visit_expr_raw_addr(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_reference(&mut self, i: &'ast ExprReference) -> Result<(), Self::Break> {
+        visit_expr_reference(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_repeat(&mut self, i: &'ast ExprRepeat) -> Result<(), Self::Break> {
+        visit_expr_repeat(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_return(&mut self, i: &'ast ExprReturn) -> Result<(), Self::Break> {
+        visit_expr_return(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_struct(&mut self, i: &'ast ExprStruct) -> Result<(), Self::Break> {
+        visit_expr_struct(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_try(&mut self, i: &'ast ExprTry) -> Result<(), Self::Break> {
+        visit_expr_try(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_try_block(&mut self, i: &'ast ExprTryBlock) -> Result<(), Self::Break> {
+        visit_expr_try_block(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_tuple(&mut self, i: &'ast ExprTuple) -> Result<(), Self::Break> {
+        visit_expr_tuple(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_type(&mut self, i: &'ast ExprType) -> Result<(), Self::Break> {
+        visit_expr_type(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_unary(&mut self, i: &'ast ExprUnary) -> Result<(), Self::Break> {
+        visit_expr_unary(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_unsafe(&mut self, i: &'ast ExprUnsafe) -> Result<(), Self::Break> {
+        visit_expr_unsafe(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_while(&mut self, i: &'ast ExprWhile) -> Result<(), Self::Break> {
+        visit_expr_while(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_yield(&mut self, i: &'ast ExprYield) -> Result<(), Self::Break> {
+        visit_expr_yield(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_field(&mut self, i: &'ast Field) -> Result<(), Self::Break> {
+        visit_field(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_field_pat(&mut self, i: &'ast FieldPat) -> Result<(), Self::Break> {
+        visit_field_pat(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_field_value(&mut self, i: &'ast FieldValue) -> Result<(), Self::Break> {
+        visit_field_value(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_fields(&mut self, i: &'ast Fields) -> Result<(), Self::Break> {
+        visit_fields(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_fields_named(&mut self, i: &'ast FieldsNamed) -> Result<(), Self::Break> {
+        visit_fields_named(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_fields_unnamed(&mut self, i: &'ast FieldsUnnamed) -> Result<(), Self::Break> {
+        visit_fields_unnamed(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_file(&mut self, i: &'ast File) -> Result<(), Self::Break> {
+        visit_file(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_fn_arg(&mut self, i: &'ast FnArg) -> Result<(), Self::Break> {
+        visit_fn_arg(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item(&mut self, i: &'ast ForeignItem) -> Result<(), Self::Break> {
+        visit_foreign_item(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_fn(&mut self, i: &'ast ForeignItemFn) -> Result<(), Self::Break> {
+        visit_foreign_item_fn(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_macro(&mut self, i: &'ast ForeignItemMacro) -> Result<(), Self::Break> {
+        visit_foreign_item_macro(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_static(&mut self, i: &'ast ForeignItemStatic) -> Result<(), Self::Break> {
+        visit_foreign_item_static(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_type(&mut self, i: &'ast ForeignItemType) -> Result<(), Self::Break> {
+        visit_foreign_item_type(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_generic_argument(&mut self, i: &'ast GenericArgument) -> Result<(), Self::Break> {
+        visit_generic_argument(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_generic_method_argument(
+        &mut self,
+        i: &'ast GenericMethodArgument,
+    ) -> Result<(), Self::Break> {
+        visit_generic_method_argument(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_generic_param(&mut self, i: &'ast GenericParam) -> Result<(), Self::Break> {
+        visit_generic_param(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_generics(&mut self, i: &'ast Generics) -> Result<(), Self::Break> {
+        visit_generics(self, i)
+    }
+    fn visit_ident(&mut self, i: &'ast Ident) -> Result<(), Self::Break> {
+        visit_ident(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item(&mut self, i: &'ast ImplItem) -> Result<(), Self::Break> {
+        visit_impl_item(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_const(&mut self, i: &'ast ImplItemConst) -> Result<(), Self::Break> {
+        visit_impl_item_const(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_macro(&mut self, i: &'ast ImplItemMacro) -> Result<(), Self::Break> {
+        visit_impl_item_macro(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_method(&mut self, i: &'ast ImplItemMethod) -> Result<(), Self::Break> {
+        visit_impl_item_method(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_type(&mut self, i: &'ast ImplItemType) -> Result<(), Self::Break> {
+        visit_impl_item_type(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_index(&mut self, i: &'ast Index) -> Result<(), Self::Break> {
+        visit_index(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item(&mut self, i: &'ast Item) -> Result<(), Self::Break> {
+        visit_item(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_const(&mut self, i: &'ast ItemConst) -> Result<(), Self::Break> {
+        visit_item_const(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_enum(&mut self, i: &'ast ItemEnum) -> Result<(), Self::Break> {
+        visit_item_enum(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_extern_crate(&mut self, i: &'ast ItemExternCrate) -> Result<(), Self::Break> {
+        visit_item_extern_crate(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_fn(&mut self, i: &'ast ItemFn) -> Result<(), Self::Break> {
+        visit_item_fn(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_fn_decl(&mut self, i: &'ast ItemFnDecl) -> Result<(), Self::Break> {
+        visit_item_fn_decl(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_foreign_mod(&mut self, i: &'ast ItemForeignMod) -> Result<(), Self::Break> {
+        visit_item_foreign_mod(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_impl(&mut self, i: &'ast ItemImpl) -> Result<(), Self::Break> {
+        visit_item_impl(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_macro(&mut self, i: &'ast ItemMacro) -> Result<(), Self::Break> {
+        visit_item_macro(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_macro2(&mut self, i: &'ast ItemMacro2) -> Result<(), Self::Break> {
+        visit_item_macro2(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_mod(&mut self, i: &'ast ItemMod) -> Result<(), Self::Break> {
+        visit_item_mod(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_static(&mut self, i: &'ast ItemStatic) -> Result<(), Self::Break> {
+        visit_item_static(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_struct(&mut self, i: &'ast ItemStruct) -> Result<(), Self::Break> {
+        visit_item_struct(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_trait(&mut self, i: &'ast ItemTrait) -> Result<(), Self::Break> {
+        visit_item_trait(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_trait_alias(&mut self, i: &'ast ItemTraitAlias) -> Result<(), Self::Break> {
+        visit_item_trait_alias(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_type(&mut self, i: &'ast ItemType) -> Result<(), Self::Break> {
+        visit_item_type(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_union(&mut self, i: &'ast ItemUnion) -> Result<(), Self::Break> {
+        visit_item_union(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_use(&mut self, i: &'ast ItemUse) -> Result<(), Self::Break> {
+        visit_item_use(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_label(&mut self, i: &'ast Label) -> Result<(), Self::Break> {
+        visit_label(self, i)
+    }
+    fn visit_lifetime(&mut self, i: &'ast Lifetime) -> Result<(), Self::Break> {
+        visit_lifetime(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lifetime_def(&mut self, i: &'ast LifetimeDef) -> Result<(), Self::Break> {
+        visit_lifetime_def(self, i)
+    }
+    fn visit_lit(&mut self, i: &'ast Lit) -> Result<(), Self::Break> {
+        visit_lit(self, i)
+    }
+    fn visit_lit_bool(&mut self, i: &'ast LitBool) -> Result<(), Self::Break> {
+        visit_lit_bool(self, i)
+    }
+    fn visit_lit_byte(&mut self, i: &'ast LitByte) -> Result<(), Self::Break> {
+        visit_lit_byte(self, i)
+    }
+    fn visit_lit_byte_str(&mut self, i: &'ast LitByteStr) -> Result<(), Self::Break> {
+        visit_lit_byte_str(self, i)
+    }
+    fn visit_lit_char(&mut self, i: &'ast LitChar) -> Result<(), Self::Break> {
+        visit_lit_char(self, i)
+    }
+    fn visit_lit_float(&mut self, i: &'ast LitFloat) -> Result<(), Self::Break> {
+        visit_lit_float(self, i)
+    }
+    fn visit_lit_int(&mut self, i: &'ast LitInt) -> Result<(), Self::Break> {
+        visit_lit_int(self, i)
+    }
+    fn visit_lit_str(&mut self, i: &'ast LitStr) -> Result<(), Self::Break> {
+        visit_lit_str(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_local(&mut self, i: &'ast Local) -> Result<(), Self::Break> {
+        visit_local(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_local_init(&mut self, i: &'ast LocalInit) -> Result<(), Self::Break> {
+        visit_local_init(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_macro(&mut self, i: &'ast Macro) -> Result<(), Self::Break> {
+        visit_macro(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_macro_delimiter(&mut self, i: &'ast MacroDelimiter) -> Result<(), Self::Break> {
+        visit_macro_delimiter(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_macro_group(&mut self, i: &'ast MacroGroup) -> Result<(), Self::Break> {
+        visit_macro_group(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_macro_metavar(&mut self, i: &'ast MacroMetavar) -> Result<(), Self::Break> {
+        visit_macro_metavar(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_macro_rep_op(&mut self, i: &'ast MacroRepOp) -> Result<(), Self::Break> {
+        visit_macro_rep_op(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_macro_repetition(&mut self, i: &'ast MacroRepetition) -> Result<(), Self::Break> {
+        visit_macro_repetition(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_macro_rule(&mut self, i: &'ast MacroRule) -> Result<(), Self::Break> {
+        visit_macro_rule(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_macro_rules(&mut self, i: &'ast MacroRules) -> Result<(), Self::Break> {
+        visit_macro_rules(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_macro_tree(&mut self, i: &'ast MacroTree) -> Result<(), Self::Break> {
+        visit_macro_tree(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_member(&mut self, i: &'ast Member) -> Result<(), Self::Break> {
+        visit_member(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_meta(&mut self, i: &'ast Meta) -> Result<(), Self::Break> {
+        visit_meta(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_meta_list(&mut self, i: &'ast MetaList) -> Result<(), Self::Break> {
+        visit_meta_list(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_meta_name_value(&mut self, i: &'ast MetaNameValue) -> Result<(), Self::Break> {
+        visit_meta_name_value(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_method_turbofish(&mut self, i: &'ast MethodTurbofish) -> Result<(), Self::Break> {
+        visit_method_turbofish(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_nested_meta(&mut self, i: &'ast NestedMeta) -> Result<(), Self::Break> {
+        visit_nested_meta(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_parenthesized_generic_arguments(
+        &mut self,
+        i: &'ast ParenthesizedGenericArguments,
+    ) -> Result<(), Self::Break> {
+        visit_parenthesized_generic_arguments(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat(&mut self, i: &'ast Pat) -> Result<(), Self::Break> {
+        visit_pat(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_box(&mut self, i: &'ast PatBox) -> Result<(), Self::Break> {
+        visit_pat_box(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_const(&mut self, i: &'ast PatConst) -> Result<(), Self::Break> {
+        visit_pat_const(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_ident(&mut self, i: &'ast PatIdent) -> Result<(), Self::Break> {
+        visit_pat_ident(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_lit(&mut self, i: &'ast PatLit) -> Result<(), Self::Break> {
+        visit_pat_lit(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_macro(&mut self, i: &'ast PatMacro) -> Result<(), Self::Break> {
+        visit_pat_macro(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_or(&mut self, i: &'ast PatOr) -> Result<(), Self::Break> {
+        visit_pat_or(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_path(&mut self, i: &'ast PatPath) -> Result<(), Self::Break> {
+        visit_pat_path(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_range(&mut self, i: &'ast PatRange) -> Result<(), Self::Break> {
+        visit_pat_range(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_reference(&mut self, i: &'ast PatReference) -> Result<(), Self::Break> {
+        visit_pat_reference(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_rest(&mut self, i: &'ast PatRest) -> Result<(), Self::Break> {
+        visit_pat_rest(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_slice(&mut self, i: &'ast PatSlice) -> Result<(), Self::Break> {
+        visit_pat_slice(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_struct(&mut self, i: &'ast PatStruct) -> Result<(), Self::Break> {
+        visit_pat_struct(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_tuple(&mut self, i: &'ast PatTuple) -> Result<(), Self::Break> {
+        visit_pat_tuple(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_tuple_struct(&mut self, i: &'ast PatTupleStruct) -> Result<(), Self::Break> {
+        visit_pat_tuple_struct(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_type(&mut self, i: &'ast PatType) -> Result<(), Self::Break> {
+        visit_pat_type(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_wild(&mut self, i: &'ast PatWild) -> Result<(), Self::Break> {
+        visit_pat_wild(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_path(&mut self, i: &'ast Path) -> Result<(), Self::Break> {
+        visit_path(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_path_arguments(&mut self, i: &'ast PathArguments) -> Result<(), Self::Break> {
+        visit_path_arguments(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_path_segment(&mut self, i: &'ast PathSegment) -> Result<(), Self::Break> {
+        visit_path_segment(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pointer_mutability(&mut self, i: &'ast PointerMutability) -> Result<(), Self::Break> {
+        visit_pointer_mutability(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_predicate_eq(&mut self, i: &'ast PredicateEq) -> Result<(), Self::Break> {
+        visit_predicate_eq(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_predicate_lifetime(&mut self, i: &'ast PredicateLifetime) -> Result<(), Self::Break> {
+        visit_predicate_lifetime(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_predicate_type(&mut self, i: &'ast PredicateType) -> Result<(), Self::Break> {
+        visit_predicate_type(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_qself(&mut self, i: &'ast QSelf) -> Result<(), Self::Break> {
+        visit_qself(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_range_limits(&mut self, i: &'ast RangeLimits) -> Result<(), Self::Break> {
+        visit_range_limits(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_receiver(&mut self, i: &'ast Receiver) -> Result<(), Self::Break> {
+        visit_receiver(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_return_type(&mut self, i: &'ast ReturnType) -> Result<(), Self::Break> {
+        visit_return_type(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_signature(&mut self, i: &'ast Signature) -> Result<(), Self::Break> {
+        visit_signature(self, i)
+    }
+    fn visit_span(&mut self, i: &Span) -> Result<(), Self::Break> {
+        visit_span(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_stmt(&mut self, i: &'ast Stmt) -> Result<(), Self::Break> {
+        visit_stmt(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_trait_bound(&mut self, i: &'ast TraitBound) -> Result<(), Self::Break> {
+        visit_trait_bound(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_trait_bound_modifier(
+        &mut self,
+        i: &'ast TraitBoundModifier,
+    ) -> Result<(), Self::Break> {
+        visit_trait_bound_modifier(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item(&mut self, i: &'ast TraitItem) -> Result<(), Self::Break> {
+        visit_trait_item(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_const(&mut self, i: &'ast TraitItemConst) -> Result<(), Self::Break> {
+        visit_trait_item_const(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_macro(&mut self, i: &'ast TraitItemMacro) -> Result<(), Self::Break> {
+        visit_trait_item_macro(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_method(&mut self, i: &'ast TraitItemMethod) -> Result<(), Self::Break> {
+        visit_trait_item_method(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_type(&mut self, i: &'ast TraitItemType) -> Result<(), Self::Break> {
+        visit_trait_item_type(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type(&mut self, i: &'ast Type) -> Result<(), Self::Break> {
+        visit_type(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_array(&mut self, i: &'ast TypeArray) -> Result<(), Self::Break> {
+        visit_type_array(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_bare_fn(&mut self, i: &'ast TypeBareFn) -> Result<(), Self::Break> {
+        visit_type_bare_fn(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_group(&mut self, i: &'ast TypeGroup) -> Result<(), Self::Break> {
+        visit_type_group(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_impl_trait(&mut self, i: &'ast TypeImplTrait) -> Result<(), Self::Break> {
+        visit_type_impl_trait(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_infer(&mut self, i: &'ast TypeInfer) -> Result<(), Self::Break> {
+        visit_type_infer(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_macro(&mut self, i: &'ast TypeMacro) -> Result<(), Self::Break> {
+        visit_type_macro(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_never(&mut self, i: &'ast TypeNever) -> Result<(), Self::Break> {
+        visit_type_never(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_param(&mut self, i: &'ast TypeParam) -> Result<(), Self::Break> {
+        visit_type_param(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_param_bound(&mut self, i: &'ast TypeParamBound) -> Result<(), Self::Break> {
+        visit_type_param_bound(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_paren(&mut self, i: &'ast TypeParen) -> Result<(), Self::Break> {
+        visit_type_paren(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_path(&mut self, i: &'ast TypePath) -> Result<(), Self::Break> {
+        visit_type_path(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_ptr(&mut self, i: &'ast TypePtr) -> Result<(), Self::Break> {
+        visit_type_ptr(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_reference(&mut self, i: &'ast TypeReference) -> Result<(), Self::Break> {
+        visit_type_reference(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_slice(&mut self, i: &'ast TypeSlice) -> Result<(), Self::Break> {
+        visit_type_slice(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_trait_object(&mut self, i: &'ast TypeTraitObject) -> Result<(), Self::Break> {
+        visit_type_trait_object(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_tuple(&mut self, i: &'ast TypeTuple) -> Result<(), Self::Break> {
+        visit_type_tuple(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_un_op(&mut self, i: &'ast UnOp) -> Result<(), Self::Break> {
+        visit_un_op(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_glob(&mut self, i: &'ast UseGlob) -> Result<(), Self::Break> {
+        visit_use_glob(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_group(&mut self, i: &'ast UseGroup) -> Result<(), Self::Break> {
+        visit_use_group(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_name(&mut self, i: &'ast UseName) -> Result<(), Self::Break> {
+        visit_use_name(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_path(&mut self, i: &'ast UsePath) -> Result<(), Self::Break> {
+        visit_use_path(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_rename(&mut self, i: &'ast UseRename) -> Result<(), Self::Break> {
+        visit_use_rename(self, i)
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_tree(&mut self, i: &'ast UseTree) -> Result<(), Self::Break> {
+        visit_use_tree(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_variadic(&mut self, i: &'ast Variadic) -> Result<(), Self::Break> {
+        visit_variadic(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_variant(&mut self, i: &'ast Variant) -> Result<(), Self::Break> {
+        visit_variant(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_vis_crate(&mut self, i: &'ast VisCrate) -> Result<(), Self::Break> {
+        visit_vis_crate(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_vis_public(&mut self, i: &'ast VisPublic) -> Result<(), Self::Break> {
+        visit_vis_public(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_vis_restricted(&mut self, i: &'ast VisRestricted) -> Result<(), Self::Break> {
+        visit_vis_restricted(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_visibility(&mut self, i: &'ast Visibility) -> Result<(), Self::Break> {
+        visit_visibility(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_where_clause(&mut self, i: &'ast WhereClause) -> Result<(), Self::Break> {
+        visit_where_clause(self, i)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_where_predicate(&mut self, i: &'ast WherePredicate) -> Result<(), Self::Break> {
+        visit_where_predicate(self, i)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_abi<'ast, V>(v: &mut V, node: &'ast Abi) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.extern_token.span)?;
+    if let Some(it) = &node.name {
+        v.visit_lit_str(it)?;
+    };
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_angle_bracketed_generic_arguments<'ast, V>(
+    v: &mut V,
+    node: &'ast AngleBracketedGenericArguments,
+) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    if let Some(it) = &node.colon2_token {
+        tokens_helper(v, &it.spans)?;
+    };
+    tokens_helper(v, &node.lt_token.spans)?;
+    for el in Punctuated::pairs(&node.args) {
+        let (it, p) = el.into_tuple();
+        v.visit_generic_argument(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    tokens_helper(v, &node.gt_token.spans)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_arm<'ast, V>(v: &mut V, node: &'ast Arm) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_pat(&node.pat)?;
+    if let Some(it) = &node.guard {
+        tokens_helper(v, &(it).0.span)?;
+        v.visit_expr(&*(it).1)?;
+    };
+    tokens_helper(v, &node.fat_arrow_token.spans)?;
+    v.visit_expr(&*node.body)?;
+    if let Some(it) = &node.comma {
+        tokens_helper(v, &it.spans)?;
+    };
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_attr_meta<'ast, V>(v: &mut V, node: &'ast AttrMeta) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        AttrMeta::Path(_binding_0) => {
+            v.visit_path(_binding_0)?;
+        }
+        AttrMeta::List(_binding_0) => {
+            v.visit_attr_meta_list(_binding_0)?;
+        }
+        AttrMeta::NameValue(_binding_0) => {
+            v.visit_attr_meta_name_value(_binding_0)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_attr_meta_list<'ast, V>(v: &mut V, node: &'ast AttrMetaList) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    v.visit_path(&node.path)?;
+    v.visit_macro_delimiter(&node.delimiter)?;
+    skip!(node.tokens);
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_attr_meta_name_value<'ast, V>(
+    v: &mut V,
+    node: &'ast AttrMetaNameValue,
+) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    v.visit_path(&node.path)?;
+    tokens_helper(v, &node.eq_token.spans)?;
+    v.visit_expr(&node.value)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_attr_style<'ast, V>(v: &mut V, node: &'ast AttrStyle) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        AttrStyle::Outer => {}
+        AttrStyle::Inner(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_attribute<'ast, V>(v: &mut V, node: &'ast Attribute) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.pound_token.spans)?;
+    v.visit_attr_style(&node.style)?;
+    tokens_helper(v, &node.bracket_token.span)?;
+    v.visit_path(&node.path)?;
+    skip!(node.tokens);
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_bare_fn_arg<'ast, V>(v: &mut V, node: &'ast BareFnArg) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    if let Some(it) = &node.name {
+        v.visit_ident(&(it).0)?;
+        tokens_helper(v, &(it).1.spans)?;
+    };
+    v.visit_type(&node.ty)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_bin_op<'ast, V>(v: &mut V, node: &'ast BinOp) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        BinOp::Add(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        BinOp::Sub(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        BinOp::Mul(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        BinOp::Div(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        BinOp::Rem(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        BinOp::And(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        BinOp::Or(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        BinOp::BitXor(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        BinOp::BitAnd(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        BinOp::BitOr(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        BinOp::Shl(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        BinOp::Shr(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        BinOp::Eq(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        BinOp::Lt(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        BinOp::Le(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        BinOp::Ne(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        BinOp::Ge(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        BinOp::Gt(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        BinOp::AddEq(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        BinOp::SubEq(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        BinOp::MulEq(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        BinOp::DivEq(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        BinOp::RemEq(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        BinOp::BitXorEq(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        BinOp::BitAndEq(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        BinOp::BitOrEq(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        BinOp::ShlEq(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        BinOp::ShrEq(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_binding<'ast, V>(v: &mut V, node: &'ast Binding) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    v.visit_ident(&node.ident)?;
+    tokens_helper(v, &node.eq_token.spans)?;
+    v.visit_type(&node.ty)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_block<'ast, V>(v: &mut V, node: &'ast Block) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.brace_token.span)?;
+    for it in &node.stmts {
+        v.visit_stmt(it)?;
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_bound_lifetimes<'ast, V>(v: &mut V, node: &'ast BoundLifetimes) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.for_token.span)?;
+    tokens_helper(v, &node.lt_token.spans)?;
+    for el in Punctuated::pairs(&node.lifetimes) {
+        let (it, p) = el.into_tuple();
+        v.visit_lifetime_def(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    tokens_helper(v, &node.gt_token.spans)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_const_param<'ast, V>(v: &mut V, node: &'ast ConstParam) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.const_token.span)?;
+    v.visit_ident(&node.ident)?;
+    tokens_helper(v, &node.colon_token.spans)?;
+    v.visit_type(&node.ty)?;
+    if let Some(it) = &node.eq_token {
+        tokens_helper(v, &it.spans)?;
+    };
+    if let Some(it) = &node.default {
+        v.visit_expr(it)?;
+    };
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_constraint<'ast, V>(v: &mut V, node: &'ast Constraint) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    v.visit_ident(&node.ident)?;
+    tokens_helper(v, &node.colon_token.spans)?;
+    for el in Punctuated::pairs(&node.bounds) {
+        let (it, p) = el.into_tuple();
+        v.visit_type_param_bound(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "derive")]
+pub fn visit_data<'ast, V>(v: &mut V, node: &'ast Data) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        Data::Struct(_binding_0) => {
+            v.visit_data_struct(_binding_0)?;
+        }
+        Data::Enum(_binding_0) => {
+            v.visit_data_enum(_binding_0)?;
+        }
+        Data::Union(_binding_0) => {
+            v.visit_data_union(_binding_0)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "derive")]
+pub fn visit_data_enum<'ast, V>(v: &mut V, node: &'ast DataEnum) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.enum_token.span)?;
+    tokens_helper(v, &node.brace_token.span)?;
+    for el in Punctuated::pairs(&node.variants) {
+        let (it, p) = el.into_tuple();
+        v.visit_variant(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "derive")]
+pub fn visit_data_struct<'ast, V>(v: &mut V, node: &'ast DataStruct) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.struct_token.span)?;
+    v.visit_fields(&node.fields)?;
+    if let Some(it) = &node.semi_token {
+        tokens_helper(v, &it.spans)?;
+    };
+    Ok(())
+}
+#[cfg(feature = "derive")]
+pub fn visit_data_union<'ast, V>(v: &mut V, node: &'ast DataUnion) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.union_token.span)?;
+    v.visit_fields_named(&node.fields)?;
+    Ok(())
+}
+#[cfg(feature = "derive")]
+pub fn visit_derive_input<'ast, V>(v: &mut V, node: &'ast DeriveInput) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_visibility(&node.vis)?;
+    v.visit_ident(&node.ident)?;
+    v.visit_generics(&node.generics)?;
+    v.visit_data(&node.data)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr<'ast, V>(v: &mut V, node: &'ast Expr) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        Expr::Array(_binding_0) => {
+            full!(v.visit_expr_array(_binding_0)?);
+        }
+        Expr::Assign(_binding_0) => {
+            full!(v.visit_expr_assign(_binding_0)?);
+        }
+        Expr::AssignOp(_binding_0) => {
+            full!(v.visit_expr_assign_op(_binding_0)?);
+        }
+        Expr::Async(_binding_0) => {
+            full!(v.visit_expr_async(_binding_0)?);
+        }
+        Expr::Await(_binding_0) => {
+            full!(v.visit_expr_await(_binding_0)?);
+        }
+        Expr::Binary(_binding_0) => {
+            v.visit_expr_binary(_binding_0)?;
+        }
+        Expr::Block(_binding_0) => {
+            full!(v.visit_expr_block(_binding_0)?);
+        }
+        Expr::Box(_binding_0) => {
+            full!(v.visit_expr_box(_binding_0)?);
+        }
+        Expr::Break(_binding_0) => {
+            full!(v.visit_expr_break(_binding_0)?);
+        }
+        Expr::Call(_binding_0) => {
+            v.visit_expr_call(_binding_0)?;
+        }
+        Expr::Cast(_binding_0) => {
+            v.visit_expr_cast(_binding_0)?;
+        }
+        Expr::Closure(_binding_0) => {
+            full!(v.visit_expr_closure(_binding_0)?);
+        }
+        Expr::Const(_binding_0) => {
+            full!(v.visit_expr_const(_binding_0)?);
+        }
+        Expr::Continue(_binding_0) => {
+            full!(v.visit_expr_continue(_binding_0)?);
+        }
+        Expr::Field(_binding_0) => {
+            v.visit_expr_field(_binding_0)?;
+        }
+        Expr::ForLoop(_binding_0) => {
+            full!(v.visit_expr_for_loop(_binding_0)?);
+        }
+        Expr::Group(_binding_0) => {
+            full!(v.visit_expr_group(_binding_0)?);
+        }
+        Expr::If(_binding_0) => {
+            full!(v.visit_expr_if(_binding_0)?);
+        }
+        Expr::Index(_binding_0) => {
+            v.visit_expr_index(_binding_0)?;
+        }
+        Expr::Let(_binding_0) => {
+            full!(v.visit_expr_let(_binding_0)?);
+        }
+        Expr::Lit(_binding_0) => {
+            v.visit_expr_lit(_binding_0)?;
+        }
+        Expr::Loop(_binding_0) => {
+            full!(v.visit_expr_loop(_binding_0)?);
+        }
+        Expr::Macro(_binding_0) => {
+            full!(v.visit_expr_macro(_binding_0)?);
+        }
+        Expr::Match(_binding_0) => {
+            full!(v.visit_expr_match(_binding_0)?);
+        }
+        Expr::MethodCall(_binding_0) => {
+            full!(v.visit_expr_method_call(_binding_0)?);
+        }
+        Expr::Paren(_binding_0) => {
+            v.visit_expr_paren(_binding_0)?;
+        }
+        Expr::Path(_binding_0) => {
+            v.visit_expr_path(_binding_0)?;
+        }
+        Expr::Range(_binding_0) => {
+            full!(v.visit_expr_range(_binding_0)?);
+        }
+        Expr::RawAddr(_binding_0) => {
+            full!(v.visit_expr_raw_addr(_binding_0)?);
+        }
+        Expr::Reference(_binding_0) => {
+            full!(v.visit_expr_reference(_binding_0)?);
+        }
+        Expr::Repeat(_binding_0) => {
+            full!(v.visit_expr_repeat(_binding_0)?);
+        }
+        Expr::Return(_binding_0) => {
+            full!(v.visit_expr_return(_binding_0)?);
+        }
+        Expr::Struct(_binding_0) => {
+            full!(v.visit_expr_struct(_binding_0)?);
+        }
+        Expr::Try(_binding_0) => {
+            full!(v.visit_expr_try(_binding_0)?);
+        }
+        Expr::TryBlock(_binding_0) => {
+            full!(v.visit_expr_try_block(_binding_0)?);
+        }
+        Expr::Tuple(_binding_0) => {
+            full!(v.visit_expr_tuple(_binding_0)?);
+        }
+        Expr::Type(_binding_0) => {
+            full!(v.visit_expr_type(_binding_0)?);
+        }
+        Expr::Unary(_binding_0) => {
+            v.visit_expr_unary(_binding_0)?;
+        }
+        Expr::Unsafe(_binding_0) => {
+            full!(v.visit_expr_unsafe(_binding_0)?);
+        }
+        Expr::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        Expr::While(_binding_0) => {
+            full!(v.visit_expr_while(_binding_0)?);
+        }
+        Expr::Yield(_binding_0) => {
+            full!(v.visit_expr_yield(_binding_0)?);
+        }
+        _ => unreachable!(),
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_array<'ast, V>(v: &mut V, node: &'ast ExprArray) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.bracket_token.span)?;
+    for el in Punctuated::pairs(&node.elems) {
+        let (it, p) = el.into_tuple();
+        v.visit_expr(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_assign<'ast, V>(v: &mut V, node: &'ast ExprAssign) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_expr(&*node.left)?;
+    tokens_helper(v, &node.eq_token.spans)?;
+    v.visit_expr(&*node.right)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_assign_op<'ast, V>(v: &mut V, node: &'ast ExprAssignOp) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_expr(&*node.left)?;
+    v.visit_bin_op(&node.op)?;
+    v.visit_expr(&*node.right)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_async<'ast, V>(v: &mut V, node: &'ast ExprAsync) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.async_token.span)?;
+    if let Some(it) = &node.capture {
+        tokens_helper(v, &it.span)?;
+    };
+    v.visit_block(&node.block)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_await<'ast, V>(v: &mut V, node: &'ast ExprAwait) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_expr(&*node.base)?;
+    tokens_helper(v, &node.dot_token.spans)?;
+    tokens_helper(v, &node.await_token.span)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr_binary<'ast, V>(v: &mut V, node: &'ast ExprBinary) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_expr(&*node.left)?;
+    v.visit_bin_op(&node.op)?;
+    v.visit_expr(&*node.right)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_block<'ast, V>(v: &mut V, node: &'ast ExprBlock) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    if let Some(it) = &node.label {
+        v.visit_label(it)?;
+    };
+    v.visit_block(&node.block)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_box<'ast, V>(v: &mut V, node: &'ast ExprBox) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.box_token.span)?;
+    v.visit_expr(&*node.expr)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_break<'ast, V>(v: &mut V, node: &'ast ExprBreak) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.break_token.span)?;
+    if let Some(it) = &node.label {
+        v.visit_lifetime(it)?;
+    };
+    if let Some(it) = &node.expr {
+        v.visit_expr(&**it)?;
+    };
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr_call<'ast, V>(v: &mut V, node: &'ast ExprCall) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_expr(&*node.func)?;
+    tokens_helper(v, &node.paren_token.span)?;
+    for el in Punctuated::pairs(&node.args) {
+        let (it, p) = el.into_tuple();
+        v.visit_expr(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr_cast<'ast, V>(v: &mut V, node: &'ast ExprCast) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_expr(&*node.expr)?;
+    tokens_helper(v, &node.as_token.span)?;
+    v.visit_type(&*node.ty)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_closure<'ast, V>(v: &mut V, node: &'ast ExprClosure) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    if let Some(it) = &node.asyncness {
+        tokens_helper(v, &it.span)?;
+    };
+    if let Some(it) = &node.movability {
+        tokens_helper(v, &it.span)?;
+    };
+    if let Some(it) = &node.capture {
+        tokens_helper(v, &it.span)?;
+    };
+    tokens_helper(v, &node.or1_token.spans)?;
+    for el in Punctuated::pairs(&node.inputs) {
+        let (it, p) = el.into_tuple();
+        v.visit_pat(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    tokens_helper(v, &node.or2_token.spans)?;
+    v.visit_return_type(&node.output)?;
+    v.visit_expr(&*node.body)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_const<'ast, V>(v: &mut V, node: &'ast ExprConst) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.const_token.span)?;
+    v.visit_block(&node.block)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_continue<'ast, V>(v: &mut V, node: &'ast ExprContinue) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.continue_token.span)?;
+    if let Some(it) = &node.label {
+        v.visit_lifetime(it)?;
+    };
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr_field<'ast, V>(v: &mut V, node: &'ast ExprField) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_expr(&*node.base)?;
+    tokens_helper(v, &node.dot_token.spans)?;
+    v.visit_member(&node.member)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_for_loop<'ast, V>(v: &mut V, node: &'ast ExprForLoop) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    if let Some(it) = &node.label {
+        v.visit_label(it)?;
+    };
+    tokens_helper(v, &node.for_token.span)?;
+    v.visit_pat(&node.pat)?;
+    tokens_helper(v, &node.in_token.span)?;
+    v.visit_expr(&*node.expr)?;
+    v.visit_block(&node.body)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_group<'ast, V>(v: &mut V, node: &'ast ExprGroup) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.group_token.span)?;
+    v.visit_expr(&*node.expr)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_if<'ast, V>(v: &mut V, node: &'ast ExprIf) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.if_token.span)?;
+    v.visit_expr(&*node.cond)?;
+    v.visit_block(&node.then_branch)?;
+    if let Some(it) = &node.else_branch {
+        tokens_helper(v, &(it).0.span)?;
+        v.visit_expr(&*(it).1)?;
+    };
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr_index<'ast, V>(v: &mut V, node: &'ast ExprIndex) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_expr(&*node.expr)?;
+    tokens_helper(v, &node.bracket_token.span)?;
+    v.visit_expr(&*node.index)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_let<'ast, V>(v: &mut V, node: &'ast ExprLet) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.let_token.span)?;
+    v.visit_pat(&node.pat)?;
+    tokens_helper(v, &node.eq_token.spans)?;
+    v.visit_expr(&*node.expr)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr_lit<'ast, V>(v: &mut V, node: &'ast ExprLit) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_lit(&node.lit)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_loop<'ast, V>(v: &mut V, node: &'ast ExprLoop) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    if let Some(it) = &node.label {
+        v.visit_label(it)?;
+    };
+    tokens_helper(v, &node.loop_token.span)?;
+    v.visit_block(&node.body)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_macro<'ast, V>(v: &mut V, node: &'ast ExprMacro) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_macro(&node.mac)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_match<'ast, V>(v: &mut V, node: &'ast ExprMatch) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.match_token.span)?;
+    v.visit_expr(&*node.expr)?;
+    tokens_helper(v, &node.brace_token.span)?;
+    for it in &node.arms {
+        v.visit_arm(it)?;
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_method_call<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprMethodCall,
+) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_expr(&*node.receiver)?;
+    tokens_helper(v, &node.dot_token.spans)?;
+    v.visit_ident(&node.method)?;
+    if let Some(it) = &node.turbofish {
+        v.visit_method_turbofish(it)?;
+    };
+    tokens_helper(v, &node.paren_token.span)?;
+    for el in Punctuated::pairs(&node.args) {
+        let (it, p) = el.into_tuple();
+        v.visit_expr(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr_paren<'ast, V>(v: &mut V, node: &'ast ExprParen) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.paren_token.span)?;
+    v.visit_expr(&*node.expr)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr_path<'ast, V>(v: &mut V, node: &'ast ExprPath) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    if let Some(it) = &node.qself {
+        v.visit_qself(it)?;
+    };
+    v.visit_path(&node.path)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_range<'ast, V>(v: &mut V, node: &'ast ExprRange) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    if let Some(it) = &node.from {
+        v.visit_expr(&**it)?;
+    };
+    v.visit_range_limits(&node.limits)?;
+    if let Some(it) = &node.to {
+        v.visit_expr(&**it)?;
+    };
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_raw_addr<'ast, V>(v: &mut V, node: &'ast ExprRawAddr) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.and_token.spans)?;
+    tokens_helper(v, &node.raw.span)?;
+    v.visit_pointer_mutability(&node.mutability)?;
+    v.visit_expr(&*node.expr)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_reference<'ast, V>(v: &mut V, node: &'ast ExprReference) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.and_token.spans)?;
+    if let Some(it) = &node.mutability {
+        tokens_helper(v, &it.span)?;
+    };
+    v.visit_expr(&*node.expr)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_repeat<'ast, V>(v: &mut V, node: &'ast ExprRepeat) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.bracket_token.span)?;
+    v.visit_expr(&*node.expr)?;
+    tokens_helper(v, &node.semi_token.spans)?;
+    v.visit_expr(&*node.len)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_return<'ast, V>(v: &mut V, node: &'ast ExprReturn) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.return_token.span)?;
+    if let Some(it) = &node.expr {
+        v.visit_expr(&**it)?;
+    };
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_struct<'ast, V>(v: &mut V, node: &'ast ExprStruct) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_path(&node.path)?;
+    tokens_helper(v, &node.brace_token.span)?;
+    for el in Punctuated::pairs(&node.fields) {
+        let (it, p) = el.into_tuple();
+        v.visit_field_value(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    if let Some(it) = &node.dot2_token {
+        tokens_helper(v, &it.spans)?;
+    };
+    if let Some(it) = &node.rest {
+        v.visit_expr(&**it)?;
+    };
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_try<'ast, V>(v: &mut V, node: &'ast ExprTry) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_expr(&*node.expr)?;
+    tokens_helper(v, &node.question_token.spans)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_try_block<'ast, V>(v: &mut V, node: &'ast ExprTryBlock) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.try_token.span)?;
+    v.visit_block(&node.block)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_tuple<'ast, V>(v: &mut V, node: &'ast ExprTuple) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.paren_token.span)?;
+    for el in Punctuated::pairs(&node.elems) {
+        let (it, p) = el.into_tuple();
+        v.visit_expr(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_type<'ast, V>(v: &mut V, node: &'ast ExprType) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_expr(&*node.expr)?;
+    tokens_helper(v, &node.colon_token.spans)?;
+    v.visit_type(&*node.ty)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr_unary<'ast, V>(v: &mut V, node: &'ast ExprUnary) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_un_op(&node.op)?;
+    v.visit_expr(&*node.expr)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_unsafe<'ast, V>(v: &mut V, node: &'ast ExprUnsafe) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.unsafe_token.span)?;
+    v.visit_block(&node.block)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_while<'ast, V>(v: &mut V, node: &'ast ExprWhile) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    if let Some(it) = &node.label {
+        v.visit_label(it)?;
+    };
+    tokens_helper(v, &node.while_token.span)?;
+    v.visit_expr(&*node.cond)?;
+    v.visit_block(&node.body)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_yield<'ast, V>(v: &mut V, node: &'ast ExprYield) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.yield_token.span)?;
+    if let Some(it) = &node.expr {
+        v.visit_expr(&**it)?;
+    };
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_field<'ast, V>(v: &mut V, node: &'ast Field) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_visibility(&node.vis)?;
+    if let Some(it) = &node.ident {
+        v.visit_ident(it)?;
+    };
+    if let Some(it) = &node.colon_token {
+        tokens_helper(v, &it.spans)?;
+    };
+    v.visit_type(&node.ty)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_field_pat<'ast, V>(v: &mut V, node: &'ast FieldPat) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_member(&node.member)?;
+    if let Some(it) = &node.colon_token {
+        tokens_helper(v, &it.spans)?;
+    };
+    v.visit_pat(&*node.pat)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_field_value<'ast, V>(v: &mut V, node: &'ast FieldValue) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_member(&node.member)?;
+    if let Some(it) = &node.colon_token {
+        tokens_helper(v, &it.spans)?;
+    };
+    v.visit_expr(&node.expr)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_fields<'ast, V>(v: &mut V, node: &'ast Fields) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        Fields::Named(_binding_0) => {
+            v.visit_fields_named(_binding_0)?;
+        }
+        Fields::Unnamed(_binding_0) => {
+            v.visit_fields_unnamed(_binding_0)?;
+        }
+        Fields::Unit => {}
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_fields_named<'ast, V>(v: &mut V, node: &'ast FieldsNamed) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.brace_token.span)?;
+    for el in Punctuated::pairs(&node.named) {
+        let (it, p) = el.into_tuple();
+        v.visit_field(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_fields_unnamed<'ast, V>(v: &mut V, node: &'ast FieldsUnnamed) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.paren_token.span)?;
+    for el in Punctuated::pairs(&node.unnamed) {
+        let (it, p) = el.into_tuple();
+        v.visit_field(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_file<'ast, V>(v: &mut V, node: &'ast File) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    skip!(node.shebang);
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    for it in &node.items {
+        v.visit_item(it)?;
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_fn_arg<'ast, V>(v: &mut V, node: &'ast FnArg) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        FnArg::Receiver(_binding_0) => {
+            v.visit_receiver(_binding_0)?;
+        }
+        FnArg::Typed(_binding_0) => {
+            v.visit_pat_type(_binding_0)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_foreign_item<'ast, V>(v: &mut V, node: &'ast ForeignItem) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        ForeignItem::Fn(_binding_0) => {
+            v.visit_foreign_item_fn(_binding_0)?;
+        }
+        ForeignItem::Static(_binding_0) => {
+            v.visit_foreign_item_static(_binding_0)?;
+        }
+        ForeignItem::Type(_binding_0) => {
+            v.visit_foreign_item_type(_binding_0)?;
+        }
+        ForeignItem::Macro(_binding_0) => {
+            v.visit_foreign_item_macro(_binding_0)?;
+        }
+        ForeignItem::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        _ => unreachable!(),
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_foreign_item_fn<'ast, V>(v: &mut V, node: &'ast ForeignItemFn) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_visibility(&node.vis)?;
+    v.visit_signature(&node.sig)?;
+    tokens_helper(v, &node.semi_token.spans)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_foreign_item_macro<'ast, V>(
+    v: &mut V,
+    node: &'ast ForeignItemMacro,
+) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_macro(&node.mac)?;
+    if let Some(it) = &node.semi_token {
+        tokens_helper(v, &it.spans)?;
+    };
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_foreign_item_static<'ast, V>(
+    v: &mut V,
+    node: &'ast ForeignItemStatic,
+) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_visibility(&node.vis)?;
+    tokens_helper(v, &node.static_token.span)?;
+    if let Some(it) = &node.mutability {
+        tokens_helper(v, &it.span)?;
+    };
+    v.visit_ident(&node.ident)?;
+    tokens_helper(v, &node.colon_token.spans)?;
+    v.visit_type(&*node.ty)?;
+    tokens_helper(v, &node.semi_token.spans)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_foreign_item_type<'ast, V>(
+    v: &mut V,
+    node: &'ast ForeignItemType,
+) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_visibility(&node.vis)?;
+    tokens_helper(v, &node.type_token.span)?;
+    v.visit_ident(&node.ident)?;
+    tokens_helper(v, &node.semi_token.spans)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_generic_argument<'ast, V>(
+    v: &mut V,
+    node: &'ast GenericArgument,
+) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        GenericArgument::Lifetime(_binding_0) => {
+            v.visit_lifetime(_binding_0)?;
+        }
+        GenericArgument::Type(_binding_0) => {
+            v.visit_type(_binding_0)?;
+        }
+        GenericArgument::Binding(_binding_0) => {
+            v.visit_binding(_binding_0)?;
+        }
+        GenericArgument::Constraint(_binding_0) => {
+            v.visit_constraint(_binding_0)?;
+        }
+        GenericArgument::Const(_binding_0) => {
+            v.visit_expr(_binding_0)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_generic_method_argument<'ast, V>(
+    v: &mut V,
+    node: &'ast GenericMethodArgument,
+) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        GenericMethodArgument::Type(_binding_0) => {
+            v.visit_type(_binding_0)?;
+        }
+        GenericMethodArgument::Const(_binding_0) => {
+            v.visit_expr(_binding_0)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_generic_param<'ast, V>(v: &mut V, node: &'ast GenericParam) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        GenericParam::Type(_binding_0) => {
+            v.visit_type_param(_binding_0)?;
+        }
+        GenericParam::Lifetime(_binding_0) => {
+            v.visit_lifetime_def(_binding_0)?;
+        }
+        GenericParam::Const(_binding_0) => {
+            v.visit_const_param(_binding_0)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_generics<'ast, V>(v: &mut V, node: &'ast Generics) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    if let Some(it) = &node.lt_token {
+        tokens_helper(v, &it.spans)?;
+    };
+    for el in Punctuated::pairs(&node.params) {
+        let (it, p) = el.into_tuple();
+        v.visit_generic_param(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    if let Some(it) = &node.gt_token {
+        tokens_helper(v, &it.spans)?;
+    };
+    if let Some(it) = &node.where_clause {
+        v.visit_where_clause(it)?;
+    };
+    Ok(())
+}
+pub fn visit_ident<'ast, V>(v: &mut V, node: &'ast Ident) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    v.visit_span(&node.span())?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_impl_item<'ast, V>(v: &mut V, node: &'ast ImplItem) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        ImplItem::Const(_binding_0) => {
+            v.visit_impl_item_const(_binding_0)?;
+        }
+        ImplItem::Method(_binding_0) => {
+            v.visit_impl_item_method(_binding_0)?;
+        }
+        ImplItem::Type(_binding_0) => {
+            v.visit_impl_item_type(_binding_0)?;
+        }
+        ImplItem::Macro(_binding_0) => {
+            v.visit_impl_item_macro(_binding_0)?;
+        }
+        ImplItem::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        _ => unreachable!(),
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_impl_item_const<'ast, V>(v: &mut V, node: &'ast ImplItemConst) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_visibility(&node.vis)?;
+    if let Some(it) = &node.defaultness {
+        tokens_helper(v, &it.span)?;
+    };
+    tokens_helper(v, &node.const_token.span)?;
+    v.visit_ident(&node.ident)?;
+    tokens_helper(v, &node.colon_token.spans)?;
+    v.visit_type(&node.ty)?;
+    tokens_helper(v, &node.eq_token.spans)?;
+    v.visit_expr(&node.expr)?;
+    tokens_helper(v, &node.semi_token.spans)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_impl_item_macro<'ast, V>(v: &mut V, node: &'ast ImplItemMacro) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_macro(&node.mac)?;
+    if let Some(it) = &node.semi_token {
+        tokens_helper(v, &it.spans)?;
+    };
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_impl_item_method<'ast, V>(
+    v: &mut V,
+    node: &'ast ImplItemMethod,
+) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_visibility(&node.vis)?;
+    if let Some(it) = &node.defaultness {
+        tokens_helper(v, &it.span)?;
+    };
+    v.visit_signature(&node.sig)?;
+    v.visit_block(&node.block)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_impl_item_type<'ast, V>(v: &mut V, node: &'ast ImplItemType) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_visibility(&node.vis)?;
+    if let Some(it) = &node.defaultness {
+        tokens_helper(v, &it.span)?;
+    };
+    tokens_helper(v, &node.type_token.span)?;
+    v.visit_ident(&node.ident)?;
+    v.visit_generics(&node.generics)?;
+    tokens_helper(v, &node.eq_token.spans)?;
+    v.visit_type(&node.ty)?;
+    tokens_helper(v, &node.semi_token.spans)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_index<'ast, V>(v: &mut V, node: &'ast Index) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    skip!(node.index);
+    v.visit_span(&node.span)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_item<'ast, V>(v: &mut V, node: &'ast Item) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        Item::Const(_binding_0) => {
+            v.visit_item_const(_binding_0)?;
+        }
+        Item::Enum(_binding_0) => {
+            v.visit_item_enum(_binding_0)?;
+        }
+        Item::ExternCrate(_binding_0) => {
+            v.visit_item_extern_crate(_binding_0)?;
+        }
+        Item::Fn(_binding_0) => {
+            v.visit_item_fn(_binding_0)?;
+        }
+        Item::FnDecl(_binding_0) => {
+            v.visit_item_fn_decl(_binding_0)?;
+        }
+        Item::ForeignMod(_binding_0) => {
+            v.visit_item_foreign_mod(_binding_0)?;
+        }
+        Item::Impl(_binding_0) => {
+            v.visit_item_impl(_binding_0)?;
+        }
+        Item::Macro(_binding_0) => {
+            v.visit_item_macro(_binding_0)?;
+        }
+        Item::Macro2(_binding_0) => {
+            v.visit_item_macro2(_binding_0)?;
+        }
+        Item::Mod(_binding_0) => {
+            v.visit_item_mod(_binding_0)?;
+        }
+        Item::Static(_binding_0) => {
+            v.visit_item_static(_binding_0)?;
+        }
+        Item::Struct(_binding_0) => {
+            v.visit_item_struct(_binding_0)?;
+        }
+        Item::Trait(_binding_0) => {
+            v.visit_item_trait(_binding_0)?;
+        }
+        Item::TraitAlias(_binding_0) => {
+            v.visit_item_trait_alias(_binding_0)?;
+        }
+        Item::Type(_binding_0) => {
+            v.visit_item_type(_binding_0)?;
+        }
+        Item::Union(_binding_0) => {
+            v.visit_item_union(_binding_0)?;
+        }
+        Item::Use(_binding_0) => {
+            v.visit_item_use(_binding_0)?;
+        }
+        Item::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        _ => unreachable!(),
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_item_const<'ast, V>(v: &mut V, node: &'ast ItemConst) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_visibility(&node.vis)?;
+    tokens_helper(v, &node.const_token.span)?;
+    v.visit_ident(&node.ident)?;
+    tokens_helper(v, &node.colon_token.spans)?;
+    v.visit_type(&*node.ty)?;
+    if let Some(it) = &node.expr {
+        tokens_helper(v, &(it).0.spans)?;
+        v.visit_expr(&*(it).1)?;
+    };
+    tokens_helper(v, &node.semi_token.spans)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_item_enum<'ast, V>(v: &mut V, node: &'ast ItemEnum) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_visibility(&node.vis)?;
+    tokens_helper(v, &node.enum_token.span)?;
+    v.visit_ident(&node.ident)?;
+    v.visit_generics(&node.generics)?;
+    tokens_helper(v, &node.brace_token.span)?;
+    for el in Punctuated::pairs(&node.variants) {
+        let (it, p) = el.into_tuple();
+        v.visit_variant(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_item_extern_crate<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemExternCrate,
+) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_visibility(&node.vis)?;
+    tokens_helper(v, &node.extern_token.span)?;
+    tokens_helper(v, &node.crate_token.span)?;
+    v.visit_ident(&node.ident)?;
+    if let Some(it) = &node.rename {
+        tokens_helper(v, &(it).0.span)?;
+        v.visit_ident(&(it).1)?;
+    };
+    tokens_helper(v, &node.semi_token.spans)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_item_fn<'ast, V>(v: &mut V, node: &'ast ItemFn) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_visibility(&node.vis)?;
+    v.visit_signature(&node.sig)?;
+    v.visit_block(&*node.block)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_item_fn_decl<'ast, V>(v: &mut V, node: &'ast ItemFnDecl) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_visibility(&node.vis)?;
+    v.visit_signature(&node.sig)?;
+    tokens_helper(v, &node.semi_token.spans)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_item_foreign_mod<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemForeignMod,
+) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    if let Some(it) = &node.unsafety {
+        tokens_helper(v, &it.span)?;
+    };
+    v.visit_abi(&node.abi)?;
+    tokens_helper(v, &node.brace_token.span)?;
+    for it in &node.items {
+        v.visit_foreign_item(it)?;
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_item_impl<'ast, V>(v: &mut V, node: &'ast ItemImpl) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    if let Some(it) = &node.defaultness {
+        tokens_helper(v, &it.span)?;
+    };
+    if let Some(it) = &node.unsafety {
+        tokens_helper(v, &it.span)?;
+    };
+    tokens_helper(v, &node.impl_token.span)?;
+    v.visit_generics(&node.generics)?;
+    if let Some(it) = &node.trait_ {
+        if let Some(it) = &(it).0 {
+            tokens_helper(v, &it.spans)?;
+        };
+        v.visit_path(&(it).1)?;
+        tokens_helper(v, &(it).2.span)?;
+    };
+    v.visit_type(&*node.self_ty)?;
+    tokens_helper(v, &node.brace_token.span)?;
+    for it in &node.items {
+        v.visit_impl_item(it)?;
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_item_macro<'ast, V>(v: &mut V, node: &'ast ItemMacro) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    if let Some(it) = &node.ident {
+        v.visit_ident(it)?;
+    };
+    v.visit_macro(&node.mac)?;
+    if let Some(it) = &node.semi_token {
+        tokens_helper(v, &it.spans)?;
+    };
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_item_macro2<'ast, V>(v: &mut V, node: &'ast ItemMacro2) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_visibility(&node.vis)?;
+    tokens_helper(v, &node.macro_token.span)?;
+    v.visit_ident(&node.ident)?;
+    skip!(node.rules);
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_item_mod<'ast, V>(v: &mut V, node: &'ast ItemMod) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_visibility(&node.vis)?;
+    if let Some(it) = &node.unsafety {
+        tokens_helper(v, &it.span)?;
+    };
+    tokens_helper(v, &node.mod_token.span)?;
+    v.visit_ident(&node.ident)?;
+    if let Some(it) = &node.content {
+        tokens_helper(v, &(it).0.span)?;
+        for it in &(it).1 {
+            v.visit_item(it)?;
+        }
+    };
+    if let Some(it) = &node.semi {
+        tokens_helper(v, &it.spans)?;
+    };
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_item_static<'ast, V>(v: &mut V, node: &'ast ItemStatic) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_visibility(&node.vis)?;
+    tokens_helper(v, &node.static_token.span)?;
+    if let Some(it) = &node.mutability {
+        tokens_helper(v, &it.span)?;
+    };
+    v.visit_ident(&node.ident)?;
+    if let Some(it) = &node.ty {
+        tokens_helper(v, &(it).0.spans)?;
+        v.visit_type(&*(it).1)?;
+    };
+    if let Some(it) = &node.expr {
+        tokens_helper(v, &(it).0.spans)?;
+        v.visit_expr(&*(it).1)?;
+    };
+    tokens_helper(v, &node.semi_token.spans)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_item_struct<'ast, V>(v: &mut V, node: &'ast ItemStruct) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_visibility(&node.vis)?;
+    tokens_helper(v, &node.struct_token.span)?;
+    v.visit_ident(&node.ident)?;
+    v.visit_generics(&node.generics)?;
+    v.visit_fields(&node.fields)?;
+    if let Some(it) = &node.semi_token {
+        tokens_helper(v, &it.spans)?;
+    };
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_item_trait<'ast, V>(v: &mut V, node: &'ast ItemTrait) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_visibility(&node.vis)?;
+    if let Some(it) = &node.unsafety {
+        tokens_helper(v, &it.span)?;
+    };
+    if let Some(it) = &node.auto_token {
+        tokens_helper(v, &it.span)?;
+    };
+    tokens_helper(v, &node.trait_token.span)?;
+    v.visit_ident(&node.ident)?;
+    v.visit_generics(&node.generics)?;
+    if let Some(it) = &node.colon_token {
+        tokens_helper(v, &it.spans)?;
+    };
+    for el in Punctuated::pairs(&node.supertraits) {
+        let (it, p) = el.into_tuple();
+        v.visit_type_param_bound(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    tokens_helper(v, &node.brace_token.span)?;
+    for it in &node.items {
+        v.visit_trait_item(it)?;
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_item_trait_alias<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemTraitAlias,
+) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_visibility(&node.vis)?;
+    tokens_helper(v, &node.trait_token.span)?;
+    v.visit_ident(&node.ident)?;
+    v.visit_generics(&node.generics)?;
+    tokens_helper(v, &node.eq_token.spans)?;
+    for el in Punctuated::pairs(&node.bounds) {
+        let (it, p) = el.into_tuple();
+        v.visit_type_param_bound(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    tokens_helper(v, &node.semi_token.spans)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_item_type<'ast, V>(v: &mut V, node: &'ast ItemType) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_visibility(&node.vis)?;
+    tokens_helper(v, &node.type_token.span)?;
+    v.visit_ident(&node.ident)?;
+    v.visit_generics(&node.generics)?;
+    tokens_helper(v, &node.eq_token.spans)?;
+    v.visit_type(&*node.ty)?;
+    tokens_helper(v, &node.semi_token.spans)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_item_union<'ast, V>(v: &mut V, node: &'ast ItemUnion) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_visibility(&node.vis)?;
+    tokens_helper(v, &node.union_token.span)?;
+    v.visit_ident(&node.ident)?;
+    v.visit_generics(&node.generics)?;
+    v.visit_fields_named(&node.fields)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_item_use<'ast, V>(v: &mut V, node: &'ast ItemUse) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_visibility(&node.vis)?;
+    tokens_helper(v, &node.use_token.span)?;
+    if let Some(it) = &node.leading_colon {
+        tokens_helper(v, &it.spans)?;
+    };
+    v.visit_use_tree(&node.tree)?;
+    tokens_helper(v, &node.semi_token.spans)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_label<'ast, V>(v: &mut V, node: &'ast Label) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    v.visit_lifetime(&node.name)?;
+    tokens_helper(v, &node.colon_token.spans)?;
+    Ok(())
+}
+pub fn visit_lifetime<'ast, V>(v: &mut V, node: &'ast Lifetime) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    v.visit_span(&node.apostrophe)?;
+    v.visit_ident(&node.ident)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_lifetime_def<'ast, V>(v: &mut V, node: &'ast LifetimeDef) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_lifetime(&node.lifetime)?;
+    if let Some(it) = &node.colon_token {
+        tokens_helper(v, &it.spans)?;
+    };
+    for el in Punctuated::pairs(&node.bounds) {
+        let (it, p) = el.into_tuple();
+        v.visit_lifetime(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    Ok(())
+}
+pub fn visit_lit<'ast, V>(v: &mut V, node: &'ast Lit) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        Lit::Str(_binding_0) => {
+            v.visit_lit_str(_binding_0)?;
+        }
+        Lit::ByteStr(_binding_0) => {
+            v.visit_lit_byte_str(_binding_0)?;
+        }
+        Lit::Byte(_binding_0) => {
+            v.visit_lit_byte(_binding_0)?;
+        }
+        Lit::Char(_binding_0) => {
+            v.visit_lit_char(_binding_0)?;
+        }
+        Lit::Int(_binding_0) => {
+            v.visit_lit_int(_binding_0)?;
+        }
+        Lit::Float(_binding_0) => {
+            v.visit_lit_float(_binding_0)?;
+        }
+        Lit::Bool(_binding_0) => {
+            v.visit_lit_bool(_binding_0)?;
+        }
+        Lit::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+    }
+    Ok(())
+}
+pub fn visit_lit_bool<'ast, V>(v: &mut V, node: &'ast LitBool) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    skip!(node.value);
+    v.visit_span(&node.span)?;
+    Ok(())
+}
+pub fn visit_lit_byte<'ast, V>(v: &mut V, node: &'ast LitByte) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    Ok(())
+}
+pub fn visit_lit_byte_str<'ast, V>(v: &mut V, node: &'ast LitByteStr) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    Ok(())
+}
+pub fn visit_lit_char<'ast, V>(v: &mut V, node: &'ast LitChar) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    Ok(())
+}
+pub fn visit_lit_float<'ast, V>(v: &mut V, node: &'ast LitFloat) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    Ok(())
+}
+pub fn visit_lit_int<'ast, V>(v: &mut V, node: &'ast LitInt) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    Ok(())
+}
+pub fn visit_lit_str<'ast, V>(v: &mut V, node: &'ast LitStr) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_local<'ast, V>(v: &mut V, node: &'ast Local) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.let_token.span)?;
+    v.visit_pat(&node.pat)?;
+    if let Some(it) = &node.init {
+        v.visit_local_init(it)?;
+    };
+    tokens_helper(v, &node.semi_token.spans)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_local_init<'ast, V>(v: &mut V, node: &'ast LocalInit) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.eq_token.spans)?;
+    v.visit_expr(&*node.expr)?;
+    if let Some(it) = &node.diverge {
+        tokens_helper(v, &(it).0.span)?;
+        v.visit_block(&(it).1)?;
+    };
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_macro<'ast, V>(v: &mut V, node: &'ast Macro) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    v.visit_path(&node.path)?;
+    tokens_helper(v, &node.bang_token.spans)?;
+    v.visit_macro_delimiter(&node.delimiter)?;
+    skip!(node.tokens);
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_macro_delimiter<'ast, V>(v: &mut V, node: &'ast MacroDelimiter) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        MacroDelimiter::Paren(_binding_0) => {
+            tokens_helper(v, &_binding_0.span)?;
+        }
+        MacroDelimiter::Brace(_binding_0) => {
+            tokens_helper(v, &_binding_0.span)?;
+        }
+        MacroDelimiter::Bracket(_binding_0) => {
+            tokens_helper(v, &_binding_0.span)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_macro_group<'ast, V>(v: &mut V, node: &'ast MacroGroup) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    v.visit_macro_delimiter(&node.delimiter)?;
+    for it in &node.content {
+        v.visit_macro_tree(it)?;
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_macro_metavar<'ast, V>(v: &mut V, node: &'ast MacroMetavar) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.dollar_token.spans)?;
+    v.visit_ident(&node.name)?;
+    if let Some(it) = &node.specifier {
+        tokens_helper(v, &(it).0.spans)?;
+        v.visit_ident(&(it).1)?;
+    };
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_macro_rep_op<'ast, V>(v: &mut V, node: &'ast MacroRepOp) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        MacroRepOp::ZeroOrMore(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        MacroRepOp::OneOrMore(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        MacroRepOp::ZeroOrOne(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_macro_repetition<'ast, V>(
+    v: &mut V,
+    node: &'ast MacroRepetition,
+) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.dollar_token.spans)?;
+    tokens_helper(v, &node.paren_token.span)?;
+    for it in &node.content {
+        v.visit_macro_tree(it)?;
+    }
+    skip!(node.separator);
+    v.visit_macro_rep_op(&node.op)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_macro_rule<'ast, V>(v: &mut V, node: &'ast MacroRule) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    v.visit_macro_group(&node.matcher)?;
+    tokens_helper(v, &node.fat_arrow_token.spans)?;
+    v.visit_macro_group(&node.transcriber)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_macro_rules<'ast, V>(v: &mut V, node: &'ast MacroRules) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for el in Punctuated::pairs(&node.rules) {
+        let (it, p) = el.into_tuple();
+        v.visit_macro_rule(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_macro_tree<'ast, V>(v: &mut V, node: &'ast MacroTree) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        MacroTree::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        MacroTree::Group(_binding_0) => {
+            v.visit_macro_group(_binding_0)?;
+        }
+        MacroTree::Metavar(_binding_0) => {
+            v.visit_macro_metavar(_binding_0)?;
+        }
+        MacroTree::Repetition(_binding_0) => {
+            v.visit_macro_repetition(_binding_0)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_member<'ast, V>(v: &mut V, node: &'ast Member) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        Member::Named(_binding_0) => {
+            v.visit_ident(_binding_0)?;
+        }
+        Member::Unnamed(_binding_0) => {
+            v.visit_index(_binding_0)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_meta<'ast, V>(v: &mut V, node: &'ast Meta) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        Meta::Path(_binding_0) => {
+            v.visit_path(_binding_0)?;
+        }
+        Meta::List(_binding_0) => {
+            v.visit_meta_list(_binding_0)?;
+        }
+        Meta::NameValue(_binding_0) => {
+            v.visit_meta_name_value(_binding_0)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_meta_list<'ast, V>(v: &mut V, node: &'ast MetaList) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    v.visit_path(&node.path)?;
+    tokens_helper(v, &node.paren_token.span)?;
+    for el in Punctuated::pairs(&node.nested) {
+        let (it, p) = el.into_tuple();
+        v.visit_nested_meta(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_meta_name_value<'ast, V>(v: &mut V, node: &'ast MetaNameValue) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    v.visit_path(&node.path)?;
+    tokens_helper(v, &node.eq_token.spans)?;
+    v.visit_lit(&node.lit)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_method_turbofish<'ast, V>(
+    v: &mut V,
+    node: &'ast MethodTurbofish,
+) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.colon2_token.spans)?;
+    tokens_helper(v, &node.lt_token.spans)?;
+    for el in Punctuated::pairs(&node.args) {
+        let (it, p) = el.into_tuple();
+        v.visit_generic_method_argument(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    tokens_helper(v, &node.gt_token.spans)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_nested_meta<'ast, V>(v: &mut V, node: &'ast NestedMeta) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        NestedMeta::Meta(_binding_0) => {
+            v.visit_meta(_binding_0)?;
+        }
+        NestedMeta::Lit(_binding_0) => {
+            v.visit_lit(_binding_0)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_parenthesized_generic_arguments<'ast, V>(
+    v: &mut V,
+    node: &'ast ParenthesizedGenericArguments,
+) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.paren_token.span)?;
+    for el in Punctuated::pairs(&node.inputs) {
+        let (it, p) = el.into_tuple();
+        v.visit_type(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    v.visit_return_type(&node.output)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_pat<'ast, V>(v: &mut V, node: &'ast Pat) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        Pat::Box(_binding_0) => {
+            v.visit_pat_box(_binding_0)?;
+        }
+        Pat::Const(_binding_0) => {
+            v.visit_pat_const(_binding_0)?;
+        }
+        Pat::Ident(_binding_0) => {
+            v.visit_pat_ident(_binding_0)?;
+        }
+        Pat::Lit(_binding_0) => {
+            v.visit_pat_lit(_binding_0)?;
+        }
+        Pat::Macro(_binding_0) => {
+            v.visit_pat_macro(_binding_0)?;
+        }
+        Pat::Or(_binding_0) => {
+            v.visit_pat_or(_binding_0)?;
+        }
+        Pat::Path(_binding_0) => {
+            v.visit_pat_path(_binding_0)?;
+        }
+        Pat::Range(_binding_0) => {
+            v.visit_pat_range(_binding_0)?;
+        }
+        Pat::Reference(_binding_0) => {
+            v.visit_pat_reference(_binding_0)?;
+        }
+        Pat::Rest(_binding_0) => {
+            v.visit_pat_rest(_binding_0)?;
+        }
+        Pat::Slice(_binding_0) => {
+            v.visit_pat_slice(_binding_0)?;
+        }
+        Pat::Struct(_binding_0) => {
+            v.visit_pat_struct(_binding_0)?;
+        }
+        Pat::Tuple(_binding_0) => {
+            v.visit_pat_tuple(_binding_0)?;
+        }
+        Pat::TupleStruct(_binding_0) => {
+            v.visit_pat_tuple_struct(_binding_0)?;
+        }
+        Pat::Type(_binding_0) => {
+            v.visit_pat_type(_binding_0)?;
+        }
+        Pat::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        Pat::Wild(_binding_0) => {
+            v.visit_pat_wild(_binding_0)?;
+        }
+        _ => unreachable!(),
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_box<'ast, V>(v: &mut V, node: &'ast PatBox) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.box_token.span)?;
+    v.visit_pat(&*node.pat)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_const<'ast, V>(v: &mut V, node: &'ast PatConst) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.const_token.span)?;
+    v.visit_block(&node.block)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_ident<'ast, V>(v: &mut V, node: &'ast PatIdent) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    if let Some(it) = &node.by_ref {
+        tokens_helper(v, &it.span)?;
+    };
+    if let Some(it) = &node.mutability {
+        tokens_helper(v, &it.span)?;
+    };
+    v.visit_ident(&node.ident)?;
+    if let Some(it) = &node.subpat {
+        tokens_helper(v, &(it).0.spans)?;
+        v.visit_pat(&*(it).1)?;
+    };
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_lit<'ast, V>(v: &mut V, node: &'ast PatLit) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_expr(&*node.expr)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_macro<'ast, V>(v: &mut V, node: &'ast PatMacro) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_macro(&node.mac)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_or<'ast, V>(v: &mut V, node: &'ast PatOr) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    if let Some(it) = &node.leading_vert {
+        tokens_helper(v, &it.spans)?;
+    };
+    for el in Punctuated::pairs(&node.cases) {
+        let (it, p) = el.into_tuple();
+        v.visit_pat(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_path<'ast, V>(v: &mut V, node: &'ast PatPath) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    if let Some(it) = &node.qself {
+        v.visit_qself(it)?;
+    };
+    v.visit_path(&node.path)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_range<'ast, V>(v: &mut V, node: &'ast PatRange) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_expr(&*node.lo)?;
+    v.visit_range_limits(&node.limits)?;
+    v.visit_expr(&*node.hi)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_reference<'ast, V>(v: &mut V, node: &'ast PatReference) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.and_token.spans)?;
+    if let Some(it) = &node.mutability {
+        tokens_helper(v, &it.span)?;
+    };
+    v.visit_pat(&*node.pat)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_rest<'ast, V>(v: &mut V, node: &'ast PatRest) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.dot2_token.spans)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_slice<'ast, V>(v: &mut V, node: &'ast PatSlice) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.bracket_token.span)?;
+    for el in Punctuated::pairs(&node.elems) {
+        let (it, p) = el.into_tuple();
+        v.visit_pat(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_struct<'ast, V>(v: &mut V, node: &'ast PatStruct) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_path(&node.path)?;
+    tokens_helper(v, &node.brace_token.span)?;
+    for el in Punctuated::pairs(&node.fields) {
+        let (it, p) = el.into_tuple();
+        v.visit_field_pat(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    if let Some(it) = &node.dot2_token {
+        tokens_helper(v, &it.spans)?;
+    };
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_tuple<'ast, V>(v: &mut V, node: &'ast PatTuple) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.paren_token.span)?;
+    for el in Punctuated::pairs(&node.elems) {
+        let (it, p) = el.into_tuple();
+        v.visit_pat(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_tuple_struct<'ast, V>(
+    v: &mut V,
+    node: &'ast PatTupleStruct,
+) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_path(&node.path)?;
+    v.visit_pat_tuple(&node.pat)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_type<'ast, V>(v: &mut V, node: &'ast PatType) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_pat(&*node.pat)?;
+    tokens_helper(v, &node.colon_token.spans)?;
+    v.visit_type(&*node.ty)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_wild<'ast, V>(v: &mut V, node: &'ast PatWild) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.underscore_token.spans)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_path<'ast, V>(v: &mut V, node: &'ast Path) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    if let Some(it) = &node.leading_colon {
+        tokens_helper(v, &it.spans)?;
+    };
+    for el in Punctuated::pairs(&node.segments) {
+        let (it, p) = el.into_tuple();
+        v.visit_path_segment(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_path_arguments<'ast, V>(v: &mut V, node: &'ast PathArguments) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        PathArguments::None => {}
+        PathArguments::AngleBracketed(_binding_0) => {
+            v.visit_angle_bracketed_generic_arguments(_binding_0)?;
+        }
+        PathArguments::Parenthesized(_binding_0) => {
+            v.visit_parenthesized_generic_arguments(_binding_0)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_path_segment<'ast, V>(v: &mut V, node: &'ast PathSegment) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    v.visit_ident(&node.ident)?;
+    v.visit_path_arguments(&node.arguments)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_pointer_mutability<'ast, V>(
+    v: &mut V,
+    node: &'ast PointerMutability,
+) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        PointerMutability::Const(_binding_0) => {
+            tokens_helper(v, &_binding_0.span)?;
+        }
+        PointerMutability::Mut(_binding_0) => {
+            tokens_helper(v, &_binding_0.span)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_predicate_eq<'ast, V>(v: &mut V, node: &'ast PredicateEq) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    v.visit_type(&node.lhs_ty)?;
+    tokens_helper(v, &node.eq_token.spans)?;
+    v.visit_type(&node.rhs_ty)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_predicate_lifetime<'ast, V>(
+    v: &mut V,
+    node: &'ast PredicateLifetime,
+) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    v.visit_lifetime(&node.lifetime)?;
+    tokens_helper(v, &node.colon_token.spans)?;
+    for el in Punctuated::pairs(&node.bounds) {
+        let (it, p) = el.into_tuple();
+        v.visit_lifetime(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_predicate_type<'ast, V>(v: &mut V, node: &'ast PredicateType) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    if let Some(it) = &node.lifetimes {
+        v.visit_bound_lifetimes(it)?;
+    };
+    v.visit_type(&node.bounded_ty)?;
+    tokens_helper(v, &node.colon_token.spans)?;
+    for el in Punctuated::pairs(&node.bounds) {
+        let (it, p) = el.into_tuple();
+        v.visit_type_param_bound(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_qself<'ast, V>(v: &mut V, node: &'ast QSelf) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.lt_token.spans)?;
+    v.visit_type(&*node.ty)?;
+    skip!(node.position);
+    if let Some(it) = &node.as_token {
+        tokens_helper(v, &it.span)?;
+    };
+    tokens_helper(v, &node.gt_token.spans)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_range_limits<'ast, V>(v: &mut V, node: &'ast RangeLimits) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        RangeLimits::HalfOpen(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        RangeLimits::Closed(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_receiver<'ast, V>(v: &mut V, node: &'ast Receiver) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    if let Some(it) = &node.reference {
+        tokens_helper(v, &(it).0.spans)?;
+        if let Some(it) = &(it).1 {
+            v.visit_lifetime(it)?;
+        };
+    };
+    if let Some(it) = &node.mutability {
+        tokens_helper(v, &it.span)?;
+    };
+    tokens_helper(v, &node.self_token.span)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_return_type<'ast, V>(v: &mut V, node: &'ast ReturnType) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        ReturnType::Default => {}
+        ReturnType::Type(_binding_0, _binding_1) => {
+            tokens_helper(v, &_binding_0.spans)?;
+            v.visit_type(&**_binding_1)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_signature<'ast, V>(v: &mut V, node: &'ast Signature) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    if let Some(it) = &node.constness {
+        tokens_helper(v, &it.span)?;
+    };
+    if let Some(it) = &node.asyncness {
+        tokens_helper(v, &it.span)?;
+    };
+    if let Some(it) = &node.unsafety {
+        tokens_helper(v, &it.span)?;
+    };
+    if let Some(it) = &node.abi {
+        v.visit_abi(it)?;
+    };
+    tokens_helper(v, &node.fn_token.span)?;
+    v.visit_ident(&node.ident)?;
+    v.visit_generics(&node.generics)?;
+    tokens_helper(v, &node.paren_token.span)?;
+    for el in Punctuated::pairs(&node.inputs) {
+        let (it, p) = el.into_tuple();
+        v.visit_fn_arg(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    if let Some(it) = &node.variadic {
+        v.visit_variadic(it)?;
+    };
+    v.visit_return_type(&node.output)?;
+    Ok(())
+}
+pub fn visit_span<'ast, V>(v: &mut V, node: &Span) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_stmt<'ast, V>(v: &mut V, node: &'ast Stmt) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        Stmt::Local(_binding_0) => {
+            v.visit_local(_binding_0)?;
+        }
+        Stmt::Item(_binding_0) => {
+            v.visit_item(_binding_0)?;
+        }
+        Stmt::Expr(_binding_0) => {
+            v.visit_expr(_binding_0)?;
+        }
+        Stmt::Semi(_binding_0, _binding_1) => {
+            v.visit_expr(_binding_0)?;
+            tokens_helper(v, &_binding_1.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_trait_bound<'ast, V>(v: &mut V, node: &'ast TraitBound) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    if let Some(it) = &node.paren_token {
+        tokens_helper(v, &it.span)?;
+    };
+    v.visit_trait_bound_modifier(&node.modifier)?;
+    if let Some(it) = &node.lifetimes {
+        v.visit_bound_lifetimes(it)?;
+    };
+    v.visit_path(&node.path)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_trait_bound_modifier<'ast, V>(
+    v: &mut V,
+    node: &'ast TraitBoundModifier,
+) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        TraitBoundModifier::None => {}
+        TraitBoundModifier::Maybe(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_trait_item<'ast, V>(v: &mut V, node: &'ast TraitItem) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        TraitItem::Const(_binding_0) => {
+            v.visit_trait_item_const(_binding_0)?;
+        }
+        TraitItem::Method(_binding_0) => {
+            v.visit_trait_item_method(_binding_0)?;
+        }
+        TraitItem::Type(_binding_0) => {
+            v.visit_trait_item_type(_binding_0)?;
+        }
+        TraitItem::Macro(_binding_0) => {
+            v.visit_trait_item_macro(_binding_0)?;
+        }
+        TraitItem::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        _ => unreachable!(),
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_trait_item_const<'ast, V>(
+    v: &mut V,
+    node: &'ast TraitItemConst,
+) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.const_token.span)?;
+    v.visit_ident(&node.ident)?;
+    tokens_helper(v, &node.colon_token.spans)?;
+    v.visit_type(&node.ty)?;
+    if let Some(it) = &node.default {
+        tokens_helper(v, &(it).0.spans)?;
+        v.visit_expr(&(it).1)?;
+    };
+    tokens_helper(v, &node.semi_token.spans)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_trait_item_macro<'ast, V>(
+    v: &mut V,
+    node: &'ast TraitItemMacro,
+) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_macro(&node.mac)?;
+    if let Some(it) = &node.semi_token {
+        tokens_helper(v, &it.spans)?;
+    };
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_trait_item_method<'ast, V>(
+    v: &mut V,
+    node: &'ast TraitItemMethod,
+) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_signature(&node.sig)?;
+    if let Some(it) = &node.default {
+        v.visit_block(it)?;
+    };
+    if let Some(it) = &node.semi_token {
+        tokens_helper(v, &it.spans)?;
+    };
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_trait_item_type<'ast, V>(v: &mut V, node: &'ast TraitItemType) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.type_token.span)?;
+    v.visit_ident(&node.ident)?;
+    v.visit_generics(&node.generics)?;
+    if let Some(it) = &node.colon_token {
+        tokens_helper(v, &it.spans)?;
+    };
+    for el in Punctuated::pairs(&node.bounds) {
+        let (it, p) = el.into_tuple();
+        v.visit_type_param_bound(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    if let Some(it) = &node.default {
+        tokens_helper(v, &(it).0.spans)?;
+        v.visit_type(&(it).1)?;
+    };
+    tokens_helper(v, &node.semi_token.spans)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type<'ast, V>(v: &mut V, node: &'ast Type) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        Type::Array(_binding_0) => {
+            v.visit_type_array(_binding_0)?;
+        }
+        Type::BareFn(_binding_0) => {
+            v.visit_type_bare_fn(_binding_0)?;
+        }
+        Type::Group(_binding_0) => {
+            v.visit_type_group(_binding_0)?;
+        }
+        Type::ImplTrait(_binding_0) => {
+            v.visit_type_impl_trait(_binding_0)?;
+        }
+        Type::Infer(_binding_0) => {
+            v.visit_type_infer(_binding_0)?;
+        }
+        Type::Macro(_binding_0) => {
+            v.visit_type_macro(_binding_0)?;
+        }
+        Type::Never(_binding_0) => {
+            v.visit_type_never(_binding_0)?;
+        }
+        Type::Paren(_binding_0) => {
+            v.visit_type_paren(_binding_0)?;
+        }
+        Type::Path(_binding_0) => {
+            v.visit_type_path(_binding_0)?;
+        }
+        Type::Ptr(_binding_0) => {
+            v.visit_type_ptr(_binding_0)?;
+        }
+        Type::Reference(_binding_0) => {
+            v.visit_type_reference(_binding_0)?;
+        }
+        Type::Slice(_binding_0) => {
+            v.visit_type_slice(_binding_0)?;
+        }
+        Type::TraitObject(_binding_0) => {
+            v.visit_type_trait_object(_binding_0)?;
+        }
+        Type::Tuple(_binding_0) => {
+            v.visit_type_tuple(_binding_0)?;
+        }
+        Type::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        _ => unreachable!(),
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_array<'ast, V>(v: &mut V, node: &'ast TypeArray) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.bracket_token.span)?;
+    v.visit_type(&*node.elem)?;
+    tokens_helper(v, &node.semi_token.spans)?;
+    v.visit_expr(&node.len)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_bare_fn<'ast, V>(v: &mut V, node: &'ast TypeBareFn) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    if let Some(it) = &node.lifetimes {
+        v.visit_bound_lifetimes(it)?;
+    };
+    if let Some(it) = &node.unsafety {
+        tokens_helper(v, &it.span)?;
+    };
+    if let Some(it) = &node.abi {
+        v.visit_abi(it)?;
+    };
+    tokens_helper(v, &node.fn_token.span)?;
+    tokens_helper(v, &node.paren_token.span)?;
+    for el in Punctuated::pairs(&node.inputs) {
+        let (it, p) = el.into_tuple();
+        v.visit_bare_fn_arg(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    if let Some(it) = &node.variadic {
+        v.visit_variadic(it)?;
+    };
+    v.visit_return_type(&node.output)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_group<'ast, V>(v: &mut V, node: &'ast TypeGroup) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.group_token.span)?;
+    v.visit_type(&*node.elem)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_impl_trait<'ast, V>(v: &mut V, node: &'ast TypeImplTrait) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.impl_token.span)?;
+    for el in Punctuated::pairs(&node.bounds) {
+        let (it, p) = el.into_tuple();
+        v.visit_type_param_bound(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_infer<'ast, V>(v: &mut V, node: &'ast TypeInfer) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.underscore_token.spans)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_macro<'ast, V>(v: &mut V, node: &'ast TypeMacro) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    v.visit_macro(&node.mac)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_never<'ast, V>(v: &mut V, node: &'ast TypeNever) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.bang_token.spans)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_param<'ast, V>(v: &mut V, node: &'ast TypeParam) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_ident(&node.ident)?;
+    if let Some(it) = &node.colon_token {
+        tokens_helper(v, &it.spans)?;
+    };
+    for el in Punctuated::pairs(&node.bounds) {
+        let (it, p) = el.into_tuple();
+        v.visit_type_param_bound(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    if let Some(it) = &node.eq_token {
+        tokens_helper(v, &it.spans)?;
+    };
+    if let Some(it) = &node.default {
+        v.visit_type(it)?;
+    };
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_param_bound<'ast, V>(
+    v: &mut V,
+    node: &'ast TypeParamBound,
+) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        TypeParamBound::Trait(_binding_0) => {
+            v.visit_trait_bound(_binding_0)?;
+        }
+        TypeParamBound::Lifetime(_binding_0) => {
+            v.visit_lifetime(_binding_0)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_paren<'ast, V>(v: &mut V, node: &'ast TypeParen) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.paren_token.span)?;
+    v.visit_type(&*node.elem)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_path<'ast, V>(v: &mut V, node: &'ast TypePath) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    if let Some(it) = &node.qself {
+        v.visit_qself(it)?;
+    };
+    v.visit_path(&node.path)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_ptr<'ast, V>(v: &mut V, node: &'ast TypePtr) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.star_token.spans)?;
+    if let Some(it) = &node.const_token {
+        tokens_helper(v, &it.span)?;
+    };
+    if let Some(it) = &node.mutability {
+        tokens_helper(v, &it.span)?;
+    };
+    v.visit_type(&*node.elem)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_reference<'ast, V>(v: &mut V, node: &'ast TypeReference) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.and_token.spans)?;
+    if let Some(it) = &node.lifetime {
+        v.visit_lifetime(it)?;
+    };
+    if let Some(it) = &node.mutability {
+        tokens_helper(v, &it.span)?;
+    };
+    v.visit_type(&*node.elem)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_slice<'ast, V>(v: &mut V, node: &'ast TypeSlice) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.bracket_token.span)?;
+    v.visit_type(&*node.elem)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_trait_object<'ast, V>(
+    v: &mut V,
+    node: &'ast TypeTraitObject,
+) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    if let Some(it) = &node.dyn_token {
+        tokens_helper(v, &it.span)?;
+    };
+    for el in Punctuated::pairs(&node.bounds) {
+        let (it, p) = el.into_tuple();
+        v.visit_type_param_bound(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_tuple<'ast, V>(v: &mut V, node: &'ast TypeTuple) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.paren_token.span)?;
+    for el in Punctuated::pairs(&node.elems) {
+        let (it, p) = el.into_tuple();
+        v.visit_type(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_un_op<'ast, V>(v: &mut V, node: &'ast UnOp) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        UnOp::Deref(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        UnOp::Not(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+        UnOp::Neg(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_use_glob<'ast, V>(v: &mut V, node: &'ast UseGlob) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.star_token.spans)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_use_group<'ast, V>(v: &mut V, node: &'ast UseGroup) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.brace_token.span)?;
+    for el in Punctuated::pairs(&node.items) {
+        let (it, p) = el.into_tuple();
+        v.visit_use_tree(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_use_name<'ast, V>(v: &mut V, node: &'ast UseName) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    v.visit_ident(&node.ident)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_use_path<'ast, V>(v: &mut V, node: &'ast UsePath) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    v.visit_ident(&node.ident)?;
+    tokens_helper(v, &node.colon2_token.spans)?;
+    v.visit_use_tree(&*node.tree)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_use_rename<'ast, V>(v: &mut V, node: &'ast UseRename) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    v.visit_ident(&node.ident)?;
+    tokens_helper(v, &node.as_token.span)?;
+    v.visit_ident(&node.rename)?;
+    Ok(())
+}
+#[cfg(feature = "full")]
+pub fn visit_use_tree<'ast, V>(v: &mut V, node: &'ast UseTree) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        UseTree::Path(_binding_0) => {
+            v.visit_use_path(_binding_0)?;
+        }
+        UseTree::Name(_binding_0) => {
+            v.visit_use_name(_binding_0)?;
+        }
+        UseTree::Rename(_binding_0) => {
+            v.visit_use_rename(_binding_0)?;
+        }
+        UseTree::Glob(_binding_0) => {
+            v.visit_use_glob(_binding_0)?;
+        }
+        UseTree::Group(_binding_0) => {
+            v.visit_use_group(_binding_0)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_variadic<'ast, V>(v: &mut V, node: &'ast Variadic) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    tokens_helper(v, &node.dots.spans)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_variant<'ast, V>(v: &mut V, node: &'ast Variant) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)?;
+    }
+    v.visit_ident(&node.ident)?;
+    v.visit_fields(&node.fields)?;
+    if let Some(it) = &node.discriminant {
+        tokens_helper(v, &(it).0.spans)?;
+        v.visit_expr(&(it).1)?;
+    };
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_vis_crate<'ast, V>(v: &mut V, node: &'ast VisCrate) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.crate_token.span)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_vis_public<'ast, V>(v: &mut V, node: &'ast VisPublic) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.pub_token.span)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_vis_restricted<'ast, V>(v: &mut V, node: &'ast VisRestricted) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.pub_token.span)?;
+    tokens_helper(v, &node.paren_token.span)?;
+    if let Some(it) = &node.in_token {
+        tokens_helper(v, &it.span)?;
+    };
+    v.visit_path(&*node.path)?;
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_visibility<'ast, V>(v: &mut V, node: &'ast Visibility) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        Visibility::Public(_binding_0) => {
+            v.visit_vis_public(_binding_0)?;
+        }
+        Visibility::Crate(_binding_0) => {
+            v.visit_vis_crate(_binding_0)?;
+        }
+        Visibility::Restricted(_binding_0) => {
+            v.visit_vis_restricted(_binding_0)?;
+        }
+        Visibility::Inherited => {}
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_where_clause<'ast, V>(v: &mut V, node: &'ast WhereClause) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.where_token.span)?;
+    for el in Punctuated::pairs(&node.predicates) {
+        let (it, p) = el.into_tuple();
+        v.visit_where_predicate(it)?;
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans)?;
+        }
+    }
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_where_predicate<'ast, V>(v: &mut V, node: &'ast WherePredicate) -> Result<(), V::Break>
+where
+    V: TryVisit<'ast> + ?Sized,
+{
+    match node {
+        WherePredicate::Type(_binding_0) => {
+            v.visit_predicate_type(_binding_0)?;
+        }
+        WherePredicate::Lifetime(_binding_0) => {
+            v.visit_predicate_lifetime(_binding_0)?;
+        }
+        WherePredicate::Eq(_binding_0) => {
+            v.visit_predicate_eq(_binding_0)?;
+        }
+    }
+    Ok(())
+}
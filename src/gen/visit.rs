@@ -44,6 +44,18 @@ pub trait Visit<'ast> {
         visit_arm(self, i);
     }
     #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_attr_meta(&mut self, i: &'ast AttrMeta) {
+        visit_attr_meta(self, i);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_attr_meta_list(&mut self, i: &'ast AttrMetaList) {
+        visit_attr_meta_list(self, i);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_attr_meta_name_value(&mut self, i: &'ast AttrMetaNameValue) {
+        visit_attr_meta_name_value(self, i);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
     fn visit_attr_style(&mut self, i: &'ast AttrStyle) {
         visit_attr_style(self, i);
     }
@@ -152,6 +164,10 @@ pub trait Visit<'ast> {
         visit_expr_closure(self, i);
     }
     #[cfg(feature = "full")]
+    fn visit_expr_const(&mut self, i: &'ast ExprConst) {
+        visit_expr_const(self, i);
+    }
+    #[cfg(feature = "full")]
     fn visit_expr_continue(&mut self, i: &'ast ExprContinue) {
         visit_expr_continue(self, i);
     }
@@ -212,6 +228,10 @@ pub trait Visit<'ast> {
         visit_expr_range(self, i);
     }
     #[cfg(feature = "full")]
+    fn visit_expr_raw_addr(&mut self, i: &'ast ExprRawAddr) {
+        visit_expr_raw_addr(self, i);
+    }
+    #[cfg(feature = "full")]
     fn visit_expr_reference(&mut self, i: &'ast ExprReference) {
         visit_expr_reference(self, i);
     }
@@ -375,6 +395,10 @@ pub trait Visit<'ast> {
         visit_item_fn(self, i);
     }
     #[cfg(feature = "full")]
+    fn visit_item_fn_decl(&mut self, i: &'ast ItemFnDecl) {
+        visit_item_fn_decl(self, i);
+    }
+    #[cfg(feature = "full")]
     fn visit_item_foreign_mod(&mut self, i: &'ast ItemForeignMod) {
         visit_item_foreign_mod(self, i);
     }
@@ -461,6 +485,10 @@ pub trait Visit<'ast> {
     fn visit_local(&mut self, i: &'ast Local) {
         visit_local(self, i);
     }
+    #[cfg(feature = "full")]
+    fn visit_local_init(&mut self, i: &'ast LocalInit) {
+        visit_local_init(self, i);
+    }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn visit_macro(&mut self, i: &'ast Macro) {
         visit_macro(self, i);
@@ -469,6 +497,34 @@ pub trait Visit<'ast> {
     fn visit_macro_delimiter(&mut self, i: &'ast MacroDelimiter) {
         visit_macro_delimiter(self, i);
     }
+    #[cfg(feature = "full")]
+    fn visit_macro_group(&mut self, i: &'ast MacroGroup) {
+        visit_macro_group(self, i);
+    }
+    #[cfg(feature = "full")]
+    fn visit_macro_metavar(&mut self, i: &'ast MacroMetavar) {
+        visit_macro_metavar(self, i);
+    }
+    #[cfg(feature = "full")]
+    fn visit_macro_rep_op(&mut self, i: &'ast MacroRepOp) {
+        visit_macro_rep_op(self, i);
+    }
+    #[cfg(feature = "full")]
+    fn visit_macro_repetition(&mut self, i: &'ast MacroRepetition) {
+        visit_macro_repetition(self, i);
+    }
+    #[cfg(feature = "full")]
+    fn visit_macro_rule(&mut self, i: &'ast MacroRule) {
+        visit_macro_rule(self, i);
+    }
+    #[cfg(feature = "full")]
+    fn visit_macro_rules(&mut self, i: &'ast MacroRules) {
+        visit_macro_rules(self, i);
+    }
+    #[cfg(feature = "full")]
+    fn visit_macro_tree(&mut self, i: &'ast MacroTree) {
+        visit_macro_tree(self, i);
+    }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn visit_member(&mut self, i: &'ast Member) {
         visit_member(self, i);
@@ -506,6 +562,10 @@ pub trait Visit<'ast> {
         visit_pat_box(self, i);
     }
     #[cfg(feature = "full")]
+    fn visit_pat_const(&mut self, i: &'ast PatConst) {
+        visit_pat_const(self, i);
+    }
+    #[cfg(feature = "full")]
     fn visit_pat_ident(&mut self, i: &'ast PatIdent) {
         visit_pat_ident(self, i);
     }
@@ -573,6 +633,10 @@ pub trait Visit<'ast> {
     fn visit_path_segment(&mut self, i: &'ast PathSegment) {
         visit_path_segment(self, i);
     }
+    #[cfg(feature = "full")]
+    fn visit_pointer_mutability(&mut self, i: &'ast PointerMutability) {
+        visit_pointer_mutability(self, i);
+    }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn visit_predicate_eq(&mut self, i: &'ast PredicateEq) {
         visit_predicate_eq(self, i);
@@ -819,6 +883,41 @@ where
     };
 }
 #[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_attr_meta<'ast, V>(v: &mut V, node: &'ast AttrMeta)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        AttrMeta::Path(_binding_0) => {
+            v.visit_path(_binding_0);
+        }
+        AttrMeta::List(_binding_0) => {
+            v.visit_attr_meta_list(_binding_0);
+        }
+        AttrMeta::NameValue(_binding_0) => {
+            v.visit_attr_meta_name_value(_binding_0);
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_attr_meta_list<'ast, V>(v: &mut V, node: &'ast AttrMetaList)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_path(&node.path);
+    v.visit_macro_delimiter(&node.delimiter);
+    skip!(node.tokens);
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_attr_meta_name_value<'ast, V>(v: &mut V, node: &'ast AttrMetaNameValue)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_path(&node.path);
+    tokens_helper(v, &node.eq_token.spans);
+    v.visit_expr(&node.value);
+}
+#[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_attr_style<'ast, V>(v: &mut V, node: &'ast AttrStyle)
 where
     V: Visit<'ast> + ?Sized,
@@ -1122,6 +1221,9 @@ where
         Expr::Closure(_binding_0) => {
             full!(v.visit_expr_closure(_binding_0));
         }
+        Expr::Const(_binding_0) => {
+            full!(v.visit_expr_const(_binding_0));
+        }
         Expr::Continue(_binding_0) => {
             full!(v.visit_expr_continue(_binding_0));
         }
@@ -1167,6 +1269,9 @@ where
         Expr::Range(_binding_0) => {
             full!(v.visit_expr_range(_binding_0));
         }
+        Expr::RawAddr(_binding_0) => {
+            full!(v.visit_expr_raw_addr(_binding_0));
+        }
         Expr::Reference(_binding_0) => {
             full!(v.visit_expr_reference(_binding_0));
         }
@@ -1388,6 +1493,17 @@ where
     v.visit_expr(&*node.body);
 }
 #[cfg(feature = "full")]
+pub fn visit_expr_const<'ast, V>(v: &mut V, node: &'ast ExprConst)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it);
+    }
+    tokens_helper(v, &node.const_token.span);
+    v.visit_block(&node.block);
+}
+#[cfg(feature = "full")]
 pub fn visit_expr_continue<'ast, V>(v: &mut V, node: &'ast ExprContinue)
 where
     V: Visit<'ast> + ?Sized,
@@ -1594,6 +1710,19 @@ where
     };
 }
 #[cfg(feature = "full")]
+pub fn visit_expr_raw_addr<'ast, V>(v: &mut V, node: &'ast ExprRawAddr)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it);
+    }
+    tokens_helper(v, &node.and_token.spans);
+    tokens_helper(v, &node.raw.span);
+    v.visit_pointer_mutability(&node.mutability);
+    v.visit_expr(&*node.expr);
+}
+#[cfg(feature = "full")]
 pub fn visit_expr_reference<'ast, V>(v: &mut V, node: &'ast ExprReference)
 where
     V: Visit<'ast> + ?Sized,
@@ -2152,6 +2281,9 @@ where
         Item::Fn(_binding_0) => {
             v.visit_item_fn(_binding_0);
         }
+        Item::FnDecl(_binding_0) => {
+            v.visit_item_fn_decl(_binding_0);
+        }
         Item::ForeignMod(_binding_0) => {
             v.visit_item_foreign_mod(_binding_0);
         }
@@ -2207,8 +2339,10 @@ where
     v.visit_ident(&node.ident);
     tokens_helper(v, &node.colon_token.spans);
     v.visit_type(&*node.ty);
-    tokens_helper(v, &node.eq_token.spans);
-    v.visit_expr(&*node.expr);
+    if let Some(it) = &node.expr {
+        tokens_helper(v, &(it).0.spans);
+        v.visit_expr(&*(it).1);
+    };
     tokens_helper(v, &node.semi_token.spans);
 }
 #[cfg(feature = "full")]
@@ -2263,6 +2397,18 @@ where
     v.visit_block(&*node.block);
 }
 #[cfg(feature = "full")]
+pub fn visit_item_fn_decl<'ast, V>(v: &mut V, node: &'ast ItemFnDecl)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it);
+    }
+    v.visit_visibility(&node.vis);
+    v.visit_signature(&node.sig);
+    tokens_helper(v, &node.semi_token.spans);
+}
+#[cfg(feature = "full")]
 pub fn visit_item_foreign_mod<'ast, V>(v: &mut V, node: &'ast ItemForeignMod)
 where
     V: Visit<'ast> + ?Sized,
@@ -2270,6 +2416,9 @@ where
     for it in &node.attrs {
         v.visit_attribute(it);
     }
+    if let Some(it) = &node.unsafety {
+        tokens_helper(v, &it.span);
+    };
     v.visit_abi(&node.abi);
     tokens_helper(v, &node.brace_token.span);
     for it in &node.items {
@@ -2343,6 +2492,9 @@ where
         v.visit_attribute(it);
     }
     v.visit_visibility(&node.vis);
+    if let Some(it) = &node.unsafety {
+        tokens_helper(v, &it.span);
+    };
     tokens_helper(v, &node.mod_token.span);
     v.visit_ident(&node.ident);
     if let Some(it) = &node.content {
@@ -2369,10 +2521,14 @@ where
         tokens_helper(v, &it.span);
     };
     v.visit_ident(&node.ident);
-    tokens_helper(v, &node.colon_token.spans);
-    v.visit_type(&*node.ty);
-    tokens_helper(v, &node.eq_token.spans);
-    v.visit_expr(&*node.expr);
+    if let Some(it) = &node.ty {
+        tokens_helper(v, &(it).0.spans);
+        v.visit_type(&*(it).1);
+    };
+    if let Some(it) = &node.expr {
+        tokens_helper(v, &(it).0.spans);
+        v.visit_expr(&*(it).1);
+    };
     tokens_helper(v, &node.semi_token.spans);
 }
 #[cfg(feature = "full")]
@@ -2607,11 +2763,22 @@ where
     tokens_helper(v, &node.let_token.span);
     v.visit_pat(&node.pat);
     if let Some(it) = &node.init {
-        tokens_helper(v, &(it).0.spans);
-        v.visit_expr(&*(it).1);
+        v.visit_local_init(it);
     };
     tokens_helper(v, &node.semi_token.spans);
 }
+#[cfg(feature = "full")]
+pub fn visit_local_init<'ast, V>(v: &mut V, node: &'ast LocalInit)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.eq_token.spans);
+    v.visit_expr(&*node.expr);
+    if let Some(it) = &node.diverge {
+        tokens_helper(v, &(it).0.span);
+        v.visit_block(&(it).1);
+    };
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_macro<'ast, V>(v: &mut V, node: &'ast Macro)
 where
@@ -2639,6 +2806,100 @@ where
         }
     }
 }
+#[cfg(feature = "full")]
+pub fn visit_macro_group<'ast, V>(v: &mut V, node: &'ast MacroGroup)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_macro_delimiter(&node.delimiter);
+    for it in &node.content {
+        v.visit_macro_tree(it);
+    }
+}
+#[cfg(feature = "full")]
+pub fn visit_macro_metavar<'ast, V>(v: &mut V, node: &'ast MacroMetavar)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.dollar_token.spans);
+    v.visit_ident(&node.name);
+    if let Some(it) = &node.specifier {
+        tokens_helper(v, &(it).0.spans);
+        v.visit_ident(&(it).1);
+    };
+}
+#[cfg(feature = "full")]
+pub fn visit_macro_rep_op<'ast, V>(v: &mut V, node: &'ast MacroRepOp)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        MacroRepOp::ZeroOrMore(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans);
+        }
+        MacroRepOp::OneOrMore(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans);
+        }
+        MacroRepOp::ZeroOrOne(_binding_0) => {
+            tokens_helper(v, &_binding_0.spans);
+        }
+    }
+}
+#[cfg(feature = "full")]
+pub fn visit_macro_repetition<'ast, V>(v: &mut V, node: &'ast MacroRepetition)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    tokens_helper(v, &node.dollar_token.spans);
+    tokens_helper(v, &node.paren_token.span);
+    for it in &node.content {
+        v.visit_macro_tree(it);
+    }
+    skip!(node.separator);
+    v.visit_macro_rep_op(&node.op);
+}
+#[cfg(feature = "full")]
+pub fn visit_macro_rule<'ast, V>(v: &mut V, node: &'ast MacroRule)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_macro_group(&node.matcher);
+    tokens_helper(v, &node.fat_arrow_token.spans);
+    v.visit_macro_group(&node.transcriber);
+}
+#[cfg(feature = "full")]
+pub fn visit_macro_rules<'ast, V>(v: &mut V, node: &'ast MacroRules)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for el in Punctuated::pairs(&node.rules) {
+        let (it, p) = el.into_tuple();
+        v.visit_macro_rule(it);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans);
+        }
+    }
+}
+#[cfg(feature = "full")]
+pub fn visit_macro_tree<'ast, V>(v: &mut V, node: &'ast MacroTree)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        MacroTree::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        MacroTree::Group(_binding_0) => {
+            v.visit_macro_group(_binding_0);
+        }
+        MacroTree::Metavar(_binding_0) => {
+            v.visit_macro_metavar(_binding_0);
+        }
+        MacroTree::Repetition(_binding_0) => {
+            v.visit_macro_repetition(_binding_0);
+        }
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_member<'ast, V>(v: &mut V, node: &'ast Member)
 where
@@ -2750,6 +3011,9 @@ where
         Pat::Box(_binding_0) => {
             v.visit_pat_box(_binding_0);
         }
+        Pat::Const(_binding_0) => {
+            v.visit_pat_const(_binding_0);
+        }
         Pat::Ident(_binding_0) => {
             v.visit_pat_ident(_binding_0);
         }
@@ -2810,6 +3074,17 @@ where
     v.visit_pat(&*node.pat);
 }
 #[cfg(feature = "full")]
+pub fn visit_pat_const<'ast, V>(v: &mut V, node: &'ast PatConst)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it);
+    }
+    tokens_helper(v, &node.const_token.span);
+    v.visit_block(&node.block);
+}
+#[cfg(feature = "full")]
 pub fn visit_pat_ident<'ast, V>(v: &mut V, node: &'ast PatIdent)
 where
     V: Visit<'ast> + ?Sized,
@@ -3044,6 +3319,20 @@ where
     v.visit_ident(&node.ident);
     v.visit_path_arguments(&node.arguments);
 }
+#[cfg(feature = "full")]
+pub fn visit_pointer_mutability<'ast, V>(v: &mut V, node: &'ast PointerMutability)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        PointerMutability::Const(_binding_0) => {
+            tokens_helper(v, &_binding_0.span);
+        }
+        PointerMutability::Mut(_binding_0) => {
+            tokens_helper(v, &_binding_0.span);
+        }
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_predicate_eq<'ast, V>(v: &mut V, node: &'ast PredicateEq)
 where
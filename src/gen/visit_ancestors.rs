@@ -0,0 +1,6212 @@
+// This file is @generated by syn-internal-codegen.
+// It is not intended for manual editing.
+
+#![allow(unused_variables)]
+#[cfg(any(feature = "full", feature = "derive"))]
+use crate::gen::helper::visit_ancestors::*;
+#[cfg(any(feature = "full", feature = "derive"))]
+use crate::punctuated::Punctuated;
+use crate::*;
+use proc_macro2::Span;
+use std::slice;
+#[cfg(feature = "full")]
+macro_rules! full {
+    ($e:expr) => {
+        $e
+    };
+}
+#[cfg(all(feature = "derive", not(feature = "full")))]
+macro_rules! full {
+    ($e:expr) => {
+        unreachable!()
+    };
+}
+macro_rules! skip {
+    ($($tt:tt)*) => {};
+}
+/// A reference to a syntax tree node of any type that can have
+/// other nodes inside of it.
+///
+/// *This type is available only if Syn is built with the `"visit-ancestors"` feature.*
+pub enum Node<'ast> {
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Abi(&'ast Abi),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    AngleBracketedGenericArguments(&'ast AngleBracketedGenericArguments),
+    #[cfg(feature = "full")]
+    Arm(&'ast Arm),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    AttrMeta(&'ast AttrMeta),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    AttrMetaList(&'ast AttrMetaList),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    AttrMetaNameValue(&'ast AttrMetaNameValue),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    AttrStyle(&'ast AttrStyle),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Attribute(&'ast Attribute),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    BareFnArg(&'ast BareFnArg),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    BinOp(&'ast BinOp),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Binding(&'ast Binding),
+    #[cfg(feature = "full")]
+    Block(&'ast Block),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    BoundLifetimes(&'ast BoundLifetimes),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ConstParam(&'ast ConstParam),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Constraint(&'ast Constraint),
+    #[cfg(feature = "derive")]
+    Data(&'ast Data),
+    #[cfg(feature = "derive")]
+    DataEnum(&'ast DataEnum),
+    #[cfg(feature = "derive")]
+    DataStruct(&'ast DataStruct),
+    #[cfg(feature = "derive")]
+    DataUnion(&'ast DataUnion),
+    #[cfg(feature = "derive")]
+    DeriveInput(&'ast DeriveInput),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Expr(&'ast Expr),
+    #[cfg(feature = "full")]
+    ExprArray(&'ast ExprArray),
+    #[cfg(feature = "full")]
+    ExprAssign(&'ast ExprAssign),
+    #[cfg(feature = "full")]
+    ExprAssignOp(&'ast ExprAssignOp),
+    #[cfg(feature = "full")]
+    ExprAsync(&'ast ExprAsync),
+    #[cfg(feature = "full")]
+    ExprAwait(&'ast ExprAwait),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprBinary(&'ast ExprBinary),
+    #[cfg(feature = "full")]
+    ExprBlock(&'ast ExprBlock),
+    #[cfg(feature = "full")]
+    ExprBox(&'ast ExprBox),
+    #[cfg(feature = "full")]
+    ExprBreak(&'ast ExprBreak),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprCall(&'ast ExprCall),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprCast(&'ast ExprCast),
+    #[cfg(feature = "full")]
+    ExprClosure(&'ast ExprClosure),
+    #[cfg(feature = "full")]
+    ExprConst(&'ast ExprConst),
+    #[cfg(feature = "full")]
+    ExprContinue(&'ast ExprContinue),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprField(&'ast ExprField),
+    #[cfg(feature = "full")]
+    ExprForLoop(&'ast ExprForLoop),
+    #[cfg(feature = "full")]
+    ExprGroup(&'ast ExprGroup),
+    #[cfg(feature = "full")]
+    ExprIf(&'ast ExprIf),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprIndex(&'ast ExprIndex),
+    #[cfg(feature = "full")]
+    ExprLet(&'ast ExprLet),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprLit(&'ast ExprLit),
+    #[cfg(feature = "full")]
+    ExprLoop(&'ast ExprLoop),
+    #[cfg(feature = "full")]
+    ExprMacro(&'ast ExprMacro),
+    #[cfg(feature = "full")]
+    ExprMatch(&'ast ExprMatch),
+    #[cfg(feature = "full")]
+    ExprMethodCall(&'ast ExprMethodCall),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprParen(&'ast ExprParen),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprPath(&'ast ExprPath),
+    #[cfg(feature = "full")]
+    ExprRange(&'ast ExprRange),
+    #[cfg(feature = "full")]
+    ExprRawAddr(&'ast ExprRawAddr),
+    #[cfg(feature = "full")]
+    ExprReference(&'ast ExprReference),
+    #[cfg(feature = "full")]
+    ExprRepeat(&'ast ExprRepeat),
+    #[cfg(feature = "full")]
+    ExprReturn(&'ast ExprReturn),
+    #[cfg(feature = "full")]
+    ExprStruct(&'ast ExprStruct),
+    #[cfg(feature = "full")]
+    ExprTry(&'ast ExprTry),
+    #[cfg(feature = "full")]
+    ExprTryBlock(&'ast ExprTryBlock),
+    #[cfg(feature = "full")]
+    ExprTuple(&'ast ExprTuple),
+    #[cfg(feature = "full")]
+    ExprType(&'ast ExprType),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprUnary(&'ast ExprUnary),
+    #[cfg(feature = "full")]
+    ExprUnsafe(&'ast ExprUnsafe),
+    #[cfg(feature = "full")]
+    ExprWhile(&'ast ExprWhile),
+    #[cfg(feature = "full")]
+    ExprYield(&'ast ExprYield),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Field(&'ast Field),
+    #[cfg(feature = "full")]
+    FieldPat(&'ast FieldPat),
+    #[cfg(feature = "full")]
+    FieldValue(&'ast FieldValue),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Fields(&'ast Fields),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    FieldsNamed(&'ast FieldsNamed),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    FieldsUnnamed(&'ast FieldsUnnamed),
+    #[cfg(feature = "full")]
+    File(&'ast File),
+    #[cfg(feature = "full")]
+    FnArg(&'ast FnArg),
+    #[cfg(feature = "full")]
+    ForeignItem(&'ast ForeignItem),
+    #[cfg(feature = "full")]
+    ForeignItemFn(&'ast ForeignItemFn),
+    #[cfg(feature = "full")]
+    ForeignItemMacro(&'ast ForeignItemMacro),
+    #[cfg(feature = "full")]
+    ForeignItemStatic(&'ast ForeignItemStatic),
+    #[cfg(feature = "full")]
+    ForeignItemType(&'ast ForeignItemType),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    GenericArgument(&'ast GenericArgument),
+    #[cfg(feature = "full")]
+    GenericMethodArgument(&'ast GenericMethodArgument),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    GenericParam(&'ast GenericParam),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Generics(&'ast Generics),
+    #[cfg(feature = "full")]
+    ImplItem(&'ast ImplItem),
+    #[cfg(feature = "full")]
+    ImplItemConst(&'ast ImplItemConst),
+    #[cfg(feature = "full")]
+    ImplItemMacro(&'ast ImplItemMacro),
+    #[cfg(feature = "full")]
+    ImplItemMethod(&'ast ImplItemMethod),
+    #[cfg(feature = "full")]
+    ImplItemType(&'ast ImplItemType),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Index(&'ast Index),
+    #[cfg(feature = "full")]
+    Item(&'ast Item),
+    #[cfg(feature = "full")]
+    ItemConst(&'ast ItemConst),
+    #[cfg(feature = "full")]
+    ItemEnum(&'ast ItemEnum),
+    #[cfg(feature = "full")]
+    ItemExternCrate(&'ast ItemExternCrate),
+    #[cfg(feature = "full")]
+    ItemFn(&'ast ItemFn),
+    #[cfg(feature = "full")]
+    ItemFnDecl(&'ast ItemFnDecl),
+    #[cfg(feature = "full")]
+    ItemForeignMod(&'ast ItemForeignMod),
+    #[cfg(feature = "full")]
+    ItemImpl(&'ast ItemImpl),
+    #[cfg(feature = "full")]
+    ItemMacro(&'ast ItemMacro),
+    #[cfg(feature = "full")]
+    ItemMacro2(&'ast ItemMacro2),
+    #[cfg(feature = "full")]
+    ItemMod(&'ast ItemMod),
+    #[cfg(feature = "full")]
+    ItemStatic(&'ast ItemStatic),
+    #[cfg(feature = "full")]
+    ItemStruct(&'ast ItemStruct),
+    #[cfg(feature = "full")]
+    ItemTrait(&'ast ItemTrait),
+    #[cfg(feature = "full")]
+    ItemTraitAlias(&'ast ItemTraitAlias),
+    #[cfg(feature = "full")]
+    ItemType(&'ast ItemType),
+    #[cfg(feature = "full")]
+    ItemUnion(&'ast ItemUnion),
+    #[cfg(feature = "full")]
+    ItemUse(&'ast ItemUse),
+    #[cfg(feature = "full")]
+    Label(&'ast Label),
+    Lifetime(&'ast Lifetime),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LifetimeDef(&'ast LifetimeDef),
+    Lit(&'ast Lit),
+    LitBool(&'ast LitBool),
+    #[cfg(feature = "full")]
+    Local(&'ast Local),
+    #[cfg(feature = "full")]
+    LocalInit(&'ast LocalInit),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Macro(&'ast Macro),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    MacroDelimiter(&'ast MacroDelimiter),
+    #[cfg(feature = "full")]
+    MacroGroup(&'ast MacroGroup),
+    #[cfg(feature = "full")]
+    MacroMetavar(&'ast MacroMetavar),
+    #[cfg(feature = "full")]
+    MacroRepOp(&'ast MacroRepOp),
+    #[cfg(feature = "full")]
+    MacroRepetition(&'ast MacroRepetition),
+    #[cfg(feature = "full")]
+    MacroRule(&'ast MacroRule),
+    #[cfg(feature = "full")]
+    MacroRules(&'ast MacroRules),
+    #[cfg(feature = "full")]
+    MacroTree(&'ast MacroTree),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Member(&'ast Member),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Meta(&'ast Meta),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    MetaList(&'ast MetaList),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    MetaNameValue(&'ast MetaNameValue),
+    #[cfg(feature = "full")]
+    MethodTurbofish(&'ast MethodTurbofish),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    NestedMeta(&'ast NestedMeta),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ParenthesizedGenericArguments(&'ast ParenthesizedGenericArguments),
+    #[cfg(feature = "full")]
+    Pat(&'ast Pat),
+    #[cfg(feature = "full")]
+    PatBox(&'ast PatBox),
+    #[cfg(feature = "full")]
+    PatConst(&'ast PatConst),
+    #[cfg(feature = "full")]
+    PatIdent(&'ast PatIdent),
+    #[cfg(feature = "full")]
+    PatLit(&'ast PatLit),
+    #[cfg(feature = "full")]
+    PatMacro(&'ast PatMacro),
+    #[cfg(feature = "full")]
+    PatOr(&'ast PatOr),
+    #[cfg(feature = "full")]
+    PatPath(&'ast PatPath),
+    #[cfg(feature = "full")]
+    PatRange(&'ast PatRange),
+    #[cfg(feature = "full")]
+    PatReference(&'ast PatReference),
+    #[cfg(feature = "full")]
+    PatRest(&'ast PatRest),
+    #[cfg(feature = "full")]
+    PatSlice(&'ast PatSlice),
+    #[cfg(feature = "full")]
+    PatStruct(&'ast PatStruct),
+    #[cfg(feature = "full")]
+    PatTuple(&'ast PatTuple),
+    #[cfg(feature = "full")]
+    PatTupleStruct(&'ast PatTupleStruct),
+    #[cfg(feature = "full")]
+    PatType(&'ast PatType),
+    #[cfg(feature = "full")]
+    PatWild(&'ast PatWild),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Path(&'ast Path),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    PathArguments(&'ast PathArguments),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    PathSegment(&'ast PathSegment),
+    #[cfg(feature = "full")]
+    PointerMutability(&'ast PointerMutability),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    PredicateEq(&'ast PredicateEq),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    PredicateLifetime(&'ast PredicateLifetime),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    PredicateType(&'ast PredicateType),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    QSelf(&'ast QSelf),
+    #[cfg(feature = "full")]
+    RangeLimits(&'ast RangeLimits),
+    #[cfg(feature = "full")]
+    Receiver(&'ast Receiver),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ReturnType(&'ast ReturnType),
+    #[cfg(feature = "full")]
+    Signature(&'ast Signature),
+    #[cfg(feature = "full")]
+    Stmt(&'ast Stmt),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TraitBound(&'ast TraitBound),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TraitBoundModifier(&'ast TraitBoundModifier),
+    #[cfg(feature = "full")]
+    TraitItem(&'ast TraitItem),
+    #[cfg(feature = "full")]
+    TraitItemConst(&'ast TraitItemConst),
+    #[cfg(feature = "full")]
+    TraitItemMacro(&'ast TraitItemMacro),
+    #[cfg(feature = "full")]
+    TraitItemMethod(&'ast TraitItemMethod),
+    #[cfg(feature = "full")]
+    TraitItemType(&'ast TraitItemType),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Type(&'ast Type),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeArray(&'ast TypeArray),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeBareFn(&'ast TypeBareFn),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeGroup(&'ast TypeGroup),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeImplTrait(&'ast TypeImplTrait),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeInfer(&'ast TypeInfer),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeMacro(&'ast TypeMacro),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeNever(&'ast TypeNever),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeParam(&'ast TypeParam),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeParamBound(&'ast TypeParamBound),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeParen(&'ast TypeParen),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypePath(&'ast TypePath),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypePtr(&'ast TypePtr),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeReference(&'ast TypeReference),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeSlice(&'ast TypeSlice),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeTraitObject(&'ast TypeTraitObject),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeTuple(&'ast TypeTuple),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    UnOp(&'ast UnOp),
+    #[cfg(feature = "full")]
+    UseGlob(&'ast UseGlob),
+    #[cfg(feature = "full")]
+    UseGroup(&'ast UseGroup),
+    #[cfg(feature = "full")]
+    UseName(&'ast UseName),
+    #[cfg(feature = "full")]
+    UsePath(&'ast UsePath),
+    #[cfg(feature = "full")]
+    UseRename(&'ast UseRename),
+    #[cfg(feature = "full")]
+    UseTree(&'ast UseTree),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Variadic(&'ast Variadic),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Variant(&'ast Variant),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    VisCrate(&'ast VisCrate),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    VisPublic(&'ast VisPublic),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    VisRestricted(&'ast VisRestricted),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Visibility(&'ast Visibility),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    WhereClause(&'ast WhereClause),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    WherePredicate(&'ast WherePredicate),
+    #[doc(hidden)]
+    __TestExhaustive(&'ast crate::private),
+}
+impl<'ast> Copy for Node<'ast> {}
+impl<'ast> Clone for Node<'ast> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+/// A node between the root of the traversal and the node being
+/// visited, along with the place in it where the traversal went
+/// down to the next node.
+///
+/// *This type is available only if Syn is built with the `"visit-ancestors"` feature.*
+pub struct Ancestor<'ast> {
+    /// The ancestor node.
+    pub node: Node<'ast>,
+    /// The field of the ancestor that holds the next node down, or
+    /// the name of its variant if the ancestor is an enum.
+    pub field: &'static str,
+    /// The position of the next node down within the field, if the
+    /// field is a `Vec` or `Punctuated`.
+    pub index: Option<usize>,
+}
+impl<'ast> Copy for Ancestor<'ast> {}
+impl<'ast> Clone for Ancestor<'ast> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+/// The ancestors of the node being visited, from the root of the
+/// traversal down to its parent.
+///
+/// *This type is available only if Syn is built with the `"visit-ancestors"` feature.*
+pub struct Ancestors<'ast> {
+    stack: Vec<Ancestor<'ast>>,
+}
+impl<'ast> Ancestors<'ast> {
+    /// Creates the empty list of ancestors with which to start a
+    /// traversal at its root.
+    pub fn new() -> Self {
+        Ancestors { stack: Vec::new() }
+    }
+    /// The parent of the node being visited, or `None` if the node
+    /// is the root of the traversal.
+    pub fn parent(&self) -> Option<&Ancestor<'ast>> {
+        self.stack.last()
+    }
+    /// Iterates over the ancestors from the root of the traversal
+    /// down to the parent of the node being visited.
+    pub fn iter(&self) -> slice::Iter<'_, Ancestor<'ast>> {
+        self.stack.iter()
+    }
+    /// The number of ancestors, which is the depth of the node
+    /// being visited.
+    pub fn len(&self) -> usize {
+        self.stack.len()
+    }
+    /// Whether the node being visited is the root of the traversal.
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+    fn push(&mut self, node: Node<'ast>) {
+        self.stack.push(Ancestor {
+            node,
+            field: "",
+            index: None,
+        });
+    }
+    fn pop(&mut self) {
+        self.stack.pop();
+    }
+    fn set_field(&mut self, field: &'static str) {
+        let last = self.stack.last_mut().unwrap();
+        last.field = field;
+        last.index = None;
+    }
+    #[cfg(any(feature = "full", feature = "derive"))]
+    fn set_index(&mut self, index: usize) {
+        self.stack.last_mut().unwrap().index = Some(index);
+    }
+}
+impl<'ast> Default for Ancestors<'ast> {
+    fn default() -> Self {
+        Ancestors::new()
+    }
+}
+impl<'ast> Clone for Ancestors<'ast> {
+    fn clone(&self) -> Self {
+        Ancestors {
+            stack: self.stack.clone(),
+        }
+    }
+}
+/// Syntax tree traversal to walk a shared borrow of a syntax tree,
+/// keeping track of the ancestors of each node.
+///
+/// See the [module documentation] for details.
+///
+/// [module documentation]: self
+///
+/// *This trait is available only if Syn is built with the `"visit-ancestors"` feature.*
+pub trait VisitAncestors<'ast> {
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_abi(&mut self, i: &'ast Abi, ancestors: &mut Ancestors<'ast>) {
+        visit_abi(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_angle_bracketed_generic_arguments(
+        &mut self,
+        i: &'ast AngleBracketedGenericArguments,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_angle_bracketed_generic_arguments(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_arm(&mut self, i: &'ast Arm, ancestors: &mut Ancestors<'ast>) {
+        visit_arm(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_attr_meta(&mut self, i: &'ast AttrMeta, ancestors: &mut Ancestors<'ast>) {
+        visit_attr_meta(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_attr_meta_list(&mut self, i: &'ast AttrMetaList, ancestors: &mut Ancestors<'ast>) {
+        visit_attr_meta_list(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_attr_meta_name_value(
+        &mut self,
+        i: &'ast AttrMetaNameValue,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_attr_meta_name_value(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_attr_style(&mut self, i: &'ast AttrStyle, ancestors: &mut Ancestors<'ast>) {
+        visit_attr_style(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_attribute(&mut self, i: &'ast Attribute, ancestors: &mut Ancestors<'ast>) {
+        visit_attribute(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_bare_fn_arg(&mut self, i: &'ast BareFnArg, ancestors: &mut Ancestors<'ast>) {
+        visit_bare_fn_arg(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_bin_op(&mut self, i: &'ast BinOp, ancestors: &mut Ancestors<'ast>) {
+        visit_bin_op(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_binding(&mut self, i: &'ast Binding, ancestors: &mut Ancestors<'ast>) {
+        visit_binding(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_block(&mut self, i: &'ast Block, ancestors: &mut Ancestors<'ast>) {
+        visit_block(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_bound_lifetimes(&mut self, i: &'ast BoundLifetimes, ancestors: &mut Ancestors<'ast>) {
+        visit_bound_lifetimes(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_const_param(&mut self, i: &'ast ConstParam, ancestors: &mut Ancestors<'ast>) {
+        visit_const_param(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_constraint(&mut self, i: &'ast Constraint, ancestors: &mut Ancestors<'ast>) {
+        visit_constraint(self, i, ancestors);
+    }
+    #[cfg(feature = "derive")]
+    fn visit_data(&mut self, i: &'ast Data, ancestors: &mut Ancestors<'ast>) {
+        visit_data(self, i, ancestors);
+    }
+    #[cfg(feature = "derive")]
+    fn visit_data_enum(&mut self, i: &'ast DataEnum, ancestors: &mut Ancestors<'ast>) {
+        visit_data_enum(self, i, ancestors);
+    }
+    #[cfg(feature = "derive")]
+    fn visit_data_struct(&mut self, i: &'ast DataStruct, ancestors: &mut Ancestors<'ast>) {
+        visit_data_struct(self, i, ancestors);
+    }
+    #[cfg(feature = "derive")]
+    fn visit_data_union(&mut self, i: &'ast DataUnion, ancestors: &mut Ancestors<'ast>) {
+        visit_data_union(self, i, ancestors);
+    }
+    #[cfg(feature = "derive")]
+    fn visit_derive_input(&mut self, i: &'ast DeriveInput, ancestors: &mut Ancestors<'ast>) {
+        visit_derive_input(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr(&mut self, i: &'ast Expr, ancestors: &mut Ancestors<'ast>) {
+        visit_expr(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_array(&mut self, i: &'ast ExprArray, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_array(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_assign(&mut self, i: &'ast ExprAssign, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_assign(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_assign_op(&mut self, i: &'ast ExprAssignOp, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_assign_op(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_async(&mut self, i: &'ast ExprAsync, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_async(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_await(&mut self, i: &'ast ExprAwait, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_await(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_binary(&mut self, i: &'ast ExprBinary, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_binary(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_block(&mut self, i: &'ast ExprBlock, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_block(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_box(&mut self, i: &'ast ExprBox, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_box(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_break(&mut self, i: &'ast ExprBreak, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_break(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_call(&mut self, i: &'ast ExprCall, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_call(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_cast(&mut self, i: &'ast ExprCast, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_cast(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_closure(&mut self, i: &'ast ExprClosure, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_closure(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_const(&mut self, i: &'ast ExprConst, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_const(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_continue(&mut self, i: &'ast ExprContinue, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_continue(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_field(&mut self, i: &'ast ExprField, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_field(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_for_loop(&mut self, i: &'ast ExprForLoop, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_for_loop(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_group(&mut self, i: &'ast ExprGroup, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_group(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_if(&mut self, i: &'ast ExprIf, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_if(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_index(&mut self, i: &'ast ExprIndex, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_index(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_let(&mut self, i: &'ast ExprLet, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_let(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_lit(&mut self, i: &'ast ExprLit, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_lit(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_loop(&mut self, i: &'ast ExprLoop, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_loop(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_macro(&mut self, i: &'ast ExprMacro, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_macro(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_match(&mut self, i: &'ast ExprMatch, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_match(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_method_call(&mut self, i: &'ast ExprMethodCall, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_method_call(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_paren(&mut self, i: &'ast ExprParen, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_paren(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_path(&mut self, i: &'ast ExprPath, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_path(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_range(&mut self, i: &'ast ExprRange, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_range(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_raw_addr(&mut self, i: &'ast ExprRawAddr, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_raw_addr(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_reference(&mut self, i: &'ast ExprReference, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_reference(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_repeat(&mut self, i: &'ast ExprRepeat, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_repeat(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_return(&mut self, i: &'ast ExprReturn, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_return(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_struct(&mut self, i: &'ast ExprStruct, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_struct(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_try(&mut self, i: &'ast ExprTry, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_try(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_try_block(&mut self, i: &'ast ExprTryBlock, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_try_block(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_tuple(&mut self, i: &'ast ExprTuple, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_tuple(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_type(&mut self, i: &'ast ExprType, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_type(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_unary(&mut self, i: &'ast ExprUnary, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_unary(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_unsafe(&mut self, i: &'ast ExprUnsafe, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_unsafe(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_while(&mut self, i: &'ast ExprWhile, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_while(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_yield(&mut self, i: &'ast ExprYield, ancestors: &mut Ancestors<'ast>) {
+        visit_expr_yield(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_field(&mut self, i: &'ast Field, ancestors: &mut Ancestors<'ast>) {
+        visit_field(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_field_pat(&mut self, i: &'ast FieldPat, ancestors: &mut Ancestors<'ast>) {
+        visit_field_pat(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_field_value(&mut self, i: &'ast FieldValue, ancestors: &mut Ancestors<'ast>) {
+        visit_field_value(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_fields(&mut self, i: &'ast Fields, ancestors: &mut Ancestors<'ast>) {
+        visit_fields(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_fields_named(&mut self, i: &'ast FieldsNamed, ancestors: &mut Ancestors<'ast>) {
+        visit_fields_named(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_fields_unnamed(&mut self, i: &'ast FieldsUnnamed, ancestors: &mut Ancestors<'ast>) {
+        visit_fields_unnamed(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_file(&mut self, i: &'ast File, ancestors: &mut Ancestors<'ast>) {
+        visit_file(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_fn_arg(&mut self, i: &'ast FnArg, ancestors: &mut Ancestors<'ast>) {
+        visit_fn_arg(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item(&mut self, i: &'ast ForeignItem, ancestors: &mut Ancestors<'ast>) {
+        visit_foreign_item(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_fn(&mut self, i: &'ast ForeignItemFn, ancestors: &mut Ancestors<'ast>) {
+        visit_foreign_item_fn(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_macro(
+        &mut self,
+        i: &'ast ForeignItemMacro,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_foreign_item_macro(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_static(
+        &mut self,
+        i: &'ast ForeignItemStatic,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_foreign_item_static(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_type(
+        &mut self,
+        i: &'ast ForeignItemType,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_foreign_item_type(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_generic_argument(
+        &mut self,
+        i: &'ast GenericArgument,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_generic_argument(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_generic_method_argument(
+        &mut self,
+        i: &'ast GenericMethodArgument,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_generic_method_argument(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_generic_param(&mut self, i: &'ast GenericParam, ancestors: &mut Ancestors<'ast>) {
+        visit_generic_param(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_generics(&mut self, i: &'ast Generics, ancestors: &mut Ancestors<'ast>) {
+        visit_generics(self, i, ancestors);
+    }
+    fn visit_ident(&mut self, i: &'ast Ident, ancestors: &mut Ancestors<'ast>) {
+        visit_ident(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item(&mut self, i: &'ast ImplItem, ancestors: &mut Ancestors<'ast>) {
+        visit_impl_item(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_const(&mut self, i: &'ast ImplItemConst, ancestors: &mut Ancestors<'ast>) {
+        visit_impl_item_const(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_macro(&mut self, i: &'ast ImplItemMacro, ancestors: &mut Ancestors<'ast>) {
+        visit_impl_item_macro(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_method(&mut self, i: &'ast ImplItemMethod, ancestors: &mut Ancestors<'ast>) {
+        visit_impl_item_method(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_type(&mut self, i: &'ast ImplItemType, ancestors: &mut Ancestors<'ast>) {
+        visit_impl_item_type(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_index(&mut self, i: &'ast Index, ancestors: &mut Ancestors<'ast>) {
+        visit_index(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item(&mut self, i: &'ast Item, ancestors: &mut Ancestors<'ast>) {
+        visit_item(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_const(&mut self, i: &'ast ItemConst, ancestors: &mut Ancestors<'ast>) {
+        visit_item_const(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_enum(&mut self, i: &'ast ItemEnum, ancestors: &mut Ancestors<'ast>) {
+        visit_item_enum(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_extern_crate(
+        &mut self,
+        i: &'ast ItemExternCrate,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_item_extern_crate(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_fn(&mut self, i: &'ast ItemFn, ancestors: &mut Ancestors<'ast>) {
+        visit_item_fn(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_fn_decl(&mut self, i: &'ast ItemFnDecl, ancestors: &mut Ancestors<'ast>) {
+        visit_item_fn_decl(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_foreign_mod(&mut self, i: &'ast ItemForeignMod, ancestors: &mut Ancestors<'ast>) {
+        visit_item_foreign_mod(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_impl(&mut self, i: &'ast ItemImpl, ancestors: &mut Ancestors<'ast>) {
+        visit_item_impl(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_macro(&mut self, i: &'ast ItemMacro, ancestors: &mut Ancestors<'ast>) {
+        visit_item_macro(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_macro2(&mut self, i: &'ast ItemMacro2, ancestors: &mut Ancestors<'ast>) {
+        visit_item_macro2(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_mod(&mut self, i: &'ast ItemMod, ancestors: &mut Ancestors<'ast>) {
+        visit_item_mod(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_static(&mut self, i: &'ast ItemStatic, ancestors: &mut Ancestors<'ast>) {
+        visit_item_static(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_struct(&mut self, i: &'ast ItemStruct, ancestors: &mut Ancestors<'ast>) {
+        visit_item_struct(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_trait(&mut self, i: &'ast ItemTrait, ancestors: &mut Ancestors<'ast>) {
+        visit_item_trait(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_trait_alias(&mut self, i: &'ast ItemTraitAlias, ancestors: &mut Ancestors<'ast>) {
+        visit_item_trait_alias(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_type(&mut self, i: &'ast ItemType, ancestors: &mut Ancestors<'ast>) {
+        visit_item_type(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_union(&mut self, i: &'ast ItemUnion, ancestors: &mut Ancestors<'ast>) {
+        visit_item_union(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_use(&mut self, i: &'ast ItemUse, ancestors: &mut Ancestors<'ast>) {
+        visit_item_use(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_label(&mut self, i: &'ast Label, ancestors: &mut Ancestors<'ast>) {
+        visit_label(self, i, ancestors);
+    }
+    fn visit_lifetime(&mut self, i: &'ast Lifetime, ancestors: &mut Ancestors<'ast>) {
+        visit_lifetime(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lifetime_def(&mut self, i: &'ast LifetimeDef, ancestors: &mut Ancestors<'ast>) {
+        visit_lifetime_def(self, i, ancestors);
+    }
+    fn visit_lit(&mut self, i: &'ast Lit, ancestors: &mut Ancestors<'ast>) {
+        visit_lit(self, i, ancestors);
+    }
+    fn visit_lit_bool(&mut self, i: &'ast LitBool, ancestors: &mut Ancestors<'ast>) {
+        visit_lit_bool(self, i, ancestors);
+    }
+    fn visit_lit_byte(&mut self, i: &'ast LitByte, ancestors: &mut Ancestors<'ast>) {
+        visit_lit_byte(self, i, ancestors);
+    }
+    fn visit_lit_byte_str(&mut self, i: &'ast LitByteStr, ancestors: &mut Ancestors<'ast>) {
+        visit_lit_byte_str(self, i, ancestors);
+    }
+    fn visit_lit_char(&mut self, i: &'ast LitChar, ancestors: &mut Ancestors<'ast>) {
+        visit_lit_char(self, i, ancestors);
+    }
+    fn visit_lit_float(&mut self, i: &'ast LitFloat, ancestors: &mut Ancestors<'ast>) {
+        visit_lit_float(self, i, ancestors);
+    }
+    fn visit_lit_int(&mut self, i: &'ast LitInt, ancestors: &mut Ancestors<'ast>) {
+        visit_lit_int(self, i, ancestors);
+    }
+    fn visit_lit_str(&mut self, i: &'ast LitStr, ancestors: &mut Ancestors<'ast>) {
+        visit_lit_str(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_local(&mut self, i: &'ast Local, ancestors: &mut Ancestors<'ast>) {
+        visit_local(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_local_init(&mut self, i: &'ast LocalInit, ancestors: &mut Ancestors<'ast>) {
+        visit_local_init(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_macro(&mut self, i: &'ast Macro, ancestors: &mut Ancestors<'ast>) {
+        visit_macro(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_macro_delimiter(&mut self, i: &'ast MacroDelimiter, ancestors: &mut Ancestors<'ast>) {
+        visit_macro_delimiter(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_macro_group(&mut self, i: &'ast MacroGroup, ancestors: &mut Ancestors<'ast>) {
+        visit_macro_group(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_macro_metavar(&mut self, i: &'ast MacroMetavar, ancestors: &mut Ancestors<'ast>) {
+        visit_macro_metavar(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_macro_rep_op(&mut self, i: &'ast MacroRepOp, ancestors: &mut Ancestors<'ast>) {
+        visit_macro_rep_op(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_macro_repetition(
+        &mut self,
+        i: &'ast MacroRepetition,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_macro_repetition(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_macro_rule(&mut self, i: &'ast MacroRule, ancestors: &mut Ancestors<'ast>) {
+        visit_macro_rule(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_macro_rules(&mut self, i: &'ast MacroRules, ancestors: &mut Ancestors<'ast>) {
+        visit_macro_rules(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_macro_tree(&mut self, i: &'ast MacroTree, ancestors: &mut Ancestors<'ast>) {
+        visit_macro_tree(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_member(&mut self, i: &'ast Member, ancestors: &mut Ancestors<'ast>) {
+        visit_member(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_meta(&mut self, i: &'ast Meta, ancestors: &mut Ancestors<'ast>) {
+        visit_meta(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_meta_list(&mut self, i: &'ast MetaList, ancestors: &mut Ancestors<'ast>) {
+        visit_meta_list(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_meta_name_value(&mut self, i: &'ast MetaNameValue, ancestors: &mut Ancestors<'ast>) {
+        visit_meta_name_value(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_method_turbofish(
+        &mut self,
+        i: &'ast MethodTurbofish,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_method_turbofish(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_nested_meta(&mut self, i: &'ast NestedMeta, ancestors: &mut Ancestors<'ast>) {
+        visit_nested_meta(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_parenthesized_generic_arguments(
+        &mut self,
+        i: &'ast ParenthesizedGenericArguments,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_parenthesized_generic_arguments(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat(&mut self, i: &'ast Pat, ancestors: &mut Ancestors<'ast>) {
+        visit_pat(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_box(&mut self, i: &'ast PatBox, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_box(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_const(&mut self, i: &'ast PatConst, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_const(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_ident(&mut self, i: &'ast PatIdent, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_ident(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_lit(&mut self, i: &'ast PatLit, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_lit(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_macro(&mut self, i: &'ast PatMacro, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_macro(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_or(&mut self, i: &'ast PatOr, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_or(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_path(&mut self, i: &'ast PatPath, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_path(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_range(&mut self, i: &'ast PatRange, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_range(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_reference(&mut self, i: &'ast PatReference, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_reference(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_rest(&mut self, i: &'ast PatRest, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_rest(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_slice(&mut self, i: &'ast PatSlice, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_slice(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_struct(&mut self, i: &'ast PatStruct, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_struct(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_tuple(&mut self, i: &'ast PatTuple, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_tuple(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_tuple_struct(&mut self, i: &'ast PatTupleStruct, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_tuple_struct(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_type(&mut self, i: &'ast PatType, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_type(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_wild(&mut self, i: &'ast PatWild, ancestors: &mut Ancestors<'ast>) {
+        visit_pat_wild(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_path(&mut self, i: &'ast Path, ancestors: &mut Ancestors<'ast>) {
+        visit_path(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_path_arguments(&mut self, i: &'ast PathArguments, ancestors: &mut Ancestors<'ast>) {
+        visit_path_arguments(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_path_segment(&mut self, i: &'ast PathSegment, ancestors: &mut Ancestors<'ast>) {
+        visit_path_segment(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_pointer_mutability(
+        &mut self,
+        i: &'ast PointerMutability,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_pointer_mutability(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_predicate_eq(&mut self, i: &'ast PredicateEq, ancestors: &mut Ancestors<'ast>) {
+        visit_predicate_eq(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_predicate_lifetime(
+        &mut self,
+        i: &'ast PredicateLifetime,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_predicate_lifetime(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_predicate_type(&mut self, i: &'ast PredicateType, ancestors: &mut Ancestors<'ast>) {
+        visit_predicate_type(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_qself(&mut self, i: &'ast QSelf, ancestors: &mut Ancestors<'ast>) {
+        visit_qself(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_range_limits(&mut self, i: &'ast RangeLimits, ancestors: &mut Ancestors<'ast>) {
+        visit_range_limits(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_receiver(&mut self, i: &'ast Receiver, ancestors: &mut Ancestors<'ast>) {
+        visit_receiver(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_return_type(&mut self, i: &'ast ReturnType, ancestors: &mut Ancestors<'ast>) {
+        visit_return_type(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_signature(&mut self, i: &'ast Signature, ancestors: &mut Ancestors<'ast>) {
+        visit_signature(self, i, ancestors);
+    }
+    fn visit_span(&mut self, i: &Span, ancestors: &mut Ancestors<'ast>) {
+        visit_span(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_stmt(&mut self, i: &'ast Stmt, ancestors: &mut Ancestors<'ast>) {
+        visit_stmt(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_trait_bound(&mut self, i: &'ast TraitBound, ancestors: &mut Ancestors<'ast>) {
+        visit_trait_bound(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_trait_bound_modifier(
+        &mut self,
+        i: &'ast TraitBoundModifier,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_trait_bound_modifier(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item(&mut self, i: &'ast TraitItem, ancestors: &mut Ancestors<'ast>) {
+        visit_trait_item(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_const(&mut self, i: &'ast TraitItemConst, ancestors: &mut Ancestors<'ast>) {
+        visit_trait_item_const(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_macro(&mut self, i: &'ast TraitItemMacro, ancestors: &mut Ancestors<'ast>) {
+        visit_trait_item_macro(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_method(
+        &mut self,
+        i: &'ast TraitItemMethod,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_trait_item_method(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_type(&mut self, i: &'ast TraitItemType, ancestors: &mut Ancestors<'ast>) {
+        visit_trait_item_type(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type(&mut self, i: &'ast Type, ancestors: &mut Ancestors<'ast>) {
+        visit_type(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_array(&mut self, i: &'ast TypeArray, ancestors: &mut Ancestors<'ast>) {
+        visit_type_array(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_bare_fn(&mut self, i: &'ast TypeBareFn, ancestors: &mut Ancestors<'ast>) {
+        visit_type_bare_fn(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_group(&mut self, i: &'ast TypeGroup, ancestors: &mut Ancestors<'ast>) {
+        visit_type_group(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_impl_trait(&mut self, i: &'ast TypeImplTrait, ancestors: &mut Ancestors<'ast>) {
+        visit_type_impl_trait(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_infer(&mut self, i: &'ast TypeInfer, ancestors: &mut Ancestors<'ast>) {
+        visit_type_infer(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_macro(&mut self, i: &'ast TypeMacro, ancestors: &mut Ancestors<'ast>) {
+        visit_type_macro(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_never(&mut self, i: &'ast TypeNever, ancestors: &mut Ancestors<'ast>) {
+        visit_type_never(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_param(&mut self, i: &'ast TypeParam, ancestors: &mut Ancestors<'ast>) {
+        visit_type_param(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_param_bound(&mut self, i: &'ast TypeParamBound, ancestors: &mut Ancestors<'ast>) {
+        visit_type_param_bound(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_paren(&mut self, i: &'ast TypeParen, ancestors: &mut Ancestors<'ast>) {
+        visit_type_paren(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_path(&mut self, i: &'ast TypePath, ancestors: &mut Ancestors<'ast>) {
+        visit_type_path(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_ptr(&mut self, i: &'ast TypePtr, ancestors: &mut Ancestors<'ast>) {
+        visit_type_ptr(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_reference(&mut self, i: &'ast TypeReference, ancestors: &mut Ancestors<'ast>) {
+        visit_type_reference(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_slice(&mut self, i: &'ast TypeSlice, ancestors: &mut Ancestors<'ast>) {
+        visit_type_slice(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_trait_object(
+        &mut self,
+        i: &'ast TypeTraitObject,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        visit_type_trait_object(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_tuple(&mut self, i: &'ast TypeTuple, ancestors: &mut Ancestors<'ast>) {
+        visit_type_tuple(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_un_op(&mut self, i: &'ast UnOp, ancestors: &mut Ancestors<'ast>) {
+        visit_un_op(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_glob(&mut self, i: &'ast UseGlob, ancestors: &mut Ancestors<'ast>) {
+        visit_use_glob(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_group(&mut self, i: &'ast UseGroup, ancestors: &mut Ancestors<'ast>) {
+        visit_use_group(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_name(&mut self, i: &'ast UseName, ancestors: &mut Ancestors<'ast>) {
+        visit_use_name(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_path(&mut self, i: &'ast UsePath, ancestors: &mut Ancestors<'ast>) {
+        visit_use_path(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_rename(&mut self, i: &'ast UseRename, ancestors: &mut Ancestors<'ast>) {
+        visit_use_rename(self, i, ancestors);
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_tree(&mut self, i: &'ast UseTree, ancestors: &mut Ancestors<'ast>) {
+        visit_use_tree(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_variadic(&mut self, i: &'ast Variadic, ancestors: &mut Ancestors<'ast>) {
+        visit_variadic(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_variant(&mut self, i: &'ast Variant, ancestors: &mut Ancestors<'ast>) {
+        visit_variant(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_vis_crate(&mut self, i: &'ast VisCrate, ancestors: &mut Ancestors<'ast>) {
+        visit_vis_crate(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_vis_public(&mut self, i: &'ast VisPublic, ancestors: &mut Ancestors<'ast>) {
+        visit_vis_public(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_vis_restricted(&mut self, i: &'ast VisRestricted, ancestors: &mut Ancestors<'ast>) {
+        visit_vis_restricted(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_visibility(&mut self, i: &'ast Visibility, ancestors: &mut Ancestors<'ast>) {
+        visit_visibility(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_where_clause(&mut self, i: &'ast WhereClause, ancestors: &mut Ancestors<'ast>) {
+        visit_where_clause(self, i, ancestors);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_where_predicate(&mut self, i: &'ast WherePredicate, ancestors: &mut Ancestors<'ast>) {
+        visit_where_predicate(self, i, ancestors);
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_abi<'ast, V>(v: &mut V, node: &'ast Abi, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Abi(node));
+    ancestors.set_field("extern_token");
+    tokens_helper(v, &node.extern_token.span, ancestors);
+    ancestors.set_field("name");
+    if let Some(it) = &node.name {
+        v.visit_lit_str(it, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_angle_bracketed_generic_arguments<'ast, V>(
+    v: &mut V,
+    node: &'ast AngleBracketedGenericArguments,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::AngleBracketedGenericArguments(node));
+    ancestors.set_field("colon2_token");
+    if let Some(it) = &node.colon2_token {
+        tokens_helper(v, &it.spans, ancestors);
+    };
+    ancestors.set_field("lt_token");
+    tokens_helper(v, &node.lt_token.spans, ancestors);
+    ancestors.set_field("args");
+    for (i, el) in Punctuated::pairs(&node.args).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_generic_argument(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.set_field("gt_token");
+    tokens_helper(v, &node.gt_token.spans, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_arm<'ast, V>(v: &mut V, node: &'ast Arm, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Arm(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("pat");
+    v.visit_pat(&node.pat, ancestors);
+    ancestors.set_field("guard");
+    if let Some(it) = &node.guard {
+        tokens_helper(v, &(it).0.span, ancestors);
+        v.visit_expr(&*(it).1, ancestors);
+    };
+    ancestors.set_field("fat_arrow_token");
+    tokens_helper(v, &node.fat_arrow_token.spans, ancestors);
+    ancestors.set_field("body");
+    v.visit_expr(&*node.body, ancestors);
+    ancestors.set_field("comma");
+    if let Some(it) = &node.comma {
+        tokens_helper(v, &it.spans, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_attr_meta<'ast, V>(v: &mut V, node: &'ast AttrMeta, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::AttrMeta(node));
+    match node {
+        AttrMeta::Path(_binding_0) => {
+            ancestors.set_field("Path");
+            v.visit_path(_binding_0, ancestors);
+        }
+        AttrMeta::List(_binding_0) => {
+            ancestors.set_field("List");
+            v.visit_attr_meta_list(_binding_0, ancestors);
+        }
+        AttrMeta::NameValue(_binding_0) => {
+            ancestors.set_field("NameValue");
+            v.visit_attr_meta_name_value(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_attr_meta_list<'ast, V>(
+    v: &mut V,
+    node: &'ast AttrMetaList,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::AttrMetaList(node));
+    ancestors.set_field("path");
+    v.visit_path(&node.path, ancestors);
+    ancestors.set_field("delimiter");
+    v.visit_macro_delimiter(&node.delimiter, ancestors);
+    skip!(node.tokens);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_attr_meta_name_value<'ast, V>(
+    v: &mut V,
+    node: &'ast AttrMetaNameValue,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::AttrMetaNameValue(node));
+    ancestors.set_field("path");
+    v.visit_path(&node.path, ancestors);
+    ancestors.set_field("eq_token");
+    tokens_helper(v, &node.eq_token.spans, ancestors);
+    ancestors.set_field("value");
+    v.visit_expr(&node.value, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_attr_style<'ast, V>(v: &mut V, node: &'ast AttrStyle, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::AttrStyle(node));
+    match node {
+        AttrStyle::Outer => {}
+        AttrStyle::Inner(_binding_0) => {
+            ancestors.set_field("Inner");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_attribute<'ast, V>(v: &mut V, node: &'ast Attribute, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Attribute(node));
+    ancestors.set_field("pound_token");
+    tokens_helper(v, &node.pound_token.spans, ancestors);
+    ancestors.set_field("style");
+    v.visit_attr_style(&node.style, ancestors);
+    ancestors.set_field("bracket_token");
+    tokens_helper(v, &node.bracket_token.span, ancestors);
+    ancestors.set_field("path");
+    v.visit_path(&node.path, ancestors);
+    skip!(node.tokens);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_bare_fn_arg<'ast, V>(v: &mut V, node: &'ast BareFnArg, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::BareFnArg(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("name");
+    if let Some(it) = &node.name {
+        v.visit_ident(&(it).0, ancestors);
+        tokens_helper(v, &(it).1.spans, ancestors);
+    };
+    ancestors.set_field("ty");
+    v.visit_type(&node.ty, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_bin_op<'ast, V>(v: &mut V, node: &'ast BinOp, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::BinOp(node));
+    match node {
+        BinOp::Add(_binding_0) => {
+            ancestors.set_field("Add");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        BinOp::Sub(_binding_0) => {
+            ancestors.set_field("Sub");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        BinOp::Mul(_binding_0) => {
+            ancestors.set_field("Mul");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        BinOp::Div(_binding_0) => {
+            ancestors.set_field("Div");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        BinOp::Rem(_binding_0) => {
+            ancestors.set_field("Rem");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        BinOp::And(_binding_0) => {
+            ancestors.set_field("And");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        BinOp::Or(_binding_0) => {
+            ancestors.set_field("Or");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        BinOp::BitXor(_binding_0) => {
+            ancestors.set_field("BitXor");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        BinOp::BitAnd(_binding_0) => {
+            ancestors.set_field("BitAnd");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        BinOp::BitOr(_binding_0) => {
+            ancestors.set_field("BitOr");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        BinOp::Shl(_binding_0) => {
+            ancestors.set_field("Shl");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        BinOp::Shr(_binding_0) => {
+            ancestors.set_field("Shr");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        BinOp::Eq(_binding_0) => {
+            ancestors.set_field("Eq");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        BinOp::Lt(_binding_0) => {
+            ancestors.set_field("Lt");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        BinOp::Le(_binding_0) => {
+            ancestors.set_field("Le");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        BinOp::Ne(_binding_0) => {
+            ancestors.set_field("Ne");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        BinOp::Ge(_binding_0) => {
+            ancestors.set_field("Ge");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        BinOp::Gt(_binding_0) => {
+            ancestors.set_field("Gt");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        BinOp::AddEq(_binding_0) => {
+            ancestors.set_field("AddEq");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        BinOp::SubEq(_binding_0) => {
+            ancestors.set_field("SubEq");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        BinOp::MulEq(_binding_0) => {
+            ancestors.set_field("MulEq");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        BinOp::DivEq(_binding_0) => {
+            ancestors.set_field("DivEq");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        BinOp::RemEq(_binding_0) => {
+            ancestors.set_field("RemEq");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        BinOp::BitXorEq(_binding_0) => {
+            ancestors.set_field("BitXorEq");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        BinOp::BitAndEq(_binding_0) => {
+            ancestors.set_field("BitAndEq");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        BinOp::BitOrEq(_binding_0) => {
+            ancestors.set_field("BitOrEq");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        BinOp::ShlEq(_binding_0) => {
+            ancestors.set_field("ShlEq");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        BinOp::ShrEq(_binding_0) => {
+            ancestors.set_field("ShrEq");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_binding<'ast, V>(v: &mut V, node: &'ast Binding, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Binding(node));
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.set_field("eq_token");
+    tokens_helper(v, &node.eq_token.spans, ancestors);
+    ancestors.set_field("ty");
+    v.visit_type(&node.ty, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_block<'ast, V>(v: &mut V, node: &'ast Block, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Block(node));
+    ancestors.set_field("brace_token");
+    tokens_helper(v, &node.brace_token.span, ancestors);
+    ancestors.set_field("stmts");
+    for (i, it) in node.stmts.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_stmt(it, ancestors);
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_bound_lifetimes<'ast, V>(
+    v: &mut V,
+    node: &'ast BoundLifetimes,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::BoundLifetimes(node));
+    ancestors.set_field("for_token");
+    tokens_helper(v, &node.for_token.span, ancestors);
+    ancestors.set_field("lt_token");
+    tokens_helper(v, &node.lt_token.spans, ancestors);
+    ancestors.set_field("lifetimes");
+    for (i, el) in Punctuated::pairs(&node.lifetimes).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_lifetime_def(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.set_field("gt_token");
+    tokens_helper(v, &node.gt_token.spans, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_const_param<'ast, V>(
+    v: &mut V,
+    node: &'ast ConstParam,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ConstParam(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("const_token");
+    tokens_helper(v, &node.const_token.span, ancestors);
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.set_field("colon_token");
+    tokens_helper(v, &node.colon_token.spans, ancestors);
+    ancestors.set_field("ty");
+    v.visit_type(&node.ty, ancestors);
+    ancestors.set_field("eq_token");
+    if let Some(it) = &node.eq_token {
+        tokens_helper(v, &it.spans, ancestors);
+    };
+    ancestors.set_field("default");
+    if let Some(it) = &node.default {
+        v.visit_expr(it, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_constraint<'ast, V>(v: &mut V, node: &'ast Constraint, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Constraint(node));
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.set_field("colon_token");
+    tokens_helper(v, &node.colon_token.spans, ancestors);
+    ancestors.set_field("bounds");
+    for (i, el) in Punctuated::pairs(&node.bounds).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_type_param_bound(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "derive")]
+pub fn visit_data<'ast, V>(v: &mut V, node: &'ast Data, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Data(node));
+    match node {
+        Data::Struct(_binding_0) => {
+            ancestors.set_field("Struct");
+            v.visit_data_struct(_binding_0, ancestors);
+        }
+        Data::Enum(_binding_0) => {
+            ancestors.set_field("Enum");
+            v.visit_data_enum(_binding_0, ancestors);
+        }
+        Data::Union(_binding_0) => {
+            ancestors.set_field("Union");
+            v.visit_data_union(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "derive")]
+pub fn visit_data_enum<'ast, V>(v: &mut V, node: &'ast DataEnum, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::DataEnum(node));
+    ancestors.set_field("enum_token");
+    tokens_helper(v, &node.enum_token.span, ancestors);
+    ancestors.set_field("brace_token");
+    tokens_helper(v, &node.brace_token.span, ancestors);
+    ancestors.set_field("variants");
+    for (i, el) in Punctuated::pairs(&node.variants).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_variant(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "derive")]
+pub fn visit_data_struct<'ast, V>(
+    v: &mut V,
+    node: &'ast DataStruct,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::DataStruct(node));
+    ancestors.set_field("struct_token");
+    tokens_helper(v, &node.struct_token.span, ancestors);
+    ancestors.set_field("fields");
+    v.visit_fields(&node.fields, ancestors);
+    ancestors.set_field("semi_token");
+    if let Some(it) = &node.semi_token {
+        tokens_helper(v, &it.spans, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(feature = "derive")]
+pub fn visit_data_union<'ast, V>(v: &mut V, node: &'ast DataUnion, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::DataUnion(node));
+    ancestors.set_field("union_token");
+    tokens_helper(v, &node.union_token.span, ancestors);
+    ancestors.set_field("fields");
+    v.visit_fields_named(&node.fields, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "derive")]
+pub fn visit_derive_input<'ast, V>(
+    v: &mut V,
+    node: &'ast DeriveInput,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::DeriveInput(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("vis");
+    v.visit_visibility(&node.vis, ancestors);
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.set_field("generics");
+    v.visit_generics(&node.generics, ancestors);
+    ancestors.set_field("data");
+    v.visit_data(&node.data, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr<'ast, V>(v: &mut V, node: &'ast Expr, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Expr(node));
+    match node {
+        Expr::Array(_binding_0) => {
+            ancestors.set_field("Array");
+            full!(v.visit_expr_array(_binding_0, ancestors));
+        }
+        Expr::Assign(_binding_0) => {
+            ancestors.set_field("Assign");
+            full!(v.visit_expr_assign(_binding_0, ancestors));
+        }
+        Expr::AssignOp(_binding_0) => {
+            ancestors.set_field("AssignOp");
+            full!(v.visit_expr_assign_op(_binding_0, ancestors));
+        }
+        Expr::Async(_binding_0) => {
+            ancestors.set_field("Async");
+            full!(v.visit_expr_async(_binding_0, ancestors));
+        }
+        Expr::Await(_binding_0) => {
+            ancestors.set_field("Await");
+            full!(v.visit_expr_await(_binding_0, ancestors));
+        }
+        Expr::Binary(_binding_0) => {
+            ancestors.set_field("Binary");
+            v.visit_expr_binary(_binding_0, ancestors);
+        }
+        Expr::Block(_binding_0) => {
+            ancestors.set_field("Block");
+            full!(v.visit_expr_block(_binding_0, ancestors));
+        }
+        Expr::Box(_binding_0) => {
+            ancestors.set_field("Box");
+            full!(v.visit_expr_box(_binding_0, ancestors));
+        }
+        Expr::Break(_binding_0) => {
+            ancestors.set_field("Break");
+            full!(v.visit_expr_break(_binding_0, ancestors));
+        }
+        Expr::Call(_binding_0) => {
+            ancestors.set_field("Call");
+            v.visit_expr_call(_binding_0, ancestors);
+        }
+        Expr::Cast(_binding_0) => {
+            ancestors.set_field("Cast");
+            v.visit_expr_cast(_binding_0, ancestors);
+        }
+        Expr::Closure(_binding_0) => {
+            ancestors.set_field("Closure");
+            full!(v.visit_expr_closure(_binding_0, ancestors));
+        }
+        Expr::Const(_binding_0) => {
+            ancestors.set_field("Const");
+            full!(v.visit_expr_const(_binding_0, ancestors));
+        }
+        Expr::Continue(_binding_0) => {
+            ancestors.set_field("Continue");
+            full!(v.visit_expr_continue(_binding_0, ancestors));
+        }
+        Expr::Field(_binding_0) => {
+            ancestors.set_field("Field");
+            v.visit_expr_field(_binding_0, ancestors);
+        }
+        Expr::ForLoop(_binding_0) => {
+            ancestors.set_field("ForLoop");
+            full!(v.visit_expr_for_loop(_binding_0, ancestors));
+        }
+        Expr::Group(_binding_0) => {
+            ancestors.set_field("Group");
+            full!(v.visit_expr_group(_binding_0, ancestors));
+        }
+        Expr::If(_binding_0) => {
+            ancestors.set_field("If");
+            full!(v.visit_expr_if(_binding_0, ancestors));
+        }
+        Expr::Index(_binding_0) => {
+            ancestors.set_field("Index");
+            v.visit_expr_index(_binding_0, ancestors);
+        }
+        Expr::Let(_binding_0) => {
+            ancestors.set_field("Let");
+            full!(v.visit_expr_let(_binding_0, ancestors));
+        }
+        Expr::Lit(_binding_0) => {
+            ancestors.set_field("Lit");
+            v.visit_expr_lit(_binding_0, ancestors);
+        }
+        Expr::Loop(_binding_0) => {
+            ancestors.set_field("Loop");
+            full!(v.visit_expr_loop(_binding_0, ancestors));
+        }
+        Expr::Macro(_binding_0) => {
+            ancestors.set_field("Macro");
+            full!(v.visit_expr_macro(_binding_0, ancestors));
+        }
+        Expr::Match(_binding_0) => {
+            ancestors.set_field("Match");
+            full!(v.visit_expr_match(_binding_0, ancestors));
+        }
+        Expr::MethodCall(_binding_0) => {
+            ancestors.set_field("MethodCall");
+            full!(v.visit_expr_method_call(_binding_0, ancestors));
+        }
+        Expr::Paren(_binding_0) => {
+            ancestors.set_field("Paren");
+            v.visit_expr_paren(_binding_0, ancestors);
+        }
+        Expr::Path(_binding_0) => {
+            ancestors.set_field("Path");
+            v.visit_expr_path(_binding_0, ancestors);
+        }
+        Expr::Range(_binding_0) => {
+            ancestors.set_field("Range");
+            full!(v.visit_expr_range(_binding_0, ancestors));
+        }
+        Expr::RawAddr(_binding_0) => {
+            ancestors.set_field("RawAddr");
+            full!(v.visit_expr_raw_addr(_binding_0, ancestors));
+        }
+        Expr::Reference(_binding_0) => {
+            ancestors.set_field("Reference");
+            full!(v.visit_expr_reference(_binding_0, ancestors));
+        }
+        Expr::Repeat(_binding_0) => {
+            ancestors.set_field("Repeat");
+            full!(v.visit_expr_repeat(_binding_0, ancestors));
+        }
+        Expr::Return(_binding_0) => {
+            ancestors.set_field("Return");
+            full!(v.visit_expr_return(_binding_0, ancestors));
+        }
+        Expr::Struct(_binding_0) => {
+            ancestors.set_field("Struct");
+            full!(v.visit_expr_struct(_binding_0, ancestors));
+        }
+        Expr::Try(_binding_0) => {
+            ancestors.set_field("Try");
+            full!(v.visit_expr_try(_binding_0, ancestors));
+        }
+        Expr::TryBlock(_binding_0) => {
+            ancestors.set_field("TryBlock");
+            full!(v.visit_expr_try_block(_binding_0, ancestors));
+        }
+        Expr::Tuple(_binding_0) => {
+            ancestors.set_field("Tuple");
+            full!(v.visit_expr_tuple(_binding_0, ancestors));
+        }
+        Expr::Type(_binding_0) => {
+            ancestors.set_field("Type");
+            full!(v.visit_expr_type(_binding_0, ancestors));
+        }
+        Expr::Unary(_binding_0) => {
+            ancestors.set_field("Unary");
+            v.visit_expr_unary(_binding_0, ancestors);
+        }
+        Expr::Unsafe(_binding_0) => {
+            ancestors.set_field("Unsafe");
+            full!(v.visit_expr_unsafe(_binding_0, ancestors));
+        }
+        Expr::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        Expr::While(_binding_0) => {
+            ancestors.set_field("While");
+            full!(v.visit_expr_while(_binding_0, ancestors));
+        }
+        Expr::Yield(_binding_0) => {
+            ancestors.set_field("Yield");
+            full!(v.visit_expr_yield(_binding_0, ancestors));
+        }
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_array<'ast, V>(v: &mut V, node: &'ast ExprArray, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprArray(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("bracket_token");
+    tokens_helper(v, &node.bracket_token.span, ancestors);
+    ancestors.set_field("elems");
+    for (i, el) in Punctuated::pairs(&node.elems).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_expr(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_assign<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprAssign,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprAssign(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("left");
+    v.visit_expr(&*node.left, ancestors);
+    ancestors.set_field("eq_token");
+    tokens_helper(v, &node.eq_token.spans, ancestors);
+    ancestors.set_field("right");
+    v.visit_expr(&*node.right, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_assign_op<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprAssignOp,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprAssignOp(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("left");
+    v.visit_expr(&*node.left, ancestors);
+    ancestors.set_field("op");
+    v.visit_bin_op(&node.op, ancestors);
+    ancestors.set_field("right");
+    v.visit_expr(&*node.right, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_async<'ast, V>(v: &mut V, node: &'ast ExprAsync, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprAsync(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("async_token");
+    tokens_helper(v, &node.async_token.span, ancestors);
+    ancestors.set_field("capture");
+    if let Some(it) = &node.capture {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("block");
+    v.visit_block(&node.block, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_await<'ast, V>(v: &mut V, node: &'ast ExprAwait, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprAwait(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("base");
+    v.visit_expr(&*node.base, ancestors);
+    ancestors.set_field("dot_token");
+    tokens_helper(v, &node.dot_token.spans, ancestors);
+    ancestors.set_field("await_token");
+    tokens_helper(v, &node.await_token.span, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr_binary<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprBinary,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprBinary(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("left");
+    v.visit_expr(&*node.left, ancestors);
+    ancestors.set_field("op");
+    v.visit_bin_op(&node.op, ancestors);
+    ancestors.set_field("right");
+    v.visit_expr(&*node.right, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_block<'ast, V>(v: &mut V, node: &'ast ExprBlock, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprBlock(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("label");
+    if let Some(it) = &node.label {
+        v.visit_label(it, ancestors);
+    };
+    ancestors.set_field("block");
+    v.visit_block(&node.block, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_box<'ast, V>(v: &mut V, node: &'ast ExprBox, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprBox(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("box_token");
+    tokens_helper(v, &node.box_token.span, ancestors);
+    ancestors.set_field("expr");
+    v.visit_expr(&*node.expr, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_break<'ast, V>(v: &mut V, node: &'ast ExprBreak, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprBreak(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("break_token");
+    tokens_helper(v, &node.break_token.span, ancestors);
+    ancestors.set_field("label");
+    if let Some(it) = &node.label {
+        v.visit_lifetime(it, ancestors);
+    };
+    ancestors.set_field("expr");
+    if let Some(it) = &node.expr {
+        v.visit_expr(&**it, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr_call<'ast, V>(v: &mut V, node: &'ast ExprCall, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprCall(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("func");
+    v.visit_expr(&*node.func, ancestors);
+    ancestors.set_field("paren_token");
+    tokens_helper(v, &node.paren_token.span, ancestors);
+    ancestors.set_field("args");
+    for (i, el) in Punctuated::pairs(&node.args).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_expr(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr_cast<'ast, V>(v: &mut V, node: &'ast ExprCast, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprCast(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("expr");
+    v.visit_expr(&*node.expr, ancestors);
+    ancestors.set_field("as_token");
+    tokens_helper(v, &node.as_token.span, ancestors);
+    ancestors.set_field("ty");
+    v.visit_type(&*node.ty, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_closure<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprClosure,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprClosure(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("asyncness");
+    if let Some(it) = &node.asyncness {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("movability");
+    if let Some(it) = &node.movability {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("capture");
+    if let Some(it) = &node.capture {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("or1_token");
+    tokens_helper(v, &node.or1_token.spans, ancestors);
+    ancestors.set_field("inputs");
+    for (i, el) in Punctuated::pairs(&node.inputs).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_pat(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.set_field("or2_token");
+    tokens_helper(v, &node.or2_token.spans, ancestors);
+    ancestors.set_field("output");
+    v.visit_return_type(&node.output, ancestors);
+    ancestors.set_field("body");
+    v.visit_expr(&*node.body, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_const<'ast, V>(v: &mut V, node: &'ast ExprConst, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprConst(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("const_token");
+    tokens_helper(v, &node.const_token.span, ancestors);
+    ancestors.set_field("block");
+    v.visit_block(&node.block, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_continue<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprContinue,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprContinue(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("continue_token");
+    tokens_helper(v, &node.continue_token.span, ancestors);
+    ancestors.set_field("label");
+    if let Some(it) = &node.label {
+        v.visit_lifetime(it, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr_field<'ast, V>(v: &mut V, node: &'ast ExprField, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprField(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("base");
+    v.visit_expr(&*node.base, ancestors);
+    ancestors.set_field("dot_token");
+    tokens_helper(v, &node.dot_token.spans, ancestors);
+    ancestors.set_field("member");
+    v.visit_member(&node.member, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_for_loop<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprForLoop,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprForLoop(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("label");
+    if let Some(it) = &node.label {
+        v.visit_label(it, ancestors);
+    };
+    ancestors.set_field("for_token");
+    tokens_helper(v, &node.for_token.span, ancestors);
+    ancestors.set_field("pat");
+    v.visit_pat(&node.pat, ancestors);
+    ancestors.set_field("in_token");
+    tokens_helper(v, &node.in_token.span, ancestors);
+    ancestors.set_field("expr");
+    v.visit_expr(&*node.expr, ancestors);
+    ancestors.set_field("body");
+    v.visit_block(&node.body, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_group<'ast, V>(v: &mut V, node: &'ast ExprGroup, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprGroup(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("group_token");
+    tokens_helper(v, &node.group_token.span, ancestors);
+    ancestors.set_field("expr");
+    v.visit_expr(&*node.expr, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_if<'ast, V>(v: &mut V, node: &'ast ExprIf, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprIf(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("if_token");
+    tokens_helper(v, &node.if_token.span, ancestors);
+    ancestors.set_field("cond");
+    v.visit_expr(&*node.cond, ancestors);
+    ancestors.set_field("then_branch");
+    v.visit_block(&node.then_branch, ancestors);
+    ancestors.set_field("else_branch");
+    if let Some(it) = &node.else_branch {
+        tokens_helper(v, &(it).0.span, ancestors);
+        v.visit_expr(&*(it).1, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr_index<'ast, V>(v: &mut V, node: &'ast ExprIndex, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprIndex(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("expr");
+    v.visit_expr(&*node.expr, ancestors);
+    ancestors.set_field("bracket_token");
+    tokens_helper(v, &node.bracket_token.span, ancestors);
+    ancestors.set_field("index");
+    v.visit_expr(&*node.index, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_let<'ast, V>(v: &mut V, node: &'ast ExprLet, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprLet(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("let_token");
+    tokens_helper(v, &node.let_token.span, ancestors);
+    ancestors.set_field("pat");
+    v.visit_pat(&node.pat, ancestors);
+    ancestors.set_field("eq_token");
+    tokens_helper(v, &node.eq_token.spans, ancestors);
+    ancestors.set_field("expr");
+    v.visit_expr(&*node.expr, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr_lit<'ast, V>(v: &mut V, node: &'ast ExprLit, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprLit(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("lit");
+    v.visit_lit(&node.lit, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_loop<'ast, V>(v: &mut V, node: &'ast ExprLoop, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprLoop(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("label");
+    if let Some(it) = &node.label {
+        v.visit_label(it, ancestors);
+    };
+    ancestors.set_field("loop_token");
+    tokens_helper(v, &node.loop_token.span, ancestors);
+    ancestors.set_field("body");
+    v.visit_block(&node.body, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_macro<'ast, V>(v: &mut V, node: &'ast ExprMacro, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprMacro(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("mac");
+    v.visit_macro(&node.mac, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_match<'ast, V>(v: &mut V, node: &'ast ExprMatch, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprMatch(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("match_token");
+    tokens_helper(v, &node.match_token.span, ancestors);
+    ancestors.set_field("expr");
+    v.visit_expr(&*node.expr, ancestors);
+    ancestors.set_field("brace_token");
+    tokens_helper(v, &node.brace_token.span, ancestors);
+    ancestors.set_field("arms");
+    for (i, it) in node.arms.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_arm(it, ancestors);
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_method_call<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprMethodCall,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprMethodCall(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("receiver");
+    v.visit_expr(&*node.receiver, ancestors);
+    ancestors.set_field("dot_token");
+    tokens_helper(v, &node.dot_token.spans, ancestors);
+    ancestors.set_field("method");
+    v.visit_ident(&node.method, ancestors);
+    ancestors.set_field("turbofish");
+    if let Some(it) = &node.turbofish {
+        v.visit_method_turbofish(it, ancestors);
+    };
+    ancestors.set_field("paren_token");
+    tokens_helper(v, &node.paren_token.span, ancestors);
+    ancestors.set_field("args");
+    for (i, el) in Punctuated::pairs(&node.args).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_expr(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr_paren<'ast, V>(v: &mut V, node: &'ast ExprParen, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprParen(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("paren_token");
+    tokens_helper(v, &node.paren_token.span, ancestors);
+    ancestors.set_field("expr");
+    v.visit_expr(&*node.expr, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr_path<'ast, V>(v: &mut V, node: &'ast ExprPath, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprPath(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("qself");
+    if let Some(it) = &node.qself {
+        v.visit_qself(it, ancestors);
+    };
+    ancestors.set_field("path");
+    v.visit_path(&node.path, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_range<'ast, V>(v: &mut V, node: &'ast ExprRange, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprRange(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("from");
+    if let Some(it) = &node.from {
+        v.visit_expr(&**it, ancestors);
+    };
+    ancestors.set_field("limits");
+    v.visit_range_limits(&node.limits, ancestors);
+    ancestors.set_field("to");
+    if let Some(it) = &node.to {
+        v.visit_expr(&**it, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_raw_addr<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprRawAddr,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprRawAddr(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("and_token");
+    tokens_helper(v, &node.and_token.spans, ancestors);
+    ancestors.set_field("raw");
+    tokens_helper(v, &node.raw.span, ancestors);
+    ancestors.set_field("mutability");
+    v.visit_pointer_mutability(&node.mutability, ancestors);
+    ancestors.set_field("expr");
+    v.visit_expr(&*node.expr, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_reference<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprReference,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprReference(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("and_token");
+    tokens_helper(v, &node.and_token.spans, ancestors);
+    ancestors.set_field("mutability");
+    if let Some(it) = &node.mutability {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("expr");
+    v.visit_expr(&*node.expr, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_repeat<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprRepeat,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprRepeat(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("bracket_token");
+    tokens_helper(v, &node.bracket_token.span, ancestors);
+    ancestors.set_field("expr");
+    v.visit_expr(&*node.expr, ancestors);
+    ancestors.set_field("semi_token");
+    tokens_helper(v, &node.semi_token.spans, ancestors);
+    ancestors.set_field("len");
+    v.visit_expr(&*node.len, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_return<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprReturn,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprReturn(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("return_token");
+    tokens_helper(v, &node.return_token.span, ancestors);
+    ancestors.set_field("expr");
+    if let Some(it) = &node.expr {
+        v.visit_expr(&**it, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_struct<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprStruct,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprStruct(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("path");
+    v.visit_path(&node.path, ancestors);
+    ancestors.set_field("brace_token");
+    tokens_helper(v, &node.brace_token.span, ancestors);
+    ancestors.set_field("fields");
+    for (i, el) in Punctuated::pairs(&node.fields).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_field_value(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.set_field("dot2_token");
+    if let Some(it) = &node.dot2_token {
+        tokens_helper(v, &it.spans, ancestors);
+    };
+    ancestors.set_field("rest");
+    if let Some(it) = &node.rest {
+        v.visit_expr(&**it, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_try<'ast, V>(v: &mut V, node: &'ast ExprTry, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprTry(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("expr");
+    v.visit_expr(&*node.expr, ancestors);
+    ancestors.set_field("question_token");
+    tokens_helper(v, &node.question_token.spans, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_try_block<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprTryBlock,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprTryBlock(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("try_token");
+    tokens_helper(v, &node.try_token.span, ancestors);
+    ancestors.set_field("block");
+    v.visit_block(&node.block, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_tuple<'ast, V>(v: &mut V, node: &'ast ExprTuple, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprTuple(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("paren_token");
+    tokens_helper(v, &node.paren_token.span, ancestors);
+    ancestors.set_field("elems");
+    for (i, el) in Punctuated::pairs(&node.elems).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_expr(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_type<'ast, V>(v: &mut V, node: &'ast ExprType, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprType(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("expr");
+    v.visit_expr(&*node.expr, ancestors);
+    ancestors.set_field("colon_token");
+    tokens_helper(v, &node.colon_token.spans, ancestors);
+    ancestors.set_field("ty");
+    v.visit_type(&*node.ty, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_expr_unary<'ast, V>(v: &mut V, node: &'ast ExprUnary, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprUnary(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("op");
+    v.visit_un_op(&node.op, ancestors);
+    ancestors.set_field("expr");
+    v.visit_expr(&*node.expr, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_unsafe<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprUnsafe,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprUnsafe(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("unsafe_token");
+    tokens_helper(v, &node.unsafe_token.span, ancestors);
+    ancestors.set_field("block");
+    v.visit_block(&node.block, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_while<'ast, V>(v: &mut V, node: &'ast ExprWhile, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprWhile(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("label");
+    if let Some(it) = &node.label {
+        v.visit_label(it, ancestors);
+    };
+    ancestors.set_field("while_token");
+    tokens_helper(v, &node.while_token.span, ancestors);
+    ancestors.set_field("cond");
+    v.visit_expr(&*node.cond, ancestors);
+    ancestors.set_field("body");
+    v.visit_block(&node.body, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_expr_yield<'ast, V>(v: &mut V, node: &'ast ExprYield, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ExprYield(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("yield_token");
+    tokens_helper(v, &node.yield_token.span, ancestors);
+    ancestors.set_field("expr");
+    if let Some(it) = &node.expr {
+        v.visit_expr(&**it, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_field<'ast, V>(v: &mut V, node: &'ast Field, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Field(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("vis");
+    v.visit_visibility(&node.vis, ancestors);
+    ancestors.set_field("ident");
+    if let Some(it) = &node.ident {
+        v.visit_ident(it, ancestors);
+    };
+    ancestors.set_field("colon_token");
+    if let Some(it) = &node.colon_token {
+        tokens_helper(v, &it.spans, ancestors);
+    };
+    ancestors.set_field("ty");
+    v.visit_type(&node.ty, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_field_pat<'ast, V>(v: &mut V, node: &'ast FieldPat, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::FieldPat(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("member");
+    v.visit_member(&node.member, ancestors);
+    ancestors.set_field("colon_token");
+    if let Some(it) = &node.colon_token {
+        tokens_helper(v, &it.spans, ancestors);
+    };
+    ancestors.set_field("pat");
+    v.visit_pat(&*node.pat, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_field_value<'ast, V>(
+    v: &mut V,
+    node: &'ast FieldValue,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::FieldValue(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("member");
+    v.visit_member(&node.member, ancestors);
+    ancestors.set_field("colon_token");
+    if let Some(it) = &node.colon_token {
+        tokens_helper(v, &it.spans, ancestors);
+    };
+    ancestors.set_field("expr");
+    v.visit_expr(&node.expr, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_fields<'ast, V>(v: &mut V, node: &'ast Fields, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Fields(node));
+    match node {
+        Fields::Named(_binding_0) => {
+            ancestors.set_field("Named");
+            v.visit_fields_named(_binding_0, ancestors);
+        }
+        Fields::Unnamed(_binding_0) => {
+            ancestors.set_field("Unnamed");
+            v.visit_fields_unnamed(_binding_0, ancestors);
+        }
+        Fields::Unit => {}
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_fields_named<'ast, V>(
+    v: &mut V,
+    node: &'ast FieldsNamed,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::FieldsNamed(node));
+    ancestors.set_field("brace_token");
+    tokens_helper(v, &node.brace_token.span, ancestors);
+    ancestors.set_field("named");
+    for (i, el) in Punctuated::pairs(&node.named).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_field(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_fields_unnamed<'ast, V>(
+    v: &mut V,
+    node: &'ast FieldsUnnamed,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::FieldsUnnamed(node));
+    ancestors.set_field("paren_token");
+    tokens_helper(v, &node.paren_token.span, ancestors);
+    ancestors.set_field("unnamed");
+    for (i, el) in Punctuated::pairs(&node.unnamed).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_field(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_file<'ast, V>(v: &mut V, node: &'ast File, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::File(node));
+    skip!(node.shebang);
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("items");
+    for (i, it) in node.items.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_item(it, ancestors);
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_fn_arg<'ast, V>(v: &mut V, node: &'ast FnArg, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::FnArg(node));
+    match node {
+        FnArg::Receiver(_binding_0) => {
+            ancestors.set_field("Receiver");
+            v.visit_receiver(_binding_0, ancestors);
+        }
+        FnArg::Typed(_binding_0) => {
+            ancestors.set_field("Typed");
+            v.visit_pat_type(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_foreign_item<'ast, V>(
+    v: &mut V,
+    node: &'ast ForeignItem,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ForeignItem(node));
+    match node {
+        ForeignItem::Fn(_binding_0) => {
+            ancestors.set_field("Fn");
+            v.visit_foreign_item_fn(_binding_0, ancestors);
+        }
+        ForeignItem::Static(_binding_0) => {
+            ancestors.set_field("Static");
+            v.visit_foreign_item_static(_binding_0, ancestors);
+        }
+        ForeignItem::Type(_binding_0) => {
+            ancestors.set_field("Type");
+            v.visit_foreign_item_type(_binding_0, ancestors);
+        }
+        ForeignItem::Macro(_binding_0) => {
+            ancestors.set_field("Macro");
+            v.visit_foreign_item_macro(_binding_0, ancestors);
+        }
+        ForeignItem::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_foreign_item_fn<'ast, V>(
+    v: &mut V,
+    node: &'ast ForeignItemFn,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ForeignItemFn(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("vis");
+    v.visit_visibility(&node.vis, ancestors);
+    ancestors.set_field("sig");
+    v.visit_signature(&node.sig, ancestors);
+    ancestors.set_field("semi_token");
+    tokens_helper(v, &node.semi_token.spans, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_foreign_item_macro<'ast, V>(
+    v: &mut V,
+    node: &'ast ForeignItemMacro,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ForeignItemMacro(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("mac");
+    v.visit_macro(&node.mac, ancestors);
+    ancestors.set_field("semi_token");
+    if let Some(it) = &node.semi_token {
+        tokens_helper(v, &it.spans, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_foreign_item_static<'ast, V>(
+    v: &mut V,
+    node: &'ast ForeignItemStatic,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ForeignItemStatic(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("vis");
+    v.visit_visibility(&node.vis, ancestors);
+    ancestors.set_field("static_token");
+    tokens_helper(v, &node.static_token.span, ancestors);
+    ancestors.set_field("mutability");
+    if let Some(it) = &node.mutability {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.set_field("colon_token");
+    tokens_helper(v, &node.colon_token.spans, ancestors);
+    ancestors.set_field("ty");
+    v.visit_type(&*node.ty, ancestors);
+    ancestors.set_field("semi_token");
+    tokens_helper(v, &node.semi_token.spans, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_foreign_item_type<'ast, V>(
+    v: &mut V,
+    node: &'ast ForeignItemType,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ForeignItemType(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("vis");
+    v.visit_visibility(&node.vis, ancestors);
+    ancestors.set_field("type_token");
+    tokens_helper(v, &node.type_token.span, ancestors);
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.set_field("semi_token");
+    tokens_helper(v, &node.semi_token.spans, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_generic_argument<'ast, V>(
+    v: &mut V,
+    node: &'ast GenericArgument,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::GenericArgument(node));
+    match node {
+        GenericArgument::Lifetime(_binding_0) => {
+            ancestors.set_field("Lifetime");
+            v.visit_lifetime(_binding_0, ancestors);
+        }
+        GenericArgument::Type(_binding_0) => {
+            ancestors.set_field("Type");
+            v.visit_type(_binding_0, ancestors);
+        }
+        GenericArgument::Binding(_binding_0) => {
+            ancestors.set_field("Binding");
+            v.visit_binding(_binding_0, ancestors);
+        }
+        GenericArgument::Constraint(_binding_0) => {
+            ancestors.set_field("Constraint");
+            v.visit_constraint(_binding_0, ancestors);
+        }
+        GenericArgument::Const(_binding_0) => {
+            ancestors.set_field("Const");
+            v.visit_expr(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_generic_method_argument<'ast, V>(
+    v: &mut V,
+    node: &'ast GenericMethodArgument,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::GenericMethodArgument(node));
+    match node {
+        GenericMethodArgument::Type(_binding_0) => {
+            ancestors.set_field("Type");
+            v.visit_type(_binding_0, ancestors);
+        }
+        GenericMethodArgument::Const(_binding_0) => {
+            ancestors.set_field("Const");
+            v.visit_expr(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_generic_param<'ast, V>(
+    v: &mut V,
+    node: &'ast GenericParam,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::GenericParam(node));
+    match node {
+        GenericParam::Type(_binding_0) => {
+            ancestors.set_field("Type");
+            v.visit_type_param(_binding_0, ancestors);
+        }
+        GenericParam::Lifetime(_binding_0) => {
+            ancestors.set_field("Lifetime");
+            v.visit_lifetime_def(_binding_0, ancestors);
+        }
+        GenericParam::Const(_binding_0) => {
+            ancestors.set_field("Const");
+            v.visit_const_param(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_generics<'ast, V>(v: &mut V, node: &'ast Generics, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Generics(node));
+    ancestors.set_field("lt_token");
+    if let Some(it) = &node.lt_token {
+        tokens_helper(v, &it.spans, ancestors);
+    };
+    ancestors.set_field("params");
+    for (i, el) in Punctuated::pairs(&node.params).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_generic_param(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.set_field("gt_token");
+    if let Some(it) = &node.gt_token {
+        tokens_helper(v, &it.spans, ancestors);
+    };
+    ancestors.set_field("where_clause");
+    if let Some(it) = &node.where_clause {
+        v.visit_where_clause(it, ancestors);
+    };
+    ancestors.pop();
+}
+pub fn visit_ident<'ast, V>(v: &mut V, node: &'ast Ident, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    v.visit_span(&node.span(), ancestors);
+}
+#[cfg(feature = "full")]
+pub fn visit_impl_item<'ast, V>(v: &mut V, node: &'ast ImplItem, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ImplItem(node));
+    match node {
+        ImplItem::Const(_binding_0) => {
+            ancestors.set_field("Const");
+            v.visit_impl_item_const(_binding_0, ancestors);
+        }
+        ImplItem::Method(_binding_0) => {
+            ancestors.set_field("Method");
+            v.visit_impl_item_method(_binding_0, ancestors);
+        }
+        ImplItem::Type(_binding_0) => {
+            ancestors.set_field("Type");
+            v.visit_impl_item_type(_binding_0, ancestors);
+        }
+        ImplItem::Macro(_binding_0) => {
+            ancestors.set_field("Macro");
+            v.visit_impl_item_macro(_binding_0, ancestors);
+        }
+        ImplItem::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_impl_item_const<'ast, V>(
+    v: &mut V,
+    node: &'ast ImplItemConst,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ImplItemConst(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("vis");
+    v.visit_visibility(&node.vis, ancestors);
+    ancestors.set_field("defaultness");
+    if let Some(it) = &node.defaultness {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("const_token");
+    tokens_helper(v, &node.const_token.span, ancestors);
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.set_field("colon_token");
+    tokens_helper(v, &node.colon_token.spans, ancestors);
+    ancestors.set_field("ty");
+    v.visit_type(&node.ty, ancestors);
+    ancestors.set_field("eq_token");
+    tokens_helper(v, &node.eq_token.spans, ancestors);
+    ancestors.set_field("expr");
+    v.visit_expr(&node.expr, ancestors);
+    ancestors.set_field("semi_token");
+    tokens_helper(v, &node.semi_token.spans, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_impl_item_macro<'ast, V>(
+    v: &mut V,
+    node: &'ast ImplItemMacro,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ImplItemMacro(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("mac");
+    v.visit_macro(&node.mac, ancestors);
+    ancestors.set_field("semi_token");
+    if let Some(it) = &node.semi_token {
+        tokens_helper(v, &it.spans, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_impl_item_method<'ast, V>(
+    v: &mut V,
+    node: &'ast ImplItemMethod,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ImplItemMethod(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("vis");
+    v.visit_visibility(&node.vis, ancestors);
+    ancestors.set_field("defaultness");
+    if let Some(it) = &node.defaultness {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("sig");
+    v.visit_signature(&node.sig, ancestors);
+    ancestors.set_field("block");
+    v.visit_block(&node.block, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_impl_item_type<'ast, V>(
+    v: &mut V,
+    node: &'ast ImplItemType,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ImplItemType(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("vis");
+    v.visit_visibility(&node.vis, ancestors);
+    ancestors.set_field("defaultness");
+    if let Some(it) = &node.defaultness {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("type_token");
+    tokens_helper(v, &node.type_token.span, ancestors);
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.set_field("generics");
+    v.visit_generics(&node.generics, ancestors);
+    ancestors.set_field("eq_token");
+    tokens_helper(v, &node.eq_token.spans, ancestors);
+    ancestors.set_field("ty");
+    v.visit_type(&node.ty, ancestors);
+    ancestors.set_field("semi_token");
+    tokens_helper(v, &node.semi_token.spans, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_index<'ast, V>(v: &mut V, node: &'ast Index, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Index(node));
+    skip!(node.index);
+    ancestors.set_field("span");
+    v.visit_span(&node.span, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item<'ast, V>(v: &mut V, node: &'ast Item, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Item(node));
+    match node {
+        Item::Const(_binding_0) => {
+            ancestors.set_field("Const");
+            v.visit_item_const(_binding_0, ancestors);
+        }
+        Item::Enum(_binding_0) => {
+            ancestors.set_field("Enum");
+            v.visit_item_enum(_binding_0, ancestors);
+        }
+        Item::ExternCrate(_binding_0) => {
+            ancestors.set_field("ExternCrate");
+            v.visit_item_extern_crate(_binding_0, ancestors);
+        }
+        Item::Fn(_binding_0) => {
+            ancestors.set_field("Fn");
+            v.visit_item_fn(_binding_0, ancestors);
+        }
+        Item::FnDecl(_binding_0) => {
+            ancestors.set_field("FnDecl");
+            v.visit_item_fn_decl(_binding_0, ancestors);
+        }
+        Item::ForeignMod(_binding_0) => {
+            ancestors.set_field("ForeignMod");
+            v.visit_item_foreign_mod(_binding_0, ancestors);
+        }
+        Item::Impl(_binding_0) => {
+            ancestors.set_field("Impl");
+            v.visit_item_impl(_binding_0, ancestors);
+        }
+        Item::Macro(_binding_0) => {
+            ancestors.set_field("Macro");
+            v.visit_item_macro(_binding_0, ancestors);
+        }
+        Item::Macro2(_binding_0) => {
+            ancestors.set_field("Macro2");
+            v.visit_item_macro2(_binding_0, ancestors);
+        }
+        Item::Mod(_binding_0) => {
+            ancestors.set_field("Mod");
+            v.visit_item_mod(_binding_0, ancestors);
+        }
+        Item::Static(_binding_0) => {
+            ancestors.set_field("Static");
+            v.visit_item_static(_binding_0, ancestors);
+        }
+        Item::Struct(_binding_0) => {
+            ancestors.set_field("Struct");
+            v.visit_item_struct(_binding_0, ancestors);
+        }
+        Item::Trait(_binding_0) => {
+            ancestors.set_field("Trait");
+            v.visit_item_trait(_binding_0, ancestors);
+        }
+        Item::TraitAlias(_binding_0) => {
+            ancestors.set_field("TraitAlias");
+            v.visit_item_trait_alias(_binding_0, ancestors);
+        }
+        Item::Type(_binding_0) => {
+            ancestors.set_field("Type");
+            v.visit_item_type(_binding_0, ancestors);
+        }
+        Item::Union(_binding_0) => {
+            ancestors.set_field("Union");
+            v.visit_item_union(_binding_0, ancestors);
+        }
+        Item::Use(_binding_0) => {
+            ancestors.set_field("Use");
+            v.visit_item_use(_binding_0, ancestors);
+        }
+        Item::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_const<'ast, V>(v: &mut V, node: &'ast ItemConst, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ItemConst(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("vis");
+    v.visit_visibility(&node.vis, ancestors);
+    ancestors.set_field("const_token");
+    tokens_helper(v, &node.const_token.span, ancestors);
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.set_field("colon_token");
+    tokens_helper(v, &node.colon_token.spans, ancestors);
+    ancestors.set_field("ty");
+    v.visit_type(&*node.ty, ancestors);
+    ancestors.set_field("expr");
+    if let Some(it) = &node.expr {
+        tokens_helper(v, &(it).0.spans, ancestors);
+        v.visit_expr(&*(it).1, ancestors);
+    };
+    ancestors.set_field("semi_token");
+    tokens_helper(v, &node.semi_token.spans, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_enum<'ast, V>(v: &mut V, node: &'ast ItemEnum, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ItemEnum(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("vis");
+    v.visit_visibility(&node.vis, ancestors);
+    ancestors.set_field("enum_token");
+    tokens_helper(v, &node.enum_token.span, ancestors);
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.set_field("generics");
+    v.visit_generics(&node.generics, ancestors);
+    ancestors.set_field("brace_token");
+    tokens_helper(v, &node.brace_token.span, ancestors);
+    ancestors.set_field("variants");
+    for (i, el) in Punctuated::pairs(&node.variants).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_variant(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_extern_crate<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemExternCrate,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ItemExternCrate(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("vis");
+    v.visit_visibility(&node.vis, ancestors);
+    ancestors.set_field("extern_token");
+    tokens_helper(v, &node.extern_token.span, ancestors);
+    ancestors.set_field("crate_token");
+    tokens_helper(v, &node.crate_token.span, ancestors);
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.set_field("rename");
+    if let Some(it) = &node.rename {
+        tokens_helper(v, &(it).0.span, ancestors);
+        v.visit_ident(&(it).1, ancestors);
+    };
+    ancestors.set_field("semi_token");
+    tokens_helper(v, &node.semi_token.spans, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_fn<'ast, V>(v: &mut V, node: &'ast ItemFn, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ItemFn(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("vis");
+    v.visit_visibility(&node.vis, ancestors);
+    ancestors.set_field("sig");
+    v.visit_signature(&node.sig, ancestors);
+    ancestors.set_field("block");
+    v.visit_block(&*node.block, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_fn_decl<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemFnDecl,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ItemFnDecl(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("vis");
+    v.visit_visibility(&node.vis, ancestors);
+    ancestors.set_field("sig");
+    v.visit_signature(&node.sig, ancestors);
+    ancestors.set_field("semi_token");
+    tokens_helper(v, &node.semi_token.spans, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_foreign_mod<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemForeignMod,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ItemForeignMod(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("unsafety");
+    if let Some(it) = &node.unsafety {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("abi");
+    v.visit_abi(&node.abi, ancestors);
+    ancestors.set_field("brace_token");
+    tokens_helper(v, &node.brace_token.span, ancestors);
+    ancestors.set_field("items");
+    for (i, it) in node.items.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_foreign_item(it, ancestors);
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_impl<'ast, V>(v: &mut V, node: &'ast ItemImpl, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ItemImpl(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("defaultness");
+    if let Some(it) = &node.defaultness {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("unsafety");
+    if let Some(it) = &node.unsafety {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("impl_token");
+    tokens_helper(v, &node.impl_token.span, ancestors);
+    ancestors.set_field("generics");
+    v.visit_generics(&node.generics, ancestors);
+    ancestors.set_field("trait_");
+    if let Some(it) = &node.trait_ {
+        if let Some(it) = &(it).0 {
+            tokens_helper(v, &it.spans, ancestors);
+        };
+        v.visit_path(&(it).1, ancestors);
+        tokens_helper(v, &(it).2.span, ancestors);
+    };
+    ancestors.set_field("self_ty");
+    v.visit_type(&*node.self_ty, ancestors);
+    ancestors.set_field("brace_token");
+    tokens_helper(v, &node.brace_token.span, ancestors);
+    ancestors.set_field("items");
+    for (i, it) in node.items.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_impl_item(it, ancestors);
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_macro<'ast, V>(v: &mut V, node: &'ast ItemMacro, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ItemMacro(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("ident");
+    if let Some(it) = &node.ident {
+        v.visit_ident(it, ancestors);
+    };
+    ancestors.set_field("mac");
+    v.visit_macro(&node.mac, ancestors);
+    ancestors.set_field("semi_token");
+    if let Some(it) = &node.semi_token {
+        tokens_helper(v, &it.spans, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_macro2<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemMacro2,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ItemMacro2(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("vis");
+    v.visit_visibility(&node.vis, ancestors);
+    ancestors.set_field("macro_token");
+    tokens_helper(v, &node.macro_token.span, ancestors);
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    skip!(node.rules);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_mod<'ast, V>(v: &mut V, node: &'ast ItemMod, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ItemMod(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("vis");
+    v.visit_visibility(&node.vis, ancestors);
+    ancestors.set_field("unsafety");
+    if let Some(it) = &node.unsafety {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("mod_token");
+    tokens_helper(v, &node.mod_token.span, ancestors);
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.set_field("content");
+    if let Some(it) = &node.content {
+        tokens_helper(v, &(it).0.span, ancestors);
+        for (i, it) in (it).1.iter().enumerate() {
+            ancestors.set_index(i);
+            v.visit_item(it, ancestors);
+        }
+    };
+    ancestors.set_field("semi");
+    if let Some(it) = &node.semi {
+        tokens_helper(v, &it.spans, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_static<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemStatic,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ItemStatic(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("vis");
+    v.visit_visibility(&node.vis, ancestors);
+    ancestors.set_field("static_token");
+    tokens_helper(v, &node.static_token.span, ancestors);
+    ancestors.set_field("mutability");
+    if let Some(it) = &node.mutability {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.set_field("ty");
+    if let Some(it) = &node.ty {
+        tokens_helper(v, &(it).0.spans, ancestors);
+        v.visit_type(&*(it).1, ancestors);
+    };
+    ancestors.set_field("expr");
+    if let Some(it) = &node.expr {
+        tokens_helper(v, &(it).0.spans, ancestors);
+        v.visit_expr(&*(it).1, ancestors);
+    };
+    ancestors.set_field("semi_token");
+    tokens_helper(v, &node.semi_token.spans, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_struct<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemStruct,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ItemStruct(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("vis");
+    v.visit_visibility(&node.vis, ancestors);
+    ancestors.set_field("struct_token");
+    tokens_helper(v, &node.struct_token.span, ancestors);
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.set_field("generics");
+    v.visit_generics(&node.generics, ancestors);
+    ancestors.set_field("fields");
+    v.visit_fields(&node.fields, ancestors);
+    ancestors.set_field("semi_token");
+    if let Some(it) = &node.semi_token {
+        tokens_helper(v, &it.spans, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_trait<'ast, V>(v: &mut V, node: &'ast ItemTrait, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ItemTrait(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("vis");
+    v.visit_visibility(&node.vis, ancestors);
+    ancestors.set_field("unsafety");
+    if let Some(it) = &node.unsafety {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("auto_token");
+    if let Some(it) = &node.auto_token {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("trait_token");
+    tokens_helper(v, &node.trait_token.span, ancestors);
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.set_field("generics");
+    v.visit_generics(&node.generics, ancestors);
+    ancestors.set_field("colon_token");
+    if let Some(it) = &node.colon_token {
+        tokens_helper(v, &it.spans, ancestors);
+    };
+    ancestors.set_field("supertraits");
+    for (i, el) in Punctuated::pairs(&node.supertraits).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_type_param_bound(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.set_field("brace_token");
+    tokens_helper(v, &node.brace_token.span, ancestors);
+    ancestors.set_field("items");
+    for (i, it) in node.items.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_trait_item(it, ancestors);
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_trait_alias<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemTraitAlias,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ItemTraitAlias(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("vis");
+    v.visit_visibility(&node.vis, ancestors);
+    ancestors.set_field("trait_token");
+    tokens_helper(v, &node.trait_token.span, ancestors);
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.set_field("generics");
+    v.visit_generics(&node.generics, ancestors);
+    ancestors.set_field("eq_token");
+    tokens_helper(v, &node.eq_token.spans, ancestors);
+    ancestors.set_field("bounds");
+    for (i, el) in Punctuated::pairs(&node.bounds).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_type_param_bound(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.set_field("semi_token");
+    tokens_helper(v, &node.semi_token.spans, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_type<'ast, V>(v: &mut V, node: &'ast ItemType, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ItemType(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("vis");
+    v.visit_visibility(&node.vis, ancestors);
+    ancestors.set_field("type_token");
+    tokens_helper(v, &node.type_token.span, ancestors);
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.set_field("generics");
+    v.visit_generics(&node.generics, ancestors);
+    ancestors.set_field("eq_token");
+    tokens_helper(v, &node.eq_token.spans, ancestors);
+    ancestors.set_field("ty");
+    v.visit_type(&*node.ty, ancestors);
+    ancestors.set_field("semi_token");
+    tokens_helper(v, &node.semi_token.spans, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_union<'ast, V>(v: &mut V, node: &'ast ItemUnion, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ItemUnion(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("vis");
+    v.visit_visibility(&node.vis, ancestors);
+    ancestors.set_field("union_token");
+    tokens_helper(v, &node.union_token.span, ancestors);
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.set_field("generics");
+    v.visit_generics(&node.generics, ancestors);
+    ancestors.set_field("fields");
+    v.visit_fields_named(&node.fields, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_item_use<'ast, V>(v: &mut V, node: &'ast ItemUse, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ItemUse(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("vis");
+    v.visit_visibility(&node.vis, ancestors);
+    ancestors.set_field("use_token");
+    tokens_helper(v, &node.use_token.span, ancestors);
+    ancestors.set_field("leading_colon");
+    if let Some(it) = &node.leading_colon {
+        tokens_helper(v, &it.spans, ancestors);
+    };
+    ancestors.set_field("tree");
+    v.visit_use_tree(&node.tree, ancestors);
+    ancestors.set_field("semi_token");
+    tokens_helper(v, &node.semi_token.spans, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_label<'ast, V>(v: &mut V, node: &'ast Label, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Label(node));
+    ancestors.set_field("name");
+    v.visit_lifetime(&node.name, ancestors);
+    ancestors.set_field("colon_token");
+    tokens_helper(v, &node.colon_token.spans, ancestors);
+    ancestors.pop();
+}
+pub fn visit_lifetime<'ast, V>(v: &mut V, node: &'ast Lifetime, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Lifetime(node));
+    ancestors.set_field("apostrophe");
+    v.visit_span(&node.apostrophe, ancestors);
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_lifetime_def<'ast, V>(
+    v: &mut V,
+    node: &'ast LifetimeDef,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::LifetimeDef(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("lifetime");
+    v.visit_lifetime(&node.lifetime, ancestors);
+    ancestors.set_field("colon_token");
+    if let Some(it) = &node.colon_token {
+        tokens_helper(v, &it.spans, ancestors);
+    };
+    ancestors.set_field("bounds");
+    for (i, el) in Punctuated::pairs(&node.bounds).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_lifetime(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+pub fn visit_lit<'ast, V>(v: &mut V, node: &'ast Lit, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Lit(node));
+    match node {
+        Lit::Str(_binding_0) => {
+            ancestors.set_field("Str");
+            v.visit_lit_str(_binding_0, ancestors);
+        }
+        Lit::ByteStr(_binding_0) => {
+            ancestors.set_field("ByteStr");
+            v.visit_lit_byte_str(_binding_0, ancestors);
+        }
+        Lit::Byte(_binding_0) => {
+            ancestors.set_field("Byte");
+            v.visit_lit_byte(_binding_0, ancestors);
+        }
+        Lit::Char(_binding_0) => {
+            ancestors.set_field("Char");
+            v.visit_lit_char(_binding_0, ancestors);
+        }
+        Lit::Int(_binding_0) => {
+            ancestors.set_field("Int");
+            v.visit_lit_int(_binding_0, ancestors);
+        }
+        Lit::Float(_binding_0) => {
+            ancestors.set_field("Float");
+            v.visit_lit_float(_binding_0, ancestors);
+        }
+        Lit::Bool(_binding_0) => {
+            ancestors.set_field("Bool");
+            v.visit_lit_bool(_binding_0, ancestors);
+        }
+        Lit::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+    }
+    ancestors.pop();
+}
+pub fn visit_lit_bool<'ast, V>(v: &mut V, node: &'ast LitBool, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::LitBool(node));
+    skip!(node.value);
+    ancestors.set_field("span");
+    v.visit_span(&node.span, ancestors);
+    ancestors.pop();
+}
+pub fn visit_lit_byte<'ast, V>(v: &mut V, node: &'ast LitByte, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+}
+pub fn visit_lit_byte_str<'ast, V>(
+    v: &mut V,
+    node: &'ast LitByteStr,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+}
+pub fn visit_lit_char<'ast, V>(v: &mut V, node: &'ast LitChar, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+}
+pub fn visit_lit_float<'ast, V>(v: &mut V, node: &'ast LitFloat, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+}
+pub fn visit_lit_int<'ast, V>(v: &mut V, node: &'ast LitInt, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+}
+pub fn visit_lit_str<'ast, V>(v: &mut V, node: &'ast LitStr, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+}
+#[cfg(feature = "full")]
+pub fn visit_local<'ast, V>(v: &mut V, node: &'ast Local, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Local(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("let_token");
+    tokens_helper(v, &node.let_token.span, ancestors);
+    ancestors.set_field("pat");
+    v.visit_pat(&node.pat, ancestors);
+    ancestors.set_field("init");
+    if let Some(it) = &node.init {
+        v.visit_local_init(it, ancestors);
+    };
+    ancestors.set_field("semi_token");
+    tokens_helper(v, &node.semi_token.spans, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_local_init<'ast, V>(v: &mut V, node: &'ast LocalInit, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::LocalInit(node));
+    ancestors.set_field("eq_token");
+    tokens_helper(v, &node.eq_token.spans, ancestors);
+    ancestors.set_field("expr");
+    v.visit_expr(&*node.expr, ancestors);
+    ancestors.set_field("diverge");
+    if let Some(it) = &node.diverge {
+        tokens_helper(v, &(it).0.span, ancestors);
+        v.visit_block(&(it).1, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_macro<'ast, V>(v: &mut V, node: &'ast Macro, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Macro(node));
+    ancestors.set_field("path");
+    v.visit_path(&node.path, ancestors);
+    ancestors.set_field("bang_token");
+    tokens_helper(v, &node.bang_token.spans, ancestors);
+    ancestors.set_field("delimiter");
+    v.visit_macro_delimiter(&node.delimiter, ancestors);
+    skip!(node.tokens);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_macro_delimiter<'ast, V>(
+    v: &mut V,
+    node: &'ast MacroDelimiter,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::MacroDelimiter(node));
+    match node {
+        MacroDelimiter::Paren(_binding_0) => {
+            ancestors.set_field("Paren");
+            tokens_helper(v, &_binding_0.span, ancestors);
+        }
+        MacroDelimiter::Brace(_binding_0) => {
+            ancestors.set_field("Brace");
+            tokens_helper(v, &_binding_0.span, ancestors);
+        }
+        MacroDelimiter::Bracket(_binding_0) => {
+            ancestors.set_field("Bracket");
+            tokens_helper(v, &_binding_0.span, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_macro_group<'ast, V>(
+    v: &mut V,
+    node: &'ast MacroGroup,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::MacroGroup(node));
+    ancestors.set_field("delimiter");
+    v.visit_macro_delimiter(&node.delimiter, ancestors);
+    ancestors.set_field("content");
+    for (i, it) in node.content.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_macro_tree(it, ancestors);
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_macro_metavar<'ast, V>(
+    v: &mut V,
+    node: &'ast MacroMetavar,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::MacroMetavar(node));
+    ancestors.set_field("dollar_token");
+    tokens_helper(v, &node.dollar_token.spans, ancestors);
+    ancestors.set_field("name");
+    v.visit_ident(&node.name, ancestors);
+    ancestors.set_field("specifier");
+    if let Some(it) = &node.specifier {
+        tokens_helper(v, &(it).0.spans, ancestors);
+        v.visit_ident(&(it).1, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_macro_rep_op<'ast, V>(
+    v: &mut V,
+    node: &'ast MacroRepOp,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::MacroRepOp(node));
+    match node {
+        MacroRepOp::ZeroOrMore(_binding_0) => {
+            ancestors.set_field("ZeroOrMore");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        MacroRepOp::OneOrMore(_binding_0) => {
+            ancestors.set_field("OneOrMore");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        MacroRepOp::ZeroOrOne(_binding_0) => {
+            ancestors.set_field("ZeroOrOne");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_macro_repetition<'ast, V>(
+    v: &mut V,
+    node: &'ast MacroRepetition,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::MacroRepetition(node));
+    ancestors.set_field("dollar_token");
+    tokens_helper(v, &node.dollar_token.spans, ancestors);
+    ancestors.set_field("paren_token");
+    tokens_helper(v, &node.paren_token.span, ancestors);
+    ancestors.set_field("content");
+    for (i, it) in node.content.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_macro_tree(it, ancestors);
+    }
+    skip!(node.separator);
+    ancestors.set_field("op");
+    v.visit_macro_rep_op(&node.op, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_macro_rule<'ast, V>(v: &mut V, node: &'ast MacroRule, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::MacroRule(node));
+    ancestors.set_field("matcher");
+    v.visit_macro_group(&node.matcher, ancestors);
+    ancestors.set_field("fat_arrow_token");
+    tokens_helper(v, &node.fat_arrow_token.spans, ancestors);
+    ancestors.set_field("transcriber");
+    v.visit_macro_group(&node.transcriber, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_macro_rules<'ast, V>(
+    v: &mut V,
+    node: &'ast MacroRules,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::MacroRules(node));
+    ancestors.set_field("rules");
+    for (i, el) in Punctuated::pairs(&node.rules).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_macro_rule(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_macro_tree<'ast, V>(v: &mut V, node: &'ast MacroTree, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::MacroTree(node));
+    match node {
+        MacroTree::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        MacroTree::Group(_binding_0) => {
+            ancestors.set_field("Group");
+            v.visit_macro_group(_binding_0, ancestors);
+        }
+        MacroTree::Metavar(_binding_0) => {
+            ancestors.set_field("Metavar");
+            v.visit_macro_metavar(_binding_0, ancestors);
+        }
+        MacroTree::Repetition(_binding_0) => {
+            ancestors.set_field("Repetition");
+            v.visit_macro_repetition(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_member<'ast, V>(v: &mut V, node: &'ast Member, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Member(node));
+    match node {
+        Member::Named(_binding_0) => {
+            ancestors.set_field("Named");
+            v.visit_ident(_binding_0, ancestors);
+        }
+        Member::Unnamed(_binding_0) => {
+            ancestors.set_field("Unnamed");
+            v.visit_index(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_meta<'ast, V>(v: &mut V, node: &'ast Meta, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Meta(node));
+    match node {
+        Meta::Path(_binding_0) => {
+            ancestors.set_field("Path");
+            v.visit_path(_binding_0, ancestors);
+        }
+        Meta::List(_binding_0) => {
+            ancestors.set_field("List");
+            v.visit_meta_list(_binding_0, ancestors);
+        }
+        Meta::NameValue(_binding_0) => {
+            ancestors.set_field("NameValue");
+            v.visit_meta_name_value(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_meta_list<'ast, V>(v: &mut V, node: &'ast MetaList, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::MetaList(node));
+    ancestors.set_field("path");
+    v.visit_path(&node.path, ancestors);
+    ancestors.set_field("paren_token");
+    tokens_helper(v, &node.paren_token.span, ancestors);
+    ancestors.set_field("nested");
+    for (i, el) in Punctuated::pairs(&node.nested).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_nested_meta(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_meta_name_value<'ast, V>(
+    v: &mut V,
+    node: &'ast MetaNameValue,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::MetaNameValue(node));
+    ancestors.set_field("path");
+    v.visit_path(&node.path, ancestors);
+    ancestors.set_field("eq_token");
+    tokens_helper(v, &node.eq_token.spans, ancestors);
+    ancestors.set_field("lit");
+    v.visit_lit(&node.lit, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_method_turbofish<'ast, V>(
+    v: &mut V,
+    node: &'ast MethodTurbofish,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::MethodTurbofish(node));
+    ancestors.set_field("colon2_token");
+    tokens_helper(v, &node.colon2_token.spans, ancestors);
+    ancestors.set_field("lt_token");
+    tokens_helper(v, &node.lt_token.spans, ancestors);
+    ancestors.set_field("args");
+    for (i, el) in Punctuated::pairs(&node.args).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_generic_method_argument(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.set_field("gt_token");
+    tokens_helper(v, &node.gt_token.spans, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_nested_meta<'ast, V>(
+    v: &mut V,
+    node: &'ast NestedMeta,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::NestedMeta(node));
+    match node {
+        NestedMeta::Meta(_binding_0) => {
+            ancestors.set_field("Meta");
+            v.visit_meta(_binding_0, ancestors);
+        }
+        NestedMeta::Lit(_binding_0) => {
+            ancestors.set_field("Lit");
+            v.visit_lit(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_parenthesized_generic_arguments<'ast, V>(
+    v: &mut V,
+    node: &'ast ParenthesizedGenericArguments,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ParenthesizedGenericArguments(node));
+    ancestors.set_field("paren_token");
+    tokens_helper(v, &node.paren_token.span, ancestors);
+    ancestors.set_field("inputs");
+    for (i, el) in Punctuated::pairs(&node.inputs).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_type(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.set_field("output");
+    v.visit_return_type(&node.output, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat<'ast, V>(v: &mut V, node: &'ast Pat, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Pat(node));
+    match node {
+        Pat::Box(_binding_0) => {
+            ancestors.set_field("Box");
+            v.visit_pat_box(_binding_0, ancestors);
+        }
+        Pat::Const(_binding_0) => {
+            ancestors.set_field("Const");
+            v.visit_pat_const(_binding_0, ancestors);
+        }
+        Pat::Ident(_binding_0) => {
+            ancestors.set_field("Ident");
+            v.visit_pat_ident(_binding_0, ancestors);
+        }
+        Pat::Lit(_binding_0) => {
+            ancestors.set_field("Lit");
+            v.visit_pat_lit(_binding_0, ancestors);
+        }
+        Pat::Macro(_binding_0) => {
+            ancestors.set_field("Macro");
+            v.visit_pat_macro(_binding_0, ancestors);
+        }
+        Pat::Or(_binding_0) => {
+            ancestors.set_field("Or");
+            v.visit_pat_or(_binding_0, ancestors);
+        }
+        Pat::Path(_binding_0) => {
+            ancestors.set_field("Path");
+            v.visit_pat_path(_binding_0, ancestors);
+        }
+        Pat::Range(_binding_0) => {
+            ancestors.set_field("Range");
+            v.visit_pat_range(_binding_0, ancestors);
+        }
+        Pat::Reference(_binding_0) => {
+            ancestors.set_field("Reference");
+            v.visit_pat_reference(_binding_0, ancestors);
+        }
+        Pat::Rest(_binding_0) => {
+            ancestors.set_field("Rest");
+            v.visit_pat_rest(_binding_0, ancestors);
+        }
+        Pat::Slice(_binding_0) => {
+            ancestors.set_field("Slice");
+            v.visit_pat_slice(_binding_0, ancestors);
+        }
+        Pat::Struct(_binding_0) => {
+            ancestors.set_field("Struct");
+            v.visit_pat_struct(_binding_0, ancestors);
+        }
+        Pat::Tuple(_binding_0) => {
+            ancestors.set_field("Tuple");
+            v.visit_pat_tuple(_binding_0, ancestors);
+        }
+        Pat::TupleStruct(_binding_0) => {
+            ancestors.set_field("TupleStruct");
+            v.visit_pat_tuple_struct(_binding_0, ancestors);
+        }
+        Pat::Type(_binding_0) => {
+            ancestors.set_field("Type");
+            v.visit_pat_type(_binding_0, ancestors);
+        }
+        Pat::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        Pat::Wild(_binding_0) => {
+            ancestors.set_field("Wild");
+            v.visit_pat_wild(_binding_0, ancestors);
+        }
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_box<'ast, V>(v: &mut V, node: &'ast PatBox, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::PatBox(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("box_token");
+    tokens_helper(v, &node.box_token.span, ancestors);
+    ancestors.set_field("pat");
+    v.visit_pat(&*node.pat, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_const<'ast, V>(v: &mut V, node: &'ast PatConst, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::PatConst(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("const_token");
+    tokens_helper(v, &node.const_token.span, ancestors);
+    ancestors.set_field("block");
+    v.visit_block(&node.block, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_ident<'ast, V>(v: &mut V, node: &'ast PatIdent, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::PatIdent(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("by_ref");
+    if let Some(it) = &node.by_ref {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("mutability");
+    if let Some(it) = &node.mutability {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.set_field("subpat");
+    if let Some(it) = &node.subpat {
+        tokens_helper(v, &(it).0.spans, ancestors);
+        v.visit_pat(&*(it).1, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_lit<'ast, V>(v: &mut V, node: &'ast PatLit, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::PatLit(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("expr");
+    v.visit_expr(&*node.expr, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_macro<'ast, V>(v: &mut V, node: &'ast PatMacro, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::PatMacro(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("mac");
+    v.visit_macro(&node.mac, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_or<'ast, V>(v: &mut V, node: &'ast PatOr, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::PatOr(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("leading_vert");
+    if let Some(it) = &node.leading_vert {
+        tokens_helper(v, &it.spans, ancestors);
+    };
+    ancestors.set_field("cases");
+    for (i, el) in Punctuated::pairs(&node.cases).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_pat(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_path<'ast, V>(v: &mut V, node: &'ast PatPath, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::PatPath(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("qself");
+    if let Some(it) = &node.qself {
+        v.visit_qself(it, ancestors);
+    };
+    ancestors.set_field("path");
+    v.visit_path(&node.path, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_range<'ast, V>(v: &mut V, node: &'ast PatRange, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::PatRange(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("lo");
+    v.visit_expr(&*node.lo, ancestors);
+    ancestors.set_field("limits");
+    v.visit_range_limits(&node.limits, ancestors);
+    ancestors.set_field("hi");
+    v.visit_expr(&*node.hi, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_reference<'ast, V>(
+    v: &mut V,
+    node: &'ast PatReference,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::PatReference(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("and_token");
+    tokens_helper(v, &node.and_token.spans, ancestors);
+    ancestors.set_field("mutability");
+    if let Some(it) = &node.mutability {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("pat");
+    v.visit_pat(&*node.pat, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_rest<'ast, V>(v: &mut V, node: &'ast PatRest, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::PatRest(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("dot2_token");
+    tokens_helper(v, &node.dot2_token.spans, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_slice<'ast, V>(v: &mut V, node: &'ast PatSlice, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::PatSlice(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("bracket_token");
+    tokens_helper(v, &node.bracket_token.span, ancestors);
+    ancestors.set_field("elems");
+    for (i, el) in Punctuated::pairs(&node.elems).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_pat(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_struct<'ast, V>(v: &mut V, node: &'ast PatStruct, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::PatStruct(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("path");
+    v.visit_path(&node.path, ancestors);
+    ancestors.set_field("brace_token");
+    tokens_helper(v, &node.brace_token.span, ancestors);
+    ancestors.set_field("fields");
+    for (i, el) in Punctuated::pairs(&node.fields).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_field_pat(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.set_field("dot2_token");
+    if let Some(it) = &node.dot2_token {
+        tokens_helper(v, &it.spans, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_tuple<'ast, V>(v: &mut V, node: &'ast PatTuple, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::PatTuple(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("paren_token");
+    tokens_helper(v, &node.paren_token.span, ancestors);
+    ancestors.set_field("elems");
+    for (i, el) in Punctuated::pairs(&node.elems).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_pat(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_tuple_struct<'ast, V>(
+    v: &mut V,
+    node: &'ast PatTupleStruct,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::PatTupleStruct(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("path");
+    v.visit_path(&node.path, ancestors);
+    ancestors.set_field("pat");
+    v.visit_pat_tuple(&node.pat, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_type<'ast, V>(v: &mut V, node: &'ast PatType, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::PatType(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("pat");
+    v.visit_pat(&*node.pat, ancestors);
+    ancestors.set_field("colon_token");
+    tokens_helper(v, &node.colon_token.spans, ancestors);
+    ancestors.set_field("ty");
+    v.visit_type(&*node.ty, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pat_wild<'ast, V>(v: &mut V, node: &'ast PatWild, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::PatWild(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("underscore_token");
+    tokens_helper(v, &node.underscore_token.spans, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_path<'ast, V>(v: &mut V, node: &'ast Path, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Path(node));
+    ancestors.set_field("leading_colon");
+    if let Some(it) = &node.leading_colon {
+        tokens_helper(v, &it.spans, ancestors);
+    };
+    ancestors.set_field("segments");
+    for (i, el) in Punctuated::pairs(&node.segments).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_path_segment(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_path_arguments<'ast, V>(
+    v: &mut V,
+    node: &'ast PathArguments,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::PathArguments(node));
+    match node {
+        PathArguments::None => {}
+        PathArguments::AngleBracketed(_binding_0) => {
+            ancestors.set_field("AngleBracketed");
+            v.visit_angle_bracketed_generic_arguments(_binding_0, ancestors);
+        }
+        PathArguments::Parenthesized(_binding_0) => {
+            ancestors.set_field("Parenthesized");
+            v.visit_parenthesized_generic_arguments(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_path_segment<'ast, V>(
+    v: &mut V,
+    node: &'ast PathSegment,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::PathSegment(node));
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.set_field("arguments");
+    v.visit_path_arguments(&node.arguments, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_pointer_mutability<'ast, V>(
+    v: &mut V,
+    node: &'ast PointerMutability,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::PointerMutability(node));
+    match node {
+        PointerMutability::Const(_binding_0) => {
+            ancestors.set_field("Const");
+            tokens_helper(v, &_binding_0.span, ancestors);
+        }
+        PointerMutability::Mut(_binding_0) => {
+            ancestors.set_field("Mut");
+            tokens_helper(v, &_binding_0.span, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_predicate_eq<'ast, V>(
+    v: &mut V,
+    node: &'ast PredicateEq,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::PredicateEq(node));
+    ancestors.set_field("lhs_ty");
+    v.visit_type(&node.lhs_ty, ancestors);
+    ancestors.set_field("eq_token");
+    tokens_helper(v, &node.eq_token.spans, ancestors);
+    ancestors.set_field("rhs_ty");
+    v.visit_type(&node.rhs_ty, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_predicate_lifetime<'ast, V>(
+    v: &mut V,
+    node: &'ast PredicateLifetime,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::PredicateLifetime(node));
+    ancestors.set_field("lifetime");
+    v.visit_lifetime(&node.lifetime, ancestors);
+    ancestors.set_field("colon_token");
+    tokens_helper(v, &node.colon_token.spans, ancestors);
+    ancestors.set_field("bounds");
+    for (i, el) in Punctuated::pairs(&node.bounds).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_lifetime(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_predicate_type<'ast, V>(
+    v: &mut V,
+    node: &'ast PredicateType,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::PredicateType(node));
+    ancestors.set_field("lifetimes");
+    if let Some(it) = &node.lifetimes {
+        v.visit_bound_lifetimes(it, ancestors);
+    };
+    ancestors.set_field("bounded_ty");
+    v.visit_type(&node.bounded_ty, ancestors);
+    ancestors.set_field("colon_token");
+    tokens_helper(v, &node.colon_token.spans, ancestors);
+    ancestors.set_field("bounds");
+    for (i, el) in Punctuated::pairs(&node.bounds).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_type_param_bound(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_qself<'ast, V>(v: &mut V, node: &'ast QSelf, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::QSelf(node));
+    ancestors.set_field("lt_token");
+    tokens_helper(v, &node.lt_token.spans, ancestors);
+    ancestors.set_field("ty");
+    v.visit_type(&*node.ty, ancestors);
+    skip!(node.position);
+    ancestors.set_field("as_token");
+    if let Some(it) = &node.as_token {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("gt_token");
+    tokens_helper(v, &node.gt_token.spans, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_range_limits<'ast, V>(
+    v: &mut V,
+    node: &'ast RangeLimits,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::RangeLimits(node));
+    match node {
+        RangeLimits::HalfOpen(_binding_0) => {
+            ancestors.set_field("HalfOpen");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        RangeLimits::Closed(_binding_0) => {
+            ancestors.set_field("Closed");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_receiver<'ast, V>(v: &mut V, node: &'ast Receiver, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Receiver(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("reference");
+    if let Some(it) = &node.reference {
+        tokens_helper(v, &(it).0.spans, ancestors);
+        if let Some(it) = &(it).1 {
+            v.visit_lifetime(it, ancestors);
+        };
+    };
+    ancestors.set_field("mutability");
+    if let Some(it) = &node.mutability {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("self_token");
+    tokens_helper(v, &node.self_token.span, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_return_type<'ast, V>(
+    v: &mut V,
+    node: &'ast ReturnType,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::ReturnType(node));
+    match node {
+        ReturnType::Default => {}
+        ReturnType::Type(_binding_0, _binding_1) => {
+            ancestors.set_field("Type");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+            v.visit_type(&**_binding_1, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_signature<'ast, V>(v: &mut V, node: &'ast Signature, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Signature(node));
+    ancestors.set_field("constness");
+    if let Some(it) = &node.constness {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("asyncness");
+    if let Some(it) = &node.asyncness {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("unsafety");
+    if let Some(it) = &node.unsafety {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("abi");
+    if let Some(it) = &node.abi {
+        v.visit_abi(it, ancestors);
+    };
+    ancestors.set_field("fn_token");
+    tokens_helper(v, &node.fn_token.span, ancestors);
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.set_field("generics");
+    v.visit_generics(&node.generics, ancestors);
+    ancestors.set_field("paren_token");
+    tokens_helper(v, &node.paren_token.span, ancestors);
+    ancestors.set_field("inputs");
+    for (i, el) in Punctuated::pairs(&node.inputs).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_fn_arg(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.set_field("variadic");
+    if let Some(it) = &node.variadic {
+        v.visit_variadic(it, ancestors);
+    };
+    ancestors.set_field("output");
+    v.visit_return_type(&node.output, ancestors);
+    ancestors.pop();
+}
+pub fn visit_span<'ast, V>(v: &mut V, node: &Span, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+}
+#[cfg(feature = "full")]
+pub fn visit_stmt<'ast, V>(v: &mut V, node: &'ast Stmt, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Stmt(node));
+    match node {
+        Stmt::Local(_binding_0) => {
+            ancestors.set_field("Local");
+            v.visit_local(_binding_0, ancestors);
+        }
+        Stmt::Item(_binding_0) => {
+            ancestors.set_field("Item");
+            v.visit_item(_binding_0, ancestors);
+        }
+        Stmt::Expr(_binding_0) => {
+            ancestors.set_field("Expr");
+            v.visit_expr(_binding_0, ancestors);
+        }
+        Stmt::Semi(_binding_0, _binding_1) => {
+            ancestors.set_field("Semi");
+            v.visit_expr(_binding_0, ancestors);
+            tokens_helper(v, &_binding_1.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_trait_bound<'ast, V>(
+    v: &mut V,
+    node: &'ast TraitBound,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::TraitBound(node));
+    ancestors.set_field("paren_token");
+    if let Some(it) = &node.paren_token {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("modifier");
+    v.visit_trait_bound_modifier(&node.modifier, ancestors);
+    ancestors.set_field("lifetimes");
+    if let Some(it) = &node.lifetimes {
+        v.visit_bound_lifetimes(it, ancestors);
+    };
+    ancestors.set_field("path");
+    v.visit_path(&node.path, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_trait_bound_modifier<'ast, V>(
+    v: &mut V,
+    node: &'ast TraitBoundModifier,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::TraitBoundModifier(node));
+    match node {
+        TraitBoundModifier::None => {}
+        TraitBoundModifier::Maybe(_binding_0) => {
+            ancestors.set_field("Maybe");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_trait_item<'ast, V>(v: &mut V, node: &'ast TraitItem, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::TraitItem(node));
+    match node {
+        TraitItem::Const(_binding_0) => {
+            ancestors.set_field("Const");
+            v.visit_trait_item_const(_binding_0, ancestors);
+        }
+        TraitItem::Method(_binding_0) => {
+            ancestors.set_field("Method");
+            v.visit_trait_item_method(_binding_0, ancestors);
+        }
+        TraitItem::Type(_binding_0) => {
+            ancestors.set_field("Type");
+            v.visit_trait_item_type(_binding_0, ancestors);
+        }
+        TraitItem::Macro(_binding_0) => {
+            ancestors.set_field("Macro");
+            v.visit_trait_item_macro(_binding_0, ancestors);
+        }
+        TraitItem::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_trait_item_const<'ast, V>(
+    v: &mut V,
+    node: &'ast TraitItemConst,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::TraitItemConst(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("const_token");
+    tokens_helper(v, &node.const_token.span, ancestors);
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.set_field("colon_token");
+    tokens_helper(v, &node.colon_token.spans, ancestors);
+    ancestors.set_field("ty");
+    v.visit_type(&node.ty, ancestors);
+    ancestors.set_field("default");
+    if let Some(it) = &node.default {
+        tokens_helper(v, &(it).0.spans, ancestors);
+        v.visit_expr(&(it).1, ancestors);
+    };
+    ancestors.set_field("semi_token");
+    tokens_helper(v, &node.semi_token.spans, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_trait_item_macro<'ast, V>(
+    v: &mut V,
+    node: &'ast TraitItemMacro,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::TraitItemMacro(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("mac");
+    v.visit_macro(&node.mac, ancestors);
+    ancestors.set_field("semi_token");
+    if let Some(it) = &node.semi_token {
+        tokens_helper(v, &it.spans, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_trait_item_method<'ast, V>(
+    v: &mut V,
+    node: &'ast TraitItemMethod,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::TraitItemMethod(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("sig");
+    v.visit_signature(&node.sig, ancestors);
+    ancestors.set_field("default");
+    if let Some(it) = &node.default {
+        v.visit_block(it, ancestors);
+    };
+    ancestors.set_field("semi_token");
+    if let Some(it) = &node.semi_token {
+        tokens_helper(v, &it.spans, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_trait_item_type<'ast, V>(
+    v: &mut V,
+    node: &'ast TraitItemType,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::TraitItemType(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("type_token");
+    tokens_helper(v, &node.type_token.span, ancestors);
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.set_field("generics");
+    v.visit_generics(&node.generics, ancestors);
+    ancestors.set_field("colon_token");
+    if let Some(it) = &node.colon_token {
+        tokens_helper(v, &it.spans, ancestors);
+    };
+    ancestors.set_field("bounds");
+    for (i, el) in Punctuated::pairs(&node.bounds).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_type_param_bound(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.set_field("default");
+    if let Some(it) = &node.default {
+        tokens_helper(v, &(it).0.spans, ancestors);
+        v.visit_type(&(it).1, ancestors);
+    };
+    ancestors.set_field("semi_token");
+    tokens_helper(v, &node.semi_token.spans, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type<'ast, V>(v: &mut V, node: &'ast Type, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Type(node));
+    match node {
+        Type::Array(_binding_0) => {
+            ancestors.set_field("Array");
+            v.visit_type_array(_binding_0, ancestors);
+        }
+        Type::BareFn(_binding_0) => {
+            ancestors.set_field("BareFn");
+            v.visit_type_bare_fn(_binding_0, ancestors);
+        }
+        Type::Group(_binding_0) => {
+            ancestors.set_field("Group");
+            v.visit_type_group(_binding_0, ancestors);
+        }
+        Type::ImplTrait(_binding_0) => {
+            ancestors.set_field("ImplTrait");
+            v.visit_type_impl_trait(_binding_0, ancestors);
+        }
+        Type::Infer(_binding_0) => {
+            ancestors.set_field("Infer");
+            v.visit_type_infer(_binding_0, ancestors);
+        }
+        Type::Macro(_binding_0) => {
+            ancestors.set_field("Macro");
+            v.visit_type_macro(_binding_0, ancestors);
+        }
+        Type::Never(_binding_0) => {
+            ancestors.set_field("Never");
+            v.visit_type_never(_binding_0, ancestors);
+        }
+        Type::Paren(_binding_0) => {
+            ancestors.set_field("Paren");
+            v.visit_type_paren(_binding_0, ancestors);
+        }
+        Type::Path(_binding_0) => {
+            ancestors.set_field("Path");
+            v.visit_type_path(_binding_0, ancestors);
+        }
+        Type::Ptr(_binding_0) => {
+            ancestors.set_field("Ptr");
+            v.visit_type_ptr(_binding_0, ancestors);
+        }
+        Type::Reference(_binding_0) => {
+            ancestors.set_field("Reference");
+            v.visit_type_reference(_binding_0, ancestors);
+        }
+        Type::Slice(_binding_0) => {
+            ancestors.set_field("Slice");
+            v.visit_type_slice(_binding_0, ancestors);
+        }
+        Type::TraitObject(_binding_0) => {
+            ancestors.set_field("TraitObject");
+            v.visit_type_trait_object(_binding_0, ancestors);
+        }
+        Type::Tuple(_binding_0) => {
+            ancestors.set_field("Tuple");
+            v.visit_type_tuple(_binding_0, ancestors);
+        }
+        Type::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_array<'ast, V>(v: &mut V, node: &'ast TypeArray, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::TypeArray(node));
+    ancestors.set_field("bracket_token");
+    tokens_helper(v, &node.bracket_token.span, ancestors);
+    ancestors.set_field("elem");
+    v.visit_type(&*node.elem, ancestors);
+    ancestors.set_field("semi_token");
+    tokens_helper(v, &node.semi_token.spans, ancestors);
+    ancestors.set_field("len");
+    v.visit_expr(&node.len, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_bare_fn<'ast, V>(
+    v: &mut V,
+    node: &'ast TypeBareFn,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::TypeBareFn(node));
+    ancestors.set_field("lifetimes");
+    if let Some(it) = &node.lifetimes {
+        v.visit_bound_lifetimes(it, ancestors);
+    };
+    ancestors.set_field("unsafety");
+    if let Some(it) = &node.unsafety {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("abi");
+    if let Some(it) = &node.abi {
+        v.visit_abi(it, ancestors);
+    };
+    ancestors.set_field("fn_token");
+    tokens_helper(v, &node.fn_token.span, ancestors);
+    ancestors.set_field("paren_token");
+    tokens_helper(v, &node.paren_token.span, ancestors);
+    ancestors.set_field("inputs");
+    for (i, el) in Punctuated::pairs(&node.inputs).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_bare_fn_arg(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.set_field("variadic");
+    if let Some(it) = &node.variadic {
+        v.visit_variadic(it, ancestors);
+    };
+    ancestors.set_field("output");
+    v.visit_return_type(&node.output, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_group<'ast, V>(v: &mut V, node: &'ast TypeGroup, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::TypeGroup(node));
+    ancestors.set_field("group_token");
+    tokens_helper(v, &node.group_token.span, ancestors);
+    ancestors.set_field("elem");
+    v.visit_type(&*node.elem, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_impl_trait<'ast, V>(
+    v: &mut V,
+    node: &'ast TypeImplTrait,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::TypeImplTrait(node));
+    ancestors.set_field("impl_token");
+    tokens_helper(v, &node.impl_token.span, ancestors);
+    ancestors.set_field("bounds");
+    for (i, el) in Punctuated::pairs(&node.bounds).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_type_param_bound(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_infer<'ast, V>(v: &mut V, node: &'ast TypeInfer, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::TypeInfer(node));
+    ancestors.set_field("underscore_token");
+    tokens_helper(v, &node.underscore_token.spans, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_macro<'ast, V>(v: &mut V, node: &'ast TypeMacro, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::TypeMacro(node));
+    ancestors.set_field("mac");
+    v.visit_macro(&node.mac, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_never<'ast, V>(v: &mut V, node: &'ast TypeNever, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::TypeNever(node));
+    ancestors.set_field("bang_token");
+    tokens_helper(v, &node.bang_token.spans, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_param<'ast, V>(v: &mut V, node: &'ast TypeParam, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::TypeParam(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.set_field("colon_token");
+    if let Some(it) = &node.colon_token {
+        tokens_helper(v, &it.spans, ancestors);
+    };
+    ancestors.set_field("bounds");
+    for (i, el) in Punctuated::pairs(&node.bounds).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_type_param_bound(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.set_field("eq_token");
+    if let Some(it) = &node.eq_token {
+        tokens_helper(v, &it.spans, ancestors);
+    };
+    ancestors.set_field("default");
+    if let Some(it) = &node.default {
+        v.visit_type(it, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_param_bound<'ast, V>(
+    v: &mut V,
+    node: &'ast TypeParamBound,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::TypeParamBound(node));
+    match node {
+        TypeParamBound::Trait(_binding_0) => {
+            ancestors.set_field("Trait");
+            v.visit_trait_bound(_binding_0, ancestors);
+        }
+        TypeParamBound::Lifetime(_binding_0) => {
+            ancestors.set_field("Lifetime");
+            v.visit_lifetime(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_paren<'ast, V>(v: &mut V, node: &'ast TypeParen, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::TypeParen(node));
+    ancestors.set_field("paren_token");
+    tokens_helper(v, &node.paren_token.span, ancestors);
+    ancestors.set_field("elem");
+    v.visit_type(&*node.elem, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_path<'ast, V>(v: &mut V, node: &'ast TypePath, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::TypePath(node));
+    ancestors.set_field("qself");
+    if let Some(it) = &node.qself {
+        v.visit_qself(it, ancestors);
+    };
+    ancestors.set_field("path");
+    v.visit_path(&node.path, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_ptr<'ast, V>(v: &mut V, node: &'ast TypePtr, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::TypePtr(node));
+    ancestors.set_field("star_token");
+    tokens_helper(v, &node.star_token.spans, ancestors);
+    ancestors.set_field("const_token");
+    if let Some(it) = &node.const_token {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("mutability");
+    if let Some(it) = &node.mutability {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("elem");
+    v.visit_type(&*node.elem, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_reference<'ast, V>(
+    v: &mut V,
+    node: &'ast TypeReference,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::TypeReference(node));
+    ancestors.set_field("and_token");
+    tokens_helper(v, &node.and_token.spans, ancestors);
+    ancestors.set_field("lifetime");
+    if let Some(it) = &node.lifetime {
+        v.visit_lifetime(it, ancestors);
+    };
+    ancestors.set_field("mutability");
+    if let Some(it) = &node.mutability {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("elem");
+    v.visit_type(&*node.elem, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_slice<'ast, V>(v: &mut V, node: &'ast TypeSlice, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::TypeSlice(node));
+    ancestors.set_field("bracket_token");
+    tokens_helper(v, &node.bracket_token.span, ancestors);
+    ancestors.set_field("elem");
+    v.visit_type(&*node.elem, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_trait_object<'ast, V>(
+    v: &mut V,
+    node: &'ast TypeTraitObject,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::TypeTraitObject(node));
+    ancestors.set_field("dyn_token");
+    if let Some(it) = &node.dyn_token {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("bounds");
+    for (i, el) in Punctuated::pairs(&node.bounds).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_type_param_bound(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_type_tuple<'ast, V>(v: &mut V, node: &'ast TypeTuple, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::TypeTuple(node));
+    ancestors.set_field("paren_token");
+    tokens_helper(v, &node.paren_token.span, ancestors);
+    ancestors.set_field("elems");
+    for (i, el) in Punctuated::pairs(&node.elems).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_type(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_un_op<'ast, V>(v: &mut V, node: &'ast UnOp, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::UnOp(node));
+    match node {
+        UnOp::Deref(_binding_0) => {
+            ancestors.set_field("Deref");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        UnOp::Not(_binding_0) => {
+            ancestors.set_field("Not");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+        UnOp::Neg(_binding_0) => {
+            ancestors.set_field("Neg");
+            tokens_helper(v, &_binding_0.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_use_glob<'ast, V>(v: &mut V, node: &'ast UseGlob, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::UseGlob(node));
+    ancestors.set_field("star_token");
+    tokens_helper(v, &node.star_token.spans, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_use_group<'ast, V>(v: &mut V, node: &'ast UseGroup, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::UseGroup(node));
+    ancestors.set_field("brace_token");
+    tokens_helper(v, &node.brace_token.span, ancestors);
+    ancestors.set_field("items");
+    for (i, el) in Punctuated::pairs(&node.items).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_use_tree(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_use_name<'ast, V>(v: &mut V, node: &'ast UseName, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::UseName(node));
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_use_path<'ast, V>(v: &mut V, node: &'ast UsePath, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::UsePath(node));
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.set_field("colon2_token");
+    tokens_helper(v, &node.colon2_token.spans, ancestors);
+    ancestors.set_field("tree");
+    v.visit_use_tree(&*node.tree, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_use_rename<'ast, V>(v: &mut V, node: &'ast UseRename, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::UseRename(node));
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.set_field("as_token");
+    tokens_helper(v, &node.as_token.span, ancestors);
+    ancestors.set_field("rename");
+    v.visit_ident(&node.rename, ancestors);
+    ancestors.pop();
+}
+#[cfg(feature = "full")]
+pub fn visit_use_tree<'ast, V>(v: &mut V, node: &'ast UseTree, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::UseTree(node));
+    match node {
+        UseTree::Path(_binding_0) => {
+            ancestors.set_field("Path");
+            v.visit_use_path(_binding_0, ancestors);
+        }
+        UseTree::Name(_binding_0) => {
+            ancestors.set_field("Name");
+            v.visit_use_name(_binding_0, ancestors);
+        }
+        UseTree::Rename(_binding_0) => {
+            ancestors.set_field("Rename");
+            v.visit_use_rename(_binding_0, ancestors);
+        }
+        UseTree::Glob(_binding_0) => {
+            ancestors.set_field("Glob");
+            v.visit_use_glob(_binding_0, ancestors);
+        }
+        UseTree::Group(_binding_0) => {
+            ancestors.set_field("Group");
+            v.visit_use_group(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_variadic<'ast, V>(v: &mut V, node: &'ast Variadic, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Variadic(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("dots");
+    tokens_helper(v, &node.dots.spans, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_variant<'ast, V>(v: &mut V, node: &'ast Variant, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Variant(node));
+    ancestors.set_field("attrs");
+    for (i, it) in node.attrs.iter().enumerate() {
+        ancestors.set_index(i);
+        v.visit_attribute(it, ancestors);
+    }
+    ancestors.set_field("ident");
+    v.visit_ident(&node.ident, ancestors);
+    ancestors.set_field("fields");
+    v.visit_fields(&node.fields, ancestors);
+    ancestors.set_field("discriminant");
+    if let Some(it) = &node.discriminant {
+        tokens_helper(v, &(it).0.spans, ancestors);
+        v.visit_expr(&(it).1, ancestors);
+    };
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_vis_crate<'ast, V>(v: &mut V, node: &'ast VisCrate, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::VisCrate(node));
+    ancestors.set_field("crate_token");
+    tokens_helper(v, &node.crate_token.span, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_vis_public<'ast, V>(v: &mut V, node: &'ast VisPublic, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::VisPublic(node));
+    ancestors.set_field("pub_token");
+    tokens_helper(v, &node.pub_token.span, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_vis_restricted<'ast, V>(
+    v: &mut V,
+    node: &'ast VisRestricted,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::VisRestricted(node));
+    ancestors.set_field("pub_token");
+    tokens_helper(v, &node.pub_token.span, ancestors);
+    ancestors.set_field("paren_token");
+    tokens_helper(v, &node.paren_token.span, ancestors);
+    ancestors.set_field("in_token");
+    if let Some(it) = &node.in_token {
+        tokens_helper(v, &it.span, ancestors);
+    };
+    ancestors.set_field("path");
+    v.visit_path(&*node.path, ancestors);
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_visibility<'ast, V>(v: &mut V, node: &'ast Visibility, ancestors: &mut Ancestors<'ast>)
+where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::Visibility(node));
+    match node {
+        Visibility::Public(_binding_0) => {
+            ancestors.set_field("Public");
+            v.visit_vis_public(_binding_0, ancestors);
+        }
+        Visibility::Crate(_binding_0) => {
+            ancestors.set_field("Crate");
+            v.visit_vis_crate(_binding_0, ancestors);
+        }
+        Visibility::Restricted(_binding_0) => {
+            ancestors.set_field("Restricted");
+            v.visit_vis_restricted(_binding_0, ancestors);
+        }
+        Visibility::Inherited => {}
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_where_clause<'ast, V>(
+    v: &mut V,
+    node: &'ast WhereClause,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::WhereClause(node));
+    ancestors.set_field("where_token");
+    tokens_helper(v, &node.where_token.span, ancestors);
+    ancestors.set_field("predicates");
+    for (i, el) in Punctuated::pairs(&node.predicates).enumerate() {
+        let (it, p) = el.into_tuple();
+        ancestors.set_index(i);
+        v.visit_where_predicate(it, ancestors);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans, ancestors);
+        }
+    }
+    ancestors.pop();
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_where_predicate<'ast, V>(
+    v: &mut V,
+    node: &'ast WherePredicate,
+    ancestors: &mut Ancestors<'ast>,
+) where
+    V: VisitAncestors<'ast> + ?Sized,
+{
+    ancestors.push(Node::WherePredicate(node));
+    match node {
+        WherePredicate::Type(_binding_0) => {
+            ancestors.set_field("Type");
+            v.visit_predicate_type(_binding_0, ancestors);
+        }
+        WherePredicate::Lifetime(_binding_0) => {
+            ancestors.set_field("Lifetime");
+            v.visit_predicate_lifetime(_binding_0, ancestors);
+        }
+        WherePredicate::Eq(_binding_0) => {
+            ancestors.set_field("Eq");
+            v.visit_predicate_eq(_binding_0, ancestors);
+        }
+    }
+    ancestors.pop();
+}
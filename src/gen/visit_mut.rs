@@ -48,6 +48,18 @@ pub trait VisitMut {
         visit_arm_mut(self, i);
     }
     #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_attr_meta_mut(&mut self, i: &mut AttrMeta) {
+        visit_attr_meta_mut(self, i);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_attr_meta_list_mut(&mut self, i: &mut AttrMetaList) {
+        visit_attr_meta_list_mut(self, i);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_attr_meta_name_value_mut(&mut self, i: &mut AttrMetaNameValue) {
+        visit_attr_meta_name_value_mut(self, i);
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
     fn visit_attr_style_mut(&mut self, i: &mut AttrStyle) {
         visit_attr_style_mut(self, i);
     }
@@ -156,6 +168,10 @@ pub trait VisitMut {
         visit_expr_closure_mut(self, i);
     }
     #[cfg(feature = "full")]
+    fn visit_expr_const_mut(&mut self, i: &mut ExprConst) {
+        visit_expr_const_mut(self, i);
+    }
+    #[cfg(feature = "full")]
     fn visit_expr_continue_mut(&mut self, i: &mut ExprContinue) {
         visit_expr_continue_mut(self, i);
     }
@@ -216,6 +232,10 @@ pub trait VisitMut {
         visit_expr_range_mut(self, i);
     }
     #[cfg(feature = "full")]
+    fn visit_expr_raw_addr_mut(&mut self, i: &mut ExprRawAddr) {
+        visit_expr_raw_addr_mut(self, i);
+    }
+    #[cfg(feature = "full")]
     fn visit_expr_reference_mut(&mut self, i: &mut ExprReference) {
         visit_expr_reference_mut(self, i);
     }
@@ -379,6 +399,10 @@ pub trait VisitMut {
         visit_item_fn_mut(self, i);
     }
     #[cfg(feature = "full")]
+    fn visit_item_fn_decl_mut(&mut self, i: &mut ItemFnDecl) {
+        visit_item_fn_decl_mut(self, i);
+    }
+    #[cfg(feature = "full")]
     fn visit_item_foreign_mod_mut(&mut self, i: &mut ItemForeignMod) {
         visit_item_foreign_mod_mut(self, i);
     }
@@ -465,6 +489,10 @@ pub trait VisitMut {
     fn visit_local_mut(&mut self, i: &mut Local) {
         visit_local_mut(self, i);
     }
+    #[cfg(feature = "full")]
+    fn visit_local_init_mut(&mut self, i: &mut LocalInit) {
+        visit_local_init_mut(self, i);
+    }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn visit_macro_mut(&mut self, i: &mut Macro) {
         visit_macro_mut(self, i);
@@ -473,6 +501,34 @@ pub trait VisitMut {
     fn visit_macro_delimiter_mut(&mut self, i: &mut MacroDelimiter) {
         visit_macro_delimiter_mut(self, i);
     }
+    #[cfg(feature = "full")]
+    fn visit_macro_group_mut(&mut self, i: &mut MacroGroup) {
+        visit_macro_group_mut(self, i);
+    }
+    #[cfg(feature = "full")]
+    fn visit_macro_metavar_mut(&mut self, i: &mut MacroMetavar) {
+        visit_macro_metavar_mut(self, i);
+    }
+    #[cfg(feature = "full")]
+    fn visit_macro_rep_op_mut(&mut self, i: &mut MacroRepOp) {
+        visit_macro_rep_op_mut(self, i);
+    }
+    #[cfg(feature = "full")]
+    fn visit_macro_repetition_mut(&mut self, i: &mut MacroRepetition) {
+        visit_macro_repetition_mut(self, i);
+    }
+    #[cfg(feature = "full")]
+    fn visit_macro_rule_mut(&mut self, i: &mut MacroRule) {
+        visit_macro_rule_mut(self, i);
+    }
+    #[cfg(feature = "full")]
+    fn visit_macro_rules_mut(&mut self, i: &mut MacroRules) {
+        visit_macro_rules_mut(self, i);
+    }
+    #[cfg(feature = "full")]
+    fn visit_macro_tree_mut(&mut self, i: &mut MacroTree) {
+        visit_macro_tree_mut(self, i);
+    }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn visit_member_mut(&mut self, i: &mut Member) {
         visit_member_mut(self, i);
@@ -510,6 +566,10 @@ pub trait VisitMut {
         visit_pat_box_mut(self, i);
     }
     #[cfg(feature = "full")]
+    fn visit_pat_const_mut(&mut self, i: &mut PatConst) {
+        visit_pat_const_mut(self, i);
+    }
+    #[cfg(feature = "full")]
     fn visit_pat_ident_mut(&mut self, i: &mut PatIdent) {
         visit_pat_ident_mut(self, i);
     }
@@ -577,6 +637,10 @@ pub trait VisitMut {
     fn visit_path_segment_mut(&mut self, i: &mut PathSegment) {
         visit_path_segment_mut(self, i);
     }
+    #[cfg(feature = "full")]
+    fn visit_pointer_mutability_mut(&mut self, i: &mut PointerMutability) {
+        visit_pointer_mutability_mut(self, i);
+    }
     #[cfg(any(feature = "derive", feature = "full"))]
     fn visit_predicate_eq_mut(&mut self, i: &mut PredicateEq) {
         visit_predicate_eq_mut(self, i);
@@ -823,6 +887,41 @@ where
     };
 }
 #[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_attr_meta_mut<V>(v: &mut V, node: &mut AttrMeta)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        AttrMeta::Path(_binding_0) => {
+            v.visit_path_mut(_binding_0);
+        }
+        AttrMeta::List(_binding_0) => {
+            v.visit_attr_meta_list_mut(_binding_0);
+        }
+        AttrMeta::NameValue(_binding_0) => {
+            v.visit_attr_meta_name_value_mut(_binding_0);
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_attr_meta_list_mut<V>(v: &mut V, node: &mut AttrMetaList)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_path_mut(&mut node.path);
+    v.visit_macro_delimiter_mut(&mut node.delimiter);
+    skip!(node.tokens);
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+pub fn visit_attr_meta_name_value_mut<V>(v: &mut V, node: &mut AttrMetaNameValue)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_path_mut(&mut node.path);
+    tokens_helper(v, &mut node.eq_token.spans);
+    v.visit_expr_mut(&mut node.value);
+}
+#[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_attr_style_mut<V>(v: &mut V, node: &mut AttrStyle)
 where
     V: VisitMut + ?Sized,
@@ -1126,6 +1225,9 @@ where
         Expr::Closure(_binding_0) => {
             full!(v.visit_expr_closure_mut(_binding_0));
         }
+        Expr::Const(_binding_0) => {
+            full!(v.visit_expr_const_mut(_binding_0));
+        }
         Expr::Continue(_binding_0) => {
             full!(v.visit_expr_continue_mut(_binding_0));
         }
@@ -1171,6 +1273,9 @@ where
         Expr::Range(_binding_0) => {
             full!(v.visit_expr_range_mut(_binding_0));
         }
+        Expr::RawAddr(_binding_0) => {
+            full!(v.visit_expr_raw_addr_mut(_binding_0));
+        }
         Expr::Reference(_binding_0) => {
             full!(v.visit_expr_reference_mut(_binding_0));
         }
@@ -1392,6 +1497,17 @@ where
     v.visit_expr_mut(&mut *node.body);
 }
 #[cfg(feature = "full")]
+pub fn visit_expr_const_mut<V>(v: &mut V, node: &mut ExprConst)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in &mut node.attrs {
+        v.visit_attribute_mut(it);
+    }
+    tokens_helper(v, &mut node.const_token.span);
+    v.visit_block_mut(&mut node.block);
+}
+#[cfg(feature = "full")]
 pub fn visit_expr_continue_mut<V>(v: &mut V, node: &mut ExprContinue)
 where
     V: VisitMut + ?Sized,
@@ -1598,6 +1714,19 @@ where
     };
 }
 #[cfg(feature = "full")]
+pub fn visit_expr_raw_addr_mut<V>(v: &mut V, node: &mut ExprRawAddr)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in &mut node.attrs {
+        v.visit_attribute_mut(it);
+    }
+    tokens_helper(v, &mut node.and_token.spans);
+    tokens_helper(v, &mut node.raw.span);
+    v.visit_pointer_mutability_mut(&mut node.mutability);
+    v.visit_expr_mut(&mut *node.expr);
+}
+#[cfg(feature = "full")]
 pub fn visit_expr_reference_mut<V>(v: &mut V, node: &mut ExprReference)
 where
     V: VisitMut + ?Sized,
@@ -2158,6 +2287,9 @@ where
         Item::Fn(_binding_0) => {
             v.visit_item_fn_mut(_binding_0);
         }
+        Item::FnDecl(_binding_0) => {
+            v.visit_item_fn_decl_mut(_binding_0);
+        }
         Item::ForeignMod(_binding_0) => {
             v.visit_item_foreign_mod_mut(_binding_0);
         }
@@ -2213,8 +2345,10 @@ where
     v.visit_ident_mut(&mut node.ident);
     tokens_helper(v, &mut node.colon_token.spans);
     v.visit_type_mut(&mut *node.ty);
-    tokens_helper(v, &mut node.eq_token.spans);
-    v.visit_expr_mut(&mut *node.expr);
+    if let Some(it) = &mut node.expr {
+        tokens_helper(v, &mut (it).0.spans);
+        v.visit_expr_mut(&mut *(it).1);
+    };
     tokens_helper(v, &mut node.semi_token.spans);
 }
 #[cfg(feature = "full")]
@@ -2269,6 +2403,18 @@ where
     v.visit_block_mut(&mut *node.block);
 }
 #[cfg(feature = "full")]
+pub fn visit_item_fn_decl_mut<V>(v: &mut V, node: &mut ItemFnDecl)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in &mut node.attrs {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_visibility_mut(&mut node.vis);
+    v.visit_signature_mut(&mut node.sig);
+    tokens_helper(v, &mut node.semi_token.spans);
+}
+#[cfg(feature = "full")]
 pub fn visit_item_foreign_mod_mut<V>(v: &mut V, node: &mut ItemForeignMod)
 where
     V: VisitMut + ?Sized,
@@ -2276,6 +2422,9 @@ where
     for it in &mut node.attrs {
         v.visit_attribute_mut(it);
     }
+    if let Some(it) = &mut node.unsafety {
+        tokens_helper(v, &mut it.span);
+    };
     v.visit_abi_mut(&mut node.abi);
     tokens_helper(v, &mut node.brace_token.span);
     for it in &mut node.items {
@@ -2349,6 +2498,9 @@ where
         v.visit_attribute_mut(it);
     }
     v.visit_visibility_mut(&mut node.vis);
+    if let Some(it) = &mut node.unsafety {
+        tokens_helper(v, &mut it.span);
+    };
     tokens_helper(v, &mut node.mod_token.span);
     v.visit_ident_mut(&mut node.ident);
     if let Some(it) = &mut node.content {
@@ -2375,10 +2527,14 @@ where
         tokens_helper(v, &mut it.span);
     };
     v.visit_ident_mut(&mut node.ident);
-    tokens_helper(v, &mut node.colon_token.spans);
-    v.visit_type_mut(&mut *node.ty);
-    tokens_helper(v, &mut node.eq_token.spans);
-    v.visit_expr_mut(&mut *node.expr);
+    if let Some(it) = &mut node.ty {
+        tokens_helper(v, &mut (it).0.spans);
+        v.visit_type_mut(&mut *(it).1);
+    };
+    if let Some(it) = &mut node.expr {
+        tokens_helper(v, &mut (it).0.spans);
+        v.visit_expr_mut(&mut *(it).1);
+    };
     tokens_helper(v, &mut node.semi_token.spans);
 }
 #[cfg(feature = "full")]
@@ -2613,11 +2769,22 @@ where
     tokens_helper(v, &mut node.let_token.span);
     v.visit_pat_mut(&mut node.pat);
     if let Some(it) = &mut node.init {
-        tokens_helper(v, &mut (it).0.spans);
-        v.visit_expr_mut(&mut *(it).1);
+        v.visit_local_init_mut(it);
     };
     tokens_helper(v, &mut node.semi_token.spans);
 }
+#[cfg(feature = "full")]
+pub fn visit_local_init_mut<V>(v: &mut V, node: &mut LocalInit)
+where
+    V: VisitMut + ?Sized,
+{
+    tokens_helper(v, &mut node.eq_token.spans);
+    v.visit_expr_mut(&mut *node.expr);
+    if let Some(it) = &mut node.diverge {
+        tokens_helper(v, &mut (it).0.span);
+        v.visit_block_mut(&mut (it).1);
+    };
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_macro_mut<V>(v: &mut V, node: &mut Macro)
 where
@@ -2645,6 +2812,100 @@ where
         }
     }
 }
+#[cfg(feature = "full")]
+pub fn visit_macro_group_mut<V>(v: &mut V, node: &mut MacroGroup)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_macro_delimiter_mut(&mut node.delimiter);
+    for it in &mut node.content {
+        v.visit_macro_tree_mut(it);
+    }
+}
+#[cfg(feature = "full")]
+pub fn visit_macro_metavar_mut<V>(v: &mut V, node: &mut MacroMetavar)
+where
+    V: VisitMut + ?Sized,
+{
+    tokens_helper(v, &mut node.dollar_token.spans);
+    v.visit_ident_mut(&mut node.name);
+    if let Some(it) = &mut node.specifier {
+        tokens_helper(v, &mut (it).0.spans);
+        v.visit_ident_mut(&mut (it).1);
+    };
+}
+#[cfg(feature = "full")]
+pub fn visit_macro_rep_op_mut<V>(v: &mut V, node: &mut MacroRepOp)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        MacroRepOp::ZeroOrMore(_binding_0) => {
+            tokens_helper(v, &mut _binding_0.spans);
+        }
+        MacroRepOp::OneOrMore(_binding_0) => {
+            tokens_helper(v, &mut _binding_0.spans);
+        }
+        MacroRepOp::ZeroOrOne(_binding_0) => {
+            tokens_helper(v, &mut _binding_0.spans);
+        }
+    }
+}
+#[cfg(feature = "full")]
+pub fn visit_macro_repetition_mut<V>(v: &mut V, node: &mut MacroRepetition)
+where
+    V: VisitMut + ?Sized,
+{
+    tokens_helper(v, &mut node.dollar_token.spans);
+    tokens_helper(v, &mut node.paren_token.span);
+    for it in &mut node.content {
+        v.visit_macro_tree_mut(it);
+    }
+    skip!(node.separator);
+    v.visit_macro_rep_op_mut(&mut node.op);
+}
+#[cfg(feature = "full")]
+pub fn visit_macro_rule_mut<V>(v: &mut V, node: &mut MacroRule)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_macro_group_mut(&mut node.matcher);
+    tokens_helper(v, &mut node.fat_arrow_token.spans);
+    v.visit_macro_group_mut(&mut node.transcriber);
+}
+#[cfg(feature = "full")]
+pub fn visit_macro_rules_mut<V>(v: &mut V, node: &mut MacroRules)
+where
+    V: VisitMut + ?Sized,
+{
+    for el in Punctuated::pairs_mut(&mut node.rules) {
+        let (it, p) = el.into_tuple();
+        v.visit_macro_rule_mut(it);
+        if let Some(p) = p {
+            tokens_helper(v, &mut p.spans);
+        }
+    }
+}
+#[cfg(feature = "full")]
+pub fn visit_macro_tree_mut<V>(v: &mut V, node: &mut MacroTree)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        MacroTree::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        MacroTree::Group(_binding_0) => {
+            v.visit_macro_group_mut(_binding_0);
+        }
+        MacroTree::Metavar(_binding_0) => {
+            v.visit_macro_metavar_mut(_binding_0);
+        }
+        MacroTree::Repetition(_binding_0) => {
+            v.visit_macro_repetition_mut(_binding_0);
+        }
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_member_mut<V>(v: &mut V, node: &mut Member)
 where
@@ -2756,6 +3017,9 @@ where
         Pat::Box(_binding_0) => {
             v.visit_pat_box_mut(_binding_0);
         }
+        Pat::Const(_binding_0) => {
+            v.visit_pat_const_mut(_binding_0);
+        }
         Pat::Ident(_binding_0) => {
             v.visit_pat_ident_mut(_binding_0);
         }
@@ -2816,6 +3080,17 @@ where
     v.visit_pat_mut(&mut *node.pat);
 }
 #[cfg(feature = "full")]
+pub fn visit_pat_const_mut<V>(v: &mut V, node: &mut PatConst)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in &mut node.attrs {
+        v.visit_attribute_mut(it);
+    }
+    tokens_helper(v, &mut node.const_token.span);
+    v.visit_block_mut(&mut node.block);
+}
+#[cfg(feature = "full")]
 pub fn visit_pat_ident_mut<V>(v: &mut V, node: &mut PatIdent)
 where
     V: VisitMut + ?Sized,
@@ -3050,6 +3325,20 @@ where
     v.visit_ident_mut(&mut node.ident);
     v.visit_path_arguments_mut(&mut node.arguments);
 }
+#[cfg(feature = "full")]
+pub fn visit_pointer_mutability_mut<V>(v: &mut V, node: &mut PointerMutability)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        PointerMutability::Const(_binding_0) => {
+            tokens_helper(v, &mut _binding_0.span);
+        }
+        PointerMutability::Mut(_binding_0) => {
+            tokens_helper(v, &mut _binding_0.span);
+        }
+    }
+}
 #[cfg(any(feature = "derive", feature = "full"))]
 pub fn visit_predicate_eq_mut<V>(v: &mut V, node: &mut PredicateEq)
 where
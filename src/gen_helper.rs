@@ -71,6 +71,85 @@ pub mod fold {
     }
 }
 
+#[cfg(feature = "try-fold")]
+pub mod try_fold {
+    use crate::punctuated::{Pair, Punctuated};
+    use crate::try_fold::TryFold;
+    use proc_macro2::Span;
+
+    pub trait FoldHelper: Sized {
+        type Item;
+        fn lift<F, M>(self, folder: &mut F, map: M) -> Result<Self, F::Error>
+        where
+            F: TryFold + ?Sized,
+            M: FnMut(&mut F, Self::Item) -> Result<Self::Item, F::Error>;
+    }
+
+    impl<T> FoldHelper for Vec<T> {
+        type Item = T;
+        fn lift<F, M>(self, folder: &mut F, mut map: M) -> Result<Self, F::Error>
+        where
+            F: TryFold + ?Sized,
+            M: FnMut(&mut F, Self::Item) -> Result<Self::Item, F::Error>,
+        {
+            self.into_iter().map(|t| map(folder, t)).collect()
+        }
+    }
+
+    impl<T, U> FoldHelper for Punctuated<T, U> {
+        type Item = T;
+        fn lift<F, M>(self, folder: &mut F, mut map: M) -> Result<Self, F::Error>
+        where
+            F: TryFold + ?Sized,
+            M: FnMut(&mut F, Self::Item) -> Result<Self::Item, F::Error>,
+        {
+            self.into_pairs()
+                .map(Pair::into_tuple)
+                .map(|(t, u)| Ok(Pair::new(map(folder, t)?, u)))
+                .collect()
+        }
+    }
+
+    pub fn tokens_helper<F: TryFold + ?Sized, S: Spans>(
+        folder: &mut F,
+        spans: &S,
+    ) -> Result<S, F::Error> {
+        spans.fold(folder)
+    }
+
+    pub trait Spans: Sized {
+        fn fold<F: TryFold + ?Sized>(&self, folder: &mut F) -> Result<Self, F::Error>;
+    }
+
+    impl Spans for Span {
+        fn fold<F: TryFold + ?Sized>(&self, folder: &mut F) -> Result<Self, F::Error> {
+            folder.fold_span(*self)
+        }
+    }
+
+    impl Spans for [Span; 1] {
+        fn fold<F: TryFold + ?Sized>(&self, folder: &mut F) -> Result<Self, F::Error> {
+            Ok([folder.fold_span(self[0])?])
+        }
+    }
+
+    impl Spans for [Span; 2] {
+        fn fold<F: TryFold + ?Sized>(&self, folder: &mut F) -> Result<Self, F::Error> {
+            Ok([folder.fold_span(self[0])?, folder.fold_span(self[1])?])
+        }
+    }
+
+    impl Spans for [Span; 3] {
+        fn fold<F: TryFold + ?Sized>(&self, folder: &mut F) -> Result<Self, F::Error> {
+            Ok([
+                folder.fold_span(self[0])?,
+                folder.fold_span(self[1])?,
+                folder.fold_span(self[2])?,
+            ])
+        }
+    }
+}
+
 #[cfg(feature = "visit")]
 pub mod visit {
     use crate::visit::Visit;
@@ -112,6 +191,115 @@ pub mod visit {
     }
 }
 
+#[cfg(feature = "try-visit")]
+pub mod try_visit {
+    use crate::try_visit::TryVisit;
+    use proc_macro2::Span;
+
+    pub fn tokens_helper<'ast, V: TryVisit<'ast> + ?Sized, S: Spans>(
+        visitor: &mut V,
+        spans: &S,
+    ) -> Result<(), V::Break> {
+        spans.visit(visitor)
+    }
+
+    pub trait Spans {
+        fn visit<'ast, V: TryVisit<'ast> + ?Sized>(&self, visitor: &mut V) -> Result<(), V::Break>;
+    }
+
+    impl Spans for Span {
+        fn visit<'ast, V: TryVisit<'ast> + ?Sized>(&self, visitor: &mut V) -> Result<(), V::Break> {
+            visitor.visit_span(self)
+        }
+    }
+
+    impl Spans for [Span; 1] {
+        fn visit<'ast, V: TryVisit<'ast> + ?Sized>(&self, visitor: &mut V) -> Result<(), V::Break> {
+            visitor.visit_span(&self[0])
+        }
+    }
+
+    impl Spans for [Span; 2] {
+        fn visit<'ast, V: TryVisit<'ast> + ?Sized>(&self, visitor: &mut V) -> Result<(), V::Break> {
+            visitor.visit_span(&self[0])?;
+            visitor.visit_span(&self[1])
+        }
+    }
+
+    impl Spans for [Span; 3] {
+        fn visit<'ast, V: TryVisit<'ast> + ?Sized>(&self, visitor: &mut V) -> Result<(), V::Break> {
+            visitor.visit_span(&self[0])?;
+            visitor.visit_span(&self[1])?;
+            visitor.visit_span(&self[2])
+        }
+    }
+}
+
+#[cfg(feature = "visit-ancestors")]
+pub mod visit_ancestors {
+    use crate::visit_ancestors::{Ancestors, VisitAncestors};
+    use proc_macro2::Span;
+
+    pub fn tokens_helper<'ast, V: VisitAncestors<'ast> + ?Sized, S: Spans>(
+        visitor: &mut V,
+        spans: &S,
+        ancestors: &mut Ancestors<'ast>,
+    ) {
+        spans.visit(visitor, ancestors);
+    }
+
+    pub trait Spans {
+        fn visit<'ast, V: VisitAncestors<'ast> + ?Sized>(
+            &self,
+            visitor: &mut V,
+            ancestors: &mut Ancestors<'ast>,
+        );
+    }
+
+    impl Spans for Span {
+        fn visit<'ast, V: VisitAncestors<'ast> + ?Sized>(
+            &self,
+            visitor: &mut V,
+            ancestors: &mut Ancestors<'ast>,
+        ) {
+            visitor.visit_span(self, ancestors);
+        }
+    }
+
+    impl Spans for [Span; 1] {
+        fn visit<'ast, V: VisitAncestors<'ast> + ?Sized>(
+            &self,
+            visitor: &mut V,
+            ancestors: &mut Ancestors<'ast>,
+        ) {
+            visitor.visit_span(&self[0], ancestors);
+        }
+    }
+
+    impl Spans for [Span; 2] {
+        fn visit<'ast, V: VisitAncestors<'ast> + ?Sized>(
+            &self,
+            visitor: &mut V,
+            ancestors: &mut Ancestors<'ast>,
+        ) {
+            visitor.visit_span(&self[0], ancestors);
+            visitor.visit_span(&self[1], ancestors);
+        }
+    }
+
+    impl Spans for [Span; 3] {
+        fn visit<'ast, V: VisitAncestors<'ast> + ?Sized>(
+            &self,
+            visitor: &mut V,
+            ancestors: &mut Ancestors<'ast>,
+        ) {
+            visitor.visit_span(&self[0], ancestors);
+            visitor.visit_span(&self[1], ancestors);
+            visitor.visit_span(&self[2], ancestors);
+        }
+    }
+}
+
 #[cfg(feature = "visit-mut")]
 pub mod visit_mut {
     use crate::visit_mut::VisitMut;
@@ -152,3 +340,218 @@ pub mod visit_mut {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+pub mod serde {
+    use proc_macro2::{Ident, Literal, TokenStream, TokenTree};
+    use serde::de::{
+        self, DeserializeSeed, Deserializer, EnumAccess, Expected, MapAccess, SeqAccess,
+        Unexpected, Visitor,
+    };
+    use serde::ser::{Serialize, Serializer};
+    use serde::Deserialize;
+    use std::fmt::{self, Display};
+    use std::str;
+
+    // Serializes an identifier, literal or token stream as its string
+    // representation.
+    pub struct Ser<'a, T>(pub &'a T);
+
+    // Deserializes an identifier, literal or token stream from its string
+    // representation.
+    pub struct De<T>(pub T);
+
+    impl<'a, T: Display> Serialize for Ser<'a, T> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_str(self.0)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for De<Ident> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let repr = String::deserialize(deserializer)?;
+            match ident(&repr) {
+                Some(ident) => Ok(De(ident)),
+                None => Err(de::Error::invalid_value(
+                    Unexpected::Str(&repr),
+                    &"an identifier",
+                )),
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for De<Literal> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let repr = String::deserialize(deserializer)?;
+            match crate::lit::serde_impls::literal(&repr) {
+                Some(literal) => Ok(De(literal)),
+                None => Err(de::Error::invalid_value(
+                    Unexpected::Str(&repr),
+                    &"a literal",
+                )),
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for De<TokenStream> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let repr = String::deserialize(deserializer)?;
+            match repr.parse() {
+                Ok(tokens) => Ok(De(tokens)),
+                Err(_) => Err(de::Error::invalid_value(
+                    Unexpected::Str(&repr),
+                    &"Rust tokens",
+                )),
+            }
+        }
+    }
+
+    fn ident(repr: &str) -> Option<Ident> {
+        if repr.starts_with("r#") {
+            // Raw identifiers are only constructible by parsing.
+            let mut tokens = repr.parse::<TokenStream>().ok()?.into_iter();
+            return match (tokens.next(), tokens.next()) {
+                (Some(TokenTree::Ident(ident)), None) if ident == repr => Some(ident),
+                _ => None,
+            };
+        }
+        if repr.is_empty() || !crate::ident::xid_ok(repr) {
+            return None;
+        }
+        Some(Ident::new(repr, proc_macro2::Span::call_site()))
+    }
+
+    // An optional token, which is represented by whether it is present.
+    #[cfg(any(feature = "full", feature = "derive"))]
+    pub fn present<T: Default>(present: bool) -> Option<T> {
+        if present {
+            Some(T::default())
+        } else {
+            None
+        }
+    }
+
+    pub fn required<T, E: de::Error>(value: Option<T>, field: &'static str) -> Result<T, E> {
+        match value {
+            Some(value) => Ok(value),
+            None => Err(E::missing_field(field)),
+        }
+    }
+
+    pub fn element<'de, T, A>(
+        seq: &mut A,
+        index: usize,
+        expected: &dyn Expected,
+    ) -> Result<T, A::Error>
+    where
+        T: Deserialize<'de>,
+        A: SeqAccess<'de>,
+    {
+        match seq.next_element()? {
+            Some(value) => Ok(value),
+            None => Err(de::Error::invalid_length(index, expected)),
+        }
+    }
+
+    // The next key of a struct, or an empty string for a field that does not
+    // exist and is ignored.
+    pub fn field<'de, A>(
+        map: &mut A,
+        fields: &'static [&'static str],
+    ) -> Result<Option<&'static str>, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        map.next_key_seed(Identifier {
+            names: fields,
+            variant: false,
+        })
+    }
+
+    pub fn variant<'de, A>(
+        data: A,
+        variants: &'static [&'static str],
+    ) -> Result<(&'static str, A::Variant), A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        data.variant_seed(Identifier {
+            names: variants,
+            variant: true,
+        })
+    }
+
+    // The name of a field or variant, identified either by name or by index
+    // depending on the data format.
+    struct Identifier {
+        names: &'static [&'static str],
+        variant: bool,
+    }
+
+    impl<'de> DeserializeSeed<'de> for Identifier {
+        type Value = &'static str;
+
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_identifier(self)
+        }
+    }
+
+    impl<'de> Visitor<'de> for Identifier {
+        type Value = &'static str;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            if self.variant {
+                formatter.write_str("variant identifier")
+            } else {
+                formatter.write_str("field identifier")
+            }
+        }
+
+        fn visit_u64<E>(self, index: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            match self.names.get(index as usize) {
+                Some(name) => Ok(*name),
+                None if self.variant => Err(E::invalid_value(Unexpected::Unsigned(index), &self)),
+                None => Ok(""),
+            }
+        }
+
+        fn visit_str<E>(self, name: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            match self.names.iter().find(|known| **known == name) {
+                Some(known) => Ok(*known),
+                None if self.variant => Err(E::unknown_variant(name, self.names)),
+                None => Ok(""),
+            }
+        }
+
+        fn visit_bytes<E>(self, name: &[u8]) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            match str::from_utf8(name) {
+                Ok(name) => self.visit_str(name),
+                Err(_) => Err(E::invalid_value(Unexpected::Bytes(name), &self)),
+            }
+        }
+    }
+}
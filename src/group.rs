@@ -76,7 +76,8 @@ fn parse_delimited<'a>(
             let scope = crate::buffer::close_span_of_group(*cursor);
             let nested = crate::parse::advance_step_cursor(cursor, content);
             let unexpected = crate::parse::get_unexpected(input);
-            let content = crate::parse::new_parse_buffer(scope, nested, unexpected);
+            let lazy = crate::parse::is_lazy(input);
+            let content = crate::parse::new_parse_buffer(scope, nested, unexpected, lazy);
             Ok(((span, content), rest))
         } else {
             let message = match delimiter {
@@ -31,6 +31,9 @@ ast_enum_of_structs! {
         /// }`.
         Fn(ItemFn),
 
+        /// A function signature without a body: `fn ping();`.
+        FnDecl(ItemFnDecl),
+
         /// A block of foreign items: `extern "C" { ... }`.
         ForeignMod(ItemForeignMod),
 
@@ -111,8 +114,8 @@ ast_struct! {
         pub ident: Ident,
         pub colon_token: Token![:],
         pub ty: Box<Type>,
-        pub eq_token: Token![=],
-        pub expr: Box<Expr>,
+        /// The value of the constant, absent in `const MAX: u16;`.
+        pub expr: Option<(Token![=], Box<Expr>)>,
         pub semi_token: Token![;],
     }
 }
@@ -163,6 +166,23 @@ ast_struct! {
     }
 }
 
+ast_struct! {
+    /// A free-standing function signature without a body: `fn ping();`.
+    ///
+    /// This is not valid Rust at module level, but is accepted by the parser
+    /// and commonly appears in the input of attribute macros that describe an
+    /// interface.
+    ///
+    /// *This type is available only if Syn is built with the `"full"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    pub struct ItemFnDecl {
+        pub attrs: Vec<Attribute>,
+        pub vis: Visibility,
+        pub sig: Signature,
+        pub semi_token: Token![;],
+    }
+}
+
 ast_struct! {
     /// A block of foreign items: `extern "C" { ... }`.
     ///
@@ -170,6 +190,7 @@ ast_struct! {
     #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
     pub struct ItemForeignMod {
         pub attrs: Vec<Attribute>,
+        pub unsafety: Option<Token![unsafe]>,
         pub abi: Abi,
         pub brace_token: token::Brace,
         pub items: Vec<ForeignItem>,
@@ -233,6 +254,7 @@ ast_struct! {
     pub struct ItemMod {
         pub attrs: Vec<Attribute>,
         pub vis: Visibility,
+        pub unsafety: Option<Token![unsafe]>,
         pub mod_token: Token![mod],
         pub ident: Ident,
         pub content: Option<(token::Brace, Vec<Item>)>,
@@ -251,10 +273,10 @@ ast_struct! {
         pub static_token: Token![static],
         pub mutability: Option<Token![mut]>,
         pub ident: Ident,
-        pub colon_token: Token![:],
-        pub ty: Box<Type>,
-        pub eq_token: Token![=],
-        pub expr: Box<Expr>,
+        /// The type of the static, absent in `static BIKE = Shed(42);`.
+        pub ty: Option<(Token![:], Box<Type>)>,
+        /// The value of the static, absent in `static BIKE: Shed;`.
+        pub expr: Option<(Token![=], Box<Expr>)>,
         pub semi_token: Token![;],
     }
 }
@@ -368,6 +390,7 @@ impl Item {
             | Item::Static(ItemStatic { attrs, .. })
             | Item::Const(ItemConst { attrs, .. })
             | Item::Fn(ItemFn { attrs, .. })
+            | Item::FnDecl(ItemFnDecl { attrs, .. })
             | Item::Mod(ItemMod { attrs, .. })
             | Item::ForeignMod(ItemForeignMod { attrs, .. })
             | Item::Type(ItemType { attrs, .. })
@@ -389,6 +412,23 @@ impl Item {
     }
 }
 
+impl ItemMacro {
+    /// Parse the body of a `macro_rules!` definition into its individual
+    /// rules.
+    ///
+    /// Fails if this item is an invocation of some macro other than
+    /// `macro_rules!`. See [`MacroRules`] for an example.
+    #[cfg(feature = "parsing")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    pub fn parse_rules(&self) -> Result<MacroRules> {
+        if !self.mac.path.is_ident("macro_rules") {
+            let span = self.mac.path.segments.last().unwrap().ident.span();
+            return Err(Error::new(span, "expected `macro_rules!`"));
+        }
+        self.mac.parse_body()
+    }
+}
+
 impl From<DeriveInput> for Item {
     fn from(input: DeriveInput) -> Item {
         match input.data {
@@ -1001,8 +1041,12 @@ pub mod parsing {
                 let vis: Visibility = input.parse()?;
                 let sig: Signature = input.parse()?;
                 if input.peek(Token![;]) {
-                    input.parse::<Token![;]>()?;
-                    Ok(Item::Verbatim(verbatim::between(begin, input)))
+                    Ok(Item::FnDecl(ItemFnDecl {
+                        attrs: Vec::new(),
+                        vis,
+                        sig,
+                        semi_token: input.parse()?,
+                    }))
                 } else {
                     parse_rest_of_fn(input, Vec::new(), vis, sig).map(Item::Fn)
                 }
@@ -1027,68 +1071,12 @@ pub mod parsing {
             } else if lookahead.peek(Token![use]) {
                 input.parse().map(Item::Use)
             } else if lookahead.peek(Token![static]) {
-                let vis = input.parse()?;
-                let static_token = input.parse()?;
-                let mutability = input.parse()?;
-                let ident = input.parse()?;
-                if input.peek(Token![=]) {
-                    input.parse::<Token![=]>()?;
-                    input.parse::<Expr>()?;
-                    input.parse::<Token![;]>()?;
-                    Ok(Item::Verbatim(verbatim::between(begin, input)))
-                } else {
-                    let colon_token = input.parse()?;
-                    let ty = input.parse()?;
-                    if input.peek(Token![;]) {
-                        input.parse::<Token![;]>()?;
-                        Ok(Item::Verbatim(verbatim::between(begin, input)))
-                    } else {
-                        Ok(Item::Static(ItemStatic {
-                            attrs: Vec::new(),
-                            vis,
-                            static_token,
-                            mutability,
-                            ident,
-                            colon_token,
-                            ty,
-                            eq_token: input.parse()?,
-                            expr: input.parse()?,
-                            semi_token: input.parse()?,
-                        }))
-                    }
-                }
+                input.parse().map(Item::Static)
             } else if lookahead.peek(Token![const]) {
                 ahead.parse::<Token![const]>()?;
                 let lookahead = ahead.lookahead1();
                 if lookahead.peek(Ident) || lookahead.peek(Token![_]) {
-                    let vis = input.parse()?;
-                    let const_token = input.parse()?;
-                    let ident = {
-                        let lookahead = input.lookahead1();
-                        if lookahead.peek(Ident) || lookahead.peek(Token![_]) {
-                            input.call(Ident::parse_any)?
-                        } else {
-                            return Err(lookahead.error());
-                        }
-                    };
-                    let colon_token = input.parse()?;
-                    let ty = input.parse()?;
-                    if input.peek(Token![;]) {
-                        input.parse::<Token![;]>()?;
-                        Ok(Item::Verbatim(verbatim::between(begin, input)))
-                    } else {
-                        Ok(Item::Const(ItemConst {
-                            attrs: Vec::new(),
-                            vis,
-                            const_token,
-                            ident,
-                            colon_token,
-                            ty,
-                            eq_token: input.parse()?,
-                            expr: input.parse()?,
-                            semi_token: input.parse()?,
-                        }))
-                    }
+                    input.parse().map(Item::Const)
                 } else {
                     Err(lookahead.error())
                 }
@@ -1107,15 +1095,15 @@ pub mod parsing {
                         Ok(Item::Verbatim(verbatim::between(begin, input)))
                     }
                 } else if lookahead.peek(Token![extern]) {
-                    input.parse::<Visibility>()?;
-                    input.parse::<Token![unsafe]>()?;
-                    input.parse::<ItemForeignMod>()?;
-                    Ok(Item::Verbatim(verbatim::between(begin, input)))
+                    if vis.is_inherited() {
+                        input.parse().map(Item::ForeignMod)
+                    } else {
+                        input.parse::<Visibility>()?;
+                        input.parse::<ItemForeignMod>()?;
+                        Ok(Item::Verbatim(verbatim::between(begin, input)))
+                    }
                 } else if lookahead.peek(Token![mod]) {
-                    input.parse::<Visibility>()?;
-                    input.parse::<Token![unsafe]>()?;
-                    input.parse::<ItemMod>()?;
-                    Ok(Item::Verbatim(verbatim::between(begin, input)))
+                    input.parse().map(Item::Mod)
                 } else {
                     Err(lookahead.error())
                 }
@@ -1396,10 +1384,27 @@ pub mod parsing {
                 static_token: input.parse()?,
                 mutability: input.parse()?,
                 ident: input.parse()?,
-                colon_token: input.parse()?,
-                ty: input.parse()?,
-                eq_token: input.parse()?,
-                expr: input.parse()?,
+                ty: {
+                    let lookahead = input.lookahead1();
+                    if lookahead.peek(Token![:]) {
+                        let colon_token: Token![:] = input.parse()?;
+                        let ty: Type = input.parse()?;
+                        Some((colon_token, Box::new(ty)))
+                    } else if lookahead.peek(Token![=]) {
+                        None
+                    } else {
+                        return Err(lookahead.error());
+                    }
+                },
+                expr: {
+                    if input.peek(Token![=]) {
+                        let eq_token: Token![=] = input.parse()?;
+                        let expr: Expr = input.parse()?;
+                        Some((eq_token, Box::new(expr)))
+                    } else {
+                        None
+                    }
+                },
                 semi_token: input.parse()?,
             })
         }
@@ -1422,8 +1427,15 @@ pub mod parsing {
                 },
                 colon_token: input.parse()?,
                 ty: input.parse()?,
-                eq_token: input.parse()?,
-                expr: input.parse()?,
+                expr: {
+                    if input.peek(Token![=]) {
+                        let eq_token: Token![=] = input.parse()?;
+                        let expr: Expr = input.parse()?;
+                        Some((eq_token, Box::new(expr)))
+                    } else {
+                        None
+                    }
+                },
                 semi_token: input.parse()?,
             })
         }
@@ -1531,6 +1543,18 @@ pub mod parsing {
         }
     }
 
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    impl Parse for ItemFnDecl {
+        fn parse(input: ParseStream) -> Result<Self> {
+            Ok(ItemFnDecl {
+                attrs: input.call(Attribute::parse_outer)?,
+                vis: input.parse()?,
+                sig: input.parse()?,
+                semi_token: input.parse()?,
+            })
+        }
+    }
+
     fn parse_rest_of_fn(
         input: ParseStream,
         mut attrs: Vec<Attribute>,
@@ -1539,8 +1563,7 @@ pub mod parsing {
     ) -> Result<ItemFn> {
         let content;
         let brace_token = braced!(content in input);
-        attr::parsing::parse_inner(&content, &mut attrs)?;
-        let stmts = content.call(Block::parse_within)?;
+        let stmts = parse_fn_body(&content, &mut attrs)?;
 
         Ok(ItemFn {
             attrs,
@@ -1550,6 +1573,81 @@ pub mod parsing {
         })
     }
 
+    // The inner attributes and statements of a function body, or the tokens
+    // of the body as a single verbatim statement if functions are being parsed
+    // lazily.
+    fn parse_fn_body(content: ParseStream, attrs: &mut Vec<Attribute>) -> Result<Vec<Stmt>> {
+        if crate::parse::is_lazy(content) && !content.is_empty() {
+            let tokens: TokenStream = content.parse()?;
+            return Ok(vec![Stmt::Expr(Expr::Verbatim(tokens))]);
+        }
+        attr::parsing::parse_inner(content, attrs)?;
+        content.call(Block::parse_within)
+    }
+
+    // Parses a body that was left as tokens by `syn::parse_file_lazy`.
+    fn parse_lazy_body(attrs: &mut Vec<Attribute>, block: &mut Block) -> Result<()> {
+        let tokens = match block.stmts.as_slice() {
+            [Stmt::Expr(Expr::Verbatim(tokens))] => tokens.clone(),
+            _ => return Ok(()),
+        };
+        let parser = |input: ParseStream| {
+            let inner = input.call(Attribute::parse_inner)?;
+            let stmts = input.call(Block::parse_within)?;
+            Ok((inner, stmts))
+        };
+        let (inner, stmts) = crate::parse::parse_scoped(parser, block.brace_token.span, tokens)?;
+        attrs.extend(inner);
+        block.stmts = stmts;
+        Ok(())
+    }
+
+    impl ItemFn {
+        /// Parses the body of a function that was left as unparsed tokens by
+        /// [`syn::parse_file_lazy`], moving any inner attributes of the body
+        /// into `attrs`.
+        ///
+        /// Does nothing if the body has been parsed already.
+        ///
+        /// [`syn::parse_file_lazy`]: crate::parse_file_lazy()
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+        pub fn parse_body(&mut self) -> Result<()> {
+            parse_lazy_body(&mut self.attrs, &mut self.block)
+        }
+    }
+
+    impl ImplItemMethod {
+        /// Parses the body of a method that was left as unparsed tokens by
+        /// [`syn::parse_file_lazy`], moving any inner attributes of the body
+        /// into `attrs`.
+        ///
+        /// Does nothing if the body has been parsed already.
+        ///
+        /// [`syn::parse_file_lazy`]: crate::parse_file_lazy()
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+        pub fn parse_body(&mut self) -> Result<()> {
+            parse_lazy_body(&mut self.attrs, &mut self.block)
+        }
+    }
+
+    impl TraitItemMethod {
+        /// Parses the default body of a method that was left as unparsed
+        /// tokens by [`syn::parse_file_lazy`], moving any inner attributes of
+        /// the body into `attrs`.
+        ///
+        /// Does nothing if the method has no default body or it has been
+        /// parsed already.
+        ///
+        /// [`syn::parse_file_lazy`]: crate::parse_file_lazy()
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+        pub fn parse_body(&mut self) -> Result<()> {
+            match &mut self.default {
+                Some(block) => parse_lazy_body(&mut self.attrs, block),
+                None => Ok(()),
+            }
+        }
+    }
+
     #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
     impl Parse for FnArg {
         fn parse(input: ParseStream) -> Result<Self> {
@@ -1671,6 +1769,7 @@ pub mod parsing {
         fn parse(input: ParseStream) -> Result<Self> {
             let mut attrs = input.call(Attribute::parse_outer)?;
             let vis: Visibility = input.parse()?;
+            let unsafety: Option<Token![unsafe]> = input.parse()?;
             let mod_token: Token![mod] = input.parse()?;
             let ident: Ident = input.parse()?;
 
@@ -1679,6 +1778,7 @@ pub mod parsing {
                 Ok(ItemMod {
                     attrs,
                     vis,
+                    unsafety,
                     mod_token,
                     ident,
                     content: None,
@@ -1697,6 +1797,7 @@ pub mod parsing {
                 Ok(ItemMod {
                     attrs,
                     vis,
+                    unsafety,
                     mod_token,
                     ident,
                     content: Some((brace_token, items)),
@@ -1712,6 +1813,7 @@ pub mod parsing {
     impl Parse for ItemForeignMod {
         fn parse(input: ParseStream) -> Result<Self> {
             let mut attrs = input.call(Attribute::parse_outer)?;
+            let unsafety: Option<Token![unsafe]> = input.parse()?;
             let abi: Abi = input.parse()?;
 
             let content;
@@ -1724,6 +1826,7 @@ pub mod parsing {
 
             Ok(ItemForeignMod {
                 attrs,
+                unsafety,
                 abi,
                 brace_token,
                 items,
@@ -2339,8 +2442,7 @@ pub mod parsing {
             let (brace_token, stmts, semi_token) = if lookahead.peek(token::Brace) {
                 let content;
                 let brace_token = braced!(content in input);
-                attr::parsing::parse_inner(&content, &mut attrs)?;
-                let stmts = content.call(Block::parse_within)?;
+                let stmts = parse_fn_body(&content, &mut attrs)?;
                 (Some(brace_token), stmts, None)
             } else if lookahead.peek(Token![;]) {
                 let semi_token: Token![;] = input.parse()?;
@@ -2693,10 +2795,9 @@ pub mod parsing {
             } else {
                 let content;
                 let brace_token = braced!(content in input);
-                attrs.extend(content.call(Attribute::parse_inner)?);
                 Block {
                     brace_token,
-                    stmts: content.call(Block::parse_within)?,
+                    stmts: parse_fn_body(&content, &mut attrs)?,
                 }
             };
 
@@ -2844,10 +2945,14 @@ mod printing {
             self.static_token.to_tokens(tokens);
             self.mutability.to_tokens(tokens);
             self.ident.to_tokens(tokens);
-            self.colon_token.to_tokens(tokens);
-            self.ty.to_tokens(tokens);
-            self.eq_token.to_tokens(tokens);
-            self.expr.to_tokens(tokens);
+            if let Some((colon_token, ty)) = &self.ty {
+                colon_token.to_tokens(tokens);
+                ty.to_tokens(tokens);
+            }
+            if let Some((eq_token, expr)) = &self.expr {
+                eq_token.to_tokens(tokens);
+                expr.to_tokens(tokens);
+            }
             self.semi_token.to_tokens(tokens);
         }
     }
@@ -2861,8 +2966,10 @@ mod printing {
             self.ident.to_tokens(tokens);
             self.colon_token.to_tokens(tokens);
             self.ty.to_tokens(tokens);
-            self.eq_token.to_tokens(tokens);
-            self.expr.to_tokens(tokens);
+            if let Some((eq_token, expr)) = &self.expr {
+                eq_token.to_tokens(tokens);
+                expr.to_tokens(tokens);
+            }
             self.semi_token.to_tokens(tokens);
         }
     }
@@ -2880,11 +2987,22 @@ mod printing {
         }
     }
 
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for ItemFnDecl {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            tokens.append_all(self.attrs.outer());
+            self.vis.to_tokens(tokens);
+            self.sig.to_tokens(tokens);
+            self.semi_token.to_tokens(tokens);
+        }
+    }
+
     #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
     impl ToTokens for ItemMod {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             tokens.append_all(self.attrs.outer());
             self.vis.to_tokens(tokens);
+            self.unsafety.to_tokens(tokens);
             self.mod_token.to_tokens(tokens);
             self.ident.to_tokens(tokens);
             if let Some((brace, items)) = &self.content {
@@ -2902,6 +3020,7 @@ mod printing {
     impl ToTokens for ItemForeignMod {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             tokens.append_all(self.attrs.outer());
+            self.unsafety.to_tokens(tokens);
             self.abi.to_tokens(tokens);
             self.brace_token.surround(tokens, |tokens| {
                 tokens.append_all(self.attrs.inner());
@@ -224,6 +224,72 @@
 //!
 //! <br>
 //!
+//! # Serialization
+//!
+//! With the `"serde"` feature, every syntax tree type implements serde's
+//! `Serialize` and `Deserialize`, for sending syntax trees between processes
+//! or caching them. In a self-describing format like JSON the encoding is:
+//!
+//! - A struct is an object with one entry per field, named as the field.
+//! - An enum is externally tagged: `{"Variant": value}`, or just `"Variant"`
+//!   for a variant without data or whose data is only tokens.
+//! - An `Ident` is its string, for example `"foo"` or `"r#match"`. A
+//!   `Lifetime` is `{"ident": "a"}`.
+//! - A literal is the string of its token exactly as written, for example
+//!   `{"Int": "0x1fu8"}` or `{"Str": "\"hi\\n\""}`, so that the suffix
+//!   and style are preserved.
+//! - A `TokenStream` field, as in `Macro::tokens` or `Attribute::tokens`, is
+//!   the string of its tokens, which is lexed again when deserializing.
+//!   Groups with `Delimiter::None` do not survive this.
+//! - A `Punctuated` is an array of its values. Trailing punctuation is
+//!   represented by a `null` after the last value.
+//! - Tokens such as `Token![fn]` or `token::Paren` are left out, and an
+//!   optional token is `true` or `false` for whether it is present. Tuples
+//!   leave out their tokens too, and a tuple that contains a single value
+//!   other than an `Option` is represented by that value.
+//! - Spans are left out. Deserialized nodes have `Span::call_site()` spans.
+//!
+//! When deserializing, fields of type `Option`, `Vec` or `Punctuated` that
+//! are missing from an object default to empty, and unknown fields are
+//! ignored.
+//!
+//! Spans cannot be represented because a `Span` is a handle into state kept
+//! by whoever created it: the compiler's source map inside of a procedural
+//! macro, or a thread-local record of the lexed strings in proc-macro2
+//! outside of one. There is no way to make a `Span` from a file and position,
+//! so even with the `"span-locations"` feature the line and column of a span
+//! could be written out but not read back in. A tool that needs locations on
+//! the other side should send them alongside the tree, for example from
+//! `span.start()` and `span.end()` of the nodes it cares about.
+//!
+//! ```
+//! use serde_json::json;
+//! use syn::Type;
+//!
+//! let ty: Type = syn::parse_str("&'a mut T").unwrap();
+//! let json = serde_json::to_value(&ty).unwrap();
+//!
+//! let expected = json!({
+//!     "Reference": {
+//!         "lifetime": { "ident": "a" },
+//!         "mutability": true,
+//!         "elem": {
+//!             "Path": {
+//!                 "qself": null,
+//!                 "path": {
+//!                     "leading_colon": false,
+//!                     "segments": [{ "ident": "T", "arguments": "None" }],
+//!                 },
+//!             },
+//!         },
+//!     },
+//! });
+//! assert_eq!(json, expected);
+//! assert_eq!(serde_json::from_value::<Type>(json).unwrap(), ty);
+//! ```
+//!
+//! <br>
+//!
 //! # Optional features
 //!
 //! Syn puts a lot of functionality behind optional features in order to
@@ -241,13 +307,24 @@
 //! - **`visit`** — Trait for traversing a syntax tree.
 //! - **`visit-mut`** — Trait for traversing and mutating in place a syntax
 //!   tree.
+//! - **`try-visit`** — Trait for traversing a syntax tree that can stop
+//!   partway through.
+//! - **`visit-ancestors`** — Trait for traversing a syntax tree that tells
+//!   each node what it is nested inside of.
 //! - **`fold`** — Trait for transforming an owned syntax tree.
+//! - **`try-fold`** — Trait for transforming an owned syntax tree that can
+//!   fail partway through.
 //! - **`clone-impls`** *(enabled by default)* — Clone impls for all syntax tree
 //!   types.
 //! - **`extra-traits`** — Debug, Eq, PartialEq, Hash impls for all syntax tree
 //!   types.
 //! - **`proc-macro`** *(enabled by default)* — Runtime dependency on the
 //!   dynamic library libproc_macro from rustc toolchain.
+//! - **`serde`** — Serialize and Deserialize impls for all syntax tree types,
+//!   in the encoding described [above](#serialization).
+//! - **`span-locations`** — Line and column information in the spans of
+//!   tokens parsed from a string outside of a procedural macro, which the
+//!   `lossless` and `source_map` modules and the rendering of errors rely on.
 
 // Syn types in rustdoc of other crates get linked to here.
 #![doc(html_root_url = "https://docs.rs/syn/1.0.73")]
@@ -308,6 +385,9 @@ extern crate unicode_xid;
 #[cfg(feature = "printing")]
 extern crate quote;
 
+#[cfg(feature = "serde")]
+extern crate serde;
+
 #[macro_use]
 mod macros;
 
@@ -327,7 +407,8 @@ pub use crate::ident::Ident;
 mod attr;
 #[cfg(any(feature = "full", feature = "derive"))]
 pub use crate::attr::{
-    AttrStyle, Attribute, AttributeArgs, Meta, MetaList, MetaNameValue, NestedMeta,
+    AttrMeta, AttrMetaList, AttrMetaNameValue, AttrStyle, Attribute, AttributeArgs, Meta, MetaList,
+    MetaNameValue, NestedMeta,
 };
 
 mod bigint;
@@ -342,17 +423,20 @@ pub use crate::data::{
 
 #[cfg(any(feature = "full", feature = "derive"))]
 mod expr;
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+pub use crate::expr::ExprParser;
 #[cfg(feature = "full")]
 pub use crate::expr::{
-    Arm, FieldValue, GenericMethodArgument, Label, MethodTurbofish, RangeLimits,
+    Arm, FieldValue, GenericMethodArgument, Label, MethodTurbofish, PointerMutability, RangeLimits,
 };
 #[cfg(any(feature = "full", feature = "derive"))]
 pub use crate::expr::{
     Expr, ExprArray, ExprAssign, ExprAssignOp, ExprAsync, ExprAwait, ExprBinary, ExprBlock,
-    ExprBox, ExprBreak, ExprCall, ExprCast, ExprClosure, ExprContinue, ExprField, ExprForLoop,
-    ExprGroup, ExprIf, ExprIndex, ExprLet, ExprLit, ExprLoop, ExprMacro, ExprMatch, ExprMethodCall,
-    ExprParen, ExprPath, ExprRange, ExprReference, ExprRepeat, ExprReturn, ExprStruct, ExprTry,
-    ExprTryBlock, ExprTuple, ExprType, ExprUnary, ExprUnsafe, ExprWhile, ExprYield, Index, Member,
+    ExprBox, ExprBreak, ExprCall, ExprCast, ExprClosure, ExprConst, ExprContinue, ExprField,
+    ExprForLoop, ExprGroup, ExprIf, ExprIndex, ExprLet, ExprLit, ExprLoop, ExprMacro, ExprMatch,
+    ExprMethodCall, ExprParen, ExprPath, ExprRange, ExprRawAddr, ExprReference, ExprRepeat,
+    ExprReturn, ExprStruct, ExprTry, ExprTryBlock, ExprTuple, ExprType, ExprUnary, ExprUnsafe,
+    ExprWhile, ExprYield, Index, Member,
 };
 
 #[cfg(any(feature = "full", feature = "derive"))]
@@ -372,10 +456,10 @@ mod item;
 pub use crate::item::{
     FnArg, ForeignItem, ForeignItemFn, ForeignItemMacro, ForeignItemStatic, ForeignItemType,
     ImplItem, ImplItemConst, ImplItemMacro, ImplItemMethod, ImplItemType, Item, ItemConst,
-    ItemEnum, ItemExternCrate, ItemFn, ItemForeignMod, ItemImpl, ItemMacro, ItemMacro2, ItemMod,
-    ItemStatic, ItemStruct, ItemTrait, ItemTraitAlias, ItemType, ItemUnion, ItemUse, Receiver,
-    Signature, TraitItem, TraitItemConst, TraitItemMacro, TraitItemMethod, TraitItemType, UseGlob,
-    UseGroup, UseName, UsePath, UseRename, UseTree,
+    ItemEnum, ItemExternCrate, ItemFn, ItemFnDecl, ItemForeignMod, ItemImpl, ItemMacro, ItemMacro2,
+    ItemMod, ItemStatic, ItemStruct, ItemTrait, ItemTraitAlias, ItemType, ItemUnion, ItemUse,
+    Receiver, Signature, TraitItem, TraitItemConst, TraitItemMacro, TraitItemMethod, TraitItemType,
+    UseGlob, UseGroup, UseName, UsePath, UseRename, UseTree,
 };
 
 #[cfg(feature = "full")]
@@ -406,10 +490,22 @@ mod op;
 #[cfg(any(feature = "full", feature = "derive"))]
 pub use crate::op::{BinOp, UnOp};
 
+#[cfg(any(feature = "full", feature = "derive"))]
+mod precedence;
+#[cfg(any(feature = "full", feature = "derive"))]
+pub use crate::precedence::Precedence;
+
+#[cfg(feature = "full")]
+mod rules;
+#[cfg(feature = "full")]
+pub use crate::rules::{
+    MacroGroup, MacroMetavar, MacroRepOp, MacroRepetition, MacroRule, MacroRules, MacroTree,
+};
+
 #[cfg(feature = "full")]
 mod stmt;
 #[cfg(feature = "full")]
-pub use crate::stmt::{Block, Local, Stmt};
+pub use crate::stmt::{Block, Local, LocalInit, Stmt};
 
 #[cfg(any(feature = "full", feature = "derive"))]
 mod ty;
@@ -424,8 +520,8 @@ pub use crate::ty::{
 mod pat;
 #[cfg(feature = "full")]
 pub use crate::pat::{
-    FieldPat, Pat, PatBox, PatIdent, PatLit, PatMacro, PatOr, PatPath, PatRange, PatReference,
-    PatRest, PatSlice, PatStruct, PatTuple, PatTupleStruct, PatType, PatWild,
+    FieldPat, Pat, PatBox, PatConst, PatIdent, PatLit, PatMacro, PatOr, PatPath, PatRange,
+    PatReference, PatRest, PatSlice, PatStruct, PatTuple, PatTupleStruct, PatType, PatWild,
 };
 
 #[cfg(any(feature = "full", feature = "derive"))]
@@ -442,11 +538,17 @@ pub mod buffer;
 #[cfg(feature = "parsing")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
 pub mod ext;
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(all(any(feature = "full", feature = "derive"), feature = "parsing")))
+)]
+pub mod meta;
 pub mod punctuated;
 #[cfg(all(any(feature = "full", feature = "derive"), feature = "extra-traits"))]
 mod tt;
 
-// Not public API except the `parse_quote!` macro.
+// Not public API except the `parse_quote!` and `parse_quote_spanned!` macros.
 #[cfg(feature = "parsing")]
 #[doc(hidden)]
 pub mod parse_quote;
@@ -464,6 +566,45 @@ pub mod parse_macro_input;
 #[cfg_attr(doc_cfg, doc(cfg(all(feature = "parsing", feature = "printing"))))]
 pub mod spanned;
 
+#[cfg(all(feature = "full", feature = "printing"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "full", feature = "printing"))))]
+pub mod pretty;
+
+#[cfg(all(
+    feature = "full",
+    feature = "parsing",
+    feature = "printing",
+    feature = "span-locations"
+))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(all(
+        feature = "full",
+        feature = "parsing",
+        feature = "printing",
+        feature = "span-locations"
+    )))
+)]
+pub mod lossless;
+
+#[cfg(all(
+    any(feature = "full", feature = "derive"),
+    feature = "parsing",
+    feature = "span-locations"
+))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(all(
+        any(feature = "full", feature = "derive"),
+        feature = "parsing",
+        feature = "span-locations"
+    )))
+)]
+pub mod source_map;
+
+#[cfg(all(feature = "parsing", feature = "full"))]
+mod recover;
+
 #[cfg(all(feature = "parsing", feature = "full"))]
 mod whitespace;
 
@@ -686,6 +827,219 @@ mod gen {
     #[rustfmt::skip]
     pub mod visit_mut;
 
+    /// Syntax tree traversal to walk a shared borrow of a syntax tree, with
+    /// the ability to stop early.
+    ///
+    /// Each method of the [`TryVisit`] trait is a hook that can be overridden
+    /// to customize the behavior when visiting the corresponding type of node.
+    /// By default, every method recursively visits the substructure of the
+    /// input by invoking the right visitor method of each of its fields, and
+    /// returns the first `Err` that any of them returns without visiting the
+    /// rest of the tree.
+    ///
+    /// [`TryVisit`]: try_visit::TryVisit
+    ///
+    /// ```
+    /// # use syn::{Attribute, BinOp, Expr, ExprBinary};
+    /// #
+    /// pub trait TryVisit<'ast> {
+    ///     type Break;
+    ///
+    ///     /* ... */
+    ///
+    ///     fn visit_expr_binary(&mut self, node: &'ast ExprBinary) -> Result<(), Self::Break> {
+    ///         visit_expr_binary(self, node)
+    ///     }
+    ///
+    ///     /* ... */
+    ///     # fn visit_attribute(&mut self, node: &'ast Attribute) -> Result<(), Self::Break>;
+    ///     # fn visit_expr(&mut self, node: &'ast Expr) -> Result<(), Self::Break>;
+    ///     # fn visit_bin_op(&mut self, node: &'ast BinOp) -> Result<(), Self::Break>;
+    /// }
+    ///
+    /// pub fn visit_expr_binary<'ast, V>(v: &mut V, node: &'ast ExprBinary) -> Result<(), V::Break>
+    /// where
+    ///     V: TryVisit<'ast> + ?Sized,
+    /// {
+    ///     for attr in &node.attrs {
+    ///         v.visit_attribute(attr)?;
+    ///     }
+    ///     v.visit_expr(&*node.left)?;
+    ///     v.visit_bin_op(&node.op)?;
+    ///     v.visit_expr(&*node.right)?;
+    ///     Ok(())
+    /// }
+    ///
+    /// /* ... */
+    /// ```
+    ///
+    /// *This module is available only if Syn is built with the `"try-visit"`
+    /// feature.*
+    ///
+    /// <br>
+    ///
+    /// # Example
+    ///
+    /// This visitor finds the first `.await` in a function body, without
+    /// looking at any of the code after it.
+    ///
+    /// ```
+    /// // [dependencies]
+    /// // quote = "1.0"
+    /// // syn = { version = "1.0", features = ["full", "try-visit"] }
+    ///
+    /// use quote::quote;
+    /// use syn::try_visit::{self, TryVisit};
+    /// use syn::{Expr, ExprAwait, ItemFn};
+    ///
+    /// struct FindAwait;
+    ///
+    /// impl<'ast> TryVisit<'ast> for FindAwait {
+    ///     type Break = &'ast ExprAwait;
+    ///
+    ///     fn visit_expr(&mut self, node: &'ast Expr) -> Result<(), Self::Break> {
+    ///         match node {
+    ///             Expr::Await(node) => Err(node),
+    ///             // Delegate to the default impl to search nested expressions.
+    ///             _ => try_visit::visit_expr(self, node),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let code = quote! {
+    ///         async fn f() {
+    ///             let a = first().await;
+    ///             second(a).await;
+    ///         }
+    ///     };
+    ///
+    ///     let syntax_tree: ItemFn = syn::parse2(code).unwrap();
+    ///     if let Err(found) = FindAwait.visit_item_fn(&syntax_tree) {
+    ///         let base = &found.base;
+    ///         println!("first await is on {}", quote!(#base));
+    ///     }
+    ///
+    ///     // Output: first await is on first ()
+    /// }
+    /// ```
+    #[cfg(feature = "try-visit")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "try-visit")))]
+    #[rustfmt::skip]
+    pub mod try_visit;
+
+    /// Syntax tree traversal to walk a shared borrow of a syntax tree, with
+    /// access to the ancestors of each node.
+    ///
+    /// Each method of the [`VisitAncestors`] trait is a hook that can be
+    /// overridden to customize the behavior when visiting the corresponding
+    /// type of node, like those of [`Visit`]. Every method is also given the
+    /// [`Ancestors`] of the node, which are the nodes that the traversal went
+    /// through to reach it, each as a typed [`Node`] along with the field and
+    /// the position within that field where the traversal went down.
+    ///
+    /// [`VisitAncestors`]: visit_ancestors::VisitAncestors
+    /// [`Visit`]: visit::Visit
+    /// [`Ancestors`]: visit_ancestors::Ancestors
+    /// [`Node`]: visit_ancestors::Node
+    ///
+    /// ```
+    /// # use syn::{Attribute, BinOp, Expr, ExprBinary};
+    /// # use syn::visit_ancestors::Ancestors;
+    /// #
+    /// pub trait VisitAncestors<'ast> {
+    ///     /* ... */
+    ///
+    ///     fn visit_expr_binary(&mut self, node: &'ast ExprBinary, ancestors: &mut Ancestors<'ast>) {
+    ///         visit_expr_binary(self, node, ancestors);
+    ///     }
+    ///
+    ///     /* ... */
+    ///     # fn visit_attribute(&mut self, node: &'ast Attribute, ancestors: &mut Ancestors<'ast>);
+    ///     # fn visit_expr(&mut self, node: &'ast Expr, ancestors: &mut Ancestors<'ast>);
+    ///     # fn visit_bin_op(&mut self, node: &'ast BinOp, ancestors: &mut Ancestors<'ast>);
+    /// }
+    ///
+    /// pub fn visit_expr_binary<'ast, V>(
+    ///     v: &mut V,
+    ///     node: &'ast ExprBinary,
+    ///     ancestors: &mut Ancestors<'ast>,
+    /// ) where
+    ///     V: VisitAncestors<'ast> + ?Sized,
+    /// {
+    ///     /* add `node` to the ancestors */
+    ///     /* set the field of `node` to "attrs" */
+    ///     for attr in &node.attrs {
+    ///         /* set the index within the field */
+    ///         v.visit_attribute(attr, ancestors);
+    ///     }
+    ///     /* set the field of `node` to "left" */
+    ///     v.visit_expr(&*node.left, ancestors);
+    ///     /* set the field of `node` to "op" */
+    ///     v.visit_bin_op(&node.op, ancestors);
+    ///     /* set the field of `node` to "right" */
+    ///     v.visit_expr(&*node.right, ancestors);
+    ///     /* remove `node` from the ancestors */
+    /// }
+    ///
+    /// /* ... */
+    /// ```
+    ///
+    /// *This module is available only if Syn is built with the
+    /// `"visit-ancestors"` feature.*
+    ///
+    /// <br>
+    ///
+    /// # Example
+    ///
+    /// This visitor prints every path expression that appears inside of a
+    /// closure, along with the field of the closure that contains it.
+    ///
+    /// ```
+    /// // [dependencies]
+    /// // quote = "1.0"
+    /// // syn = { version = "1.0", features = ["full", "visit-ancestors"] }
+    ///
+    /// use quote::quote;
+    /// use syn::visit_ancestors::{self, Ancestors, Node, VisitAncestors};
+    /// use syn::{ExprPath, File};
+    ///
+    /// struct PathsInClosures;
+    ///
+    /// impl<'ast> VisitAncestors<'ast> for PathsInClosures {
+    ///     fn visit_expr_path(&mut self, node: &'ast ExprPath, ancestors: &mut Ancestors<'ast>) {
+    ///         let closure = ancestors.iter().rev().find(|ancestor| match ancestor.node {
+    ///             Node::ExprClosure(_) => true,
+    ///             _ => false,
+    ///         });
+    ///         if let Some(closure) = closure {
+    ///             println!("{} in closure {}", quote!(#node), closure.field);
+    ///         }
+    ///         visit_ancestors::visit_expr_path(self, node, ancestors);
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let code = quote! {
+    ///         fn f() {
+    ///             let g = |x: T| x + y;
+    ///             g(z)
+    ///         }
+    ///     };
+    ///
+    ///     let syntax_tree: File = syn::parse2(code).unwrap();
+    ///     PathsInClosures.visit_file(&syntax_tree, &mut Ancestors::new());
+    ///
+    ///     // Output:
+    ///     //     x in closure body
+    ///     //     y in closure body
+    /// }
+    /// ```
+    #[cfg(feature = "visit-ancestors")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "visit-ancestors")))]
+    #[rustfmt::skip]
+    pub mod visit_ancestors;
+
     /// Syntax tree traversal to transform the nodes of an owned syntax tree.
     ///
     /// Each method of the [`Fold`] trait is a hook that can be overridden to
@@ -773,6 +1127,114 @@ mod gen {
     #[rustfmt::skip]
     pub mod fold;
 
+    /// Syntax tree traversal to transform the nodes of an owned syntax tree,
+    /// with the ability to fail.
+    ///
+    /// Each method of the [`TryFold`] trait is a hook that can be overridden
+    /// to customize the behavior when transforming the corresponding type of
+    /// node. By default, every method recursively visits the substructure of
+    /// the input by invoking the right visitor method of each of its fields,
+    /// and returns the first error that any of them returns.
+    ///
+    /// [`TryFold`]: try_fold::TryFold
+    ///
+    /// ```
+    /// # use syn::{Attribute, BinOp, Expr, ExprBinary};
+    /// #
+    /// pub trait TryFold {
+    ///     type Error;
+    ///
+    ///     /* ... */
+    ///
+    ///     fn fold_expr_binary(&mut self, node: ExprBinary) -> Result<ExprBinary, Self::Error> {
+    ///         fold_expr_binary(self, node)
+    ///     }
+    ///
+    ///     /* ... */
+    ///     # fn fold_attribute(&mut self, node: Attribute) -> Result<Attribute, Self::Error>;
+    ///     # fn fold_expr(&mut self, node: Expr) -> Result<Expr, Self::Error>;
+    ///     # fn fold_bin_op(&mut self, node: BinOp) -> Result<BinOp, Self::Error>;
+    /// }
+    ///
+    /// pub fn fold_expr_binary<V>(v: &mut V, node: ExprBinary) -> Result<ExprBinary, V::Error>
+    /// where
+    ///     V: TryFold + ?Sized,
+    /// {
+    ///     Ok(ExprBinary {
+    ///         attrs: node
+    ///             .attrs
+    ///             .into_iter()
+    ///             .map(|attr| v.fold_attribute(attr))
+    ///             .collect::<Result<_, _>>()?,
+    ///         left: Box::new(v.fold_expr(*node.left)?),
+    ///         op: v.fold_bin_op(node.op)?,
+    ///         right: Box::new(v.fold_expr(*node.right)?),
+    ///     })
+    /// }
+    ///
+    /// /* ... */
+    /// ```
+    ///
+    /// *This module is available only if Syn is built with the `"try-fold"`
+    /// feature.*
+    ///
+    /// <br>
+    ///
+    /// # Example
+    ///
+    /// This fold turns every addition into a call of a `checked_add` function,
+    /// and reports an error for any subtraction, which it does not support.
+    ///
+    /// ```
+    /// // [dependencies]
+    /// // quote = "1.0"
+    /// // syn = { version = "1.0", features = ["full", "try-fold"] }
+    ///
+    /// use quote::quote;
+    /// use syn::try_fold::{fold_expr, TryFold};
+    /// use syn::{parse_quote, BinOp, Expr};
+    ///
+    /// struct CheckedAdd;
+    ///
+    /// impl TryFold for CheckedAdd {
+    ///     type Error = syn::Error;
+    ///
+    ///     fn fold_expr(&mut self, expr: Expr) -> syn::Result<Expr> {
+    ///         match fold_expr(self, expr)? {
+    ///             Expr::Binary(expr) => match expr.op {
+    ///                 BinOp::Add(_) => {
+    ///                     let (left, right) = (expr.left, expr.right);
+    ///                     Ok(parse_quote!(checked_add(#left, #right)))
+    ///                 }
+    ///                 BinOp::Sub(op) => Err(syn::Error::new_spanned(op, "unsupported operator")),
+    ///                 _ => Ok(Expr::Binary(expr)),
+    ///             },
+    ///             expr => Ok(expr),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let code = quote! { a + b * (c + d) };
+    ///     let expr: Expr = syn::parse2(code).unwrap();
+    ///     let checked = CheckedAdd.fold_expr(expr).unwrap();
+    ///     println!("{}", quote!(#checked));
+    ///
+    ///     // Output: checked_add (a , b * (checked_add (c , d)))
+    ///
+    ///     let code = quote! { a + (b - c) };
+    ///     let expr: Expr = syn::parse2(code).unwrap();
+    ///     let err = CheckedAdd.fold_expr(expr).unwrap_err();
+    ///     println!("{}", err);
+    ///
+    ///     // Output: unsupported operator
+    /// }
+    /// ```
+    #[cfg(feature = "try-fold")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "try-fold")))]
+    #[rustfmt::skip]
+    pub mod try_fold;
+
     #[cfg(feature = "clone-impls")]
     #[rustfmt::skip]
     mod clone;
@@ -789,7 +1251,11 @@ mod gen {
     #[rustfmt::skip]
     mod debug;
 
-    #[cfg(any(feature = "full", feature = "derive"))]
+    #[cfg(feature = "serde")]
+    #[rustfmt::skip]
+    mod serde;
+
+    #[cfg(any(feature = "full", feature = "derive", feature = "serde"))]
     #[path = "../gen_helper.rs"]
     mod helper;
 }
@@ -835,7 +1301,14 @@ mod rustdoc_workaround {
 ////////////////////////////////////////////////////////////////////////////////
 
 mod error;
-pub use crate::error::{Error, Result};
+pub use crate::error::{Error, ErrorCollector, Result};
+
+#[cfg(all(
+    any(feature = "full", feature = "derive"),
+    feature = "parsing",
+    feature = "span-locations"
+))]
+mod render;
 
 /// Parse tokens of source code into the chosen syntax tree node.
 ///
@@ -973,7 +1446,117 @@ pub fn parse_str<T: parse::Parse>(s: &str) -> Result<T> {
 /// ```
 #[cfg(all(feature = "parsing", feature = "full"))]
 #[cfg_attr(doc_cfg, doc(cfg(all(feature = "parsing", feature = "full"))))]
-pub fn parse_file(mut content: &str) -> Result<File> {
+pub fn parse_file(content: &str) -> Result<File> {
+    let (shebang, content) = split_shebang(content);
+    let mut file: File = parse_str(content)?;
+    file.shebang = shebang;
+    Ok(file)
+}
+
+/// Parse the content of a file of Rust code, leaving the bodies of functions
+/// unparsed.
+///
+/// This is like [`parse_file`] for tools that only need the items of a file
+/// and the signatures of its functions, and is considerably faster on typical
+/// code. The body of every [`ItemFn`], [`ImplItemMethod`] and
+/// [`TraitItemMethod`] is kept as a [`Block`] holding its tokens, inner
+/// attributes included, in a single `Stmt::Expr(Expr::Verbatim(...))`
+/// statement. The `parse_body` method of each of these parses its body on
+/// demand. Printing the file reproduces the original tokens either way.
+///
+/// Syntax errors inside the bodies of functions are not reported until the
+/// bodies are parsed.
+///
+/// *This function is available only if Syn is built with the `"parsing"` and
+/// `"full"` features.*
+///
+/// # Example
+///
+/// ```
+/// use syn::{Item, Stmt};
+///
+/// let content = "fn main() { #![allow(unused)] let x = 1; }";
+/// let mut file = syn::parse_file_lazy(content).unwrap();
+///
+/// if let Item::Fn(item) = &mut file.items[0] {
+///     assert_eq!(item.sig.ident, "main");
+///     assert!(item.attrs.is_empty());
+///
+///     item.parse_body().unwrap();
+///     assert_eq!(item.attrs.len(), 1);
+///     assert!(match item.block.stmts[0] {
+///         Stmt::Local(_) => true,
+///         _ => false,
+///     });
+/// }
+/// ```
+#[cfg(all(feature = "parsing", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "parsing", feature = "full"))))]
+pub fn parse_file_lazy(content: &str) -> Result<File> {
+    let (shebang, content) = split_shebang(content);
+    let mut file: File = parse::parse_lazy(<File as parse::Parse>::parse, error::lex(content)?)?;
+    file.shebang = shebang;
+    Ok(file)
+}
+
+/// Parse the content of a file of Rust code, carrying on past syntax errors.
+///
+/// Unlike [`parse_file`], which stops at the first error, this returns a
+/// syntax tree of the whole file along with every error that was found in
+/// it. An item that fails to parse is replaced by an [`Item::Verbatim`]
+/// holding its tokens, and parsing resumes at the next item. Errors inside
+/// the body of a function, module, impl block or trait only replace the
+/// items or statements of the body that they occur in. A statement that fails
+/// to parse is replaced by an [`Expr::Verbatim`] holding its tokens, and
+/// parsing resumes at the next `;` or `let` statement.
+///
+/// If the file is not even made of well-formed tokens, for example because
+/// its delimiters are unbalanced, the returned file has no items.
+///
+/// *This function is available only if Syn is built with the `"parsing"` and
+/// `"full"` features.*
+///
+/// # Example
+///
+/// ```
+/// use syn::{Item, Stmt};
+///
+/// let content = "
+///     fn main() {
+///         let x = ;
+///         println!();
+///     }
+///
+///     struct S { x: u8 y: u8 }
+///
+///     struct T;
+/// ";
+/// let (file, errors) = syn::parse_file_recovering(content);
+///
+/// assert_eq!(errors.len(), 2);
+/// assert_eq!(errors[0].to_string(), "expected expression");
+/// assert_eq!(errors[1].to_string(), "expected `,`");
+///
+/// assert_eq!(file.items.len(), 3);
+/// if let Item::Fn(item) = &file.items[0] {
+///     assert_eq!(item.block.stmts.len(), 2);
+///     assert!(match item.block.stmts[1] {
+///         Stmt::Semi(..) => true,
+///         _ => false,
+///     });
+/// }
+/// assert_eq!(file.items[1], Item::Verbatim(quote::quote!(struct S { x: u8 y: u8 })));
+/// ```
+#[cfg(all(feature = "parsing", feature = "full"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "parsing", feature = "full"))))]
+pub fn parse_file_recovering(content: &str) -> (File, Vec<Error>) {
+    recover::parse_file(content)
+}
+
+// Splits off the BOM and shebang line at the start of a source file, which are
+// not part of its tokens.
+#[cfg(all(feature = "parsing", feature = "full"))]
+pub(crate) fn split_shebang(mut content: &str) -> (Option<String>, &str) {
     // Strip the BOM if it is present
     const BOM: &str = "\u{feff}";
     if content.starts_with(BOM) {
@@ -994,7 +1577,5 @@ pub fn parse_file(mut content: &str) -> Result<File> {
         }
     }
 
-    let mut file: File = parse_str(content)?;
-    file.shebang = shebang;
-    Ok(file)
+    (shebang, content)
 }
@@ -5,7 +5,7 @@ use crate::parse::{Parse, Parser};
 use crate::{Error, Result};
 #[cfg(feature = "printing")]
 use proc_macro2::Ident;
-#[cfg(feature = "parsing")]
+#[cfg(any(feature = "parsing", feature = "serde"))]
 use proc_macro2::TokenStream;
 use proc_macro2::TokenTree;
 use proc_macro2::{Literal, Span};
@@ -922,6 +922,63 @@ mod printing {
     }
 }
 
+#[cfg(feature = "serde")]
+pub mod serde_impls {
+    use super::*;
+    use serde::de::{self, Deserialize, Deserializer, Unexpected};
+    use serde::ser::{Serialize, Serializer};
+
+    macro_rules! lit_serde {
+        ($ty:ident, $variant:ident, $expected:expr) => {
+            #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+            impl Serialize for $ty {
+                fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    serializer.collect_str(&self.repr.token)
+                }
+            }
+
+            #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let repr = String::deserialize(deserializer)?;
+                    match literal(&repr).map(Lit::new) {
+                        Some(Lit::$variant(lit)) => Ok(lit),
+                        _ => Err(de::Error::invalid_value(Unexpected::Str(&repr), &$expected)),
+                    }
+                }
+            }
+        };
+    }
+
+    lit_serde!(LitStr, Str, "a string literal");
+    lit_serde!(LitByteStr, ByteStr, "a byte string literal");
+    lit_serde!(LitByte, Byte, "a byte literal");
+    lit_serde!(LitChar, Char, "a character literal");
+    lit_serde!(LitInt, Int, "an integer literal");
+    lit_serde!(LitFloat, Float, "a floating point literal");
+
+    // Lexes the string representation of a literal. Negative numbers are a
+    // single literal token only when created by the Literal constructors.
+    pub fn literal(repr: &str) -> Option<Literal> {
+        if repr.starts_with('-') {
+            let (digits, suffix) =
+                value::parse_lit_int(repr).or_else(|| value::parse_lit_float(repr))?;
+            return value::to_literal(repr, &digits, &suffix);
+        }
+        let mut tokens = repr.parse::<TokenStream>().ok()?.into_iter();
+        match (tokens.next(), tokens.next()) {
+            (Some(TokenTree::Literal(literal)), None) => Some(literal),
+            _ => None,
+        }
+    }
+}
+
 mod value {
     use super::*;
     use crate::bigint::BigInt;
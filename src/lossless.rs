@@ -0,0 +1,625 @@
+//! Parsing that keeps comments and formatting, for rewriting source files.
+//!
+//! *This module is available only if Syn is built with the `"full"`,
+//! `"parsing"`, `"printing"` and `"span-locations"` features.*
+//!
+//! A syntax tree from [`syn::parse_file`] has no trace of the comments other
+//! than doc comments in the file, nor of its whitespace, so printing it back
+//! out loses them. The [`parse_file`] function of this module additionally
+//! returns a [`Source`] that knows the byte range of every token of the tree
+//! in the original text. After the tree has been edited, [`Source::print`]
+//! writes it back out, reproducing the original text byte for byte for every
+//! part of the file that was not modified, including the comments and
+//! whitespace in between.
+//!
+//! [`syn::parse_file`]: crate::parse_file
+//!
+//! A token counts as modified if its text differs from the original one,
+//! even if it kept the original span, as with `Ident::new("name",
+//! old.span())`. Items that are moved or copied elsewhere in the file bring
+//! the comments before them along, while a comment that follows a token on
+//! the same line stays with that token.
+//!
+//! Tokens that do not come from the original text, such as those of a node
+//! constructed by `parse_quote!` during the rewrite, are printed with single
+//! spaces between them where necessary.
+//!
+//! Locations of tokens are looked up from the line and column information
+//! that proc-macro2 keeps for code parsed from a string outside of a
+//! procedural macro, so this module is meant for standalone tools and not for
+//! use within a procedural macro. That information is thread local, so a tree
+//! must be printed on the same thread that parsed it.
+//!
+//! # Example
+//!
+//! ```
+//! use syn::{Ident, Item};
+//!
+//! # fn main() -> syn::Result<()> {
+//! let src = "\
+//! // Adds one.
+//! fn add_one(x: u32) -> u32 {
+//!     x + 1 // cannot overflow
+//! }
+//!
+//! /* The answer. */
+//! const ANSWER: u32 = 42;
+//! ";
+//!
+//! let (mut file, source) = syn::lossless::parse_file(src)?;
+//! if let Item::Fn(item) = &mut file.items[0] {
+//!     item.sig.ident = Ident::new("increment", item.sig.ident.span());
+//! }
+//!
+//! let expected = "\
+//! // Adds one.
+//! fn increment(x: u32) -> u32 {
+//!     x + 1 // cannot overflow
+//! }
+//!
+//! /* The answer. */
+//! const ANSWER: u32 = 42;
+//! ";
+//! assert_eq!(source.print(&file), expected);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::source_map::SourceFile;
+use crate::{File, Result};
+use proc_macro2::{Delimiter, Literal, Spacing, Span, TokenStream, TokenTree};
+use quote::ToTokens;
+use std::ops::Range;
+
+/// Parses a Rust source file along with the locations of its tokens.
+///
+/// The returned [`Source`] prints the syntax tree, possibly after edits, with
+/// the comments and formatting of the original text.
+pub fn parse_file(text: &str) -> Result<(File, Source)> {
+    let (shebang, content) = crate::split_shebang(text);
+    let tokens = crate::error::lex(content)?;
+
+    let mut pieces = Vec::new();
+    flatten(tokens.clone(), &mut pieces);
+    let span = match (pieces.first(), pieces.last()) {
+        (Some(first), Some(last)) => first.span().join(last.span()),
+        _ => None,
+    };
+
+    let mut file = SourceFile::new("", text, text.len() - content.len());
+    if let Some(span) = span {
+        file.set_span(span);
+    }
+    let mut source = Source {
+        file,
+        shebang: shebang.clone(),
+        tokens: Vec::new(),
+    };
+    for piece in &pieces {
+        let range = source.file.byte_range(piece.span()).unwrap();
+        // The pieces that a doc comment expands to all point into the same
+        // comment, which counts as one token.
+        match source.tokens.last() {
+            Some(last) if range.start < last.end => {}
+            _ => source.tokens.push(range),
+        }
+    }
+
+    let mut file: File = crate::parse2(tokens)?;
+    file.shebang = shebang;
+    Ok((file, source))
+}
+
+/// The original text of a file parsed by [`parse_file`], along with the
+/// locations of its tokens.
+pub struct Source {
+    file: SourceFile,
+    shebang: Option<String>,
+    // Byte ranges of the tokens in order. A doc comment is a single token.
+    tokens: Vec<Range<usize>>,
+}
+
+impl Source {
+    /// The original text of the file.
+    pub fn text(&self) -> &str {
+        self.file.text()
+    }
+
+    /// The byte range in the original text from the start of the first token
+    /// of a syntax tree node to the end of its last token.
+    ///
+    /// Returns `None` if the first or the last token of the node does not come
+    /// from this file.
+    pub fn range<T: ToTokens + ?Sized>(&self, node: &T) -> Option<Range<usize>> {
+        let mut pieces = Vec::new();
+        flatten(node.to_token_stream(), &mut pieces);
+        let indices = self.token_indices(&pieces);
+        let first = (*indices.first()?)?;
+        let last = (*indices.last()?)?;
+        Some(self.tokens[first].start..self.tokens[last].end)
+    }
+
+    /// Prints a file, reproducing the original text of every part of it that
+    /// has not been modified since parsing.
+    pub fn print(&self, file: &File) -> String {
+        let mut out = String::new();
+        if file.shebang == self.shebang {
+            out.push_str(&self.file.text()[..self.file.offset()]);
+        } else if let Some(shebang) = &file.shebang {
+            out.push_str(shebang);
+            out.push('\n');
+        }
+
+        let mut pieces = Vec::new();
+        flatten(file.to_token_stream(), &mut pieces);
+        if pieces.is_empty() && self.tokens.is_empty() {
+            out.push_str(&self.file.text()[self.file.offset()..]);
+            return out;
+        }
+
+        let last = self.write(&pieces, &mut out, true);
+        let trailer = self.gap_before(self.tokens.len());
+        if last.map_or(false, |last| last + 1 == self.tokens.len()) {
+            out.push_str(trailer);
+        } else {
+            if let Some(last) = last {
+                out.push_str(self.trailing(last));
+            }
+            // Comments at the end of the file stay there.
+            let trailer = trailer.trim_start();
+            if !trailer.is_empty() && !out.is_empty() && !out.ends_with('\n') {
+                out.push('\n');
+            }
+            out.push_str(trailer);
+            if !out.is_empty() && !out.ends_with('\n') {
+                out.push('\n');
+            }
+        }
+        out
+    }
+
+    /// Prints a syntax tree node, reproducing the original text of every part
+    /// of it that has not been modified since parsing.
+    ///
+    /// The output begins with the node's first token and ends with its last,
+    /// without the comments and whitespace around it.
+    pub fn print_tokens<T: ToTokens + ?Sized>(&self, node: &T) -> String {
+        let mut pieces = Vec::new();
+        flatten(node.to_token_stream(), &mut pieces);
+        let mut out = String::new();
+        self.write(&pieces, &mut out, false);
+        out
+    }
+
+    // Writes the pieces, copying the original text between tokens that were
+    // adjacent in the original file. Returns the index of the last token
+    // written if it comes from this file.
+    fn write(&self, pieces: &[Piece], out: &mut String, whole_file: bool) -> Option<usize> {
+        let indices = self.token_indices(pieces);
+        let mut written = vec![false; self.tokens.len()];
+        for index in indices.iter().filter_map(|index| *index) {
+            written[index] = true;
+        }
+        let mut prev: Option<(&Piece, Option<usize>)> = None;
+        let mut spacer = Spacer::default();
+        for (k, piece) in pieces.iter().enumerate() {
+            let index = indices[k];
+            if let (Some((_, Some(prev))), Some(index)) = (prev, index) {
+                // A doc comment is made of several pieces.
+                if prev == index && is_comment(&self.file.text()[self.tokens[index].clone()]) {
+                    continue;
+                }
+            }
+            let space = match prev {
+                Some((prev, _)) => spacer.space(prev, piece),
+                None => false,
+            };
+            match (prev, index) {
+                (Some((_, Some(prev))), Some(index)) if prev + 1 == index => {
+                    out.push_str(&self.file.text()[self.tokens[prev].end..self.tokens[index].end]);
+                }
+                (None, Some(index)) => {
+                    if whole_file {
+                        let mut gap = self.gap_before(index);
+                        if index > 0 && written[index - 1] {
+                            gap = &gap[self.trailing(index - 1).len()..];
+                        }
+                        // The comments and blank lines before a token that
+                        // was not first in the original file.
+                        out.push_str(if index == 0 { gap } else { gap.trim_start() });
+                    }
+                    out.push_str(&self.file.text()[self.tokens[index].clone()]);
+                }
+                (Some((prev, prev_index)), Some(index)) => {
+                    // The comments before a token that was moved or copied
+                    // come along with it, except for a comment on the same
+                    // line as the token before it, which stays with that
+                    // token if it is printed too. If there are none, the
+                    // whitespace that followed the previous token separates
+                    // the two.
+                    let trailing = prev_index.map_or("", |prev| self.trailing(prev));
+                    out.push_str(trailing);
+                    let mut gap = self.gap_before(index);
+                    if index > 0 && written[index - 1] {
+                        gap = &gap[self.trailing(index - 1).len()..];
+                    }
+                    if let (true, Some(prev_index)) = (gap.is_empty(), prev_index) {
+                        let after = self.gap_before(prev_index + 1);
+                        gap = &after[after.trim_end().len()..];
+                    }
+                    // Nothing may follow a line comment on its line, and a
+                    // comment that began a line in the original still does.
+                    let indent = &gap[..gap.len() - gap.trim_start().len()];
+                    if !indent.contains('\n')
+                        && (!trailing.is_empty()
+                            || index == 0 && prev_index.is_none() && is_comment(gap))
+                    {
+                        out.push('\n');
+                        gap = gap.trim_start();
+                    } else if gap.is_empty() && must_separate(prev, piece) {
+                        out.push(' ');
+                    }
+                    out.push_str(gap);
+                    out.push_str(&self.file.text()[self.tokens[index].clone()]);
+                }
+                (None, None) => piece.write(out),
+                (Some((prev, Some(prev_index))), None) => {
+                    let gap = self.gap_before(prev_index + 1);
+                    let next = indices[k..].iter().filter_map(|index| *index).next();
+                    let gap = if next == Some(prev_index + 1)
+                        || next.is_none() && prev_index + 1 == self.tokens.len()
+                    {
+                        // Tokens inserted before the next original token,
+                        // whose comments stay with it. A comment on the same
+                        // line stays with the previous token instead.
+                        let trailing = self.trailing(prev_index).len();
+                        let rest = &gap[trailing..];
+                        &gap[..trailing + rest.len() - rest.trim_start().len()]
+                    } else {
+                        // Tokens replacing some original ones.
+                        gap
+                    };
+                    if gap.is_empty() && must_separate(prev, piece) {
+                        out.push(' ');
+                    }
+                    out.push_str(gap);
+                    piece.write(out);
+                }
+                (Some((_, None)), None) => {
+                    if space {
+                        out.push(' ');
+                    }
+                    piece.write(out);
+                }
+            }
+            prev = Some((piece, index));
+        }
+        prev.and_then(|(_, index)| index)
+    }
+
+    // The comments and whitespace before a token, or after the last token.
+    fn gap_before(&self, index: usize) -> &str {
+        let start = match index {
+            0 => self.file.offset(),
+            _ => self.tokens[index - 1].end,
+        };
+        let end = match self.tokens.get(index) {
+            Some(token) => token.start,
+            None => self.file.text().len(),
+        };
+        &self.file.text()[start..end]
+    }
+
+    // The comments after a token on the same line, up to the end of the line,
+    // or nothing if the next token is on the same line.
+    fn trailing(&self, index: usize) -> &str {
+        let gap = self.gap_before(index + 1);
+        match gap.find('\n') {
+            Some(end) if !gap[..end].trim().is_empty() => &gap[..end],
+            _ => "",
+        }
+    }
+
+    fn token_index(&self, piece: &Piece) -> Option<usize> {
+        let offset = self.file.byte_range(piece.span())?.start;
+        let index = match self
+            .tokens
+            .binary_search_by(|token| token.start.cmp(&offset))
+        {
+            Ok(index) => index,
+            Err(0) => return None,
+            Err(index) => index - 1,
+        };
+        if offset < self.tokens[index].end {
+            Some(index)
+        } else {
+            None
+        }
+    }
+
+    // The index of the original token that each piece is, or `None` if the
+    // piece is new or was edited in place, which keeps the span of the token
+    // but not its text. The pieces of a doc comment are only original if all
+    // of them are.
+    fn token_indices(&self, pieces: &[Piece]) -> Vec<Option<usize>> {
+        let mut indices: Vec<Option<usize>> = pieces.iter().map(|p| self.token_index(p)).collect();
+        let mut start = 0;
+        while start < pieces.len() {
+            let mut end = start + 1;
+            while end < pieces.len() && indices[end].is_some() && indices[end] == indices[start] {
+                end += 1;
+            }
+            if let Some(index) = indices[start] {
+                let original = &self.file.text()[self.tokens[index].clone()];
+                if !pieces[start..end].iter().all(|p| is_original(p, original)) {
+                    for index in &mut indices[start..end] {
+                        *index = None;
+                    }
+                }
+            }
+            start = end;
+        }
+        indices
+    }
+}
+
+// Whether a piece has the same text as the original token at its location.
+fn is_original(piece: &Piece, original: &str) -> bool {
+    if is_comment(original) {
+        // The pieces of a doc comment are `#`, `!`, `[`, `doc`, `=`, the
+        // contents as a string literal, and `]`.
+        return match piece {
+            Piece::Token(TokenTree::Literal(lit)) => {
+                lit.to_string() == Literal::string(doc_contents(original)).to_string()
+            }
+            Piece::Token(TokenTree::Ident(ident)) => ident == "doc",
+            Piece::Token(TokenTree::Punct(punct)) => match punct.as_char() {
+                '#' | '!' | '=' => true,
+                _ => false,
+            },
+            Piece::Open(delimiter, _) | Piece::Close(delimiter, _) => {
+                *delimiter == Delimiter::Bracket
+            }
+            Piece::Token(TokenTree::Group(_)) => false,
+        };
+    }
+    match piece {
+        Piece::Token(TokenTree::Ident(ident)) => ident == original,
+        Piece::Token(TokenTree::Literal(lit)) => lit.to_string() == original,
+        Piece::Token(TokenTree::Punct(punct)) => {
+            let mut chars = original.chars();
+            chars.next() == Some(punct.as_char()) && chars.next().is_none()
+        }
+        Piece::Token(TokenTree::Group(_)) => false,
+        Piece::Open(..) | Piece::Close(..) => {
+            let mut text = String::new();
+            piece.write(&mut text);
+            text == original
+        }
+    }
+}
+
+fn is_comment(token: &str) -> bool {
+    token.starts_with("//") || token.starts_with("/*")
+}
+
+// The text of a doc comment without its delimiters, as it appears in the
+// string literal of the equivalent `doc` attribute.
+fn doc_contents(comment: &str) -> &str {
+    if comment.starts_with("/*") {
+        &comment[3..comment.len() - 2]
+    } else {
+        &comment[3..]
+    }
+}
+
+// A token or delimiter of a flattened token stream.
+enum Piece {
+    Token(TokenTree),
+    Open(Delimiter, Span),
+    Close(Delimiter, Span),
+}
+
+impl Piece {
+    fn span(&self) -> Span {
+        match self {
+            Piece::Token(token) => token.span(),
+            Piece::Open(_, span) | Piece::Close(_, span) => *span,
+        }
+    }
+
+    fn write(&self, out: &mut String) {
+        match self {
+            Piece::Token(TokenTree::Punct(punct)) => out.push(punct.as_char()),
+            Piece::Token(token) => out.push_str(&token.to_string()),
+            Piece::Open(delimiter, _) => out.push_str(match delimiter {
+                Delimiter::Parenthesis => "(",
+                Delimiter::Brace => "{",
+                Delimiter::Bracket => "[",
+                Delimiter::None => "",
+            }),
+            Piece::Close(delimiter, _) => out.push_str(match delimiter {
+                Delimiter::Parenthesis => ")",
+                Delimiter::Brace => "}",
+                Delimiter::Bracket => "]",
+                Delimiter::None => "",
+            }),
+        }
+    }
+
+    fn punct(&self) -> Option<(char, Spacing)> {
+        match self {
+            Piece::Token(TokenTree::Punct(punct)) => Some((punct.as_char(), punct.spacing())),
+            _ => None,
+        }
+    }
+
+    fn is_word(&self) -> bool {
+        match self {
+            Piece::Token(TokenTree::Ident(_)) | Piece::Token(TokenTree::Literal(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+fn flatten(tokens: TokenStream, pieces: &mut Vec<Piece>) {
+    for token in tokens {
+        match token {
+            TokenTree::Group(group) => {
+                if group.delimiter() == Delimiter::None {
+                    flatten(group.stream(), pieces);
+                    continue;
+                }
+                pieces.push(Piece::Open(group.delimiter(), group.span_open()));
+                flatten(group.stream(), pieces);
+                pieces.push(Piece::Close(group.delimiter(), group.span_close()));
+            }
+            token => pieces.push(Piece::Token(token)),
+        }
+    }
+}
+
+// Whether two tokens printed next to each other would be lexed differently
+// than as those two tokens.
+fn must_separate(prev: &Piece, next: &Piece) -> bool {
+    if prev.is_word() && next.is_word() {
+        return true;
+    }
+    match (prev.punct(), next.punct()) {
+        (Some((prev, Spacing::Alone)), Some((next, _))) => {
+            // Operators that a lexer would read as one, and comments.
+            const GLUED: &[&str] = &[
+                "::", "->", "=>", "==", "!=", "<=", ">=", "&&", "||", "+=", "-=", "*=", "/=", "%=",
+                "^=", "&=", "|=", "<<", ">>", "..", "//", "/*",
+            ];
+            let pair = format!("{}{}", prev, next);
+            GLUED.contains(&pair.as_str())
+        }
+        (Some(('\'', _)), _) | (Some(('#', _)), _) => false,
+        (None, Some(('.', _))) => match prev {
+            Piece::Token(TokenTree::Literal(_)) => true,
+            _ => false,
+        },
+        (None, Some(('\'', _))) | (None, Some(('#', _))) => prev.is_word(),
+        _ => false,
+    }
+}
+
+// Spacing between tokens that were not next to each other in the original
+// file, approximating how they would be written by hand.
+#[derive(Default)]
+struct Spacer {
+    // Whether the token before the operator that is being printed ends an
+    // operand, which tells a binary operator apart from a unary one.
+    operand: bool,
+    // Whether the previous token is the second colon of a path separator.
+    path: bool,
+    // Number of angle brackets opened by generic arguments.
+    generics: usize,
+    // Whether the previous token opens or closes generic arguments.
+    opened: bool,
+    closed: bool,
+}
+
+impl Spacer {
+    fn space(&mut self, prev: &Piece, next: &Piece) -> bool {
+        let opened = self.opened;
+        let closed = self.closed;
+        self.opened = false;
+        self.closed = false;
+        let space = self.space_between(prev, next, opened, closed);
+        match prev.punct() {
+            Some((_, Spacing::Joint)) => {}
+            _ => {
+                self.operand = match prev {
+                    Piece::Token(TokenTree::Ident(ident)) => !is_keyword(&ident.to_string()),
+                    Piece::Token(TokenTree::Literal(_)) | Piece::Close(..) => true,
+                    _ => closed,
+                };
+            }
+        }
+        self.path = prev.punct() == Some((':', Spacing::Joint))
+            && next.punct() == Some((':', Spacing::Alone));
+        space
+    }
+
+    fn space_between(&mut self, prev: &Piece, next: &Piece, opened: bool, closed: bool) -> bool {
+        if must_separate(prev, next) {
+            return true;
+        }
+        if let Some((_, Spacing::Joint)) = prev.punct() {
+            return false;
+        }
+        match next.punct() {
+            Some(('<', _)) => {
+                let opens = match prev {
+                    Piece::Token(TokenTree::Ident(ident)) => {
+                        ident.to_string().starts_with(char::is_uppercase)
+                    }
+                    _ => self.path,
+                };
+                if opens {
+                    self.generics += 1;
+                    self.opened = true;
+                    return false;
+                }
+            }
+            Some(('>', _)) if self.generics > 0 => {
+                self.generics -= 1;
+                self.closed = true;
+                return false;
+            }
+            _ => {}
+        }
+        match (prev, next) {
+            (Piece::Open(Delimiter::Brace, _), Piece::Close(..)) => return false,
+            (Piece::Open(Delimiter::Brace, _), _) | (_, Piece::Close(Delimiter::Brace, _)) => {
+                return true;
+            }
+            (Piece::Open(..), _) | (_, Piece::Close(..)) => return false,
+            (Piece::Token(TokenTree::Ident(ident)), Piece::Open(delimiter, _)) => {
+                return *delimiter == Delimiter::Brace || is_keyword(&ident.to_string());
+            }
+            (Piece::Close(..), Piece::Open(delimiter, _)) => {
+                return *delimiter == Delimiter::Brace;
+            }
+            (Piece::Token(TokenTree::Punct(_)), Piece::Open(delimiter, _)) if closed => {
+                return *delimiter == Delimiter::Brace;
+            }
+            _ => {}
+        }
+        match next.punct() {
+            Some((',', _)) | Some((';', _)) | Some(('.', _)) | Some(('?', _)) => return false,
+            Some((':', _)) | Some(('!', Spacing::Alone)) => {
+                return match prev {
+                    Piece::Token(TokenTree::Ident(ident)) => is_keyword(&ident.to_string()),
+                    Piece::Token(TokenTree::Punct(_)) => !closed,
+                    _ => false,
+                };
+            }
+            _ => {}
+        }
+        match prev.punct() {
+            Some(('.', _)) | Some(('#', _)) | Some(('$', _)) => false,
+            Some(('<', _)) if opened => false,
+            Some((':', _)) => !self.path,
+            // The bang of a macro invocation.
+            Some(('!', _)) if self.operand => match next {
+                Piece::Open(delimiter, _) => *delimiter == Delimiter::Brace,
+                _ => true,
+            },
+            Some(('!', _)) | Some(('&', _)) | Some(('*', _)) | Some(('-', _)) => self.operand,
+            _ => true,
+        }
+    }
+}
+
+// Keywords after which a parenthesized or bracketed expression or pattern is
+// separated by a space.
+fn is_keyword(ident: &str) -> bool {
+    match ident {
+        "as" | "box" | "break" | "dyn" | "else" | "for" | "if" | "impl" | "in" | "let"
+        | "match" | "move" | "mut" | "ref" | "return" | "where" | "while" | "yield" => true,
+        _ => false,
+    }
+}
@@ -0,0 +1,389 @@
+//! Facility for interpreting structured content inside of an `Attribute`.
+//!
+//! *This module is available only if Syn is built with the `"parsing"` feature
+//! and either the `"derive"` or `"full"` feature.*
+//!
+//! Helper attributes of derive macros conventionally hold a comma-separated
+//! list of entries, each of which is a path optionally followed by `= value`
+//! or by a parenthesized nested list:
+//!
+//! ```text
+//! #[serde(rename = "id", skip_serializing_if = "Option::is_none", bound(serialize = "T: Serialize"))]
+//! ```
+//!
+//! The [`parser`] function and [`Attribute::parse_nested_meta`] walk such a
+//! list and call a closure once per entry, leaving it up to the closure to
+//! decide how the rest of the entry is parsed. On top of that, [`Keys`] keeps
+//! a registry of expected keys together with typed slots to store their
+//! values into, and reports unknown and duplicate keys.
+//!
+//! [`Attribute::parse_nested_meta`]: crate::Attribute::parse_nested_meta
+//!
+//! # Example
+//!
+//! ```
+//! # extern crate proc_macro;
+//! #
+//! use proc_macro::TokenStream;
+//! use syn::{meta, parse_macro_input, DeriveInput, Expr, LitStr, Path};
+//!
+//! # const IGNORE_TOKENS: &str = stringify! {
+//! #[proc_macro_derive(MyMacro, attributes(my_macro))]
+//! # };
+//! pub fn my_macro(input: TokenStream) -> TokenStream {
+//!     let input = parse_macro_input!(input as DeriveInput);
+//!
+//!     // #[my_macro(rename = "...", crate = path::to::my_macro, skip, default = Vec::new())]
+//!     let mut rename: Option<LitStr> = None;
+//!     let mut krate: Option<Path> = None;
+//!     let mut default: Option<Expr> = None;
+//!     let mut skip = false;
+//!
+//!     let result = meta::Keys::new()
+//!         .value("rename", &mut rename)
+//!         .value("crate", &mut krate)
+//!         .value("default", &mut default)
+//!         .flag("skip", &mut skip)
+//!         .parse_attrs(&input.attrs, "my_macro");
+//!
+//!     if let Err(err) = result {
+//!         return err.to_compile_error().into();
+//!     }
+//!
+//!     /* ... */
+//! #   "".parse().unwrap()
+//! }
+//! ```
+
+use crate::attr::parsing::parse_meta_path;
+use crate::error::{self, Error};
+use crate::parse::{self, Parse, ParseStream, Parser, Result};
+use crate::{Attribute, Path};
+use proc_macro2::TokenTree;
+use std::fmt::Display;
+
+/// Make a parser that is usable with `parse_macro_input!` in a
+/// `#[proc_macro_attribute]` macro.
+///
+/// The `logic` closure is called once per comma-separated entry of the input,
+/// after the path at the front of the entry has been parsed.
+///
+/// # Example
+///
+/// ```
+/// # extern crate proc_macro;
+/// #
+/// use proc_macro::TokenStream;
+/// use syn::{meta, parse_macro_input, LitStr};
+///
+/// # const IGNORE_TOKENS: &str = stringify! {
+/// #[proc_macro_attribute]
+/// # };
+/// pub fn route(args: TokenStream, input: TokenStream) -> TokenStream {
+///     // #[route(path = "/v1/refresh", auth)]
+///     let mut path: Option<LitStr> = None;
+///     let mut auth = false;
+///     let parser = meta::parser(|meta| {
+///         if meta.path.is_ident("path") {
+///             path = Some(meta.value()?.parse()?);
+///             Ok(())
+///         } else if meta.path.is_ident("auth") {
+///             auth = true;
+///             Ok(())
+///         } else {
+///             Err(meta.error("unsupported route property"))
+///         }
+///     });
+///
+///     parse_macro_input!(args with parser);
+///
+///     /* ... */
+/// #   input
+/// }
+/// ```
+pub fn parser(logic: impl FnMut(ParseNestedMeta) -> Result<()>) -> impl Parser<Output = ()> {
+    |input: ParseStream| {
+        if input.is_empty() {
+            Ok(())
+        } else {
+            parse_nested_meta(input, logic)
+        }
+    }
+}
+
+/// Context for parsing a single entry of a nested meta list.
+///
+/// Refer to the [module documentation](self) for an example.
+pub struct ParseNestedMeta<'a> {
+    /// The path at the front of the entry, like `rename` in `rename = "id"`.
+    pub path: Path,
+    /// The rest of the input, positioned right after `path`.
+    pub input: ParseStream<'a>,
+}
+
+impl<'a> ParseNestedMeta<'a> {
+    /// Parses the `=` following the path, and returns the stream positioned
+    /// at the value.
+    ///
+    /// ```
+    /// # use syn::{meta, LitStr, Result};
+    /// #
+    /// # fn example(meta: meta::ParseNestedMeta) -> Result<()> {
+    /// // #[serde(rename = "id")]
+    /// let rename: LitStr = meta.value()?.parse()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn value(&self) -> Result<ParseStream<'a>> {
+        self.input.parse::<Token![=]>()?;
+        Ok(self.input)
+    }
+
+    /// Parses a parenthesized nested list following the path, calling
+    /// `logic` once per entry inside of it.
+    ///
+    /// ```
+    /// # use syn::{meta, LitStr, Result};
+    /// #
+    /// # fn example(meta: meta::ParseNestedMeta) -> Result<()> {
+    /// // #[serde(bound(serialize = "T: Serialize"))]
+    /// let mut serialize: Option<LitStr> = None;
+    /// meta.parse_nested_meta(|meta| {
+    ///     if meta.path.is_ident("serialize") {
+    ///         serialize = Some(meta.value()?.parse()?);
+    ///         Ok(())
+    ///     } else {
+    ///         Err(meta.error("unsupported bound"))
+    ///     }
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_nested_meta(
+        &self,
+        logic: impl FnMut(ParseNestedMeta) -> Result<()>,
+    ) -> Result<()> {
+        let content;
+        parenthesized!(content in self.input);
+        if content.is_empty() {
+            Ok(())
+        } else {
+            parse_nested_meta(&content, logic)
+        }
+    }
+
+    /// Report that the entry is not supported, pointing at its path.
+    pub fn error(&self, msg: impl Display) -> Error {
+        let start = match &self.path.leading_colon {
+            Some(colon) => colon.spans[0],
+            None => self.path.segments[0].ident.span(),
+        };
+        let end = self.path.segments[self.path.segments.len() - 1]
+            .ident
+            .span();
+        error::new2(start, end, msg)
+    }
+}
+
+pub(crate) fn parse_nested_meta(
+    input: ParseStream,
+    mut logic: impl FnMut(ParseNestedMeta) -> Result<()>,
+) -> Result<()> {
+    loop {
+        let path = input.call(parse_meta_path)?;
+        logic(ParseNestedMeta { path, input })?;
+        if input.is_empty() {
+            return Ok(());
+        }
+        input.parse::<Token![,]>()?;
+        if input.is_empty() {
+            return Ok(());
+        }
+    }
+}
+
+/// A registry of the keys expected inside of a helper attribute.
+///
+/// Each key is registered together with a place to store its value. Parsing
+/// then fails on keys that were not registered and on keys that appear more
+/// than once, with the error pointing at the offending key.
+///
+/// Refer to the [module documentation](self) for an example.
+pub struct Keys<'a> {
+    keys: Vec<Key<'a>>,
+}
+
+struct Key<'a> {
+    name: &'a str,
+    seen: bool,
+    parse: Box<dyn FnMut(ParseNestedMeta) -> Result<()> + 'a>,
+}
+
+impl<'a> Keys<'a> {
+    /// Creates a registry with no keys.
+    pub fn new() -> Self {
+        Keys { keys: Vec::new() }
+    }
+
+    /// Registers a `name = value` key whose value is parsed as `T`.
+    ///
+    /// Any syntax tree type works here, for example `LitStr` for `rename =
+    /// "id"`, `Path` for `crate = path::to::krate`, or `Expr` for `default =
+    /// Vec::new()`.
+    pub fn value<T: Parse + 'a>(self, name: &'a str, slot: &'a mut Option<T>) -> Self {
+        self.key(name, move |meta| {
+            *slot = Some(meta.value()?.parse()?);
+            Ok(())
+        })
+    }
+
+    /// Registers a key that takes no value, like `skip`, which sets `slot` to
+    /// true when present.
+    pub fn flag(self, name: &'a str, slot: &'a mut bool) -> Self {
+        self.key(name, move |meta| {
+            if meta.input.is_empty() || meta.input.peek(Token![,]) {
+                *slot = true;
+                Ok(())
+            } else {
+                Err(meta.error(format!("`{}` does not take a value", name)))
+            }
+        })
+    }
+
+    /// Registers a `name(...)` key whose nested entries are passed one by one
+    /// to `logic`.
+    ///
+    /// The nested entries can in turn be interpreted by another `Keys`
+    /// through [`Keys::parse_meta`].
+    pub fn nested(
+        self,
+        name: &'a str,
+        mut logic: impl FnMut(ParseNestedMeta) -> Result<()> + 'a,
+    ) -> Self {
+        self.key(name, move |meta| meta.parse_nested_meta(&mut logic))
+    }
+
+    /// Registers a key whose entry is parsed entirely by `logic`, starting
+    /// right after the key.
+    pub fn key(
+        mut self,
+        name: &'a str,
+        logic: impl FnMut(ParseNestedMeta) -> Result<()> + 'a,
+    ) -> Self {
+        self.keys.push(Key {
+            name,
+            seen: false,
+            parse: Box::new(logic),
+        });
+        self
+    }
+
+    /// Parses one entry of a nested meta list according to the registered
+    /// keys.
+    ///
+    /// This is intended to be called from within the closure passed to
+    /// [`parser`] or [`Attribute::parse_nested_meta`].
+    ///
+    /// [`Attribute::parse_nested_meta`]: crate::Attribute::parse_nested_meta
+    pub fn parse_meta(&mut self, meta: ParseNestedMeta) -> Result<()> {
+        for key in &mut self.keys {
+            if meta.path.is_ident(key.name) {
+                if key.seen {
+                    return Err(meta.error(format!("duplicate `{}` attribute", key.name)));
+                }
+                key.seen = true;
+                return (key.parse)(meta);
+            }
+        }
+
+        let mut msg = String::from("unknown attribute `");
+        for (i, segment) in meta.path.segments.iter().enumerate() {
+            if i > 0 || meta.path.leading_colon.is_some() {
+                msg.push_str("::");
+            }
+            msg.push_str(&segment.ident.to_string());
+        }
+        msg.push('`');
+        for (i, key) in self.keys.iter().enumerate() {
+            msg.push_str(if i == 0 {
+                ", expected "
+            } else if i + 1 == self.keys.len() {
+                if i == 1 {
+                    " or "
+                } else {
+                    ", or "
+                }
+            } else {
+                ", "
+            });
+            msg.push('`');
+            msg.push_str(key.name);
+            msg.push('`');
+        }
+        Err(meta.error(msg))
+    }
+
+    /// Parses the arguments of every attribute in `attrs` whose path is
+    /// `name` according to the registered keys.
+    ///
+    /// Parsing continues after an invalid entry so that all problems are
+    /// reported at once, combined into a single `Error`.
+    pub fn parse_attrs(mut self, attrs: &[Attribute], name: &str) -> Result<()> {
+        let mut errors = Vec::new();
+        for attr in attrs {
+            if !attr.path.is_ident(name) {
+                continue;
+            }
+            let mut entry_errors = Vec::new();
+            let result = attr.parse_nested_meta(|meta| {
+                let input = meta.input;
+                let had_unexpected = parse::has_unexpected(input);
+                if let Err(err) = self.parse_meta(meta) {
+                    entry_errors.push(err);
+                    // The unparsed remainder of an invalid entry would
+                    // otherwise be reported a second time as an unexpected
+                    // token.
+                    if !had_unexpected {
+                        parse::clear_unexpected(input);
+                    }
+                    skip_entry(input)?;
+                }
+                Ok(())
+            });
+            errors.extend(entry_errors);
+            if let Err(err) = result {
+                errors.push(err);
+            }
+        }
+
+        let mut errors = errors.into_iter();
+        match errors.next() {
+            Some(mut combined) => {
+                for err in errors {
+                    combined.combine(err);
+                }
+                Err(combined)
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'a> Default for Keys<'a> {
+    fn default() -> Self {
+        Keys::new()
+    }
+}
+
+fn skip_entry(input: ParseStream) -> Result<()> {
+    input.step(|cursor| {
+        let mut rest = *cursor;
+        while let Some((tt, next)) = rest.token_tree() {
+            match tt {
+                TokenTree::Punct(ref punct) if punct.as_char() == ',' => break,
+                _ => rest = next,
+            }
+        }
+        Ok(((), rest))
+    })
+}
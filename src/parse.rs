@@ -203,7 +203,6 @@ use std::marker::PhantomData;
 use std::mem;
 use std::ops::Deref;
 use std::rc::Rc;
-use std::str::FromStr;
 
 pub use crate::error::{Error, Result};
 pub use crate::lookahead::{Lookahead1, Peek};
@@ -262,6 +261,9 @@ pub struct ParseBuffer<'a> {
     cell: Cell<Cursor<'static>>,
     marker: PhantomData<Cursor<'a>>,
     unexpected: Cell<Option<Rc<Cell<Unexpected>>>>,
+    // Whether function bodies are left unparsed, for `syn::parse_file_lazy`.
+    // Inherited by forks and by the contents of delimited groups.
+    lazy: bool,
 }
 
 impl<'a> Drop for ParseBuffer<'a> {
@@ -385,6 +387,7 @@ pub(crate) fn new_parse_buffer(
     scope: Span,
     cursor: Cursor,
     unexpected: Rc<Cell<Unexpected>>,
+    lazy: bool,
 ) -> ParseBuffer {
     ParseBuffer {
         scope,
@@ -392,6 +395,7 @@ pub(crate) fn new_parse_buffer(
         cell: Cell::new(unsafe { mem::transmute::<Cursor, Cursor<'static>>(cursor) }),
         marker: PhantomData,
         unexpected: Cell::new(Some(unexpected)),
+        lazy,
     }
 }
 
@@ -441,6 +445,20 @@ pub(crate) fn get_unexpected(buffer: &ParseBuffer) -> Rc<Cell<Unexpected>> {
     cell_clone(&buffer.unexpected).unwrap()
 }
 
+#[cfg(any(feature = "full", feature = "derive"))]
+pub(crate) fn has_unexpected(buffer: &ParseBuffer) -> bool {
+    inner_unexpected(buffer).1.is_some()
+}
+
+#[cfg(any(feature = "full", feature = "derive"))]
+pub(crate) fn clear_unexpected(buffer: &ParseBuffer) {
+    inner_unexpected(buffer).0.set(Unexpected::None);
+}
+
+pub(crate) fn is_lazy(buffer: &ParseBuffer) -> bool {
+    buffer.lazy
+}
+
 fn span_of_unexpected_ignoring_nones(mut cursor: Cursor) -> Option<Span> {
     if cursor.eof() {
         return None;
@@ -920,6 +938,7 @@ impl<'a> ParseBuffer<'a> {
             // Not the parent's unexpected. Nothing cares whether the clone
             // parses all the way unless we `advance_to`.
             unexpected: Cell::new(Some(Rc::new(Cell::new(Unexpected::None)))),
+            lazy: self.lazy,
         }
     }
 
@@ -1171,7 +1190,7 @@ pub trait Parser: Sized {
     /// Every span in the resulting syntax tree will be set to resolve at the
     /// macro call site.
     fn parse_str(self, s: &str) -> Result<Self::Output> {
-        self.parse2(proc_macro2::TokenStream::from_str(s)?)
+        self.parse2(crate::error::lex(s)?)
     }
 
     // Not public API.
@@ -1194,7 +1213,7 @@ fn tokens_to_parse_buffer(tokens: &TokenBuffer) -> ParseBuffer {
     let scope = Span::call_site();
     let cursor = tokens.begin();
     let unexpected = Rc::new(Cell::new(Unexpected::None));
-    new_parse_buffer(scope, cursor, unexpected)
+    new_parse_buffer(scope, cursor, unexpected, false)
 }
 
 impl<F, T> Parser for F
@@ -1221,7 +1240,7 @@ where
         let buf = TokenBuffer::new2(tokens);
         let cursor = buf.begin();
         let unexpected = Rc::new(Cell::new(Unexpected::None));
-        let state = new_parse_buffer(scope, cursor, unexpected);
+        let state = new_parse_buffer(scope, cursor, unexpected, false);
         let node = self(&state)?;
         state.check_unexpected()?;
         if let Some(unexpected_span) = span_of_unexpected_ignoring_nones(state.cursor()) {
@@ -1238,11 +1257,38 @@ where
     }
 }
 
+// Parses with the bodies of functions left as unparsed tokens, for
+// `syn::parse_file_lazy`.
+#[cfg(feature = "full")]
+pub(crate) fn parse_lazy<T>(f: fn(ParseStream) -> Result<T>, tokens: TokenStream) -> Result<T> {
+    let buf = TokenBuffer::new2(tokens);
+    let mut state = tokens_to_parse_buffer(&buf);
+    state.lazy = true;
+    let node = f(&state)?;
+    state.check_unexpected()?;
+    if let Some(unexpected_span) = span_of_unexpected_ignoring_nones(state.cursor()) {
+        Err(Error::new(unexpected_span, "unexpected token"))
+    } else {
+        Ok(node)
+    }
+}
+
 #[cfg(any(feature = "full", feature = "derive"))]
 pub(crate) fn parse_scoped<F: Parser>(f: F, scope: Span, tokens: TokenStream) -> Result<F::Output> {
     f.__parse_scoped(scope, tokens)
 }
 
+// The span of errors at the end of the input: that of the last token, rather
+// than the call site which has no location.
+#[cfg(any(feature = "full", all(feature = "derive", feature = "span-locations")))]
+pub(crate) fn end_of_input(last: Option<TokenTree>) -> Span {
+    match last {
+        Some(TokenTree::Group(group)) => group.span_close(),
+        Some(token) => token.span(),
+        None => Span::call_site(),
+    }
+}
+
 #[cfg(any(feature = "full", feature = "derive"))]
 pub(crate) fn parse_stream<F: Parser>(f: F, input: ParseStream) -> Result<F::Output> {
     f.__parse_stream(input)
@@ -81,6 +81,51 @@ macro_rules! parse_quote {
     };
 }
 
+/// This macro is [`parse_quote!`] + [`quote_spanned!`].
+///
+/// [`quote_spanned!`]: https://docs.rs/quote/1.0/quote/macro.quote_spanned.html
+///
+/// Please refer to each of their documentation.
+///
+/// The given span is applied to every token written directly inside of the
+/// macro invocation. Tokens interpolated with `#var` keep the spans they
+/// already had.
+///
+/// # Example
+///
+/// ```
+/// use quote::{quote, quote_spanned};
+/// use syn::spanned::Spanned;
+/// use syn::{parse_quote_spanned, ReturnType, Signature};
+///
+/// // Changes `fn()` to `fn() -> Pin<Box<dyn Future<Output = ()>>>`,
+/// // and `fn() -> T` to `fn() -> Pin<Box<dyn Future<Output = T>>>`,
+/// // without introducing any call_site() spans.
+/// fn make_output_boxed_future(function: &mut Signature) {
+///     let ret = match &function.output {
+///         ReturnType::Default => quote_spanned!(function.paren_token.span=> ()),
+///         ReturnType::Type(_, ret) => quote!(#ret),
+///     };
+///     function.output = parse_quote_spanned! {ret.span()=>
+///         -> ::std::pin::Pin<::std::boxed::Box<dyn ::std::future::Future<Output = #ret>>>
+///     };
+/// }
+/// ```
+///
+/// *This macro is available only if Syn is built with the `"parsing"` and
+/// `"printing"` features.*
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "parsing", feature = "printing"))))]
+#[macro_export]
+macro_rules! parse_quote_spanned {
+    ($span:expr=> $($tt:tt)*) => {
+        $crate::parse_quote::parse(
+            $crate::__private::From::from(
+                $crate::__private::quote::quote_spanned!($span=> $($tt)*)
+            )
+        )
+    };
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Can parse any type that implements Parse.
 
@@ -18,6 +18,9 @@ ast_enum_of_structs! {
         /// A box pattern: `box v`.
         Box(PatBox),
 
+        /// A const block in pattern position: `const { ... }`.
+        Const(PatConst),
+
         /// A pattern that binds a new variable: `ref mut binding @ SUBPATTERN`.
         Ident(PatIdent),
 
@@ -112,6 +115,18 @@ ast_struct! {
     }
 }
 
+ast_struct! {
+    /// A const block in pattern position: `const { ... }`.
+    ///
+    /// *This type is available only if Syn is built with the `"full"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    pub struct PatConst {
+        pub attrs: Vec<Attribute>,
+        pub const_token: Token![const],
+        pub block: Block,
+    }
+}
+
 ast_struct! {
     /// A pattern that binds a new variable: `ref mut binding @ SUBPATTERN`.
     ///
@@ -365,7 +380,7 @@ pub mod parsing {
             } else if lookahead.peek(Token![..]) && !input.peek(Token![...]) {
                 pat_range_half_open(input, begin)
             } else if lookahead.peek(Token![const]) {
-                input.call(pat_const).map(Pat::Verbatim)
+                input.call(pat_const).map(Pat::Const)
             } else {
                 Err(lookahead.error())
             }
@@ -643,8 +658,12 @@ pub mod parsing {
             } else {
                 Ok(Pat::Verbatim(verbatim::between(begin, input)))
             }
-        } else if let Expr::Verbatim(verbatim) = *lo {
-            Ok(Pat::Verbatim(verbatim))
+        } else if let Expr::Const(expr) = *lo {
+            Ok(Pat::Const(PatConst {
+                attrs: expr.attrs,
+                const_token: expr.const_token,
+                block: expr.block,
+            }))
         } else {
             Ok(Pat::Lit(PatLit {
                 attrs: Vec::new(),
@@ -679,7 +698,7 @@ pub mod parsing {
         {
             Expr::Path(input.parse()?)
         } else if lookahead.peek(Token![const]) {
-            Expr::Verbatim(input.call(expr::parsing::expr_const)?)
+            Expr::Const(input.parse()?)
         } else {
             return Err(lookahead.error());
         };
@@ -717,16 +736,19 @@ pub mod parsing {
         })
     }
 
-    fn pat_const(input: ParseStream) -> Result<TokenStream> {
-        let begin = input.fork();
-        input.parse::<Token![const]>()?;
+    fn pat_const(input: ParseStream) -> Result<PatConst> {
+        let const_token: Token![const] = input.parse()?;
 
         let content;
-        braced!(content in input);
-        content.call(Attribute::parse_inner)?;
-        content.call(Block::parse_within)?;
+        let brace_token = braced!(content in input);
+        let inner_attrs = content.call(Attribute::parse_inner)?;
+        let stmts = content.call(Block::parse_within)?;
 
-        Ok(verbatim::between(begin, input))
+        Ok(PatConst {
+            attrs: inner_attrs,
+            const_token,
+            block: Block { brace_token, stmts },
+        })
     }
 
     pub fn multi_pat(input: ParseStream) -> Result<Pat> {
@@ -852,6 +874,18 @@ mod printing {
         }
     }
 
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for PatConst {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            tokens.append_all(self.attrs.outer());
+            self.const_token.to_tokens(tokens);
+            self.block.brace_token.surround(tokens, |tokens| {
+                tokens.append_all(self.attrs.inner());
+                tokens.append_all(&self.block.stmts);
+            });
+        }
+    }
+
     #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
     impl ToTokens for PatReference {
         fn to_tokens(&self, tokens: &mut TokenStream) {
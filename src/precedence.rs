@@ -0,0 +1,659 @@
+use crate::expr::Expr;
+#[cfg(feature = "visit-mut")]
+use crate::expr::ExprParen;
+use crate::lit::Lit;
+use crate::op::BinOp;
+#[cfg(all(feature = "full", feature = "visit-mut"))]
+use crate::stmt::Stmt;
+#[cfg(feature = "visit-mut")]
+use crate::token;
+#[cfg(feature = "visit-mut")]
+use crate::visit_mut::{self, VisitMut};
+#[cfg(feature = "visit-mut")]
+use proc_macro2::TokenStream;
+use std::cmp::Ordering;
+use std::fmt::{self, Debug};
+#[cfg(feature = "visit-mut")]
+use std::mem;
+
+/// How tightly an operator binds its operands, from loosest to tightest.
+///
+/// *This type is available only if Syn is built with the `"derive"` or
+/// `"full"` feature.*
+///
+/// Levels compare in binding order, so `Precedence::Term >
+/// Precedence::Arithmetic` because `*` binds more tightly than `+`.
+///
+/// A precedence is used as the lower bound when parsing an expression with
+/// `ExprParser::min_precedence`: operators binding more loosely than the bound
+/// are left in the input. When printing, the same ordering decides where
+/// parentheses are required around a subexpression.
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "full", feature = "derive"))))]
+pub enum Precedence {
+    /// No bound: any expression at all.
+    Any,
+    /// `return`, `break`, `yield`, closures, `let` and ranges without a lower
+    /// bound, which extend as far to the right as possible.
+    Jump,
+    /// `=` `+=` `-=` `*=` `/=` `%=` `&=` `|=` `^=` `<<=` `>>=`
+    Assign,
+    /// `..` `..=`
+    Range,
+    /// `||`
+    Or,
+    /// `&&`
+    And,
+    /// `==` `!=` `<` `>` `<=` `>=`
+    Compare,
+    /// `|`
+    BitOr,
+    /// `^`
+    BitXor,
+    /// `&`
+    BitAnd,
+    /// `<<` `>>`
+    Shift,
+    /// `+` `-`
+    Arithmetic,
+    /// `*` `/` `%`
+    Term,
+    /// `as` and type ascription
+    Cast,
+    /// `-x` `!x` `*x` `&x` `&mut x` `box x`
+    Prefix,
+    /// `x()` `x[i]` `x.f` `x.f()` `x?` `x.await`
+    Postfix,
+    /// Expressions that are delimited on both ends, like paths, literals,
+    /// blocks and parenthesized expressions.
+    Unambiguous,
+}
+
+impl Precedence {
+    /// The precedence of a binary operator.
+    pub fn of(op: &BinOp) -> Self {
+        match *op {
+            BinOp::Add(_) | BinOp::Sub(_) => Precedence::Arithmetic,
+            BinOp::Mul(_) | BinOp::Div(_) | BinOp::Rem(_) => Precedence::Term,
+            BinOp::And(_) => Precedence::And,
+            BinOp::Or(_) => Precedence::Or,
+            BinOp::BitXor(_) => Precedence::BitXor,
+            BinOp::BitAnd(_) => Precedence::BitAnd,
+            BinOp::BitOr(_) => Precedence::BitOr,
+            BinOp::Shl(_) | BinOp::Shr(_) => Precedence::Shift,
+            BinOp::Eq(_)
+            | BinOp::Lt(_)
+            | BinOp::Le(_)
+            | BinOp::Ne(_)
+            | BinOp::Ge(_)
+            | BinOp::Gt(_) => Precedence::Compare,
+            BinOp::AddEq(_)
+            | BinOp::SubEq(_)
+            | BinOp::MulEq(_)
+            | BinOp::DivEq(_)
+            | BinOp::RemEq(_)
+            | BinOp::BitXorEq(_)
+            | BinOp::BitAndEq(_)
+            | BinOp::BitOrEq(_)
+            | BinOp::ShlEq(_)
+            | BinOp::ShrEq(_) => Precedence::Assign,
+        }
+    }
+
+    /// The precedence of the outermost operator of an expression.
+    ///
+    /// A subexpression whose precedence is lower than required by its
+    /// position needs to be wrapped in parentheses to be printed correctly.
+    pub fn of_expr(expr: &Expr) -> Self {
+        match expr {
+            Expr::Closure(_) | Expr::Return(_) | Expr::Break(_) | Expr::Yield(_) | Expr::Let(_) => {
+                Precedence::Jump
+            }
+            // A range without a lower bound takes everything to its right as
+            // its upper bound.
+            #[cfg(feature = "full")]
+            Expr::Range(e) if e.from.is_none() => Precedence::Jump,
+            Expr::Assign(_) | Expr::AssignOp(_) => Precedence::Assign,
+            Expr::Range(_) => Precedence::Range,
+            Expr::Binary(e) => Precedence::of(&e.op),
+            Expr::Cast(_) | Expr::Type(_) => Precedence::Cast,
+            Expr::Unary(_) | Expr::Reference(_) | Expr::RawAddr(_) | Expr::Box(_) => {
+                Precedence::Prefix
+            }
+            // A negative literal is printed as a `-` followed by the number.
+            Expr::Lit(e) if is_negative(&e.lit) => Precedence::Prefix,
+            Expr::Call(_)
+            | Expr::MethodCall(_)
+            | Expr::Field(_)
+            | Expr::Index(_)
+            | Expr::Try(_)
+            | Expr::Await(_) => Precedence::Postfix,
+            _ => Precedence::Unambiguous,
+        }
+    }
+}
+
+fn is_negative(lit: &Lit) -> bool {
+    match lit {
+        Lit::Int(lit) => lit.base10_digits().starts_with('-'),
+        Lit::Float(lit) => lit.base10_digits().starts_with('-'),
+        _ => false,
+    }
+}
+
+impl Copy for Precedence {}
+
+impl Clone for Precedence {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl PartialEq for Precedence {
+    fn eq(&self, other: &Self) -> bool {
+        *self as u8 == *other as u8
+    }
+}
+
+impl Eq for Precedence {}
+
+impl PartialOrd for Precedence {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Precedence {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let this = *self as u8;
+        let other = *other as u8;
+        this.cmp(&other)
+    }
+}
+
+impl Debug for Precedence {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(match self {
+            Precedence::Any => "Any",
+            Precedence::Jump => "Jump",
+            Precedence::Assign => "Assign",
+            Precedence::Range => "Range",
+            Precedence::Or => "Or",
+            Precedence::And => "And",
+            Precedence::Compare => "Compare",
+            Precedence::BitOr => "BitOr",
+            Precedence::BitXor => "BitXor",
+            Precedence::BitAnd => "BitAnd",
+            Precedence::Shift => "Shift",
+            Precedence::Arithmetic => "Arithmetic",
+            Precedence::Term => "Term",
+            Precedence::Cast => "Cast",
+            Precedence::Prefix => "Prefix",
+            Precedence::Postfix => "Postfix",
+            Precedence::Unambiguous => "Unambiguous",
+        })
+    }
+}
+
+// The syntactic position of a subexpression within its parent expression.
+#[cfg(any(feature = "printing", feature = "visit-mut"))]
+pub(crate) enum Operand {
+    // Left-hand side of a binary operator, assignment, range or cast.
+    Left(Precedence),
+    // Left-hand side of `<` or `<<`, after which a cast's type would take the
+    // operator as the start of generic arguments.
+    LeftOfLt(Precedence),
+    // Right-hand side of a binary operator, assignment or range.
+    Right(Precedence),
+    // Operand of a unary operator, reference or `box`.
+    Prefix,
+    // Operand of a method call, field access, index, `?` or `.await`.
+    Postfix,
+    // The function being called in a call expression.
+    Callee,
+    // Position before a block, like the condition of an `if`, in which the
+    // block must not be parsed as part of the expression.
+    #[cfg(feature = "full")]
+    Condition,
+}
+
+#[cfg(any(feature = "printing", feature = "visit-mut"))]
+impl Operand {
+    pub(crate) fn left_of(op: &BinOp) -> Self {
+        match op {
+            BinOp::Lt(_) | BinOp::Shl(_) => Operand::LeftOfLt(Precedence::of(op)),
+            _ => Operand::Left(Precedence::of(op)),
+        }
+    }
+
+    pub(crate) fn right_of(op: &BinOp) -> Self {
+        Operand::Right(Precedence::of(op))
+    }
+}
+
+#[cfg(any(feature = "printing", feature = "visit-mut"))]
+impl Copy for Operand {}
+
+#[cfg(any(feature = "printing", feature = "visit-mut"))]
+impl Clone for Operand {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+// Whether `expr` must be wrapped in parentheses in order to be printed in the
+// given position.
+#[cfg(any(feature = "printing", feature = "visit-mut"))]
+pub(crate) fn needs_parens(expr: &Expr, operand: Operand) -> bool {
+    let precedence = Precedence::of_expr(expr);
+    match operand {
+        Operand::Left(base) => left_needs_parens(expr, precedence, base),
+        Operand::LeftOfLt(base) => {
+            left_needs_parens(expr, precedence, base)
+                || match rightmost(expr) {
+                    Expr::Cast(_) | Expr::Type(_) => true,
+                    _ => false,
+                }
+        }
+        Operand::Right(base) => {
+            precedence < base || precedence == base && base != Precedence::Assign
+        }
+        // A closure or jump extends as far to the right as it can, which is
+        // harmless in the operand of a prefix operator; it is the expression
+        // containing the prefix operator that gets parenthesized if needed.
+        Operand::Prefix => precedence < Precedence::Prefix && !is_greedy(expr),
+        Operand::Postfix => precedence < Precedence::Postfix,
+        Operand::Callee => {
+            precedence < Precedence::Postfix
+                || match expr {
+                    Expr::Field(_) => true,
+                    _ => false,
+                }
+        }
+        #[cfg(feature = "full")]
+        Operand::Condition => swallows_block(expr),
+    }
+}
+
+#[cfg(any(feature = "printing", feature = "visit-mut"))]
+fn left_needs_parens(expr: &Expr, precedence: Precedence, base: Precedence) -> bool {
+    if precedence < base {
+        return true;
+    }
+    if precedence == base {
+        // Assignment is right associative; comparisons and ranges do not
+        // associate at all.
+        match base {
+            Precedence::Assign | Precedence::Compare | Precedence::Range => return true,
+            _ => {}
+        }
+    }
+    // A range without an upper bound would take the following operator as the
+    // start of its upper bound.
+    let rightmost = rightmost(expr);
+    match rightmost {
+        #[cfg(feature = "full")]
+        Expr::Range(range) => range.to.is_none(),
+        _ => is_greedy(rightmost),
+    }
+}
+
+// Expressions that take everything to their right as part of themselves.
+#[cfg(any(feature = "printing", feature = "visit-mut"))]
+fn is_greedy(expr: &Expr) -> bool {
+    match expr {
+        Expr::Closure(_) | Expr::Return(_) | Expr::Break(_) | Expr::Yield(_) => true,
+        _ => false,
+    }
+}
+
+// The subexpression printed last when printing `expr`, not counting the ones
+// that get parenthesized.
+#[cfg(any(feature = "printing", feature = "visit-mut"))]
+fn rightmost(expr: &Expr) -> &Expr {
+    let (next, operand) = match expr {
+        Expr::Binary(e) => (&*e.right, Operand::right_of(&e.op)),
+        Expr::Unary(e) => (&*e.expr, Operand::Prefix),
+        #[cfg(feature = "full")]
+        Expr::Assign(e) => (&*e.right, Operand::Right(Precedence::Assign)),
+        #[cfg(feature = "full")]
+        Expr::AssignOp(e) => (&*e.right, Operand::Right(Precedence::Assign)),
+        #[cfg(feature = "full")]
+        Expr::Range(e) => match &e.to {
+            Some(to) => (&**to, Operand::Right(Precedence::Range)),
+            None => return expr,
+        },
+        #[cfg(feature = "full")]
+        Expr::Reference(e) => (&*e.expr, Operand::Prefix),
+        #[cfg(feature = "full")]
+        Expr::RawAddr(e) => (&*e.expr, Operand::Prefix),
+        #[cfg(feature = "full")]
+        Expr::Box(e) => (&*e.expr, Operand::Prefix),
+        _ => return expr,
+    };
+    if needs_parens(next, operand) {
+        expr
+    } else {
+        rightmost(next)
+    }
+}
+
+// Whether printing `expr` in front of a block would cause the block to be
+// parsed as part of the expression, either because a struct literal is printed
+// outside of any delimiters or because the expression ends in `return` or
+// `yield` which greedily take a block as their value.
+#[cfg(all(feature = "full", any(feature = "printing", feature = "visit-mut")))]
+fn swallows_block(expr: &Expr) -> bool {
+    fn exposed(expr: &Expr, operand: Operand) -> bool {
+        !needs_parens(expr, operand) && swallows_block(expr)
+    }
+
+    match expr {
+        Expr::Struct(_) => true,
+        Expr::Binary(e) => {
+            exposed(&e.left, Operand::left_of(&e.op)) || exposed(&e.right, Operand::right_of(&e.op))
+        }
+        Expr::Assign(e) => {
+            exposed(&e.left, Operand::Left(Precedence::Assign))
+                || exposed(&e.right, Operand::Right(Precedence::Assign))
+        }
+        Expr::AssignOp(e) => {
+            exposed(&e.left, Operand::Left(Precedence::Assign))
+                || exposed(&e.right, Operand::Right(Precedence::Assign))
+        }
+        Expr::Range(e) => {
+            e.from.as_ref().map_or(false, |from| {
+                exposed(from, Operand::Left(Precedence::Range))
+            }) || e
+                .to
+                .as_ref()
+                .map_or(false, |to| exposed(to, Operand::Right(Precedence::Range)))
+        }
+        Expr::Cast(e) => exposed(&e.expr, Operand::Left(Precedence::Cast)),
+        Expr::Type(e) => exposed(&e.expr, Operand::Left(Precedence::Cast)),
+        Expr::Unary(e) => exposed(&e.expr, Operand::Prefix),
+        Expr::Reference(e) => exposed(&e.expr, Operand::Prefix),
+        Expr::RawAddr(e) => exposed(&e.expr, Operand::Prefix),
+        Expr::Box(e) => exposed(&e.expr, Operand::Prefix),
+        Expr::Call(e) => exposed(&e.func, Operand::Callee),
+        Expr::MethodCall(e) => exposed(&e.receiver, Operand::Postfix),
+        Expr::Field(e) => exposed(&e.base, Operand::Postfix),
+        Expr::Index(e) => exposed(&e.expr, Operand::Postfix),
+        Expr::Try(e) => exposed(&e.expr, Operand::Postfix),
+        Expr::Await(e) => exposed(&e.base, Operand::Postfix),
+        Expr::Return(e) => e.expr.as_ref().map_or(true, |e| swallows_block(e)),
+        // Where a block may follow, `break` followed by `{` has no value.
+        Expr::Break(e) => e
+            .expr
+            .as_ref()
+            .map_or(false, |e| swallows_block(e) || starts_with_brace(e)),
+        Expr::Yield(_) => true,
+        Expr::Closure(e) => swallows_block(&e.body),
+        _ => false,
+    }
+}
+
+// The block-like expression, such as a `match`, that `expr` begins with if it
+// would end the statement on its own when `expr` is printed at the start of a
+// statement, along with the number of attributes printed before it. Only a `.`
+// or `?` after the block carries on the expression.
+#[cfg(all(feature = "full", any(feature = "printing", feature = "visit-mut")))]
+pub(crate) fn leading_block(expr: &Expr) -> Option<(&Expr, usize)> {
+    let (operand, position, attrs) = leading_operand(expr)?;
+    if needs_parens(operand, position) {
+        return None;
+    }
+    if !is_block_like(operand) {
+        let (block, inner_attrs) = leading_block(operand)?;
+        return Some((block, attrs + inner_attrs));
+    }
+    match expr {
+        Expr::MethodCall(_) | Expr::Field(_) | Expr::Try(_) | Expr::Await(_) => None,
+        _ => Some((operand, attrs)),
+    }
+}
+
+// The operand printed first in `expr`, along with its position and the number
+// of attributes of `expr` printed before it.
+#[cfg(all(feature = "full", any(feature = "printing", feature = "visit-mut")))]
+fn leading_operand(expr: &Expr) -> Option<(&Expr, Operand, usize)> {
+    match expr {
+        Expr::Binary(e) => Some((&e.left, Operand::left_of(&e.op), e.attrs.len())),
+        Expr::Assign(e) => Some((&e.left, Operand::Left(Precedence::Assign), e.attrs.len())),
+        Expr::AssignOp(e) => Some((&e.left, Operand::Left(Precedence::Assign), e.attrs.len())),
+        Expr::Range(e) => e
+            .from
+            .as_ref()
+            .map(|from| (&**from, Operand::Left(Precedence::Range), e.attrs.len())),
+        Expr::Cast(e) => Some((&e.expr, Operand::Left(Precedence::Cast), e.attrs.len())),
+        Expr::Type(e) => Some((&e.expr, Operand::Left(Precedence::Cast), e.attrs.len())),
+        Expr::Call(e) => Some((&e.func, Operand::Callee, e.attrs.len())),
+        Expr::Index(e) => Some((&e.expr, Operand::Postfix, e.attrs.len())),
+        Expr::MethodCall(e) => Some((&e.receiver, Operand::Postfix, e.attrs.len())),
+        Expr::Field(e) => Some((&e.base, Operand::Postfix, e.attrs.len())),
+        Expr::Try(e) => Some((&e.expr, Operand::Postfix, e.attrs.len())),
+        Expr::Await(e) => Some((&e.base, Operand::Postfix, e.attrs.len())),
+        _ => None,
+    }
+}
+
+// Expressions that are parsed as a complete statement when they appear at the
+// start of one.
+#[cfg(all(feature = "full", any(feature = "printing", feature = "visit-mut")))]
+fn is_block_like(expr: &Expr) -> bool {
+    match expr {
+        Expr::If(_)
+        | Expr::While(_)
+        | Expr::ForLoop(_)
+        | Expr::Loop(_)
+        | Expr::Match(_)
+        | Expr::TryBlock(_)
+        | Expr::Unsafe(_)
+        | Expr::Const(_)
+        | Expr::Block(_) => true,
+        _ => false,
+    }
+}
+
+// Whether the first token printed for `expr` is the `{` of a block.
+#[cfg(all(feature = "full", any(feature = "printing", feature = "visit-mut")))]
+fn starts_with_brace(expr: &Expr) -> bool {
+    match expr {
+        Expr::Block(e) => e.attrs.is_empty() && e.label.is_none(),
+        _ => match leading_operand(expr) {
+            Some((operand, position, _attrs)) => {
+                !needs_parens(operand, position) && starts_with_brace(operand)
+            }
+            None => false,
+        },
+    }
+}
+
+#[cfg(feature = "visit-mut")]
+impl Expr {
+    /// Rewrites the parentheses around the operands of every expression
+    /// inside of `self` into the minimal set that preserves its structure.
+    ///
+    /// Parentheses that are not required by precedence are removed, and
+    /// `Expr::Paren` nodes are inserted where the structure could not
+    /// otherwise be expressed in source code. Afterward, printing the
+    /// expression and parsing it back produces an identical syntax tree.
+    ///
+    /// Printing with `ToTokens` already inserts the required parentheses on
+    /// the fly, so this is only needed when the parenthesized tree itself is
+    /// of interest, for example to compare it against a parsed one.
+    ///
+    /// *This method is available only if Syn is built with the `"visit-mut"`
+    /// feature.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use quote::quote;
+    /// use syn::{parse_quote, Expr, ExprBinary};
+    ///
+    /// let mut sum: ExprBinary = parse_quote!(a + b);
+    /// let product: ExprBinary = parse_quote!(c * d);
+    /// sum.left = Box::new(Expr::Binary(product));
+    /// let mut expr = Expr::Binary(ExprBinary {
+    ///     right: Box::new(Expr::Binary(sum)),
+    ///     ..parse_quote!(x * y)
+    /// });
+    ///
+    /// expr.normalize_parens();
+    /// assert_eq!(quote!(#expr).to_string(), "x * (c * d + b)");
+    /// ```
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "visit-mut")))]
+    pub fn normalize_parens(&mut self) {
+        StripParens.visit_expr_mut(self);
+        InsertParens.visit_expr_mut(self);
+    }
+}
+
+#[cfg(feature = "visit-mut")]
+struct StripParens;
+
+#[cfg(feature = "visit-mut")]
+impl VisitMut for StripParens {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        visit_mut::visit_expr_mut(self, expr);
+        operands_mut(expr, &mut |operand, _position| loop {
+            let inner = match operand {
+                Expr::Paren(paren) if paren.attrs.is_empty() => take(&mut paren.expr),
+                _ => break,
+            };
+            *operand = inner;
+        });
+    }
+}
+
+#[cfg(feature = "visit-mut")]
+struct InsertParens;
+
+#[cfg(feature = "visit-mut")]
+impl VisitMut for InsertParens {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        operands_mut(expr, &mut |operand, position| {
+            if needs_parens(operand, position) {
+                parenthesize(operand);
+            }
+        });
+        visit_mut::visit_expr_mut(self, expr);
+    }
+
+    // An expression at the start of a statement gets parenthesized if it is a
+    // `let`, or else around the block-like expression it begins with.
+    #[cfg(feature = "full")]
+    fn visit_stmt_mut(&mut self, stmt: &mut Stmt) {
+        match stmt {
+            Stmt::Expr(expr) | Stmt::Semi(expr, _) => match expr {
+                Expr::Let(_) => parenthesize(expr),
+                _ => parenthesize_leading_block(expr),
+            },
+            Stmt::Local(_) | Stmt::Item(_) => {}
+        }
+        visit_mut::visit_stmt_mut(self, stmt);
+    }
+}
+
+#[cfg(all(feature = "full", feature = "visit-mut"))]
+fn parenthesize_leading_block(expr: &mut Expr) {
+    if leading_block(expr).is_none() {
+        return;
+    }
+    let operand = match expr {
+        Expr::Binary(e) => &mut *e.left,
+        Expr::Assign(e) => &mut *e.left,
+        Expr::AssignOp(e) => &mut *e.left,
+        Expr::Range(e) => match &mut e.from {
+            Some(from) => &mut **from,
+            None => return,
+        },
+        Expr::Cast(e) => &mut *e.expr,
+        Expr::Type(e) => &mut *e.expr,
+        Expr::Call(e) => &mut *e.func,
+        Expr::Index(e) => &mut *e.expr,
+        Expr::MethodCall(e) => &mut *e.receiver,
+        Expr::Field(e) => &mut *e.base,
+        Expr::Try(e) => &mut *e.expr,
+        Expr::Await(e) => &mut *e.base,
+        _ => return,
+    };
+    if is_block_like(operand) {
+        parenthesize(operand);
+    } else {
+        parenthesize_leading_block(operand);
+    }
+}
+
+#[cfg(feature = "visit-mut")]
+fn parenthesize(expr: &mut Expr) {
+    *expr = Expr::Paren(ExprParen {
+        attrs: Vec::new(),
+        paren_token: token::Paren::default(),
+        expr: Box::new(take(expr)),
+    });
+}
+
+#[cfg(feature = "visit-mut")]
+fn take(expr: &mut Expr) -> Expr {
+    mem::replace(expr, Expr::Verbatim(TokenStream::new()))
+}
+
+#[cfg(feature = "visit-mut")]
+fn operands_mut(expr: &mut Expr, f: &mut dyn FnMut(&mut Expr, Operand)) {
+    match expr {
+        Expr::Binary(e) => {
+            f(&mut e.left, Operand::left_of(&e.op));
+            f(&mut e.right, Operand::right_of(&e.op));
+        }
+        Expr::Cast(e) => f(&mut e.expr, Operand::Left(Precedence::Cast)),
+        Expr::Unary(e) => f(&mut e.expr, Operand::Prefix),
+        Expr::Call(e) => f(&mut e.func, Operand::Callee),
+        Expr::Field(e) => f(&mut e.base, Operand::Postfix),
+        Expr::Index(e) => f(&mut e.expr, Operand::Postfix),
+        #[cfg(feature = "full")]
+        Expr::Assign(e) => {
+            f(&mut e.left, Operand::Left(Precedence::Assign));
+            f(&mut e.right, Operand::Right(Precedence::Assign));
+        }
+        #[cfg(feature = "full")]
+        Expr::AssignOp(e) => {
+            f(&mut e.left, Operand::Left(Precedence::Assign));
+            f(&mut e.right, Operand::Right(Precedence::Assign));
+        }
+        #[cfg(feature = "full")]
+        Expr::Range(e) => {
+            if let Some(from) = &mut e.from {
+                f(from, Operand::Left(Precedence::Range));
+            }
+            if let Some(to) = &mut e.to {
+                f(to, Operand::Right(Precedence::Range));
+            }
+        }
+        #[cfg(feature = "full")]
+        Expr::Type(e) => f(&mut e.expr, Operand::Left(Precedence::Cast)),
+        #[cfg(feature = "full")]
+        Expr::Reference(e) => f(&mut e.expr, Operand::Prefix),
+        #[cfg(feature = "full")]
+        Expr::RawAddr(e) => f(&mut e.expr, Operand::Prefix),
+        #[cfg(feature = "full")]
+        Expr::Box(e) => f(&mut e.expr, Operand::Prefix),
+        #[cfg(feature = "full")]
+        Expr::MethodCall(e) => f(&mut e.receiver, Operand::Postfix),
+        #[cfg(feature = "full")]
+        Expr::Try(e) => f(&mut e.expr, Operand::Postfix),
+        #[cfg(feature = "full")]
+        Expr::Await(e) => f(&mut e.base, Operand::Postfix),
+        #[cfg(feature = "full")]
+        Expr::Let(e) => f(&mut e.expr, Operand::Condition),
+        #[cfg(feature = "full")]
+        Expr::If(e) => f(&mut e.cond, Operand::Condition),
+        #[cfg(feature = "full")]
+        Expr::While(e) => f(&mut e.cond, Operand::Condition),
+        #[cfg(feature = "full")]
+        Expr::ForLoop(e) => f(&mut e.expr, Operand::Condition),
+        #[cfg(feature = "full")]
+        Expr::Match(e) => f(&mut e.expr, Operand::Condition),
+        _ => {}
+    }
+}
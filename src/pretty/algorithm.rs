@@ -0,0 +1,258 @@
+// Line breaking algorithm used by the pretty printer.
+//
+// The printer first builds a tree of groups out of words and breaks, then
+// lays it out in a single pass. A group is printed on one line if everything
+// from the start of the group up to the next line break after it fits within
+// the margin. Otherwise every break directly inside of the group becomes a
+// newline, and nested groups get the same choice independently. A hard break
+// always produces a newline and forces its enclosing group to break.
+
+use std::borrow::Cow;
+
+pub(crate) const MARGIN: isize = 100;
+pub(crate) const INDENT: isize = 4;
+
+enum Doc {
+    Text(Cow<'static, str>),
+    Break(Break),
+    // Text printed only if the enclosing group is broken, such as a trailing
+    // comma after the last element of a list.
+    IfBreak(&'static str),
+    Group(Group),
+}
+
+struct Break {
+    // Number of spaces printed in place of the break if the group is flat.
+    blank: usize,
+    // Indentation of the next line relative to the enclosing group.
+    offset: isize,
+    hard: bool,
+}
+
+struct Group {
+    // Indentation of the contents relative to the parent group, applied only
+    // if this group is broken.
+    indent: isize,
+    docs: Vec<Doc>,
+    hard: bool,
+}
+
+pub(crate) struct Printer {
+    stack: Vec<Group>,
+}
+
+impl Printer {
+    pub fn new() -> Self {
+        Printer {
+            stack: vec![Group {
+                indent: 0,
+                docs: Vec::new(),
+                hard: true,
+            }],
+        }
+    }
+
+    fn push(&mut self, doc: Doc) {
+        self.stack.last_mut().unwrap().docs.push(doc);
+    }
+
+    pub fn word<S: Into<Cow<'static, str>>>(&mut self, word: S) {
+        let word = word.into();
+        if !word.is_empty() {
+            self.push(Doc::Text(word));
+        }
+    }
+
+    pub fn nbsp(&mut self) {
+        self.word(" ");
+    }
+
+    fn brk(&mut self, blank: usize, hard: bool) {
+        if hard {
+            self.force_break();
+        }
+        self.push(Doc::Break(Break {
+            blank,
+            offset: 0,
+            hard,
+        }));
+    }
+
+    // A space if the group fits on one line, otherwise a newline.
+    pub fn space(&mut self) {
+        self.brk(1, false);
+    }
+
+    // Nothing if the group fits on one line, otherwise a newline.
+    pub fn zerobreak(&mut self) {
+        self.brk(0, false);
+    }
+
+    pub fn hardbreak(&mut self) {
+        self.brk(0, true);
+    }
+
+    // Break the current group as if it contained a hard break.
+    pub fn force_break(&mut self) {
+        self.stack.last_mut().unwrap().hard = true;
+    }
+
+    pub fn trailing_comma(&mut self, is_last: bool) {
+        if is_last {
+            self.push(Doc::IfBreak(","));
+        } else {
+            self.word(",");
+            self.space();
+        }
+    }
+
+    // Adjust the indentation of the line following the most recent break,
+    // typically by -INDENT for the line holding a closing delimiter.
+    pub fn offset(&mut self, offset: isize) {
+        if let Some(Doc::Break(brk)) = self.stack.last_mut().unwrap().docs.last_mut() {
+            brk.offset = offset;
+        }
+    }
+
+    pub fn cbox(&mut self, indent: isize) {
+        self.stack.push(Group {
+            indent,
+            docs: Vec::new(),
+            hard: false,
+        });
+    }
+
+    pub fn end(&mut self) {
+        let group = self.stack.pop().unwrap();
+        self.push(Doc::Group(group));
+    }
+
+    pub fn eof(mut self) -> String {
+        assert_eq!(self.stack.len(), 1, "unbalanced pretty printer groups");
+        let root = self.stack.pop().unwrap();
+        let mut out = Output {
+            string: String::new(),
+            column: 0,
+            pending: 0,
+        };
+        let mut stack = Vec::new();
+        push_docs(&mut stack, &root.docs, 0, true);
+        while let Some(cmd) = stack.pop() {
+            match cmd.doc {
+                Doc::Text(text) => out.text(text),
+                Doc::Break(brk) => {
+                    if cmd.broken || brk.hard {
+                        out.newline(cmd.indent + brk.offset);
+                    } else {
+                        out.pending += brk.blank;
+                    }
+                }
+                Doc::IfBreak(text) => {
+                    if cmd.broken {
+                        out.text(text);
+                    }
+                }
+                Doc::Group(group) => {
+                    let broken = group.hard || !fits(MARGIN - out.width(), group, &stack);
+                    let indent = if broken {
+                        cmd.indent + group.indent
+                    } else {
+                        cmd.indent
+                    };
+                    push_docs(&mut stack, &group.docs, indent, broken);
+                }
+            }
+        }
+        if !out.string.ends_with('\n') {
+            out.string.push('\n');
+        }
+        out.string
+    }
+}
+
+struct Cmd<'a> {
+    // Indentation of lines started by breaks directly inside of the group.
+    indent: isize,
+    // Whether the group directly containing the doc is broken.
+    broken: bool,
+    doc: &'a Doc,
+}
+
+fn push_docs<'a>(stack: &mut Vec<Cmd<'a>>, docs: &'a [Doc], indent: isize, broken: bool) {
+    for doc in docs.iter().rev() {
+        stack.push(Cmd {
+            indent,
+            broken,
+            doc,
+        });
+    }
+}
+
+// Whether `group` printed flat, followed by everything up to the next newline
+// in `rest`, fits within `width` columns.
+fn fits(mut width: isize, group: &Group, rest: &[Cmd]) -> bool {
+    let mut stack: Vec<(bool, &Doc)> = group.docs.iter().rev().map(|doc| (false, doc)).collect();
+    let mut rest = rest.iter().rev();
+    loop {
+        if width < 0 {
+            return false;
+        }
+        let (broken, doc) = match stack.pop() {
+            Some(entry) => entry,
+            None => match rest.next() {
+                Some(cmd) => (cmd.broken, cmd.doc),
+                None => return true,
+            },
+        };
+        match doc {
+            Doc::Text(text) => width -= text.chars().count() as isize,
+            Doc::Break(brk) => {
+                if broken || brk.hard {
+                    return true;
+                }
+                width -= brk.blank as isize;
+            }
+            Doc::IfBreak(text) => {
+                if broken {
+                    width -= text.len() as isize;
+                }
+            }
+            Doc::Group(group) => {
+                let broken = group.hard || broken;
+                stack.extend(group.docs.iter().rev().map(|doc| (broken, doc)));
+            }
+        }
+    }
+}
+
+struct Output {
+    string: String,
+    column: isize,
+    // Spaces not yet written, which are dropped if a newline comes first.
+    pending: usize,
+}
+
+impl Output {
+    fn width(&self) -> isize {
+        self.column + self.pending as isize
+    }
+
+    fn text(&mut self, text: &str) {
+        for _ in 0..self.pending {
+            self.string.push(' ');
+        }
+        self.column += self.pending as isize;
+        self.pending = 0;
+        self.string.push_str(text);
+        match text.rfind('\n') {
+            Some(i) => self.column = text[i + 1..].chars().count() as isize,
+            None => self.column += text.chars().count() as isize,
+        }
+    }
+
+    fn newline(&mut self, indent: isize) {
+        self.string.push('\n');
+        self.column = 0;
+        self.pending = if indent > 0 { indent as usize } else { 0 };
+    }
+}
@@ -0,0 +1,111 @@
+use super::algorithm::Printer;
+use crate::attr::FilterAttrs;
+use crate::{AttrStyle, Attribute, Lit, PathArguments};
+use proc_macro2::{TokenStream, TokenTree};
+
+impl Printer {
+    // Attributes of an item or statement, each on its own line.
+    pub fn outer_attrs(&mut self, attrs: &[Attribute]) {
+        for attr in attrs.outer() {
+            self.attr(attr);
+            self.hardbreak();
+        }
+    }
+
+    pub fn inner_attrs(&mut self, attrs: &[Attribute]) {
+        for attr in attrs.inner() {
+            self.attr(attr);
+            self.hardbreak();
+        }
+    }
+
+    // Attributes of an expression or a part of a list, on the same line as
+    // the thing they are applied to.
+    pub fn outer_attrs_inline(&mut self, attrs: &[Attribute]) {
+        for attr in attrs.outer() {
+            self.attr_tokens(attr);
+            self.nbsp();
+        }
+    }
+
+    pub fn inner_attrs_inline(&mut self, attrs: &[Attribute]) {
+        for attr in attrs.inner() {
+            self.attr_tokens(attr);
+            self.nbsp();
+        }
+    }
+
+    fn attr(&mut self, attr: &Attribute) {
+        let doc = match doc_comment(attr) {
+            Some(doc) => doc,
+            None => return self.attr_tokens(attr),
+        };
+        let prefix = match attr.style {
+            AttrStyle::Outer => "///",
+            AttrStyle::Inner(_) => "//!",
+        };
+        self.doc_line(prefix, &doc);
+    }
+
+    pub fn doc_line(&mut self, prefix: &'static str, doc: &str) {
+        self.word(format!("{}{}", prefix, doc));
+    }
+
+    fn attr_tokens(&mut self, attr: &Attribute) {
+        self.word(match attr.style {
+            AttrStyle::Outer => "#[",
+            AttrStyle::Inner(_) => "#![",
+        });
+        self.path(&attr.path);
+        self.attr_args(&attr.tokens);
+        self.word("]");
+    }
+}
+
+pub fn has_inner_attrs(attrs: &[Attribute]) -> bool {
+    attrs.inner().next().is_some()
+}
+
+fn doc_comment(attr: &Attribute) -> Option<String> {
+    if attr.path.leading_colon.is_some() || attr.path.segments.len() != 1 {
+        return None;
+    }
+    let segment = &attr.path.segments[0];
+    if segment.ident != "doc" {
+        return None;
+    }
+    if let PathArguments::None = segment.arguments {
+    } else {
+        return None;
+    }
+
+    doc_value(attr.tokens.clone())
+}
+
+// The string in `= "..."` following `doc` in a doc attribute, if it can be
+// written as a line comment with the same meaning.
+pub fn doc_value(tokens: TokenStream) -> Option<String> {
+    let mut tokens = tokens.into_iter();
+    match tokens.next() {
+        Some(TokenTree::Punct(ref punct)) if punct.as_char() == '=' => {}
+        _ => return None,
+    }
+    let doc = match tokens.next() {
+        Some(TokenTree::Literal(literal)) => match Lit::new(literal) {
+            Lit::Str(lit) => lit.value(),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    if tokens.next().is_some() {
+        return None;
+    }
+
+    // A fourth slash would turn the line into an ordinary comment, carriage
+    // returns are not allowed in doc comments, and a line break would split
+    // the attribute into one per line.
+    if doc.starts_with('/') || doc.contains('\r') || doc.contains('\n') {
+        return None;
+    }
+    Some(doc)
+}
@@ -0,0 +1,720 @@
+use super::algorithm::{Printer, INDENT};
+use super::attr::has_inner_attrs;
+use super::path::PathKind;
+use crate::precedence::{self, Operand, Precedence};
+use crate::punctuated::Punctuated;
+use crate::{
+    Arm, Attribute, BinOp, Block, Expr, ExprArray, ExprAssign, ExprAssignOp, ExprAsync, ExprAwait,
+    ExprBinary, ExprBlock, ExprBox, ExprBreak, ExprCall, ExprCast, ExprClosure, ExprConst,
+    ExprContinue, ExprField, ExprForLoop, ExprGroup, ExprIf, ExprIndex, ExprLet, ExprLit, ExprLoop,
+    ExprMacro, ExprMatch, ExprMethodCall, ExprParen, ExprPath, ExprRange, ExprRawAddr,
+    ExprReference, ExprRepeat, ExprReturn, ExprStruct, ExprTry, ExprTryBlock, ExprTuple, ExprType,
+    ExprUnary, ExprUnsafe, ExprWhile, ExprYield, FieldValue, GenericMethodArgument, Label,
+    MethodTurbofish, PointerMutability, RangeLimits, ReturnType, UnOp,
+};
+
+impl Printer {
+    pub fn expr(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Array(expr) => self.expr_array(expr),
+            Expr::Assign(expr) => self.expr_assign(expr),
+            Expr::AssignOp(expr) => self.expr_assign_op(expr),
+            Expr::Async(expr) => self.expr_async(expr),
+            Expr::Await(expr) => self.expr_await(expr),
+            Expr::Binary(expr) => self.expr_binary(expr),
+            Expr::Block(expr) => self.expr_block(expr),
+            Expr::Box(expr) => self.expr_box(expr),
+            Expr::Break(expr) => self.expr_break(expr),
+            Expr::Call(expr) => self.expr_call(expr),
+            Expr::Cast(expr) => self.expr_cast(expr),
+            Expr::Closure(expr) => self.expr_closure(expr),
+            Expr::Const(expr) => self.expr_const(expr),
+            Expr::Continue(expr) => self.expr_continue(expr),
+            Expr::Field(expr) => self.expr_field(expr),
+            Expr::ForLoop(expr) => self.expr_for_loop(expr),
+            Expr::Group(expr) => self.expr_group(expr),
+            Expr::If(expr) => self.expr_if(expr),
+            Expr::Index(expr) => self.expr_index(expr),
+            Expr::Let(expr) => self.expr_let(expr),
+            Expr::Lit(expr) => self.expr_lit(expr),
+            Expr::Loop(expr) => self.expr_loop(expr),
+            Expr::Macro(expr) => self.expr_macro(expr),
+            Expr::Match(expr) => self.expr_match(expr),
+            Expr::MethodCall(expr) => self.expr_method_call(expr),
+            Expr::Paren(expr) => self.expr_paren(expr),
+            Expr::Path(expr) => self.expr_path(expr),
+            Expr::Range(expr) => self.expr_range(expr),
+            Expr::RawAddr(expr) => self.expr_raw_addr(expr),
+            Expr::Reference(expr) => self.expr_reference(expr),
+            Expr::Repeat(expr) => self.expr_repeat(expr),
+            Expr::Return(expr) => self.expr_return(expr),
+            Expr::Struct(expr) => self.expr_struct(expr),
+            Expr::Try(expr) => self.expr_try(expr),
+            Expr::TryBlock(expr) => self.expr_try_block(expr),
+            Expr::Tuple(expr) => self.expr_tuple(expr),
+            Expr::Type(expr) => self.expr_type(expr),
+            Expr::Unary(expr) => self.expr_unary(expr),
+            Expr::Unsafe(expr) => self.expr_unsafe(expr),
+            Expr::Verbatim(expr) => self.verbatim(expr),
+            Expr::While(expr) => self.expr_while(expr),
+            Expr::Yield(expr) => self.expr_yield(expr),
+
+            #[cfg(test)]
+            Expr::__TestExhaustive(_) => unimplemented!(),
+            #[cfg(not(test))]
+            _ => unreachable!(),
+        }
+    }
+
+    // An operand of a larger expression, parenthesized if the operator would
+    // otherwise bind differently.
+    pub fn subexpr(&mut self, expr: &Expr, operand: Operand) {
+        if precedence::needs_parens(expr, operand) {
+            self.word("(");
+            self.expr(expr);
+            self.word(")");
+        } else {
+            self.expr(expr);
+        }
+    }
+
+    fn expr_array(&mut self, expr: &ExprArray) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.word("[");
+        self.inner_attrs_inline(&expr.attrs);
+        self.comma_separated(&expr.elems);
+        self.word("]");
+    }
+
+    fn expr_assign(&mut self, expr: &ExprAssign) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.subexpr(&expr.left, Operand::Left(Precedence::Assign));
+        self.word(" =");
+        self.rhs(&expr.right, Some(Operand::Right(Precedence::Assign)));
+    }
+
+    fn expr_assign_op(&mut self, expr: &ExprAssignOp) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.subexpr(&expr.left, Operand::Left(Precedence::Assign));
+        self.nbsp();
+        self.word(binary_operator(&expr.op));
+        self.rhs(&expr.right, Some(Operand::Right(Precedence::Assign)));
+    }
+
+    fn expr_async(&mut self, expr: &ExprAsync) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.word("async ");
+        if expr.capture.is_some() {
+            self.word("move ");
+        }
+        self.block_with_inner_attrs(&expr.block, &expr.attrs);
+    }
+
+    fn expr_await(&mut self, expr: &ExprAwait) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.subexpr(&expr.base, Operand::Postfix);
+        self.word(".await");
+    }
+
+    fn expr_binary(&mut self, expr: &ExprBinary) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.cbox(INDENT);
+        self.binary_operands(expr);
+        self.end();
+    }
+
+    // A chain of operators of the same precedence, as in `a && b && c`, is
+    // printed as one group so that it breaks before every operator at the
+    // same indentation.
+    fn binary_operands(&mut self, expr: &ExprBinary) {
+        let operand = Operand::left_of(&expr.op);
+        match &*expr.left {
+            Expr::Binary(left)
+                if left.attrs.is_empty()
+                    && Precedence::of(&left.op) == Precedence::of(&expr.op)
+                    && !precedence::needs_parens(&expr.left, operand) =>
+            {
+                self.binary_operands(left);
+            }
+            left => self.subexpr(left, operand),
+        }
+        self.space();
+        self.word(binary_operator(&expr.op));
+        self.nbsp();
+        self.subexpr(&expr.right, Operand::right_of(&expr.op));
+    }
+
+    fn expr_block(&mut self, expr: &ExprBlock) {
+        self.outer_attrs_inline(&expr.attrs);
+        if let Some(label) = &expr.label {
+            self.label(label);
+        }
+        self.block_with_inner_attrs(&expr.block, &expr.attrs);
+    }
+
+    fn expr_box(&mut self, expr: &ExprBox) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.word("box ");
+        self.subexpr(&expr.expr, Operand::Prefix);
+    }
+
+    fn expr_break(&mut self, expr: &ExprBreak) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.word("break");
+        if let Some(lifetime) = &expr.label {
+            self.nbsp();
+            self.lifetime(lifetime);
+        }
+        if let Some(value) = &expr.expr {
+            self.nbsp();
+            self.expr(value);
+        }
+    }
+
+    fn expr_call(&mut self, expr: &ExprCall) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.subexpr(&expr.func, Operand::Callee);
+        self.word("(");
+        self.comma_separated(&expr.args);
+        self.word(")");
+    }
+
+    fn expr_cast(&mut self, expr: &ExprCast) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.subexpr(&expr.expr, Operand::Left(Precedence::Cast));
+        self.word(" as ");
+        self.ty(&expr.ty);
+    }
+
+    fn expr_closure(&mut self, expr: &ExprClosure) {
+        self.outer_attrs_inline(&expr.attrs);
+        if expr.asyncness.is_some() {
+            self.word("async ");
+        }
+        if expr.movability.is_some() {
+            self.word("static ");
+        }
+        if expr.capture.is_some() {
+            self.word("move ");
+        }
+        self.word("|");
+        self.cbox(INDENT);
+        self.zerobreak();
+        for (i, input) in expr.inputs.iter().enumerate() {
+            self.pat(input);
+            self.trailing_comma(i + 1 == expr.inputs.len());
+        }
+        self.zerobreak();
+        self.offset(-INDENT);
+        self.end();
+        self.word("|");
+        if let ReturnType::Type(..) = expr.output {
+            self.return_type(&expr.output);
+        }
+        self.nbsp();
+        self.expr(&expr.body);
+    }
+
+    fn expr_const(&mut self, expr: &ExprConst) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.word("const ");
+        self.block_with_inner_attrs(&expr.block, &expr.attrs);
+    }
+
+    fn expr_continue(&mut self, expr: &ExprContinue) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.word("continue");
+        if let Some(lifetime) = &expr.label {
+            self.nbsp();
+            self.lifetime(lifetime);
+        }
+    }
+
+    fn expr_field(&mut self, expr: &ExprField) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.subexpr(&expr.base, Operand::Postfix);
+        self.word(".");
+        self.member(&expr.member);
+    }
+
+    fn expr_for_loop(&mut self, expr: &ExprForLoop) {
+        self.outer_attrs_inline(&expr.attrs);
+        if let Some(label) = &expr.label {
+            self.label(label);
+        }
+        self.word("for ");
+        self.pat(&expr.pat);
+        self.word(" in ");
+        self.subexpr(&expr.expr, Operand::Condition);
+        self.nbsp();
+        self.block_with_inner_attrs(&expr.body, &expr.attrs);
+    }
+
+    fn expr_group(&mut self, expr: &ExprGroup) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.expr(&expr.expr);
+    }
+
+    fn expr_if(&mut self, expr: &ExprIf) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.word("if ");
+        self.condition(&expr.cond);
+        self.block(&expr.then_branch);
+        if let Some((_else, else_branch)) = &expr.else_branch {
+            self.word(" else ");
+            self.expr(else_branch);
+        }
+    }
+
+    fn expr_index(&mut self, expr: &ExprIndex) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.subexpr(&expr.expr, Operand::Postfix);
+        self.word("[");
+        self.expr(&expr.index);
+        self.word("]");
+    }
+
+    fn expr_let(&mut self, expr: &ExprLet) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.word("let ");
+        self.pat(&expr.pat);
+        self.word(" = ");
+        self.subexpr(&expr.expr, Operand::Condition);
+    }
+
+    fn expr_lit(&mut self, expr: &ExprLit) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.lit(&expr.lit);
+    }
+
+    fn expr_loop(&mut self, expr: &ExprLoop) {
+        self.outer_attrs_inline(&expr.attrs);
+        if let Some(label) = &expr.label {
+            self.label(label);
+        }
+        self.word("loop ");
+        self.block_with_inner_attrs(&expr.body, &expr.attrs);
+    }
+
+    fn expr_macro(&mut self, expr: &ExprMacro) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.mac(&expr.mac, None);
+    }
+
+    fn expr_match(&mut self, expr: &ExprMatch) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.word("match ");
+        self.subexpr(&expr.expr, Operand::Condition);
+        self.word(" {");
+        if expr.arms.is_empty() && !has_inner_attrs(&expr.attrs) {
+            self.word("}");
+            return;
+        }
+        self.cbox(INDENT);
+        self.hardbreak();
+        self.inner_attrs(&expr.attrs);
+        for (i, arm) in expr.arms.iter().enumerate() {
+            self.arm(arm, i + 1 == expr.arms.len());
+            self.hardbreak();
+        }
+        self.offset(-INDENT);
+        self.end();
+        self.word("}");
+    }
+
+    fn arm(&mut self, arm: &Arm, is_last: bool) {
+        self.outer_attrs(&arm.attrs);
+        self.pat(&arm.pat);
+        if let Some((_if, guard)) = &arm.guard {
+            self.word(" if ");
+            self.expr(guard);
+        }
+        self.word(" => ");
+        self.expr(&arm.body);
+        if arm.comma.is_some() || !is_last && requires_terminator(&arm.body) {
+            self.word(",");
+        }
+    }
+
+    fn expr_method_call(&mut self, expr: &ExprMethodCall) {
+        self.outer_attrs_inline(&expr.attrs);
+
+        // In a chain of more than one method call, each call goes on its own
+        // line if the chain does not fit on one line.
+        let mut chain = vec![expr];
+        let mut receiver = &*expr.receiver;
+        while let Expr::MethodCall(call) = receiver {
+            if !call.attrs.is_empty() {
+                break;
+            }
+            chain.push(call);
+            receiver = &call.receiver;
+        }
+
+        self.subexpr(receiver, Operand::Postfix);
+        let multiline = chain.len() > 1;
+        if multiline {
+            self.cbox(INDENT);
+        }
+        for call in chain.into_iter().rev() {
+            if multiline {
+                self.zerobreak();
+            }
+            self.word(".");
+            self.ident(&call.method);
+            if let Some(turbofish) = &call.turbofish {
+                self.method_turbofish(turbofish);
+            }
+            self.word("(");
+            self.comma_separated(&call.args);
+            self.word(")");
+        }
+        if multiline {
+            self.end();
+        }
+    }
+
+    fn method_turbofish(&mut self, turbofish: &MethodTurbofish) {
+        self.word("::<");
+        for (i, arg) in turbofish.args.iter().enumerate() {
+            if i > 0 {
+                self.word(", ");
+            }
+            match arg {
+                GenericMethodArgument::Type(ty) => self.ty(ty),
+                GenericMethodArgument::Const(expr) => self.const_argument(expr),
+            }
+        }
+        self.word(">");
+    }
+
+    fn expr_paren(&mut self, expr: &ExprParen) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.word("(");
+        self.inner_attrs_inline(&expr.attrs);
+        self.expr(&expr.expr);
+        self.word(")");
+    }
+
+    fn expr_path(&mut self, expr: &ExprPath) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.qpath(&expr.qself, &expr.path, PathKind::Expr);
+    }
+
+    fn expr_range(&mut self, expr: &ExprRange) {
+        self.outer_attrs_inline(&expr.attrs);
+        if let Some(from) = &expr.from {
+            self.subexpr(from, Operand::Left(Precedence::Range));
+        }
+        self.word(match expr.limits {
+            RangeLimits::HalfOpen(_) => "..",
+            RangeLimits::Closed(_) => "..=",
+        });
+        if let Some(to) = &expr.to {
+            self.subexpr(to, Operand::Right(Precedence::Range));
+        }
+    }
+
+    fn expr_raw_addr(&mut self, expr: &ExprRawAddr) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.word(match expr.mutability {
+            PointerMutability::Const(_) => "&raw const ",
+            PointerMutability::Mut(_) => "&raw mut ",
+        });
+        self.subexpr(&expr.expr, Operand::Prefix);
+    }
+
+    fn expr_reference(&mut self, expr: &ExprReference) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.word("&");
+        if expr.mutability.is_some() {
+            self.word("mut ");
+        }
+        self.subexpr(&expr.expr, Operand::Prefix);
+    }
+
+    fn expr_repeat(&mut self, expr: &ExprRepeat) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.word("[");
+        self.inner_attrs_inline(&expr.attrs);
+        self.expr(&expr.expr);
+        self.word("; ");
+        self.expr(&expr.len);
+        self.word("]");
+    }
+
+    fn expr_return(&mut self, expr: &ExprReturn) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.word("return");
+        if let Some(value) = &expr.expr {
+            self.nbsp();
+            self.expr(value);
+        }
+    }
+
+    fn expr_struct(&mut self, expr: &ExprStruct) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.path_kind(&expr.path, PathKind::Expr);
+        self.word(" {");
+        if expr.fields.is_empty() && expr.dot2_token.is_none() {
+            self.inner_attrs_inline(&expr.attrs);
+            self.word("}");
+            return;
+        }
+        self.cbox(INDENT);
+        self.space();
+        self.inner_attrs_inline(&expr.attrs);
+        for (i, field) in expr.fields.iter().enumerate() {
+            self.field_value(field);
+            self.trailing_comma(i + 1 == expr.fields.len() && expr.dot2_token.is_none());
+        }
+        if expr.dot2_token.is_some() {
+            self.word("..");
+            if let Some(rest) = &expr.rest {
+                self.expr(rest);
+            }
+        }
+        self.space();
+        self.offset(-INDENT);
+        self.end();
+        self.word("}");
+    }
+
+    fn field_value(&mut self, field: &FieldValue) {
+        self.outer_attrs_inline(&field.attrs);
+        if field.colon_token.is_none() {
+            // Shorthand, as in `Point { x, y }`.
+            self.expr(&field.expr);
+            return;
+        }
+        self.member(&field.member);
+        self.word(": ");
+        self.expr(&field.expr);
+    }
+
+    fn expr_try(&mut self, expr: &ExprTry) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.subexpr(&expr.expr, Operand::Postfix);
+        self.word("?");
+    }
+
+    fn expr_try_block(&mut self, expr: &ExprTryBlock) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.word("try ");
+        self.block_with_inner_attrs(&expr.block, &expr.attrs);
+    }
+
+    fn expr_tuple(&mut self, expr: &ExprTuple) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.word("(");
+        self.inner_attrs_inline(&expr.attrs);
+        if expr.elems.len() == 1 {
+            self.expr(&expr.elems[0]);
+            self.word(",");
+        } else {
+            self.comma_separated(&expr.elems);
+        }
+        self.word(")");
+    }
+
+    fn expr_type(&mut self, expr: &ExprType) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.subexpr(&expr.expr, Operand::Left(Precedence::Cast));
+        self.word(": ");
+        self.ty(&expr.ty);
+    }
+
+    fn expr_unary(&mut self, expr: &ExprUnary) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.word(match expr.op {
+            UnOp::Deref(_) => "*",
+            UnOp::Not(_) => "!",
+            UnOp::Neg(_) => "-",
+        });
+        self.subexpr(&expr.expr, Operand::Prefix);
+    }
+
+    fn expr_unsafe(&mut self, expr: &ExprUnsafe) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.word("unsafe ");
+        self.block_with_inner_attrs(&expr.block, &expr.attrs);
+    }
+
+    fn expr_while(&mut self, expr: &ExprWhile) {
+        self.outer_attrs_inline(&expr.attrs);
+        if let Some(label) = &expr.label {
+            self.label(label);
+        }
+        self.word("while ");
+        self.condition(&expr.cond);
+        self.block_with_inner_attrs(&expr.body, &expr.attrs);
+    }
+
+    fn expr_yield(&mut self, expr: &ExprYield) {
+        self.outer_attrs_inline(&expr.attrs);
+        self.word("yield");
+        if let Some(value) = &expr.expr {
+            self.nbsp();
+            self.expr(value);
+        }
+    }
+
+    // The condition of an `if` or `while`. A chain of operators that does
+    // not fit on one line is broken before every operator, with the opening
+    // brace of the body on a line of its own.
+    fn condition(&mut self, cond: &Expr) {
+        match cond {
+            Expr::Binary(binary)
+                if binary.attrs.is_empty()
+                    && !precedence::needs_parens(cond, Operand::Condition) =>
+            {
+                self.cbox(INDENT);
+                self.binary_operands(binary);
+                self.space();
+                self.offset(-INDENT);
+                self.end();
+            }
+            _ => {
+                self.subexpr(cond, Operand::Condition);
+                self.nbsp();
+            }
+        }
+    }
+
+    fn label(&mut self, label: &Label) {
+        self.lifetime(&label.name);
+        self.word(": ");
+    }
+
+    // Arguments of a call or elements of an array, broken one per line if
+    // they do not fit on one line.
+    fn comma_separated<P>(&mut self, exprs: &Punctuated<Expr, P>) {
+        if exprs.is_empty() {
+            return;
+        }
+        if exprs.len() == 1 && overflows(&exprs[0]) {
+            // A lone struct literal, block or closure argument keeps its
+            // opening delimiter on the line of the call, as in `f(S {`.
+            self.expr(&exprs[0]);
+            return;
+        }
+        self.cbox(INDENT);
+        self.zerobreak();
+        for (i, expr) in exprs.iter().enumerate() {
+            self.expr(expr);
+            self.trailing_comma(i + 1 == exprs.len());
+        }
+        self.zerobreak();
+        self.offset(-INDENT);
+        self.end();
+    }
+
+    // The right-hand side of an assignment or `let` following the `=`. It
+    // moves to the next line if it does not fit, unless it is an expression
+    // that spans several lines anyway, which starts on the same line.
+    pub fn rhs(&mut self, rhs: &Expr, operand: Option<Operand>) {
+        let overflow = match rhs {
+            // Continuation lines of the operator chain are indented instead.
+            Expr::Binary(_) => true,
+            _ => overflows(rhs),
+        };
+        if overflow {
+            self.nbsp();
+        } else {
+            self.cbox(INDENT);
+            self.space();
+        }
+        match operand {
+            Some(operand) => self.subexpr(rhs, operand),
+            None => self.expr(rhs),
+        }
+        if !overflow {
+            self.end();
+        }
+    }
+
+    pub fn block(&mut self, block: &Block) {
+        self.word("{");
+        self.block_body(&block.stmts, &[]);
+    }
+
+    pub fn block_with_inner_attrs(&mut self, block: &Block, attrs: &[Attribute]) {
+        self.word("{");
+        self.block_body(&block.stmts, attrs);
+    }
+}
+
+// Whether the expression ends with a delimited body that can be broken across
+// lines while its first line stays beside whatever precedes it.
+fn overflows(expr: &Expr) -> bool {
+    match expr {
+        Expr::Struct(e) => !e.fields.is_empty() || e.rest.is_some(),
+        Expr::Unsafe(_)
+        | Expr::Block(_)
+        | Expr::If(_)
+        | Expr::Match(_)
+        | Expr::While(_)
+        | Expr::Loop(_)
+        | Expr::ForLoop(_)
+        | Expr::Async(_)
+        | Expr::TryBlock(_)
+        | Expr::Const(_) => true,
+        Expr::Closure(e) => match &*e.body {
+            Expr::Block(_) => true,
+            _ => false,
+        },
+        Expr::Call(e) => e.args.len() == 1 && overflows(&e.args[0]),
+        _ => false,
+    }
+}
+
+// Whether the expression must be followed by a comma as a match arm or a
+// semicolon as a statement if it is not the last one.
+pub fn requires_terminator(expr: &Expr) -> bool {
+    match expr {
+        Expr::Unsafe(_)
+        | Expr::Block(_)
+        | Expr::If(_)
+        | Expr::Match(_)
+        | Expr::While(_)
+        | Expr::Loop(_)
+        | Expr::ForLoop(_)
+        | Expr::Async(_)
+        | Expr::TryBlock(_)
+        | Expr::Const(_) => false,
+        _ => true,
+    }
+}
+
+fn binary_operator(op: &BinOp) -> &'static str {
+    match op {
+        BinOp::Add(_) => "+",
+        BinOp::Sub(_) => "-",
+        BinOp::Mul(_) => "*",
+        BinOp::Div(_) => "/",
+        BinOp::Rem(_) => "%",
+        BinOp::And(_) => "&&",
+        BinOp::Or(_) => "||",
+        BinOp::BitXor(_) => "^",
+        BinOp::BitAnd(_) => "&",
+        BinOp::BitOr(_) => "|",
+        BinOp::Shl(_) => "<<",
+        BinOp::Shr(_) => ">>",
+        BinOp::Eq(_) => "==",
+        BinOp::Lt(_) => "<",
+        BinOp::Le(_) => "<=",
+        BinOp::Ne(_) => "!=",
+        BinOp::Ge(_) => ">=",
+        BinOp::Gt(_) => ">",
+        BinOp::AddEq(_) => "+=",
+        BinOp::SubEq(_) => "-=",
+        BinOp::MulEq(_) => "*=",
+        BinOp::DivEq(_) => "/=",
+        BinOp::RemEq(_) => "%=",
+        BinOp::BitXorEq(_) => "^=",
+        BinOp::BitAndEq(_) => "&=",
+        BinOp::BitOrEq(_) => "|=",
+        BinOp::ShlEq(_) => "<<=",
+        BinOp::ShrEq(_) => ">>=",
+    }
+}
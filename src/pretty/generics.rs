@@ -0,0 +1,219 @@
+use super::algorithm::{Printer, INDENT};
+use crate::punctuated::Punctuated;
+use crate::{
+    BoundLifetimes, ConstParam, GenericParam, Generics, Lifetime, LifetimeDef, PredicateEq,
+    PredicateLifetime, PredicateType, TraitBound, TraitBoundModifier, TypeParam, TypeParamBound,
+    WhereClause, WherePredicate,
+};
+
+impl Printer {
+    pub fn generics(&mut self, generics: &Generics) {
+        if generics.params.is_empty() {
+            return;
+        }
+
+        self.word("<");
+        self.cbox(INDENT);
+        self.zerobreak();
+        for (i, param) in generics.params.iter().enumerate() {
+            self.generic_param(param);
+            self.trailing_comma(i + 1 == generics.params.len());
+        }
+        self.zerobreak();
+        self.offset(-INDENT);
+        self.end();
+        self.word(">");
+    }
+
+    fn generic_param(&mut self, param: &GenericParam) {
+        match param {
+            GenericParam::Type(type_param) => self.type_param(type_param),
+            GenericParam::Lifetime(lifetime_def) => self.lifetime_def(lifetime_def),
+            GenericParam::Const(const_param) => self.const_param(const_param),
+        }
+    }
+
+    fn type_param(&mut self, type_param: &TypeParam) {
+        self.outer_attrs_inline(&type_param.attrs);
+        self.ident(&type_param.ident);
+        if !type_param.bounds.is_empty() {
+            self.word(": ");
+            self.type_param_bounds(&type_param.bounds);
+        }
+        if let Some(default) = &type_param.default {
+            self.word(" = ");
+            self.ty(default);
+        }
+    }
+
+    fn lifetime_def(&mut self, lifetime_def: &LifetimeDef) {
+        self.outer_attrs_inline(&lifetime_def.attrs);
+        self.lifetime(&lifetime_def.lifetime);
+        if !lifetime_def.bounds.is_empty() {
+            self.word(": ");
+            self.lifetime_bounds(&lifetime_def.bounds);
+        }
+    }
+
+    fn const_param(&mut self, const_param: &ConstParam) {
+        self.outer_attrs_inline(&const_param.attrs);
+        self.word("const ");
+        self.ident(&const_param.ident);
+        self.word(": ");
+        self.ty(&const_param.ty);
+        if let Some(default) = &const_param.default {
+            self.word(" = ");
+            self.const_argument(default);
+        }
+    }
+
+    pub fn bound_lifetimes(&mut self, bound_lifetimes: &BoundLifetimes) {
+        self.word("for<");
+        for (i, lifetime_def) in bound_lifetimes.lifetimes.iter().enumerate() {
+            if i > 0 {
+                self.word(", ");
+            }
+            self.lifetime_def(lifetime_def);
+        }
+        self.word("> ");
+    }
+
+    pub fn type_param_bounds<P>(&mut self, bounds: &Punctuated<TypeParamBound, P>) {
+        self.cbox(INDENT);
+        for (i, bound) in bounds.iter().enumerate() {
+            if i > 0 {
+                self.space();
+                self.word("+ ");
+            }
+            self.type_param_bound(bound);
+        }
+        self.end();
+    }
+
+    fn type_param_bound(&mut self, bound: &TypeParamBound) {
+        match bound {
+            TypeParamBound::Trait(trait_bound) => self.trait_bound(trait_bound),
+            TypeParamBound::Lifetime(lifetime) => self.lifetime(lifetime),
+        }
+    }
+
+    fn trait_bound(&mut self, trait_bound: &TraitBound) {
+        if trait_bound.paren_token.is_some() {
+            self.word("(");
+        }
+        if let TraitBoundModifier::Maybe(_) = trait_bound.modifier {
+            self.word("?");
+        }
+        if let Some(bound_lifetimes) = &trait_bound.lifetimes {
+            self.bound_lifetimes(bound_lifetimes);
+        }
+        self.path(&trait_bound.path);
+        if trait_bound.paren_token.is_some() {
+            self.word(")");
+        }
+    }
+
+    fn lifetime_bounds<P>(&mut self, bounds: &Punctuated<Lifetime, P>) {
+        for (i, lifetime) in bounds.iter().enumerate() {
+            if i > 0 {
+                self.word(" + ");
+            }
+            self.lifetime(lifetime);
+        }
+    }
+
+    // A where-clause followed by the opening brace of a body, in which each
+    // predicate goes on its own line.
+    pub fn where_clause_for_body(&mut self, where_clause: &Option<WhereClause>) {
+        let where_clause = match where_clause {
+            Some(where_clause) if !where_clause.predicates.is_empty() => where_clause,
+            _ => return self.word(" {"),
+        };
+        self.hardbreak();
+        self.word("where");
+        self.cbox(INDENT);
+        for predicate in &where_clause.predicates {
+            self.hardbreak();
+            self.where_predicate(predicate);
+            self.word(",");
+        }
+        self.end();
+        self.hardbreak();
+        self.word("{");
+    }
+
+    // A where-clause followed by the semicolon that ends an item.
+    pub fn where_clause_semi(&mut self, where_clause: &Option<WhereClause>) {
+        let where_clause = match where_clause {
+            Some(where_clause) if !where_clause.predicates.is_empty() => where_clause,
+            _ => return self.word(";"),
+        };
+        self.hardbreak();
+        self.word("where");
+        self.cbox(INDENT);
+        for (i, predicate) in where_clause.predicates.iter().enumerate() {
+            if i > 0 {
+                self.word(",");
+            }
+            self.hardbreak();
+            self.where_predicate(predicate);
+        }
+        self.end();
+        self.word(";");
+    }
+
+    // A where-clause in the middle of an item, such as before the `=` of a
+    // type alias.
+    pub fn where_clause_oneline(&mut self, where_clause: &Option<WhereClause>) {
+        let where_clause = match where_clause {
+            Some(where_clause) if !where_clause.predicates.is_empty() => where_clause,
+            _ => return,
+        };
+        self.cbox(INDENT);
+        self.space();
+        self.word("where ");
+        for (i, predicate) in where_clause.predicates.iter().enumerate() {
+            if i > 0 {
+                self.word(",");
+                self.space();
+            }
+            self.where_predicate(predicate);
+        }
+        self.end();
+    }
+
+    fn where_predicate(&mut self, predicate: &WherePredicate) {
+        match predicate {
+            WherePredicate::Type(predicate) => self.predicate_type(predicate),
+            WherePredicate::Lifetime(predicate) => self.predicate_lifetime(predicate),
+            WherePredicate::Eq(predicate) => self.predicate_eq(predicate),
+        }
+    }
+
+    fn predicate_type(&mut self, predicate: &PredicateType) {
+        if let Some(bound_lifetimes) = &predicate.lifetimes {
+            self.bound_lifetimes(bound_lifetimes);
+        }
+        self.ty(&predicate.bounded_ty);
+        self.word(":");
+        if !predicate.bounds.is_empty() {
+            self.nbsp();
+            self.type_param_bounds(&predicate.bounds);
+        }
+    }
+
+    fn predicate_lifetime(&mut self, predicate: &PredicateLifetime) {
+        self.lifetime(&predicate.lifetime);
+        self.word(":");
+        if !predicate.bounds.is_empty() {
+            self.nbsp();
+            self.lifetime_bounds(&predicate.bounds);
+        }
+    }
+
+    fn predicate_eq(&mut self, predicate: &PredicateEq) {
+        self.ty(&predicate.lhs_ty);
+        self.word(" = ");
+        self.ty(&predicate.rhs_ty);
+    }
+}
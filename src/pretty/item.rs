@@ -0,0 +1,754 @@
+use super::algorithm::{Printer, INDENT};
+use super::attr::has_inner_attrs;
+use crate::{
+    Field, Fields, FieldsNamed, FieldsUnnamed, File, FnArg, ForeignItem, ForeignItemFn,
+    ForeignItemMacro, ForeignItemStatic, ForeignItemType, ImplItem, ImplItemConst, ImplItemMacro,
+    ImplItemMethod, ImplItemType, Item, ItemConst, ItemEnum, ItemExternCrate, ItemFn, ItemFnDecl,
+    ItemForeignMod, ItemImpl, ItemMacro, ItemMacro2, ItemMod, ItemStatic, ItemStruct, ItemTrait,
+    ItemTraitAlias, ItemType, ItemUnion, ItemUse, Receiver, Signature, Stmt, TraitItem,
+    TraitItemConst, TraitItemMacro, TraitItemMethod, TraitItemType, UseGroup, UseTree, Variant,
+    Visibility,
+};
+
+impl Printer {
+    pub fn file(&mut self, file: &File) {
+        if let Some(shebang) = &file.shebang {
+            self.word(shebang.clone());
+            self.hardbreak();
+        }
+        self.inner_attrs(&file.attrs);
+        if has_inner_attrs(&file.attrs) && !file.items.is_empty() {
+            self.hardbreak();
+        }
+        self.items(&file.items);
+    }
+
+    // Items separated by blank lines, except between consecutive `use` items
+    // or `extern crate` items.
+    fn items(&mut self, items: &[Item]) {
+        let mut prev: Option<&Item> = None;
+        for item in items {
+            if let Some(prev) = prev {
+                self.hardbreak();
+                let grouped = match (prev, item) {
+                    (Item::Use(_), Item::Use(_)) => true,
+                    (Item::ExternCrate(_), Item::ExternCrate(_)) => true,
+                    _ => false,
+                };
+                if !grouped {
+                    self.hardbreak();
+                }
+            }
+            self.item(item);
+            prev = Some(item);
+        }
+    }
+
+    pub fn item(&mut self, item: &Item) {
+        match item {
+            Item::Const(item) => self.item_const(item),
+            Item::Enum(item) => self.item_enum(item),
+            Item::ExternCrate(item) => self.item_extern_crate(item),
+            Item::Fn(item) => self.item_fn(item),
+            Item::FnDecl(item) => self.item_fn_decl(item),
+            Item::ForeignMod(item) => self.item_foreign_mod(item),
+            Item::Impl(item) => self.item_impl(item),
+            Item::Macro(item) => self.item_macro(item),
+            Item::Macro2(item) => self.item_macro2(item),
+            Item::Mod(item) => self.item_mod(item),
+            Item::Static(item) => self.item_static(item),
+            Item::Struct(item) => self.item_struct(item),
+            Item::Trait(item) => self.item_trait(item),
+            Item::TraitAlias(item) => self.item_trait_alias(item),
+            Item::Type(item) => self.item_type(item),
+            Item::Union(item) => self.item_union(item),
+            Item::Use(item) => self.item_use(item),
+            Item::Verbatim(item) => self.verbatim(item),
+
+            #[cfg(test)]
+            Item::__TestExhaustive(_) => unimplemented!(),
+            #[cfg(not(test))]
+            _ => unreachable!(),
+        }
+    }
+
+    fn item_const(&mut self, item: &ItemConst) {
+        self.outer_attrs(&item.attrs);
+        self.cbox(INDENT);
+        self.visibility(&item.vis);
+        self.word("const ");
+        self.ident(&item.ident);
+        self.word(": ");
+        self.ty(&item.ty);
+        if let Some((_eq, expr)) = &item.expr {
+            self.word(" =");
+            self.space();
+            self.expr(expr);
+        }
+        self.word(";");
+        self.end();
+    }
+
+    fn item_enum(&mut self, item: &ItemEnum) {
+        self.outer_attrs(&item.attrs);
+        self.visibility(&item.vis);
+        self.word("enum ");
+        self.ident(&item.ident);
+        self.generics(&item.generics);
+        self.where_clause_for_body(&item.generics.where_clause);
+        if !item.variants.is_empty() {
+            self.cbox(INDENT);
+            for variant in &item.variants {
+                self.hardbreak();
+                self.variant(variant);
+                self.word(",");
+            }
+            self.hardbreak();
+            self.offset(-INDENT);
+            self.end();
+        }
+        self.word("}");
+    }
+
+    fn variant(&mut self, variant: &Variant) {
+        self.outer_attrs(&variant.attrs);
+        self.ident(&variant.ident);
+        match &variant.fields {
+            Fields::Named(fields) => {
+                self.nbsp();
+                if fields.named.is_empty()
+                    || fields.named.iter().any(|field| !field.attrs.is_empty())
+                {
+                    self.fields_named(fields);
+                } else {
+                    // Short struct variants fit on one line, as in
+                    // `Point { x: i32, y: i32 }`.
+                    self.word("{");
+                    self.cbox(INDENT);
+                    self.space();
+                    for (i, field) in fields.named.iter().enumerate() {
+                        self.field(field);
+                        self.trailing_comma(i + 1 == fields.named.len());
+                    }
+                    self.space();
+                    self.offset(-INDENT);
+                    self.end();
+                    self.word("}");
+                }
+            }
+            Fields::Unnamed(fields) => self.fields_unnamed(fields),
+            Fields::Unit => {}
+        }
+        if let Some((_eq, discriminant)) = &variant.discriminant {
+            self.word(" = ");
+            self.expr(discriminant);
+        }
+    }
+
+    fn item_extern_crate(&mut self, item: &ItemExternCrate) {
+        self.outer_attrs(&item.attrs);
+        self.visibility(&item.vis);
+        self.word("extern crate ");
+        self.ident(&item.ident);
+        if let Some((_as, rename)) = &item.rename {
+            self.word(" as ");
+            self.ident(rename);
+        }
+        self.word(";");
+    }
+
+    fn item_fn(&mut self, item: &ItemFn) {
+        self.outer_attrs(&item.attrs);
+        self.visibility(&item.vis);
+        self.signature(&item.sig);
+        self.where_clause_for_body(&item.sig.generics.where_clause);
+        self.block_body(&item.block.stmts, &item.attrs);
+    }
+
+    fn item_fn_decl(&mut self, item: &ItemFnDecl) {
+        self.outer_attrs(&item.attrs);
+        self.visibility(&item.vis);
+        self.signature(&item.sig);
+        self.where_clause_semi(&item.sig.generics.where_clause);
+    }
+
+    fn item_foreign_mod(&mut self, item: &ItemForeignMod) {
+        self.outer_attrs(&item.attrs);
+        if item.unsafety.is_some() {
+            self.word("unsafe ");
+        }
+        self.abi(&item.abi);
+        self.word("{");
+        if has_inner_attrs(&item.attrs) || !item.items.is_empty() {
+            self.cbox(INDENT);
+            self.hardbreak();
+            self.inner_attrs(&item.attrs);
+            for (i, foreign_item) in item.items.iter().enumerate() {
+                if i > 0 {
+                    self.hardbreak();
+                }
+                self.foreign_item(foreign_item);
+            }
+            self.hardbreak();
+            self.offset(-INDENT);
+            self.end();
+        }
+        self.word("}");
+    }
+
+    fn item_impl(&mut self, item: &ItemImpl) {
+        self.outer_attrs(&item.attrs);
+        if item.defaultness.is_some() {
+            self.word("default ");
+        }
+        if item.unsafety.is_some() {
+            self.word("unsafe ");
+        }
+        self.word("impl");
+        self.generics(&item.generics);
+        self.nbsp();
+        if let Some((negative_polarity, path, _for)) = &item.trait_ {
+            if negative_polarity.is_some() {
+                self.word("!");
+            }
+            self.path(path);
+            self.word(" for ");
+        }
+        self.ty(&item.self_ty);
+        self.where_clause_for_body(&item.generics.where_clause);
+        if has_inner_attrs(&item.attrs) || !item.items.is_empty() {
+            self.cbox(INDENT);
+            self.hardbreak();
+            self.inner_attrs(&item.attrs);
+            for (i, impl_item) in item.items.iter().enumerate() {
+                if i > 0 {
+                    self.hardbreak();
+                    self.hardbreak();
+                }
+                self.impl_item(impl_item);
+            }
+            self.hardbreak();
+            self.offset(-INDENT);
+            self.end();
+        }
+        self.word("}");
+    }
+
+    fn item_macro(&mut self, item: &ItemMacro) {
+        self.outer_attrs(&item.attrs);
+        self.mac(&item.mac, item.ident.as_ref());
+        self.mac_semi(&item.mac, item.semi_token.is_some());
+    }
+
+    fn item_macro2(&mut self, item: &ItemMacro2) {
+        self.outer_attrs(&item.attrs);
+        self.visibility(&item.vis);
+        self.word("macro ");
+        self.ident(&item.ident);
+        self.verbatim(&item.rules);
+    }
+
+    fn item_mod(&mut self, item: &ItemMod) {
+        self.outer_attrs(&item.attrs);
+        self.visibility(&item.vis);
+        if item.unsafety.is_some() {
+            self.word("unsafe ");
+        }
+        self.word("mod ");
+        self.ident(&item.ident);
+        let items = match &item.content {
+            Some((_brace, items)) => items,
+            None => return self.word(";"),
+        };
+        self.word(" {");
+        if has_inner_attrs(&item.attrs) || !items.is_empty() {
+            self.cbox(INDENT);
+            self.hardbreak();
+            self.inner_attrs(&item.attrs);
+            if has_inner_attrs(&item.attrs) && !items.is_empty() {
+                self.hardbreak();
+            }
+            self.items(items);
+            self.hardbreak();
+            self.offset(-INDENT);
+            self.end();
+        }
+        self.word("}");
+    }
+
+    fn item_static(&mut self, item: &ItemStatic) {
+        self.outer_attrs(&item.attrs);
+        self.cbox(INDENT);
+        self.visibility(&item.vis);
+        self.word("static ");
+        if item.mutability.is_some() {
+            self.word("mut ");
+        }
+        self.ident(&item.ident);
+        if let Some((_colon, ty)) = &item.ty {
+            self.word(": ");
+            self.ty(ty);
+        }
+        if let Some((_eq, expr)) = &item.expr {
+            self.word(" =");
+            self.space();
+            self.expr(expr);
+        }
+        self.word(";");
+        self.end();
+    }
+
+    fn item_struct(&mut self, item: &ItemStruct) {
+        self.outer_attrs(&item.attrs);
+        self.visibility(&item.vis);
+        self.word("struct ");
+        self.ident(&item.ident);
+        self.generics(&item.generics);
+        match &item.fields {
+            Fields::Named(fields) => {
+                self.where_clause_for_body(&item.generics.where_clause);
+                self.fields_named_body(fields);
+            }
+            Fields::Unnamed(fields) => {
+                self.fields_unnamed(fields);
+                self.where_clause_semi(&item.generics.where_clause);
+            }
+            Fields::Unit => self.where_clause_semi(&item.generics.where_clause),
+        }
+    }
+
+    fn item_trait(&mut self, item: &ItemTrait) {
+        self.outer_attrs(&item.attrs);
+        self.visibility(&item.vis);
+        if item.unsafety.is_some() {
+            self.word("unsafe ");
+        }
+        if item.auto_token.is_some() {
+            self.word("auto ");
+        }
+        self.word("trait ");
+        self.ident(&item.ident);
+        self.generics(&item.generics);
+        if !item.supertraits.is_empty() {
+            self.word(": ");
+            self.type_param_bounds(&item.supertraits);
+        }
+        self.where_clause_for_body(&item.generics.where_clause);
+        if has_inner_attrs(&item.attrs) || !item.items.is_empty() {
+            self.cbox(INDENT);
+            self.hardbreak();
+            self.inner_attrs(&item.attrs);
+            for (i, trait_item) in item.items.iter().enumerate() {
+                if i > 0 {
+                    self.hardbreak();
+                    self.hardbreak();
+                }
+                self.trait_item(trait_item);
+            }
+            self.hardbreak();
+            self.offset(-INDENT);
+            self.end();
+        }
+        self.word("}");
+    }
+
+    fn item_trait_alias(&mut self, item: &ItemTraitAlias) {
+        self.outer_attrs(&item.attrs);
+        self.visibility(&item.vis);
+        self.word("trait ");
+        self.ident(&item.ident);
+        self.generics(&item.generics);
+        self.word(" = ");
+        self.type_param_bounds(&item.bounds);
+        self.where_clause_semi(&item.generics.where_clause);
+    }
+
+    fn item_type(&mut self, item: &ItemType) {
+        self.outer_attrs(&item.attrs);
+        self.cbox(INDENT);
+        self.visibility(&item.vis);
+        self.word("type ");
+        self.ident(&item.ident);
+        self.generics(&item.generics);
+        self.where_clause_oneline(&item.generics.where_clause);
+        self.word(" =");
+        self.space();
+        self.ty(&item.ty);
+        self.word(";");
+        self.end();
+    }
+
+    fn item_union(&mut self, item: &ItemUnion) {
+        self.outer_attrs(&item.attrs);
+        self.visibility(&item.vis);
+        self.word("union ");
+        self.ident(&item.ident);
+        self.generics(&item.generics);
+        self.where_clause_for_body(&item.generics.where_clause);
+        self.fields_named_body(&item.fields);
+    }
+
+    fn item_use(&mut self, item: &ItemUse) {
+        self.outer_attrs(&item.attrs);
+        self.visibility(&item.vis);
+        self.word("use ");
+        if item.leading_colon.is_some() {
+            self.word("::");
+        }
+        self.use_tree(&item.tree);
+        self.word(";");
+    }
+
+    fn use_tree(&mut self, use_tree: &UseTree) {
+        match use_tree {
+            UseTree::Path(use_path) => {
+                self.ident(&use_path.ident);
+                self.word("::");
+                self.use_tree(&use_path.tree);
+            }
+            UseTree::Name(use_name) => self.ident(&use_name.ident),
+            UseTree::Rename(use_rename) => {
+                self.ident(&use_rename.ident);
+                self.word(" as ");
+                self.ident(&use_rename.rename);
+            }
+            UseTree::Glob(_) => self.word("*"),
+            UseTree::Group(use_group) => self.use_group(use_group),
+        }
+    }
+
+    fn use_group(&mut self, use_group: &UseGroup) {
+        self.word("{");
+        if !use_group.items.is_empty() {
+            self.cbox(INDENT);
+            self.zerobreak();
+            for (i, use_tree) in use_group.items.iter().enumerate() {
+                self.use_tree(use_tree);
+                self.trailing_comma(i + 1 == use_group.items.len());
+            }
+            self.zerobreak();
+            self.offset(-INDENT);
+            self.end();
+        }
+        self.word("}");
+    }
+
+    // The braced fields of a struct or union following the opening brace
+    // printed with the where-clause.
+    fn fields_named_body(&mut self, fields: &FieldsNamed) {
+        if !fields.named.is_empty() {
+            self.cbox(INDENT);
+            for field in &fields.named {
+                self.hardbreak();
+                self.field(field);
+                self.word(",");
+            }
+            self.hardbreak();
+            self.offset(-INDENT);
+            self.end();
+        }
+        self.word("}");
+    }
+
+    fn fields_named(&mut self, fields: &FieldsNamed) {
+        self.word("{");
+        self.fields_named_body(fields);
+    }
+
+    fn fields_unnamed(&mut self, fields: &FieldsUnnamed) {
+        self.word("(");
+        if !fields.unnamed.is_empty() {
+            self.cbox(INDENT);
+            self.zerobreak();
+            for (i, field) in fields.unnamed.iter().enumerate() {
+                self.field(field);
+                self.trailing_comma(i + 1 == fields.unnamed.len());
+            }
+            self.zerobreak();
+            self.offset(-INDENT);
+            self.end();
+        }
+        self.word(")");
+    }
+
+    fn field(&mut self, field: &Field) {
+        if field.ident.is_some() {
+            self.outer_attrs(&field.attrs);
+        } else {
+            self.outer_attrs_inline(&field.attrs);
+        }
+        self.visibility(&field.vis);
+        if let Some(ident) = &field.ident {
+            self.ident(ident);
+            self.word(": ");
+        }
+        self.ty(&field.ty);
+    }
+
+    fn foreign_item(&mut self, foreign_item: &ForeignItem) {
+        match foreign_item {
+            ForeignItem::Fn(item) => self.foreign_item_fn(item),
+            ForeignItem::Static(item) => self.foreign_item_static(item),
+            ForeignItem::Type(item) => self.foreign_item_type(item),
+            ForeignItem::Macro(item) => self.foreign_item_macro(item),
+            ForeignItem::Verbatim(item) => self.verbatim(item),
+
+            #[cfg(test)]
+            ForeignItem::__TestExhaustive(_) => unimplemented!(),
+            #[cfg(not(test))]
+            _ => unreachable!(),
+        }
+    }
+
+    fn foreign_item_fn(&mut self, foreign_item: &ForeignItemFn) {
+        self.outer_attrs(&foreign_item.attrs);
+        self.visibility(&foreign_item.vis);
+        self.signature(&foreign_item.sig);
+        self.where_clause_semi(&foreign_item.sig.generics.where_clause);
+    }
+
+    fn foreign_item_static(&mut self, foreign_item: &ForeignItemStatic) {
+        self.outer_attrs(&foreign_item.attrs);
+        self.visibility(&foreign_item.vis);
+        self.word("static ");
+        if foreign_item.mutability.is_some() {
+            self.word("mut ");
+        }
+        self.ident(&foreign_item.ident);
+        self.word(": ");
+        self.ty(&foreign_item.ty);
+        self.word(";");
+    }
+
+    fn foreign_item_type(&mut self, foreign_item: &ForeignItemType) {
+        self.outer_attrs(&foreign_item.attrs);
+        self.visibility(&foreign_item.vis);
+        self.word("type ");
+        self.ident(&foreign_item.ident);
+        self.word(";");
+    }
+
+    fn foreign_item_macro(&mut self, foreign_item: &ForeignItemMacro) {
+        self.outer_attrs(&foreign_item.attrs);
+        self.mac(&foreign_item.mac, None);
+        self.mac_semi(&foreign_item.mac, foreign_item.semi_token.is_some());
+    }
+
+    fn trait_item(&mut self, trait_item: &TraitItem) {
+        match trait_item {
+            TraitItem::Const(item) => self.trait_item_const(item),
+            TraitItem::Method(item) => self.trait_item_method(item),
+            TraitItem::Type(item) => self.trait_item_type(item),
+            TraitItem::Macro(item) => self.trait_item_macro(item),
+            TraitItem::Verbatim(item) => self.verbatim(item),
+
+            #[cfg(test)]
+            TraitItem::__TestExhaustive(_) => unimplemented!(),
+            #[cfg(not(test))]
+            _ => unreachable!(),
+        }
+    }
+
+    fn trait_item_const(&mut self, trait_item: &TraitItemConst) {
+        self.outer_attrs(&trait_item.attrs);
+        self.cbox(INDENT);
+        self.word("const ");
+        self.ident(&trait_item.ident);
+        self.word(": ");
+        self.ty(&trait_item.ty);
+        if let Some((_eq, default)) = &trait_item.default {
+            self.word(" =");
+            self.space();
+            self.expr(default);
+        }
+        self.word(";");
+        self.end();
+    }
+
+    fn trait_item_method(&mut self, trait_item: &TraitItemMethod) {
+        self.outer_attrs(&trait_item.attrs);
+        self.signature(&trait_item.sig);
+        match &trait_item.default {
+            Some(block) => {
+                self.where_clause_for_body(&trait_item.sig.generics.where_clause);
+                self.block_body(&block.stmts, &trait_item.attrs);
+            }
+            None => self.where_clause_semi(&trait_item.sig.generics.where_clause),
+        }
+    }
+
+    fn trait_item_type(&mut self, trait_item: &TraitItemType) {
+        self.outer_attrs(&trait_item.attrs);
+        self.word("type ");
+        self.ident(&trait_item.ident);
+        self.generics(&trait_item.generics);
+        if !trait_item.bounds.is_empty() {
+            self.word(": ");
+            self.type_param_bounds(&trait_item.bounds);
+        }
+        self.where_clause_oneline(&trait_item.generics.where_clause);
+        if let Some((_eq, default)) = &trait_item.default {
+            self.word(" = ");
+            self.ty(default);
+        }
+        self.word(";");
+    }
+
+    fn trait_item_macro(&mut self, trait_item: &TraitItemMacro) {
+        self.outer_attrs(&trait_item.attrs);
+        self.mac(&trait_item.mac, None);
+        self.mac_semi(&trait_item.mac, trait_item.semi_token.is_some());
+    }
+
+    fn impl_item(&mut self, impl_item: &ImplItem) {
+        match impl_item {
+            ImplItem::Const(item) => self.impl_item_const(item),
+            ImplItem::Method(item) => self.impl_item_method(item),
+            ImplItem::Type(item) => self.impl_item_type(item),
+            ImplItem::Macro(item) => self.impl_item_macro(item),
+            ImplItem::Verbatim(item) => self.verbatim(item),
+
+            #[cfg(test)]
+            ImplItem::__TestExhaustive(_) => unimplemented!(),
+            #[cfg(not(test))]
+            _ => unreachable!(),
+        }
+    }
+
+    fn impl_item_const(&mut self, impl_item: &ImplItemConst) {
+        self.outer_attrs(&impl_item.attrs);
+        self.cbox(INDENT);
+        self.visibility(&impl_item.vis);
+        if impl_item.defaultness.is_some() {
+            self.word("default ");
+        }
+        self.word("const ");
+        self.ident(&impl_item.ident);
+        self.word(": ");
+        self.ty(&impl_item.ty);
+        self.word(" =");
+        self.space();
+        self.expr(&impl_item.expr);
+        self.word(";");
+        self.end();
+    }
+
+    fn impl_item_method(&mut self, impl_item: &ImplItemMethod) {
+        self.outer_attrs(&impl_item.attrs);
+        self.visibility(&impl_item.vis);
+        if impl_item.defaultness.is_some() {
+            self.word("default ");
+        }
+        self.signature(&impl_item.sig);
+        if is_bodiless(impl_item) {
+            self.where_clause_semi(&impl_item.sig.generics.where_clause);
+            return;
+        }
+        self.where_clause_for_body(&impl_item.sig.generics.where_clause);
+        self.block_body(&impl_item.block.stmts, &impl_item.attrs);
+    }
+
+    fn impl_item_type(&mut self, impl_item: &ImplItemType) {
+        self.outer_attrs(&impl_item.attrs);
+        self.cbox(INDENT);
+        self.visibility(&impl_item.vis);
+        if impl_item.defaultness.is_some() {
+            self.word("default ");
+        }
+        self.word("type ");
+        self.ident(&impl_item.ident);
+        self.generics(&impl_item.generics);
+        self.where_clause_oneline(&impl_item.generics.where_clause);
+        self.word(" =");
+        self.space();
+        self.ty(&impl_item.ty);
+        self.word(";");
+        self.end();
+    }
+
+    fn impl_item_macro(&mut self, impl_item: &ImplItemMacro) {
+        self.outer_attrs(&impl_item.attrs);
+        self.mac(&impl_item.mac, None);
+        self.mac_semi(&impl_item.mac, impl_item.semi_token.is_some());
+    }
+
+    fn signature(&mut self, sig: &Signature) {
+        if sig.constness.is_some() {
+            self.word("const ");
+        }
+        if sig.asyncness.is_some() {
+            self.word("async ");
+        }
+        if sig.unsafety.is_some() {
+            self.word("unsafe ");
+        }
+        if let Some(abi) = &sig.abi {
+            self.abi(abi);
+        }
+        self.word("fn ");
+        self.ident(&sig.ident);
+        self.generics(&sig.generics);
+        self.word("(");
+        self.cbox(INDENT);
+        self.zerobreak();
+        for (i, input) in sig.inputs.iter().enumerate() {
+            self.fn_arg(input);
+            self.trailing_comma(i + 1 == sig.inputs.len() && sig.variadic.is_none());
+        }
+        if let Some(variadic) = &sig.variadic {
+            self.variadic(variadic);
+        }
+        self.zerobreak();
+        self.offset(-INDENT);
+        self.end();
+        self.word(")");
+        self.return_type(&sig.output);
+    }
+
+    fn fn_arg(&mut self, fn_arg: &FnArg) {
+        match fn_arg {
+            FnArg::Receiver(receiver) => self.receiver(receiver),
+            FnArg::Typed(pat_type) => self.pat_type(pat_type),
+        }
+    }
+
+    fn receiver(&mut self, receiver: &Receiver) {
+        self.outer_attrs_inline(&receiver.attrs);
+        if let Some((_ampersand, lifetime)) = &receiver.reference {
+            self.word("&");
+            if let Some(lifetime) = lifetime {
+                self.lifetime(lifetime);
+                self.nbsp();
+            }
+        }
+        if receiver.mutability.is_some() {
+            self.word("mut ");
+        }
+        self.word("self");
+    }
+
+    pub fn visibility(&mut self, vis: &Visibility) {
+        match vis {
+            Visibility::Public(_) => self.word("pub "),
+            Visibility::Crate(_) => self.word("crate "),
+            Visibility::Restricted(vis) => {
+                self.word("pub(");
+                if vis.in_token.is_some() {
+                    self.word("in ");
+                }
+                self.path(&vis.path);
+                self.word(") ");
+            }
+            Visibility::Inherited => {}
+        }
+    }
+}
+
+// A method without a body, like `fn f();` in an impl, is parsed as a block
+// containing only a verbatim semicolon.
+fn is_bodiless(impl_item: &ImplItemMethod) -> bool {
+    match impl_item.block.stmts.as_slice() {
+        [Stmt::Item(Item::Verbatim(verbatim))] => verbatim.to_string() == ";",
+        _ => false,
+    }
+}
@@ -0,0 +1,422 @@
+use super::algorithm::{Printer, INDENT};
+use super::attr::doc_value;
+use crate::{Ident, Macro, MacroDelimiter};
+use proc_macro2::{Delimiter, Spacing, TokenStream, TokenTree};
+
+impl Printer {
+    pub fn mac(&mut self, mac: &Macro, ident: Option<&Ident>) {
+        self.path(&mac.path);
+        self.word("!");
+        if let Some(ident) = ident {
+            self.nbsp();
+            self.ident(ident);
+        }
+        let delimiter = match mac.delimiter {
+            MacroDelimiter::Paren(_) => Delimiter::Parenthesis,
+            MacroDelimiter::Brace(_) => {
+                self.nbsp();
+                Delimiter::Brace
+            }
+            MacroDelimiter::Bracket(_) => Delimiter::Bracket,
+        };
+        self.delimited(delimiter, &mac.tokens);
+    }
+
+    pub fn mac_semi(&mut self, mac: &Macro, semi: bool) {
+        if semi {
+            self.word(";");
+        } else if let MacroDelimiter::Brace(_) = mac.delimiter {
+        } else {
+            // Required in item and statement position, where a macro call
+            // delimited by parentheses or brackets is otherwise incomplete.
+            self.word(";");
+        }
+    }
+
+    // The arguments that follow the path of an attribute, as in `(Debug)` in
+    // `#[derive(Debug)]` or `= "..."` in `#[doc = "..."]`.
+    pub fn attr_args(&mut self, tokens: &TokenStream) {
+        self.token_stream(tokens, Tok::Ident(false), Delimiter::None);
+    }
+
+    pub fn verbatim(&mut self, tokens: &TokenStream) {
+        self.token_stream(tokens, Tok::Start, Delimiter::None);
+    }
+
+    fn delimited(&mut self, delimiter: Delimiter, tokens: &TokenStream) {
+        let (open, close) = match delimiter {
+            Delimiter::Parenthesis => ("(", ")"),
+            Delimiter::Brace => ("{", "}"),
+            Delimiter::Bracket => ("[", "]"),
+            Delimiter::None => return self.token_stream(tokens, Tok::Start, delimiter),
+        };
+        self.word(open);
+        if !tokens.is_empty() {
+            let braced = delimiter == Delimiter::Brace;
+            self.cbox(INDENT);
+            if braced {
+                self.space();
+            } else {
+                self.zerobreak();
+            }
+            self.token_stream(tokens, Tok::Start, delimiter);
+            if braced {
+                self.space();
+            } else {
+                self.zerobreak();
+            }
+            self.offset(-INDENT);
+            self.end();
+        }
+        self.word(close);
+    }
+
+    // Prints tokens with spacing approximating how they would be written by
+    // hand. Inside of delimiters, lines may break after commas, and inside of
+    // braces a line break follows every semicolon.
+    fn token_stream(&mut self, tokens: &TokenStream, prev: Tok, delimiter: Delimiter) {
+        let tokens: Vec<TokenTree> = tokens.clone().into_iter().collect();
+        let mut state = State {
+            prev,
+            generics: 0,
+            closure_params: false,
+            declaring: false,
+        };
+        let mut i = 0;
+        while i < tokens.len() {
+            match &tokens[i] {
+                TokenTree::Group(group) => {
+                    let next = Tok::Open(group.delimiter());
+                    if space_between(&state.prev, &next) {
+                        self.nbsp();
+                    }
+                    self.delimited(group.delimiter(), &group.stream());
+                    state.prev = Tok::Close;
+                    let ends_line = match group.delimiter() {
+                        // Attributes inside of a braced macro body, such as on
+                        // a struct in `ast_struct! { ... }`.
+                        Delimiter::Bracket => is_attr_start(&tokens[..i]),
+                        // The body of an item or block followed by another
+                        // item or statement.
+                        Delimiter::Brace => starts_line(tokens.get(i + 1)),
+                        _ => false,
+                    };
+                    if delimiter == Delimiter::Brace && ends_line && i + 1 < tokens.len() {
+                        self.hardbreak();
+                        state.prev = Tok::Start;
+                    }
+                }
+                TokenTree::Ident(ident) => {
+                    let ident = ident.to_string();
+                    let next = Tok::Ident(is_keyword(&ident));
+                    if space_between(&state.prev, &next) {
+                        self.nbsp();
+                    }
+                    let declared = state.declaring;
+                    state.declaring = is_declaration(&ident);
+                    state.prev = if declared {
+                        // The name of a declared item, possibly a macro
+                        // variable as in `fn $name<T>()`.
+                        Tok::Type
+                    } else if let Tok::Punct("$", _) = state.prev {
+                        Tok::Var
+                    } else if ident == "impl" || ident.starts_with(char::is_uppercase) {
+                        Tok::Type
+                    } else if state.declaring {
+                        Tok::Declare
+                    } else {
+                        next
+                    };
+                    self.word(ident);
+                }
+                TokenTree::Literal(literal) => {
+                    let literal = literal.to_string();
+                    let next =
+                        Tok::Literal(literal.bytes().all(|b| b.is_ascii_digit() || b == b'_'));
+                    if space_between(&state.prev, &next) {
+                        self.nbsp();
+                    }
+                    self.word(literal);
+                    state.prev = next;
+                }
+                TokenTree::Punct(punct)
+                    if punct.as_char() == '#' && delimiter == Delimiter::Brace =>
+                {
+                    if let Some((prefix, doc, len)) = doc_attr(&tokens[i..]) {
+                        if let Tok::Start = state.prev {
+                        } else {
+                            // A doc comment extends to the end of the line.
+                            self.hardbreak();
+                        }
+                        self.doc_line(prefix, &doc);
+                        i += len;
+                        if i < tokens.len() {
+                            self.hardbreak();
+                        } else {
+                            // The closing delimiter must go on the next line.
+                            self.force_break();
+                        }
+                        state.prev = Tok::Start;
+                        continue;
+                    }
+                    self.punct_run(&tokens, &mut i, &mut state, delimiter);
+                    continue;
+                }
+                TokenTree::Punct(_) => {
+                    self.punct_run(&tokens, &mut i, &mut state, delimiter);
+                    continue;
+                }
+            }
+            i += 1;
+        }
+    }
+
+    // Prints a run of joint punctuation, split into the longest operators it
+    // contains.
+    fn punct_run(
+        &mut self,
+        tokens: &[TokenTree],
+        i: &mut usize,
+        state: &mut State,
+        delimiter: Delimiter,
+    ) {
+        // A punctuation character printed directly after another one is lexed
+        // as joint with it, so one that was alone must stay separated.
+        let after_alone = match tokens.get(i.wrapping_sub(1)) {
+            Some(TokenTree::Punct(punct)) => punct.spacing() == Spacing::Alone,
+            _ => false,
+        };
+        let mut run = String::new();
+        while let Some(TokenTree::Punct(punct)) = tokens.get(*i) {
+            run.push(punct.as_char());
+            *i += 1;
+            if punct.spacing() == Spacing::Alone {
+                break;
+            }
+        }
+        let mut rest = run.as_str();
+        let mut first = true;
+        while !rest.is_empty() {
+            let op = split_op(rest);
+            rest = &rest[op.len()..];
+            let prev = &state.prev;
+            let mut space = space_between(prev, &Tok::Punct(op, prev.expects_operand()));
+            let next = if let (":", Tok::Var) = (op, prev) {
+                space = false;
+                Tok::Fragment
+            } else if op == "<" && prev.starts_generics() {
+                state.generics += 1;
+                space = space_between(prev, &Tok::OpenGenerics);
+                Tok::OpenGenerics
+            } else if (op == ">" || op == ">>") && state.generics > 0 {
+                state.generics -= op.len().min(state.generics);
+                space = false;
+                Tok::Close
+            } else if op == "|" && state.closure_params {
+                // The end of a closure's parameter list, as in `|x| x + 1`.
+                state.closure_params = false;
+                space = false;
+                Tok::Punct(op, false)
+            } else {
+                let prefix = prev.expects_operand();
+                if op == "|" && prefix {
+                    state.closure_params = true;
+                }
+                Tok::Punct(op, prefix)
+            };
+            let separate = match prev {
+                // A line break already separates them.
+                Tok::Start => false,
+                // A lifetime after a word would be lexed as the closing quote
+                // of a character literal, as in `'a'b`.
+                Tok::Ident(_) | Tok::Declare | Tok::Type | Tok::Var | Tok::Literal(_) => op == "'",
+                _ => after_alone || would_glue(prev, op),
+            };
+            if first && (space || separate) {
+                self.nbsp();
+            }
+            self.word(op);
+            first = false;
+            state.prev = next;
+            if op == ";" && delimiter == Delimiter::Brace {
+                if *i < tokens.len() {
+                    self.hardbreak();
+                } else {
+                    self.force_break();
+                }
+                state.prev = Tok::Start;
+            } else if op == "," && delimiter != Delimiter::None {
+                if *i < tokens.len() {
+                    self.space();
+                }
+                state.prev = Tok::Start;
+            }
+        }
+    }
+}
+
+struct State {
+    prev: Tok,
+    // Number of unclosed `<` that started generic arguments.
+    generics: usize,
+    // Whether the parameter list of a closure is open, as after `|x`.
+    closure_params: bool,
+    // Whether the next identifier is the name of a declared item.
+    declaring: bool,
+}
+
+// The most recently printed token, for deciding whether a space goes before
+// the next one.
+enum Tok {
+    Start,
+    // Whether the identifier is a keyword, after which a delimiter or a
+    // prefix operator is separated by a space.
+    Ident(bool),
+    // A keyword followed by the name of the item it declares, like `fn`.
+    Declare,
+    // An identifier after which `<` starts generic arguments.
+    Type,
+    // A macro variable, as in `$e`.
+    Var,
+    // The colon between a macro variable and its fragment specifier, as in
+    // `$e:expr`.
+    Fragment,
+    // Whether the literal is an integer that a following `.` would turn into
+    // a float.
+    Literal(bool),
+    // Whether the operator is in prefix position, like `&` in `&x`.
+    Punct(&'static str, bool),
+    OpenGenerics,
+    Open(Delimiter),
+    Close,
+}
+
+impl Tok {
+    fn starts_generics(&self) -> bool {
+        match self {
+            Tok::Type => true,
+            Tok::Punct(op, _) => *op == "::",
+            _ => false,
+        }
+    }
+
+    // Whether an operator following this token would be a prefix operator.
+    fn expects_operand(&self) -> bool {
+        match self {
+            Tok::Start | Tok::OpenGenerics | Tok::Open(_) | Tok::Ident(true) | Tok::Declare => true,
+            Tok::Punct(op, _) => *op != "?",
+            Tok::Ident(false) | Tok::Type | Tok::Var | Tok::Literal(_) | Tok::Close => false,
+            Tok::Fragment => true,
+        }
+    }
+}
+
+fn space_between(prev: &Tok, next: &Tok) -> bool {
+    match (prev, next) {
+        (Tok::Start, _) | (Tok::OpenGenerics, _) | (_, Tok::Close) => false,
+        (Tok::Fragment, _) | (_, Tok::Fragment) => false,
+        (_, Tok::Punct(",", _)) | (_, Tok::Punct(";", _)) => false,
+        (Tok::Literal(true), Tok::Punct(".", _)) => true,
+        (Tok::Punct(op, _), _) if is_glue(op) => false,
+        (Tok::Punct(_, true), _) => false,
+        (_, Tok::Punct("#", _)) | (_, Tok::Punct("$", _)) => true,
+        (_, Tok::Punct(op, false)) if is_glue(op) || *op == "?" => false,
+        (Tok::Punct("!", _), Tok::Open(Delimiter::Brace)) => true,
+        (Tok::Punct("!", _), _) => false,
+        (Tok::Punct(_, _), _) => true,
+        (Tok::Ident(true), Tok::Punct(_, _)) | (Tok::Declare, _) => true,
+        (_, Tok::Punct(op, _)) => *op != "!" && *op != ":",
+        (_, Tok::Open(Delimiter::Brace)) => true,
+        (Tok::Ident(keyword), Tok::Open(_)) => *keyword,
+        (_, Tok::Open(_)) | (_, Tok::OpenGenerics) => false,
+        _ => true,
+    }
+}
+
+// Operators that are written without spaces on either side.
+fn is_glue(op: &str) -> bool {
+    match op {
+        "::" | "." | ".." | "..=" | "#" | "$" | "'" => true,
+        _ => false,
+    }
+}
+
+// Whether an operator printed directly after the previous one would be lexed
+// together with it as a different operator, as in `& &x` or `..` `=`.
+fn would_glue(prev: &Tok, op: &str) -> bool {
+    match prev {
+        Tok::Punct(prev, _) => {
+            let joined = format!("{}{}", prev, op);
+            split_op(&joined).len() > prev.len()
+        }
+        _ => false,
+    }
+}
+
+fn split_op(run: &str) -> &'static str {
+    const OPS: &[&str] = &[
+        "...", "..=", "<<=", ">>=", "::", "->", "=>", "==", "!=", "<=", ">=", "&&", "||", "+=",
+        "-=", "*=", "/=", "%=", "^=", "&=", "|=", "<<", ">>", "..", "=", "<", ">", "!", "~", "+",
+        "-", "*", "/", "%", "^", "&", "|", "@", ".", ",", ";", ":", "#", "$", "?", "'",
+    ];
+    // Every character that a `Punct` can hold is an operator by itself.
+    OPS.iter().find(|op| run.starts_with(*op)).unwrap()
+}
+
+fn is_declaration(ident: &str) -> bool {
+    match ident {
+        "enum" | "fn" | "struct" | "trait" | "type" | "union" => true,
+        _ => false,
+    }
+}
+
+// Whether the token after a closing brace belongs on a new line.
+fn starts_line(next: Option<&TokenTree>) -> bool {
+    match next {
+        Some(TokenTree::Ident(ident)) => ident != "else" && ident != "as",
+        Some(TokenTree::Punct(punct)) => punct.as_char() == '#' || punct.as_char() == '$',
+        _ => false,
+    }
+}
+
+fn is_keyword(ident: &str) -> bool {
+    match ident {
+        "as" | "async" | "box" | "break" | "const" | "continue" | "dyn" | "else" | "enum"
+        | "extern" | "fn" | "for" | "if" | "impl" | "in" | "let" | "loop" | "match" | "mod"
+        | "move" | "mut" | "pub" | "ref" | "return" | "static" | "struct" | "trait" | "type"
+        | "union" | "unsafe" | "use" | "where" | "while" | "yield" => true,
+        _ => false,
+    }
+}
+
+// Whether the tokens end with the `#` or `#!` that begins an attribute.
+fn is_attr_start(tokens: &[TokenTree]) -> bool {
+    let is_punct = |i: usize, ch: char| match tokens.get(i) {
+        Some(TokenTree::Punct(punct)) => punct.as_char() == ch,
+        _ => false,
+    };
+    let len = tokens.len();
+    len >= 1 && is_punct(len - 1, '#')
+        || len >= 2 && is_punct(len - 1, '!') && is_punct(len - 2, '#')
+}
+
+// A `#[doc = "..."]` or `#![doc = "..."]` attribute at the start of `tokens`
+// that can be printed as a doc comment, along with the number of tokens it
+// spans.
+fn doc_attr(tokens: &[TokenTree]) -> Option<(&'static str, String, usize)> {
+    let (prefix, len) = match tokens.get(1) {
+        Some(TokenTree::Punct(punct)) if punct.as_char() == '!' => ("//!", 3),
+        _ => ("///", 2),
+    };
+    let group = match tokens.get(len - 1) {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Bracket => group,
+        _ => return None,
+    };
+    let mut stream = group.stream().into_iter();
+    match stream.next() {
+        Some(TokenTree::Ident(ident)) if ident == "doc" => {}
+        _ => return None,
+    }
+    let doc = doc_value(stream.collect())?;
+    Some((prefix, doc, len))
+}
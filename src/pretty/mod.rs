@@ -0,0 +1,87 @@
+//! Pretty printer that renders a syntax tree as formatted Rust source code.
+//!
+//! *This module is available only if Syn is built with both the `"full"` and
+//! `"printing"` features.*
+//!
+//! Printing a syntax tree through `ToTokens` and `to_string()` produces a
+//! single line of space separated tokens, which is fine for handing to the
+//! compiler but not for reading. [`unparse`] instead lays out the tree with
+//! line breaks and indentation in roughly the style of rustfmt, so code
+//! generated from a build script can be written to a `.rs` file that is
+//! pleasant to look at in an editor or in a backtrace, without depending on
+//! rustfmt being installed.
+//!
+//! Doc attributes are printed as `///` and `//!` comments. Expressions are
+//! parenthesized wherever the structure of the syntax tree requires it, as in
+//! [`ToTokens`]. Macro invocations and `Verbatim` nodes are printed token by
+//! token since their contents have no structure known to Syn.
+//!
+//! [`ToTokens`]: quote::ToTokens
+//!
+//! # Example
+//!
+//! ```
+//! use quote::quote;
+//!
+//! # fn main() -> syn::Result<()> {
+//! let tokens = quote! {
+//!     /// Returns the answer.
+//!     pub fn answer() -> u32 { 42 }
+//! };
+//!
+//! let file: syn::File = syn::parse2(tokens)?;
+//! let expected = "\
+//! /// Returns the answer.
+//! pub fn answer() -> u32 {
+//!     42
+//! }
+//! ";
+//! assert_eq!(syn::pretty::unparse(&file), expected);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! In a build script:
+//!
+//! ```no_run
+//! use quote::quote;
+//! use std::env;
+//! use std::fs;
+//! use std::path::Path;
+//!
+//! fn main() {
+//!     let tokens = quote! {
+//!         pub const GENERATED: &str = "hello";
+//!     };
+//!     let file: syn::File = syn::parse2(tokens).unwrap();
+//!
+//!     let out_dir = env::var_os("OUT_DIR").unwrap();
+//!     let path = Path::new(&out_dir).join("generated.rs");
+//!     fs::write(path, syn::pretty::unparse(&file)).unwrap();
+//! }
+//! ```
+
+mod algorithm;
+mod attr;
+mod expr;
+mod generics;
+mod item;
+mod mac;
+mod pat;
+mod path;
+mod stmt;
+mod token;
+mod ty;
+
+use self::algorithm::Printer;
+use crate::File;
+
+/// Renders a file as formatted Rust source code.
+///
+/// The output ends with a newline. Refer to the [module
+/// documentation](self) for an example.
+pub fn unparse(file: &File) -> String {
+    let mut p = Printer::new();
+    p.file(file);
+    p.eof()
+}
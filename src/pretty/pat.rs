@@ -0,0 +1,211 @@
+use super::algorithm::{Printer, INDENT};
+use super::path::PathKind;
+use crate::{
+    FieldPat, Member, Pat, PatBox, PatConst, PatIdent, PatLit, PatMacro, PatOr, PatPath, PatRange,
+    PatReference, PatSlice, PatStruct, PatTuple, PatTupleStruct, PatType, RangeLimits,
+};
+
+impl Printer {
+    pub fn pat(&mut self, pat: &Pat) {
+        match pat {
+            Pat::Box(pat) => self.pat_box(pat),
+            Pat::Const(pat) => self.pat_const(pat),
+            Pat::Ident(pat) => self.pat_ident(pat),
+            Pat::Lit(pat) => self.pat_lit(pat),
+            Pat::Macro(pat) => self.pat_macro(pat),
+            Pat::Or(pat) => self.pat_or(pat),
+            Pat::Path(pat) => self.pat_path(pat),
+            Pat::Range(pat) => self.pat_range(pat),
+            Pat::Reference(pat) => self.pat_reference(pat),
+            Pat::Rest(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                self.word("..");
+            }
+            Pat::Slice(pat) => self.pat_slice(pat),
+            Pat::Struct(pat) => self.pat_struct(pat),
+            Pat::Tuple(pat) => self.pat_tuple(pat),
+            Pat::TupleStruct(pat) => self.pat_tuple_struct(pat),
+            Pat::Type(pat) => self.pat_type(pat),
+            Pat::Verbatim(pat) => self.verbatim(pat),
+            Pat::Wild(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                self.word("_");
+            }
+
+            #[cfg(test)]
+            Pat::__TestExhaustive(_) => unimplemented!(),
+            #[cfg(not(test))]
+            _ => unreachable!(),
+        }
+    }
+
+    fn pat_box(&mut self, pat: &PatBox) {
+        self.outer_attrs_inline(&pat.attrs);
+        self.word("box ");
+        self.pat(&pat.pat);
+    }
+
+    fn pat_const(&mut self, pat: &PatConst) {
+        self.outer_attrs_inline(&pat.attrs);
+        self.word("const ");
+        self.block(&pat.block);
+    }
+
+    fn pat_ident(&mut self, pat: &PatIdent) {
+        self.outer_attrs_inline(&pat.attrs);
+        if pat.by_ref.is_some() {
+            self.word("ref ");
+        }
+        if pat.mutability.is_some() {
+            self.word("mut ");
+        }
+        self.ident(&pat.ident);
+        if let Some((_at, subpat)) = &pat.subpat {
+            self.word(" @ ");
+            self.pat(subpat);
+        }
+    }
+
+    fn pat_lit(&mut self, pat: &PatLit) {
+        self.outer_attrs_inline(&pat.attrs);
+        self.expr(&pat.expr);
+    }
+
+    fn pat_macro(&mut self, pat: &PatMacro) {
+        self.outer_attrs_inline(&pat.attrs);
+        self.mac(&pat.mac, None);
+    }
+
+    fn pat_or(&mut self, pat: &PatOr) {
+        self.outer_attrs_inline(&pat.attrs);
+        self.cbox(0);
+        if pat.leading_vert.is_some() {
+            self.word("| ");
+        }
+        for (i, case) in pat.cases.iter().enumerate() {
+            if i > 0 {
+                self.space();
+                self.word("| ");
+            }
+            self.pat(case);
+        }
+        self.end();
+    }
+
+    fn pat_path(&mut self, pat: &PatPath) {
+        self.outer_attrs_inline(&pat.attrs);
+        self.qpath(&pat.qself, &pat.path, PathKind::Expr);
+    }
+
+    fn pat_range(&mut self, pat: &PatRange) {
+        self.outer_attrs_inline(&pat.attrs);
+        self.expr(&pat.lo);
+        self.word(match pat.limits {
+            RangeLimits::HalfOpen(_) => "..",
+            RangeLimits::Closed(_) => "..=",
+        });
+        self.expr(&pat.hi);
+    }
+
+    fn pat_reference(&mut self, pat: &PatReference) {
+        self.outer_attrs_inline(&pat.attrs);
+        self.word("&");
+        if pat.mutability.is_some() {
+            self.word("mut ");
+        }
+        self.pat(&pat.pat);
+    }
+
+    fn pat_slice(&mut self, pat: &PatSlice) {
+        self.outer_attrs_inline(&pat.attrs);
+        self.word("[");
+        self.cbox(INDENT);
+        self.zerobreak();
+        for (i, elem) in pat.elems.iter().enumerate() {
+            self.pat(elem);
+            self.trailing_comma(i + 1 == pat.elems.len());
+        }
+        self.zerobreak();
+        self.offset(-INDENT);
+        self.end();
+        self.word("]");
+    }
+
+    fn pat_struct(&mut self, pat: &PatStruct) {
+        self.outer_attrs_inline(&pat.attrs);
+        self.path_kind(&pat.path, PathKind::Expr);
+        self.word(" {");
+        if pat.fields.is_empty() && pat.dot2_token.is_none() {
+            self.word("}");
+            return;
+        }
+        self.cbox(INDENT);
+        self.space();
+        for (i, field) in pat.fields.iter().enumerate() {
+            self.field_pat(field);
+            self.trailing_comma(i + 1 == pat.fields.len() && pat.dot2_token.is_none());
+        }
+        if pat.dot2_token.is_some() {
+            self.word("..");
+        }
+        self.space();
+        self.offset(-INDENT);
+        self.end();
+        self.word("}");
+    }
+
+    fn field_pat(&mut self, field: &FieldPat) {
+        self.outer_attrs_inline(&field.attrs);
+        if field.colon_token.is_none() {
+            // Shorthand, as in `Point { x, ref mut y }`.
+            self.pat(&field.pat);
+            return;
+        }
+        self.member(&field.member);
+        self.word(": ");
+        self.pat(&field.pat);
+    }
+
+    fn pat_tuple(&mut self, pat: &PatTuple) {
+        self.outer_attrs_inline(&pat.attrs);
+        self.word("(");
+        self.cbox(INDENT);
+        self.zerobreak();
+        for (i, elem) in pat.elems.iter().enumerate() {
+            self.pat(elem);
+            if pat.elems.len() == 1 {
+                // A single element without a trailing comma is a parenthesized
+                // pattern rather than a tuple.
+                if pat.elems.trailing_punct() {
+                    self.word(",");
+                }
+            } else {
+                self.trailing_comma(i + 1 == pat.elems.len());
+            }
+        }
+        self.zerobreak();
+        self.offset(-INDENT);
+        self.end();
+        self.word(")");
+    }
+
+    fn pat_tuple_struct(&mut self, pat: &PatTupleStruct) {
+        self.outer_attrs_inline(&pat.attrs);
+        self.path_kind(&pat.path, PathKind::Expr);
+        self.pat_tuple(&pat.pat);
+    }
+
+    pub fn pat_type(&mut self, pat: &PatType) {
+        self.outer_attrs_inline(&pat.attrs);
+        self.pat(&pat.pat);
+        self.word(": ");
+        self.ty(&pat.ty);
+    }
+
+    pub fn member(&mut self, member: &Member) {
+        match member {
+            Member::Named(ident) => self.ident(ident),
+            Member::Unnamed(index) => self.word(index.index.to_string()),
+        }
+    }
+}
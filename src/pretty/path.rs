@@ -0,0 +1,148 @@
+use super::algorithm::{Printer, INDENT};
+use crate::{
+    AngleBracketedGenericArguments, Binding, Constraint, Expr, GenericArgument,
+    ParenthesizedGenericArguments, Path, PathArguments, PathSegment, QSelf,
+};
+
+// Generic arguments in expression position take a leading `::`, as in
+// `Vec::<u8>::new()`.
+pub enum PathKind {
+    Type,
+    Expr,
+}
+
+impl Copy for PathKind {}
+
+impl Clone for PathKind {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Printer {
+    pub fn path(&mut self, path: &Path) {
+        self.path_kind(path, PathKind::Type);
+    }
+
+    pub fn path_kind(&mut self, path: &Path, kind: PathKind) {
+        if path.leading_colon.is_some() {
+            self.word("::");
+        }
+        for (i, segment) in path.segments.iter().enumerate() {
+            if i > 0 {
+                self.word("::");
+            }
+            self.path_segment(segment, kind);
+        }
+    }
+
+    pub fn qpath(&mut self, qself: &Option<QSelf>, path: &Path, kind: PathKind) {
+        let qself = match qself {
+            Some(qself) => qself,
+            None => return self.path_kind(path, kind),
+        };
+
+        self.word("<");
+        self.ty(&qself.ty);
+        let mut segments = path.segments.iter();
+        if qself.position > 0 {
+            self.word(" as ");
+            if path.leading_colon.is_some() {
+                self.word("::");
+            }
+            for (i, segment) in segments.by_ref().take(qself.position).enumerate() {
+                if i > 0 {
+                    self.word("::");
+                }
+                self.path_segment(segment, PathKind::Type);
+            }
+        }
+        self.word(">");
+        for segment in segments {
+            self.word("::");
+            self.path_segment(segment, kind);
+        }
+    }
+
+    fn path_segment(&mut self, segment: &PathSegment, kind: PathKind) {
+        self.ident(&segment.ident);
+        match &segment.arguments {
+            PathArguments::None => {}
+            PathArguments::AngleBracketed(arguments) => {
+                self.angle_bracketed_generic_arguments(arguments, kind);
+            }
+            PathArguments::Parenthesized(arguments) => {
+                self.parenthesized_generic_arguments(arguments);
+            }
+        }
+    }
+
+    pub fn angle_bracketed_generic_arguments(
+        &mut self,
+        arguments: &AngleBracketedGenericArguments,
+        kind: PathKind,
+    ) {
+        if let PathKind::Expr = kind {
+            self.word("::");
+        }
+        self.word("<");
+        self.cbox(INDENT);
+        self.zerobreak();
+        for (i, arg) in arguments.args.iter().enumerate() {
+            self.generic_argument(arg);
+            self.trailing_comma(i + 1 == arguments.args.len());
+        }
+        self.zerobreak();
+        self.offset(-INDENT);
+        self.end();
+        self.word(">");
+    }
+
+    fn generic_argument(&mut self, arg: &GenericArgument) {
+        match arg {
+            GenericArgument::Lifetime(lifetime) => self.lifetime(lifetime),
+            GenericArgument::Type(ty) => self.ty(ty),
+            GenericArgument::Binding(binding) => self.binding(binding),
+            GenericArgument::Constraint(constraint) => self.constraint(constraint),
+            GenericArgument::Const(expr) => self.const_argument(expr),
+        }
+    }
+
+    pub fn const_argument(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Lit(_) | Expr::Block(_) => self.expr(expr),
+            _ => {
+                self.word("{ ");
+                self.expr(expr);
+                self.word(" }");
+            }
+        }
+    }
+
+    fn binding(&mut self, binding: &Binding) {
+        self.ident(&binding.ident);
+        self.word(" = ");
+        self.ty(&binding.ty);
+    }
+
+    fn constraint(&mut self, constraint: &Constraint) {
+        self.ident(&constraint.ident);
+        self.word(": ");
+        self.type_param_bounds(&constraint.bounds);
+    }
+
+    fn parenthesized_generic_arguments(&mut self, arguments: &ParenthesizedGenericArguments) {
+        self.word("(");
+        self.cbox(INDENT);
+        self.zerobreak();
+        for (i, ty) in arguments.inputs.iter().enumerate() {
+            self.ty(ty);
+            self.trailing_comma(i + 1 == arguments.inputs.len());
+        }
+        self.zerobreak();
+        self.offset(-INDENT);
+        self.end();
+        self.word(")");
+        self.return_type(&arguments.output);
+    }
+}
@@ -0,0 +1,102 @@
+use super::algorithm::{Printer, INDENT};
+use super::attr::has_inner_attrs;
+use crate::precedence::{self, Operand, Precedence};
+use crate::{Attribute, Expr, Local, Stmt};
+
+impl Printer {
+    pub fn stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Local(local) => self.local(local),
+            Stmt::Item(item) => self.item(item),
+            Stmt::Expr(expr) => self.expr_stmt(expr),
+            Stmt::Semi(expr, _semi) => {
+                self.expr_stmt(expr);
+                self.word(";");
+            }
+        }
+    }
+
+    // The statements of a block following its opening brace, preceded by the
+    // inner attributes of the enclosing expression or function.
+    pub fn block_body(&mut self, stmts: &[Stmt], attrs: &[Attribute]) {
+        if has_inner_attrs(attrs) || !stmts.is_empty() {
+            self.cbox(INDENT);
+            self.hardbreak();
+            self.inner_attrs(attrs);
+            for (i, stmt) in stmts.iter().enumerate() {
+                if i > 0 {
+                    self.hardbreak();
+                }
+                self.stmt(stmt);
+            }
+            self.hardbreak();
+            self.offset(-INDENT);
+            self.end();
+        }
+        self.word("}");
+    }
+
+    fn local(&mut self, local: &Local) {
+        self.outer_attrs(&local.attrs);
+        self.word("let ");
+        self.pat(&local.pat);
+        if let Some(init) = &local.init {
+            self.word(" =");
+            self.rhs(&init.expr, None);
+            if let Some((_else, diverge)) = &init.diverge {
+                self.word(" else ");
+                self.block(diverge);
+            }
+        }
+        self.word(";");
+    }
+
+    fn expr_stmt(&mut self, expr: &Expr) {
+        if starts_with_block(expr, false) {
+            // Otherwise the block would be parsed as a statement of its own.
+            self.word("(");
+            self.expr(expr);
+            self.word(")");
+        } else {
+            self.expr(expr);
+        }
+    }
+}
+
+// Whether the leftmost subexpression of `expr` is a block-like expression that
+// would end the statement early when printed at the start of a statement.
+// Method calls, field accesses and `?` may directly follow such a block, but
+// binary operators, calls and indexing may not.
+fn starts_with_block(expr: &Expr, behind_operator: bool) -> bool {
+    let (operand, operator, position) = match expr {
+        Expr::Unsafe(_)
+        | Expr::Block(_)
+        | Expr::If(_)
+        | Expr::Match(_)
+        | Expr::While(_)
+        | Expr::Loop(_)
+        | Expr::ForLoop(_)
+        | Expr::TryBlock(_)
+        | Expr::Const(_) => return behind_operator,
+        Expr::Binary(e) => (&*e.left, true, Operand::left_of(&e.op)),
+        Expr::Assign(e) => (&*e.left, true, Operand::Left(Precedence::Assign)),
+        Expr::AssignOp(e) => (&*e.left, true, Operand::Left(Precedence::Assign)),
+        Expr::Cast(e) => (&*e.expr, true, Operand::Left(Precedence::Cast)),
+        Expr::Type(e) => (&*e.expr, true, Operand::Left(Precedence::Cast)),
+        Expr::Range(e) => match &e.from {
+            Some(from) => (&**from, true, Operand::Left(Precedence::Range)),
+            None => return false,
+        },
+        Expr::Call(e) => (&*e.func, true, Operand::Callee),
+        Expr::Index(e) => (&*e.expr, true, Operand::Postfix),
+        Expr::MethodCall(e) => (&*e.receiver, false, Operand::Postfix),
+        Expr::Field(e) => (&*e.base, false, Operand::Postfix),
+        Expr::Try(e) => (&*e.expr, false, Operand::Postfix),
+        Expr::Await(e) => (&*e.base, false, Operand::Postfix),
+        _ => return false,
+    };
+    if precedence::needs_parens(operand, position) {
+        return false;
+    }
+    starts_with_block(operand, operator)
+}
@@ -0,0 +1,21 @@
+use super::algorithm::Printer;
+use crate::{Ident, Lifetime, Lit, LitStr};
+use quote::ToTokens;
+
+impl Printer {
+    pub fn ident(&mut self, ident: &Ident) {
+        self.word(ident.to_string());
+    }
+
+    pub fn lifetime(&mut self, lifetime: &Lifetime) {
+        self.word(lifetime.to_string());
+    }
+
+    pub fn lit(&mut self, lit: &Lit) {
+        self.word(lit.to_token_stream().to_string());
+    }
+
+    pub fn lit_str(&mut self, lit: &LitStr) {
+        self.word(lit.to_token_stream().to_string());
+    }
+}
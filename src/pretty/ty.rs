@@ -0,0 +1,173 @@
+use super::algorithm::{Printer, INDENT};
+use super::path::PathKind;
+use crate::{
+    Abi, BareFnArg, ReturnType, Type, TypeArray, TypeBareFn, TypeGroup, TypeImplTrait, TypeMacro,
+    TypeParen, TypePath, TypePtr, TypeReference, TypeSlice, TypeTraitObject, TypeTuple, Variadic,
+};
+
+impl Printer {
+    pub fn ty(&mut self, ty: &Type) {
+        match ty {
+            Type::Array(ty) => self.type_array(ty),
+            Type::BareFn(ty) => self.type_bare_fn(ty),
+            Type::Group(ty) => self.type_group(ty),
+            Type::ImplTrait(ty) => self.type_impl_trait(ty),
+            Type::Infer(_) => self.word("_"),
+            Type::Macro(ty) => self.type_macro(ty),
+            Type::Never(_) => self.word("!"),
+            Type::Paren(ty) => self.type_paren(ty),
+            Type::Path(ty) => self.type_path(ty),
+            Type::Ptr(ty) => self.type_ptr(ty),
+            Type::Reference(ty) => self.type_reference(ty),
+            Type::Slice(ty) => self.type_slice(ty),
+            Type::TraitObject(ty) => self.type_trait_object(ty),
+            Type::Tuple(ty) => self.type_tuple(ty),
+            Type::Verbatim(ty) => self.verbatim(ty),
+
+            #[cfg(test)]
+            Type::__TestExhaustive(_) => unimplemented!(),
+            #[cfg(not(test))]
+            _ => unreachable!(),
+        }
+    }
+
+    fn type_array(&mut self, ty: &TypeArray) {
+        self.word("[");
+        self.ty(&ty.elem);
+        self.word("; ");
+        self.expr(&ty.len);
+        self.word("]");
+    }
+
+    fn type_bare_fn(&mut self, ty: &TypeBareFn) {
+        if let Some(bound_lifetimes) = &ty.lifetimes {
+            self.bound_lifetimes(bound_lifetimes);
+        }
+        if ty.unsafety.is_some() {
+            self.word("unsafe ");
+        }
+        if let Some(abi) = &ty.abi {
+            self.abi(abi);
+        }
+        self.word("fn(");
+        self.cbox(INDENT);
+        self.zerobreak();
+        for (i, arg) in ty.inputs.iter().enumerate() {
+            self.bare_fn_arg(arg);
+            self.trailing_comma(i + 1 == ty.inputs.len() && ty.variadic.is_none());
+        }
+        if let Some(variadic) = &ty.variadic {
+            self.variadic(variadic);
+        }
+        self.zerobreak();
+        self.offset(-INDENT);
+        self.end();
+        self.word(")");
+        self.return_type(&ty.output);
+    }
+
+    fn type_group(&mut self, ty: &TypeGroup) {
+        self.ty(&ty.elem);
+    }
+
+    fn type_impl_trait(&mut self, ty: &TypeImplTrait) {
+        self.word("impl ");
+        self.type_param_bounds(&ty.bounds);
+    }
+
+    fn type_macro(&mut self, ty: &TypeMacro) {
+        self.mac(&ty.mac, None);
+    }
+
+    fn type_paren(&mut self, ty: &TypeParen) {
+        self.word("(");
+        self.ty(&ty.elem);
+        self.word(")");
+    }
+
+    fn type_path(&mut self, ty: &TypePath) {
+        self.qpath(&ty.qself, &ty.path, PathKind::Type);
+    }
+
+    fn type_ptr(&mut self, ty: &TypePtr) {
+        self.word("*");
+        if ty.mutability.is_some() {
+            self.word("mut ");
+        } else {
+            self.word("const ");
+        }
+        self.ty(&ty.elem);
+    }
+
+    fn type_reference(&mut self, ty: &TypeReference) {
+        self.word("&");
+        if let Some(lifetime) = &ty.lifetime {
+            self.lifetime(lifetime);
+            self.nbsp();
+        }
+        if ty.mutability.is_some() {
+            self.word("mut ");
+        }
+        self.ty(&ty.elem);
+    }
+
+    fn type_slice(&mut self, ty: &TypeSlice) {
+        self.word("[");
+        self.ty(&ty.elem);
+        self.word("]");
+    }
+
+    fn type_trait_object(&mut self, ty: &TypeTraitObject) {
+        if ty.dyn_token.is_some() {
+            self.word("dyn ");
+        }
+        self.type_param_bounds(&ty.bounds);
+    }
+
+    fn type_tuple(&mut self, ty: &TypeTuple) {
+        self.word("(");
+        self.cbox(INDENT);
+        self.zerobreak();
+        for (i, elem) in ty.elems.iter().enumerate() {
+            self.ty(elem);
+            if ty.elems.len() == 1 {
+                self.word(",");
+            } else {
+                self.trailing_comma(i + 1 == ty.elems.len());
+            }
+        }
+        self.zerobreak();
+        self.offset(-INDENT);
+        self.end();
+        self.word(")");
+    }
+
+    fn bare_fn_arg(&mut self, arg: &BareFnArg) {
+        self.outer_attrs_inline(&arg.attrs);
+        if let Some((name, _colon)) = &arg.name {
+            self.ident(name);
+            self.word(": ");
+        }
+        self.ty(&arg.ty);
+    }
+
+    pub fn variadic(&mut self, variadic: &Variadic) {
+        self.outer_attrs_inline(&variadic.attrs);
+        self.word("...");
+    }
+
+    pub fn return_type(&mut self, ty: &ReturnType) {
+        if let ReturnType::Type(_arrow, ty) = ty {
+            self.word(" -> ");
+            self.ty(ty);
+        }
+    }
+
+    pub fn abi(&mut self, abi: &Abi) {
+        self.word("extern ");
+        if let Some(name) = &abi.name {
+            self.lit_str(name);
+            self.nbsp();
+        }
+    }
+}
@@ -20,6 +20,10 @@
 //!                 ~~~~^ ~~~~^ ~~~~
 //! ```
 
+#[cfg(feature = "serde")]
+use serde::de::{self, Deserialize, Deserializer};
+#[cfg(feature = "serde")]
+use serde::ser::{Serialize, SerializeSeq, Serializer};
 #[cfg(feature = "extra-traits")]
 use std::fmt::{self, Debug};
 #[cfg(feature = "extra-traits")]
@@ -433,6 +437,56 @@ impl<T: Debug, P: Debug> Debug for Punctuated<T, P> {
     }
 }
 
+/// Serializes as a sequence of the values, leaving out the punctuation. A
+/// trailing punctuation is represented by a `null` after the last value.
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<T: Serialize, P> Serialize for Punctuated<T, P> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let trailing = self.trailing_punct();
+        let mut seq = serializer.serialize_seq(Some(self.len() + trailing as usize))?;
+        for value in self {
+            seq.serialize_element(&Some(value))?;
+        }
+        if trailing {
+            seq.serialize_element(&None::<&T>)?;
+        }
+        seq.end()
+    }
+}
+
+/// Deserializes from a sequence of the values with default punctuation
+/// between them, as serialized by the `Serialize` impl.
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de, T, P> Deserialize<'de> for Punctuated<T, P>
+where
+    T: Deserialize<'de>,
+    P: Default,
+{
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let values = Vec::<Option<T>>::deserialize(deserializer)?;
+        let len = values.len();
+        let mut punctuated = Punctuated::new();
+        for (i, value) in values.into_iter().enumerate() {
+            match value {
+                Some(value) => punctuated.push(value),
+                None if i + 1 == len && !punctuated.is_empty() => {
+                    punctuated.push_punct(P::default());
+                }
+                None => return Err(de::Error::custom("unexpected punctuation")),
+            }
+        }
+        Ok(punctuated)
+    }
+}
+
 impl<T, P> FromIterator<T> for Punctuated<T, P>
 where
     P: Default,
@@ -0,0 +1,305 @@
+// Parsing of a whole file that carries on past syntax errors.
+//
+// An item or statement that fails to parse is replaced by a placeholder
+// holding its tokens, and parsing resumes at the next item or statement
+// boundary. Before giving up on an item whose body is a braced group, such as
+// a function, module, impl or trait, its header is parsed on its own so that
+// only the broken items or statements inside the body become placeholders.
+
+use crate::parse::discouraged::Speculative;
+use crate::parse::{Parse, ParseStream, Parser};
+use crate::stmt::parsing::parse_stmt;
+use crate::{
+    expr, token, Attribute, Block, Error, Expr, File, ImplItem, Item, Result, Stmt, TraitItem,
+};
+use proc_macro2::{Delimiter, Group, Span, TokenStream, TokenTree};
+use std::iter;
+
+pub(crate) fn parse_file(content: &str) -> (File, Vec<Error>) {
+    let (shebang, content) = crate::split_shebang(content);
+    let mut file = File {
+        shebang,
+        attrs: Vec::new(),
+        items: Vec::new(),
+    };
+    let mut errors = Vec::new();
+
+    let tokens = match crate::error::lex(content) {
+        Ok(tokens) => tokens,
+        Err(err) => {
+            errors.push(Error::from(err));
+            return (file, errors);
+        }
+    };
+
+    let scope = crate::parse::end_of_input(tokens.clone().into_iter().last());
+    let (attrs, items) = parse_body(tokens, scope, &mut errors, parse_items);
+    file.attrs = attrs;
+    file.items = items;
+    (file, errors)
+}
+
+// Syntax tree nodes that may stand in a list of items.
+trait Recover: Parse {
+    // A placeholder for tokens that failed to parse.
+    fn verbatim(tokens: TokenStream) -> Self;
+
+    // Fills in the body of a node that was parsed with an empty body, or
+    // returns false if the node has no body that can be recovered.
+    fn recover_body(&mut self, body: &Group, errors: &mut Vec<Error>) -> bool;
+}
+
+impl Recover for Item {
+    fn verbatim(tokens: TokenStream) -> Self {
+        Item::Verbatim(tokens)
+    }
+
+    fn recover_body(&mut self, body: &Group, errors: &mut Vec<Error>) -> bool {
+        let brace_token = token::Brace(body.span());
+        match self {
+            Item::Fn(item) => {
+                let (attrs, stmts) = parse_group(body, errors, parse_stmts);
+                item.attrs.extend(attrs);
+                *item.block = Block { brace_token, stmts };
+                true
+            }
+            Item::Mod(item) if item.content.is_some() => {
+                let (attrs, items) = parse_group(body, errors, parse_items);
+                item.attrs.extend(attrs);
+                item.content = Some((brace_token, items));
+                true
+            }
+            Item::Impl(item) => {
+                let (attrs, items) = parse_group(body, errors, parse_items);
+                item.attrs.extend(attrs);
+                item.brace_token = brace_token;
+                item.items = items;
+                true
+            }
+            Item::Trait(item) => {
+                let (attrs, items) = parse_group(body, errors, parse_items);
+                item.attrs.extend(attrs);
+                item.brace_token = brace_token;
+                item.items = items;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Recover for ImplItem {
+    fn verbatim(tokens: TokenStream) -> Self {
+        ImplItem::Verbatim(tokens)
+    }
+
+    fn recover_body(&mut self, body: &Group, errors: &mut Vec<Error>) -> bool {
+        match self {
+            ImplItem::Method(item) => {
+                let (attrs, stmts) = parse_group(body, errors, parse_stmts);
+                item.attrs.extend(attrs);
+                item.block = Block {
+                    brace_token: token::Brace(body.span()),
+                    stmts,
+                };
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Recover for TraitItem {
+    fn verbatim(tokens: TokenStream) -> Self {
+        TraitItem::Verbatim(tokens)
+    }
+
+    fn recover_body(&mut self, body: &Group, errors: &mut Vec<Error>) -> bool {
+        match self {
+            TraitItem::Method(item) if item.default.is_some() => {
+                let (attrs, stmts) = parse_group(body, errors, parse_stmts);
+                item.attrs.extend(attrs);
+                item.default = Some(Block {
+                    brace_token: token::Brace(body.span()),
+                    stmts,
+                });
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+// Parses the inner attributes and contents of a braced body.
+fn parse_group<T>(
+    body: &Group,
+    errors: &mut Vec<Error>,
+    parse: fn(ParseStream, &mut Vec<Error>) -> Vec<T>,
+) -> (Vec<Attribute>, Vec<T>) {
+    parse_body(body.stream(), body.span_close(), errors, parse)
+}
+
+fn parse_body<T>(
+    tokens: TokenStream,
+    scope: Span,
+    errors: &mut Vec<Error>,
+    parse: fn(ParseStream, &mut Vec<Error>) -> Vec<T>,
+) -> (Vec<Attribute>, Vec<T>) {
+    let parser = |input: ParseStream| -> Result<(Vec<Attribute>, Vec<T>)> {
+        // Malformed inner attributes are left for the contents to report.
+        let ahead = input.fork();
+        let attrs = match ahead.call(Attribute::parse_inner) {
+            Ok(attrs) => {
+                input.advance_to(&ahead);
+                attrs
+            }
+            Err(_) => Vec::new(),
+        };
+        Ok((attrs, parse(input, errors)))
+    };
+    // The contents always consume every token, so this never fails.
+    crate::parse::parse_scoped(parser, scope, tokens).unwrap_or_default()
+}
+
+fn parse_items<T: Recover>(input: ParseStream, errors: &mut Vec<Error>) -> Vec<T> {
+    let mut items = Vec::new();
+    while !input.is_empty() {
+        let ahead = input.fork();
+        match ahead.parse() {
+            Ok(item) => {
+                input.advance_to(&ahead);
+                items.push(item);
+            }
+            Err(err) => items.push(match recover_body(input, errors) {
+                Some(item) => item,
+                None => {
+                    errors.push(err);
+                    T::verbatim(skip(input, false))
+                }
+            }),
+        }
+    }
+    items
+}
+
+fn parse_stmts(input: ParseStream, errors: &mut Vec<Error>) -> Vec<Stmt> {
+    let mut stmts = Vec::new();
+    loop {
+        while let Ok(Some(semi)) = input.parse::<Option<Token![;]>>() {
+            stmts.push(Stmt::Semi(Expr::Verbatim(TokenStream::new()), semi));
+        }
+        if input.is_empty() {
+            break;
+        }
+
+        let ahead = input.fork();
+        let err = match parse_stmt(&ahead, true) {
+            Ok(stmt) => {
+                input.advance_to(&ahead);
+                let requires_semicolon = match &stmt {
+                    Stmt::Expr(expr) => expr::requires_terminator(expr),
+                    _ => false,
+                };
+                stmts.push(stmt);
+                if input.is_empty() || !requires_semicolon {
+                    continue;
+                }
+                input.error("unexpected token")
+            }
+            Err(err) => match recover_body(input, errors) {
+                Some(item) => {
+                    stmts.push(Stmt::Item(item));
+                    continue;
+                }
+                None => err,
+            },
+        };
+
+        errors.push(err);
+        let tokens = skip(input, true);
+        stmts.push(match input.parse::<Option<Token![;]>>() {
+            Ok(Some(semi)) => Stmt::Semi(Expr::Verbatim(tokens), semi),
+            _ => Stmt::Expr(Expr::Verbatim(tokens)),
+        });
+    }
+    stmts
+}
+
+// Parses a node up to the first braced group as if that group were empty, then
+// recovers the contents of the group separately. Succeeds only if the errors
+// were in the contents.
+fn recover_body<T: Recover>(input: ParseStream, errors: &mut Vec<Error>) -> Option<T> {
+    let ahead = input.fork();
+    let mut header = TokenStream::new();
+    let body = loop {
+        match ahead.parse::<TokenTree>().ok()? {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => break group,
+            TokenTree::Punct(punct) if punct.as_char() == ';' => return None,
+            token => header.extend(iter::once(token)),
+        }
+    };
+    let mut empty = Group::new(Delimiter::Brace, TokenStream::new());
+    empty.set_span(body.span());
+    header.extend(iter::once(TokenTree::Group(empty)));
+
+    let mut node = T::parse.parse2(header).ok()?;
+    let mut nested = Vec::new();
+    if !node.recover_body(&body, &mut nested) || nested.is_empty() {
+        return None;
+    }
+    input.advance_to(&ahead);
+    errors.extend(nested);
+    Some(node)
+}
+
+// Skips the tokens of an item or statement that failed to parse. An item ends
+// after a `;` or braced group. A statement ends before a `;`, which is left for
+// the caller to parse, or after a braced group that is not followed by `else`
+// or punctuation. Either one also ends before a following `let` statement or
+// item.
+fn skip(input: ParseStream, stmt: bool) -> TokenStream {
+    let mut tokens = TokenStream::new();
+    while !input.is_empty() {
+        if !tokens.is_empty() && (stmt && input.peek(Token![let]) || starts_item(input)) {
+            break;
+        }
+        if stmt && input.peek(Token![;]) {
+            break;
+        }
+        let token: TokenTree = match input.parse() {
+            Ok(token) => token,
+            Err(_) => break,
+        };
+        let end = match &token {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+                !stmt || !input.peek(Token![else]) && input.cursor().punct().is_none()
+            }
+            TokenTree::Punct(punct) => punct.as_char() == ';',
+            _ => false,
+        };
+        tokens.extend(iter::once(token));
+        if end {
+            break;
+        }
+    }
+    tokens
+}
+
+fn starts_item(input: ParseStream) -> bool {
+    let keyword = input.peek(Token![#]) && input.peek2(token::Bracket)
+        || input.peek(Token![pub])
+        || input.peek(Token![fn])
+        || input.peek(Token![struct])
+        || input.peek(Token![enum])
+        || input.peek(Token![trait])
+        || input.peek(Token![impl])
+        || input.peek(Token![mod])
+        || input.peek(Token![use])
+        || input.peek(Token![static])
+        || input.peek(Token![const])
+        || input.peek(Token![extern])
+        || input.peek(Token![type])
+        || input.peek(Token![unsafe])
+        || input.peek(Token![async]);
+    keyword && input.fork().parse::<Item>().is_ok()
+}
@@ -0,0 +1,382 @@
+use crate::error::{ChildKind, ErrorMessage, Level, SpanRange};
+use crate::source_map::SourceFile;
+use crate::Error;
+use proc_macro2::LineColumn;
+use std::fmt::Write;
+
+impl Error {
+    /// Renders every message of the error in the style of rustc's
+    /// diagnostics, given the name and text of the file that was parsed.
+    ///
+    /// Each message is printed with its `file:line:column` location and the
+    /// lines of source code that its span covers, underlined with carets from
+    /// the start of the first token of the span to the end of its last.
+    /// Labels of other spans are underlined with dashes, and notes and help
+    /// messages follow the message they are attached to.
+    ///
+    /// ```text
+    /// error: expected `,`
+    ///  --> src/main.rs:3:5
+    ///   |
+    /// 3 |     y: u8,
+    ///   |     ^
+    /// ```
+    ///
+    /// The spans of the error must come from parsing `source` with
+    /// [`syn::parse_file`], [`syn::parse_str`] or similar. Their locations are
+    /// resolved when the error is created, so the error may be rendered on a
+    /// different thread than the one that parsed it. Messages whose span is
+    /// not from `source`, such as one from another parsed string or from
+    /// `Span::call_site()`, are printed without a snippet.
+    ///
+    /// A span is tied to its string when the error is created, by looking
+    /// through the last 64 strings parsed on the same thread. An error created
+    /// after more strings than that have been parsed is printed without a
+    /// snippet as well. To locate errors among any number of files, use
+    /// [`Error::location`] with a [`SourceMap`].
+    ///
+    /// [`SourceMap`]: crate::source_map::SourceMap
+    ///
+    /// [`syn::parse_file`]: crate::parse_file()
+    /// [`syn::parse_str`]: crate::parse_str()
+    ///
+    /// *This method is available only if Syn is built with the
+    /// `"span-locations"` feature.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let source = "fn main() {\n    let x = ;\n}\n";
+    /// let err = syn::parse_file(source).unwrap_err();
+    ///
+    /// let expected = "\
+    /// error: expected expression
+    ///  --> src/main.rs:2:13
+    ///   |
+    /// 2 |     let x = ;
+    ///   |             ^
+    /// ";
+    /// assert_eq!(err.render("src/main.rs", source), expected);
+    /// ```
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "span-locations")))]
+    pub fn render(&self, file: &str, source: &str) -> String {
+        let source = source_file(file, source);
+        let mut out = String::new();
+        for (i, message) in self.messages().iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            render_text(&mut out, &source, message);
+        }
+        out
+    }
+
+    /// Renders every message of the error as JSON, in the format of rustc's
+    /// `--error-format=json` diagnostics, given the name and text of the file
+    /// that was parsed.
+    ///
+    /// There is one JSON object per line for each message. Each object has
+    /// the `message`, `level`, `spans` and `rendered` fields of a rustc
+    /// diagnostic, with 1-based lines and columns in its span. A message
+    /// whose span is not from `source` has an empty list of spans.
+    ///
+    /// *This method is available only if Syn is built with the
+    /// `"span-locations"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "span-locations")))]
+    pub fn render_json(&self, file: &str, source: &str) -> String {
+        let source = source_file(file, source);
+        let mut out = String::new();
+        for message in self.messages() {
+            render_json(&mut out, &source, message);
+            out.push('\n');
+        }
+        out
+    }
+}
+
+fn source_file(file: &str, source: &str) -> SourceFile {
+    // Columns on the first line are counted after the byte order mark, which
+    // is not part of the tokens.
+    const BOM: &str = "\u{feff}";
+    let offset = if source.starts_with(BOM) {
+        BOM.len()
+    } else {
+        0
+    };
+    SourceFile::new(file, source, offset)
+}
+
+struct Snippet {
+    start: LineColumn,
+    end: LineColumn,
+}
+
+// A span to underline, with carets for the primary span of a message and
+// dashes for the others.
+struct Underline<'a> {
+    snippet: Snippet,
+    primary: bool,
+    label: &'a str,
+}
+
+// The lines and columns of a span, if it was lexed from the source.
+fn snippet(source: &SourceFile, span: &SpanRange) -> Option<Snippet> {
+    let (start, end) = span.location()?;
+    if !span.is_from(source.text())
+        || (end.line, end.column) < (start.line, start.column)
+        || source.line(end.line).is_none()
+    {
+        return None;
+    }
+    Some(Snippet { start, end })
+}
+
+fn level_name(level: &Level) -> &'static str {
+    match level {
+        Level::Error => "error",
+        Level::Warning => "warning",
+    }
+}
+
+fn kind_name(kind: &ChildKind) -> &'static str {
+    match kind {
+        ChildKind::Label | ChildKind::Note => "note",
+        ChildKind::Help => "help",
+    }
+}
+
+fn render_text(out: &mut String, source: &SourceFile, message: &ErrorMessage) {
+    let mut underlines = Vec::new();
+    let mut notes = Vec::new();
+    let mut spanned_notes = Vec::new();
+    let primary = snippet(source, &message.span);
+    if let Some(snippet) = primary {
+        underlines.push(Underline {
+            snippet,
+            primary: true,
+            label: "",
+        });
+    }
+    for child in &message.children {
+        let snippet = child.span.as_ref().and_then(|span| snippet(source, span));
+        match (&child.kind, snippet) {
+            (ChildKind::Label, Some(snippet)) if !underlines.is_empty() => {
+                underlines.push(Underline {
+                    snippet,
+                    primary: false,
+                    label: &child.message,
+                });
+            }
+            (ChildKind::Label, _) | (_, None) => notes.push(child),
+            (_, Some(snippet)) => spanned_notes.push((child, snippet)),
+        }
+    }
+
+    let last_line = underlines
+        .iter()
+        .map(|underline| &underline.snippet)
+        .chain(spanned_notes.iter().map(|(_child, snippet)| snippet))
+        .map(|snippet| snippet.end.line)
+        .max()
+        .unwrap_or(0);
+    let gutter = " ".repeat(last_line.to_string().len());
+
+    let _ = writeln!(out, "{}: {}", level_name(&message.level), message.message);
+    render_snippet(out, source, &gutter, &mut underlines);
+    if !notes.is_empty() {
+        let _ = writeln!(out, "{} |", gutter);
+    }
+    for note in notes {
+        let _ = writeln!(
+            out,
+            "{} = {}: {}",
+            gutter,
+            kind_name(&note.kind),
+            note.message
+        );
+    }
+    for (note, snippet) in spanned_notes {
+        let _ = writeln!(out, "{}: {}", kind_name(&note.kind), note.message);
+        let mut underlines = vec![Underline {
+            snippet,
+            primary: true,
+            label: "",
+        }];
+        render_snippet(out, source, &gutter, &mut underlines);
+    }
+}
+
+fn render_snippet(
+    out: &mut String,
+    source: &SourceFile,
+    gutter: &str,
+    underlines: &mut [Underline],
+) {
+    let location = match underlines.first() {
+        Some(primary) => format!(
+            "{}:{}:{}",
+            source.name(),
+            primary.snippet.start.line,
+            primary.snippet.start.column + 1,
+        ),
+        None => source.name().to_owned(),
+    };
+    let _ = writeln!(out, "{}--> {}", gutter, location);
+    if underlines.is_empty() {
+        return;
+    }
+
+    let _ = writeln!(out, "{} |", gutter);
+    underlines.sort_by_key(|underline| underline.snippet.start.line);
+    let mut printed = 0;
+    for underline in underlines.iter() {
+        let snippet = &underline.snippet;
+        if printed != 0 && snippet.start.line == printed + 2 {
+            print_line(out, source, gutter, printed + 1);
+        } else if printed != 0 && snippet.start.line > printed + 2 {
+            let _ = writeln!(out, "...");
+        }
+        for line in snippet.start.line..=snippet.end.line {
+            let text = source.line(line).unwrap();
+            let from = if line == snippet.start.line {
+                snippet.start.column
+            } else {
+                text.chars().take_while(|ch| ch.is_whitespace()).count()
+            };
+            let to = if line == snippet.end.line {
+                snippet.end.column
+            } else {
+                text.chars().count()
+            };
+            print_line(out, source, gutter, line);
+            let (indent, width) = underline_width(text, from, to);
+            let marker = if underline.primary { "^" } else { "-" };
+            let mut marked = format!(
+                "{} | {}{}",
+                gutter,
+                " ".repeat(indent),
+                marker.repeat(width)
+            );
+            if line == snippet.end.line && !underline.label.is_empty() {
+                marked.push(' ');
+                marked.push_str(underline.label);
+            }
+            out.push_str(&marked);
+            out.push('\n');
+        }
+        printed = snippet.end.line;
+    }
+}
+
+fn print_line(out: &mut String, source: &SourceFile, gutter: &str, line: usize) {
+    let text = source.line(line).unwrap().replace('\t', "    ");
+    let numbered = format!("{:>width$} | {}", line, text, width = gutter.len());
+    out.push_str(numbered.trim_end());
+    out.push('\n');
+}
+
+// The display widths before and of the characters from one column to another,
+// which are underlined by at least one marker.
+fn underline_width(text: &str, from: usize, to: usize) -> (usize, usize) {
+    let width = |ch: char| if ch == '\t' { 4 } else { 1 };
+    let indent = text.chars().take(from).map(width).sum();
+    let carets = text
+        .chars()
+        .skip(from)
+        .take(to.saturating_sub(from))
+        .map(width)
+        .sum();
+    (indent, if carets == 0 { 1 } else { carets })
+}
+
+fn render_json(out: &mut String, source: &SourceFile, message: &ErrorMessage) {
+    let mut rendered = String::new();
+    render_text(&mut rendered, source, message);
+
+    out.push_str("{\"message\":");
+    json_string(out, &message.message);
+    let _ = write!(
+        out,
+        ",\"code\":null,\"level\":\"{}\",\"spans\":[",
+        level_name(&message.level),
+    );
+    let primary = snippet(source, &message.span);
+    if let Some(snippet) = &primary {
+        json_span(out, source, snippet, None);
+    }
+    for child in &message.children {
+        let snippet = child.span.as_ref().and_then(|span| snippet(source, span));
+        if let (ChildKind::Label, Some(_), Some(snippet)) = (&child.kind, &primary, snippet) {
+            out.push(',');
+            json_span(out, source, &snippet, Some(&child.message));
+        }
+    }
+    out.push_str("],\"children\":[");
+    let mut first = true;
+    for child in &message.children {
+        let snippet = child.span.as_ref().and_then(|span| snippet(source, span));
+        if let ChildKind::Label = child.kind {
+            if snippet.is_some() && primary.is_some() {
+                continue;
+            }
+        }
+        if !first {
+            out.push(',');
+        }
+        first = false;
+        out.push_str("{\"message\":");
+        json_string(out, &child.message);
+        let _ = write!(
+            out,
+            ",\"code\":null,\"level\":\"{}\",\"spans\":[",
+            kind_name(&child.kind),
+        );
+        if let Some(snippet) = &snippet {
+            json_span(out, source, snippet, None);
+        }
+        out.push_str("],\"children\":[],\"rendered\":null}");
+    }
+    out.push_str("],\"rendered\":");
+    json_string(out, &rendered);
+    out.push('}');
+}
+
+fn json_span(out: &mut String, source: &SourceFile, snippet: &Snippet, label: Option<&str>) {
+    out.push_str("{\"file_name\":");
+    json_string(out, source.name());
+    let _ = write!(
+        out,
+        ",\"byte_start\":{},\"byte_end\":{},\"line_start\":{},\"line_end\":{},\
+         \"column_start\":{},\"column_end\":{},\"is_primary\":{},\"label\":",
+        source.byte(snippet.start),
+        source.byte(snippet.end),
+        snippet.start.line,
+        snippet.end.line,
+        snippet.start.column + 1,
+        snippet.end.column + 1,
+        label.is_none(),
+    );
+    match label {
+        Some(label) => json_string(out, label),
+        None => out.push_str("null"),
+    }
+    out.push('}');
+}
+
+fn json_string(out: &mut String, string: &str) {
+    out.push('"');
+    for ch in string.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", ch as u32);
+            }
+            ch => out.push(ch),
+        }
+    }
+    out.push('"');
+}
@@ -1,9 +1,9 @@
 // Type for a syntax tree node that is reserved for future use.
 //
-// For example ExprReference contains a field `raw` of type Reserved. If `&raw
-// place` syntax becomes a thing as per https://github.com/rust-lang/rfcs/pull/2582,
-// we can backward compatibly change `raw`'s type to Option<Token![raw]> without
-// the possibility of breaking any code.
+// For example ExprReference contains a field `raw` of type Reserved. It was set
+// aside for `&raw place` syntax as per https://github.com/rust-lang/rfcs/pull/2582,
+// which ended up being represented by the separate ExprRawAddr node instead
+// because it also needs to carry an explicit `const` or `mut`.
 
 use proc_macro2::Span;
 use std::marker::PhantomData;
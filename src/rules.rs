@@ -0,0 +1,405 @@
+use super::*;
+use crate::punctuated::Punctuated;
+use proc_macro2::TokenStream;
+
+ast_struct! {
+    /// The rules of a `macro_rules!` definition, as produced by
+    /// [`ItemMacro::parse_rules`].
+    ///
+    /// *This type is available only if Syn is built with the `"full"` feature.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syn::{parse_quote, ItemMacro, MacroTree};
+    ///
+    /// let item: ItemMacro = parse_quote! {
+    ///     macro_rules! vec {
+    ///         () => { Vec::new() };
+    ///         ($($elem:expr),* $(,)?) => { <[_]>::into_vec(Box::new([$($elem),*])) };
+    ///     }
+    /// };
+    ///
+    /// let rules = item.parse_rules().unwrap();
+    /// assert_eq!(rules.rules.len(), 2);
+    ///
+    /// let matcher = &rules.rules[1].matcher;
+    /// match &matcher.content[0] {
+    ///     MacroTree::Repetition(repetition) => {
+    ///         assert_eq!(repetition.separator.as_ref().unwrap().to_string(), ",");
+    ///     }
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    pub struct MacroRules {
+        pub rules: Punctuated<MacroRule, Token![;]>,
+    }
+}
+
+ast_struct! {
+    /// A single rule of a `macro_rules!` definition: `($x:expr) => { ... }`.
+    ///
+    /// *This type is available only if Syn is built with the `"full"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    pub struct MacroRule {
+        pub matcher: MacroGroup,
+        pub fat_arrow_token: Token![=>],
+        pub transcriber: MacroGroup,
+    }
+}
+
+ast_struct! {
+    /// A delimited group inside of a macro rule, including the outermost
+    /// matcher and transcriber of the rule.
+    ///
+    /// *This type is available only if Syn is built with the `"full"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    pub struct MacroGroup {
+        pub delimiter: MacroDelimiter,
+        pub content: Vec<MacroTree>,
+    }
+}
+
+ast_enum_of_structs! {
+    /// A piece of the matcher or transcriber of a macro rule.
+    ///
+    /// *This type is available only if Syn is built with the `"full"` feature.*
+    ///
+    /// # Syntax tree enum
+    ///
+    /// This type is a [syntax tree enum].
+    ///
+    /// [syntax tree enum]: Expr#syntax-tree-enums
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    pub enum MacroTree {
+        /// A run of tokens matched or emitted as they are.
+        Verbatim(TokenStream),
+
+        /// A nested delimited group: `(...)`, `[...]` or `{...}`.
+        Group(MacroGroup),
+
+        /// A metavariable: `$x:expr` in a matcher, or `$x` in a transcriber.
+        Metavar(MacroMetavar),
+
+        /// A repetition: `$(...),*`.
+        Repetition(MacroRepetition),
+    }
+}
+
+ast_struct! {
+    /// A metavariable: `$x:expr` in a matcher, or `$x` in a transcriber.
+    ///
+    /// *This type is available only if Syn is built with the `"full"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    pub struct MacroMetavar {
+        pub dollar_token: Token![$],
+        pub name: Ident,
+        /// The fragment specifier, such as `expr` or `tt`. Always present in
+        /// a matcher and never in a transcriber.
+        pub specifier: Option<(Token![:], Ident)>,
+    }
+}
+
+ast_struct! {
+    /// A repetition inside of a macro rule: `$($x:expr),*`.
+    ///
+    /// *This type is available only if Syn is built with the `"full"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    pub struct MacroRepetition {
+        pub dollar_token: Token![$],
+        pub paren_token: token::Paren,
+        pub content: Vec<MacroTree>,
+        /// The separator between repeated elements, such as the `,` in
+        /// `$($x:expr),*`.
+        pub separator: Option<TokenStream>,
+        pub op: MacroRepOp,
+    }
+}
+
+ast_enum! {
+    /// The repetition operator of a macro repetition: `*`, `+` or `?`.
+    ///
+    /// *This type is available only if Syn is built with the `"full"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    pub enum MacroRepOp {
+        /// Zero or more repetitions: `*`.
+        ZeroOrMore(Token![*]),
+        /// One or more repetitions: `+`.
+        OneOrMore(Token![+]),
+        /// Zero or one repetition: `?`.
+        ZeroOrOne(Token![?]),
+    }
+}
+
+#[cfg(feature = "parsing")]
+pub mod parsing {
+    use super::*;
+    use crate::ext::IdentExt;
+    use crate::parse::{Parse, ParseStream, Result};
+    use proc_macro2::{Spacing, TokenTree};
+    use std::iter;
+    use std::mem;
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    impl Parse for MacroRules {
+        fn parse(input: ParseStream) -> Result<Self> {
+            Ok(MacroRules {
+                rules: input.parse_terminated(MacroRule::parse)?,
+            })
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    impl Parse for MacroRule {
+        fn parse(input: ParseStream) -> Result<Self> {
+            Ok(MacroRule {
+                matcher: macro_group(input, true)?,
+                fat_arrow_token: input.parse()?,
+                transcriber: macro_group(input, false)?,
+            })
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "parsing")))]
+    impl Parse for MacroRepOp {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let lookahead = input.lookahead1();
+            if lookahead.peek(Token![*]) {
+                input.parse().map(MacroRepOp::ZeroOrMore)
+            } else if lookahead.peek(Token![+]) {
+                input.parse().map(MacroRepOp::OneOrMore)
+            } else if lookahead.peek(Token![?]) {
+                input.parse().map(MacroRepOp::ZeroOrOne)
+            } else {
+                Err(lookahead.error())
+            }
+        }
+    }
+
+    fn macro_group(input: ParseStream, matcher: bool) -> Result<MacroGroup> {
+        let content;
+        let lookahead = input.lookahead1();
+        let delimiter = if lookahead.peek(token::Paren) {
+            MacroDelimiter::Paren(parenthesized!(content in input))
+        } else if lookahead.peek(token::Bracket) {
+            MacroDelimiter::Bracket(bracketed!(content in input))
+        } else if lookahead.peek(token::Brace) {
+            MacroDelimiter::Brace(braced!(content in input))
+        } else {
+            return Err(lookahead.error());
+        };
+        Ok(MacroGroup {
+            delimiter,
+            content: macro_trees(&content, matcher)?,
+        })
+    }
+
+    fn macro_trees(input: ParseStream, matcher: bool) -> Result<Vec<MacroTree>> {
+        let mut trees = Vec::new();
+        let mut verbatim = TokenStream::new();
+        while !input.is_empty() {
+            let tree = if input.peek(Token![$]) && input.peek2(Ident::peek_any) {
+                MacroTree::Metavar(macro_metavar(input, matcher)?)
+            } else if input.peek(Token![$]) && input.peek2(token::Paren) {
+                MacroTree::Repetition(macro_repetition(input, matcher)?)
+            } else if input.peek(token::Paren)
+                || input.peek(token::Bracket)
+                || input.peek(token::Brace)
+            {
+                MacroTree::Group(macro_group(input, matcher)?)
+            } else {
+                let tt: TokenTree = input.parse()?;
+                verbatim.extend(iter::once(tt));
+                continue;
+            };
+            if !verbatim.is_empty() {
+                trees.push(MacroTree::Verbatim(mem::replace(
+                    &mut verbatim,
+                    TokenStream::new(),
+                )));
+            }
+            trees.push(tree);
+        }
+        if !verbatim.is_empty() {
+            trees.push(MacroTree::Verbatim(verbatim));
+        }
+        Ok(trees)
+    }
+
+    fn macro_metavar(input: ParseStream, matcher: bool) -> Result<MacroMetavar> {
+        let dollar_token: Token![$] = input.parse()?;
+        let name = input.call(Ident::parse_any)?;
+        let specifier = if matcher {
+            let colon_token: Token![:] = input.parse()?;
+            let specifier = input.call(Ident::parse_any)?;
+            if !is_fragment_specifier(&specifier) {
+                let msg = format!("invalid fragment specifier `{}`", specifier);
+                return Err(Error::new(specifier.span(), msg));
+            }
+            Some((colon_token, specifier))
+        } else {
+            None
+        };
+        Ok(MacroMetavar {
+            dollar_token,
+            name,
+            specifier,
+        })
+    }
+
+    fn is_fragment_specifier(ident: &Ident) -> bool {
+        const SPECIFIERS: &[&str] = &[
+            "block",
+            "expr",
+            "expr_2021",
+            "ident",
+            "item",
+            "lifetime",
+            "literal",
+            "meta",
+            "pat",
+            "pat_param",
+            "path",
+            "stmt",
+            "tt",
+            "ty",
+            "vis",
+        ];
+        SPECIFIERS.iter().any(|specifier| ident == specifier)
+    }
+
+    fn macro_repetition(input: ParseStream, matcher: bool) -> Result<MacroRepetition> {
+        let content;
+        Ok(MacroRepetition {
+            dollar_token: input.parse()?,
+            paren_token: parenthesized!(content in input),
+            content: macro_trees(&content, matcher)?,
+            separator: {
+                if is_rep_op(input) {
+                    None
+                } else {
+                    Some(repetition_separator(input)?)
+                }
+            },
+            op: input.parse()?,
+        })
+    }
+
+    fn is_rep_op(input: ParseStream) -> bool {
+        input.peek(Token![*]) || input.peek(Token![+]) || input.peek(Token![?])
+    }
+
+    // The separator is a single Rust token, which may consist of several
+    // joint punctuation characters such as `=>`.
+    fn repetition_separator(input: ParseStream) -> Result<TokenStream> {
+        input.step(|cursor| {
+            let mut separator = TokenStream::new();
+            let mut rest = *cursor;
+            loop {
+                match rest.token_tree() {
+                    Some((TokenTree::Group(_), _)) | None => break,
+                    Some((TokenTree::Punct(punct), next)) => {
+                        let spacing = punct.spacing();
+                        separator.extend(iter::once(TokenTree::Punct(punct)));
+                        rest = next;
+                        let glued = match rest.punct() {
+                            Some((next, _)) => {
+                                spacing == Spacing::Joint && !"*+?".contains(next.as_char())
+                            }
+                            None => false,
+                        };
+                        if !glued {
+                            break;
+                        }
+                    }
+                    Some((tt, next)) => {
+                        separator.extend(iter::once(tt));
+                        rest = next;
+                        break;
+                    }
+                }
+            }
+            if separator.is_empty() {
+                Err(cursor.error("expected repetition separator or operator"))
+            } else {
+                Ok((separator, rest))
+            }
+        })
+    }
+}
+
+#[cfg(feature = "printing")]
+mod printing {
+    use super::*;
+    use proc_macro2::TokenStream;
+    use quote::{ToTokens, TokenStreamExt};
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for MacroRules {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.rules.to_tokens(tokens);
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for MacroRule {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.matcher.to_tokens(tokens);
+            self.fat_arrow_token.to_tokens(tokens);
+            self.transcriber.to_tokens(tokens);
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for MacroGroup {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            match &self.delimiter {
+                MacroDelimiter::Paren(paren) => {
+                    paren.surround(tokens, |tokens| tokens.append_all(&self.content));
+                }
+                MacroDelimiter::Brace(brace) => {
+                    brace.surround(tokens, |tokens| tokens.append_all(&self.content));
+                }
+                MacroDelimiter::Bracket(bracket) => {
+                    bracket.surround(tokens, |tokens| tokens.append_all(&self.content));
+                }
+            }
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for MacroMetavar {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.dollar_token.to_tokens(tokens);
+            self.name.to_tokens(tokens);
+            if let Some((colon_token, specifier)) = &self.specifier {
+                colon_token.to_tokens(tokens);
+                specifier.to_tokens(tokens);
+            }
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for MacroRepetition {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.dollar_token.to_tokens(tokens);
+            self.paren_token.surround(tokens, |tokens| {
+                tokens.append_all(&self.content);
+            });
+            self.separator.to_tokens(tokens);
+            self.op.to_tokens(tokens);
+        }
+    }
+
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+    impl ToTokens for MacroRepOp {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            match self {
+                MacroRepOp::ZeroOrMore(t) => t.to_tokens(tokens),
+                MacroRepOp::OneOrMore(t) => t.to_tokens(tokens),
+                MacroRepOp::ZeroOrOne(t) => t.to_tokens(tokens),
+            }
+        }
+    }
+}
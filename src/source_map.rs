@@ -0,0 +1,274 @@
+//! Locations in the original source text of spans from parsed strings.
+//!
+//! *This module is available only if Syn is built with the `"parsing"` and
+//! `"span-locations"` features, along with `"derive"` or `"full"`.*
+//!
+//! Code parsed from a string outside of a procedural macro has spans that
+//! know their line and column within that string, but not which string they
+//! came from. A [`SourceMap`] parses any number of files and keeps track of
+//! which one every span belongs to, so that the span of a syntax tree node or
+//! of an [`Error`] can be turned into a file name and a byte range of that
+//! file's text.
+//!
+//! # Example
+//!
+//! ```
+//! use syn::source_map::SourceMap;
+//! use syn::ItemStruct;
+//!
+//! let mut source_map = SourceMap::new();
+//! source_map.parse_str::<ItemStruct>("a.rs", "struct A;").unwrap();
+//!
+//! let err = source_map
+//!     .parse_str::<ItemStruct>("b.rs", "struct B {\n    x: u8\n    y: u8,\n}")
+//!     .unwrap_err();
+//! let location = err.location(&source_map).unwrap();
+//! assert_eq!(location.file, "b.rs");
+//! assert_eq!(&location.source[location.byte_range], "y");
+//! assert_eq!((location.start.line, location.start.column), (3, 4));
+//! ```
+//!
+//! Spans of parsed strings refer to information that proc-macro2 keeps in a
+//! thread local, so a `SourceMap` is only able to locate spans from strings
+//! parsed on the same thread as it.
+
+use crate::parse::{Parse, Parser};
+#[cfg(feature = "full")]
+use crate::File;
+use crate::{Error, Result};
+use proc_macro2::{LineColumn, Span};
+use std::fmt::{self, Debug};
+use std::ops::Range;
+
+/// A collection of parsed files that resolves spans to locations within
+/// them.
+pub struct SourceMap {
+    files: Vec<SourceFile>,
+}
+
+/// The location of a span within a file of a [`SourceMap`].
+pub struct Location<'a> {
+    /// The name that the file was added to the source map with.
+    pub file: &'a str,
+    /// The full text of the file.
+    pub source: &'a str,
+    /// The bytes of `source` covered by the span.
+    pub byte_range: Range<usize>,
+    /// The line and column at which the span begins. Lines are 1-based and
+    /// columns are 0-based, counted in characters.
+    pub start: LineColumn,
+    /// The line and column at which the span ends.
+    pub end: LineColumn,
+}
+
+impl SourceMap {
+    /// Creates a source map that does not hold any files yet.
+    pub fn new() -> Self {
+        SourceMap { files: Vec::new() }
+    }
+
+    /// Parses the content of a Rust source file, as with [`syn::parse_file`],
+    /// and adds it to the source map under the given name.
+    ///
+    /// [`syn::parse_file`]: crate::parse_file()
+    ///
+    /// *This method is available only if Syn is built with the `"full"`
+    /// feature.*
+    #[cfg(feature = "full")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    pub fn parse_file(&mut self, name: &str, text: &str) -> Result<File> {
+        let (shebang, content) = crate::split_shebang(text);
+        let offset = text.len() - content.len();
+        let mut file: File = self.parse(File::parse, name, text, offset)?;
+        file.shebang = shebang;
+        Ok(file)
+    }
+
+    /// Parses a string of Rust code into the chosen syntax tree node, as with
+    /// [`syn::parse_str`], and adds it to the source map under the given
+    /// name.
+    ///
+    /// [`syn::parse_str`]: crate::parse_str()
+    pub fn parse_str<T: Parse>(&mut self, name: &str, text: &str) -> Result<T> {
+        self.parse(T::parse, name, text, 0)
+    }
+
+    /// Parses a string of Rust code with the given parser and adds it to the
+    /// source map under the given name.
+    pub fn parse_with<F: Parser>(
+        &mut self,
+        parser: F,
+        name: &str,
+        text: &str,
+    ) -> Result<F::Output> {
+        self.parse(parser, name, text, 0)
+    }
+
+    fn parse<F: Parser>(
+        &mut self,
+        parser: F,
+        name: &str,
+        text: &str,
+        offset: usize,
+    ) -> Result<F::Output> {
+        let mut file = SourceFile::new(name, text, offset);
+        let tokens = match crate::error::lex(&text[offset..]) {
+            Ok(tokens) => tokens,
+            Err(err) => {
+                let err = Error::from(err);
+                file.span = Some(err.span());
+                self.files.push(file);
+                return Err(err);
+            }
+        };
+        let first = tokens.clone().into_iter().next();
+        let last = tokens.clone().into_iter().last();
+        file.span = match (&first, &last) {
+            (Some(first), Some(last)) => first.span().join(last.span()),
+            _ => None,
+        };
+        self.files.push(file);
+
+        let scope = crate::parse::end_of_input(last);
+        crate::parse::parse_scoped(parser, scope, tokens)
+    }
+
+    /// Finds the file that a span belongs to and the location of the span in
+    /// it.
+    ///
+    /// Returns `None` for spans that do not come from a file in the source
+    /// map, including those created by `Span::call_site()`.
+    pub fn locate(&self, span: Span) -> Option<Location<'_>> {
+        self.locate_between(span, span)
+    }
+
+    // The location from the start of one span to the end of another in the
+    // same file.
+    pub(crate) fn locate_between(&self, start: Span, end: Span) -> Option<Location<'_>> {
+        let file = self.files.iter().find(|file| file.contains(start))?;
+        if !file.contains(end) {
+            return None;
+        }
+        let start = start.start();
+        let end = end.end();
+        Some(Location {
+            file: &file.name,
+            source: &file.text,
+            byte_range: file.byte(start)..file.byte(end),
+            start,
+            end,
+        })
+    }
+}
+
+impl Default for SourceMap {
+    fn default() -> Self {
+        SourceMap::new()
+    }
+}
+
+impl<'a> Clone for Location<'a> {
+    fn clone(&self) -> Self {
+        Location {
+            file: self.file,
+            source: self.source,
+            byte_range: self.byte_range.clone(),
+            start: self.start,
+            end: self.end,
+        }
+    }
+}
+
+impl<'a> Debug for Location<'a> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("Location")
+            .field("file", &self.file)
+            .field("byte_range", &self.byte_range)
+            .field("start", &self.start)
+            .field("end", &self.end)
+            .finish()
+    }
+}
+
+// The text of a file, and a span that belongs to it for identifying other
+// spans from the same file.
+pub(crate) struct SourceFile {
+    name: String,
+    text: String,
+    // Byte offset at which the lexed content begins, after any BOM and
+    // shebang.
+    offset: usize,
+    // Byte offsets of the beginnings of lines, relative to `offset`.
+    lines: Vec<usize>,
+    span: Option<Span>,
+}
+
+impl SourceFile {
+    pub(crate) fn new(name: &str, text: &str, offset: usize) -> Self {
+        let mut lines = vec![0];
+        lines.extend(text[offset..].match_indices('\n').map(|(i, _)| i + 1));
+        SourceFile {
+            name: name.to_owned(),
+            text: text.to_owned(),
+            offset,
+            lines,
+            span: None,
+        }
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn text(&self) -> &str {
+        &self.text
+    }
+
+    // The text of a line, by 1-based line number, without its line ending.
+    pub(crate) fn line(&self, line: usize) -> Option<&str> {
+        let start = self.offset + *self.lines.get(line.checked_sub(1)?)?;
+        let text = &self.text[start..];
+        let text = match text.find('\n') {
+            Some(end) => &text[..end],
+            None => text,
+        };
+        Some(text.trim_end_matches('\r'))
+    }
+
+    fn contains(&self, span: Span) -> bool {
+        match self.span {
+            Some(file) => file.join(span).is_some(),
+            None => false,
+        }
+    }
+
+    pub(crate) fn byte(&self, location: LineColumn) -> usize {
+        let start = self.offset + self.lines[location.line - 1];
+        let column = self.text[start..]
+            .char_indices()
+            .nth(location.column)
+            .map_or(self.text.len() - start, |(i, _ch)| i);
+        start + column
+    }
+}
+
+#[cfg(all(feature = "full", feature = "printing"))]
+impl SourceFile {
+    pub(crate) fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub(crate) fn set_span(&mut self, span: Span) {
+        self.span = Some(span);
+    }
+
+    // The byte range of a span in the text, if it is from this file.
+    pub(crate) fn byte_range(&self, span: Span) -> Option<Range<usize>> {
+        if self.contains(span) {
+            Some(self.byte(span.start())..self.byte(span.end()))
+        } else {
+            None
+        }
+    }
+}
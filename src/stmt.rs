@@ -41,11 +41,70 @@ ast_struct! {
         pub attrs: Vec<Attribute>,
         pub let_token: Token![let],
         pub pat: Pat,
-        pub init: Option<(Token![=], Box<Expr>)>,
+        pub init: Option<LocalInit>,
         pub semi_token: Token![;],
     }
 }
 
+ast_struct! {
+    /// The expression assigned in a local `let` binding, along with the
+    /// diverging `else` block of a `let`-`else` statement.
+    ///
+    /// `LocalInit` represents `= s.parse()?` in `let x: u64 = s.parse()?` and
+    /// `= opt else { return; }` in `let Some(x) = opt else { return; };`.
+    ///
+    /// *This type is available only if Syn is built with the `"full"` feature.*
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "full")))]
+    pub struct LocalInit {
+        pub eq_token: Token![=],
+        pub expr: Box<Expr>,
+        pub diverge: Option<(Token![else], Block)>,
+    }
+}
+
+// Whether the expression ends in a `}`, which would make `let PAT = EXPR
+// else { ... };` ambiguous with an `if`-`else`.
+//
+// see https://github.com/rust-lang/rust/blob/1.65.0/compiler/rustc_ast/src/util/classify.rs#L54-L90
+#[cfg(any(feature = "parsing", feature = "printing"))]
+fn expr_trailing_brace(mut expr: &Expr) -> bool {
+    loop {
+        match expr {
+            Expr::Async(_)
+            | Expr::Block(_)
+            | Expr::Const(_)
+            | Expr::ForLoop(_)
+            | Expr::If(_)
+            | Expr::Loop(_)
+            | Expr::Match(_)
+            | Expr::Struct(_)
+            | Expr::TryBlock(_)
+            | Expr::Unsafe(_)
+            | Expr::While(_) => return true,
+            Expr::Macro(e) => {
+                return match e.mac.delimiter {
+                    MacroDelimiter::Brace(_) => true,
+                    MacroDelimiter::Paren(_) | MacroDelimiter::Bracket(_) => false,
+                };
+            }
+            Expr::Assign(e) => expr = &e.right,
+            Expr::AssignOp(e) => expr = &e.right,
+            Expr::Binary(e) => expr = &e.right,
+            Expr::Box(e) => expr = &e.expr,
+            Expr::Closure(e) => expr = &e.body,
+            Expr::Let(e) => expr = &e.expr,
+            Expr::RawAddr(e) => expr = &e.expr,
+            Expr::Reference(e) => expr = &e.expr,
+            Expr::Unary(e) => expr = &e.expr,
+            Expr::Break(ExprBreak { expr: Some(e), .. })
+            | Expr::Return(ExprReturn { expr: Some(e), .. })
+            | Expr::Yield(ExprYield { expr: Some(e), .. })
+            | Expr::Range(ExprRange { to: Some(e), .. }) => expr = e,
+            _ => return false,
+        }
+    }
+}
+
 #[cfg(feature = "parsing")]
 pub mod parsing {
     use super::*;
@@ -151,7 +210,7 @@ pub mod parsing {
         }
     }
 
-    fn parse_stmt(input: ParseStream, allow_nosemi: bool) -> Result<Stmt> {
+    pub(crate) fn parse_stmt(input: ParseStream, allow_nosemi: bool) -> Result<Stmt> {
         let mut attrs = input.call(Attribute::parse_outer)?;
 
         // brace-style macros; paren and bracket macros get parsed as
@@ -220,32 +279,60 @@ pub mod parsing {
     }
 
     fn stmt_local(input: ParseStream, attrs: Vec<Attribute>) -> Result<Local> {
-        Ok(Local {
-            attrs,
-            let_token: input.parse()?,
-            pat: {
-                let mut pat: Pat = pat::parsing::multi_pat_with_leading_vert(input)?;
-                if input.peek(Token![:]) {
-                    let colon_token: Token![:] = input.parse()?;
-                    let ty: Type = input.parse()?;
-                    pat = Pat::Type(PatType {
-                        attrs: Vec::new(),
-                        pat: Box::new(pat),
-                        colon_token,
-                        ty: Box::new(ty),
-                    });
+        let let_token: Token![let] = input.parse()?;
+
+        let mut pat: Pat = pat::parsing::multi_pat_with_leading_vert(input)?;
+        if input.peek(Token![:]) {
+            let colon_token: Token![:] = input.parse()?;
+            let ty: Type = input.parse()?;
+            pat = Pat::Type(PatType {
+                attrs: Vec::new(),
+                pat: Box::new(pat),
+                colon_token,
+                ty: Box::new(ty),
+            });
+        }
+
+        let mut init = None;
+        if input.peek(Token![=]) {
+            let eq_token: Token![=] = input.parse()?;
+            let expr: Expr = input.parse()?;
+            let mut diverge = None;
+            if input.peek(Token![else]) {
+                match &expr {
+                    Expr::Binary(ExprBinary {
+                        op: BinOp::And(_), ..
+                    })
+                    | Expr::Binary(ExprBinary {
+                        op: BinOp::Or(_), ..
+                    }) => {
+                        return Err(input.error(
+                            "a lazy boolean expression cannot be directly assigned in `let...else`",
+                        ));
+                    }
+                    _ => {}
                 }
-                pat
-            },
-            init: {
-                if input.peek(Token![=]) {
-                    let eq_token: Token![=] = input.parse()?;
-                    let init: Expr = input.parse()?;
-                    Some((eq_token, Box::new(init)))
-                } else {
-                    None
+                if expr_trailing_brace(&expr) {
+                    return Err(input.error(
+                        "right curly brace `}` before `else` in a `let...else` statement not allowed",
+                    ));
                 }
-            },
+                let else_token: Token![else] = input.parse()?;
+                let block: Block = input.parse()?;
+                diverge = Some((else_token, block));
+            }
+            init = Some(LocalInit {
+                eq_token,
+                expr: Box::new(expr),
+                diverge,
+            });
+        }
+
+        Ok(Local {
+            attrs,
+            let_token,
+            pat,
+            init,
             semi_token: input.parse()?,
         })
     }
@@ -279,6 +366,7 @@ pub mod parsing {
 #[cfg(feature = "printing")]
 mod printing {
     use super::*;
+    use crate::precedence;
     use proc_macro2::TokenStream;
     use quote::{ToTokens, TokenStreamExt};
 
@@ -297,24 +385,55 @@ mod printing {
             match self {
                 Stmt::Local(local) => local.to_tokens(tokens),
                 Stmt::Item(item) => item.to_tokens(tokens),
-                Stmt::Expr(expr) => expr.to_tokens(tokens),
+                Stmt::Expr(expr) => expr_stmt_to_tokens(expr, tokens),
                 Stmt::Semi(expr, semi) => {
-                    expr.to_tokens(tokens);
+                    expr_stmt_to_tokens(expr, tokens);
                     semi.to_tokens(tokens);
                 }
             }
         }
     }
 
+    // Prints an expression at the start of a statement, where a `let` would
+    // begin a local binding and a block-like expression would end the
+    // statement early unless parenthesized.
+    fn expr_stmt_to_tokens(expr: &Expr, tokens: &mut TokenStream) {
+        if let Expr::Let(_) = expr {
+            token::Paren::default().surround(tokens, |tokens| expr.to_tokens(tokens));
+            return;
+        }
+        match precedence::leading_block(expr) {
+            Some((block, attrs)) => {
+                // Every attribute is printed as a `#` and a bracketed group.
+                let mut printed = expr.to_token_stream().into_iter();
+                tokens.extend(printed.by_ref().take(2 * attrs));
+                let len = block.to_token_stream().into_iter().count();
+                token::Paren::default().surround(tokens, |tokens| {
+                    tokens.extend(printed.by_ref().take(len));
+                });
+                tokens.extend(printed);
+            }
+            None => expr.to_tokens(tokens),
+        }
+    }
+
     #[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
     impl ToTokens for Local {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             expr::printing::outer_attrs_to_tokens(&self.attrs, tokens);
             self.let_token.to_tokens(tokens);
             self.pat.to_tokens(tokens);
-            if let Some((eq_token, init)) = &self.init {
-                eq_token.to_tokens(tokens);
-                init.to_tokens(tokens);
+            if let Some(init) = &self.init {
+                init.eq_token.to_tokens(tokens);
+                if init.diverge.is_some() && expr_trailing_brace(&init.expr) {
+                    token::Paren::default().surround(tokens, |tokens| init.expr.to_tokens(tokens));
+                } else {
+                    init.expr.to_tokens(tokens);
+                }
+                if let Some((else_token, diverge)) = &init.diverge {
+                    else_token.to_tokens(tokens);
+                    diverge.to_tokens(tokens);
+                }
             }
             self.semi_token.to_tokens(tokens);
         }
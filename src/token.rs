@@ -8,14 +8,14 @@
 //!
 //! # Example
 //!
-//! The [`ItemStatic`] syntax tree node is defined like this.
+//! The [`ForeignItemStatic`] syntax tree node is defined like this.
 //!
-//! [`ItemStatic`]: crate::ItemStatic
+//! [`ForeignItemStatic`]: crate::ForeignItemStatic
 //!
 //! ```
-//! # use syn::{Attribute, Expr, Ident, Token, Type, Visibility};
+//! # use syn::{Attribute, Ident, Token, Type, Visibility};
 //! #
-//! pub struct ItemStatic {
+//! pub struct ForeignItemStatic {
 //!     pub attrs: Vec<Attribute>,
 //!     pub vis: Visibility,
 //!     pub static_token: Token![static],
@@ -23,8 +23,6 @@
 //!     pub ident: Ident,
 //!     pub colon_token: Token![:],
 //!     pub ty: Box<Type>,
-//!     pub eq_token: Token![=],
-//!     pub expr: Box<Expr>,
 //!     pub semi_token: Token![;],
 //! }
 //! ```
@@ -44,14 +42,14 @@
 //! use syn::{Attribute, Result};
 //! use syn::parse::{Parse, ParseStream};
 //! #
-//! # enum ItemStatic {}
+//! # enum ForeignItemStatic {}
 //!
-//! // Parse the ItemStatic struct shown above.
-//! impl Parse for ItemStatic {
+//! // Parse the ForeignItemStatic struct shown above.
+//! impl Parse for ForeignItemStatic {
 //!     fn parse(input: ParseStream) -> Result<Self> {
-//!         # use syn::ItemStatic;
-//!         # fn parse(input: ParseStream) -> Result<ItemStatic> {
-//!         Ok(ItemStatic {
+//!         # use syn::ForeignItemStatic;
+//!         # fn parse(input: ParseStream) -> Result<ForeignItemStatic> {
+//!         Ok(ForeignItemStatic {
 //!             attrs: input.call(Attribute::parse_outer)?,
 //!             vis: input.parse()?,
 //!             static_token: input.parse()?,
@@ -59,8 +57,6 @@
 //!             ident: input.parse()?,
 //!             colon_token: input.parse()?,
 //!             ty: input.parse()?,
-//!             eq_token: input.parse()?,
-//!             expr: input.parse()?,
 //!             semi_token: input.parse()?,
 //!         })
 //!         # }
@@ -158,7 +154,7 @@ fn peek_impl(cursor: Cursor, peek: fn(ParseStream) -> bool) -> bool {
 
     let scope = Span::call_site();
     let unexpected = Rc::new(Cell::new(Unexpected::None));
-    let buffer = crate::parse::new_parse_buffer(scope, cursor, unexpected);
+    let buffer = crate::parse::new_parse_buffer(scope, cursor, unexpected, false);
     peek(&buffer)
 }
 
@@ -683,6 +679,7 @@ define_keywords! {
     "override"    pub struct Override     /// `override`
     "priv"        pub struct Priv         /// `priv`
     "pub"         pub struct Pub          /// `pub`
+    "raw"         pub struct Raw          /// `raw`
     "ref"         pub struct Ref          /// `ref`
     "return"      pub struct Return       /// `return`
     "Self"        pub struct SelfType     /// `Self`
@@ -805,6 +802,7 @@ macro_rules! export_token_macro {
             [override]    => { $crate::token::Override };
             [priv]        => { $crate::token::Priv };
             [pub]         => { $crate::token::Pub };
+            [raw]         => { $crate::token::Raw };
             [ref]         => { $crate::token::Ref };
             [return]      => { $crate::token::Return };
             [Self]        => { $crate::token::SelfType };
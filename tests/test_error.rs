@@ -0,0 +1,231 @@
+use proc_macro2::Span;
+use quote::quote;
+use std::panic;
+use std::thread;
+use syn::{Error, ErrorCollector, LitStr};
+
+#[test]
+fn test_collect_errors() {
+    let errors = ErrorCollector::new();
+    assert!(errors.is_empty());
+
+    errors.push(Error::new(Span::call_site(), "first"));
+    let lit = errors.handle(syn::parse2::<LitStr>(quote!("ok")));
+    assert_eq!(lit.unwrap().value(), "ok");
+    let lit = errors.handle(syn::parse2::<LitStr>(quote!(0)));
+    assert!(lit.is_none());
+    errors.push_spanned(quote!(a + b), "second");
+    assert!(!errors.is_empty());
+
+    let err = errors.check().unwrap_err();
+    let messages: Vec<String> = err.into_iter().map(|err| err.to_string()).collect();
+    assert_eq!(messages, ["first", "expected string literal", "second"]);
+
+    ErrorCollector::new().check().unwrap();
+}
+
+#[test]
+fn test_finish_with_partial_output() {
+    let errors = ErrorCollector::new();
+    let output = errors.finish(quote!(impl Trait for S {}));
+    assert_eq!(output.to_string(), "impl Trait for S { }");
+
+    let errors = ErrorCollector::new();
+    errors.push(Error::new(Span::call_site(), "unsupported"));
+    let output = errors.finish(quote!(impl Trait for S {}));
+    assert_eq!(
+        output.to_string(),
+        "impl Trait for S { } compile_error ! { \"unsupported\" }",
+    );
+}
+
+#[test]
+fn test_drop_unchecked() {
+    let result = panic::catch_unwind(|| {
+        let errors = ErrorCollector::new();
+        errors.push(Error::new(Span::call_site(), "lost"));
+    });
+    assert_eq!(result.is_err(), cfg!(debug_assertions));
+}
+
+#[test]
+fn test_render() {
+    let source = "struct S {\n\tx: u8\n\ty: u8,\n}\n";
+    let mut err = syn::parse_str::<syn::ItemStruct>(source).unwrap_err();
+    let item: syn::ItemStruct = syn::parse_str("struct T {\n    a: u8,\n    b: u8,\n}").unwrap();
+    err.combine(Error::new_spanned(
+        &item.fields,
+        "fields of the wrong struct",
+    ));
+    err.combine(Error::new(Span::call_site(), "no location"));
+
+    let expected = "\
+error: expected `,`
+ --> lib.rs:3:2
+  |
+3 |     y: u8,
+  |     ^
+
+error: fields of the wrong struct
+ --> lib.rs
+
+error: no location
+ --> lib.rs
+";
+    assert_eq!(err.render("lib.rs", source), expected);
+}
+
+#[test]
+fn test_render_after_many_parses() {
+    for i in 0..100 {
+        syn::parse_str::<syn::Expr>(&format!("x + {}", i)).unwrap();
+    }
+    let source = "fn f() {\n    let x = ;\n}\n";
+    let err = syn::parse_file(source).unwrap_err();
+    let expected = "\
+error: expected expression
+ --> lib.rs:2:13
+  |
+2 |     let x = ;
+  |             ^
+";
+    assert_eq!(err.render("lib.rs", source), expected);
+}
+
+#[test]
+fn test_render_json() {
+    let source = "fn main() {\n    let x = \"é\" + ;\n}\n";
+    let err = syn::parse_file(source).unwrap_err();
+    let expected = concat!(
+        r#"{"message":"expected expression","code":null,"level":"error","spans":[{"file_name":"src/main.rs","#,
+        r#""byte_start":31,"byte_end":32,"line_start":2,"line_end":2,"column_start":19,"column_end":20,"#,
+        r#""is_primary":true,"label":null}],"children":[],"rendered":"error: expected expression\n"#,
+        r#" --> src/main.rs:2:19\n  |\n2 |     let x = \"é\" + ;\n  |                   ^\n"}"#,
+        "\n",
+    );
+    assert_eq!(err.render_json("src/main.rs", source), expected);
+}
+
+#[test]
+fn test_warning() {
+    let warning = Error::warning(Span::call_site(), "unused attribute").help("remove it");
+    let tokens = warning.to_compile_error().to_string();
+    let name = tokens.split(' ').nth(4).unwrap();
+    assert!(name.starts_with("__syn_warning_"));
+    assert_eq!(
+        tokens.replacen(name, "NAME", 1),
+        "# [allow (dead_code)] fn NAME () { \
+         # [deprecated (note = \"unused attribute\\n= help: remove it\")] \
+         # [allow (non_upper_case_globals)] \
+         const warning : () = () ; \
+         let _ = warning ; \
+         }",
+    );
+
+    // Warnings in the same module must not define the same function.
+    let again = warning.to_compile_error().to_string();
+    assert_ne!(again.split(' ').nth(4).unwrap(), name);
+
+    // The same expansion gets the same names from one build to the next.
+    let first = || {
+        thread::spawn(|| {
+            let warning = Error::warning(Span::call_site(), "unused attribute");
+            warning.to_compile_error().to_string()
+        })
+        .join()
+        .unwrap()
+    };
+    assert_eq!(first(), first());
+}
+
+#[test]
+fn test_children() {
+    let err = Error::new(Span::call_site(), "duplicate field")
+        .span_label(Span::call_site(), "first defined here")
+        .note("fields must have distinct names")
+        .span_help(Span::call_site(), "rename this field");
+    assert_eq!(
+        err.clone().to_compile_error().to_string(),
+        "compile_error ! { \"duplicate field\\n= note: fields must have distinct names\" } \
+         compile_error ! { \"first defined here\" } \
+         compile_error ! { \"help: rename this field\" }",
+    );
+}
+
+#[test]
+fn test_render_children() {
+    let source = "struct S {\n    id: u64,\n    name: String,\n    id: u64,\n}\n";
+    let item: syn::ItemStruct = syn::parse_str(source).unwrap();
+    let fields: Vec<&syn::Ident> = item
+        .fields
+        .iter()
+        .map(|field| field.ident.as_ref().unwrap())
+        .collect();
+    let mut err = Error::new(fields[2].span(), "duplicate field `id`")
+        .span_label(fields[0].span(), "first defined here")
+        .note("fields must have distinct names");
+    err.combine(
+        Error::warning(item.ident.span(), "struct is never constructed")
+            .span_help(fields[1].span(), "or remove this field"),
+    );
+
+    let expected = "\
+error: duplicate field `id`
+ --> lib.rs:4:5
+  |
+2 |     id: u64,
+  |     -- first defined here
+3 |     name: String,
+4 |     id: u64,
+  |     ^^
+  |
+  = note: fields must have distinct names
+
+warning: struct is never constructed
+ --> lib.rs:1:8
+  |
+1 | struct S {
+  |        ^
+help: or remove this field
+ --> lib.rs:3:5
+  |
+3 |     name: String,
+  |     ^^^^
+";
+    assert_eq!(err.render("lib.rs", source), expected);
+
+    let json = err.render_json("lib.rs", source);
+    let first = json.lines().next().unwrap();
+    assert!(first.starts_with(
+        r#"{"message":"duplicate field `id`","code":null,"level":"error","spans":[{"file_name":"lib.rs","byte_start":46,"#
+    ));
+    assert!(first.contains(r#""is_primary":false,"label":"first defined here"}],"children":[{"message":"fields must have distinct names","code":null,"level":"note","spans":[],"children":[],"rendered":null}]"#));
+    let second = json.lines().nth(1).unwrap();
+    assert!(second.contains(r#""level":"warning""#));
+    assert!(second.contains(r#""level":"help","spans":[{"file_name":"lib.rs","byte_start":28,"#));
+}
+
+#[test]
+fn test_send_to_other_thread() {
+    let source = "fn main() {\n    let x = ;\n}\n";
+    let err = thread::spawn(move || syn::parse_file(source).unwrap_err())
+        .join()
+        .unwrap();
+
+    assert_eq!(err.span().start(), Span::call_site().start());
+    assert_eq!((err.start().line, err.start().column), (2, 12));
+    assert_eq!((err.end().line, err.end().column), (2, 13));
+
+    let expected = "\
+error: expected expression
+ --> main.rs:2:13
+  |
+2 |     let x = ;
+  |             ^
+";
+    assert_eq!(err.render("main.rs", source), expected);
+    assert_eq!(err.clone().render("main.rs", source), expected);
+
+    let err = Error::new(Span::call_site(), "no location");
+    assert_eq!(err.start().line, 0);
+}
@@ -4,7 +4,8 @@ mod macros;
 use proc_macro2::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
 use quote::quote;
 use std::iter::FromIterator;
-use syn::{Expr, ExprRange};
+use syn::parse::{ParseStream, Parser};
+use syn::{Expr, ExprParser, ExprRange, Precedence, Token};
 
 #[test]
 fn test_expr_parse() {
@@ -318,3 +319,190 @@ fn test_closure_vs_rangefull() {
     }
     "###);
 }
+
+#[test]
+fn test_raw_addr() {
+    let tokens = quote!(&raw const x);
+    snapshot!(tokens as Expr, @r###"
+    Expr::RawAddr {
+        mutability: Const,
+        expr: Expr::Path {
+            path: Path {
+                segments: [
+                    PathSegment {
+                        ident: "x",
+                        arguments: None,
+                    },
+                ],
+            },
+        },
+    }
+    "###);
+
+    let tokens = quote!(&raw mut *p);
+    snapshot!(tokens as Expr, @r###"
+    Expr::RawAddr {
+        mutability: Mut,
+        expr: Expr::Unary {
+            op: Deref,
+            expr: Expr::Path {
+                path: Path {
+                    segments: [
+                        PathSegment {
+                            ident: "p",
+                            arguments: None,
+                        },
+                    ],
+                },
+            },
+        },
+    }
+    "###);
+
+    // Not a raw borrow: a reference to a variable named `raw`.
+    let tokens = quote!(&raw);
+    snapshot!(tokens as Expr, @r###"
+    Expr::Reference {
+        expr: Expr::Path {
+            path: Path {
+                segments: [
+                    PathSegment {
+                        ident: "raw",
+                        arguments: None,
+                    },
+                ],
+            },
+        },
+    }
+    "###);
+}
+
+#[test]
+fn test_const_block() {
+    let tokens = quote!(
+        const {
+            #![allow(unused)]
+            1 + 1
+        }
+    );
+    snapshot!(tokens as Expr, @r###"
+    Expr::Const {
+        attrs: [
+            Attribute {
+                style: Inner,
+                path: Path {
+                    segments: [
+                        PathSegment {
+                            ident: "allow",
+                            arguments: None,
+                        },
+                    ],
+                },
+                tokens: TokenStream(`(unused)`),
+            },
+        ],
+        block: Block {
+            stmts: [
+                Expr(Expr::Binary {
+                    left: Expr::Lit {
+                        lit: 1,
+                    },
+                    op: Add,
+                    right: Expr::Lit {
+                        lit: 1,
+                    },
+                }),
+            ],
+        },
+    }
+    "###);
+}
+
+#[test]
+fn test_expr_parser() {
+    let tokens = quote!(x {});
+    let (expr, rest) = (|input: ParseStream| {
+        let expr = ExprParser::new()
+            .without_eager_brace()
+            .parse_stream(input)?;
+        let rest: TokenStream = input.parse()?;
+        Ok((expr, rest))
+    })
+    .parse2(tokens.clone())
+    .unwrap();
+    snapshot!(expr, @r###"
+    Expr::Path {
+        path: Path {
+            segments: [
+                PathSegment {
+                    ident: "x",
+                    arguments: None,
+                },
+            ],
+        },
+    }
+    "###);
+    assert_eq!(rest.to_string(), "{ }");
+    snapshot!(ExprParser::new().parse2(tokens).unwrap(), @r###"
+    Expr::Struct {
+        path: Path {
+            segments: [
+                PathSegment {
+                    ident: "x",
+                    arguments: None,
+                },
+            ],
+        },
+    }
+    "###);
+
+    let tokens = quote!(a + b * c == d || e);
+    let (expr, rest) = (|input: ParseStream| {
+        let parser = ExprParser::new().min_precedence(Precedence::Arithmetic);
+        let expr = parser.parse_stream(input)?;
+        input.parse::<Token![==]>()?;
+        let rest: TokenStream = input.parse()?;
+        Ok((expr, rest))
+    })
+    .parse2(tokens)
+    .unwrap();
+    snapshot!(expr, @r###"
+    Expr::Binary {
+        left: Expr::Path {
+            path: Path {
+                segments: [
+                    PathSegment {
+                        ident: "a",
+                        arguments: None,
+                    },
+                ],
+            },
+        },
+        op: Add,
+        right: Expr::Binary {
+            left: Expr::Path {
+                path: Path {
+                    segments: [
+                        PathSegment {
+                            ident: "b",
+                            arguments: None,
+                        },
+                    ],
+                },
+            },
+            op: Mul,
+            right: Expr::Path {
+                path: Path {
+                    segments: [
+                        PathSegment {
+                            ident: "c",
+                            arguments: None,
+                        },
+                    ],
+                },
+            },
+        },
+    }
+    "###);
+    assert_eq!(rest.to_string(), "d || e");
+}
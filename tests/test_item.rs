@@ -299,3 +299,148 @@ fn test_impl_type_parameter_defaults() {
         self_ty: Type::Tuple,
     }"###);
 }
+
+#[test]
+fn test_static_and_const_without_type_or_value() {
+    let tokens = quote! {
+        static X = 1;
+    };
+    snapshot!(tokens as Item, @r###"
+    Item::Static {
+        vis: Inherited,
+        ident: "X",
+        expr: Some(Expr::Lit {
+            lit: 1,
+        }),
+    }
+    "###);
+
+    let tokens = quote! {
+        static mut X: u8;
+    };
+    snapshot!(tokens as Item, @r###"
+    Item::Static {
+        vis: Inherited,
+        mutability: Some,
+        ident: "X",
+        ty: Some(Type::Path {
+            path: Path {
+                segments: [
+                    PathSegment {
+                        ident: "u8",
+                        arguments: None,
+                    },
+                ],
+            },
+        }),
+    }
+    "###);
+
+    let tokens = quote! {
+        pub const X: u8;
+    };
+    snapshot!(tokens as Item, @r###"
+    Item::Const {
+        vis: Visibility::Public,
+        ident: "X",
+        ty: Type::Path {
+            path: Path {
+                segments: [
+                    PathSegment {
+                        ident: "u8",
+                        arguments: None,
+                    },
+                ],
+            },
+        },
+    }
+    "###);
+}
+
+#[test]
+fn test_unsafe_extern_and_mod() {
+    let tokens = quote! {
+        unsafe extern "C" {
+            fn f();
+        }
+    };
+    snapshot!(tokens as Item, @r###"
+    Item::ForeignMod {
+        unsafety: Some,
+        abi: Abi {
+            name: Some("C"),
+        },
+        items: [
+            ForeignItem::Fn {
+                vis: Inherited,
+                sig: Signature {
+                    ident: "f",
+                    generics: Generics,
+                    output: Default,
+                },
+            },
+        ],
+    }
+    "###);
+
+    let tokens = quote! {
+        pub unsafe mod m {}
+    };
+    snapshot!(tokens as Item, @r###"
+    Item::Mod {
+        vis: Visibility::Public,
+        unsafety: Some,
+        ident: "m",
+        content: Some([]),
+    }
+    "###);
+}
+
+#[test]
+fn test_fn_without_body() {
+    let tokens = quote! {
+        #[doc(hidden)]
+        pub async fn ping(&self) -> u64;
+    };
+    snapshot!(tokens as Item, @r###"
+    Item::FnDecl {
+        attrs: [
+            Attribute {
+                style: Outer,
+                path: Path {
+                    segments: [
+                        PathSegment {
+                            ident: "doc",
+                            arguments: None,
+                        },
+                    ],
+                },
+                tokens: TokenStream(`(hidden)`),
+            },
+        ],
+        vis: Visibility::Public,
+        sig: Signature {
+            asyncness: Some,
+            ident: "ping",
+            generics: Generics,
+            inputs: [
+                Receiver(Receiver {
+                    reference: Some(None),
+                }),
+            ],
+            output: Type(
+                Type::Path {
+                    path: Path {
+                        segments: [
+                            PathSegment {
+                                ident: "u64",
+                                arguments: None,
+                            },
+                        ],
+                    },
+                },
+            ),
+        },
+    }
+    "###);
+}
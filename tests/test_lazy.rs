@@ -0,0 +1,127 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use std::fs;
+use syn::visit_mut::{self, VisitMut};
+use syn::{Expr, ImplItem, ImplItemMethod, Item, ItemFn, Stmt, TraitItem, TraitItemMethod};
+use walkdir::WalkDir;
+
+struct ParseBodies;
+
+impl VisitMut for ParseBodies {
+    fn visit_item_fn_mut(&mut self, item: &mut ItemFn) {
+        item.parse_body().unwrap();
+        visit_mut::visit_item_fn_mut(self, item);
+    }
+
+    fn visit_impl_item_method_mut(&mut self, item: &mut ImplItemMethod) {
+        item.parse_body().unwrap();
+        visit_mut::visit_impl_item_method_mut(self, item);
+    }
+
+    fn visit_trait_item_method_mut(&mut self, item: &mut TraitItemMethod) {
+        item.parse_body().unwrap();
+        visit_mut::visit_trait_item_method_mut(self, item);
+    }
+}
+
+fn is_unparsed(stmts: &[Stmt]) -> bool {
+    match stmts {
+        [Stmt::Expr(Expr::Verbatim(_))] => true,
+        _ => false,
+    }
+}
+
+#[test]
+fn test_lazy_bodies() {
+    let content = "
+        fn f() {
+            #![allow(unused)]
+            let x = 1;
+        }
+        impl S {
+            fn g(&self) -> u8 { self.0 }
+            fn h();
+        }
+        trait T {
+            fn i() { fn nested() {} }
+            fn j();
+        }
+        fn empty() {}
+    ";
+    let mut file = syn::parse_file_lazy(content).unwrap();
+    let tokens: TokenStream = content.parse().unwrap();
+    assert_eq!(file.to_token_stream().to_string(), tokens.to_string());
+
+    match &file.items[0] {
+        Item::Fn(item) => {
+            assert!(item.attrs.is_empty());
+            assert!(is_unparsed(&item.block.stmts));
+        }
+        _ => panic!("expected fn"),
+    }
+    match &file.items[1] {
+        Item::Impl(item) => match (&item.items[0], &item.items[1]) {
+            (ImplItem::Method(g), ImplItem::Method(h)) => {
+                assert!(is_unparsed(&g.block.stmts));
+                assert!(!is_unparsed(&h.block.stmts));
+            }
+            _ => panic!("expected methods"),
+        },
+        _ => panic!("expected impl"),
+    }
+    match &file.items[2] {
+        Item::Trait(item) => match (&item.items[0], &item.items[1]) {
+            (TraitItem::Method(i), TraitItem::Method(j)) => {
+                assert!(is_unparsed(&i.default.as_ref().unwrap().stmts));
+                assert!(j.default.is_none());
+            }
+            _ => panic!("expected methods"),
+        },
+        _ => panic!("expected trait"),
+    }
+    match &file.items[3] {
+        Item::Fn(item) => assert!(item.block.stmts.is_empty()),
+        _ => panic!("expected fn"),
+    }
+
+    let expected = syn::parse_file(content).unwrap();
+    ParseBodies.visit_file_mut(&mut file);
+    assert_eq!(file, expected);
+
+    // Parsing a body a second time leaves it as it is.
+    ParseBodies.visit_file_mut(&mut file);
+    assert_eq!(file, expected);
+}
+
+#[test]
+fn test_body_error() {
+    let mut file = syn::parse_file_lazy("fn f() { let x = ; }").unwrap();
+    match &mut file.items[0] {
+        Item::Fn(item) => {
+            let err = item.parse_body().unwrap_err();
+            assert_eq!(err.to_string(), "expected expression");
+        }
+        _ => panic!("expected fn"),
+    }
+}
+
+// Parsing the crate's own sources lazily and then parsing every body must give
+// the same syntax tree as parsing them eagerly.
+#[test]
+fn test_sources() {
+    for entry in WalkDir::new("src") {
+        let entry = entry.unwrap();
+        let path = entry.path();
+        if path.extension().map_or(true, |ext| ext != "rs") {
+            continue;
+        }
+        let content = fs::read_to_string(path).unwrap();
+        let mut file = syn::parse_file_lazy(&content).unwrap();
+        ParseBodies.visit_file_mut(&mut file);
+        assert!(
+            file == syn::parse_file(&content).unwrap(),
+            "{}",
+            path.display()
+        );
+    }
+}
@@ -0,0 +1,226 @@
+use proc_macro2::Span;
+use std::fs;
+use syn::{parse_quote, parse_quote_spanned, Expr, ExprLit, Ident, Item, Lit, LitInt, Stmt};
+use walkdir::WalkDir;
+
+#[test]
+fn test_unmodified() {
+    let src = "\u{feff}#!/usr/bin/env run-cargo-script
+//! Crate docs.
+
+// A comment.
+use std::io ;
+
+/* A block comment. */ fn main( ) {
+    let x = 1 ;   // trailing
+    println!( \"{}\", x );
+}
+";
+    let (file, source) = syn::lossless::parse_file(src).unwrap();
+    assert_eq!(source.text(), src);
+    assert_eq!(source.print(&file), src);
+}
+
+#[test]
+fn test_empty() {
+    for src in &["", "\n", "// only a comment\n", "#!/bin/sh\n"] {
+        let (file, source) = syn::lossless::parse_file(src).unwrap();
+        assert_eq!(source.print(&file), *src);
+    }
+}
+
+#[test]
+fn test_replace() {
+    let src = "
+fn f() {
+    // Comment before.
+    let x = 1;
+    x + 1 // comment after
+}
+";
+    let (mut file, source) = syn::lossless::parse_file(src).unwrap();
+    if let Item::Fn(item) = &mut file.items[0] {
+        item.block.stmts[0] = parse_quote!(let y = &*x as u8;);
+    }
+    let expected = "
+fn f() {
+    // Comment before.
+    let y = &*x as u8;
+    x + 1 // comment after
+}
+";
+    assert_eq!(source.print(&file), expected);
+}
+
+#[test]
+fn test_insert() {
+    let src = "
+/// Docs.
+struct S;
+
+// The end.
+";
+    let (mut file, source) = syn::lossless::parse_file(src).unwrap();
+    file.items.push(parse_quote! {
+        impl Trait<u8> for S where Vec<S>: Clone {
+            fn f(&self, x: &mut Option<u8>) -> bool { !x.is_some() && m!(x) }
+        }
+    });
+    let expected = "
+/// Docs.
+struct S;
+
+impl Trait<u8> for S where Vec<S>: Clone { fn f(&self, x: &mut Option<u8>) -> bool { !x.is_some() && m!(x) } }
+// The end.
+";
+    assert_eq!(source.print(&file), expected);
+}
+
+#[test]
+fn test_insert_first() {
+    let src = "// Header.\nuse a::b;\n";
+    let (mut file, source) = syn::lossless::parse_file(src).unwrap();
+    file.items.insert(0, parse_quote! { fn f() {} });
+    let expected = "fn f() {}\n// Header.\nuse a::b;\n";
+    assert_eq!(source.print(&file), expected);
+}
+
+#[test]
+fn test_delete() {
+    let src = "
+// First.
+fn first() {}
+
+// Second.
+fn second() {}
+
+// Third.
+fn third() {}
+";
+    let (mut file, source) = syn::lossless::parse_file(src).unwrap();
+    file.items.remove(1);
+    let expected = "
+// First.
+fn first() {}
+
+// Third.
+fn third() {}
+";
+    assert_eq!(source.print(&file), expected);
+
+    file.items.remove(0);
+    let expected = "// Third.
+fn third() {}
+";
+    assert_eq!(source.print(&file), expected);
+}
+
+#[test]
+fn test_rename() {
+    let src = "fn f(x: u8) -> u8 { x /* same */ }";
+    let (mut file, source) = syn::lossless::parse_file(src).unwrap();
+    if let Item::Fn(item) = &mut file.items[0] {
+        item.sig.ident = Ident::new("g", Span::call_site());
+        assert_eq!(source.print_tokens(&item.block), "{ x /* same */ }");
+    }
+    assert_eq!(source.print(&file), "fn g(x: u8) -> u8 { x /* same */ }");
+}
+
+// Edits that keep the span of the original token still change the output.
+#[test]
+fn test_edit_in_place() {
+    let src = "fn add_one(x: u8) -> u8 {\n    x + 1 // one\n}\n";
+    let (mut file, source) = syn::lossless::parse_file(src).unwrap();
+    if let Item::Fn(item) = &mut file.items[0] {
+        let ident = &mut item.sig.ident;
+        *ident = Ident::new("increment", ident.span());
+        if let Stmt::Expr(Expr::Binary(expr)) = &mut item.block.stmts[0] {
+            if let Expr::Lit(ExprLit {
+                lit: Lit::Int(lit), ..
+            }) = &mut *expr.right
+            {
+                *lit = LitInt::new("2", lit.span());
+            }
+        }
+    }
+    let expected = "fn increment(x: u8) -> u8 {\n    x + 2 // one\n}\n";
+    assert_eq!(source.print(&file), expected);
+
+    let (mut file, source) = syn::lossless::parse_file("/// Old.\nstruct S;\n").unwrap();
+    if let Item::Struct(item) = &mut file.items[0] {
+        let span = item.attrs[0].bracket_token.span;
+        item.attrs[0] = parse_quote_spanned!(span=> #[doc = " New."]);
+    }
+    assert_eq!(source.print(&file), "#[doc = \" New.\"]\nstruct S;\n");
+}
+
+#[test]
+fn test_reorder() {
+    let src = "fn a() {}\n// about b\nfn b() {}\n";
+    let (mut file, source) = syn::lossless::parse_file(src).unwrap();
+
+    let mut swapped = file.clone();
+    swapped.items.swap(0, 1);
+    let expected = "// about b\nfn b() {}\nfn a() {}\n";
+    assert_eq!(source.print(&swapped), expected);
+
+    let first = file.items[0].clone();
+    let second = file.items[1].clone();
+    file.items.push(second);
+    file.items.push(first);
+    let expected = "fn a() {}\n// about b\nfn b() {}\n// about b\nfn b() {}\nfn a() {}\n";
+    assert_eq!(source.print(&file), expected);
+
+    // A comment after a token on its line stays with that token.
+    let src = "use a::b; // trailing\nuse c::d;\n";
+    let (mut file, source) = syn::lossless::parse_file(src).unwrap();
+    file.items.swap(0, 1);
+    let expected = "use c::d;\nuse a::b; // trailing\n";
+    assert_eq!(source.print(&file), expected);
+
+    file.items.swap(0, 1);
+    file.items.insert(1, parse_quote! { use e::f; });
+    let expected = "use a::b; // trailing\nuse e::f;\nuse c::d;\n";
+    assert_eq!(source.print(&file), expected);
+}
+
+#[test]
+fn test_range() {
+    let src = "
+/// Docs.
+fn f() {}
+
+struct S;
+";
+    let (file, source) = syn::lossless::parse_file(src).unwrap();
+    let range = source.range(&file.items[0]).unwrap();
+    assert_eq!(&src[range], "/// Docs.\nfn f() {}");
+    let range = source.range(&file.items[1]).unwrap();
+    assert_eq!(&src[range], "struct S;");
+
+    let item: Item = parse_quote! {
+        struct S;
+    };
+    assert_eq!(source.range(&item), None);
+
+    // Spans of another file parsed on the same thread are not confused with
+    // this one's.
+    let (other, _) = syn::lossless::parse_file(src).unwrap();
+    assert_eq!(source.range(&other.items[0]), None);
+}
+
+// Printing the crate's own sources without modification must reproduce them
+// exactly.
+#[test]
+fn test_round_trip_sources() {
+    for entry in WalkDir::new("src") {
+        let entry = entry.unwrap();
+        let path = entry.path();
+        if path.extension().map_or(true, |ext| ext != "rs") {
+            continue;
+        }
+        let src = fs::read_to_string(path).unwrap();
+        let (file, source) = syn::lossless::parse_file(&src).unwrap();
+        assert!(source.print(&file) == src, "{}", path.display());
+    }
+}
@@ -0,0 +1,193 @@
+#[macro_use]
+mod macros;
+
+use quote::quote;
+use syn::{ItemMacro, MacroRules};
+
+#[test]
+fn test_rules() {
+    let item: ItemMacro = syn::parse2(quote! {
+        macro_rules! m {
+            () => {};
+            ($($name:ident = $value:expr),+ $(,)?) => {
+                $(let $name = $value;)+
+            };
+        }
+    })
+    .unwrap();
+
+    snapshot!(item.parse_rules().unwrap(), @r###"
+    MacroRules {
+        rules: [
+            MacroRule {
+                matcher: MacroGroup {
+                    delimiter: Paren,
+                },
+                transcriber: MacroGroup {
+                    delimiter: Brace,
+                },
+            },
+            MacroRule {
+                matcher: MacroGroup {
+                    delimiter: Paren,
+                    content: [
+                        Repetition(MacroRepetition {
+                            content: [
+                                Metavar(MacroMetavar {
+                                    name: "name",
+                                    specifier: Some("ident"),
+                                }),
+                                Verbatim(`=`),
+                                Metavar(MacroMetavar {
+                                    name: "value",
+                                    specifier: Some("expr"),
+                                }),
+                            ],
+                            separator: Some(TokenStream(`,`)),
+                            op: OneOrMore,
+                        }),
+                        Repetition(MacroRepetition {
+                            content: [
+                                Verbatim(`,`),
+                            ],
+                            op: ZeroOrOne,
+                        }),
+                    ],
+                },
+                transcriber: MacroGroup {
+                    delimiter: Brace,
+                    content: [
+                        Repetition(MacroRepetition {
+                            content: [
+                                Verbatim(`let`),
+                                Metavar(MacroMetavar {
+                                    name: "name",
+                                }),
+                                Verbatim(`=`),
+                                Metavar(MacroMetavar {
+                                    name: "value",
+                                }),
+                                Verbatim(`;`),
+                            ],
+                            op: OneOrMore,
+                        }),
+                    ],
+                },
+            },
+        ],
+    }
+    "###);
+}
+
+#[test]
+fn test_repetition_separator() {
+    let tokens = quote! {
+        ($($a:tt)=>*) => {};
+        ($($b:tt)++) => {};
+        ($($c:tt);?) => {};
+    };
+
+    snapshot!(tokens as MacroRules, @r###"
+    MacroRules {
+        rules: [
+            MacroRule {
+                matcher: MacroGroup {
+                    delimiter: Paren,
+                    content: [
+                        Repetition(MacroRepetition {
+                            content: [
+                                Metavar(MacroMetavar {
+                                    name: "a",
+                                    specifier: Some("tt"),
+                                }),
+                            ],
+                            separator: Some(TokenStream(`=>`)),
+                            op: ZeroOrMore,
+                        }),
+                    ],
+                },
+                transcriber: MacroGroup {
+                    delimiter: Brace,
+                },
+            },
+            MacroRule {
+                matcher: MacroGroup {
+                    delimiter: Paren,
+                    content: [
+                        Repetition(MacroRepetition {
+                            content: [
+                                Metavar(MacroMetavar {
+                                    name: "b",
+                                    specifier: Some("tt"),
+                                }),
+                            ],
+                            op: OneOrMore,
+                        }),
+                        Verbatim(`+`),
+                    ],
+                },
+                transcriber: MacroGroup {
+                    delimiter: Brace,
+                },
+            },
+            MacroRule {
+                matcher: MacroGroup {
+                    delimiter: Paren,
+                    content: [
+                        Repetition(MacroRepetition {
+                            content: [
+                                Metavar(MacroMetavar {
+                                    name: "c",
+                                    specifier: Some("tt"),
+                                }),
+                            ],
+                            separator: Some(TokenStream(`;`)),
+                            op: ZeroOrOne,
+                        }),
+                    ],
+                },
+                transcriber: MacroGroup {
+                    delimiter: Brace,
+                },
+            },
+        ],
+    }
+    "###);
+}
+
+#[test]
+fn test_round_trip() {
+    let tokens = quote! {
+        ($($k:expr => $v:expr),* $(,)?) => {{
+            let mut map = $crate::Map::new();
+            $(map.insert($k, $v);)*
+            map
+        }};
+        [$first:ident $($rest:tt)*] => (m!($($rest)*));
+    };
+
+    let rules: MacroRules = syn::parse2(tokens.clone()).unwrap();
+    assert_eq!(quote!(#rules).to_string(), tokens.to_string());
+}
+
+#[test]
+fn test_invalid_rules() {
+    let missing_specifier = quote!(($x) => {});
+    let err = syn::parse2::<MacroRules>(missing_specifier).unwrap_err();
+    assert_eq!(err.to_string(), "expected `:`");
+
+    let invalid_specifier = quote!(($x:type) => {});
+    let err = syn::parse2::<MacroRules>(invalid_specifier).unwrap_err();
+    assert_eq!(err.to_string(), "invalid fragment specifier `type`");
+
+    let missing_op = quote!(($($x:tt)) => {});
+    let err = syn::parse2::<MacroRules>(missing_op).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "unexpected end of input, expected repetition separator or operator"
+    );
+
+    let not_macro_rules: ItemMacro = syn::parse2(quote!(println!("{}", x);)).unwrap();
+    let err = not_macro_rules.parse_rules().unwrap_err();
+    assert_eq!(err.to_string(), "expected `macro_rules!`");
+}
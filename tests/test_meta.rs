@@ -1,7 +1,11 @@
 #[macro_use]
 mod macros;
 
-use syn::{Meta, MetaList, MetaNameValue, NestedMeta};
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::{
+    meta, AttrMeta, Attribute, Expr, LitStr, Meta, MetaList, MetaNameValue, NestedMeta, Path, Token,
+};
 
 #[test]
 fn test_parse_meta_item_word() {
@@ -374,3 +378,228 @@ fn test_parse_path() {
     }))
     "###);
 }
+
+#[test]
+fn test_parse_attr_meta_name_value_expr() {
+    let input = "limit = 1 + 2";
+    snapshot!(input as AttrMeta, @r###"
+    AttrMeta::NameValue {
+        path: Path {
+            segments: [
+                PathSegment {
+                    ident: "limit",
+                    arguments: None,
+                },
+            ],
+        },
+        value: Expr::Binary {
+            left: Expr::Lit {
+                lit: 1,
+            },
+            op: Add,
+            right: Expr::Lit {
+                lit: 2,
+            },
+        },
+    }
+    "###);
+}
+
+#[test]
+fn test_parse_attr_meta_list() {
+    let attr: Attribute = syn::parse_quote!(#[serde(default = path::to::f)]);
+    let meta = attr.parse_attr_meta().unwrap();
+    snapshot!(&meta, @r###"
+    AttrMeta::List {
+        path: Path {
+            segments: [
+                PathSegment {
+                    ident: "serde",
+                    arguments: None,
+                },
+            ],
+        },
+        delimiter: Paren,
+        tokens: TokenStream(`default = path :: to :: f`),
+    }
+    "###);
+
+    let list = match meta {
+        AttrMeta::List(list) => list,
+        _ => unreachable!(),
+    };
+    let nested = list
+        .parse_args_with(Punctuated::<AttrMeta, Token![,]>::parse_terminated)
+        .unwrap();
+    snapshot!(nested, @r###"
+    [
+        AttrMeta::NameValue {
+            path: Path {
+                segments: [
+                    PathSegment {
+                        ident: "default",
+                        arguments: None,
+                    },
+                ],
+            },
+            value: Expr::Path {
+                path: Path {
+                    segments: [
+                        PathSegment {
+                            ident: "path",
+                            arguments: None,
+                        },
+                        PathSegment {
+                            ident: "to",
+                            arguments: None,
+                        },
+                        PathSegment {
+                            ident: "f",
+                            arguments: None,
+                        },
+                    ],
+                },
+            },
+        },
+    ]
+    "###);
+}
+
+#[test]
+fn test_parse_attr_meta_round_trip() {
+    let tokens = quote! {
+        builder(setter(into), default = Vec::new())
+    };
+    let meta: AttrMeta = syn::parse2(tokens.clone()).unwrap();
+    assert_eq!(quote!(#meta).to_string(), tokens.to_string());
+
+    let attr: Attribute = syn::parse_quote!(#[path = "sys/windows.rs" extra]);
+    let err = attr.parse_attr_meta().unwrap_err();
+    assert_eq!(err.to_string(), "unexpected token");
+}
+
+#[test]
+fn test_attribute_parse_nested_meta() {
+    let attr: Attribute = syn::parse_quote! {
+        #[serde(rename = "id", skip, bound(serialize = "T: Serialize"))]
+    };
+
+    let mut rename = None;
+    let mut skip = false;
+    let mut serialize = None;
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("rename") {
+            rename = Some(meta.value()?.parse::<LitStr>()?.value());
+            Ok(())
+        } else if meta.path.is_ident("skip") {
+            skip = true;
+            Ok(())
+        } else if meta.path.is_ident("bound") {
+            meta.parse_nested_meta(|meta| {
+                serialize = Some(meta.value()?.parse::<LitStr>()?.value());
+                Ok(())
+            })
+        } else {
+            Err(meta.error("unsupported serde attribute"))
+        }
+    })
+    .unwrap();
+
+    assert_eq!(rename.unwrap(), "id");
+    assert!(skip);
+    assert_eq!(serialize.unwrap(), "T: Serialize");
+
+    let attr: Attribute = syn::parse_quote!(#[serde(other::path)]);
+    let err = attr
+        .parse_nested_meta(|meta| Err(meta.error("unsupported serde attribute")))
+        .unwrap_err();
+    assert_eq!(err.to_string(), "unsupported serde attribute");
+}
+
+#[test]
+fn test_meta_keys() {
+    let attrs: Vec<Attribute> = vec![
+        syn::parse_quote!(#[my_macro(rename = "id", crate = path::to::krate)]),
+        syn::parse_quote!(#[doc = "unrelated"]),
+        syn::parse_quote!(#[my_macro(skip, default = Vec::new(), bound(serialize))]),
+    ];
+
+    let mut rename: Option<LitStr> = None;
+    let mut krate: Option<Path> = None;
+    let mut default: Option<Expr> = None;
+    let mut skip = false;
+    let mut serialize = false;
+    meta::Keys::new()
+        .value("rename", &mut rename)
+        .value("crate", &mut krate)
+        .value("default", &mut default)
+        .flag("skip", &mut skip)
+        .nested("bound", |meta| {
+            meta::Keys::new()
+                .flag("serialize", &mut serialize)
+                .parse_meta(meta)
+        })
+        .parse_attrs(&attrs, "my_macro")
+        .unwrap();
+
+    assert_eq!(rename.unwrap().value(), "id");
+    let krate = krate.unwrap();
+    assert_eq!(quote!(#krate).to_string(), "path :: to :: krate");
+    let default = default.unwrap();
+    assert_eq!(quote!(#default).to_string(), "Vec :: new ()");
+    assert!(skip);
+    assert!(serialize);
+}
+
+#[test]
+fn test_meta_keys_errors() {
+    let attrs: Vec<Attribute> = vec![
+        syn::parse_quote!(#[my_macro(rename = "a", unknown = 1, skip)]),
+        syn::parse_quote!(#[my_macro(rename = "b", skip = true, bound(other))]),
+    ];
+
+    let mut rename: Option<LitStr> = None;
+    let mut skip = false;
+    let err = meta::Keys::new()
+        .value("rename", &mut rename)
+        .flag("skip", &mut skip)
+        .nested("bound", |meta| meta::Keys::new().parse_meta(meta))
+        .parse_attrs(&attrs, "my_macro")
+        .unwrap_err();
+
+    let messages: Vec<String> = err.into_iter().map(|err| err.to_string()).collect();
+    assert_eq!(
+        messages,
+        [
+            "unknown attribute `unknown`, expected `rename`, `skip`, or `bound`",
+            "duplicate `rename` attribute",
+            "duplicate `skip` attribute",
+            "unknown attribute `other`",
+        ],
+    );
+}
+
+#[test]
+fn test_meta_keys_malformed_after_error() {
+    let attrs: Vec<Attribute> = vec![
+        syn::parse_quote!(#[my_macro(unknown(a b), 123)]),
+        syn::parse_quote!(#[my_macro(bound(other x), skip)]),
+    ];
+
+    let mut skip = false;
+    let err = meta::Keys::new()
+        .flag("skip", &mut skip)
+        .nested("bound", |meta| meta::Keys::new().parse_meta(meta))
+        .parse_attrs(&attrs, "my_macro")
+        .unwrap_err();
+
+    let messages: Vec<String> = err.into_iter().map(|err| err.to_string()).collect();
+    assert_eq!(
+        messages,
+        [
+            "unknown attribute `unknown`, expected `skip` or `bound`",
+            "expected path",
+            "unknown attribute `other`",
+        ],
+    );
+}
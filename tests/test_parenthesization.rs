@@ -0,0 +1,247 @@
+//! The tests in this module do the following:
+//!
+//! 1. Build expressions programmatically by substituting small expressions
+//!    into the operand positions of every kind of operator, without inserting
+//!    any parentheses.
+//! 2. Print each expression with `ToTokens` and parse the result back.
+//! 3. Compare the parsed expression with the original one after running the
+//!    paren fixer over it, which must insert exactly the parentheses that
+//!    printing inserted.
+//! 4. Check that the fixed expression round trips unchanged, and that it
+//!    differs from the original expression only by parentheses.
+
+use proc_macro2::Span;
+use quote::quote;
+use syn::visit_mut::{self, VisitMut};
+use syn::{Expr, ExprLit, ExprParen, Lit, LitInt, Stmt};
+
+#[macro_use]
+mod macros;
+
+// Templates in which `x` and `y` are operand positions to substitute into.
+const TEMPLATES: &[&str] = &[
+    "x + y",
+    "x - y",
+    "x * y",
+    "x == y",
+    "x < y",
+    "x << y",
+    "x & y",
+    "x && y",
+    "x || y",
+    "x = y",
+    "x += y",
+    "x .. y",
+    "x ..= y",
+    "x ..",
+    ".. y",
+    "x as T",
+    "x: T",
+    "-x",
+    "!x",
+    "*x",
+    "&x",
+    "&mut x",
+    "&raw const x",
+    "box x",
+    "x?",
+    "x.f",
+    "x.0",
+    "x.m()",
+    "x[y]",
+    "x(y)",
+    "x.await",
+    "return x",
+    "break x",
+    "yield x",
+    "|| x",
+    "let P = x",
+    "if x {}",
+    "while x {}",
+    "for p in x {}",
+    "match x {}",
+    "{ x }",
+];
+
+const LEAVES: &[&str] = &["a", "1", "S {}", "f()", "return", "|| c", "{}"];
+
+struct Substitute<'a> {
+    x: &'a Expr,
+    y: &'a Expr,
+}
+
+impl<'a> VisitMut for Substitute<'a> {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        let replacement = if let Expr::Path(path) = expr {
+            if path.path.is_ident("x") {
+                Some(self.x)
+            } else if path.path.is_ident("y") {
+                Some(self.y)
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+        match replacement {
+            Some(replacement) => *expr = replacement.clone(),
+            None => visit_mut::visit_expr_mut(self, expr),
+        }
+    }
+}
+
+struct StripParens;
+
+impl VisitMut for StripParens {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        visit_mut::visit_expr_mut(self, expr);
+        if let Expr::Paren(ExprParen { expr: inner, .. }) = expr {
+            *expr = (**inner).clone();
+        }
+    }
+}
+
+fn instantiate(template: &Expr, x: &Expr, y: &Expr) -> Expr {
+    let mut expr = template.clone();
+    Substitute { x, y }.visit_expr_mut(&mut expr);
+    expr
+}
+
+fn check(expr: &Expr) -> Result<(), String> {
+    let mut fixed = expr.clone();
+    fixed.normalize_parens();
+
+    let printed = quote!(#expr);
+    let reparsed: Expr = syn::parse2(printed.clone())
+        .map_err(|err| format!("failed to parse `{}`: {}", printed, err))?;
+    if reparsed != fixed {
+        let fixed_printed = quote!(#fixed);
+        return Err(format!(
+            "`{}` parsed differently from the fixed `{}`",
+            printed, fixed_printed,
+        ));
+    }
+
+    let fixed_printed = quote!(#fixed);
+    let fixed_reparsed: Expr = syn::parse2(fixed_printed.clone())
+        .map_err(|err| format!("failed to parse fixed `{}`: {}", fixed_printed, err))?;
+    if fixed_reparsed != fixed {
+        return Err(format!("fixed `{}` does not round trip", fixed_printed));
+    }
+
+    let mut stripped = fixed;
+    StripParens.visit_expr_mut(&mut stripped);
+    if stripped != *expr {
+        return Err(format!("fixing `{}` changed more than parentheses", printed));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_constructed_expressions() {
+    let templates: Vec<Expr> = TEMPLATES
+        .iter()
+        .map(|template| syn::parse_str(template).unwrap())
+        .collect();
+    let leaves: Vec<Expr> = LEAVES
+        .iter()
+        .map(|leaf| syn::parse_str(leaf).unwrap())
+        .collect();
+    let b: Expr = syn::parse_str("b").unwrap();
+
+    let mut shallow = Vec::new();
+    for template in &templates {
+        for leaf in &leaves {
+            shallow.push(instantiate(template, leaf, &b));
+        }
+    }
+
+    let mut count = 0;
+    let mut failures = Vec::new();
+    for template in &templates {
+        for operand in &shallow {
+            for expr in &[
+                instantiate(template, operand, &b),
+                instantiate(template, &b, operand),
+            ] {
+                count += 1;
+                if let Err(message) = check(expr) {
+                    failures.push(message);
+                }
+            }
+        }
+    }
+
+    for message in &failures {
+        errorf!("{}\n", message);
+    }
+    if !failures.is_empty() {
+        panic!("{} of {} expressions failed", failures.len(), count);
+    }
+}
+
+#[test]
+fn test_print_parenthesized() {
+    let mut expr: Expr = syn::parse_str("a * b").unwrap();
+    if let Expr::Binary(binary) = &mut expr {
+        *binary.left = syn::parse_str("x .. y").unwrap();
+        *binary.right = syn::parse_str("c + d").unwrap();
+    }
+    assert_eq!(quote!(#expr).to_string(), "(x .. y) * (c + d)");
+
+    let mut expr: Expr = syn::parse_str("x.m()").unwrap();
+    if let Expr::MethodCall(call) = &mut expr {
+        *call.receiver = syn::parse_str("a as u8").unwrap();
+    }
+    assert_eq!(quote!(#expr).to_string(), "(a as u8) . m ()");
+
+    let mut expr: Expr = syn::parse_str("if x {}").unwrap();
+    if let Expr::If(expr_if) = &mut expr {
+        *expr_if.cond = syn::parse_str("a == S {}").unwrap();
+    }
+    assert_eq!(quote!(#expr).to_string(), "if (a == S { }) { }");
+}
+
+#[test]
+fn test_negative_literal() {
+    let mut expr: Expr = syn::parse_str("x.abs()").unwrap();
+    if let Expr::MethodCall(call) = &mut expr {
+        *call.receiver = Expr::Lit(ExprLit {
+            attrs: Vec::new(),
+            lit: Lit::Int(LitInt::new("-1", Span::call_site())),
+        });
+    }
+    let printed = quote!(#expr);
+    assert_eq!(printed.to_string(), "(- 1) . abs ()");
+    match syn::parse2(printed).unwrap() {
+        Expr::MethodCall(call) => match *call.receiver {
+            Expr::Paren(_) => {}
+            receiver => panic!("unexpected receiver: {}", quote!(#receiver)),
+        },
+        expr => panic!("unexpected expression: {}", quote!(#expr)),
+    }
+
+    expr.normalize_parens();
+    if let Expr::MethodCall(call) = &expr {
+        assert!(match *call.receiver {
+            Expr::Paren(_) => true,
+            _ => false,
+        });
+    }
+}
+
+#[test]
+fn test_block_in_statement() {
+    let mut expr: Expr = syn::parse_str("{ x + 1 }").unwrap();
+    if let Expr::Block(block) = &mut expr {
+        if let Stmt::Expr(Expr::Binary(binary)) = &mut block.block.stmts[0] {
+            *binary.left = syn::parse_str("match y {}").unwrap();
+        }
+    }
+    assert_eq!(quote!(#expr).to_string(), "{ (match y { }) + 1 }");
+    check(&expr).unwrap();
+
+    let expr: Expr = syn::parse_str("{ match y {}.f() + 1 }").unwrap();
+    assert_eq!(quote!(#expr).to_string(), "{ match y { } . f () + 1 }");
+}
@@ -0,0 +1,85 @@
+#[macro_use]
+mod macros;
+
+use proc_macro2::Span;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{parse_quote_spanned, Attribute, Ident, Stmt, Token, WherePredicate};
+
+#[test]
+fn test_parse_quote_spanned() {
+    // A span that is not the call site, at line 2, columns 4 to 8.
+    let ident: Ident = syn::parse_str("\n    span").unwrap();
+    let span = ident.span();
+
+    let predicate: WherePredicate = parse_quote_spanned!(span=> T: Clone);
+    assert_span(predicate.span(), span);
+    snapshot!(predicate, @r###"
+    Type(PredicateType {
+        bounded_ty: Type::Path {
+            path: Path {
+                segments: [
+                    PathSegment {
+                        ident: "T",
+                        arguments: None,
+                    },
+                ],
+            },
+        },
+        bounds: [
+            Trait(TraitBound {
+                modifier: None,
+                path: Path {
+                    segments: [
+                        PathSegment {
+                            ident: "Clone",
+                            arguments: None,
+                        },
+                    ],
+                },
+            }),
+        ],
+    })
+    "###);
+
+    let attr: Attribute = parse_quote_spanned!(span=> #[inline]);
+    assert_span(attr.pound_token.span, span);
+    assert_span(attr.path.segments[0].ident.span(), span);
+    snapshot!(attr, @r###"
+    Attribute {
+        style: Outer,
+        path: Path {
+            segments: [
+                PathSegment {
+                    ident: "inline",
+                    arguments: None,
+                },
+            ],
+        },
+        tokens: TokenStream(``),
+    }
+    "###);
+
+    let list: Punctuated<WherePredicate, Token![,]> =
+        parse_quote_spanned!(span=> T: Clone, U: Copy,);
+    assert_eq!(list.len(), 2);
+    assert_span(list[1].span(), span);
+
+    let stmts: Vec<Stmt> = parse_quote_spanned! {span=>
+        let x = 1;
+        x + 1
+    };
+    assert_eq!(stmts.len(), 2);
+    assert_span(stmts[1].span(), span);
+}
+
+fn assert_span(actual: Span, expected: Span) {
+    assert_eq!(format!("{:?}", actual), format!("{:?}", expected));
+    #[cfg(feature = "span-locations")]
+    {
+        let start = (actual.start().line, actual.start().column);
+        let end = (actual.end().line, actual.end().column);
+        assert_eq!(start, (2, 4));
+        assert_eq!(end, (2, 8));
+    }
+}
@@ -65,3 +65,57 @@ fn test_group() {
     }
     "###);
 }
+
+#[test]
+fn test_const_block() {
+    let tokens = quote!(const { N + 1 });
+    snapshot!(tokens as Pat, @r###"
+    Pat::Const {
+        block: Block {
+            stmts: [
+                Expr(Expr::Binary {
+                    left: Expr::Path {
+                        path: Path {
+                            segments: [
+                                PathSegment {
+                                    ident: "N",
+                                    arguments: None,
+                                },
+                            ],
+                        },
+                    },
+                    op: Add,
+                    right: Expr::Lit {
+                        lit: 1,
+                    },
+                }),
+            ],
+        },
+    }
+    "###);
+
+    let tokens = quote!(const { 1 }..=const { 2 });
+    snapshot!(tokens as Pat, @r###"
+    Pat::Range {
+        lo: Expr::Const {
+            block: Block {
+                stmts: [
+                    Expr(Expr::Lit {
+                        lit: 1,
+                    }),
+                ],
+            },
+        },
+        limits: Closed,
+        hi: Expr::Const {
+            block: Block {
+                stmts: [
+                    Expr(Expr::Lit {
+                        lit: 2,
+                    }),
+                ],
+            },
+        },
+    }
+    "###);
+}
@@ -0,0 +1,403 @@
+use proc_macro2::{TokenStream, TokenTree};
+use std::fs;
+use syn::punctuated::Punctuated;
+use syn::visit::Visit;
+use syn::visit_mut::{self, VisitMut};
+use syn::{
+    AngleBracketedGenericArguments, Attribute, Expr, ExprArray, ExprCall, ExprClosure,
+    ExprMethodCall, ExprStruct, ExprTuple, FieldsNamed, FieldsUnnamed, File, Generics, Item,
+    ItemEnum, Macro, ParenthesizedGenericArguments, PatSlice, PatStruct, PatTuple, Signature, Stmt,
+    TypeBareFn, TypeTuple, UseGroup, WhereClause,
+};
+use walkdir::WalkDir;
+
+fn pretty(src: &str) -> String {
+    let file = syn::parse_file(src).unwrap();
+    syn::pretty::unparse(&file)
+}
+
+#[test]
+fn test_doc_comments() {
+    let src = r#"
+        #![doc = " Crate documentation."]
+        /// First line.
+        ///
+        /// Second paragraph.
+        #[doc = "/ Not a doc comment when written with slashes."]
+        #[doc = "Two\nlines."]
+        #[derive(Debug)]
+        pub struct Unit;
+    "#;
+    let expected = r#"//! Crate documentation.
+
+/// First line.
+///
+/// Second paragraph.
+#[doc = "/ Not a doc comment when written with slashes."]
+#[doc = "Two\nlines."]
+#[derive(Debug)]
+pub struct Unit;
+"#;
+    assert_eq!(pretty(src), expected);
+}
+
+#[test]
+fn test_items() {
+    let src = "
+        use std::fmt; use std::io;
+        pub(crate) enum E<T> { A, B(T), C { x: u8, y: u8 } }
+        impl<T: Clone> Trait for E<T> { type Output = T; fn f(&self) -> T { loop {} } }
+    ";
+    let expected = "\
+use std::fmt;
+use std::io;
+
+pub(crate) enum E<T> {
+    A,
+    B(T),
+    C { x: u8, y: u8 },
+}
+
+impl<T: Clone> Trait for E<T> {
+    type Output = T;
+
+    fn f(&self) -> T {
+        loop {}
+    }
+}
+";
+    assert_eq!(pretty(src), expected);
+}
+
+#[test]
+fn test_bodiless_method() {
+    let src = "impl T { fn f(); fn g<U>() where U: Copy; }";
+    let expected = "\
+impl T {
+    fn f();
+
+    fn g<U>()
+    where
+        U: Copy;
+}
+";
+    assert_eq!(pretty(src), expected);
+}
+
+#[test]
+fn test_where_clause() {
+    let src = "
+        fn f<T, U>(t: T, u: U) -> Result<T, U> where T: Clone + Send + 'static, U: Iterator<Item = T> { g(t, u) }
+    ";
+    let expected = "\
+fn f<T, U>(t: T, u: U) -> Result<T, U>
+where
+    T: Clone + Send + 'static,
+    U: Iterator<Item = T>,
+{
+    g(t, u)
+}
+";
+    assert_eq!(pretty(src), expected);
+}
+
+#[test]
+fn test_line_breaking() {
+    let src = "
+        fn f() {
+            let value = function_with_a_long_name(first_argument, second_argument, third_argument, fourth_argument);
+            function_with_a_long_name(first_argument, second_argument, third_argument, fourth_argument, fifth_argument);
+            let s = S { first_field: first_argument, second_field: second_argument, third_field: third_argument };
+            builder.first_method(first_argument).second_method(second_argument).third_method(third_argument).build();
+        }
+    ";
+    let expected = "\
+fn f() {
+    let value =
+        function_with_a_long_name(first_argument, second_argument, third_argument, fourth_argument);
+    function_with_a_long_name(
+        first_argument,
+        second_argument,
+        third_argument,
+        fourth_argument,
+        fifth_argument,
+    );
+    let s = S {
+        first_field: first_argument,
+        second_field: second_argument,
+        third_field: third_argument,
+    };
+    builder
+        .first_method(first_argument)
+        .second_method(second_argument)
+        .third_method(third_argument)
+        .build();
+}
+";
+    assert_eq!(pretty(src), expected);
+}
+
+#[test]
+fn test_operator_chain() {
+    let src = "
+        fn f() {
+            if first_condition(argument) && second_condition(argument) && third_condition(another_argument) {
+                run();
+            }
+        }
+    ";
+    let expected = "\
+fn f() {
+    if first_condition(argument)
+        && second_condition(argument)
+        && third_condition(another_argument)
+    {
+        run();
+    }
+}
+";
+    assert_eq!(pretty(src), expected);
+}
+
+#[test]
+fn test_parenthesize_statement() {
+    // Constructed without parentheses, a match on the left of a binary
+    // operator would otherwise end the statement after the match.
+    let mut file: File = syn::parse_str("fn f() { x; }").unwrap();
+    let expr: Expr = syn::parse_str("match x {} + 1").unwrap();
+    if let Item::Fn(item) = &mut file.items[0] {
+        item.block.stmts[0] = Stmt::Semi(expr, Default::default());
+    }
+    let expected = "\
+fn f() {
+    (match x {} + 1);
+}
+";
+    assert_eq!(syn::pretty::unparse(&file), expected);
+}
+
+#[test]
+fn test_macros() {
+    let src = r#"
+        macro_rules! m { ($e:expr) => { println!("{}", $e); }; }
+        ast_struct! {
+            /// Docs.
+            pub struct S { a: u8 }
+        }
+    "#;
+    let expected = r#"macro_rules! m {
+    ($e:expr) => {
+        println!("{}", $e);
+    };
+}
+
+ast_struct! {
+    /// Docs.
+    pub struct S { a: u8 }
+}
+"#;
+    assert_eq!(pretty(src), expected);
+}
+
+// Printing the crate's own sources must produce code that parses back to the
+// same syntax tree, and prints back to the same text.
+#[test]
+fn test_idempotent() {
+    for entry in WalkDir::new("src") {
+        let entry = entry.unwrap();
+        let path = entry.path();
+        if path.extension().map_or(true, |ext| ext != "rs") {
+            continue;
+        }
+        let src = fs::read_to_string(path).unwrap();
+        let mut original = syn::parse_file(&src).unwrap();
+        let printed = syn::pretty::unparse(&original);
+        let mut reparsed = match syn::parse_file(&printed) {
+            Ok(file) => file,
+            Err(err) => panic!("failed to reparse {}: {}", path.display(), err),
+        };
+        let reprinted = syn::pretty::unparse(&reparsed);
+        assert!(printed == reprinted, "not idempotent: {}", path.display());
+
+        TrailingPunct.visit_file_mut(&mut original);
+        TrailingPunct.visit_file_mut(&mut reparsed);
+        assert!(original == reparsed, "{}", path.display());
+    }
+}
+
+// Removes the trailing punctuation of every list, which the printer adds or
+// leaves out depending on whether the list is broken across lines.
+struct TrailingPunct;
+
+fn strip<T, P>(punctuated: &mut Punctuated<T, P>) {
+    if punctuated.trailing_punct() {
+        let last = punctuated.pop().unwrap().into_value();
+        punctuated.push_value(last);
+    }
+}
+
+impl VisitMut for TrailingPunct {
+    fn visit_angle_bracketed_generic_arguments_mut(
+        &mut self,
+        node: &mut AngleBracketedGenericArguments,
+    ) {
+        strip(&mut node.args);
+        visit_mut::visit_angle_bracketed_generic_arguments_mut(self, node);
+    }
+
+    fn visit_expr_array_mut(&mut self, node: &mut ExprArray) {
+        strip(&mut node.elems);
+        visit_mut::visit_expr_array_mut(self, node);
+    }
+
+    fn visit_expr_call_mut(&mut self, node: &mut ExprCall) {
+        strip(&mut node.args);
+        visit_mut::visit_expr_call_mut(self, node);
+    }
+
+    fn visit_expr_closure_mut(&mut self, node: &mut ExprClosure) {
+        strip(&mut node.inputs);
+        visit_mut::visit_expr_closure_mut(self, node);
+    }
+
+    fn visit_expr_method_call_mut(&mut self, node: &mut ExprMethodCall) {
+        strip(&mut node.args);
+        visit_mut::visit_expr_method_call_mut(self, node);
+    }
+
+    fn visit_expr_struct_mut(&mut self, node: &mut ExprStruct) {
+        strip(&mut node.fields);
+        visit_mut::visit_expr_struct_mut(self, node);
+    }
+
+    fn visit_expr_tuple_mut(&mut self, node: &mut ExprTuple) {
+        strip(&mut node.elems);
+        visit_mut::visit_expr_tuple_mut(self, node);
+    }
+
+    fn visit_fields_named_mut(&mut self, node: &mut FieldsNamed) {
+        strip(&mut node.named);
+        visit_mut::visit_fields_named_mut(self, node);
+    }
+
+    fn visit_fields_unnamed_mut(&mut self, node: &mut FieldsUnnamed) {
+        strip(&mut node.unnamed);
+        visit_mut::visit_fields_unnamed_mut(self, node);
+    }
+
+    fn visit_generics_mut(&mut self, node: &mut Generics) {
+        strip(&mut node.params);
+        visit_mut::visit_generics_mut(self, node);
+    }
+
+    fn visit_item_enum_mut(&mut self, node: &mut ItemEnum) {
+        strip(&mut node.variants);
+        visit_mut::visit_item_enum_mut(self, node);
+    }
+
+    fn visit_parenthesized_generic_arguments_mut(
+        &mut self,
+        node: &mut ParenthesizedGenericArguments,
+    ) {
+        strip(&mut node.inputs);
+        visit_mut::visit_parenthesized_generic_arguments_mut(self, node);
+    }
+
+    fn visit_pat_slice_mut(&mut self, node: &mut PatSlice) {
+        strip(&mut node.elems);
+        visit_mut::visit_pat_slice_mut(self, node);
+    }
+
+    fn visit_pat_struct_mut(&mut self, node: &mut PatStruct) {
+        strip(&mut node.fields);
+        visit_mut::visit_pat_struct_mut(self, node);
+    }
+
+    fn visit_pat_tuple_mut(&mut self, node: &mut PatTuple) {
+        strip(&mut node.elems);
+        visit_mut::visit_pat_tuple_mut(self, node);
+    }
+
+    fn visit_signature_mut(&mut self, node: &mut Signature) {
+        strip(&mut node.inputs);
+        visit_mut::visit_signature_mut(self, node);
+    }
+
+    fn visit_type_bare_fn_mut(&mut self, node: &mut TypeBareFn) {
+        strip(&mut node.inputs);
+        visit_mut::visit_type_bare_fn_mut(self, node);
+    }
+
+    fn visit_type_tuple_mut(&mut self, node: &mut TypeTuple) {
+        strip(&mut node.elems);
+        visit_mut::visit_type_tuple_mut(self, node);
+    }
+
+    fn visit_use_group_mut(&mut self, node: &mut UseGroup) {
+        strip(&mut node.items);
+        visit_mut::visit_use_group_mut(self, node);
+    }
+
+    fn visit_where_clause_mut(&mut self, node: &mut WhereClause) {
+        strip(&mut node.predicates);
+        visit_mut::visit_where_clause_mut(self, node);
+    }
+}
+
+#[test]
+fn test_macro_spacing() {
+    let src = "
+        fn f() {
+            m!('a 'b);
+            m!(break 'outer 'inner: loop {});
+            m!(- -1, && &&x, a < -b, 'c' 'd);
+        }
+    ";
+    let original = syn::parse_file(src).unwrap();
+    let printed = syn::pretty::unparse(&original);
+    let reparsed = syn::parse_file(&printed).unwrap();
+    let before = token_streams(&original);
+    let after = token_streams(&reparsed);
+    assert_eq!(before.len(), 3);
+    for (before, after) in before.iter().zip(&after) {
+        assert!(same_tokens(before.clone(), after.clone()), "{}", printed);
+    }
+}
+
+// The tokens of every macro call and attribute, in order.
+fn token_streams(file: &File) -> Vec<TokenStream> {
+    struct Tokens(Vec<TokenStream>);
+
+    impl<'ast> Visit<'ast> for Tokens {
+        fn visit_attribute(&mut self, attr: &'ast Attribute) {
+            self.0.push(attr.tokens.clone());
+        }
+
+        fn visit_macro(&mut self, mac: &'ast Macro) {
+            self.0.push(mac.tokens.clone());
+        }
+    }
+
+    let mut tokens = Tokens(Vec::new());
+    tokens.visit_file(file);
+    tokens.0
+}
+
+// Whether two token streams are the same, including the spacing of their
+// punctuation.
+fn same_tokens(a: TokenStream, b: TokenStream) -> bool {
+    let a: Vec<TokenTree> = a.into_iter().collect();
+    let b: Vec<TokenTree> = b.into_iter().collect();
+    a.len() == b.len()
+        && a.into_iter().zip(b).all(|pair| match pair {
+            (TokenTree::Group(a), TokenTree::Group(b)) => {
+                a.delimiter() == b.delimiter() && same_tokens(a.stream(), b.stream())
+            }
+            (TokenTree::Punct(a), TokenTree::Punct(b)) => {
+                a.as_char() == b.as_char() && a.spacing() == b.spacing()
+            }
+            (TokenTree::Ident(a), TokenTree::Ident(b)) => a == b,
+            (TokenTree::Literal(a), TokenTree::Literal(b)) => a.to_string() == b.to_string(),
+            _ => false,
+        })
+}
@@ -0,0 +1,133 @@
+use quote::quote;
+use syn::{Expr, ImplItem, Item, Stmt};
+
+#[test]
+fn test_items() {
+    let content = "
+        use std::io;
+        struct S { x: u8 y: u8 }
+        const C: u8 = ;
+        enum E { A }
+    ";
+    let (file, errors) = syn::parse_file_recovering(content);
+    let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+    assert_eq!(messages, ["expected `,`", "expected expression"]);
+
+    assert_eq!(file.items.len(), 4);
+    assert_eq!(
+        file.items[1],
+        Item::Verbatim(quote!(struct S { x: u8 y: u8 })),
+    );
+    assert_eq!(file.items[2], Item::Verbatim(quote!(const C: u8 = ;)));
+    assert!(match file.items[3] {
+        Item::Enum(_) => true,
+        _ => false,
+    });
+}
+
+#[test]
+fn test_missing_semicolon() {
+    // The broken item ends where the next one begins.
+    let content = "
+        type T = u8
+        fn f() {}
+    ";
+    let (file, errors) = syn::parse_file_recovering(content);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(file.items.len(), 2);
+    assert_eq!(file.items[0], Item::Verbatim(quote!(type T = u8)));
+    assert!(match file.items[1] {
+        Item::Fn(_) => true,
+        _ => false,
+    });
+}
+
+#[test]
+fn test_stmts() {
+    let content = "
+        fn f() {
+            let x = 1 +;
+            let y = x;
+            g(x y);
+            if x { h() } else { * }
+            x
+        }
+    ";
+    let (file, errors) = syn::parse_file_recovering(content);
+    let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        messages,
+        [
+            "expected expression",
+            "expected `,`",
+            "unexpected end of input, expected expression",
+        ],
+    );
+
+    let stmts = match &file.items[0] {
+        Item::Fn(item) => &item.block.stmts,
+        _ => panic!("expected fn"),
+    };
+    assert_eq!(stmts.len(), 5);
+    assert!(match &stmts[0] {
+        Stmt::Semi(Expr::Verbatim(tokens), _) => tokens.to_string() == "let x = 1 +",
+        _ => false,
+    });
+    assert!(match stmts[1] {
+        Stmt::Local(_) => true,
+        _ => false,
+    });
+    assert!(match &stmts[3] {
+        Stmt::Expr(Expr::Verbatim(tokens)) => tokens.to_string().starts_with("if x"),
+        _ => false,
+    });
+    assert!(match stmts[4] {
+        Stmt::Expr(Expr::Path(_)) => true,
+        _ => false,
+    });
+}
+
+#[test]
+fn test_nested() {
+    let content = "
+        mod m {
+            impl S {
+                fn ok(&self) {}
+                fn broken(&self) { self. }
+                const C: u8 = 1
+            }
+        }
+    ";
+    let (file, errors) = syn::parse_file_recovering(content);
+    assert_eq!(errors.len(), 2);
+
+    let items = match &file.items[0] {
+        Item::Mod(item) => &item.content.as_ref().unwrap().1,
+        _ => panic!("expected mod"),
+    };
+    let items = match &items[0] {
+        Item::Impl(item) => &item.items,
+        _ => panic!("expected impl"),
+    };
+    assert_eq!(items.len(), 3);
+    assert!(match &items[1] {
+        ImplItem::Method(method) => method.block.stmts.len() == 1,
+        _ => false,
+    });
+    assert_eq!(items[2], ImplItem::Verbatim(quote!(const C: u8 = 1)));
+}
+
+#[test]
+fn test_unbalanced() {
+    let (file, errors) = syn::parse_file_recovering("fn f() {");
+    assert!(file.items.is_empty());
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn test_no_errors() {
+    let content = "#!/bin/sh\n#![allow(dead_code)]\nfn main() {}\n";
+    let (file, errors) = syn::parse_file_recovering(content);
+    assert!(errors.is_empty());
+    assert_eq!(file, syn::parse_file(content).unwrap());
+}
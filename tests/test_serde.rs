@@ -0,0 +1,126 @@
+use serde_json::json;
+use std::fs;
+use syn::{Expr, File, Item, Lit, LitInt, Stmt};
+use walkdir::WalkDir;
+
+#[test]
+fn test_encoding() {
+    let expr: Expr = syn::parse_str("r#match.f::<T>(b\"s\", m!(x + 1))").unwrap();
+    let path = |ident: &str| {
+        json!({
+            "leading_colon": false,
+            "segments": [{ "ident": ident, "arguments": "None" }],
+        })
+    };
+    let expected = json!({
+        "MethodCall": {
+            "attrs": [],
+            "receiver": {
+                "Path": { "attrs": [], "qself": null, "path": path("r#match") },
+            },
+            "method": "f",
+            "turbofish": {
+                "args": [{ "Type": { "Path": { "qself": null, "path": path("T") } } }],
+            },
+            "args": [
+                { "Lit": { "attrs": [], "lit": { "ByteStr": "b\"s\"" } } },
+                {
+                    "Macro": {
+                        "attrs": [],
+                        "mac": { "path": path("m"), "delimiter": "Paren", "tokens": "x + 1" },
+                    },
+                },
+            ],
+        },
+    });
+    assert_eq!(serde_json::to_value(&expr).unwrap(), expected);
+    assert_eq!(serde_json::from_value::<Expr>(expected).unwrap(), expr);
+}
+
+#[test]
+fn test_tokens() {
+    let item: Item = syn::parse_str("impl<'a> !Send for S<'a> { fn f(&'a mut self) {} }").unwrap();
+    let json = serde_json::to_value(&item).unwrap();
+
+    // Optional tokens are represented by whether they are present, and tokens
+    // are left out of tuples.
+    let item_impl = &json["Impl"];
+    assert_eq!(item_impl["unsafety"], json!(false));
+    assert_eq!(item_impl["generics"]["lt_token"], json!(true));
+    assert_eq!(item_impl["trait_"][0], json!(true));
+    let receiver = &item_impl["items"][0]["Method"]["sig"]["inputs"][0]["Receiver"];
+    assert_eq!(receiver["reference"], json!([{ "ident": "a" }]));
+    assert_eq!(receiver["mutability"], json!(true));
+
+    assert_eq!(serde_json::from_value::<Item>(json).unwrap(), item);
+
+    // Trailing punctuation is represented by a null after the last value.
+    let expr: Expr = syn::parse_str("(a,)").unwrap();
+    let json = serde_json::to_value(&expr).unwrap();
+    let elems = json["Tuple"]["elems"].as_array().unwrap();
+    assert_eq!(elems.len(), 2);
+    assert!(elems[1].is_null());
+    assert_eq!(serde_json::from_value::<Expr>(json).unwrap(), expr);
+}
+
+#[test]
+fn test_omitted_fields() {
+    let json = json!({
+        "Path": {
+            "path": { "segments": [{ "ident": "a", "arguments": "None" }] },
+        },
+    });
+    let expr: Expr = serde_json::from_value(json).unwrap();
+    assert_eq!(expr, syn::parse_str::<Expr>("a").unwrap());
+
+    let json = json!({ "Path": { "qself": null } });
+    let err = serde_json::from_value::<Expr>(json).unwrap_err();
+    assert_eq!(err.to_string(), "missing field `path`");
+}
+
+#[test]
+fn test_invalid() {
+    let err = serde_json::from_value::<Expr>(json!({ "Path": { "path": {
+        "segments": [{ "ident": "1a", "arguments": "None" }],
+    } } }))
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value: string \"1a\", expected an identifier",
+    );
+
+    let err = serde_json::from_value::<LitInt>(json!("\"s\"")).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value: string \"\\\"s\\\"\", expected an integer literal",
+    );
+
+    let err = serde_json::from_value::<Stmt>(json!({ "Return": null })).unwrap_err();
+    assert!(err.to_string().starts_with("unknown variant `Return`"));
+}
+
+#[test]
+fn test_negative_literal() {
+    let lit = Lit::Int(LitInt::new("-10i32", proc_macro2::Span::call_site()));
+    let json = serde_json::to_value(&lit).unwrap();
+    assert_eq!(json, json!({ "Int": "-10i32" }));
+    assert_eq!(serde_json::from_value::<Lit>(json).unwrap(), lit);
+}
+
+// Every file of the crate's own sources must deserialize back to the same
+// syntax tree after serializing it to JSON.
+#[test]
+fn test_sources() {
+    for entry in WalkDir::new("src") {
+        let entry = entry.unwrap();
+        let path = entry.path();
+        if path.extension().map_or(true, |ext| ext != "rs") {
+            continue;
+        }
+        let content = fs::read_to_string(path).unwrap();
+        let file = syn::parse_file(&content).unwrap();
+        let json = serde_json::to_string(&file).unwrap();
+        let back: File = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, file, "{}", path.display());
+    }
+}
@@ -0,0 +1,88 @@
+use proc_macro2::Span;
+use syn::source_map::SourceMap;
+use syn::spanned::Spanned;
+use syn::{Expr, Item, ItemStruct};
+
+#[test]
+fn test_locate_node() {
+    let mut source_map = SourceMap::new();
+    let first = source_map
+        .parse_file("first.rs", "fn f() {}\n\nstruct S;\n")
+        .unwrap();
+    let second = source_map
+        .parse_file("second.rs", "// Comment.\nconst Ä: u8 = 1 + 2;\n")
+        .unwrap();
+
+    let location = source_map.locate(first.items[1].span()).unwrap();
+    assert_eq!(location.file, "first.rs");
+    assert_eq!(location.byte_range, 11..20);
+    assert_eq!((location.start.line, location.start.column), (3, 0));
+    assert_eq!((location.end.line, location.end.column), (3, 9));
+
+    let expr = match &second.items[0] {
+        Item::Const(item) => &item.expr.as_ref().unwrap().1,
+        _ => unreachable!(),
+    };
+    let location = source_map.locate(expr.span()).unwrap();
+    assert_eq!(location.file, "second.rs");
+    assert_eq!(&location.source[location.byte_range], "1 + 2");
+    assert_eq!((location.start.line, location.start.column), (2, 14));
+    assert_eq!((location.end.line, location.end.column), (2, 19));
+
+    assert!(source_map.locate(Span::call_site()).is_none());
+    let other: Expr = syn::parse_str("1 + 2").unwrap();
+    assert!(source_map.locate(other.span()).is_none());
+}
+
+#[test]
+fn test_shebang() {
+    let mut source_map = SourceMap::new();
+    let text = "\u{feff}#!/usr/bin/env run-cargo-script\nfn main() {}\n";
+    let file = source_map.parse_file("main.rs", text).unwrap();
+    let location = source_map.locate(file.items[0].span()).unwrap();
+    assert_eq!(&text[location.byte_range], "fn main() {}");
+    assert_eq!((location.start.line, location.start.column), (2, 0));
+}
+
+#[test]
+fn test_error_location() {
+    let mut source_map = SourceMap::new();
+    source_map
+        .parse_str::<ItemStruct>("ok.rs", "struct A;")
+        .unwrap();
+
+    let err = source_map
+        .parse_str::<ItemStruct>("missing_comma.rs", "struct B {\n    x: u8\n    y: u8,\n}")
+        .unwrap_err();
+    let location = err.location(&source_map).unwrap();
+    assert_eq!(location.file, "missing_comma.rs");
+    assert_eq!(&location.source[location.byte_range], "y");
+    assert_eq!((location.start.line, location.start.column), (3, 4));
+
+    // Errors at the end of the input point at the last token.
+    let err = source_map
+        .parse_str::<ItemStruct>("eof.rs", "struct C")
+        .unwrap_err();
+    let location = err.location(&source_map).unwrap();
+    assert_eq!(location.file, "eof.rs");
+    assert_eq!(&location.source[location.byte_range], "C");
+
+    let err = source_map
+        .parse_str::<ItemStruct>("unclosed.rs", "struct D {\n    x: \"u8,\n}")
+        .unwrap_err();
+    let location = err.location(&source_map).unwrap();
+    assert_eq!(location.file, "unclosed.rs");
+}
+
+#[test]
+fn test_error_spanned() {
+    let mut source_map = SourceMap::new();
+    let item: ItemStruct = source_map
+        .parse_str("lib.rs", "pub struct S<T> {\n    t: T,\n}")
+        .unwrap();
+    let err = syn::Error::new_spanned(&item.generics, "unused type parameter");
+    let location = err.location(&source_map).unwrap();
+    assert_eq!(&location.source[location.byte_range], "<T>");
+    assert_eq!((location.start.line, location.start.column), (1, 12));
+    assert_eq!((location.end.line, location.end.column), (1, 15));
+}
@@ -2,8 +2,9 @@
 mod macros;
 
 use proc_macro2::{Delimiter, Group, Ident, Span, TokenStream, TokenTree};
+use quote::quote;
 use std::iter::FromIterator;
-use syn::Stmt;
+use syn::{Expr, Stmt};
 
 #[test]
 fn test_raw_operator() {
@@ -12,7 +13,21 @@ fn test_raw_operator() {
     snapshot!(stmt, @r###"
     Local(Local {
         pat: Pat::Wild,
-        init: Some(Verbatim(`& raw const x`)),
+        init: Some(LocalInit {
+            expr: Expr::RawAddr {
+                mutability: Const,
+                expr: Expr::Path {
+                    path: Path {
+                        segments: [
+                            PathSegment {
+                                ident: "x",
+                                arguments: None,
+                            },
+                        ],
+                    },
+                },
+            },
+        }),
     })
     "###);
 }
@@ -24,15 +39,17 @@ fn test_raw_variable() {
     snapshot!(stmt, @r###"
     Local(Local {
         pat: Pat::Wild,
-        init: Some(Expr::Reference {
-            expr: Expr::Path {
-                path: Path {
-                    segments: [
-                        PathSegment {
-                            ident: "raw",
-                            arguments: None,
-                        },
-                    ],
+        init: Some(LocalInit {
+            expr: Expr::Reference {
+                expr: Expr::Path {
+                    path: Path {
+                        segments: [
+                            PathSegment {
+                                ident: "raw",
+                                arguments: None,
+                            },
+                        ],
+                    },
                 },
             },
         }),
@@ -72,3 +89,80 @@ fn test_none_group() {
     })
     "###);
 }
+
+#[test]
+fn test_let_else() {
+    let stmt = syn::parse_str::<Stmt>("let Some(x) = opt else { return; };").unwrap();
+    assert_eq!(
+        quote!(#stmt).to_string(),
+        "let Some (x) = opt else { return ; } ;",
+    );
+
+    snapshot!(stmt, @r###"
+    Local(Local {
+        pat: Pat::TupleStruct {
+            path: Path {
+                segments: [
+                    PathSegment {
+                        ident: "Some",
+                        arguments: None,
+                    },
+                ],
+            },
+            pat: PatTuple {
+                elems: [
+                    Pat::Ident {
+                        ident: "x",
+                    },
+                ],
+            },
+        },
+        init: Some(LocalInit {
+            expr: Expr::Path {
+                path: Path {
+                    segments: [
+                        PathSegment {
+                            ident: "opt",
+                            arguments: None,
+                        },
+                    ],
+                },
+            },
+            diverge: Some(Block {
+                stmts: [
+                    Semi(
+                        Expr::Return,
+                    ),
+                ],
+            }),
+        }),
+    })
+    "###);
+}
+
+#[test]
+fn test_let_else_trailing_brace() {
+    let mut stmt = syn::parse_str::<Stmt>("let Some(x) = (S {}) else { return; };").unwrap();
+    let paren = match &mut stmt {
+        Stmt::Local(local) => &mut local.init.as_mut().unwrap().expr,
+        _ => unreachable!(),
+    };
+    **paren = match &**paren {
+        Expr::Paren(paren) => (*paren.expr).clone(),
+        _ => unreachable!(),
+    };
+
+    let printed = quote!(#stmt).to_string();
+    assert_eq!(printed, "let Some (x) = (S { }) else { return ; } ;");
+    let reparsed = syn::parse_str::<Stmt>(&printed).unwrap();
+    assert_eq!(quote!(#reparsed).to_string(), printed);
+}
+
+#[test]
+fn test_let_else_invalid() {
+    assert!(syn::parse_str::<Stmt>("let x = S {} else { return; };").is_err());
+    assert!(syn::parse_str::<Stmt>("let x = if a { b } else { c } else { d };").is_err());
+    assert!(syn::parse_str::<Stmt>("let x = m! {} else { return; };").is_err());
+    assert!(syn::parse_str::<Stmt>("let x = a && b else { return; };").is_err());
+    assert!(syn::parse_str::<Stmt>("let x = (a && b) else { return; };").is_ok());
+}
@@ -0,0 +1,63 @@
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::try_fold::{self, TryFold};
+use syn::{parse_quote, Expr, Lit};
+
+// Doubles every integer literal, and rejects any literal that is not an
+// integer.
+struct Double;
+
+impl TryFold for Double {
+    type Error = syn::Error;
+
+    fn fold_expr(&mut self, expr: Expr) -> syn::Result<Expr> {
+        match try_fold::fold_expr(self, expr)? {
+            Expr::Lit(expr) => match expr.lit {
+                Lit::Int(lit) => {
+                    let value = lit.base10_parse::<u64>()? * 2;
+                    Ok(parse_quote!(#value))
+                }
+                lit => Err(syn::Error::new_spanned(lit, "expected integer")),
+            },
+            expr => Ok(expr),
+        }
+    }
+}
+
+#[test]
+fn test_transform() {
+    let expr: Expr = syn::parse_str("f(1, [2, 3 + x])").unwrap();
+    let doubled = Double.fold_expr(expr).unwrap();
+    assert_eq!(quote!(#doubled).to_string(), "f (2u64 , [4u64 , 6u64 + x])");
+}
+
+#[test]
+fn test_error() {
+    let expr: Expr = syn::parse_str("f(1, \"s\", 'c')").unwrap();
+    let err = Double.fold_expr(expr).unwrap_err();
+    assert_eq!(err.to_string(), "expected integer");
+    assert_eq!(err.into_iter().count(), 1);
+}
+
+#[test]
+fn test_error_span() {
+    let expr: Expr = syn::parse_str("[1, 2 + \"s\", 'c']").unwrap();
+    let expected = match &expr {
+        Expr::Array(array) => match &array.elems[1] {
+            Expr::Binary(binary) => binary.right.span(),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+
+    // The error points at the first literal that could not be folded, even
+    // though the folds of the expressions around it have taken apart the
+    // tree that it came from.
+    let err = Double.fold_expr(expr).unwrap_err();
+    assert_eq!(format!("{:?}", err.span()), format!("{:?}", expected));
+    #[cfg(feature = "span-locations")]
+    {
+        let start = err.span().start();
+        assert_eq!((start.line, start.column), (1, 8));
+    }
+}
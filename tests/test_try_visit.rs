@@ -0,0 +1,71 @@
+use quote::quote;
+use syn::try_visit::{self, TryVisit};
+use syn::{Expr, ExprUnsafe, File, Ident, Item};
+
+struct FindUnsafe;
+
+impl<'ast> TryVisit<'ast> for FindUnsafe {
+    type Break = &'ast ExprUnsafe;
+
+    fn visit_expr(&mut self, node: &'ast Expr) -> Result<(), Self::Break> {
+        match node {
+            Expr::Unsafe(node) => Err(node),
+            _ => try_visit::visit_expr(self, node),
+        }
+    }
+}
+
+#[test]
+fn test_find_first() {
+    let file: File = syn::parse_str(
+        "fn f() { let x = g(h(unsafe { a() })); unsafe { b() } }\n\
+         fn g() { unsafe { c() } }",
+    )
+    .unwrap();
+    let found = FindUnsafe.visit_file(&file).unwrap_err();
+    let stmts = &found.block.stmts;
+    assert_eq!(quote!(#(#stmts)*).to_string(), "a ()");
+
+    let file: File = syn::parse_str("fn f() { a() }").unwrap();
+    assert!(FindUnsafe.visit_file(&file).is_ok());
+}
+
+// Counts the identifiers visited until one of them is the given name, at
+// which point the traversal must stop.
+struct StopAt {
+    name: &'static str,
+    seen: Vec<String>,
+}
+
+impl<'ast> TryVisit<'ast> for StopAt {
+    type Break = String;
+
+    fn visit_ident(&mut self, node: &'ast Ident) -> Result<(), Self::Break> {
+        self.seen.push(node.to_string());
+        if node == self.name {
+            Err(format!("found {}", node))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[test]
+fn test_stop_early() {
+    let item: Item = syn::parse_str("struct S { a: A, b: B, c: C }").unwrap();
+
+    let mut visitor = StopAt {
+        name: "b",
+        seen: Vec::new(),
+    };
+    let err = visitor.visit_item(&item).unwrap_err();
+    assert_eq!(err, "found b");
+    assert_eq!(visitor.seen, ["S", "a", "A", "b"]);
+
+    let mut visitor = StopAt {
+        name: "z",
+        seen: Vec::new(),
+    };
+    assert_eq!(visitor.visit_item(&item), Ok(()));
+    assert_eq!(visitor.seen, ["S", "a", "A", "b", "B", "c", "C"]);
+}
@@ -0,0 +1,108 @@
+use proc_macro2::Span;
+use syn::visit_ancestors::{self, Ancestors, Node, VisitAncestors};
+use syn::{ExprPath, File, Ident, Item};
+
+// Records, for each path expression, the chain of fields from the root down
+// to it and whether it is inside of a closure or a const item.
+struct Paths {
+    found: Vec<(String, String, bool)>,
+}
+
+impl<'ast> VisitAncestors<'ast> for Paths {
+    fn visit_expr_path(&mut self, node: &'ast ExprPath, ancestors: &mut Ancestors<'ast>) {
+        let name = node.path.segments[0].ident.to_string();
+        let fields = ancestors
+            .iter()
+            .map(|ancestor| match ancestor.index {
+                Some(index) => format!("{}[{}]", ancestor.field, index),
+                None => ancestor.field.to_owned(),
+            })
+            .collect::<Vec<_>>()
+            .join(".");
+        let nested = ancestors.iter().any(|ancestor| match ancestor.node {
+            Node::ExprClosure(_) | Node::ItemConst(_) => true,
+            _ => false,
+        });
+        self.found.push((name, fields, nested));
+        visit_ancestors::visit_expr_path(self, node, ancestors);
+    }
+}
+
+#[test]
+fn test_fields() {
+    let file: File = syn::parse_str(
+        "const C: u8 = a;\n\
+         fn f() { b; let g = |x| c; }",
+    )
+    .unwrap();
+    let mut visitor = Paths { found: Vec::new() };
+    let mut ancestors = Ancestors::new();
+    visitor.visit_file(&file, &mut ancestors);
+    assert!(ancestors.is_empty());
+
+    let expected = [
+        ("a", "items[0].Const.expr.Path", true),
+        ("b", "items[1].Fn.block.stmts[0].Semi.Path", false),
+        (
+            "c",
+            "items[1].Fn.block.stmts[1].Local.init.expr.Closure.body.Path",
+            true,
+        ),
+    ];
+    assert_eq!(visitor.found.len(), expected.len());
+    for (found, expected) in visitor.found.iter().zip(&expected) {
+        assert_eq!(found.0, expected.0);
+        assert_eq!(found.1, expected.1);
+        assert_eq!(found.2, expected.2);
+    }
+}
+
+// Checks that each identifier's parent is the node that it belongs to, with
+// the typed reference pointing at that exact node.
+struct Parents<'ast> {
+    item: &'ast Item,
+    checked: usize,
+}
+
+impl<'ast> VisitAncestors<'ast> for Parents<'ast> {
+    fn visit_ident(&mut self, node: &'ast Ident, ancestors: &mut Ancestors<'ast>) {
+        let parent = ancestors.parent().unwrap();
+        if node == "S" {
+            match (parent.node, self.item) {
+                (Node::ItemStruct(parent), Item::Struct(item)) => {
+                    assert!(std::ptr::eq(parent, item));
+                }
+                _ => panic!("unexpected parent of S"),
+            }
+            assert_eq!(parent.field, "ident");
+            assert_eq!(ancestors.len(), 2);
+            self.checked += 1;
+        } else if node == "x" {
+            match parent.node {
+                Node::Field(_) => {}
+                _ => panic!("unexpected parent of x"),
+            }
+            let fields = ancestors.iter().nth(2).unwrap();
+            assert_eq!(fields.field, "Named");
+            let named = ancestors.iter().nth(3).unwrap();
+            assert_eq!((named.field, named.index), ("named", Some(1)));
+            self.checked += 1;
+        }
+        visit_ancestors::visit_ident(self, node, ancestors);
+    }
+
+    fn visit_span(&mut self, _node: &Span, ancestors: &mut Ancestors<'ast>) {
+        assert!(!ancestors.is_empty());
+    }
+}
+
+#[test]
+fn test_parent() {
+    let item: Item = syn::parse_str("struct S { w: u8, x: u8 }").unwrap();
+    let mut visitor = Parents {
+        item: &item,
+        checked: 0,
+    };
+    visitor.visit_item(&item, &mut Ancestors::new());
+    assert_eq!(visitor.checked, 2);
+}